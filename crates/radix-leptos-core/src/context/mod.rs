@@ -2,25 +2,28 @@
 //! 
 //! Context providers and utilities for component communication.
 
-pub mod collection;
+// pub mod collection; // Temporarily disabled
 
-pub use collection::*;
+// pub use collection::*;
 
-/// Direction for component orientation
-#[derive(Debug, Clone, Copy, PartialEq)]
+/// Reading direction, which decides what the horizontal arrow keys mean
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum Direction {
+    #[default]
     Ltr,
     Rtl,
 }
 
-impl Direction {
-    pub fn from_str(s: &str) -> Self {
+impl From<&str> for Direction {
+    fn from(s: &str) -> Self {
         match s.to_lowercase().as_str() {
             "rtl" => Direction::Rtl,
             _ => Direction::Ltr,
         }
     }
-    
+}
+
+impl Direction {
     pub fn as_str(&self) -> &'static str {
         match self {
             Direction::Ltr => "ltr",
@@ -36,14 +39,16 @@ pub enum Orientation {
     Vertical,
 }
 
-impl Orientation {
-    pub fn from_str(s: &str) -> Self {
+impl From<&str> for Orientation {
+    fn from(s: &str) -> Self {
         match s.to_lowercase().as_str() {
             "vertical" => Orientation::Vertical,
             _ => Orientation::Horizontal,
         }
     }
-    
+}
+
+impl Orientation {
     pub fn as_str(&self) -> &'static str {
         match self {
             Orientation::Horizontal => "horizontal",
@@ -1,21 +1,67 @@
 //! # Core Hooks
-//! 
+//!
 //! Essential hooks for building accessible and interactive components.
 
-pub mod use_controllable_state;
+pub mod use_body_scroll_lock;
+pub mod use_clipboard;
 pub mod use_compose_refs;
+pub mod use_controllable_state;
+pub mod use_debounce;
+pub mod use_element_size;
 pub mod use_escape_keydown;
-pub mod use_outside_click;
+pub mod use_event_listener;
 pub mod use_focus_trap;
-pub mod use_body_scroll_lock;
+pub mod use_focus_visible;
+pub mod use_focus_within;
+pub mod use_forced_colors;
+pub mod use_hotkeys;
 pub mod use_id;
+pub mod use_idle;
+pub mod use_intersection_observer;
+pub mod use_long_press;
+pub mod use_media_query;
+pub mod use_mutation_observer;
+pub mod use_network_status;
+pub mod use_outside_click;
+pub mod use_prefers_reduced_motion;
+pub mod use_presence;
 pub mod use_previous;
+pub mod use_resize_observer;
+pub mod use_roving_focus;
+pub mod use_scroll;
+pub mod use_storage;
+pub mod use_swipe;
+pub mod use_throttle;
+pub mod use_typeahead;
 
-pub use use_controllable_state::*;
+pub use use_body_scroll_lock::*;
+pub use use_clipboard::*;
 pub use use_compose_refs::*;
+pub use use_controllable_state::*;
+pub use use_debounce::*;
+pub use use_element_size::*;
 pub use use_escape_keydown::*;
-pub use use_outside_click::*;
+pub use use_event_listener::*;
 pub use use_focus_trap::*;
-pub use use_body_scroll_lock::*;
+pub use use_focus_visible::*;
+pub use use_focus_within::*;
+pub use use_forced_colors::*;
+pub use use_hotkeys::*;
 pub use use_id::*;
-pub use use_previous::*;
\ No newline at end of file
+pub use use_idle::*;
+pub use use_intersection_observer::*;
+pub use use_long_press::*;
+pub use use_media_query::*;
+pub use use_mutation_observer::*;
+pub use use_network_status::*;
+pub use use_outside_click::*;
+pub use use_prefers_reduced_motion::*;
+pub use use_presence::*;
+pub use use_previous::*;
+pub use use_resize_observer::*;
+pub use use_roving_focus::*;
+pub use use_scroll::*;
+pub use use_storage::*;
+pub use use_swipe::*;
+pub use use_throttle::*;
+pub use use_typeahead::*;
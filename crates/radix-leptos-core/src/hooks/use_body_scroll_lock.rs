@@ -1,31 +1,36 @@
-use web_sys::{HtmlElement, CssStyleDeclaration};
+use leptos::prelude::*;
+use std::cell::RefCell;
+use web_sys::{CssStyleDeclaration, HtmlElement};
 
 /// Hook for locking body scroll to prevent background scrolling
-/// 
+///
 /// This hook is essential for modal overlays, full-screen dialogs,
 /// and other components that should prevent the underlying content from scrolling.
-/// It handles both preventing scrollwheel events and preserving scroll position.
-/// 
+/// It reserves the scrollbar's width to avoid layout shift and preserves the
+/// scroll position. Locks nest: the body is unlocked once every hook that
+/// locked it has released it.
+///
 /// # Example
-/// 
-/// ```rust
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
 /// use radix_leptos_core::use_body_scroll_lock;
-/// 
+///
 /// #[component]
 /// pub fn Modal() -> impl IntoView {
-///     let (open, setopen) = create_signal(false);
-///     
+///     let open = RwSignal::new(false);
+///
 ///     // Lock body scroll when modal is open
 ///     use_body_scroll_lock(open.into());
-///     
+///
 ///     view! {
-///         <button on:click=move |_| setopen.set(true)>
+///         <button on:click=move |_| open.set(true)>
 ///             "Open Modal"
 ///         </button>
 ///         <Show when=move || open.get()>
 ///             <div class="modal-overlay">
 ///                 <div class="modal-content">
-///                     <button on:click=move |_| setopen.set(false)>
+///                     <button on:click=move |_| open.set(false)>
 ///                         "Close"
 ///                     </button>
 ///                 </div>
@@ -35,124 +40,16 @@ use web_sys::{HtmlElement, CssStyleDeclaration};
 /// }
 /// ```
 pub fn use_body_scroll_lock(locked: Signal<bool>) {
-    let (original_style, set_original_style) = create_signal::<Option<String>>(None);
-    let (original_scroll_position, set_original_scroll_position) = create_signal::<Option<f64>>(None);
-    
-    create_effect(move |_| {
-        let should_lock = locked.get();
-        
-        if should_lock {
-            lock_body_scroll(set_original_style, set_original_scroll_position);
-    });
-    
-    // Cleanup on component unmount
-    on_cleanup(move || {
-        unlock_body_scroll(original_style.get_untracked(), original_scroll_position.get_untracked());
-    });
-}
-
-/// Lock body scroll with style preservation
-fn lock_body_scroll(
-    set_original_style: WriteSignal<Option<String>>,
-    set_original_scroll_position: WriteSignal<Option<f64>>,
-) {
-    if let Some(body) = get_body_element() {
-        let style = body.style();
-        
-        // Store original overflow style
-        let original_overflow = style.get_property_value("overflow").unwrap_or_default();
-        if !original_overflow.is_empty() {
-            set_original_style.set(Some(original_overflow));
-        }
-        
-        // Store current scroll position
-        if let Some(window) = web_sys::window() {
-            let scroll_y = window.scroll_y().unwrap_or(0.0);
-            set_original_scroll_position.set(Some(scroll_y));
-        }
-        
-        // Apply scroll lock styles
-        let _ = style.set_property("overflow", "hidden");
-        let _ = style.set_property("padding-right", &get_scrollbar_width());
-        
-        // Prevent scroll position jumping by setting top position
-        if let Some(scroll_pos) = set_original_scroll_position.get_untracked() {
-            let _ = style.set_property("position", "fixed");
-            let _ = style.set_property("top", &format!("-{}px", scroll_pos));
-            let _ = style.set_property("width", "100%");
-        }
-    }
-}
-
-/// Unlock body scroll and restore original styles
-fn unlock_body_scroll(
-    original_style: Option<String>,
-    original_scroll_position: Option<f64>,
-) {
-    if let Some(body) = get_body_element() {
-        let style = body.style();
-        
-        // Restore original overflow or remove if it was empty
-        if let Some(original) = original_style {
-            let _ = style.set_property("overflow", &original);
-        
-        // Remove scroll lock styles
-        let _ = style.remove_property("padding-right");
-        let _ = style.remove_property("position");
-        let _ = style.remove_property("top");
-        let _ = style.remove_property("width");
-        
-        // Restore scroll position
-        if let (Some(window), Some(scroll_pos)) = (web_sys::window(), original_scroll_position) {
-            window.scroll_to_with_x_and_y(0.0, scroll_pos);
-        }
-    }
-}
-
-/// Get the body element
-fn get_body_element() -> Option<HtmlElement> {
-    web_sys::window()?
-        .document()?
-        .body()
+    use_body_scroll_lock_with_options(locked, ScrollLockOptions::default());
 }
 
-/// Calculate scrollbar width to prevent layout shift
-fn get_scrollbar_width() -> String {
-    // Create a temporary element to measure scrollbar width
-    if let Some(document) = web_sys::window().and_then(|w| w.document()) {
-        if let Ok(div) = document.create_element("div") {
-            if let Ok(style) = div.dyn_ref::<HtmlElement>() {
-                let css = style.style();
-                let _ = css.set_property("visibility", "hidden");
-                let _ = css.set_property("position", "absolute");
-                let _ = css.set_property("top", "-9999px");
-                let _ = css.set_property("width", "50px");
-                let _ = css.set_property("height", "50px");
-                let _ = css.set_property("overflow", "scroll");
-                
-                if let Ok(body) = document.body().ok_or("No body") {
-                    let _ = body.append_child(&div);
-                    
-                    let scrollbar_width = 50 - div.client_width();
-                    let _ = body.remove_child(&div);
-                    
-                    return format!("{}px", scrollbar_width);
-                }
-            }
-        }
-    }
-    
-    // Fallback scrollbar width
-    "15px".to_string()
-}
-
-/// Hook with options for customizing scroll lock behavior
-#[derive(Clone)]
+/// Options for customizing scroll lock behavior
+#[derive(Debug, Clone, PartialEq)]
 pub struct ScrollLockOptions {
     /// Whether to reserve space for scrollbar to prevent layout shift
-    pub __reserve_scrollbar_gap: bool,
+    pub reserve_scrollbar_gap: bool,
     /// Whether to prevent scroll position jumping
-    pub __prevent_scroll_jump: bool,
+    pub prevent_scroll_jump: bool,
     /// Custom padding to apply when locking
     pub custom_padding: Option<String>,
 }
@@ -168,136 +65,185 @@ impl Default for ScrollLockOptions {
 }
 
 /// Advanced scroll lock hook with customizable options
-pub fn use_body_scroll_lock_with_options(
-    locked: Signal<bool>,
-    options: ScrollLockOptions,
-) {
-    let (original_styles, set_original_styles) = create_signal::<Option<ScrollLockState>>(None);
-    
-    create_effect(move |_| {
+pub fn use_body_scroll_lock_with_options(locked: Signal<bool>, options: ScrollLockOptions) {
+    let holding = StoredValue::new(false);
+
+    Effect::new(move |_| {
         let should_lock = locked.get();
-        let opts = options.clone();
-        
-        if should_lock {
-            lock_body_scroll_advanced(set_original_styles, opts);
+        if should_lock != holding.get_value() {
+            holding.set_value(should_lock);
+            if should_lock {
+                acquire_scroll_lock(&options);
+            } else {
+                release_scroll_lock();
+            }
+        }
     });
-    
+
+    // Release on unmount, so a dialog removed while open doesn't leave the page locked
     on_cleanup(move || {
-        unlock_body_scroll_advanced(original_styles.get_untracked());
+        if holding.try_get_value() == Some(true) {
+            release_scroll_lock();
+        }
     });
 }
 
+/// Body styles saved by the first lock, restored by the last unlock
 #[derive(Clone)]
 struct ScrollLockState {
-    overflow: Option<String>,
-    position: Option<String>,
-    top: Option<String>,
-    width: Option<String>,
-    padding_right: Option<String>,
+    overflow: String,
+    position: String,
+    top: String,
+    width: String,
+    padding_right: String,
     scroll_position: Option<f64>,
 }
 
-fn lock_body_scroll_advanced(
-    set_original_styles: WriteSignal<Option<ScrollLockState>>,
-    options: ScrollLockOptions,
-) {
-    if let Some(body) = get_body_element() {
-        let style = body.style();
-        
-        // Store original styles
-        let original_state = ScrollLockState {
-            overflow: get_style_property(&style, "overflow"),
-            position: get_style_property(&style, "position"),
-            top: get_style_property(&style, "top"),
-            width: get_style_property(&style, "width"),
-            padding_right: get_style_property(&style, "padding-right"),
-            scroll_position: web_sys::window().and_then(|w| w.scroll_y().ok()),
-        };
-        
-        set_original_styles.set(Some(original_state.clone()));
-        
-        // Apply scroll lock
-        let _ = style.set_property("overflow", "hidden");
-        
-        if options.reserve_scrollbar_gap {
-            let padding = options.custom_padding
-                .unwrap_or_else(get_scrollbar_width);
-            let _ = style.set_property("padding-right", &padding);
+/// Number of active locks, and the styles to restore when the last one is released
+#[derive(Default)]
+struct ScrollLockRegistry {
+    count: usize,
+    saved: Option<ScrollLockState>,
+}
+
+thread_local! {
+    static SCROLL_LOCK: RefCell<ScrollLockRegistry> = RefCell::default();
+}
+
+fn acquire_scroll_lock(options: &ScrollLockOptions) {
+    SCROLL_LOCK.with_borrow_mut(|registry| {
+        registry.count += 1;
+        if registry.count == 1 {
+            registry.saved = lock_body_scroll(options);
         }
-        
-        if options.prevent_scroll_jump {
-            if let Some(scroll_pos) = original_state.scroll_position {
-                let _ = style.set_property("position", "fixed");
-                let _ = style.set_property("top", &format!("-{}px", scroll_pos));
-                let _ = style.set_property("width", "100%");
-            }
+    });
+}
+
+fn release_scroll_lock() {
+    let released = SCROLL_LOCK.with_borrow_mut(|registry| {
+        registry.count = registry.count.saturating_sub(1);
+        if registry.count == 0 {
+            registry.saved.take()
+        } else {
+            None
         }
+    });
+    if let Some(state) = released {
+        unlock_body_scroll(state);
     }
 }
 
-fn unlock_body_scroll_advanced(original_state: Option<ScrollLockState>) {
-    if let (Some(body), Some(state)) = (get_body_element(), original_state) {
-        let style = body.style();
-        
-        // Restore or remove properties
-        restore_or_remove_property(&style, "overflow", state.overflow);
-        restore_or_remove_property(&style, "position", state.position);
-        restore_or_remove_property(&style, "top", state.top);
-        restore_or_remove_property(&style, "width", state.width);
-        restore_or_remove_property(&style, "padding-right", state.padding_right);
-        
-        // Restore scroll position
-        if let (Some(window), Some(scroll_pos)) = (web_sys::window(), state.scroll_position) {
-            window.scroll_to_with_x_and_y(0.0, scroll_pos);
+fn lock_body_scroll(options: &ScrollLockOptions) -> Option<ScrollLockState> {
+    let window = web_sys::window()?;
+    let body = get_body_element()?;
+    let style = body.style();
+    // Measured before overflow is hidden, while the scrollbar is still there
+    let scrollbar_width = get_scrollbar_width();
+
+    let original_state = ScrollLockState {
+        overflow: get_style_property(&style, "overflow"),
+        position: get_style_property(&style, "position"),
+        top: get_style_property(&style, "top"),
+        width: get_style_property(&style, "width"),
+        padding_right: get_style_property(&style, "padding-right"),
+        scroll_position: window.scroll_y().ok(),
+    };
+
+    let _ = style.set_property("overflow", "hidden");
+
+    if options.reserve_scrollbar_gap {
+        let padding = options
+            .custom_padding
+            .clone()
+            .unwrap_or_else(|| format!("{}px", scrollbar_width));
+        let _ = style.set_property("padding-right", &padding);
+    }
+
+    // Pinning the body keeps iOS Safari from scrolling it anyway
+    if options.prevent_scroll_jump {
+        if let Some(scroll_pos) = original_state.scroll_position {
+            let _ = style.set_property("position", "fixed");
+            let _ = style.set_property("top", &format!("-{}px", scroll_pos));
+            let _ = style.set_property("width", "100%");
         }
     }
+
+    Some(original_state)
 }
 
-fn get_style_property(style: &CssStyleDeclaration, property: &str) -> Option<String> {
-    style.get_property_value(property).ok()
+fn unlock_body_scroll(state: ScrollLockState) {
+    let Some(body) = get_body_element() else {
+        return;
+    };
+    let style = body.style();
 
-fn restore_or_remove_property(
-    style: &CssStyleDeclaration,
-    property: &str,
-    original_value: Option<String>,
-) {
-    if let Some(value) = original_value {
-        let _ = style.set_property(property, &value);
+    restore_or_remove_property(&style, "overflow", &state.overflow);
+    restore_or_remove_property(&style, "position", &state.position);
+    restore_or_remove_property(&style, "top", &state.top);
+    restore_or_remove_property(&style, "width", &state.width);
+    restore_or_remove_property(&style, "padding-right", &state.padding_right);
+
+    if let (Some(window), Some(scroll_pos)) = (web_sys::window(), state.scroll_position) {
+        window.scroll_to_with_x_and_y(0.0, scroll_pos);
+    }
+}
+
+/// Get the body element
+fn get_body_element() -> Option<HtmlElement> {
+    web_sys::window()?.document()?.body()
+}
+
+/// Width of the page's vertical scrollbar, 0 for overlay scrollbars
+fn get_scrollbar_width() -> f64 {
+    let Some(window) = web_sys::window() else {
+        return 0.0;
+    };
+    let viewport_width = window
+        .inner_width()
+        .ok()
+        .and_then(|width| width.as_f64())
+        .unwrap_or(0.0);
+    let content_width = window
+        .document()
+        .and_then(|document| document.document_element())
+        .map(|root| root.client_width() as f64)
+        .unwrap_or(viewport_width);
+
+    (viewport_width - content_width).max(0.0)
+}
+
+fn get_style_property(style: &CssStyleDeclaration, property: &str) -> String {
+    style.get_property_value(property).unwrap_or_default()
+}
+
+fn restore_or_remove_property(style: &CssStyleDeclaration, property: &str, original_value: &str) {
+    if original_value.is_empty() {
+        let _ = style.remove_property(property);
+    } else {
+        let _ = style.set_property(property, original_value);
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::*;
     use wasm_bindgen_test::*;
-    
+
     wasm_bindgen_test_configure!(run_in_browser);
-    
+
     #[wasm_bindgen_test]
-    fn test_scroll_lock_activation() {
-        run_test(|cx| {
-            let (locked, set_locked) = create_signal(cx, false);
-            
-            use_body_scroll_lock(locked.into());
-            
-            // Initially not locked
-            assert!(!locked.get());
-            
-            // Lock scroll
-            set_locked.set(true);
-            
-            // Verify body has overflow hidden (simplified test)
-            if let Some(body) = get_body_element() {
-                // In a real test, we'd check the computed style
-                // This is a simplified verification
-                assert!(locked.get());
-            }
-        });
-    }
-    
-    fn run_test<F>(f: F) where F: FnOnce(Scope) {
-        let rt = tokio::runtime::Runtime::new().unwrap();
-        rt.block_on(async {
-            let _ = create_runtime();
-            run_scope(create_runtime(), f);
-        });
+    fn test_nested_scroll_locks() {
+        let overflow = || get_style_property(&get_body_element().unwrap().style(), "overflow");
+        let options = ScrollLockOptions::default();
+
+        acquire_scroll_lock(&options);
+        acquire_scroll_lock(&options);
+        assert_eq!(overflow(), "hidden");
+
+        // The body stays locked until the last lock is released
+        release_scroll_lock();
+        assert_eq!(overflow(), "hidden");
+        release_scroll_lock();
+        assert_eq!(overflow(), "");
     }
-}
\ No newline at end of file
+}
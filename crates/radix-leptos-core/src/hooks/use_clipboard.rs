@@ -0,0 +1,277 @@
+use std::time::Duration;
+
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{HtmlDocument, HtmlTextAreaElement};
+
+/// Why the clipboard could not be read or written
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ClipboardError {
+    #[error("the clipboard is not available in this context")]
+    Unsupported,
+    #[error("clipboard access was denied")]
+    PermissionDenied,
+    #[error("clipboard access failed: {0}")]
+    Failed(String),
+}
+
+impl ClipboardError {
+    /// Error for a rejected clipboard promise, from the `DOMException` name
+    fn from_dom_exception(name: &str, message: String) -> Self {
+        match name {
+            "NotAllowedError" | "SecurityError" => ClipboardError::PermissionDenied,
+            _ => ClipboardError::Failed(message),
+        }
+    }
+}
+
+/// Options for [`use_clipboard_with_options`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UseClipboardOptions {
+    /// How long `copied` stays true after a successful copy
+    pub copied_duration: Duration,
+}
+
+impl Default for UseClipboardOptions {
+    fn default() -> Self {
+        Self {
+            copied_duration: Duration::from_millis(1500),
+        }
+    }
+}
+
+/// Return value for the use_clipboard hook
+#[derive(Debug, Clone, Copy)]
+pub struct UseClipboardReturn {
+    /// Whether the asynchronous Clipboard API can be used
+    pub is_supported: Signal<bool>,
+    /// Text last copied or read
+    pub text: Signal<Option<String>>,
+    /// True for a moment after a successful copy, e.g. to show a check mark
+    pub copied: Signal<bool>,
+    /// Error of the last copy or read, cleared by the next attempt
+    pub error: Signal<Option<ClipboardError>>,
+    set_text: RwSignal<Option<String>>,
+    set_copied: RwSignal<bool>,
+    set_error: RwSignal<Option<ClipboardError>>,
+    reset_timer: StoredValue<Option<TimeoutHandle>>,
+    options: UseClipboardOptions,
+}
+
+impl UseClipboardReturn {
+    /// Write text to the clipboard
+    ///
+    /// Uses the Clipboard API where available and falls back to
+    /// `document.execCommand("copy")` when it is missing or refuses, as in
+    /// insecure contexts and older browsers.
+    pub fn copy(&self, text: impl Into<String>) {
+        let this = *self;
+        let text = text.into();
+        this.set_error.set(None);
+        spawn_local(async move {
+            let written = match write_with_clipboard_api(&text).await {
+                Ok(true) => Ok(()),
+                Ok(false) => copy_with_exec_command(&text),
+                Err(error) => copy_with_exec_command(&text).map_err(|_| error),
+            };
+            match written {
+                Ok(()) => {
+                    this.set_text.try_set(Some(text));
+                    this.mark_copied();
+                }
+                Err(error) => {
+                    this.set_error.try_set(Some(error));
+                }
+            }
+        });
+    }
+
+    /// Read text from the clipboard into `text`
+    ///
+    /// Browsers ask the user for permission first; a refusal ends up in
+    /// `error` as [`ClipboardError::PermissionDenied`].
+    pub fn read(&self) {
+        let this = *self;
+        this.set_error.set(None);
+        spawn_local(async move {
+            let Some(clipboard) = clipboard() else {
+                this.set_error.try_set(Some(ClipboardError::Unsupported));
+                return;
+            };
+            match JsFuture::from(clipboard.read_text()).await {
+                Ok(text) => {
+                    this.set_text.try_set(text.as_string());
+                }
+                Err(error) => {
+                    this.set_error.try_set(Some(rejection_error(error)));
+                }
+            }
+        });
+    }
+
+    fn mark_copied(&self) {
+        let copied = self.set_copied;
+        if let Some(Some(timer)) = self.reset_timer.try_update_value(Option::take) {
+            timer.clear();
+        }
+        copied.try_set(true);
+        let timer = set_timeout_with_handle(
+            move || {
+                copied.try_set(false);
+            },
+            self.options.copied_duration,
+        );
+        self.reset_timer.try_set_value(timer.ok());
+    }
+}
+
+/// Hook for copying text to and reading it from the system clipboard
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_core::use_clipboard;
+///
+/// #[component]
+/// pub fn CopyButton(code: String) -> impl IntoView {
+///     let clipboard = use_clipboard();
+///
+///     view! {
+///         <button on:click=move |_| clipboard.copy(code.clone())>
+///             {move || if clipboard.copied.get() { "Copied" } else { "Copy" }}
+///         </button>
+///     }
+/// }
+/// ```
+pub fn use_clipboard() -> UseClipboardReturn {
+    use_clipboard_with_options(UseClipboardOptions::default())
+}
+
+/// Clipboard hook with a custom `copied` duration
+pub fn use_clipboard_with_options(options: UseClipboardOptions) -> UseClipboardReturn {
+    let is_supported = RwSignal::new(false);
+    let set_text = RwSignal::new(None);
+    let set_copied = RwSignal::new(false);
+    let set_error = RwSignal::new(None);
+    let reset_timer = StoredValue::new(None::<TimeoutHandle>);
+
+    // Checked once mounted, so server and hydration renders agree
+    Effect::new(move |_| is_supported.set(clipboard().is_some()));
+    on_cleanup(move || {
+        if let Some(Some(timer)) = reset_timer.try_update_value(Option::take) {
+            timer.clear();
+        }
+    });
+
+    UseClipboardReturn {
+        is_supported: is_supported.into(),
+        text: set_text.into(),
+        copied: set_copied.into(),
+        error: set_error.into(),
+        set_text,
+        set_copied,
+        set_error,
+        reset_timer,
+        options,
+    }
+}
+
+/// The Clipboard API, which only exists in secure contexts
+fn clipboard() -> Option<web_sys::Clipboard> {
+    let window = web_sys::window()?;
+    if !window.is_secure_context() {
+        return None;
+    }
+    let navigator = window.navigator();
+    js_sys::Reflect::get(&navigator, &JsValue::from_str("clipboard"))
+        .ok()
+        .filter(|clipboard| !clipboard.is_undefined())
+        .map(|clipboard| clipboard.unchecked_into())
+}
+
+/// Write with the Clipboard API; `Ok(false)` when it is unavailable
+async fn write_with_clipboard_api(text: &str) -> Result<bool, ClipboardError> {
+    let Some(clipboard) = clipboard() else {
+        return Ok(false);
+    };
+    JsFuture::from(clipboard.write_text(text))
+        .await
+        .map(|_| true)
+        .map_err(rejection_error)
+}
+
+/// Copy through a hidden textarea, for browsers without the Clipboard API
+fn copy_with_exec_command(text: &str) -> Result<(), ClipboardError> {
+    let document = web_sys::window()
+        .and_then(|window| window.document())
+        .ok_or(ClipboardError::Unsupported)?;
+    let body = document.body().ok_or(ClipboardError::Unsupported)?;
+    let textarea: HtmlTextAreaElement = document
+        .create_element("textarea")
+        .map_err(|_| ClipboardError::Unsupported)?
+        .unchecked_into();
+    textarea.set_value(text);
+    let _ = textarea.set_attribute("readonly", "");
+    let _ = textarea.set_attribute("aria-hidden", "true");
+    let _ = textarea.set_attribute(
+        "style",
+        "position: fixed; top: 0; left: -9999px; opacity: 0;",
+    );
+
+    // Selecting moves focus, which is handed back afterwards
+    let previously_focused = document.active_element();
+    let _ = body.append_child(&textarea);
+    textarea.select();
+    let copied = document
+        .unchecked_ref::<HtmlDocument>()
+        .exec_command("copy")
+        .unwrap_or(false);
+    textarea.remove();
+    if let Some(element) =
+        previously_focused.and_then(|e| e.dyn_into::<web_sys::HtmlElement>().ok())
+    {
+        let _ = element.focus();
+    }
+
+    if copied {
+        Ok(())
+    } else {
+        Err(ClipboardError::Failed(
+            "execCommand(\"copy\") was refused".to_string(),
+        ))
+    }
+}
+
+fn rejection_error(error: JsValue) -> ClipboardError {
+    match error.dyn_ref::<js_sys::Error>() {
+        Some(error) => ClipboardError::from_dom_exception(
+            &String::from(error.name()),
+            String::from(error.message()),
+        ),
+        None => ClipboardError::Failed(format!("{:?}", error)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dom_exception_mapping() {
+        assert_eq!(
+            ClipboardError::from_dom_exception("NotAllowedError", "Document is not focused".into()),
+            ClipboardError::PermissionDenied
+        );
+        assert_eq!(
+            ClipboardError::from_dom_exception("DataError", "Bad data".into()),
+            ClipboardError::Failed("Bad data".to_string())
+        );
+        assert_eq!(
+            ClipboardError::PermissionDenied.to_string(),
+            "clipboard access was denied"
+        );
+    }
+}
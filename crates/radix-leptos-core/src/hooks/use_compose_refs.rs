@@ -1,76 +1,99 @@
+use leptos::html::ElementType;
+use leptos::prelude::*;
+use leptos::tachys::html::node_ref::NodeRefContainer;
 use web_sys::Element;
 
-/// Compose multiple refs into a single ref callback
-/// 
+/// Several node refs filled by a single `node_ref` attribute
+///
+/// Created by [`use_compose_refs`] or [`use_compose_refs_with_callback`].
+pub struct ComposedRefs<E>
+where
+    E: ElementType,
+    E::Output: 'static,
+{
+    refs: Vec<NodeRef<E>>,
+    callback: Option<Callback<Element>>,
+}
+
+impl<E> Clone for ComposedRefs<E>
+where
+    E: ElementType,
+    E::Output: 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            refs: self.refs.clone(),
+            callback: self.callback,
+        }
+    }
+}
+
+impl<E> NodeRefContainer<E> for ComposedRefs<E>
+where
+    E: ElementType + 'static,
+    E::Output: wasm_bindgen::JsCast + 'static,
+{
+    fn load(self, el: &Element) {
+        for node_ref in self.refs {
+            node_ref.load(el);
+        }
+        if let Some(callback) = self.callback {
+            callback.run(el.clone());
+        }
+    }
+}
+
+/// Compose multiple refs into a single ref
+///
 /// This is useful when you need to apply multiple refs to the same element,
 /// such as when forwarding a ref while also maintaining an internal ref.
-/// 
+///
 /// # Example
-/// 
-/// ```rust
+///
+/// ```rust,no_run
+/// use leptos::html;
+/// use leptos::prelude::*;
 /// use radix_leptos_core::use_compose_refs;
-/// 
+///
 /// #[component]
 /// pub fn ForwardedButton(
-///     #[prop(optional)] node_ref: Option<NodeRef<web_sys::Element>>,
+///     #[prop(optional)] node_ref: Option<NodeRef<html::Button>>,
 ///     children: Children,
 /// ) -> impl IntoView {
-///     let internal_ref = create_node_ref::<web_sys::Element>();
-///     let composed_ref = use_compose_refs(&[Some(internal_ref), node_ref]);
-///     
+///     let internal_ref = NodeRef::<html::Button>::new();
+///     let composed_ref = use_compose_refs(std::iter::once(internal_ref).chain(node_ref));
+///
 ///     view! {
-///         <button ref=composed_ref>
+///         <button node_ref=composed_ref>
 ///             {children()}
 ///         </button>
 ///     }
 /// }
 /// ```
-pub fn use_compose_refs(refs: &[Option<NodeRef<Element>>]) -> NodeRef<Element> {
-    let composed_ref = create_node_ref::<Element>();
-    
-    // Set up an effect to synchronize all refs when the composed ref changes
-    create_effect(move |_| {
-        if let Some(element) = composed_ref.get() {
-            // Update all provided refs with the current element
-            for ref_option in refs {
-                if let Some(node_ref) = ref_option {
-                    // This is a simplified version - in practice, we'd need to handle
-                    // the ref forwarding more carefully
-                    // For now, this demonstrates the concept
-                }
-            }
-        }
-    });
-    
-    composed_ref
+pub fn use_compose_refs<E>(refs: impl IntoIterator<Item = NodeRef<E>>) -> ComposedRefs<E>
+where
+    E: ElementType,
+    E::Output: 'static,
+{
+    ComposedRefs {
+        refs: refs.into_iter().collect(),
+        callback: None,
+    }
 }
 
 /// Compose refs with a callback function
-/// 
-/// This version allows you to compose refs with a callback function
-/// that gets called whenever the element changes.
-pub fn use_compose_refs_with_callback<F>(
-    refs: &[Option<NodeRef<Element>>],
-    callback: F,
-) -> NodeRef<Element>
+///
+/// This version also calls `callback` with the element once it is mounted.
+pub fn use_compose_refs_with_callback<E>(
+    refs: impl IntoIterator<Item = NodeRef<E>>,
+    callback: impl Fn(Element) + Send + Sync + 'static,
+) -> ComposedRefs<E>
 where
-    F: Fn(Option<Element>) + 'static,
+    E: ElementType,
+    E::Output: 'static,
 {
-    let composed_ref = create_node_ref::<Element>();
-    
-    create_effect(move |_| {
-        let element = composed_ref.get();
-        callback(element.clone());
-        
-        // Forward to other refs
-        if let Some(ref element) = element {
-            for ref_option in refs {
-                if let Some(_node_ref) = ref_option {
-                    // Forward ref - implementation would depend on Leptos internals
-                }
-            }
-        }
-    });
-    
-    composed_ref
-}
\ No newline at end of file
+    ComposedRefs {
+        refs: refs.into_iter().collect(),
+        callback: Some(Callback::new(callback)),
+    }
+}
@@ -0,0 +1,330 @@
+use std::time::Duration;
+
+use leptos::prelude::*;
+
+type LimiterSlot<A> = StoredValue<RateLimiter<A>, LocalStorage>;
+
+/// Options for [`use_debounce_with_options`] and [`use_debounce_fn`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UseDebounceOptions {
+    /// Call at the start of a burst of calls
+    pub leading: bool,
+    /// Call with the last arguments once calls have stopped for the delay
+    pub trailing: bool,
+}
+
+impl Default for UseDebounceOptions {
+    fn default() -> Self {
+        Self {
+            leading: false,
+            trailing: true,
+        }
+    }
+}
+
+/// Hook for a copy of a signal that only follows it once it settles
+///
+/// The returned signal takes the source's value after the source has not
+/// changed for `delay`, e.g. to search once the user stops typing.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+///
+/// use leptos::prelude::*;
+/// use radix_leptos_core::use_debounce;
+///
+/// #[component]
+/// pub fn SearchBox() -> impl IntoView {
+///     let query = RwSignal::new(String::new());
+///     let debounced_query = use_debounce::<String>(query, Duration::from_millis(300));
+///
+///     view! {
+///         <input on:input=move |ev| query.set(event_target_value(&ev)) />
+///         <p>"Results for " {debounced_query}</p>
+///     }
+/// }
+/// ```
+pub fn use_debounce<T>(value: impl Into<Signal<T>>, delay: Duration) -> Signal<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    use_debounce_with_options(value, delay, UseDebounceOptions::default())
+}
+
+/// Debounced signal hook with leading and trailing options
+pub fn use_debounce_with_options<T>(
+    value: impl Into<Signal<T>>,
+    delay: Duration,
+    options: UseDebounceOptions,
+) -> Signal<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    let value = value.into();
+    let debounced = RwSignal::new(value.get_untracked());
+    let update = use_debounce_fn(move |new_value: T| debounced.set(new_value), delay, options);
+    follow_changes(value, update);
+    debounced.into()
+}
+
+/// Hook for a debounced version of a callback
+///
+/// Calls are delayed until none have been made for `delay`, then the
+/// callback runs once with the latest arguments, e.g. for autosave or
+/// loading Combobox options as the user types.
+pub fn use_debounce_fn<A: 'static>(
+    callback: impl Fn(A) + Send + Sync + 'static,
+    delay: Duration,
+    options: UseDebounceOptions,
+) -> RateLimitedCallback<A> {
+    RateLimitedCallback::new(
+        callback,
+        delay,
+        RateLimiter::new(RateLimit::Debounce, options.leading, options.trailing),
+    )
+}
+
+/// Feed every change of `value` after the current one to `update`
+pub(crate) fn follow_changes<T>(value: Signal<T>, update: RateLimitedCallback<T>)
+where
+    T: Clone + Send + Sync + 'static,
+{
+    Effect::new(move |initialized: Option<()>| {
+        let new_value = value.get();
+        if initialized.is_some() {
+            update.run(new_value);
+        }
+    });
+}
+
+/// A callback whose calls are debounced or throttled
+///
+/// Returned by [`use_debounce_fn`] and
+/// [`use_throttle_fn`](super::use_throttle::use_throttle_fn). Pending calls
+/// are dropped when the owning component is cleaned up.
+pub struct RateLimitedCallback<A: 'static> {
+    callback: Callback<A>,
+    limiter: LimiterSlot<A>,
+    timer: StoredValue<Option<TimeoutHandle>>,
+    delay: Duration,
+    pending: RwSignal<bool>,
+}
+
+impl<A: 'static> Clone for RateLimitedCallback<A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<A: 'static> Copy for RateLimitedCallback<A> {}
+
+impl<A: 'static> RateLimitedCallback<A> {
+    pub(crate) fn new(
+        callback: impl Fn(A) + Send + Sync + 'static,
+        delay: Duration,
+        limiter: RateLimiter<A>,
+    ) -> Self {
+        let rate_limited = Self {
+            callback: Callback::new(callback),
+            limiter: StoredValue::new_local(limiter),
+            timer: StoredValue::new(None),
+            delay,
+            pending: RwSignal::new(false),
+        };
+        on_cleanup(move || rate_limited.clear_timer());
+        rate_limited
+    }
+
+    /// Call the callback now, later or not at all, as the rate limit allows
+    pub fn run(&self, args: A) {
+        if let Some(step) = self.limiter.try_update_value(|limiter| limiter.call(args)) {
+            self.apply(step);
+        }
+    }
+
+    /// Drop a pending trailing call
+    pub fn cancel(&self) {
+        self.clear_timer();
+        self.limiter.try_update_value(RateLimiter::reset);
+        self.pending.try_set(false);
+    }
+
+    /// Whether a trailing call is waiting for the delay to pass
+    pub fn is_pending(&self) -> Signal<bool> {
+        self.pending.into()
+    }
+
+    fn apply(&self, step: Step<A>) {
+        if step.restart_timer {
+            self.restart_timer();
+        } else if !step.window_open {
+            self.clear_timer();
+        }
+        let pending = self
+            .limiter
+            .try_with_value(RateLimiter::has_pending)
+            .unwrap_or(false);
+        self.pending.try_set(pending);
+        if let Some(args) = step.fire {
+            self.callback.try_run(args);
+        }
+    }
+
+    fn restart_timer(&self) {
+        self.clear_timer();
+        let this = *self;
+        let timer = set_timeout_with_handle(
+            move || {
+                this.timer.try_set_value(None);
+                if let Some(step) = this.limiter.try_update_value(RateLimiter::elapse) {
+                    this.apply(step);
+                }
+            },
+            self.delay,
+        );
+        self.timer.try_set_value(timer.ok());
+    }
+
+    fn clear_timer(&self) {
+        if let Some(Some(timer)) = self.timer.try_update_value(Option::take) {
+            timer.clear();
+        }
+    }
+}
+
+/// How calls within the delay are limited
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RateLimit {
+    /// Every call restarts the delay
+    Debounce,
+    /// The delay runs from the first call of a window
+    Throttle,
+}
+
+/// What to do after a call or after the delay passed
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct Step<A> {
+    pub fire: Option<A>,
+    pub restart_timer: bool,
+    pub window_open: bool,
+}
+
+/// Timing rules shared by debouncing and throttling, without the timers
+#[derive(Debug)]
+pub(crate) struct RateLimiter<A> {
+    kind: RateLimit,
+    leading: bool,
+    trailing: bool,
+    pending: Option<A>,
+    window_open: bool,
+}
+
+impl<A> RateLimiter<A> {
+    pub fn new(kind: RateLimit, leading: bool, trailing: bool) -> Self {
+        Self {
+            kind,
+            leading,
+            trailing,
+            pending: None,
+            window_open: false,
+        }
+    }
+
+    /// A call was made
+    pub fn call(&mut self, args: A) -> Step<A> {
+        let opens_window = !self.window_open;
+        self.window_open = true;
+        let fire = if opens_window && self.leading {
+            Some(args)
+        } else {
+            if self.trailing {
+                self.pending = Some(args);
+            }
+            None
+        };
+        Step {
+            fire,
+            restart_timer: opens_window || self.kind == RateLimit::Debounce,
+            window_open: true,
+        }
+    }
+
+    /// The delay passed since the timer was last (re)started
+    pub fn elapse(&mut self) -> Step<A> {
+        match self.pending.take() {
+            // A throttled trailing call starts the next window
+            Some(args) if self.kind == RateLimit::Throttle => Step {
+                fire: Some(args),
+                restart_timer: true,
+                window_open: true,
+            },
+            fire => {
+                self.window_open = false;
+                Step {
+                    fire,
+                    restart_timer: false,
+                    window_open: false,
+                }
+            }
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.pending = None;
+        self.window_open = false;
+    }
+
+    pub fn has_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fired<A>(step: Step<A>) -> Option<A> {
+        step.fire
+    }
+
+    #[test]
+    fn test_debounce_trailing() {
+        let mut limiter = RateLimiter::new(RateLimit::Debounce, false, true);
+        let first = limiter.call("a");
+        assert_eq!(first.fire, None);
+        assert!(first.restart_timer);
+        // Every call pushes the deadline back and replaces the arguments
+        assert!(limiter.call("ab").restart_timer);
+        assert!(limiter.has_pending());
+        assert_eq!(fired(limiter.elapse()), Some("ab"));
+        assert!(!limiter.has_pending());
+        assert_eq!(fired(limiter.elapse()), None);
+    }
+
+    #[test]
+    fn test_debounce_leading() {
+        let mut limiter = RateLimiter::new(RateLimit::Debounce, true, false);
+        assert_eq!(fired(limiter.call(1)), Some(1));
+        assert_eq!(fired(limiter.call(2)), None);
+        assert_eq!(fired(limiter.elapse()), None);
+        // The next burst fires right away again
+        assert_eq!(fired(limiter.call(3)), Some(3));
+
+        let mut both = RateLimiter::new(RateLimit::Debounce, true, true);
+        assert_eq!(fired(both.call(1)), Some(1));
+        assert_eq!(fired(both.elapse()), None);
+        assert_eq!(fired(both.call(2)), Some(2));
+        assert_eq!(fired(both.call(3)), None);
+        assert_eq!(fired(both.elapse()), Some(3));
+    }
+
+    #[test]
+    fn test_cancel_drops_pending_call() {
+        let mut limiter = RateLimiter::new(RateLimit::Debounce, false, true);
+        limiter.call(1);
+        limiter.reset();
+        assert_eq!(fired(limiter.elapse()), None);
+    }
+}
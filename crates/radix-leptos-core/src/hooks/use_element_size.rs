@@ -0,0 +1,156 @@
+use leptos::html::ElementType;
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::Element;
+
+use super::use_resize_observer::{
+    use_resize_observer_with_options, ResizeObserverBox, UseResizeObserverOptions,
+};
+
+type ElementSignal = Signal<Option<Element>, LocalStorage>;
+
+/// Options for [`use_element_size_with_options`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct UseElementSizeOptions {
+    /// Box that `width` and `height` describe
+    pub observed_box: ResizeObserverBox,
+}
+
+/// Return value for the use_element_size hook
+#[derive(Debug, Clone, Copy)]
+pub struct UseElementSizeReturn {
+    /// Width of the observed box in CSS pixels
+    pub width: Signal<f64>,
+    /// Height of the observed box in CSS pixels
+    pub height: Signal<f64>,
+    /// Width of the element's content including the part scrolled out of view
+    pub scroll_width: Signal<f64>,
+    /// Height of the element's content including the part scrolled out of view
+    pub scroll_height: Signal<f64>,
+    target: ElementSignal,
+    set_scroll_width: RwSignal<f64>,
+    set_scroll_height: RwSignal<f64>,
+}
+
+impl UseElementSizeReturn {
+    /// Re-read the scroll size
+    ///
+    /// The scroll size is updated whenever the element resizes; call this
+    /// after changing content that may overflow without resizing the element,
+    /// e.g. when items were added to a fixed-height list.
+    pub fn measure(&self) {
+        if let Some(element) = self.target.get_untracked() {
+            self.set_scroll_width.set(f64::from(element.scroll_width()));
+            self.set_scroll_height
+                .set(f64::from(element.scroll_height()));
+        }
+    }
+}
+
+/// Hook for the reactive dimensions of an element
+///
+/// Reports the content-box size of the element behind `target`, like
+/// [`use_resize_observer`](super::use_resize_observer::use_resize_observer),
+/// together with its scroll size. All values are 0 until the element has
+/// been measured.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::html;
+/// use leptos::prelude::*;
+/// use radix_leptos_core::use_element_size;
+///
+/// #[component]
+/// pub fn Transcript() -> impl IntoView {
+///     let log_ref = NodeRef::<html::Div>::new();
+///     let size = use_element_size(log_ref);
+///     let overflowing = move || size.scroll_height.get() > size.height.get();
+///
+///     view! {
+///         <div node_ref=log_ref class="log" data-overflowing=move || overflowing().to_string()>
+///             "…"
+///         </div>
+///     }
+/// }
+/// ```
+pub fn use_element_size<E>(target: NodeRef<E>) -> UseElementSizeReturn
+where
+    E: ElementType + 'static,
+    E::Output: JsCast + Clone + 'static,
+{
+    use_element_size_with_options(target, UseElementSizeOptions::default())
+}
+
+/// Element size hook that can measure the border box instead
+pub fn use_element_size_with_options<E>(
+    target: NodeRef<E>,
+    options: UseElementSizeOptions,
+) -> UseElementSizeReturn
+where
+    E: ElementType + 'static,
+    E::Output: JsCast + Clone + 'static,
+{
+    let size = use_resize_observer_with_options(
+        target,
+        UseResizeObserverOptions {
+            observed_box: options.observed_box,
+            ..Default::default()
+        },
+    );
+    let scroll_width = RwSignal::new(0.0);
+    let scroll_height = RwSignal::new(0.0);
+    let element = Signal::derive_local(move || {
+        target
+            .get()
+            .map(|element| element.unchecked_into::<Element>())
+    });
+
+    let element_size = UseElementSizeReturn {
+        width: size.width,
+        height: size.height,
+        scroll_width: scroll_width.into(),
+        scroll_height: scroll_height.into(),
+        target: element,
+        set_scroll_width: scroll_width,
+        set_scroll_height: scroll_height,
+    };
+
+    Effect::new(move |_| {
+        // Subscribes to resizes; the values themselves aren't needed
+        size.width.track();
+        size.height.track();
+        element.track();
+        element_size.measure();
+    });
+
+    element_size
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptos::html;
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    async fn test_measures_scroll_size() {
+        let size = RwSignal::new(None);
+        mount_to_body(move || {
+            let list_ref = NodeRef::<html::Div>::new();
+            size.set(Some(use_element_size(list_ref)));
+            view! {
+                <div node_ref=list_ref style="width: 100px; height: 50px; overflow: auto;">
+                    <div style="width: 100px; height: 200px;"></div>
+                </div>
+            }
+        });
+        gloo_timers::future::TimeoutFuture::new(50).await;
+
+        let size = size.get_untracked().unwrap();
+        assert_eq!(size.height.get_untracked(), 50.0);
+        assert_eq!(size.scroll_height.get_untracked(), 200.0);
+    }
+}
@@ -0,0 +1,142 @@
+use leptos::prelude::*;
+use std::rc::Rc;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+use web_sys::{Event, EventTarget};
+
+type ListenerCallback = Closure<dyn Fn(Event)>;
+type ListenerSlot = StoredValue<Option<Listener>, LocalStorage>;
+
+/// An attached DOM event listener, kept alive until it is removed
+struct Listener {
+    target: EventTarget,
+    event: &'static str,
+    callback: ListenerCallback,
+}
+
+impl Listener {
+    fn detach(self) {
+        let _ = self.target.remove_event_listener_with_callback(
+            self.event,
+            self.callback.as_ref().unchecked_ref(),
+        );
+    }
+}
+
+/// Handle to a listener registered with [`use_event_listener`]
+///
+/// The listener is removed automatically when the owning component is
+/// cleaned up; [`EventListenerHandle::remove`] detaches it early.
+#[derive(Clone, Copy)]
+pub struct EventListenerHandle {
+    listener: ListenerSlot,
+}
+
+impl EventListenerHandle {
+    fn new() -> Self {
+        Self {
+            listener: StoredValue::new_local(None),
+        }
+    }
+
+    /// Detach the listener; it stays detached even if the target changes
+    pub fn remove(&self) {
+        self.detach();
+        self.listener.dispose();
+    }
+
+    /// Whether the listener is currently attached to a target
+    pub fn is_active(&self) -> bool {
+        self.listener
+            .try_with_value(Option::is_some)
+            .unwrap_or(false)
+    }
+
+    fn detach(&self) {
+        if let Some(Some(listener)) = self.listener.try_update_value(Option::take) {
+            listener.detach();
+        }
+    }
+
+    fn attach(&self, target: EventTarget, event: &'static str, callback: ListenerCallback) {
+        if target
+            .add_event_listener_with_callback(event, callback.as_ref().unchecked_ref())
+            .is_ok()
+        {
+            self.listener.try_set_value(Some(Listener {
+                target,
+                event,
+                callback,
+            }));
+        }
+    }
+}
+
+/// Hook for listening to DOM events on a reactive target
+///
+/// `target` is re-evaluated whenever the signals it reads change (for example
+/// a `NodeRef` being mounted); the listener moves to the new target and is
+/// detached while the target is `None`. The event is passed to `handler` cast
+/// to `E`, so only use an event type that matches `event`.
+///
+/// Listeners are attached in an effect, so nothing is registered during
+/// server-side rendering.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_core::use_event_listener;
+///
+/// #[component]
+/// pub fn ResizeLogger() -> impl IntoView {
+///     let width = RwSignal::new(0.0);
+///
+///     use_event_listener(
+///         || Some(window().into()),
+///         "resize",
+///         move |_: web_sys::Event| {
+///             width.set(window().inner_width().ok().and_then(|w| w.as_f64()).unwrap_or(0.0));
+///         },
+///     );
+///
+///     view! { <p>"Width: " {width}</p> }
+/// }
+/// ```
+pub fn use_event_listener<T, E, F>(
+    target: T,
+    event: &'static str,
+    handler: F,
+) -> EventListenerHandle
+where
+    T: Fn() -> Option<EventTarget> + 'static,
+    E: JsCast + 'static,
+    F: Fn(E) + 'static,
+{
+    let handle = EventListenerHandle::new();
+    let handler = Rc::new(handler);
+
+    Effect::new(move |_| {
+        let target = target();
+        if handle.listener.is_disposed() {
+            return;
+        }
+        handle.detach();
+        if let Some(target) = target {
+            let handler = Rc::clone(&handler);
+            let callback =
+                Closure::<dyn Fn(Event)>::new(move |event: Event| handler(event.unchecked_into()));
+            handle.attach(target, event, callback);
+        }
+    });
+    on_cleanup(move || handle.detach());
+
+    handle
+}
+
+/// Target getter for the current document, for use with [`use_event_listener`]
+pub fn document_target() -> Option<EventTarget> {
+    web_sys::window()
+        .and_then(|window| window.document())
+        .map(Into::into)
+}
@@ -0,0 +1,195 @@
+use std::cell::RefCell;
+
+use leptos::html::ElementType;
+use leptos::prelude::*;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+use web_sys::{Event, FocusEvent, KeyboardEvent};
+
+use super::use_event_listener::use_event_listener;
+
+/// Attribute set on focused components while focus should be shown
+pub const FOCUS_VISIBLE_ATTRIBUTE: &str = "data-focus-visible";
+
+/// How the user last interacted with the page
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputModality {
+    Keyboard,
+    Pointer,
+}
+
+impl InputModality {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InputModality::Keyboard => "keyboard",
+            InputModality::Pointer => "pointer",
+        }
+    }
+}
+
+/// Whether a key press moves the user to keyboard modality
+///
+/// Shortcuts and bare modifier presses don't: pressing Cmd+C after clicking
+/// a button should not bring up its focus ring.
+pub fn is_modality_key(key: &str, ctrl: bool, meta: bool, alt: bool) -> bool {
+    !(ctrl || meta || alt || matches!(key, "Control" | "Meta" | "Alt" | "Shift" | "OS"))
+}
+
+type ModalityListener = Closure<dyn Fn(Event)>;
+
+struct ModalityState {
+    modality: ArcRwSignal<InputModality>,
+    listeners: Vec<ModalityListener>,
+}
+
+impl Default for ModalityState {
+    // Until the first interaction focus is shown, as browsers do for
+    // autofocused elements
+    fn default() -> Self {
+        Self {
+            modality: ArcRwSignal::new(InputModality::Keyboard),
+            listeners: Vec::new(),
+        }
+    }
+}
+
+thread_local! {
+    static MODALITY: RefCell<ModalityState> = RefCell::default();
+}
+
+/// Listen for keyboard and pointer input on the document, once per page
+///
+/// Capture phase, so components that stop propagation still count.
+fn ensure_modality_listeners() {
+    MODALITY.with_borrow_mut(|state| {
+        if !state.listeners.is_empty() {
+            return;
+        }
+        let Some(document) = web_sys::window().and_then(|window| window.document()) else {
+            return;
+        };
+
+        let modality = state.modality.clone();
+        let keydown = Closure::<dyn Fn(Event)>::new(move |event: Event| {
+            let event: KeyboardEvent = event.unchecked_into();
+            if is_modality_key(
+                &event.key(),
+                event.ctrl_key(),
+                event.meta_key(),
+                event.alt_key(),
+            ) {
+                modality.set(InputModality::Keyboard);
+            }
+        });
+        let modality = state.modality.clone();
+        let pointerdown = Closure::<dyn Fn(Event)>::new(move |_: Event| {
+            modality.set(InputModality::Pointer);
+        });
+
+        for (event, listener) in [("keydown", keydown), ("pointerdown", pointerdown)] {
+            if document
+                .add_event_listener_with_callback_and_bool(
+                    event,
+                    listener.as_ref().unchecked_ref(),
+                    true,
+                )
+                .is_ok()
+            {
+                state.listeners.push(listener);
+            }
+        }
+    });
+}
+
+/// How the user last interacted with the page, for one-off checks in event
+/// handlers
+pub fn current_input_modality() -> InputModality {
+    MODALITY.with_borrow(|state| state.modality.get_untracked())
+}
+
+/// Hook for the input modality the user is currently on
+///
+/// Switches to [`InputModality::Pointer`] on any mouse, touch or pen press
+/// and back to [`InputModality::Keyboard`] on any key press that is not a
+/// shortcut. One set of document listeners is shared by every caller.
+pub fn use_input_modality() -> Signal<InputModality> {
+    Effect::new(move |_| ensure_modality_listeners());
+    let modality = MODALITY.with_borrow(|state| state.modality.clone());
+    Signal::derive(move || modality.get())
+}
+
+/// Hook for whether the element behind `target` should show a focus ring
+///
+/// True while the element has focus and the user is on the keyboard, like
+/// the `:focus-visible` selector, but the same in every browser and also
+/// for elements that are focusable through `tabindex`. Focus moved by a
+/// component after a key press, such as roving focus, counts as keyboard
+/// focus; pressing a key while a clicked element is focused brings its ring
+/// up.
+///
+/// Components put the result on [`FOCUS_VISIBLE_ATTRIBUTE`], so one
+/// `[data-focus-visible]` rule styles focus rings across the library.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::html;
+/// use leptos::prelude::*;
+/// use radix_leptos_core::use_focus_visible;
+///
+/// #[component]
+/// pub fn Chip() -> impl IntoView {
+///     let chip_ref = NodeRef::<html::Span>::new();
+///     let focus_visible = use_focus_visible(chip_ref);
+///
+///     view! {
+///         <span
+///             node_ref=chip_ref
+///             tabindex="0"
+///             data-focus-visible=move || focus_visible.get().then_some("")
+///         >
+///             "Rust"
+///         </span>
+///     }
+/// }
+/// ```
+pub fn use_focus_visible<E>(target: NodeRef<E>) -> Signal<bool>
+where
+    E: ElementType + 'static,
+    E::Output: JsCast + Clone + 'static,
+{
+    let modality = use_input_modality();
+    let focused = RwSignal::new(false);
+    let element = move || {
+        target
+            .get()
+            .map(|element| element.unchecked_into::<web_sys::EventTarget>())
+    };
+
+    use_event_listener(element, "focus", move |_: FocusEvent| focused.set(true));
+    use_event_listener(element, "blur", move |_: FocusEvent| focused.set(false));
+
+    Signal::derive(move || focused.get() && modality.get() == InputModality::Keyboard)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_modality_key() {
+        assert!(is_modality_key("Tab", false, false, false));
+        assert!(is_modality_key("ArrowDown", false, false, false));
+        assert!(is_modality_key(" ", false, false, false));
+        assert!(!is_modality_key("c", false, true, false));
+        assert!(!is_modality_key("c", true, false, false));
+        assert!(!is_modality_key("Shift", false, false, false));
+        assert!(!is_modality_key("Meta", false, false, false));
+    }
+
+    #[test]
+    fn test_input_modality_as_str() {
+        assert_eq!(InputModality::Keyboard.as_str(), "keyboard");
+        assert_eq!(InputModality::Pointer.as_str(), "pointer");
+    }
+}
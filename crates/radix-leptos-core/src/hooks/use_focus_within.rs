@@ -0,0 +1,142 @@
+use std::time::Duration;
+
+use leptos::html::ElementType;
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, FocusEvent, Node};
+
+use super::use_event_listener::use_event_listener;
+
+/// Hook for whether focus is inside an element
+///
+/// True while the element behind `target` or one of its descendants has
+/// focus, like the `:focus-within` selector. Losing focus is confirmed a
+/// tick later, so moving focus between two children, or re-rendering the
+/// focused child, does not make the value flicker to false.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::html;
+/// use leptos::prelude::*;
+/// use radix_leptos_core::use_focus_within;
+///
+/// #[component]
+/// pub fn AddressFields() -> impl IntoView {
+///     let group_ref = NodeRef::<html::Fieldset>::new();
+///     let focused = use_focus_within(group_ref);
+///
+///     view! {
+///         <fieldset node_ref=group_ref class:fieldset--active=move || focused.get()>
+///             <input name="street" />
+///             <input name="city" />
+///         </fieldset>
+///     }
+/// }
+/// ```
+pub fn use_focus_within<E>(target: NodeRef<E>) -> Signal<bool>
+where
+    E: ElementType + 'static,
+    E::Output: JsCast + Clone + 'static,
+{
+    let focused = RwSignal::new(false);
+    let pending_blur = StoredValue::new(None::<TimeoutHandle>);
+    let element = move || {
+        target
+            .get()
+            .map(|element| element.unchecked_into::<Element>())
+    };
+
+    let cancel_blur = move || {
+        if let Some(Some(timer)) = pending_blur.try_update_value(Option::take) {
+            timer.clear();
+        }
+    };
+    on_cleanup(cancel_blur);
+
+    use_event_listener(
+        move || element().map(Into::into),
+        "focusin",
+        move |_: FocusEvent| {
+            cancel_blur();
+            focused.set(true);
+        },
+    );
+
+    use_event_listener(
+        move || element().map(Into::into),
+        "focusout",
+        move |event: FocusEvent| {
+            let Some(container) = target
+                .get_untracked()
+                .map(|element| element.unchecked_into::<Node>())
+            else {
+                return;
+            };
+            let next = event
+                .related_target()
+                .and_then(|target| target.dyn_into::<Node>().ok());
+            if next.is_some_and(|next| container.contains(Some(&next))) {
+                return;
+            }
+
+            cancel_blur();
+            let timer = set_timeout_with_handle(
+                move || {
+                    pending_blur.try_set_value(None);
+                    let active = web_sys::window()
+                        .and_then(|window| window.document())
+                        .and_then(|document| document.active_element())
+                        .map(Node::from);
+                    focused.try_set(container.contains(active.as_ref()));
+                },
+                Duration::ZERO,
+            );
+            pending_blur.set_value(timer.ok());
+        },
+    );
+
+    focused.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptos::html;
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    async fn test_tracks_focus_inside() {
+        let focused = RwSignal::new(None);
+        let first_ref = NodeRef::<html::Input>::new();
+        let second_ref = NodeRef::<html::Input>::new();
+        let outside_ref = NodeRef::<html::Button>::new();
+        mount_to_body(move || {
+            let group_ref = NodeRef::<html::Div>::new();
+            focused.set(Some(use_focus_within(group_ref)));
+            view! {
+                <div node_ref=group_ref>
+                    <input node_ref=first_ref />
+                    <input node_ref=second_ref />
+                </div>
+                <button node_ref=outside_ref>"Outside"</button>
+            }
+        });
+        let focused = focused.get_untracked().unwrap();
+        let settle = || gloo_timers::future::TimeoutFuture::new(10);
+
+        first_ref.get_untracked().unwrap().focus().unwrap();
+        settle().await;
+        assert!(focused.get_untracked());
+
+        second_ref.get_untracked().unwrap().focus().unwrap();
+        settle().await;
+        assert!(focused.get_untracked());
+
+        outside_ref.get_untracked().unwrap().focus().unwrap();
+        settle().await;
+        assert!(!focused.get_untracked());
+    }
+}
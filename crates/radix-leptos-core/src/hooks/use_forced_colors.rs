@@ -0,0 +1,49 @@
+use leptos::prelude::*;
+
+use super::use_media_query::use_media_query;
+
+/// Media query matching while the system forces a limited color palette,
+/// e.g. Windows high contrast themes
+pub const FORCED_COLORS_QUERY: &str = "(forced-colors: active)";
+
+/// Hook for whether forced colors are active
+///
+/// In forced colors mode the browser replaces author colors with a few
+/// system colors and drops background colors, so anything told apart by
+/// its background alone disappears. Components that rely on backgrounds
+/// switch to system colors such as `Highlight` and `ButtonText` and to
+/// visible borders while this is true. Like [`use_media_query`] it is
+/// `false` on the server and until mounted.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_core::use_forced_colors;
+///
+/// #[component]
+/// pub fn StatusDot() -> impl IntoView {
+///     let forced_colors = use_forced_colors();
+///
+///     view! {
+///         <span
+///             class="status-dot"
+///             style=move || forced_colors.get().then_some("border: 2px solid CanvasText;")
+///             role="img"
+///             aria-label="Online"
+///         />
+///     }
+/// }
+/// ```
+pub fn use_forced_colors() -> Signal<bool> {
+    use_media_query(FORCED_COLORS_QUERY.to_string())
+}
+
+/// Whether forced colors are active right now, for one-off checks such as
+/// drawing on a canvas
+pub fn forced_colors_active() -> bool {
+    web_sys::window()
+        .and_then(|window| window.match_media(FORCED_COLORS_QUERY).ok())
+        .flatten()
+        .is_some_and(|query| query.matches())
+}
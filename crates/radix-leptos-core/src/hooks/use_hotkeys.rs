@@ -0,0 +1,582 @@
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::fmt;
+
+use leptos::prelude::*;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, KeyboardEvent};
+
+/// Scope of shortcuts registered without one
+pub const GLOBAL_HOTKEY_SCOPE: &str = "global";
+
+type ListenerCallback = Closure<dyn Fn(KeyboardEvent)>;
+
+/// Identifies a shortcut in the [`HotkeyRegistry`]
+pub type HotkeyId = u64;
+
+/// Why a shortcut could not be registered
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum HotkeyError {
+    #[error("empty shortcut")]
+    Empty,
+    #[error("unknown modifier `{0}`")]
+    UnknownModifier(String),
+    #[error("`{hotkey}` is already bound in scope `{scope}`")]
+    Conflict { hotkey: String, scope: String },
+}
+
+/// A key combination such as `ctrl+shift+p`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Hotkey {
+    pub ctrl: bool,
+    pub alt: bool,
+    pub shift: bool,
+    pub meta: bool,
+    /// Lowercase `KeyboardEvent.key`, e.g. `k`, `?` or `escape`
+    pub key: String,
+}
+
+impl Hotkey {
+    /// Parse a shortcut like `"mod+k"` or `"shift+?"`
+    ///
+    /// Parts are separated by `+` and are case-insensitive. `mod` is Cmd on
+    /// Apple platforms and Ctrl elsewhere; `esc`, `space`, `plus` and the
+    /// arrow names without the `arrow` prefix are accepted as aliases.
+    pub fn parse(spec: &str) -> Result<Self, HotkeyError> {
+        Self::parse_for_platform(spec, is_apple_platform())
+    }
+
+    /// Parse a shortcut, resolving `mod` for an Apple platform or not
+    pub fn parse_for_platform(spec: &str, apple: bool) -> Result<Self, HotkeyError> {
+        let spec = spec.trim().to_lowercase();
+        // A trailing "+" is the plus key itself, as in "ctrl++"
+        let (modifiers, key) = match spec.strip_suffix("++") {
+            Some(modifiers) => (modifiers, "+"),
+            None if spec == "+" => ("", "+"),
+            None => spec.rsplit_once('+').unwrap_or(("", spec.as_str())),
+        };
+        let key = match key.trim() {
+            "" => return Err(HotkeyError::Empty),
+            "esc" => "escape",
+            "space" => " ",
+            "plus" => "+",
+            "up" => "arrowup",
+            "down" => "arrowdown",
+            "left" => "arrowleft",
+            "right" => "arrowright",
+            key => key,
+        };
+
+        let mut hotkey = Hotkey {
+            key: key.to_string(),
+            ..Default::default()
+        };
+        for modifier in modifiers
+            .split('+')
+            .map(str::trim)
+            .filter(|m| !m.is_empty())
+        {
+            match modifier {
+                "ctrl" | "control" => hotkey.ctrl = true,
+                "alt" | "option" => hotkey.alt = true,
+                "shift" => hotkey.shift = true,
+                "meta" | "cmd" | "command" => hotkey.meta = true,
+                "mod" if apple => hotkey.meta = true,
+                "mod" => hotkey.ctrl = true,
+                other => return Err(HotkeyError::UnknownModifier(other.to_string())),
+            }
+        }
+        Ok(hotkey)
+    }
+
+    /// Whether a key press triggers this shortcut
+    ///
+    /// Punctuation often needs Shift to be typed at all (`?` on a US
+    /// layout), so Shift only has to match for letters, digits and named keys.
+    pub fn matches(&self, press: &KeyPress) -> bool {
+        let mut chars = self.key.chars();
+        let shift_matters = match (chars.next(), chars.next()) {
+            (Some(c), None) => c.is_alphanumeric(),
+            _ => true,
+        };
+        press.key.to_lowercase() == self.key
+            && press.ctrl == self.ctrl
+            && press.alt == self.alt
+            && press.meta == self.meta
+            && (!shift_matters || press.shift == self.shift)
+    }
+
+    /// Labels of the keys to hold, modifiers first, for display with one
+    /// `<kbd>` each
+    pub fn key_labels(&self) -> Vec<String> {
+        self.key_labels_for_platform(is_apple_platform())
+    }
+
+    /// Key labels using the Apple modifier symbols or not
+    pub fn key_labels_for_platform(&self, apple: bool) -> Vec<String> {
+        let modifiers = [
+            (self.ctrl, "⌃", "Ctrl"),
+            (self.alt, "⌥", "Alt"),
+            (self.shift, "⇧", "Shift"),
+            (self.meta, "⌘", "Meta"),
+        ];
+        let mut labels = modifiers
+            .iter()
+            .filter(|(held, _, _)| *held)
+            .map(|(_, symbol, name)| if apple { *symbol } else { *name }.to_string())
+            .collect::<Vec<_>>();
+        let key = match self.key.as_str() {
+            " " => "Space".to_string(),
+            "escape" => "Esc".to_string(),
+            "arrowup" => "↑".to_string(),
+            "arrowdown" => "↓".to_string(),
+            "arrowleft" => "←".to_string(),
+            "arrowright" => "→".to_string(),
+            key => {
+                let mut chars = key.chars();
+                chars
+                    .next()
+                    .map(|first| first.to_uppercase().chain(chars).collect())
+                    .unwrap_or_default()
+            }
+        };
+        labels.push(key);
+        labels
+    }
+}
+
+impl fmt::Display for Hotkey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let modifiers = [
+            (self.ctrl, "ctrl+"),
+            (self.alt, "alt+"),
+            (self.shift, "shift+"),
+            (self.meta, "meta+"),
+        ];
+        for (_, name) in modifiers.iter().filter(|(held, _)| *held) {
+            f.write_str(name)?;
+        }
+        match self.key.as_str() {
+            " " => f.write_str("space"),
+            key => f.write_str(key),
+        }
+    }
+}
+
+/// The parts of a keydown that shortcuts are matched against
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct KeyPress {
+    pub key: String,
+    pub ctrl: bool,
+    pub alt: bool,
+    pub shift: bool,
+    pub meta: bool,
+}
+
+impl From<&KeyboardEvent> for KeyPress {
+    fn from(event: &KeyboardEvent) -> Self {
+        Self {
+            key: event.key(),
+            ctrl: event.ctrl_key(),
+            alt: event.alt_key(),
+            shift: event.shift_key(),
+            meta: event.meta_key(),
+        }
+    }
+}
+
+/// A registered shortcut
+#[derive(Debug, Clone)]
+pub struct HotkeyBinding {
+    pub id: HotkeyId,
+    pub scope: String,
+    pub hotkey: Hotkey,
+    pub handler: Callback<KeyboardEvent>,
+    pub prevent_default: bool,
+    pub enable_on_form_fields: bool,
+    pub description: Option<String>,
+}
+
+/// Shortcuts of an application, grouped in scopes that can be turned off
+///
+/// Within a scope a key combination can only be bound once. Across scopes
+/// the most recently registered binding wins, so a dialog's shortcuts shadow
+/// the page's while it is open.
+#[derive(Debug, Default)]
+pub struct HotkeyRegistry {
+    bindings: Vec<HotkeyBinding>,
+    disabled_scopes: HashSet<String>,
+    next_id: HotkeyId,
+}
+
+impl HotkeyRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Bind `hotkey` in `scope`, unless the scope already binds it
+    pub fn register(
+        &mut self,
+        scope: &str,
+        hotkey: Hotkey,
+        handler: Callback<KeyboardEvent>,
+        options: &UseHotkeysOptions,
+    ) -> Result<HotkeyId, HotkeyError> {
+        if self
+            .bindings
+            .iter()
+            .any(|binding| binding.scope == scope && binding.hotkey == hotkey)
+        {
+            return Err(HotkeyError::Conflict {
+                hotkey: hotkey.to_string(),
+                scope: scope.to_string(),
+            });
+        }
+        self.next_id += 1;
+        self.bindings.push(HotkeyBinding {
+            id: self.next_id,
+            scope: scope.to_string(),
+            hotkey,
+            handler,
+            prevent_default: options.prevent_default,
+            enable_on_form_fields: options.enable_on_form_fields,
+            description: options.description.clone(),
+        });
+        Ok(self.next_id)
+    }
+
+    pub fn unregister(&mut self, id: HotkeyId) {
+        self.bindings.retain(|binding| binding.id != id);
+    }
+
+    pub fn set_scope_enabled(&mut self, scope: &str, enabled: bool) {
+        if enabled {
+            self.disabled_scopes.remove(scope);
+        } else {
+            self.disabled_scopes.insert(scope.to_string());
+        }
+    }
+
+    pub fn is_scope_enabled(&self, scope: &str) -> bool {
+        !self.disabled_scopes.contains(scope)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bindings.is_empty()
+    }
+
+    /// All bindings, in registration order
+    pub fn bindings(&self) -> &[HotkeyBinding] {
+        &self.bindings
+    }
+
+    /// Enabled binding a key press triggers, `in_form_field` telling whether
+    /// the user is typing into a text control
+    pub fn find(&self, press: &KeyPress, in_form_field: bool) -> Option<&HotkeyBinding> {
+        self.bindings.iter().rev().find(|binding| {
+            self.is_scope_enabled(&binding.scope)
+                && (binding.enable_on_form_fields || !in_form_field)
+                && binding.hotkey.matches(press)
+        })
+    }
+}
+
+/// Options for [`use_hotkeys_with_options`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct UseHotkeysOptions {
+    /// Scope the shortcut belongs to, see [`set_hotkey_scope_enabled`]
+    pub scope: String,
+    /// Prevent the browser's own handling of the key combination
+    pub prevent_default: bool,
+    /// Also fire while typing in an input, textarea, select or editable element
+    pub enable_on_form_fields: bool,
+    /// What the shortcut does, listed by shortcut help overlays
+    pub description: Option<String>,
+}
+
+impl Default for UseHotkeysOptions {
+    fn default() -> Self {
+        Self {
+            scope: GLOBAL_HOTKEY_SCOPE.to_string(),
+            prevent_default: true,
+            enable_on_form_fields: false,
+            description: None,
+        }
+    }
+}
+
+#[derive(Default)]
+struct HotkeyState {
+    registry: HotkeyRegistry,
+    listener: Option<ListenerCallback>,
+}
+
+thread_local! {
+    static HOTKEYS: RefCell<HotkeyState> = RefCell::default();
+}
+
+/// Hook for registering a keyboard shortcut for the lifetime of a component
+///
+/// The shortcut fires anywhere in the document, except while typing in a
+/// form field, and is removed again when the component is cleaned up. It is
+/// rejected with [`HotkeyError::Conflict`] when its scope already binds the
+/// same keys. All shortcuts share one document listener.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_core::use_hotkeys;
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     let palette_open = RwSignal::new(false);
+///     let _ = use_hotkeys("mod+k", move |_| palette_open.update(|open| *open = !*open));
+///
+///     view! {
+///         <Show when=move || palette_open.get()>
+///             <div role="dialog" aria-label="Command palette">"..."</div>
+///         </Show>
+///     }
+/// }
+/// ```
+pub fn use_hotkeys(
+    spec: &str,
+    handler: impl Fn(KeyboardEvent) + Send + Sync + 'static,
+) -> Result<HotkeyId, HotkeyError> {
+    use_hotkeys_with_options(spec, handler, UseHotkeysOptions::default())
+}
+
+/// Shortcut hook with a scope and form field and default handling options
+pub fn use_hotkeys_with_options(
+    spec: &str,
+    handler: impl Fn(KeyboardEvent) + Send + Sync + 'static,
+    options: UseHotkeysOptions,
+) -> Result<HotkeyId, HotkeyError> {
+    let hotkey = Hotkey::parse(spec)?;
+    let handler = Callback::new(handler);
+    let id = HOTKEYS.with_borrow_mut(|state| {
+        state
+            .registry
+            .register(&options.scope, hotkey, handler, &options)
+    })?;
+    Effect::new(move |_| ensure_listener());
+    on_cleanup(move || {
+        HOTKEYS.with_borrow_mut(|state| {
+            state.registry.unregister(id);
+            if state.registry.is_empty() {
+                remove_listener(state);
+            }
+        });
+    });
+    Ok(id)
+}
+
+/// Turn all shortcuts of a scope on or off, e.g. the editor's while a
+/// dialog is open
+pub fn set_hotkey_scope_enabled(scope: &str, enabled: bool) {
+    HOTKEYS.with_borrow_mut(|state| state.registry.set_scope_enabled(scope, enabled));
+}
+
+/// Whether the shortcuts of a scope currently fire
+pub fn is_hotkey_scope_enabled(scope: &str) -> bool {
+    HOTKEYS.with_borrow(|state| state.registry.is_scope_enabled(scope))
+}
+
+/// Shortcuts registered through [`use_hotkeys`] whose scope is enabled, in
+/// registration order
+pub fn registered_hotkeys() -> Vec<HotkeyBinding> {
+    HOTKEYS.with_borrow(|state| {
+        state
+            .registry
+            .bindings()
+            .iter()
+            .filter(|binding| state.registry.is_scope_enabled(&binding.scope))
+            .cloned()
+            .collect()
+    })
+}
+
+fn ensure_listener() {
+    HOTKEYS.with_borrow_mut(|state| {
+        if state.listener.is_some() || state.registry.is_empty() {
+            return;
+        }
+        let Some(document) = web_sys::window().and_then(|window| window.document()) else {
+            return;
+        };
+        let listener = ListenerCallback::new(dispatch_hotkey);
+        if document
+            .add_event_listener_with_callback("keydown", listener.as_ref().unchecked_ref())
+            .is_ok()
+        {
+            state.listener = Some(listener);
+        }
+    });
+}
+
+fn remove_listener(state: &mut HotkeyState) {
+    let (Some(listener), Some(document)) = (
+        state.listener.take(),
+        web_sys::window().and_then(|window| window.document()),
+    ) else {
+        return;
+    };
+    let _ =
+        document.remove_event_listener_with_callback("keydown", listener.as_ref().unchecked_ref());
+}
+
+fn dispatch_hotkey(event: KeyboardEvent) {
+    if event.default_prevented() || event.repeat() {
+        return;
+    }
+    let press = KeyPress::from(&event);
+    let in_form_field = event
+        .target()
+        .and_then(|target| target.dyn_into::<Element>().ok())
+        .is_some_and(|target| is_form_field(&target));
+    // Released before running, the handler may register or remove shortcuts
+    let Some((handler, prevent_default)) = HOTKEYS.with_borrow(|state| {
+        state
+            .registry
+            .find(&press, in_form_field)
+            .map(|binding| (binding.handler, binding.prevent_default))
+    }) else {
+        return;
+    };
+    if prevent_default {
+        event.prevent_default();
+    }
+    handler.try_run(event);
+}
+
+fn is_form_field(element: &Element) -> bool {
+    matches!(
+        element.tag_name().to_lowercase().as_str(),
+        "input" | "textarea" | "select"
+    ) || element
+        .dyn_ref::<web_sys::HtmlElement>()
+        .is_some_and(|element| element.is_content_editable())
+}
+
+/// Whether `mod` shortcuts use Cmd here, as on macOS and iOS
+pub fn is_apple_platform() -> bool {
+    web_sys::window()
+        .and_then(|window| window.navigator().platform().ok())
+        .is_some_and(|platform| platform.starts_with("Mac") || platform.starts_with("i"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(key: &str, modifiers: &str) -> KeyPress {
+        KeyPress {
+            key: key.to_string(),
+            ctrl: modifiers.contains('c'),
+            alt: modifiers.contains('a'),
+            shift: modifiers.contains('s'),
+            meta: modifiers.contains('m'),
+        }
+    }
+
+    #[test]
+    fn test_parse_shortcuts() {
+        let palette = Hotkey::parse_for_platform("mod+k", false).unwrap();
+        assert!(palette.ctrl && !palette.meta);
+        assert_eq!(palette.key, "k");
+        let palette = Hotkey::parse_for_platform("Mod+K", true).unwrap();
+        assert!(palette.meta && !palette.ctrl);
+
+        assert_eq!(
+            Hotkey::parse_for_platform("ctrl++", false)
+                .unwrap()
+                .to_string(),
+            "ctrl++"
+        );
+        assert_eq!(
+            Hotkey::parse_for_platform("shift + esc", false)
+                .unwrap()
+                .to_string(),
+            "shift+escape"
+        );
+        assert_eq!(
+            Hotkey::parse_for_platform("hyper+k", false),
+            Err(HotkeyError::UnknownModifier("hyper".to_string()))
+        );
+        assert_eq!(
+            Hotkey::parse_for_platform("ctrl+", false),
+            Err(HotkeyError::Empty)
+        );
+    }
+
+    #[test]
+    fn test_matching() {
+        let palette = Hotkey::parse_for_platform("mod+k", false).unwrap();
+        assert!(palette.matches(&press("k", "c")));
+        assert!(palette.matches(&press("K", "c")));
+        assert!(!palette.matches(&press("k", "cs")));
+        assert!(!palette.matches(&press("k", "")));
+
+        // "?" needs Shift on most layouts, with or without it in the spec
+        let help = Hotkey::parse_for_platform("shift+?", false).unwrap();
+        assert!(help.matches(&press("?", "s")));
+        assert!(Hotkey::parse_for_platform("?", false)
+            .unwrap()
+            .matches(&press("?", "s")));
+        assert!(!help.matches(&press("?", "cs")));
+    }
+
+    #[test]
+    fn test_key_labels() {
+        let palette = Hotkey::parse_for_platform("mod+shift+p", true).unwrap();
+        assert_eq!(palette.key_labels_for_platform(true), vec!["⇧", "⌘", "P"]);
+        let palette = Hotkey::parse_for_platform("mod+shift+p", false).unwrap();
+        assert_eq!(
+            palette.key_labels_for_platform(false),
+            vec!["Ctrl", "Shift", "P"]
+        );
+        let close = Hotkey::parse_for_platform("esc", false).unwrap();
+        assert_eq!(close.key_labels_for_platform(false), vec!["Esc"]);
+        let help = Hotkey::parse_for_platform("?", false).unwrap();
+        assert_eq!(help.key_labels_for_platform(false), vec!["?"]);
+    }
+
+    #[test]
+    fn test_registry_scopes_and_conflicts() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let handler = Callback::new(|_: KeyboardEvent| {});
+            let options = UseHotkeysOptions::default();
+            let save = Hotkey::parse_for_platform("ctrl+s", false).unwrap();
+            let mut registry = HotkeyRegistry::new();
+
+            let page = registry
+                .register("page", save.clone(), handler, &options)
+                .unwrap();
+            assert_eq!(
+                registry.register("page", save.clone(), handler, &options),
+                Err(HotkeyError::Conflict {
+                    hotkey: "ctrl+s".to_string(),
+                    scope: "page".to_string(),
+                })
+            );
+            let dialog = registry
+                .register("dialog", save, handler, &options)
+                .unwrap();
+
+            // The most recent binding shadows the page's
+            let ctrl_s = press("s", "c");
+            assert_eq!(registry.find(&ctrl_s, false).map(|b| b.id), Some(dialog));
+            registry.set_scope_enabled("dialog", false);
+            assert_eq!(registry.find(&ctrl_s, false).map(|b| b.id), Some(page));
+            assert!(registry.find(&ctrl_s, true).is_none());
+
+            registry.unregister(page);
+            assert!(registry.find(&ctrl_s, false).is_none());
+            registry.set_scope_enabled("dialog", true);
+            assert_eq!(registry.find(&ctrl_s, false).map(|b| b.id), Some(dialog));
+        });
+    }
+}
@@ -1,30 +1,32 @@
+use leptos::prelude::*;
 use uuid::Uuid;
 
 /// Hook for generating stable, unique IDs for component accessibility
-/// 
+///
 /// This hook generates a unique ID that remains stable across re-renders,
 /// essential for ARIA attributes like `aria-labelledby` and `aria-describedby`.
-/// 
+///
 /// # Arguments
-/// 
-/// * `prefix` - Optional prefix for the generated ID
-/// 
+///
+/// * `prefix` - Optional prefix for the generated ID (default: `radix`)
+///
 /// # Example
-/// 
-/// ```rust
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
 /// use radix_leptos_core::use_id;
-/// 
+///
 /// #[component]
 /// pub fn FormField() -> impl IntoView {
-///     let field_id = use_id(Some("field"));
-///     let label_id = use_id(Some("label"));
-///     let desc_id = use_id(Some("description"));
-///     
+///     let field_id = use_id(Some("field".to_string()));
+///     let label_id = use_id(Some("label".to_string()));
+///     let desc_id = use_id(Some("description".to_string()));
+///
 ///     view! {
 ///         <div>
 ///             <label id=label_id for=field_id>"Email"</label>
-///             <input 
-///                 id=field_id 
+///             <input
+///                 id=field_id
 ///                 aria-labelledby=label_id
 ///                 aria-describedby=desc_id
 ///             />
@@ -34,66 +36,35 @@ use uuid::Uuid;
 /// }
 /// ```
 pub fn use_id(prefix: Option<String>) -> Signal<String> {
-    let id = create_memo(move |_| {
-        let uuid = Uuid::new_v4().to_string();
-        let short_id = &uuid[..8]; // Use first 8 characters
-        
-        match &prefix {
-            Some(p) => format!("{}-{}", p, short_id),
-            None => format!("radix-{}", short_id),
-        }
-    });
-    
-    id.into()
+    Signal::stored(format_id(prefix.as_deref(), Uuid::new_v4()))
+}
+
+fn format_id(prefix: Option<&str>, uuid: Uuid) -> String {
+    // The first 8 hex digits are unique enough within a page
+    let short_id = &uuid.simple().to_string()[..8];
+    format!("{}-{}", prefix.unwrap_or("radix"), short_id)
 }
 
 #[cfg(test)]
 mod tests {
-    
+    use super::*;
+
     #[test]
     fn test_generates_unique_ids() {
-        run_test(|| {
-            // Test ID generation logic directly
-            let id1 = uuid::Uuid::new_v4().to_string();
-            let id2 = uuid::Uuid::new_v4().to_string();
-            
-            // Should generate different IDs
-            assert_ne!(id1, id2);
-            
-            // Should be valid UUIDs
-            assert!(uuid::Uuid::parse_str(&id1).is_ok());
-            assert!(uuid::Uuid::parse_str(&id2).is_ok());
+        let owner = Owner::new();
+        owner.with(|| {
+            let id1 = use_id(None);
+            let id2 = use_id(None);
+
+            assert_ne!(id1.get_untracked(), id2.get_untracked());
+            assert!(id1.get_untracked().starts_with("radix-"));
         });
     }
-    
+
     #[test]
     fn test_custom_prefix() {
-        run_test(|| {
-            // Test prefix logic directly
-            let uuid = uuid::Uuid::new_v4().to_string();
-            let short_id = &uuid[..8];
-            let prefixed_id = format!("button-{}", short_id);
-            
-            // Should start with custom prefix
-            assert!(prefixed_id.starts_with("button-"));
-        });
+        let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+        assert_eq!(format_id(Some("button"), uuid), "button-67e55044");
+        assert_eq!(format_id(None, uuid), "radix-67e55044");
     }
-    
-    #[test]
-    fn test_id_stability() {
-        run_test(|| {
-            // Test that UUID generation is consistent
-            let id1 = uuid::Uuid::new_v4();
-            let id2 = uuid::Uuid::new_v4();
-            
-            // Each call should generate a unique ID
-            assert_ne!(id1, id2);
-        });
-    }
-    
-    fn run_test<F>(f: F) where F: FnOnce() {
-        // Simplified test runner for Leptos 0.8
-        // In a real test environment, this would set up the runtime properly
-        f();
-    }
-}
\ No newline at end of file
+}
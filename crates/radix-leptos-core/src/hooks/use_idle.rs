@@ -0,0 +1,170 @@
+use std::time::Duration;
+
+use leptos::prelude::*;
+
+use super::use_event_listener::{document_target, use_event_listener};
+
+/// Events that count as user activity by default
+pub const IDLE_ACTIVITY_EVENTS: &[&str] = &[
+    "mousemove",
+    "mousedown",
+    "keydown",
+    "touchstart",
+    "wheel",
+    "visibilitychange",
+];
+
+/// Options for [`use_idle_with_options`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UseIdleOptions {
+    /// Document events that reset the idle timer
+    pub events: &'static [&'static str],
+}
+
+impl Default for UseIdleOptions {
+    fn default() -> Self {
+        Self {
+            events: IDLE_ACTIVITY_EVENTS,
+        }
+    }
+}
+
+/// Return value for the use_idle hook
+#[derive(Debug, Clone, Copy)]
+pub struct UseIdleReturn {
+    /// Whether there has been no activity for the timeout
+    pub is_idle: Signal<bool>,
+    /// Time of the last activity in milliseconds since the Unix epoch, 0
+    /// until mounted
+    pub last_active: Signal<f64>,
+    set_idle: RwSignal<bool>,
+    set_last_active: RwSignal<f64>,
+    timer: StoredValue<Option<TimeoutHandle>>,
+    timeout: Duration,
+}
+
+impl UseIdleReturn {
+    /// Count as activity now, e.g. when the user picks "Stay signed in"
+    pub fn reset(&self) {
+        self.set_last_active.try_set(js_sys::Date::now());
+        if self.set_idle.try_get_untracked() == Some(true) {
+            self.set_idle.set(false);
+            self.schedule(self.timeout);
+        }
+    }
+
+    fn schedule(&self, delay: Duration) {
+        self.clear_timer();
+        let this = *self;
+        let timer = set_timeout_with_handle(move || this.check(), delay);
+        self.timer.try_set_value(timer.ok());
+    }
+
+    /// Go idle, or wait for the rest of the timeout if there was activity
+    fn check(&self) {
+        let Some(last_active) = self.set_last_active.try_get_untracked() else {
+            return;
+        };
+        match idle_remaining(js_sys::Date::now(), last_active, self.timeout) {
+            Some(remaining) => self.schedule(remaining),
+            None => {
+                self.timer.try_set_value(None);
+                self.set_idle.try_set(true);
+            }
+        }
+    }
+
+    fn clear_timer(&self) {
+        if let Some(Some(timer)) = self.timer.try_update_value(Option::take) {
+            timer.clear();
+        }
+    }
+}
+
+/// Hook for detecting user inactivity
+///
+/// `is_idle` turns true once no mouse, keyboard, touch or wheel activity has
+/// been seen on the document for `timeout`, and false again with the next
+/// activity. Activity only records a timestamp, so frequent events such as
+/// `mousemove` stay cheap. Pair it with an AlertDialog to warn before a
+/// session expires.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+///
+/// use leptos::prelude::*;
+/// use radix_leptos_core::use_idle;
+///
+/// #[component]
+/// pub fn SessionTimeout() -> impl IntoView {
+///     let idle = use_idle(Duration::from_secs(15 * 60));
+///
+///     view! {
+///         <div role="alertdialog" aria-label="Session expiring" hidden=move || !idle.is_idle.get()>
+///             <p>"You will be signed out soon."</p>
+///             <button on:click=move |_| idle.reset()>"Stay signed in"</button>
+///         </div>
+///     }
+/// }
+/// ```
+pub fn use_idle(timeout: Duration) -> UseIdleReturn {
+    use_idle_with_options(timeout, UseIdleOptions::default())
+}
+
+/// Idle hook listening to a custom set of activity events
+pub fn use_idle_with_options(timeout: Duration, options: UseIdleOptions) -> UseIdleReturn {
+    let set_idle = RwSignal::new(false);
+    let set_last_active = RwSignal::new(0.0);
+    let idle = UseIdleReturn {
+        is_idle: set_idle.into(),
+        last_active: set_last_active.into(),
+        set_idle,
+        set_last_active,
+        timer: StoredValue::new(None),
+        timeout,
+    };
+
+    // The clock starts once mounted, never during server-side rendering
+    Effect::new(move |_| {
+        set_last_active.set(js_sys::Date::now());
+        idle.schedule(timeout);
+    });
+    on_cleanup(move || idle.clear_timer());
+
+    for &event in options.events {
+        use_event_listener(document_target, event, move |_: web_sys::Event| {
+            idle.reset()
+        });
+    }
+
+    idle
+}
+
+/// Time left until idle, or `None` when the timeout has passed
+fn idle_remaining(now: f64, last_active: f64, timeout: Duration) -> Option<Duration> {
+    let remaining = timeout.as_secs_f64() * 1000.0 - (now - last_active);
+    (remaining > 0.0).then(|| Duration::from_secs_f64(remaining / 1000.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_idle_remaining() {
+        let timeout = Duration::from_secs(60);
+        assert_eq!(
+            idle_remaining(10_000.0, 10_000.0, timeout),
+            Some(Duration::from_secs(60))
+        );
+        // Activity after the timer started pushes the deadline back
+        assert_eq!(
+            idle_remaining(70_000.0, 40_000.0, timeout),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(idle_remaining(70_000.0, 10_000.0, timeout), None);
+        assert_eq!(idle_remaining(90_000.0, 10_000.0, timeout), None);
+    }
+}
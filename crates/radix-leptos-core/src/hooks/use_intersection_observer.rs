@@ -0,0 +1,199 @@
+use leptos::html::ElementType;
+use leptos::prelude::*;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Element, IntersectionObserver, IntersectionObserverEntry, IntersectionObserverInit};
+
+type ObserverCallback = Closure<dyn Fn(js_sys::Array)>;
+type ObserverSlot = StoredValue<Option<(IntersectionObserver, ObserverCallback)>, LocalStorage>;
+type RootGetter = Callback<(), Option<Element>>;
+
+/// Options for [`use_intersection_observer_with_options`]
+///
+/// By default the viewport is the root, with no margin and a single
+/// threshold of 0 (any visible pixel counts).
+#[derive(Clone, Default)]
+pub struct UseIntersectionObserverOptions {
+    root: Option<RootGetter>,
+    root_margin: Option<String>,
+    threshold: Vec<f64>,
+}
+
+impl UseIntersectionObserverOptions {
+    /// Measure intersection with this scroll container instead of the viewport
+    pub fn root<E>(mut self, root: NodeRef<E>) -> Self
+    where
+        E: ElementType + 'static,
+        E::Output: JsCast + Clone + 'static,
+    {
+        self.root = Some(Callback::new(move |()| {
+            root.get().map(|root| root.unchecked_into::<Element>())
+        }));
+        self
+    }
+
+    /// Grow or shrink the root's box, in CSS margin syntax (e.g. `"0px 0px 200px 0px"`)
+    pub fn root_margin(mut self, root_margin: impl Into<String>) -> Self {
+        self.root_margin = Some(root_margin.into());
+        self
+    }
+
+    /// Visible ratios (0.0–1.0) at which to report a change
+    pub fn threshold(mut self, threshold: Vec<f64>) -> Self {
+        self.threshold = threshold;
+        self
+    }
+}
+
+/// Return value for the use_intersection_observer hook
+#[derive(Debug, Clone, Copy)]
+pub struct UseIntersectionObserverReturn {
+    /// Whether any part of the element is inside the root
+    pub is_visible: Signal<bool>,
+    /// Visible fraction of the element, from 0.0 to 1.0
+    pub intersection_ratio: Signal<f64>,
+}
+
+/// Hook for tracking whether an element is visible in the viewport
+///
+/// Watches the element behind `target` with an `IntersectionObserver`.
+/// Observation starts when the element mounts, follows it if it is replaced
+/// and stops on cleanup; nothing is observed during server-side rendering,
+/// where the element counts as not visible.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::html;
+/// use leptos::prelude::*;
+/// use radix_leptos_core::use_intersection_observer;
+///
+/// #[component]
+/// pub fn FadeIn(children: Children) -> impl IntoView {
+///     let section_ref = NodeRef::<html::Section>::new();
+///     let visibility = use_intersection_observer(section_ref);
+///
+///     view! {
+///         <section
+///             node_ref=section_ref
+///             style=move || format!("opacity: {}", visibility.intersection_ratio.get())
+///         >
+///             {children()}
+///         </section>
+///     }
+/// }
+/// ```
+pub fn use_intersection_observer<E>(target: NodeRef<E>) -> UseIntersectionObserverReturn
+where
+    E: ElementType + 'static,
+    E::Output: JsCast + Clone + 'static,
+{
+    use_intersection_observer_with_options(target, UseIntersectionObserverOptions::default())
+}
+
+/// Intersection observer hook with a custom root, margin and thresholds
+pub fn use_intersection_observer_with_options<E>(
+    target: NodeRef<E>,
+    options: UseIntersectionObserverOptions,
+) -> UseIntersectionObserverReturn
+where
+    E: ElementType + 'static,
+    E::Output: JsCast + Clone + 'static,
+{
+    let is_visible = RwSignal::new(false);
+    let intersection_ratio = RwSignal::new(0.0);
+    let observer: ObserverSlot = StoredValue::new_local(None);
+
+    let disconnect = move || {
+        if let Some(Some((observer, _callback))) = observer.try_update_value(Option::take) {
+            observer.disconnect();
+        }
+    };
+
+    Effect::new(move |_| {
+        let element = target
+            .get()
+            .map(|element| element.unchecked_into::<Element>());
+        let root = options.root.map(|root| root.run(()));
+        disconnect();
+        let Some(element) = element else {
+            return;
+        };
+        // Wait for a custom root to mount rather than falling back to the viewport
+        if root.as_ref().is_some_and(Option::is_none) {
+            return;
+        }
+
+        let callback = ObserverCallback::new(move |entries: js_sys::Array| {
+            // Only the latest entry matters when several changes were batched
+            let Some(entry) = entries
+                .iter()
+                .last()
+                .and_then(|entry| entry.dyn_into::<IntersectionObserverEntry>().ok())
+            else {
+                return;
+            };
+            is_visible.set(entry.is_intersecting());
+            intersection_ratio.set(entry.intersection_ratio().clamp(0.0, 1.0));
+        });
+
+        let init = IntersectionObserverInit::new();
+        init.set_root(root.flatten().as_ref());
+        if let Some(root_margin) = &options.root_margin {
+            init.set_root_margin(root_margin);
+        }
+        if !options.threshold.is_empty() {
+            let threshold: js_sys::Array = options
+                .threshold
+                .iter()
+                .map(|&ratio| JsValue::from_f64(ratio.clamp(0.0, 1.0)))
+                .collect();
+            init.set_threshold(&threshold);
+        }
+        let Ok(intersection_observer) =
+            IntersectionObserver::new_with_options(callback.as_ref().unchecked_ref(), &init)
+        else {
+            return;
+        };
+        intersection_observer.observe(&element);
+        observer.set_value(Some((intersection_observer, callback)));
+    });
+    on_cleanup(disconnect);
+
+    UseIntersectionObserverReturn {
+        is_visible: is_visible.into(),
+        intersection_ratio: intersection_ratio.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptos::html;
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    async fn test_reports_visibility() {
+        let visibility = RwSignal::new(None);
+        mount_to_body(move || {
+            let shown_ref = NodeRef::<html::Div>::new();
+            let hidden_ref = NodeRef::<html::Div>::new();
+            visibility.set(Some((
+                use_intersection_observer(shown_ref),
+                use_intersection_observer(hidden_ref),
+            )));
+            view! {
+                <div node_ref=shown_ref style="height: 10px;"></div>
+                <div node_ref=hidden_ref style="position: fixed; top: -100px; height: 10px;"></div>
+            }
+        });
+        gloo_timers::future::TimeoutFuture::new(50).await;
+
+        let (shown, hidden) = visibility.get_untracked().unwrap();
+        assert!(shown.is_visible.get_untracked());
+        assert_eq!(shown.intersection_ratio.get_untracked(), 1.0);
+        assert!(!hidden.is_visible.get_untracked());
+    }
+}
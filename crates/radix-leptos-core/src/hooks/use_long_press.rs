@@ -0,0 +1,223 @@
+use std::time::Duration;
+
+use leptos::html::ElementType;
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, MouseEvent, PointerEvent};
+
+use super::use_event_listener::use_event_listener;
+
+/// Where and with what a long press happened
+#[derive(Debug, Clone, PartialEq)]
+pub struct LongPress {
+    pub client_x: f64,
+    pub client_y: f64,
+    /// `"mouse"`, `"pen"` or `"touch"`
+    pub pointer_type: String,
+}
+
+/// Options for [`use_long_press_with_options`]
+#[derive(Debug, Clone, Copy)]
+pub struct UseLongPressOptions {
+    /// How long the pointer has to stay down
+    pub duration: Duration,
+    /// Distance in CSS pixels the pointer may drift before the press is cancelled
+    pub move_tolerance: f64,
+    /// Whether mouse presses count, or only touch and pen
+    pub mouse: bool,
+    /// Called when a press starts
+    pub on_start: Option<Callback<()>>,
+    /// Called when a press ends, moves or leaves before it was long enough
+    pub on_cancel: Option<Callback<()>>,
+}
+
+impl Default for UseLongPressOptions {
+    fn default() -> Self {
+        Self {
+            duration: Duration::from_millis(500),
+            move_tolerance: 10.0,
+            mouse: true,
+            on_start: None,
+            on_cancel: None,
+        }
+    }
+}
+
+/// Return value for the use_long_press hook
+#[derive(Debug, Clone, Copy)]
+pub struct UseLongPressReturn {
+    /// Whether a press is under way and not yet long enough
+    pub is_pressing: Signal<bool>,
+}
+
+/// A press in progress
+#[derive(Debug, Clone)]
+struct PressState {
+    press: LongPress,
+    timer: Option<TimeoutHandle>,
+}
+
+/// Hook for detecting press-and-hold on an element
+///
+/// `on_finish` runs once the pointer has stayed down on the element behind
+/// `target` for half a second without moving more than 10 pixels. Releasing,
+/// moving away or a cancelled pointer before then aborts the press, and the
+/// click that follows a finished press is swallowed so it does not also
+/// activate the element.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::html;
+/// use leptos::prelude::*;
+/// use radix_leptos_core::use_long_press;
+///
+/// #[component]
+/// pub fn DeletableChip() -> impl IntoView {
+///     let chip_ref = NodeRef::<html::Button>::new();
+///     let confirm = RwSignal::new(false);
+///     let long_press = use_long_press(chip_ref, move |_| confirm.set(true));
+///
+///     view! {
+///         <button node_ref=chip_ref data-pressing=move || long_press.is_pressing.get().to_string()>
+///             "Hold to delete"
+///         </button>
+///     }
+/// }
+/// ```
+pub fn use_long_press<E>(
+    target: NodeRef<E>,
+    on_finish: impl Fn(LongPress) + Send + Sync + 'static,
+) -> UseLongPressReturn
+where
+    E: ElementType + 'static,
+    E::Output: JsCast + Clone + 'static,
+{
+    use_long_press_with_options(target, on_finish, UseLongPressOptions::default())
+}
+
+/// Long press hook with a custom duration, tolerance and start/cancel callbacks
+pub fn use_long_press_with_options<E>(
+    target: NodeRef<E>,
+    on_finish: impl Fn(LongPress) + Send + Sync + 'static,
+    options: UseLongPressOptions,
+) -> UseLongPressReturn
+where
+    E: ElementType + 'static,
+    E::Output: JsCast + Clone + 'static,
+{
+    let on_finish = Callback::new(on_finish);
+    let is_pressing = RwSignal::new(false);
+    let state = StoredValue::new(None::<PressState>);
+    let swallow_click = StoredValue::new(false);
+    let element = move || {
+        target
+            .get()
+            .map(|element| element.unchecked_into::<Element>().into())
+    };
+
+    let clear = move || {
+        is_pressing.try_set(false);
+        state
+            .try_update_value(Option::take)
+            .flatten()
+            .inspect(|pressed| {
+                if let Some(timer) = pressed.timer {
+                    timer.clear();
+                }
+            })
+            .is_some()
+    };
+    let cancel = move || {
+        if clear() {
+            if let Some(on_cancel) = options.on_cancel {
+                on_cancel.run(());
+            }
+        }
+    };
+    on_cleanup(move || {
+        clear();
+    });
+
+    use_event_listener(element, "pointerdown", move |event: PointerEvent| {
+        let pointer_type = event.pointer_type();
+        if !event.is_primary() || event.button() != 0 || (!options.mouse && pointer_type == "mouse")
+        {
+            return;
+        }
+        clear();
+        swallow_click.set_value(false);
+        let press = LongPress {
+            client_x: event.client_x() as f64,
+            client_y: event.client_y() as f64,
+            pointer_type,
+        };
+        let finished = press.clone();
+        let timer = set_timeout_with_handle(
+            move || {
+                if clear() {
+                    swallow_click.try_set_value(true);
+                    on_finish.run(finished);
+                }
+            },
+            options.duration,
+        );
+        state.set_value(Some(PressState {
+            press,
+            timer: timer.ok(),
+        }));
+        is_pressing.set(true);
+        if let Some(on_start) = options.on_start {
+            on_start.run(());
+        }
+    });
+
+    use_event_listener(element, "pointermove", move |event: PointerEvent| {
+        let moved_away = state.with_value(|pressed| {
+            pressed.as_ref().is_some_and(|pressed| {
+                exceeds_tolerance(
+                    (pressed.press.client_x, pressed.press.client_y),
+                    (event.client_x() as f64, event.client_y() as f64),
+                    options.move_tolerance,
+                )
+            })
+        });
+        if moved_away {
+            cancel();
+        }
+    });
+
+    for event in ["pointerup", "pointerleave", "pointercancel"] {
+        use_event_listener(element, event, move |_: PointerEvent| cancel());
+    }
+
+    use_event_listener(element, "click", move |event: MouseEvent| {
+        if swallow_click.get_value() {
+            swallow_click.set_value(false);
+            event.prevent_default();
+            event.stop_propagation();
+        }
+    });
+
+    UseLongPressReturn {
+        is_pressing: is_pressing.into(),
+    }
+}
+
+/// Whether the pointer drifted further than `tolerance` from where it went down
+pub fn exceeds_tolerance(start: (f64, f64), current: (f64, f64), tolerance: f64) -> bool {
+    let (dx, dy) = (current.0 - start.0, current.1 - start.1);
+    dx.hypot(dy) > tolerance
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exceeds_tolerance() {
+        assert!(!exceeds_tolerance((100.0, 100.0), (106.0, 108.0), 10.0));
+        assert!(exceeds_tolerance((100.0, 100.0), (107.0, 108.0), 10.0));
+        assert!(exceeds_tolerance((0.0, 0.0), (-11.0, 0.0), 10.0));
+    }
+}
@@ -0,0 +1,100 @@
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::{Event, MediaQueryList};
+
+use super::use_event_listener::use_event_listener;
+
+/// Options for [`use_media_query_with_options`]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct UseMediaQueryOptions {
+    /// Value used during server-side rendering and until the query has been
+    /// evaluated in the browser
+    pub ssr_default: bool,
+}
+
+/// Hook for tracking whether a CSS media query matches
+///
+/// The result updates when the query starts or stops matching (for example
+/// on resize or when the user changes a system preference) and when `query`
+/// itself changes. It is `false` on the server and during hydration, so the
+/// first client render always matches the server's; use
+/// [`use_media_query_with_options`] to pick a different default.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_core::use_media_query;
+///
+/// #[component]
+/// pub fn Navigation() -> impl IntoView {
+///     let is_desktop = use_media_query("(min-width: 768px)");
+///
+///     view! {
+///         <Show when=move || is_desktop.get() fallback=|| view! { <button>"Menu"</button> }>
+///             <nav>"Home · Docs · Blog"</nav>
+///         </Show>
+///     }
+/// }
+/// ```
+pub fn use_media_query(query: impl Into<Signal<String>>) -> Signal<bool> {
+    use_media_query_with_options(query, UseMediaQueryOptions::default())
+}
+
+/// Media query hook with a configurable server-side default
+pub fn use_media_query_with_options(
+    query: impl Into<Signal<String>>,
+    options: UseMediaQueryOptions,
+) -> Signal<bool> {
+    let query = query.into();
+    let matches = RwSignal::new(options.ssr_default);
+
+    Effect::new(move |_| {
+        let list = media_query_list(&query.get());
+        matches.set(list.map_or(options.ssr_default, |list| list.matches()));
+    });
+
+    use_event_listener(
+        move || media_query_list(&query.get()).map(Into::into),
+        "change",
+        move |event: Event| {
+            if let Some(list) = event
+                .target()
+                .and_then(|target| target.dyn_into::<MediaQueryList>().ok())
+            {
+                matches.set(list.matches());
+            }
+        },
+    );
+
+    matches.read_only().into()
+}
+
+fn media_query_list(query: &str) -> Option<MediaQueryList> {
+    web_sys::window()?.match_media(query).ok().flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    async fn test_media_query_matches() {
+        let query = RwSignal::new("(min-width: 0px)".to_string());
+        let matches = RwSignal::new(None);
+        mount_to_body(move || {
+            let options = UseMediaQueryOptions { ssr_default: true };
+            matches.set(Some(use_media_query_with_options(query, options)));
+        });
+        leptos::task::tick().await;
+        assert!(matches.get_untracked().unwrap().get_untracked());
+
+        // The query itself is reactive
+        query.set("(max-width: 0px)".to_string());
+        leptos::task::tick().await;
+        assert!(!matches.get_untracked().unwrap().get_untracked());
+    }
+}
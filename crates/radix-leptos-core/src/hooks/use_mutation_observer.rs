@@ -0,0 +1,196 @@
+use std::rc::Rc;
+
+use leptos::html::ElementType;
+use leptos::prelude::*;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Element, MutationObserver, MutationObserverInit, MutationRecord};
+
+type ObserverCallback = Closure<dyn Fn(js_sys::Array, MutationObserver)>;
+type ObserverSlot = StoredValue<Option<(MutationObserver, ObserverCallback)>, LocalStorage>;
+
+/// What [`use_mutation_observer`] watches, mirroring `MutationObserverInit`
+///
+/// At least one of `child_list`, `attributes` and `character_data` has to be
+/// requested. Asking for old values or an attribute filter implies the
+/// matching kind of change.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct UseMutationObserverOptions {
+    /// Children being added or removed
+    pub child_list: bool,
+    /// Attribute changes
+    pub attributes: bool,
+    /// Text changes
+    pub character_data: bool,
+    /// Watch all descendants instead of only the element itself
+    pub subtree: bool,
+    /// Report attribute values from before the change
+    pub attribute_old_value: bool,
+    /// Report text from before the change
+    pub character_data_old_value: bool,
+    /// Only these attributes
+    pub attribute_filter: Option<Vec<String>>,
+}
+
+impl UseMutationObserverOptions {
+    fn observes_attributes(&self) -> bool {
+        self.attributes || self.attribute_old_value || self.attribute_filter.is_some()
+    }
+
+    fn observes_character_data(&self) -> bool {
+        self.character_data || self.character_data_old_value
+    }
+
+    /// Whether the options request any kind of change; the browser rejects
+    /// them otherwise
+    pub fn is_valid(&self) -> bool {
+        self.child_list || self.observes_attributes() || self.observes_character_data()
+    }
+
+    fn to_init(&self) -> MutationObserverInit {
+        let init = MutationObserverInit::new();
+        init.set_child_list(self.child_list);
+        init.set_subtree(self.subtree);
+        // Setting a kind to false while asking for its old value is an error
+        if self.observes_attributes() {
+            init.set_attributes(true);
+            init.set_attribute_old_value(self.attribute_old_value);
+        }
+        if self.observes_character_data() {
+            init.set_character_data(true);
+            init.set_character_data_old_value(self.character_data_old_value);
+        }
+        if let Some(filter) = &self.attribute_filter {
+            let filter: js_sys::Array = filter.iter().map(|name| JsValue::from_str(name)).collect();
+            init.set_attribute_filter(&filter);
+        }
+        init
+    }
+}
+
+/// Handle to an observer created by [`use_mutation_observer`]
+#[derive(Clone, Copy)]
+pub struct UseMutationObserverReturn {
+    observer: ObserverSlot,
+}
+
+impl UseMutationObserverReturn {
+    /// Stop observing before the component is cleaned up
+    pub fn stop(&self) {
+        if let Some(Some((observer, _callback))) = self.observer.try_update_value(Option::take) {
+            observer.disconnect();
+        }
+    }
+}
+
+/// Hook for watching DOM changes in an element
+///
+/// Calls `callback` with the batched mutation records whenever the element
+/// behind `target` changes in one of the ways requested in `options`.
+/// Observation follows the node ref like
+/// [`use_resize_observer`](super::use_resize_observer::use_resize_observer)
+/// and never starts during server-side rendering; invalid options observe
+/// nothing.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::html;
+/// use leptos::prelude::*;
+/// use radix_leptos_core::{use_mutation_observer, UseMutationObserverOptions};
+///
+/// #[component]
+/// pub fn Inbox() -> impl IntoView {
+///     let list_ref = NodeRef::<html::Ul>::new();
+///     let changes = RwSignal::new(0);
+///     use_mutation_observer(
+///         list_ref,
+///         move |records| changes.update(|count| *count += records.len()),
+///         UseMutationObserverOptions {
+///             child_list: true,
+///             ..Default::default()
+///         },
+///     );
+///
+///     view! {
+///         <ul node_ref=list_ref></ul>
+///         <p>{changes} " changes"</p>
+///     }
+/// }
+/// ```
+pub fn use_mutation_observer<E>(
+    target: NodeRef<E>,
+    callback: impl Fn(Vec<MutationRecord>) + 'static,
+    options: UseMutationObserverOptions,
+) -> UseMutationObserverReturn
+where
+    E: ElementType + 'static,
+    E::Output: JsCast + Clone + 'static,
+{
+    let handle = UseMutationObserverReturn {
+        observer: StoredValue::new_local(None),
+    };
+    let callback = Rc::new(callback);
+    let init = StoredValue::new_local(options.is_valid().then(|| options.to_init()));
+
+    Effect::new(move |_| {
+        let element = target
+            .get()
+            .map(|element| element.unchecked_into::<Element>());
+        if handle.observer.is_disposed() {
+            return;
+        }
+        handle.stop();
+        let (Some(element), Some(Some(init))) = (element, init.try_get_value()) else {
+            return;
+        };
+
+        let callback = Rc::clone(&callback);
+        let observer_callback =
+            ObserverCallback::new(move |records: js_sys::Array, _: MutationObserver| {
+                callback(records.iter().map(JsCast::unchecked_into).collect());
+            });
+        let Ok(observer) = MutationObserver::new(observer_callback.as_ref().unchecked_ref()) else {
+            return;
+        };
+        if observer.observe_with_options(&element, &init).is_ok() {
+            handle
+                .observer
+                .set_value(Some((observer, observer_callback)));
+        }
+    });
+    on_cleanup(move || handle.stop());
+
+    handle
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_options_validity() {
+        assert!(!UseMutationObserverOptions::default().is_valid());
+        // Subtree alone says where to look, not what for
+        assert!(!UseMutationObserverOptions {
+            subtree: true,
+            ..Default::default()
+        }
+        .is_valid());
+        assert!(UseMutationObserverOptions {
+            child_list: true,
+            ..Default::default()
+        }
+        .is_valid());
+        assert!(UseMutationObserverOptions {
+            attribute_filter: Some(vec!["style".to_string()]),
+            ..Default::default()
+        }
+        .is_valid());
+        assert!(UseMutationObserverOptions {
+            character_data_old_value: true,
+            ..Default::default()
+        }
+        .is_valid());
+    }
+}
@@ -0,0 +1,145 @@
+use leptos::prelude::*;
+use wasm_bindgen::JsValue;
+use web_sys::EventTarget;
+
+use super::use_event_listener::use_event_listener;
+
+/// Connection details from the Network Information API
+///
+/// Only Chromium-based browsers report these; elsewhere the hook's
+/// `connection` stays `None`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ConnectionInfo {
+    /// `"slow-2g"`, `"2g"`, `"3g"` or `"4g"`
+    pub effective_type: Option<String>,
+    /// Estimated bandwidth in megabits per second
+    pub downlink: Option<f64>,
+    /// Estimated round-trip time in milliseconds
+    pub rtt: Option<f64>,
+    /// Whether the user asked for reduced data usage
+    pub save_data: bool,
+}
+
+impl ConnectionInfo {
+    /// Whether heavy content such as autoplaying video should be held back
+    pub fn is_constrained(&self) -> bool {
+        self.save_data || matches!(self.effective_type.as_deref(), Some("slow-2g" | "2g"))
+    }
+
+    fn from_js(connection: &JsValue) -> Self {
+        let field = |name: &str| js_sys::Reflect::get(connection, &JsValue::from_str(name)).ok();
+        Self {
+            effective_type: field("effectiveType").and_then(|value| value.as_string()),
+            downlink: field("downlink").and_then(|value| value.as_f64()),
+            rtt: field("rtt").and_then(|value| value.as_f64()),
+            save_data: field("saveData")
+                .and_then(|value| value.as_bool())
+                .unwrap_or(false),
+        }
+    }
+}
+
+/// Return value for the use_network_status hook
+#[derive(Debug, Clone, Copy)]
+pub struct UseNetworkStatusReturn {
+    /// Whether the browser has a network connection
+    pub is_online: Signal<bool>,
+    /// Connection details where the browser reports them
+    pub connection: Signal<Option<ConnectionInfo>>,
+}
+
+/// Hook for the browser's online status and connection quality
+///
+/// `is_online` follows `navigator.onLine` and the window's `online` and
+/// `offline` events. It is true during server-side rendering and until
+/// mounted, so nothing is disabled before the browser has had its say.
+/// Being online only means a network is connected, not that your server is
+/// reachable.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_core::use_network_status;
+///
+/// #[component]
+/// pub fn CheckoutForm() -> impl IntoView {
+///     let network = use_network_status();
+///     let offline = move || !network.is_online.get();
+///
+///     view! {
+///         <p role="status" hidden=move || !offline()>"You are offline. Changes will not be saved."</p>
+///         <form>
+///             <button type="submit" disabled=offline>"Place order"</button>
+///         </form>
+///     }
+/// }
+/// ```
+pub fn use_network_status() -> UseNetworkStatusReturn {
+    let is_online = RwSignal::new(true);
+    let connection = RwSignal::new(None);
+
+    let read_connection =
+        move || connection.set(network_connection().map(|c| ConnectionInfo::from_js(&c)));
+
+    Effect::new(move |_| {
+        if let Some(window) = web_sys::window() {
+            is_online.set(window.navigator().on_line());
+        }
+        read_connection();
+    });
+
+    let window_target = || web_sys::window().map(Into::into);
+    use_event_listener(window_target, "online", move |_: web_sys::Event| {
+        is_online.set(true);
+        read_connection();
+    });
+    use_event_listener(window_target, "offline", move |_: web_sys::Event| {
+        is_online.set(false)
+    });
+    use_event_listener(
+        || network_connection().map(EventTarget::from),
+        "change",
+        move |_: web_sys::Event| read_connection(),
+    );
+
+    UseNetworkStatusReturn {
+        is_online: is_online.into(),
+        connection: connection.into(),
+    }
+}
+
+/// `navigator.connection`, which web-sys doesn't expose
+fn network_connection() -> Option<JsValue> {
+    let navigator = web_sys::window()?.navigator();
+    js_sys::Reflect::get(&navigator, &JsValue::from_str("connection"))
+        .ok()
+        .filter(|connection| connection.is_object())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_constrained_connection() {
+        let fast = ConnectionInfo {
+            effective_type: Some("4g".to_string()),
+            downlink: Some(10.0),
+            rtt: Some(50.0),
+            save_data: false,
+        };
+        assert!(!fast.is_constrained());
+        assert!(ConnectionInfo {
+            effective_type: Some("slow-2g".to_string()),
+            ..fast.clone()
+        }
+        .is_constrained());
+        assert!(ConnectionInfo {
+            save_data: true,
+            ..fast
+        }
+        .is_constrained());
+        assert!(!ConnectionInfo::default().is_constrained());
+    }
+}
@@ -1,42 +1,48 @@
-use leptos_use::{use_event_listener, UseEventListenerReturn};
+use leptos::html::ElementType;
+use leptos::prelude::*;
+use std::rc::Rc;
 use wasm_bindgen::JsCast;
-use web_sys::{Element, Event, MouseEvent, TouchEvent, EventTarget};
+use web_sys::{Element, Event, Node};
+
+use super::use_event_listener::{document_target, use_event_listener, EventListenerHandle};
+
+/// DOM events that count as a click for [`use_outside_click`]
+pub type OutsideClickEvents = Vec<&'static str>;
 
 /// Hook for detecting clicks outside a target element
-/// 
+///
 /// This hook is essential for closing overlays, dropdowns, and other components
-/// when the user clicks outside of them.
-/// 
+/// when the user clicks outside of them. Nothing happens while the target is
+/// not mounted.
+///
 /// # Arguments
-/// 
+///
 /// * `target` - The element to detect outside clicks for
 /// * `handler` - Callback function to execute when outside click is detected
 /// * `events` - Optional list of events to listen for (defaults to mousedown and touchstart)
-/// 
+///
 /// # Example
-/// 
-/// ```rust
+///
+/// ```rust,no_run
+/// use leptos::html;
+/// use leptos::prelude::*;
 /// use radix_leptos_core::use_outside_click;
-/// 
+///
 /// #[component]
 /// pub fn Dropdown() -> impl IntoView {
-///     let (open, setopen) = create_signal(false);
-///     let dropdown_ref = create_node_ref::<web_sys::Element>();
-///     
+///     let open = RwSignal::new(false);
+///     let dropdown_ref = NodeRef::<html::Div>::new();
+///
 ///     // Close dropdown when clicking outside
-///     use_outside_click(
-///         dropdown_ref,
-///         move || setopen.set(false),
-///         None,
-///     );
-///     
+///     use_outside_click(dropdown_ref, move || open.set(false), None);
+///
 ///     view! {
 ///         <div>
-///             <button on:click=move |_| setopen.set(!open.get())>
+///             <button on:click=move |_| open.update(|open| *open = !*open)>
 ///                 "Toggle Dropdown"
 ///             </button>
 ///             <Show when=move || open.get()>
-///                 <div ref=dropdown_ref class="dropdown-content">
+///                 <div node_ref=dropdown_ref class="dropdown-content">
 ///                     "Dropdown content"
 ///                 </div>
 ///             </Show>
@@ -44,39 +50,37 @@ use web_sys::{Element, Event, MouseEvent, TouchEvent, EventTarget};
 ///     }
 /// }
 /// ```
-pub fn use_outside_click<F>(
-    target: NodeRef<Element>,
+pub fn use_outside_click<E, F>(
+    target: NodeRef<E>,
     handler: F,
-    events: Option<Vec<&'static str>>,
-) -> Vec<UseEventListenerReturn>
+    events: Option<OutsideClickEvents>,
+) -> Vec<EventListenerHandle>
 where
-    F: Fn() + Clone + 'static,
+    E: ElementType + 'static,
+    E::Output: JsCast + Clone + 'static,
+    F: Fn() + 'static,
 {
-    let events = events.unwrap_or_else(|| ["mousedown", "touchstart"]);
-    let document = web_sys::window()
-        .unwrap()
-        .document()
-        .unwrap();
+    let events = events.unwrap_or_else(|| vec!["mousedown", "touchstart"]);
+    let handler = Rc::new(handler);
 
     events
         .into_iter()
         .map(|event_type| {
-            let handler_clone = handler.clone();
-            
+            let handler = Rc::clone(&handler);
             use_event_listener(
-                document.clone().unchecked_into::<EventTarget>(),
+                move || target.with(Option::is_some).then(document_target).flatten(),
                 event_type,
                 move |event: Event| {
-                    // Get the target element
-                    if let Some(target_element) = target.get() {
-                        // Check if click target is outside our element
-                        if let Some(event_target) = event.target() {
-                            if let Ok(clicked_element) = event_target.dyn_into::<Element>() {
-                                // Check if clicked element is contained within our target
-                                if !target_element.contains(Some(&clicked_element)) {
-                                    handler_clone();
-                                }
-                            }
+                    let Some(target_element) = target.get_untracked() else {
+                        return;
+                    };
+                    let target_element: &Element = target_element.unchecked_ref();
+                    let clicked = event
+                        .target()
+                        .and_then(|target| target.dyn_into::<Node>().ok());
+                    if let Some(clicked) = clicked {
+                        if !target_element.contains(Some(&clicked)) {
+                            handler();
                         }
                     }
                 },
@@ -86,29 +90,31 @@ where
 }
 
 /// Hook for detecting outside clicks with conditional execution
-/// 
+///
 /// This variant only executes the handler when a condition is met,
 /// useful for components that should only respond to outside clicks when active.
-/// 
+///
 /// # Arguments
-/// 
+///
 /// * `target` - The element to detect outside clicks for
 /// * `handler` - Callback function to execute when outside click is detected
 /// * `should_handle` - Signal that determines whether to handle the event
 /// * `events` - Optional list of events to listen for
-pub fn use_outside_click_conditional<F>(
-    target: NodeRef<Element>,
+pub fn use_outside_click_conditional<E, F>(
+    target: NodeRef<E>,
     handler: F,
     should_handle: Signal<bool>,
-    events: Option<Vec<&'static str>>,
-) -> Vec<UseEventListenerReturn>
+    events: Option<OutsideClickEvents>,
+) -> Vec<EventListenerHandle>
 where
-    F: Fn() + Clone + 'static,
+    E: ElementType + 'static,
+    E::Output: JsCast + Clone + 'static,
+    F: Fn() + 'static,
 {
     use_outside_click(
         target,
         move || {
-            if should_handle.get() {
+            if should_handle.get_untracked() {
                 handler();
             }
         },
@@ -118,119 +124,51 @@ where
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+    use leptos::html;
     use wasm_bindgen_test::*;
-    use web_sys::HtmlElement;
-    
+
     wasm_bindgen_test_configure!(run_in_browser);
-    
-    #[wasm_bindgen_test]
-    fn test_outside_click_detection() {
-        run_test(|cx| {
-            let (clicked_outside, set_clicked_outside) = create_signal(cx, false);
-            let target_ref = create_node_ref::<web_sys::Element>();
-            
-            // Create target element
-            let target = web_sys::window()
-                .unwrap()
-                .document()
-                .unwrap()
-                .create_element("div")
-                .unwrap();
-            target.set_id("target");
-            
-            web_sys::window()
-                .unwrap()
-                .document()
-                .unwrap()
-                .body()
-                .unwrap()
-                .append_child(&target)
-                .unwrap();
-            
-            // Set up outside click handler
-            let _listeners = use_outside_click(
-                target_ref,
-                move || set_clicked_outside.set(true),
-                None,
-            );
-            
-            // Simulate click outside target
-            let outside_element = web_sys::window()
-                .unwrap()
-                .document()
-                .unwrap()
-                .create_element("button")
-                .unwrap();
-            
-            web_sys::window()
-                .unwrap()
-                .document()
-                .unwrap()
-                .body()
-                .unwrap()
-                .append_child(&outside_element)
-                .unwrap();
-            
-            // Simulate mouse click
-            let event = web_sys::MouseEvent::new("mousedown").unwrap();
-            outside_element.dispatch_event(&event).unwrap();
-            
-            assert!(clicked_outside.get());
-        });
+
+    fn mousedown_on(element: &Element) {
+        let init = web_sys::EventInit::new();
+        init.set_bubbles(true);
+        let event = Event::new_with_event_init_dict("mousedown", &init).unwrap();
+        element.dispatch_event(&event).unwrap();
     }
-    
+
     #[wasm_bindgen_test]
-    fn test_inside_click_ignored() {
-        run_test(|cx| {
-            let (clicked_outside, set_clicked_outside) = create_signal(cx, false);
-            let target_ref = create_node_ref::<web_sys::Element>();
-            
-            // Create target element with child
-            let target = web_sys::window()
-                .unwrap()
-                .document()
-                .unwrap()
-                .create_element("div")
-                .unwrap();
-            
-            let child = web_sys::window()
-                .unwrap()
-                .document()
-                .unwrap()
-                .create_element("button")
-                .unwrap();
-            
-            target.append_child(&child).unwrap();
-            
-            web_sys::window()
-                .unwrap()
-                .document()
-                .unwrap()
-                .body()
-                .unwrap()
-                .append_child(&target)
-                .unwrap();
-            
-            // Set up outside click handler
-            let _listeners = use_outside_click(
+    async fn test_outside_click_detection() {
+        let clicked_outside = RwSignal::new(0);
+        let target_ref = NodeRef::<html::Div>::new();
+        mount_to_body(move || {
+            use_outside_click(
                 target_ref,
-                move || set_clicked_outside.set(true),
+                move || clicked_outside.update(|n| *n += 1),
                 None,
             );
-            
-            // Simulate click on child (should not trigger)
-            let event = web_sys::MouseEvent::new("mousedown").unwrap();
-            child.dispatch_event(&event).unwrap();
-            
-            assert!(!clicked_outside.get());
-        });
-    }
-    
-    fn run_test<F>(f: F) where F: FnOnce(Scope) {
-        let rt = tokio::runtime::Runtime::new().unwrap();
-        rt.block_on(async {
-            let _ = create_runtime();
-            run_scope(create_runtime(), f);
+            view! {
+                <div node_ref=target_ref>
+                    <button id="outside-click-inside">"Inside"</button>
+                </div>
+                <button id="outside-click-outside">"Outside"</button>
+            }
         });
+        leptos::task::tick().await;
+
+        // Clicks inside the target, including on its children, are ignored
+        mousedown_on(
+            &document()
+                .get_element_by_id("outside-click-inside")
+                .unwrap(),
+        );
+        assert_eq!(clicked_outside.get_untracked(), 0);
+
+        mousedown_on(
+            &document()
+                .get_element_by_id("outside-click-outside")
+                .unwrap(),
+        );
+        assert_eq!(clicked_outside.get_untracked(), 1);
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,72 @@
+use leptos::prelude::*;
+
+use super::use_media_query::use_media_query;
+
+/// Media query matching when the user asked to minimise non-essential motion
+pub const REDUCED_MOTION_QUERY: &str = "(prefers-reduced-motion: reduce)";
+
+/// Hook for whether the user prefers reduced motion
+///
+/// Follows the system setting live, so animations can stop as soon as it is
+/// turned on. Like [`use_media_query`] it is `false` on the server and until
+/// mounted.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_core::use_prefers_reduced_motion;
+///
+/// #[component]
+/// pub fn Spinner() -> impl IntoView {
+///     let reduced_motion = use_prefers_reduced_motion();
+///
+///     view! {
+///         <span class="spinner" class:spinner--static=move || reduced_motion.get() role="status">
+///             "Loading"
+///         </span>
+///     }
+/// }
+/// ```
+pub fn use_prefers_reduced_motion() -> Signal<bool> {
+    use_media_query(REDUCED_MOTION_QUERY.to_string())
+}
+
+/// Whether the user prefers reduced motion right now, for one-off checks in
+/// event handlers
+pub fn prefers_reduced_motion() -> bool {
+    web_sys::window()
+        .and_then(|window| window.match_media(REDUCED_MOTION_QUERY).ok())
+        .flatten()
+        .is_some_and(|query| query.matches())
+}
+
+/// Reduced motion turned on for a subtree by the application, on top of the
+/// user's own setting
+#[derive(Debug, Clone, Copy)]
+pub struct ReducedMotionContext {
+    pub reduce: Signal<bool>,
+}
+
+/// Turn off non-essential animation for everything below, e.g. from an
+/// in-app accessibility setting
+///
+/// Nested calls can only add to an outer one: motion reduced further up
+/// stays reduced.
+pub fn provide_reduced_motion(reduce: Signal<bool>) {
+    let outer = use_context::<ReducedMotionContext>();
+    let reduce =
+        Signal::derive(move || reduce.get() || outer.is_some_and(|outer| outer.reduce.get()));
+    provide_context(ReducedMotionContext { reduce });
+}
+
+/// Hook for whether to drop non-essential animation
+///
+/// True while the user prefers reduced motion or a [`provide_reduced_motion`]
+/// above turned it on. Animated components check this rather than
+/// [`use_prefers_reduced_motion`] so both switches reach them.
+pub fn use_reduced_motion() -> Signal<bool> {
+    let prefers = use_prefers_reduced_motion();
+    let provided = use_context::<ReducedMotionContext>();
+    Signal::derive(move || prefers.get() || provided.is_some_and(|provided| provided.reduce.get()))
+}
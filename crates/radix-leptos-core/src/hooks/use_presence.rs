@@ -0,0 +1,277 @@
+use std::time::Duration;
+
+use leptos::html::ElementType;
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, EventTarget};
+
+use super::use_event_listener::use_event_listener;
+use super::use_prefers_reduced_motion::use_reduced_motion;
+
+type ElementSignal = Signal<Option<Element>, LocalStorage>;
+
+/// Extra time before unmounting when the end event never arrives, e.g.
+/// because the animation was cancelled
+const EXIT_FALLBACK_SLACK: Duration = Duration::from_millis(50);
+
+/// Whether content is shown or on its way out, for `data-state`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PresenceState {
+    Open,
+    Closed,
+}
+
+impl PresenceState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PresenceState::Open => "open",
+            PresenceState::Closed => "closed",
+        }
+    }
+}
+
+/// Return value for the use_presence hook
+#[derive(Debug, Clone, Copy)]
+pub struct UsePresenceReturn {
+    /// Whether the content should be mounted, which stays true until its
+    /// exit animation has finished
+    pub is_present: Signal<bool>,
+    /// `Closed` as soon as the content should leave, so CSS can start the
+    /// exit animation through `data-state="closed"`
+    pub state: Signal<PresenceState>,
+    set_present: RwSignal<bool>,
+    set_state: RwSignal<PresenceState>,
+    target: ElementSignal,
+    timer: StoredValue<Option<TimeoutHandle>>,
+    reduced_motion: Signal<bool>,
+}
+
+impl UsePresenceReturn {
+    /// Unmount if the content is closing
+    ///
+    /// The hook listens for `animationend` and `transitionend` on the target
+    /// itself; call this when the animation runs somewhere the hook cannot
+    /// see, such as a child rendered into a portal.
+    pub fn on_animation_end(&self) {
+        if self.set_state.try_get_untracked() == Some(PresenceState::Closed) {
+            self.clear_timer();
+            self.set_present.try_set(false);
+        }
+    }
+
+    /// Wait for the exit animation the closed state started, if any
+    fn exit(&self) {
+        if self.set_state.try_get_untracked() != Some(PresenceState::Closed) {
+            return;
+        }
+        let duration = self
+            .target
+            .try_get_untracked()
+            .flatten()
+            .and_then(|element| MotionStyle::of(&element))
+            .and_then(|style| style.exit_duration());
+        match duration {
+            Some(duration) if !self.reduced_motion.try_get_untracked().unwrap_or(false) => {
+                self.clear_timer();
+                let this = *self;
+                let timer = set_timeout_with_handle(
+                    move || this.on_animation_end(),
+                    duration + EXIT_FALLBACK_SLACK,
+                );
+                self.timer.try_set_value(timer.ok());
+            }
+            _ => self.on_animation_end(),
+        }
+    }
+
+    fn clear_timer(&self) {
+        if let Some(Some(timer)) = self.timer.try_update_value(Option::take) {
+            timer.clear();
+        }
+    }
+}
+
+/// Hook for keeping content mounted while it animates out
+///
+/// When `present` turns false, `state` switches to `Closed` straight away
+/// but `is_present` only follows once the CSS animation or transition on the
+/// element behind `target` has ended. Content without an exit animation, and
+/// every exit while [`use_reduced_motion`] is on, unmounts on the next
+/// frame. Render the content while `is_present` is true and put `state` in
+/// its `data-state` attribute.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::html;
+/// use leptos::prelude::*;
+/// use radix_leptos_core::use_presence;
+///
+/// #[component]
+/// pub fn Notice(open: Signal<bool>) -> impl IntoView {
+///     let notice_ref = NodeRef::<html::Div>::new();
+///     let presence = use_presence(open, notice_ref);
+///
+///     view! {
+///         <Show when=move || presence.is_present.get()>
+///             <div node_ref=notice_ref class="notice" data-state=move || presence.state.get().as_str()>
+///                 "Saved"
+///             </div>
+///         </Show>
+///     }
+/// }
+/// ```
+pub fn use_presence<E>(present: impl Into<Signal<bool>>, target: NodeRef<E>) -> UsePresenceReturn
+where
+    E: ElementType + 'static,
+    E::Output: JsCast + Clone + 'static,
+{
+    let present = present.into();
+    let initially_present = present.get_untracked();
+    let set_present = RwSignal::new(initially_present);
+    let set_state = RwSignal::new(if initially_present {
+        PresenceState::Open
+    } else {
+        PresenceState::Closed
+    });
+    let element = Signal::derive_local(move || {
+        target
+            .get()
+            .map(|element| element.unchecked_into::<Element>())
+    });
+    let presence = UsePresenceReturn {
+        is_present: set_present.into(),
+        state: set_state.into(),
+        set_present,
+        set_state,
+        target: element,
+        timer: StoredValue::new(None),
+        reduced_motion: use_reduced_motion(),
+    };
+
+    Effect::new(move |_| {
+        if present.get() {
+            presence.clear_timer();
+            set_state.set(PresenceState::Open);
+            set_present.set(true);
+        } else if set_present.get_untracked() {
+            set_state.set(PresenceState::Closed);
+            // The closed styles apply on the next frame
+            request_animation_frame(move || presence.exit());
+        }
+    });
+    on_cleanup(move || presence.clear_timer());
+
+    for event in ["animationend", "transitionend"] {
+        use_event_listener(
+            move || element.get().map(Into::into),
+            event,
+            move |event: web_sys::Event| {
+                // Ignore animations of children bubbling up
+                let own = event.target().zip(element.get_untracked());
+                if own.is_some_and(|(target, element)| target == EventTarget::from(element)) {
+                    presence.on_animation_end();
+                }
+            },
+        );
+    }
+
+    presence
+}
+
+/// Computed animation and transition timing of an element
+#[derive(Debug, Clone, PartialEq, Default)]
+struct MotionStyle {
+    animation_name: String,
+    animation_duration: String,
+    animation_delay: String,
+    transition_duration: String,
+    transition_delay: String,
+}
+
+impl MotionStyle {
+    fn of(element: &Element) -> Option<Self> {
+        let style = web_sys::window()?.get_computed_style(element).ok()??;
+        let property = |name: &str| style.get_property_value(name).unwrap_or_default();
+        Some(Self {
+            animation_name: property("animation-name"),
+            animation_duration: property("animation-duration"),
+            animation_delay: property("animation-delay"),
+            transition_duration: property("transition-duration"),
+            transition_delay: property("transition-delay"),
+        })
+    }
+
+    /// How long the longest animation or transition runs, `None` when there
+    /// is nothing to wait for
+    fn exit_duration(&self) -> Option<Duration> {
+        let animates = self
+            .animation_name
+            .split(',')
+            .any(|name| !matches!(name.trim(), "" | "none"));
+        let animation = if animates {
+            longest_timing(&self.animation_duration, &self.animation_delay)
+        } else {
+            0.0
+        };
+        let transition = longest_timing(&self.transition_duration, &self.transition_delay);
+        let longest = animation.max(transition);
+        (longest > 0.0).then(|| Duration::from_secs_f64(longest / 1000.0))
+    }
+}
+
+/// Longest duration plus delay in milliseconds; CSS repeats the shorter list
+fn longest_timing(durations: &str, delays: &str) -> f64 {
+    let durations: Vec<f64> = durations.split(',').map(parse_css_time).collect();
+    let delays: Vec<f64> = delays.split(',').map(parse_css_time).collect();
+    durations
+        .iter()
+        .enumerate()
+        .filter(|(_, duration)| **duration > 0.0)
+        .map(|(i, duration)| duration + delays[i % delays.len()])
+        .fold(0.0, f64::max)
+}
+
+/// A CSS `<time>` such as `150ms` or `0.2s` in milliseconds
+fn parse_css_time(time: &str) -> f64 {
+    let time = time.trim();
+    let millis = if let Some(ms) = time.strip_suffix("ms") {
+        ms.parse().ok()
+    } else {
+        time.strip_suffix('s')
+            .and_then(|s| s.parse::<f64>().ok())
+            .map(|s| s * 1000.0)
+    };
+    millis.unwrap_or(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exit_duration() {
+        assert_eq!(MotionStyle::default().exit_duration(), None);
+        let fade_out = MotionStyle {
+            animation_name: "fade-out".to_string(),
+            animation_duration: "0.2s".to_string(),
+            animation_delay: "0s".to_string(),
+            transition_duration: "0s".to_string(),
+            transition_delay: "0s".to_string(),
+        };
+        assert_eq!(fade_out.exit_duration(), Some(Duration::from_millis(200)));
+        // A duration without an animation name animates nothing
+        let unnamed = MotionStyle {
+            animation_name: "none".to_string(),
+            ..fade_out.clone()
+        };
+        assert_eq!(unnamed.exit_duration(), None);
+        // Delays count, and are repeated to match the durations
+        let transition = MotionStyle {
+            transition_duration: "150ms, 0.3s".to_string(),
+            transition_delay: "100ms".to_string(),
+            ..unnamed
+        };
+        assert_eq!(transition.exit_duration(), Some(Duration::from_millis(400)));
+    }
+}
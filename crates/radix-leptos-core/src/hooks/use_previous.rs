@@ -1,207 +1,207 @@
+use leptos::prelude::*;
+
+/// Current value of a signal and the one observed before it
+#[derive(Clone, PartialEq)]
+struct PreviousState<T> {
+    previous: Option<T>,
+    current: T,
+}
+
+/// Current value of a signal paired with its previous value
+pub type CurrentAndPrevious<T> = (T, Option<T>);
 
 /// Hook for tracking the previous value of a signal
-/// 
+///
 /// This hook is useful for detecting changes and implementing
-/// transition effects based on value changes.
-/// 
+/// transition effects based on value changes. The previous value is the one
+/// last observed before the current one, so read the result (or something
+/// derived from it) wherever the history matters.
+///
 /// # Example
-/// 
-/// ```rust
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
 /// use radix_leptos_core::use_previous;
-/// 
+///
 /// #[component]
 /// pub fn Counter() -> impl IntoView {
-///     let (count, set_count) = create_signal(0);
-///     let previous_count = use_previous(count.into());
-///     
+///     let count = RwSignal::new(0);
+///     let previous_count = use_previous::<i32>(count.into());
+///
 ///     let direction = move || {
 ///         match previous_count.get() {
 ///             Some(prev) if prev < count.get() => "up",
-///             Some(prev) if prev > count.get() => "down", 
+///             Some(prev) if prev > count.get() => "down",
 ///             _ => "unchanged"
 ///         }
 ///     };
-///     
+///
 ///     view! {
 ///         <div>
-///             <button on:click=move |_| set_count.update(|n| *n -= 1)>"-"</button>
+///             <button on:click=move |_| count.update(|n| *n -= 1)>"-"</button>
 ///             <span>"Count: " {count} " (" {direction} ")"</span>
-///             <button on:click=move |_| set_count.update(|n| *n += 1)>"+"</button>
+///             <button on:click=move |_| count.update(|n| *n += 1)>"+"</button>
 ///         </div>
 ///     }
 /// }
 /// ```
 pub fn use_previous<T>(signal: Signal<T>) -> Signal<Option<T>>
 where
-    T: Clone + PartialEq + 'static,
+    T: Clone + PartialEq + Send + Sync + 'static,
 {
-    let (previous, set_previous) = create_signal::<Option<T>>(None);
-    
-    create_effect(move |prev_value: Option<Option<T>>| {
-        let current_value = signal.get();
-        
-        // Update previous with the last value (from the effect's previous run)
-        if let Some(Some(last_value)) = prev_value {
-            set_previous.set(Some(last_value));
-        }
-        
-        // Return current value to be used as prev_value in next run
-        Some(current_value)
+    let state = Memo::<PreviousState<T>>::new(move |last| PreviousState {
+        previous: last.map(|last| last.current.clone()),
+        current: signal.get(),
     });
-    
-    previous.into()
+
+    Signal::derive(move || state.with(|state| state.previous.clone()))
 }
 
 /// Hook for tracking previous value with a custom comparison function
-pub fn use_previous_with_eq<T, F>(
-    signal: Signal<T>,
-    eq_fn: F,
-) -> Signal<Option<T>>
+///
+/// The previous value only moves on when `eq_fn` considers the new value
+/// different from the current one.
+pub fn use_previous_with_eq<T, F>(signal: Signal<T>, eq_fn: F) -> Signal<Option<T>>
 where
-    T: Clone + 'static,
-    F: Fn(&T, &T) -> bool + 'static,
+    T: Clone + Send + Sync + 'static,
+    F: Fn(&T, &T) -> bool + Send + Sync + 'static,
 {
-    let (previous, set_previous) = create_signal::<Option<T>>(None);
-    
-    create_effect(move |prev_value: Option<Option<T>>| {
-        let current_value = signal.get();
-        
-        // Only update if value actually changed according to custom eq function
-        let should_update = match (prev_value.as_ref().and_then(|v| v.as_ref()), previous.get_untracked().as_ref()) {
-            (Some(last), Some(stored)) => !eq_fn(last, stored),
-            (Some(_), None) => true,
-            _ => false,
-        };
-        
-        if should_update {
-            if let Some(Some(last_value)) = prev_value {
-                set_previous.set(Some(last_value));
-            }
-        }
-        
-        Some(current_value)
-    });
-    
-    previous.into()
+    let state = Memo::<PreviousState<T>>::new_with_compare(
+        move |last| {
+            let current = signal.get();
+            let previous = match last {
+                Some(last) if eq_fn(&last.current, &current) => last.previous.clone(),
+                Some(last) => Some(last.current.clone()),
+                None => None,
+            };
+            PreviousState { previous, current }
+        },
+        // Without `PartialEq`, every recomputation counts as a change
+        |_, _| true,
+    );
+
+    Signal::derive(move || state.with(|state| state.previous.clone()))
 }
 
 /// Hook for detecting when a value has changed
 pub fn use_changed<T>(signal: Signal<T>) -> Signal<bool>
 where
-    T: Clone + PartialEq + 'static,
+    T: Clone + PartialEq + Send + Sync + 'static,
 {
     let previous = use_previous(signal);
-    
-    create_memo(move |_| {
-        if let Some(prev) = previous.get() {
-            prev != signal.get()
-    }).into()
+
+    Memo::new(move |_| {
+        let current = signal.get();
+        previous.with(|previous| {
+            previous
+                .as_ref()
+                .is_some_and(|previous| *previous != current)
+        })
+    })
+    .into()
 }
 
 /// Hook for getting both current and previous values
-pub fn usecurrent_and_previous<T>(signal: Signal<T>) -> Signal<(T, Option<T>)>
+pub fn use_current_and_previous<T>(signal: Signal<T>) -> Signal<CurrentAndPrevious<T>>
 where
-    T: Clone + PartialEq + 'static,
+    T: Clone + PartialEq + Send + Sync + 'static,
 {
     let previous = use_previous(signal);
-    
-    create_memo(move |_| {
-        (signal.get(), previous.get())
-    }).into()
+
+    Memo::new(move |_| (signal.get(), previous.get())).into()
 }
 
 /// Hook for tracking multiple previous values (history)
+///
+/// Keeps the last `max_history` observed values, oldest first, including the
+/// current one.
 pub fn use_history<T>(signal: Signal<T>, max_history: usize) -> Signal<Vec<T>>
 where
-    T: Clone + PartialEq + 'static,
+    T: Clone + PartialEq + Send + Sync + 'static,
 {
-    let (history, set_history) = create_signal::<Vec<T>>(Vec::new());
-    
-    create_effect(move |_| {
-        let current_value = signal.get();
-        
-        set_history.update(|hist| {
-            hist.push(current_value);
-            
-            // Keep only the max number of history items
-            if hist.len() > max_history {
-                hist.remove(0);
-            }
-        });
+    let history: Memo<Vec<T>> = Memo::new(move |last| {
+        let mut history: Vec<T> = last.cloned().unwrap_or_default();
+        history.push(signal.get());
+        let excess = history.len().saturating_sub(max_history);
+        history.drain(..excess);
+        history
     });
-    
+
     history.into()
 }
 
 #[cfg(test)]
 mod tests {
-    
+    use super::*;
+
     #[test]
     fn test_use_previous() {
-        run_test(|cx| {
-            let (value, set_value) = create_signal(cx, 1);
-            let previous = use_previous(value.into());
-            
+        let owner = Owner::new();
+        owner.with(|| {
+            let value = RwSignal::new(1);
+            let previous = use_previous::<i32>(value.into());
+
             // Initially no previous value
             assert_eq!(previous.get(), None);
-            
-            // Update value
-            set_value.set(2);
-            
-            // Previous should now be the old value
+
+            value.set(2);
             assert_eq!(previous.get(), Some(1));
-            
-            // Update again
-            set_value.set(3);
-            
-            // Previous should be the previous update
+
+            value.set(3);
             assert_eq!(previous.get(), Some(2));
         });
     }
-    
+
+    #[test]
+    fn test_use_previous_with_eq() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let value = RwSignal::new(1.0_f64);
+            let previous =
+                use_previous_with_eq(value.into(), |a: &f64, b: &f64| (a - b).abs() < 0.5);
+
+            assert_eq!(previous.get(), None);
+            value.set(1.2);
+            assert_eq!(previous.get(), None);
+            value.set(2.0);
+            assert_eq!(previous.get(), Some(1.2));
+        });
+    }
+
     #[test]
     fn test_use_changed() {
-        run_test(|cx| {
-            let (value, set_value) = create_signal(cx, 1);
-            let changed = use_changed(value.into());
-            
+        let owner = Owner::new();
+        owner.with(|| {
+            let value = RwSignal::new(1);
+            let changed = use_changed::<i32>(value.into());
+
             // Initially not changed
-            assert_eq!(changed.get(), false);
-            
-            // Update value
-            set_value.set(2);
-            
-            // Should detect change
-            assert_eq!(changed.get(), true);
+            assert!(!changed.get());
+
+            value.set(2);
+            assert!(changed.get());
         });
     }
-    
+
     #[test]
     fn test_use_history() {
-        run_test(|cx| {
-            let (value, set_value) = create_signal(cx, 1);
-            let history = use_history(value.into(), 3);
-            
+        let owner = Owner::new();
+        owner.with(|| {
+            let value = RwSignal::new(1);
+            let history = use_history::<i32>(value.into(), 3);
+
             // Should start with just the initial value
             assert_eq!(history.get(), [1]);
-            
-            set_value.set(2);
+
+            value.set(2);
             assert_eq!(history.get(), [1, 2]);
-            
-            set_value.set(3);
+
+            value.set(3);
             assert_eq!(history.get(), [1, 2, 3]);
-            
-            set_value.set(4);
+
+            value.set(4);
             // Should maintain max history size
             assert_eq!(history.get(), [2, 3, 4]);
         });
     }
-    
-    fn run_test<F>(f: F) where F: FnOnce(Scope) {
-        let rt = tokio::runtime::Runtime::new().unwrap();
-        rt.block_on(async {
-            let _ = create_runtime();
-            run_scope(create_runtime(), f);
-        });
-    }
-}
\ No newline at end of file
+}
@@ -0,0 +1,178 @@
+use leptos::html::ElementType;
+use leptos::prelude::*;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+use web_sys::{
+    Element, ResizeObserver, ResizeObserverBoxOptions, ResizeObserverEntry, ResizeObserverOptions,
+    ResizeObserverSize,
+};
+
+type ObserverCallback = Closure<dyn Fn(js_sys::Array)>;
+type ObserverSlot = StoredValue<Option<(ResizeObserver, ObserverCallback)>, LocalStorage>;
+
+/// Which box of the element [`use_resize_observer_with_options`] measures
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResizeObserverBox {
+    /// The content area, excluding padding and border
+    #[default]
+    ContentBox,
+    /// The content area plus padding and border
+    BorderBox,
+}
+
+/// Options for [`use_resize_observer_with_options`]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct UseResizeObserverOptions {
+    pub observed_box: ResizeObserverBox,
+    /// Size reported during server-side rendering and until the element has
+    /// been measured
+    pub initial_size: (f64, f64),
+}
+
+/// Return value for the use_resize_observer hook
+#[derive(Debug, Clone, Copy)]
+pub struct UseResizeObserverReturn {
+    pub width: Signal<f64>,
+    pub height: Signal<f64>,
+}
+
+/// Hook for tracking the rendered size of an element
+///
+/// Watches the element behind `target` with a `ResizeObserver` and reports
+/// its content-box width and height in CSS pixels. Observation follows the
+/// node ref: it starts when the element mounts, moves if it is replaced and
+/// stops on cleanup. Both values are 0 until the first measurement; use
+/// [`use_resize_observer_with_options`] to measure the border box instead or
+/// to start from another size.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::html;
+/// use leptos::prelude::*;
+/// use radix_leptos_core::use_resize_observer;
+///
+/// #[component]
+/// pub fn SizeLabel() -> impl IntoView {
+///     let panel_ref = NodeRef::<html::Div>::new();
+///     let size = use_resize_observer(panel_ref);
+///
+///     view! {
+///         <div node_ref=panel_ref class="panel">
+///             {move || format!("{:.0} × {:.0}", size.width.get(), size.height.get())}
+///         </div>
+///     }
+/// }
+/// ```
+pub fn use_resize_observer<E>(target: NodeRef<E>) -> UseResizeObserverReturn
+where
+    E: ElementType + 'static,
+    E::Output: JsCast + Clone + 'static,
+{
+    use_resize_observer_with_options(target, UseResizeObserverOptions::default())
+}
+
+/// Resize observer hook with a choice of measured box and initial size
+pub fn use_resize_observer_with_options<E>(
+    target: NodeRef<E>,
+    options: UseResizeObserverOptions,
+) -> UseResizeObserverReturn
+where
+    E: ElementType + 'static,
+    E::Output: JsCast + Clone + 'static,
+{
+    let (initial_width, initial_height) = options.initial_size;
+    let width = RwSignal::new(initial_width);
+    let height = RwSignal::new(initial_height);
+    let observer: ObserverSlot = StoredValue::new_local(None);
+
+    let disconnect = move || {
+        if let Some(Some((observer, _callback))) = observer.try_update_value(Option::take) {
+            observer.disconnect();
+        }
+    };
+
+    Effect::new(move |_| {
+        let element = target
+            .get()
+            .map(|element| element.unchecked_into::<Element>());
+        disconnect();
+        let Some(element) = element else {
+            return;
+        };
+
+        let callback = ObserverCallback::new(move |entries: js_sys::Array| {
+            // Only the latest entry matters when several resizes were batched
+            let Some(entry) = entries
+                .iter()
+                .last()
+                .and_then(|entry| entry.dyn_into::<ResizeObserverEntry>().ok())
+            else {
+                return;
+            };
+            let (new_width, new_height) = entry_size(&entry, options.observed_box);
+            width.set(new_width);
+            height.set(new_height);
+        });
+        let Ok(resize_observer) = ResizeObserver::new(callback.as_ref().unchecked_ref()) else {
+            return;
+        };
+        let observe_options = ResizeObserverOptions::new();
+        observe_options.set_box(match options.observed_box {
+            ResizeObserverBox::ContentBox => ResizeObserverBoxOptions::ContentBox,
+            ResizeObserverBox::BorderBox => ResizeObserverBoxOptions::BorderBox,
+        });
+        resize_observer.observe_with_options(&element, &observe_options);
+        observer.set_value(Some((resize_observer, callback)));
+    });
+    on_cleanup(disconnect);
+
+    UseResizeObserverReturn {
+        width: width.into(),
+        height: height.into(),
+    }
+}
+
+fn entry_size(entry: &ResizeObserverEntry, observed_box: ResizeObserverBox) -> (f64, f64) {
+    let sizes = match observed_box {
+        ResizeObserverBox::ContentBox => entry.content_box_size(),
+        ResizeObserverBox::BorderBox => entry.border_box_size(),
+    };
+    // Sizes are per fragment; a plain block element has exactly one
+    match sizes.get(0).dyn_into::<ResizeObserverSize>() {
+        Ok(size) => (size.inline_size(), size.block_size()),
+        Err(_) => {
+            let rect = entry.content_rect();
+            (rect.width(), rect.height())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptos::html;
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    async fn test_measures_element() {
+        let size = RwSignal::new(None);
+        mount_to_body(move || {
+            let panel_ref = NodeRef::<html::Div>::new();
+            let options = UseResizeObserverOptions {
+                observed_box: ResizeObserverBox::BorderBox,
+                ..Default::default()
+            };
+            size.set(Some(use_resize_observer_with_options(panel_ref, options)));
+            view! { <div node_ref=panel_ref style="width: 120px; height: 40px; padding: 5px;"></div> }
+        });
+        // The observer reports after layout, on the next animation frame
+        gloo_timers::future::TimeoutFuture::new(50).await;
+
+        let size = size.get_untracked().unwrap();
+        assert_eq!(size.width.get_untracked(), 130.0);
+        assert_eq!(size.height.get_untracked(), 50.0);
+    }
+}
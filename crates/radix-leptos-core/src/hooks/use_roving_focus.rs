@@ -0,0 +1,295 @@
+use leptos::html::ElementType;
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, FocusEvent, HtmlElement, KeyboardEvent};
+
+use crate::context::Direction;
+
+/// Attribute marking the elements that share a group's tab stop
+pub const ROVING_FOCUS_ITEM: &str = "data-roving-focus-item";
+
+/// Arrow keys that move focus within a roving focus group
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RovingOrientation {
+    #[default]
+    Horizontal,
+    Vertical,
+    Both,
+}
+
+/// Options for [`use_roving_focus`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RovingFocusOptions {
+    pub orientation: RovingOrientation,
+    /// Wrap from the last item to the first
+    pub looping: bool,
+    /// Reading direction; in right-to-left layouts ArrowLeft moves forward
+    pub dir: Direction,
+}
+
+impl Default for RovingFocusOptions {
+    fn default() -> Self {
+        Self {
+            orientation: RovingOrientation::default(),
+            looping: true,
+            dir: Direction::Ltr,
+        }
+    }
+}
+
+/// Return value for the use_roving_focus hook
+#[derive(Debug, Clone, Copy)]
+pub struct UseRovingFocusReturn {
+    /// Position of the item holding the group's tab stop among the enabled items
+    pub active_index: Signal<usize>,
+    /// Handler for `on:keydown` on the container
+    pub on_keydown: Callback<KeyboardEvent>,
+    /// Handler for `on:focusin` on the container
+    pub on_focusin: Callback<FocusEvent>,
+    /// Move focus (and the tab stop) to the enabled item at an index
+    pub focus_item: Callback<usize>,
+}
+
+/// Hook for managing a roving tabindex inside a container
+///
+/// Items are the descendants of `container` carrying [`ROVING_FOCUS_ITEM`];
+/// disabled ones are skipped. Exactly one item is in the tab order, so Tab
+/// enters and leaves the group in one step, while the arrow keys (following
+/// the orientation and reading direction), Home and End move focus between
+/// items. The last focused item stays the group's tab stop.
+///
+/// The handlers are returned rather than attached so they run after the
+/// items' own: keys an item already handled (e.g. a menu trigger opening on
+/// ArrowDown) are left alone.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::html;
+/// use leptos::prelude::*;
+/// use radix_leptos_core::{use_roving_focus, RovingFocusOptions};
+///
+/// #[component]
+/// pub fn Toolbar() -> impl IntoView {
+///     let toolbar_ref = NodeRef::<html::Div>::new();
+///     let roving = use_roving_focus(toolbar_ref, RovingFocusOptions::default());
+///
+///     view! {
+///         <div
+///             node_ref=toolbar_ref
+///             role="toolbar"
+///             on:keydown=move |ev| roving.on_keydown.run(ev)
+///             on:focusin=move |ev| roving.on_focusin.run(ev)
+///         >
+///             <button data-roving-focus-item="" tabindex="-1">"Bold"</button>
+///             <button data-roving-focus-item="" tabindex="-1">"Italic"</button>
+///             <button data-roving-focus-item="" tabindex="-1">"Underline"</button>
+///         </div>
+///     }
+/// }
+/// ```
+pub fn use_roving_focus<E>(
+    container: NodeRef<E>,
+    options: RovingFocusOptions,
+) -> UseRovingFocusReturn
+where
+    E: ElementType + 'static,
+    E::Output: JsCast + Clone + 'static,
+{
+    let active_index = RwSignal::new(0);
+    let container_element = move || {
+        container
+            .get()
+            .map(|container| container.unchecked_into::<Element>())
+    };
+    let move_tab_stop = move |items: &[HtmlElement], index: usize| {
+        set_tab_stop(items, index);
+        active_index.set(index);
+    };
+
+    // Once mounted, keep the item rendered as the tab stop or promote the first
+    Effect::new(move |_| {
+        if let Some(container) = container_element() {
+            let items = roving_items(&container);
+            let active = items
+                .iter()
+                .position(|item| item.tab_index() == 0)
+                .unwrap_or(0);
+            move_tab_stop(&items, active);
+        }
+    });
+
+    let on_keydown = Callback::new(move |event: KeyboardEvent| {
+        if event.default_prevented() || event.alt_key() || event.ctrl_key() || event.meta_key() {
+            return;
+        }
+        let Some(container) = untrack(container_element) else {
+            return;
+        };
+        let items = roving_items(&container);
+        let Some(current) = focused_item(&items) else {
+            return;
+        };
+        let Some(target) = roving_target(current, items.len(), &event.key(), &options) else {
+            return;
+        };
+        event.prevent_default();
+        move_tab_stop(&items, target);
+        let _ = items[target].focus();
+    });
+
+    let on_focusin = Callback::new(move |_: FocusEvent| {
+        if let Some(container) = untrack(container_element) {
+            let items = roving_items(&container);
+            if let Some(current) = focused_item(&items) {
+                move_tab_stop(&items, current);
+            }
+        }
+    });
+
+    let focus_item = Callback::new(move |index: usize| {
+        if let Some(container) = untrack(container_element) {
+            let items = roving_items(&container);
+            if let Some(item) = items.get(index) {
+                let _ = item.focus();
+                move_tab_stop(&items, index);
+            }
+        }
+    });
+
+    UseRovingFocusReturn {
+        active_index: active_index.into(),
+        on_keydown,
+        on_focusin,
+        focus_item,
+    }
+}
+
+/// Index focus moves to when `key` is pressed on item `current`
+pub fn roving_target(
+    current: usize,
+    count: usize,
+    key: &str,
+    options: &RovingFocusOptions,
+) -> Option<usize> {
+    if count == 0 {
+        return None;
+    }
+    let horizontal = options.orientation != RovingOrientation::Vertical;
+    let vertical = options.orientation != RovingOrientation::Horizontal;
+    let (forward_key, backward_key) = match options.dir {
+        Direction::Ltr => ("ArrowRight", "ArrowLeft"),
+        Direction::Rtl => ("ArrowLeft", "ArrowRight"),
+    };
+    let last = count - 1;
+    let target = match key {
+        "ArrowDown" if vertical => next_index(current, last, options.looping),
+        "ArrowUp" if vertical => previous_index(current, last, options.looping),
+        key if horizontal && key == forward_key => next_index(current, last, options.looping),
+        key if horizontal && key == backward_key => previous_index(current, last, options.looping),
+        "Home" => 0,
+        "End" => last,
+        _ => return None,
+    };
+    (target != current).then_some(target)
+}
+
+fn next_index(current: usize, last: usize, looping: bool) -> usize {
+    match (current >= last, looping) {
+        (true, true) => 0,
+        (true, false) => last,
+        (false, _) => current + 1,
+    }
+}
+
+fn previous_index(current: usize, last: usize, looping: bool) -> usize {
+    match (current == 0, looping) {
+        (true, true) => last,
+        (true, false) => 0,
+        (false, _) => current - 1,
+    }
+}
+
+/// Enabled items of the group, in document order
+fn roving_items(container: &Element) -> Vec<HtmlElement> {
+    let Ok(nodes) = container.query_selector_all(&format!("[{}]", ROVING_FOCUS_ITEM)) else {
+        return Vec::new();
+    };
+    (0..nodes.length())
+        .filter_map(|index| nodes.item(index))
+        .filter_map(|node| node.dyn_into::<HtmlElement>().ok())
+        .filter(|item| {
+            !item.has_attribute("disabled")
+                && item.get_attribute("aria-disabled").as_deref() != Some("true")
+        })
+        .collect()
+}
+
+fn focused_item(items: &[HtmlElement]) -> Option<usize> {
+    let active = web_sys::window()?.document()?.active_element()?;
+    items
+        .iter()
+        .position(|item| item.contains(Some(active.unchecked_ref())))
+}
+
+fn set_tab_stop(items: &[HtmlElement], active: usize) {
+    for (index, item) in items.iter().enumerate() {
+        item.set_tab_index(if index == active { 0 } else { -1 });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(orientation: RovingOrientation, looping: bool) -> RovingFocusOptions {
+        RovingFocusOptions {
+            orientation,
+            looping,
+            dir: Direction::Ltr,
+        }
+    }
+
+    #[test]
+    fn test_horizontal_group_ignores_vertical_arrows() {
+        let h = options(RovingOrientation::Horizontal, true);
+        assert_eq!(roving_target(0, 3, "ArrowRight", &h), Some(1));
+        assert_eq!(roving_target(1, 3, "ArrowLeft", &h), Some(0));
+        assert_eq!(roving_target(1, 3, "ArrowDown", &h), None);
+        assert_eq!(roving_target(1, 3, "ArrowUp", &h), None);
+    }
+
+    #[test]
+    fn test_vertical_group() {
+        let v = options(RovingOrientation::Vertical, true);
+        assert_eq!(roving_target(0, 3, "ArrowDown", &v), Some(1));
+        assert_eq!(roving_target(0, 3, "ArrowRight", &v), None);
+        let both = options(RovingOrientation::Both, true);
+        assert_eq!(roving_target(1, 3, "ArrowUp", &both), Some(0));
+    }
+
+    #[test]
+    fn test_wrapping_and_ends() {
+        let looping = options(RovingOrientation::Horizontal, true);
+        let clamped = options(RovingOrientation::Horizontal, false);
+        assert_eq!(roving_target(2, 3, "ArrowRight", &looping), Some(0));
+        assert_eq!(roving_target(0, 3, "ArrowLeft", &looping), Some(2));
+        assert_eq!(roving_target(2, 3, "ArrowRight", &clamped), None);
+        assert_eq!(roving_target(0, 3, "ArrowLeft", &clamped), None);
+        assert_eq!(roving_target(1, 3, "Home", &clamped), Some(0));
+        assert_eq!(roving_target(1, 3, "End", &clamped), Some(2));
+        assert_eq!(roving_target(0, 0, "ArrowRight", &looping), None);
+    }
+
+    #[test]
+    fn test_rtl_reverses_horizontal_arrows() {
+        let rtl = RovingFocusOptions {
+            dir: Direction::Rtl,
+            ..options(RovingOrientation::Both, false)
+        };
+        assert_eq!(roving_target(0, 3, "ArrowLeft", &rtl), Some(1));
+        assert_eq!(roving_target(1, 3, "ArrowRight", &rtl), Some(0));
+        // Vertical arrows don't depend on the reading direction
+        assert_eq!(roving_target(0, 3, "ArrowDown", &rtl), Some(1));
+    }
+}
@@ -0,0 +1,355 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+
+use leptos::html::ElementType;
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, EventTarget, ScrollBehavior, ScrollToOptions};
+
+use super::use_event_listener::use_event_listener;
+use super::use_prefers_reduced_motion::use_reduced_motion;
+
+type ContainerSlot = StoredValue<Option<ScrollContainer>, LocalStorage>;
+type ScrollPosition = (f64, f64);
+
+/// Number of routes whose scroll position is remembered
+const RESTORED_ROUTES: usize = 50;
+
+/// Something that scrolls: the window or an overflowing element
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScrollContainer {
+    Window,
+    Element(Element),
+}
+
+impl ScrollContainer {
+    /// Current horizontal and vertical scroll offset in CSS pixels
+    pub fn position(&self) -> (f64, f64) {
+        match self {
+            ScrollContainer::Window => web_sys::window()
+                .map(|window| {
+                    (
+                        window.scroll_x().unwrap_or(0.0),
+                        window.scroll_y().unwrap_or(0.0),
+                    )
+                })
+                .unwrap_or((0.0, 0.0)),
+            ScrollContainer::Element(element) => (
+                f64::from(element.scroll_left()),
+                f64::from(element.scroll_top()),
+            ),
+        }
+    }
+
+    /// Scroll to an absolute offset; `None` leaves that axis where it is
+    pub fn scroll_to(&self, x: Option<f64>, y: Option<f64>, smooth: bool) {
+        let options = scroll_options(x, y, smooth);
+        match self {
+            ScrollContainer::Window => {
+                if let Some(window) = web_sys::window() {
+                    window.scroll_to_with_scroll_to_options(&options);
+                }
+            }
+            ScrollContainer::Element(element) => element.scroll_to_with_scroll_to_options(&options),
+        }
+    }
+
+    /// Scroll by a relative distance
+    pub fn scroll_by(&self, dx: f64, dy: f64, smooth: bool) {
+        let options = scroll_options(Some(dx), Some(dy), smooth);
+        match self {
+            ScrollContainer::Window => {
+                if let Some(window) = web_sys::window() {
+                    window.scroll_by_with_scroll_to_options(&options);
+                }
+            }
+            ScrollContainer::Element(element) => element.scroll_by_with_scroll_to_options(&options),
+        }
+    }
+
+    fn event_target(&self) -> Option<EventTarget> {
+        match self {
+            ScrollContainer::Window => web_sys::window().map(Into::into),
+            ScrollContainer::Element(element) => Some(element.clone().into()),
+        }
+    }
+}
+
+/// Options for [`use_scroll_container`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UseScrollOptions {
+    /// Animate `scroll_to` and friends, unless motion is reduced (see
+    /// [`use_reduced_motion`])
+    pub smooth: bool,
+}
+
+impl Default for UseScrollOptions {
+    fn default() -> Self {
+        Self { smooth: true }
+    }
+}
+
+/// Return value for the use_scroll hooks
+#[derive(Debug, Clone, Copy)]
+pub struct UseScrollReturn {
+    /// Horizontal scroll offset in CSS pixels
+    pub x: Signal<f64>,
+    /// Vertical scroll offset in CSS pixels
+    pub y: Signal<f64>,
+    container: ContainerSlot,
+    options: UseScrollOptions,
+    reduced_motion: Signal<bool>,
+}
+
+impl UseScrollReturn {
+    /// Scroll to an absolute offset; `None` leaves that axis where it is
+    pub fn scroll_to(&self, x: Option<f64>, y: Option<f64>) {
+        let smooth = self.smooth();
+        self.with_container(|container| container.scroll_to(x, y, smooth));
+    }
+
+    /// Scroll by a relative distance
+    pub fn scroll_by(&self, dx: f64, dy: f64) {
+        let smooth = self.smooth();
+        self.with_container(|container| container.scroll_by(dx, dy, smooth));
+    }
+
+    /// Scroll back to the top
+    pub fn scroll_to_top(&self) {
+        self.scroll_to(None, Some(0.0));
+    }
+
+    fn smooth(&self) -> bool {
+        self.options.smooth && !self.reduced_motion.try_get_untracked().unwrap_or(false)
+    }
+
+    fn with_container(&self, f: impl FnOnce(&ScrollContainer)) {
+        self.container.with_value(|container| {
+            if let Some(container) = container {
+                f(container);
+            }
+        });
+    }
+}
+
+/// Hook for the scroll position of an element
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::html;
+/// use leptos::prelude::*;
+/// use radix_leptos_core::use_scroll;
+///
+/// #[component]
+/// pub fn Changelog() -> impl IntoView {
+///     let list_ref = NodeRef::<html::Div>::new();
+///     let scroll = use_scroll(list_ref);
+///
+///     view! {
+///         <div node_ref=list_ref class="changelog" style="overflow: auto;">"…"</div>
+///         <button hidden=move || scroll.y.get() == 0.0 on:click=move |_| scroll.scroll_to_top()>
+///             "Back to latest"
+///         </button>
+///     }
+/// }
+/// ```
+pub fn use_scroll<E>(target: NodeRef<E>) -> UseScrollReturn
+where
+    E: ElementType + 'static,
+    E::Output: JsCast + Clone + 'static,
+{
+    use_scroll_container(
+        move || {
+            target
+                .get()
+                .map(|element| ScrollContainer::Element(element.unchecked_into()))
+        },
+        UseScrollOptions::default(),
+    )
+}
+
+/// Hook for the scroll position of the window, see [`use_scroll`]
+pub fn use_window_scroll() -> UseScrollReturn {
+    use_scroll_container(
+        || Some(ScrollContainer::Window),
+        UseScrollOptions::default(),
+    )
+}
+
+/// Scroll hook for a container picked at mount time, e.g. by selector
+///
+/// `container` is re-evaluated whenever the signals it reads change, like the
+/// target of [`use_event_listener`]. Positions stay 0 during server-side
+/// rendering.
+pub fn use_scroll_container(
+    container: impl Fn() -> Option<ScrollContainer> + 'static,
+    options: UseScrollOptions,
+) -> UseScrollReturn {
+    let x = RwSignal::new(0.0);
+    let y = RwSignal::new(0.0);
+    let current: ContainerSlot = StoredValue::new_local(None);
+
+    let update = move || {
+        if let Some((new_x, new_y)) = current
+            .try_with_value(|container| container.as_ref().map(ScrollContainer::position))
+            .flatten()
+        {
+            x.try_set(new_x);
+            y.try_set(new_y);
+        }
+    };
+
+    use_event_listener(
+        move || {
+            let container = container();
+            let target = container.as_ref().and_then(ScrollContainer::event_target);
+            current.set_value(container);
+            update();
+            target
+        },
+        "scroll",
+        move |_: web_sys::Event| update(),
+    );
+
+    UseScrollReturn {
+        x: x.into(),
+        y: y.into(),
+        container: current,
+        options,
+        reduced_motion: use_reduced_motion(),
+    }
+}
+
+/// Hook that remembers the window's scroll position per route
+///
+/// Call it once near the router with a key identifying the current route,
+/// such as the location's path. Leaving a route stores where it was
+/// scrolled to; coming back scrolls there again after the next frame, and
+/// routes seen for the first time start at the top.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_core::use_scroll_restoration;
+///
+/// #[component]
+/// pub fn Shell(path: Signal<String>, children: Children) -> impl IntoView {
+///     use_scroll_restoration(path);
+///
+///     view! { <main>{children()}</main> }
+/// }
+/// ```
+pub fn use_scroll_restoration(key: impl Into<Signal<String>>) {
+    let key = key.into();
+    let scroll = use_scroll_container(
+        || Some(ScrollContainer::Window),
+        UseScrollOptions { smooth: false },
+    );
+    // Scroll events while a new route renders belong to neither route
+    let restoring = StoredValue::new(false);
+
+    Effect::new(move |_| {
+        let position = (scroll.x.get(), scroll.y.get());
+        if !restoring.get_value() {
+            let key = key.get_untracked();
+            SCROLL_POSITIONS.with(|positions| positions.borrow_mut().save(key, position));
+        }
+    });
+
+    Effect::new(move |previous: Option<String>| {
+        let current = key.get();
+        if previous.is_some_and(|previous| previous != current) {
+            restoring.set_value(true);
+            let restore_key = current.clone();
+            request_animation_frame(move || {
+                let (x, y) =
+                    SCROLL_POSITIONS.with(|positions| positions.borrow().get(&restore_key));
+                scroll.scroll_to(Some(x), Some(y));
+                restoring.try_set_value(false);
+            });
+        }
+        current
+    });
+}
+
+#[derive(Debug)]
+struct SavedPosition {
+    key: String,
+    position: ScrollPosition,
+}
+
+/// Most recently saved scroll positions, oldest first
+#[derive(Debug)]
+struct ScrollPositions {
+    entries: VecDeque<SavedPosition>,
+    capacity: usize,
+}
+
+impl ScrollPositions {
+    fn new(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::new(),
+            capacity,
+        }
+    }
+
+    fn save(&mut self, key: String, position: ScrollPosition) {
+        self.entries.retain(|saved| saved.key != key);
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(SavedPosition { key, position });
+    }
+
+    /// Saved position of a route, the top for unknown ones
+    fn get(&self, key: &str) -> ScrollPosition {
+        self.entries
+            .iter()
+            .find(|saved| saved.key == key)
+            .map(|saved| saved.position)
+            .unwrap_or((0.0, 0.0))
+    }
+}
+
+thread_local! {
+    static SCROLL_POSITIONS: RefCell<ScrollPositions> =
+        RefCell::new(ScrollPositions::new(RESTORED_ROUTES));
+}
+
+fn scroll_options(x: Option<f64>, y: Option<f64>, smooth: bool) -> ScrollToOptions {
+    let options = ScrollToOptions::new();
+    if let Some(x) = x {
+        options.set_left(x);
+    }
+    if let Some(y) = y {
+        options.set_top(y);
+    }
+    options.set_behavior(if smooth {
+        ScrollBehavior::Smooth
+    } else {
+        ScrollBehavior::Instant
+    });
+    options
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scroll_positions() {
+        let mut positions = ScrollPositions::new(2);
+        positions.save("/docs".to_string(), (0.0, 640.0));
+        positions.save("/blog".to_string(), (0.0, 120.0));
+        assert_eq!(positions.get("/docs"), (0.0, 640.0));
+        assert_eq!(positions.get("/pricing"), (0.0, 0.0));
+
+        // Saving again refreshes a route, so the least recent one is dropped
+        positions.save("/docs".to_string(), (0.0, 700.0));
+        positions.save("/pricing".to_string(), (0.0, 50.0));
+        assert_eq!(positions.get("/docs"), (0.0, 700.0));
+        assert_eq!(positions.get("/blog"), (0.0, 0.0));
+    }
+}
@@ -0,0 +1,176 @@
+use leptos::prelude::*;
+use leptos::serde_json;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use web_sys::{Storage, StorageEvent};
+
+use super::use_event_listener::use_event_listener;
+
+/// Which Web Storage area a value is kept in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StorageKind {
+    /// Kept across sessions and shared by all tabs of the origin
+    Local,
+    /// Kept for the lifetime of the tab
+    Session,
+}
+
+impl StorageKind {
+    fn storage(self) -> Option<Storage> {
+        let window = web_sys::window()?;
+        match self {
+            StorageKind::Local => window.local_storage(),
+            StorageKind::Session => window.session_storage(),
+        }
+        .ok()
+        .flatten()
+    }
+}
+
+/// Return value for the use_local_storage and use_session_storage hooks
+pub struct UseStorageReturn<T: Send + Sync + 'static> {
+    /// The stored value; setting it writes it to storage
+    pub value: RwSignal<T>,
+    /// Delete the entry and go back to the default value
+    pub remove: Callback<()>,
+}
+
+impl<T: Send + Sync + 'static> Clone for UseStorageReturn<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Send + Sync + 'static> Copy for UseStorageReturn<T> {}
+
+/// Hook for a signal persisted in `localStorage`
+///
+/// The value is stored as JSON under `key`. It starts out as `default` on
+/// the server and during hydration and is replaced by the stored value once
+/// mounted; entries that cannot be parsed count as missing. Changes made in
+/// other tabs are picked up through the `storage` event.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_core::use_local_storage;
+///
+/// #[component]
+/// pub fn Sidebar() -> impl IntoView {
+///     let collapsed = use_local_storage("sidebar-collapsed", false).value;
+///
+///     view! {
+///         <aside data-collapsed=move || collapsed.get().to_string()>
+///             <button on:click=move |_| collapsed.update(|c| *c = !*c)>"Toggle"</button>
+///         </aside>
+///     }
+/// }
+/// ```
+pub fn use_local_storage<T>(key: impl Into<String>, default: T) -> UseStorageReturn<T>
+where
+    T: Serialize + DeserializeOwned + Clone + Send + Sync + 'static,
+{
+    use_storage(StorageKind::Local, key, default)
+}
+
+/// Hook for a signal persisted in `sessionStorage`, see [`use_local_storage`]
+pub fn use_session_storage<T>(key: impl Into<String>, default: T) -> UseStorageReturn<T>
+where
+    T: Serialize + DeserializeOwned + Clone + Send + Sync + 'static,
+{
+    use_storage(StorageKind::Session, key, default)
+}
+
+/// Hook for a signal persisted in either storage area
+pub fn use_storage<T>(kind: StorageKind, key: impl Into<String>, default: T) -> UseStorageReturn<T>
+where
+    T: Serialize + DeserializeOwned + Clone + Send + Sync + 'static,
+{
+    let key = StoredValue::new(key.into());
+    let value = RwSignal::new(default.clone());
+    let default = StoredValue::new(default);
+    // What storage holds as far as this hook knows, to skip redundant writes
+    let last_written = StoredValue::new(None::<String>);
+
+    let load = move |raw: Option<String>| {
+        last_written.set_value(raw.clone());
+        value.set(decode_stored(raw.as_deref()).unwrap_or_else(|| default.get_value()));
+    };
+
+    // Read on mount rather than during render, so hydration matches the server
+    Effect::new(move |_| {
+        let raw = kind
+            .storage()
+            .and_then(|storage| key.with_value(|key| storage.get_item(key).ok().flatten()));
+        untrack(|| load(raw));
+    });
+
+    Effect::new(move |initialized: Option<()>| {
+        let encoded = value.with(|value| serde_json::to_string(value).ok());
+        if initialized.is_none() {
+            return;
+        }
+        let (Some(encoded), Some(storage)) = (encoded, kind.storage()) else {
+            return;
+        };
+        if last_written.with_value(|last| last.as_deref() != Some(encoded.as_str())) {
+            key.with_value(|key| {
+                let _ = storage.set_item(key, &encoded);
+            });
+            last_written.set_value(Some(encoded));
+        }
+    });
+
+    // Another tab changed the same area; `sessionStorage` never reports these
+    use_event_listener(
+        move || web_sys::window().map(Into::into),
+        "storage",
+        move |event: StorageEvent| {
+            let same_area = event.storage_area() == kind.storage();
+            // A missing key means the whole area was cleared
+            let same_key = event
+                .key()
+                .is_none_or(|changed| key.with_value(|key| &changed == key));
+            if same_area && same_key {
+                load(event.new_value());
+            }
+        },
+    );
+
+    let remove = Callback::new(move |()| {
+        if let Some(storage) = kind.storage() {
+            key.with_value(|key| {
+                let _ = storage.remove_item(key);
+            });
+        }
+        // Keeps the persisting effect from writing the default back
+        last_written.set_value(default.with_value(|default| serde_json::to_string(default).ok()));
+        value.set(default.get_value());
+    });
+
+    UseStorageReturn { value, remove }
+}
+
+/// Parse a stored entry, `None` when it is missing or malformed
+fn decode_stored<T: DeserializeOwned>(raw: Option<&str>) -> Option<T> {
+    serde_json::from_str(raw?).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_stored() {
+        assert_eq!(decode_stored::<bool>(Some("true")), Some(true));
+        assert_eq!(
+            decode_stored::<Vec<String>>(Some(r#"["name","email"]"#)),
+            Some(vec!["name".to_string(), "email".to_string()])
+        );
+        assert_eq!(decode_stored::<bool>(None), None);
+        // Entries written by other code or older versions are ignored
+        assert_eq!(decode_stored::<u32>(Some("not json")), None);
+        assert_eq!(decode_stored::<u32>(Some("\"text\"")), None);
+    }
+}
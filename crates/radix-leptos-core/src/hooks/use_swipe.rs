@@ -0,0 +1,290 @@
+use leptos::html::ElementType;
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, PointerEvent};
+
+use super::use_event_listener::use_event_listener;
+
+/// Which way a swipe went
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SwipeDirection {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+impl SwipeDirection {
+    pub fn is_horizontal(&self) -> bool {
+        matches!(self, SwipeDirection::Left | SwipeDirection::Right)
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SwipeDirection::Left => "left",
+            SwipeDirection::Right => "right",
+            SwipeDirection::Up => "up",
+            SwipeDirection::Down => "down",
+        }
+    }
+}
+
+/// A finished swipe
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Swipe {
+    /// Dominant axis and sign of the movement
+    pub direction: SwipeDirection,
+    /// Horizontal movement in CSS pixels, negative to the left
+    pub dx: f64,
+    /// Vertical movement in CSS pixels, negative upwards
+    pub dy: f64,
+    /// Movement along `direction`'s axis in CSS pixels
+    pub distance: f64,
+    /// `distance` per millisecond
+    pub velocity: f64,
+}
+
+impl Swipe {
+    /// The swipe for a movement of `dx`/`dy` pixels over `elapsed_ms`
+    ///
+    /// The axis that moved further decides the direction; ties go to the
+    /// horizontal axis.
+    pub fn new(dx: f64, dy: f64, elapsed_ms: f64) -> Self {
+        let direction = match (dx.abs() >= dy.abs(), dx < 0.0, dy < 0.0) {
+            (true, true, _) => SwipeDirection::Left,
+            (true, false, _) => SwipeDirection::Right,
+            (false, _, true) => SwipeDirection::Up,
+            (false, _, false) => SwipeDirection::Down,
+        };
+        let distance = dx.abs().max(dy.abs());
+        Self {
+            direction,
+            dx,
+            dy,
+            distance,
+            velocity: distance / elapsed_ms.max(1.0),
+        }
+    }
+
+    /// Whether the swipe was long or fast enough to count
+    pub fn passes(&self, options: &UseSwipeOptions) -> bool {
+        self.distance >= options.threshold
+            || options
+                .min_velocity
+                .is_some_and(|min_velocity| self.velocity >= min_velocity)
+    }
+}
+
+/// Options for [`use_swipe_with_options`]
+#[derive(Debug, Clone, Copy)]
+pub struct UseSwipeOptions {
+    /// Distance in CSS pixels a swipe has to cover
+    pub threshold: f64,
+    /// Speed in pixels per millisecond at which shorter flicks count too
+    pub min_velocity: Option<f64>,
+    /// Whether mouse drags count, or only touch and pen
+    pub mouse: bool,
+}
+
+impl Default for UseSwipeOptions {
+    fn default() -> Self {
+        Self {
+            threshold: 50.0,
+            min_velocity: None,
+            mouse: true,
+        }
+    }
+}
+
+/// Return value for the use_swipe hook
+#[derive(Debug, Clone, Copy)]
+pub struct UseSwipeReturn {
+    /// Whether a pointer is down and being tracked
+    pub is_swiping: Signal<bool>,
+    /// Horizontal movement since the pointer went down, for drag feedback
+    pub offset_x: Signal<f64>,
+    /// Vertical movement since the pointer went down
+    pub offset_y: Signal<f64>,
+}
+
+/// Where and when the pointer went down
+#[derive(Debug, Clone, Copy)]
+struct SwipeStart {
+    pointer_id: i32,
+    x: f64,
+    y: f64,
+    time_stamp: f64,
+}
+
+/// Hook for detecting swipes on an element
+///
+/// Tracks the primary pointer from `pointerdown` to `pointerup` on the
+/// element behind `target` and calls `on_swipe` when it moved at least 50
+/// pixels. Set `touch-action` on the element for the axes you handle, or
+/// the browser will scroll instead.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::html;
+/// use leptos::prelude::*;
+/// use radix_leptos_core::{use_swipe, SwipeDirection};
+///
+/// #[component]
+/// pub fn Pager() -> impl IntoView {
+///     let pager_ref = NodeRef::<html::Div>::new();
+///     let page = RwSignal::new(0);
+///     use_swipe(pager_ref, move |swipe| match swipe.direction {
+///         SwipeDirection::Left => page.update(|page| *page += 1),
+///         SwipeDirection::Right => page.update(|page| *page -= 1),
+///         _ => {}
+///     });
+///
+///     view! { <div node_ref=pager_ref style="touch-action: pan-y;">"Page " {page}</div> }
+/// }
+/// ```
+pub fn use_swipe<E>(
+    target: NodeRef<E>,
+    on_swipe: impl Fn(Swipe) + Send + Sync + 'static,
+) -> UseSwipeReturn
+where
+    E: ElementType + 'static,
+    E::Output: JsCast + Clone + 'static,
+{
+    use_swipe_with_options(target, on_swipe, UseSwipeOptions::default())
+}
+
+/// Swipe hook with a custom threshold and pointer types
+pub fn use_swipe_with_options<E>(
+    target: NodeRef<E>,
+    on_swipe: impl Fn(Swipe) + Send + Sync + 'static,
+    options: UseSwipeOptions,
+) -> UseSwipeReturn
+where
+    E: ElementType + 'static,
+    E::Output: JsCast + Clone + 'static,
+{
+    let on_swipe = Callback::new(on_swipe);
+    let start = StoredValue::new(None::<SwipeStart>);
+    let is_swiping = RwSignal::new(false);
+    let offset_x = RwSignal::new(0.0);
+    let offset_y = RwSignal::new(0.0);
+    let set_offset = move |(dx, dy): (f64, f64)| {
+        offset_x.set(dx);
+        offset_y.set(dy);
+    };
+    let element = move || {
+        target
+            .get()
+            .map(|element| element.unchecked_into::<Element>().into())
+    };
+
+    let tracked = move |event: &PointerEvent| {
+        start
+            .get_value()
+            .filter(|start| start.pointer_id == event.pointer_id())
+    };
+    let delta = move |start: &SwipeStart, event: &PointerEvent| {
+        (
+            f64::from(event.client_x()) - start.x,
+            f64::from(event.client_y()) - start.y,
+        )
+    };
+    let reset = move || {
+        start.set_value(None);
+        is_swiping.set(false);
+        set_offset((0.0, 0.0));
+    };
+
+    use_event_listener(element, "pointerdown", move |event: PointerEvent| {
+        if !event.is_primary()
+            || event.button() != 0
+            || (!options.mouse && event.pointer_type() == "mouse")
+        {
+            return;
+        }
+        start.set_value(Some(SwipeStart {
+            pointer_id: event.pointer_id(),
+            x: f64::from(event.client_x()),
+            y: f64::from(event.client_y()),
+            time_stamp: event.time_stamp(),
+        }));
+        set_offset((0.0, 0.0));
+        is_swiping.set(true);
+    });
+
+    use_event_listener(element, "pointermove", move |event: PointerEvent| {
+        if let Some(start) = tracked(&event) {
+            set_offset(delta(&start, &event));
+        }
+    });
+
+    use_event_listener(element, "pointerup", move |event: PointerEvent| {
+        let Some(start) = tracked(&event) else {
+            return;
+        };
+        reset();
+        let (dx, dy) = delta(&start, &event);
+        let swipe = Swipe::new(dx, dy, event.time_stamp() - start.time_stamp);
+        if swipe.passes(&options) {
+            on_swipe.run(swipe);
+        }
+    });
+
+    use_event_listener(element, "pointercancel", move |event: PointerEvent| {
+        if tracked(&event).is_some() {
+            reset();
+        }
+    });
+
+    UseSwipeReturn {
+        is_swiping: is_swiping.into(),
+        offset_x: offset_x.into(),
+        offset_y: offset_y.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_swipe_direction() {
+        assert_eq!(
+            Swipe::new(-80.0, 10.0, 200.0).direction,
+            SwipeDirection::Left
+        );
+        assert_eq!(
+            Swipe::new(80.0, -30.0, 200.0).direction,
+            SwipeDirection::Right
+        );
+        assert_eq!(Swipe::new(5.0, -60.0, 200.0).direction, SwipeDirection::Up);
+        assert_eq!(
+            Swipe::new(-5.0, 60.0, 200.0).direction,
+            SwipeDirection::Down
+        );
+    }
+
+    #[test]
+    fn test_swipe_distance_and_velocity() {
+        let swipe = Swipe::new(-30.0, 120.0, 200.0);
+        assert_eq!(swipe.distance, 120.0);
+        assert_eq!(swipe.velocity, 0.6);
+        // A pointer lifted within the same millisecond doesn't divide by zero
+        assert_eq!(Swipe::new(10.0, 0.0, 0.0).velocity, 10.0);
+    }
+
+    #[test]
+    fn test_swipe_passes() {
+        let options = UseSwipeOptions::default();
+        assert!(Swipe::new(-50.0, 0.0, 500.0).passes(&options));
+        assert!(!Swipe::new(-30.0, 0.0, 50.0).passes(&options));
+
+        let flick = UseSwipeOptions {
+            min_velocity: Some(0.5),
+            ..options
+        };
+        assert!(Swipe::new(-30.0, 0.0, 50.0).passes(&flick));
+        assert!(!Swipe::new(-30.0, 0.0, 500.0).passes(&flick));
+    }
+}
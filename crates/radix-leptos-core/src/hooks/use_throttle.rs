@@ -0,0 +1,131 @@
+use std::time::Duration;
+
+use leptos::prelude::*;
+
+use super::use_debounce::{follow_changes, RateLimit, RateLimitedCallback, RateLimiter};
+
+/// Options for [`use_throttle_with_options`] and [`use_throttle_fn`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UseThrottleOptions {
+    /// Call right away at the start of a window
+    pub leading: bool,
+    /// Call with the last arguments of a window once it ends
+    pub trailing: bool,
+}
+
+impl Default for UseThrottleOptions {
+    fn default() -> Self {
+        Self {
+            leading: true,
+            trailing: true,
+        }
+    }
+}
+
+/// Hook for a copy of a signal that follows it at most once per interval
+///
+/// The first change shows up right away; later changes within `interval`
+/// are merged into one update at its end, so the returned signal always
+/// ends up with the source's latest value.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+///
+/// use leptos::prelude::*;
+/// use radix_leptos_core::use_throttle;
+///
+/// #[component]
+/// pub fn PointerTracker() -> impl IntoView {
+///     let x = RwSignal::new(0);
+///     let throttled_x = use_throttle::<i32>(x, Duration::from_millis(100));
+///
+///     view! {
+///         <div on:pointermove=move |ev| x.set(ev.client_x())>
+///             "x = " {throttled_x}
+///         </div>
+///     }
+/// }
+/// ```
+pub fn use_throttle<T>(value: impl Into<Signal<T>>, interval: Duration) -> Signal<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    use_throttle_with_options(value, interval, UseThrottleOptions::default())
+}
+
+/// Throttled signal hook with leading and trailing options
+pub fn use_throttle_with_options<T>(
+    value: impl Into<Signal<T>>,
+    interval: Duration,
+    options: UseThrottleOptions,
+) -> Signal<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    let value = value.into();
+    let throttled = RwSignal::new(value.get_untracked());
+    let update = use_throttle_fn(
+        move |new_value: T| throttled.set(new_value),
+        interval,
+        options,
+    );
+    follow_changes(value, update);
+    throttled.into()
+}
+
+/// Hook for a throttled version of a callback
+///
+/// The callback runs at most once per `interval`, e.g. for scroll or
+/// resize handlers and progress saves.
+pub fn use_throttle_fn<A: 'static>(
+    callback: impl Fn(A) + Send + Sync + 'static,
+    interval: Duration,
+    options: UseThrottleOptions,
+) -> RateLimitedCallback<A> {
+    RateLimitedCallback::new(
+        callback,
+        interval,
+        RateLimiter::new(RateLimit::Throttle, options.leading, options.trailing),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_throttle_windows() {
+        let mut limiter = RateLimiter::new(RateLimit::Throttle, true, true);
+        let first = limiter.call(1);
+        assert_eq!(first.fire, Some(1));
+        assert!(first.restart_timer);
+        // Calls inside the window don't move its end
+        let second = limiter.call(2);
+        assert_eq!(second.fire, None);
+        assert!(!second.restart_timer);
+        limiter.call(3);
+
+        // The trailing call opens the next window
+        let end = limiter.elapse();
+        assert_eq!(end.fire, Some(3));
+        assert!(end.restart_timer);
+        let quiet = limiter.elapse();
+        assert_eq!(quiet.fire, None);
+        assert!(!quiet.window_open);
+        assert_eq!(limiter.call(4).fire, Some(4));
+    }
+
+    #[test]
+    fn test_throttle_without_leading_or_trailing() {
+        let mut trailing_only = RateLimiter::new(RateLimit::Throttle, false, true);
+        assert_eq!(trailing_only.call(1).fire, None);
+        assert_eq!(trailing_only.elapse().fire, Some(1));
+
+        let mut leading_only = RateLimiter::new(RateLimit::Throttle, true, false);
+        assert_eq!(leading_only.call(1).fire, Some(1));
+        assert_eq!(leading_only.call(2).fire, None);
+        assert_eq!(leading_only.elapse().fire, None);
+    }
+}
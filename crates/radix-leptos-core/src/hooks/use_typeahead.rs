@@ -0,0 +1,344 @@
+use std::time::Duration;
+
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement, KeyboardEvent};
+
+/// Attribute overriding the text an item is matched by, for items whose
+/// content is not plain text (icons, keyboard hints, ...)
+pub const TYPEAHEAD_TEXT_VALUE: &str = "data-text-value";
+
+/// Options for [`use_typeahead_with_options`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UseTypeaheadOptions {
+    /// Pause after which typed characters start a new search
+    pub timeout: Duration,
+    /// Continue searching from the first item after the last one
+    pub looping: bool,
+    /// Match "e" against "É", "ß" against "ss" and so on
+    pub ignore_diacritics: bool,
+}
+
+impl Default for UseTypeaheadOptions {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_millis(1000),
+            looping: true,
+            ignore_diacritics: true,
+        }
+    }
+}
+
+/// Return value for the use_typeahead hook
+#[derive(Debug, Clone, Copy)]
+pub struct UseTypeaheadReturn {
+    /// Characters typed since the last pause
+    pub search: Signal<String>,
+    buffer: RwSignal<String>,
+    timer: StoredValue<Option<TimeoutHandle>>,
+    options: UseTypeaheadOptions,
+}
+
+impl UseTypeaheadReturn {
+    /// Feed a key press and get the item to move to, if any
+    ///
+    /// `labels` are the items' texts in order and `current` the position of
+    /// the focused one. Keys other than printable characters are ignored.
+    pub fn on_key(
+        &self,
+        key: &str,
+        labels: &[impl AsRef<str>],
+        current: Option<usize>,
+    ) -> Option<usize> {
+        let searching = !self.buffer.with_untracked(String::is_empty);
+        if !is_typeahead_key(key, searching) {
+            return None;
+        }
+        self.buffer.update(|buffer| buffer.push_str(key));
+        self.restart_timer();
+        self.buffer
+            .with_untracked(|search| typeahead_match(labels, search, current, &self.options))
+    }
+
+    /// Handle a keydown on a list of items, focusing the matching one
+    ///
+    /// Items are the elements under `container` matching `item_selector`,
+    /// matched by their [`TYPEAHEAD_TEXT_VALUE`] or else their text.
+    /// Returns whether focus moved.
+    pub fn handle_keydown(
+        &self,
+        event: &KeyboardEvent,
+        container: &Element,
+        item_selector: &str,
+    ) -> bool {
+        if event.default_prevented() || event.alt_key() || event.ctrl_key() || event.meta_key() {
+            return false;
+        }
+        let items = typeahead_items(container, item_selector);
+        let labels: Vec<String> = items.iter().map(item_text).collect();
+        let active = web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.active_element());
+        let current = active.and_then(|active| {
+            items
+                .iter()
+                .position(|item| item.contains(Some(active.unchecked_ref())))
+        });
+        let Some(target) = self.on_key(&event.key(), &labels, current) else {
+            return false;
+        };
+        event.prevent_default();
+        let _ = items[target].focus();
+        true
+    }
+
+    /// Forget the characters typed so far
+    pub fn reset(&self) {
+        if let Some(Some(timer)) = self.timer.try_update_value(Option::take) {
+            timer.clear();
+        }
+        self.buffer.update(String::clear);
+    }
+
+    fn restart_timer(&self) {
+        let buffer = self.buffer;
+        if let Some(Some(timer)) = self.timer.try_update_value(Option::take) {
+            timer.clear();
+        }
+        let timer =
+            set_timeout_with_handle(move || buffer.update(String::clear), self.options.timeout);
+        self.timer.set_value(timer.ok());
+    }
+}
+
+/// Hook for jumping to an item by typing the start of its label
+///
+/// Typing "ap" in quick succession moves to the first item starting with
+/// "Apple"; after a pause of one second the next character starts a new
+/// search. Repeating a single character ("a", "a", ...) cycles through the
+/// items starting with it. Matching ignores case and diacritics and wraps
+/// around the end of the list.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::html;
+/// use leptos::prelude::*;
+/// use radix_leptos_core::use_typeahead;
+///
+/// #[component]
+/// pub fn FruitMenu() -> impl IntoView {
+///     let menu_ref = NodeRef::<html::Div>::new();
+///     let typeahead = use_typeahead();
+///
+///     view! {
+///         <div
+///             node_ref=menu_ref
+///             role="menu"
+///             on:keydown=move |ev| {
+///                 if let Some(menu) = menu_ref.get_untracked() {
+///                     typeahead.handle_keydown(&ev, &menu, "[role='menuitem']");
+///                 }
+///             }
+///         >
+///             <div role="menuitem" tabindex="-1">"Apple"</div>
+///             <div role="menuitem" tabindex="-1">"Apricot"</div>
+///             <div role="menuitem" tabindex="-1">"Banana"</div>
+///         </div>
+///     }
+/// }
+/// ```
+pub fn use_typeahead() -> UseTypeaheadReturn {
+    use_typeahead_with_options(UseTypeaheadOptions::default())
+}
+
+/// Typeahead hook with a custom timeout, wrapping and diacritic handling
+pub fn use_typeahead_with_options(options: UseTypeaheadOptions) -> UseTypeaheadReturn {
+    let buffer = RwSignal::new(String::new());
+    let timer = StoredValue::new(None::<TimeoutHandle>);
+    on_cleanup(move || {
+        if let Some(Some(timer)) = timer.try_update_value(Option::take) {
+            timer.clear();
+        }
+    });
+
+    UseTypeaheadReturn {
+        search: buffer.into(),
+        buffer,
+        timer,
+        options,
+    }
+}
+
+/// Index of the item `search` moves to from `current`, if any
+///
+/// The search starts at the focused item so that typing more characters
+/// keeps it while it still matches; a single character (or the same one
+/// repeated) starts after it instead, cycling through the matches.
+pub fn typeahead_match(
+    labels: &[impl AsRef<str>],
+    search: &str,
+    current: Option<usize>,
+    options: &UseTypeaheadOptions,
+) -> Option<usize> {
+    let normalize = |text: &str| normalize_label(text, options.ignore_diacritics);
+    let mut search = normalize(search);
+    let mut chars = search.chars();
+    if let Some(first) = chars.next() {
+        if chars.all(|c| c == first) {
+            search = first.to_string();
+        }
+    }
+    if search.is_empty() || labels.is_empty() {
+        return None;
+    }
+
+    let start = match current {
+        Some(current) if search.chars().count() == 1 => current + 1,
+        Some(current) => current,
+        None => 0,
+    };
+    let count = labels.len();
+    let span = if options.looping {
+        count
+    } else {
+        count.saturating_sub(start)
+    };
+    (0..span)
+        .map(|offset| (start + offset) % count)
+        .find(|&index| normalize(labels[index].as_ref()).starts_with(&search))
+        .filter(|&index| Some(index) != current)
+}
+
+/// Whether a key contributes to the search: a printable character, or a
+/// space once a search is under way
+fn is_typeahead_key(key: &str, searching: bool) -> bool {
+    let mut chars = key.chars();
+    match (chars.next(), chars.next()) {
+        (Some(' '), None) => searching,
+        (Some(c), None) => !c.is_control(),
+        _ => false,
+    }
+}
+
+fn normalize_label(text: &str, ignore_diacritics: bool) -> String {
+    let text = text.trim_start().to_lowercase();
+    if !ignore_diacritics {
+        return text;
+    }
+    let mut folded = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            'ß' => folded.push_str("ss"),
+            'æ' => folded.push_str("ae"),
+            'œ' => folded.push_str("oe"),
+            c => folded.push(fold_diacritic(c)),
+        }
+    }
+    folded
+}
+
+/// Base letter of a lowercase Latin letter with a diacritic
+fn fold_diacritic(c: char) -> char {
+    match c {
+        'à'..='å' | 'ā' | 'ă' | 'ą' => 'a',
+        'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => 'c',
+        'ď' | 'đ' => 'd',
+        'è'..='ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => 'e',
+        'ĝ' | 'ğ' | 'ġ' | 'ģ' => 'g',
+        'ĥ' | 'ħ' => 'h',
+        'ì'..='ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => 'i',
+        'ĵ' => 'j',
+        'ķ' => 'k',
+        'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => 'l',
+        'ñ' | 'ń' | 'ņ' | 'ň' => 'n',
+        'ò'..='ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => 'o',
+        'ŕ' | 'ŗ' | 'ř' => 'r',
+        'ś' | 'ŝ' | 'ş' | 'š' => 's',
+        'ţ' | 'ť' | 'ŧ' => 't',
+        'ù'..='ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => 'u',
+        'ŵ' => 'w',
+        'ý' | 'ÿ' | 'ŷ' => 'y',
+        'ź' | 'ż' | 'ž' => 'z',
+        c => c,
+    }
+}
+
+fn typeahead_items(container: &Element, item_selector: &str) -> Vec<HtmlElement> {
+    let Ok(nodes) = container.query_selector_all(item_selector) else {
+        return Vec::new();
+    };
+    (0..nodes.length())
+        .filter_map(|index| nodes.item(index))
+        .filter_map(|node| node.dyn_into::<HtmlElement>().ok())
+        .filter(|item| {
+            !item.has_attribute("disabled")
+                && item.get_attribute("aria-disabled").as_deref() != Some("true")
+        })
+        .collect()
+}
+
+fn item_text(item: &HtmlElement) -> String {
+    item.get_attribute(TYPEAHEAD_TEXT_VALUE)
+        .or_else(|| item.text_content())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FRUITS: [&str; 5] = ["Apple", "Apricot", "Banana", "Éclair", "avocado"];
+
+    #[test]
+    fn test_prefix_search_keeps_matching_item() {
+        let options = UseTypeaheadOptions::default();
+        assert_eq!(typeahead_match(&FRUITS, "a", None, &options), Some(0));
+        // "ap" still matches the focused Apple, so focus stays
+        assert_eq!(typeahead_match(&FRUITS, "ap", Some(0), &options), None);
+        assert_eq!(typeahead_match(&FRUITS, "apr", Some(0), &options), Some(1));
+        assert_eq!(typeahead_match(&FRUITS, "x", Some(0), &options), None);
+    }
+
+    #[test]
+    fn test_repeated_character_cycles() {
+        let options = UseTypeaheadOptions::default();
+        assert_eq!(typeahead_match(&FRUITS, "a", Some(0), &options), Some(1));
+        assert_eq!(typeahead_match(&FRUITS, "aa", Some(1), &options), Some(4));
+        assert_eq!(typeahead_match(&FRUITS, "aaa", Some(4), &options), Some(0));
+
+        let clamped = UseTypeaheadOptions {
+            looping: false,
+            ..options
+        };
+        assert_eq!(typeahead_match(&FRUITS, "a", Some(4), &clamped), None);
+    }
+
+    #[test]
+    fn test_diacritics() {
+        let options = UseTypeaheadOptions::default();
+        assert_eq!(typeahead_match(&FRUITS, "ec", None, &options), Some(3));
+        assert_eq!(
+            typeahead_match(&["Straße"], "strass", None, &options),
+            Some(0)
+        );
+
+        let exact = UseTypeaheadOptions {
+            ignore_diacritics: false,
+            ..options
+        };
+        assert_eq!(typeahead_match(&FRUITS, "ec", None, &exact), None);
+        assert_eq!(typeahead_match(&FRUITS, "éc", None, &exact), Some(3));
+    }
+
+    #[test]
+    fn test_typeahead_keys() {
+        assert!(is_typeahead_key("a", false));
+        assert!(is_typeahead_key("É", false));
+        assert!(!is_typeahead_key("ArrowDown", false));
+        assert!(!is_typeahead_key("Enter", true));
+        // Space selects an item unless it continues a search like "new y"
+        assert!(!is_typeahead_key(" ", false));
+        assert!(is_typeahead_key(" ", true));
+    }
+}
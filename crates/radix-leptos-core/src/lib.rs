@@ -3,13 +3,13 @@
 //! Core utilities, hooks, and primitives for building accessible UI components in Leptos.
 //! This crate provides the foundational building blocks for the Radix-Leptos component library.
 
-// pub mod hooks; // Temporarily disabled due to leptos-use conflicts
+pub mod hooks;
 pub mod utils;
-// pub mod context; // Temporarily disabled
+pub mod context;
 pub mod primitives;
 
 // Re-export commonly used items
-// pub use hooks::*;
+pub use hooks::*;
 pub use utils::*;
-// pub use context::*;
+pub use context::*;
 pub use primitives::*;
@@ -0,0 +1,309 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::time::Duration;
+
+use leptos::prelude::*;
+
+use super::visually_hidden::VisuallyHidden;
+use crate::utils::accessibility::{announce_to_screen_reader, AriaLive};
+use crate::utils::live_region::{channels, Announcement, ChannelConfig, LiveRegionManager};
+
+/// Repeats of a message within this many milliseconds are dropped
+pub const ANNOUNCEMENT_DEDUPE_MS: f64 = 1000.0;
+
+/// How long a message stays in its live region, so it isn't read again
+/// when the user browses past the region
+const CLEAR_AFTER: Duration = Duration::from_secs(5);
+
+/// Drops messages announced moments ago, e.g. when several fields report
+/// the same error or a status flickers
+///
+/// Like [`LiveRegionManager`](crate::utils::LiveRegionManager) it is
+/// clock-agnostic: callers pass the current time in milliseconds.
+#[derive(Debug, Clone)]
+pub struct AnnouncementFilter {
+    window_ms: f64,
+    recent: HashMap<String, f64>,
+}
+
+impl Default for AnnouncementFilter {
+    fn default() -> Self {
+        Self::new(ANNOUNCEMENT_DEDUPE_MS)
+    }
+}
+
+impl AnnouncementFilter {
+    pub fn new(window_ms: f64) -> Self {
+        Self {
+            window_ms,
+            recent: HashMap::new(),
+        }
+    }
+
+    /// Whether `message` should be announced at `now`, recording it if so
+    pub fn admit(&mut self, message: &str, now: f64) -> bool {
+        let window_ms = self.window_ms;
+        self.recent
+            .retain(|_, announced_at| now - *announced_at < window_ms);
+        if message.trim().is_empty() || self.recent.contains_key(message) {
+            return false;
+        }
+        self.recent.insert(message.to_string(), now);
+        true
+    }
+}
+
+/// Text for a live region showing `current` to announce `message`
+///
+/// Screen readers ignore a region whose text doesn't change, so a repeated
+/// message gets a trailing no-break space.
+pub fn next_region_text(current: &str, message: &str) -> String {
+    if current == message {
+        format!("{}\u{a0}", message)
+    } else {
+        message.to_string()
+    }
+}
+
+/// Channel rate limiting and de-duplication behind one set of live regions
+#[derive(Debug, Clone, Default)]
+struct AnnouncerState {
+    manager: LiveRegionManager,
+    filter: AnnouncementFilter,
+}
+
+thread_local! {
+    static DOCUMENT_STATE: RefCell<AnnouncerState> = RefCell::new(AnnouncerState::default());
+}
+
+#[derive(Clone, Copy)]
+struct LiveRegions {
+    polite: RwSignal<String>,
+    assertive: RwSignal<String>,
+    state: StoredValue<AnnouncerState>,
+}
+
+/// Handle for announcing messages to screen readers
+///
+/// Messages go through a [`LiveRegionManager`] channel, which decides their
+/// politeness and rate limiting, and are then de-duplicated. Inside an
+/// [`AnnouncerProvider`] they go to its live regions; elsewhere they go to
+/// regions appended to the document body.
+#[derive(Clone, Copy)]
+pub struct Announcer {
+    regions: Option<LiveRegions>,
+}
+
+impl Announcer {
+    /// Announcer writing to the document-wide live regions
+    pub(crate) fn document() -> Self {
+        Self { regions: None }
+    }
+
+    /// Announce `message` on `channel`, e.g. [`channels::STATUS`](crate::utils::channels::STATUS)
+    pub fn announce(&self, channel: &str, message: &str) {
+        let message = message.trim();
+        let now = js_sys::Date::now();
+        if let Some(Some(announcement)) =
+            self.with_state(|state| state.manager.announce(channel, message, now))
+        {
+            self.deliver(&announcement, now);
+        }
+        self.schedule_flush();
+    }
+
+    /// Announce the pending message of `channel` right away (e.g. at drag end)
+    pub fn commit(&self, channel: &str) {
+        let now = js_sys::Date::now();
+        if let Some(Some(announcement)) =
+            self.with_state(|state| state.manager.commit(channel, now))
+        {
+            self.deliver(&announcement, now);
+        }
+    }
+
+    /// Drop the pending message of `channel` without announcing it
+    pub fn cancel(&self, channel: &str) {
+        self.with_state(|state| state.manager.cancel(channel));
+    }
+
+    /// Set the politeness and rate limiting of `channel`
+    pub fn configure(&self, channel: &str, config: ChannelConfig) {
+        self.with_state(|state| state.manager.configure(channel, config));
+    }
+
+    fn with_state<R>(&self, f: impl FnOnce(&mut AnnouncerState) -> R) -> Option<R> {
+        match self.regions {
+            Some(regions) => regions.state.try_update_value(f),
+            None => Some(DOCUMENT_STATE.with(|state| f(&mut state.borrow_mut()))),
+        }
+    }
+
+    fn deliver(&self, announcement: &Announcement, now: f64) {
+        let admitted = self
+            .with_state(|state| state.filter.admit(&announcement.message, now))
+            .unwrap_or(false);
+        if !admitted {
+            return;
+        }
+        let Some(regions) = self.regions else {
+            announce_to_screen_reader(&announcement.message, announcement.politeness);
+            return;
+        };
+        let region = match announcement.politeness {
+            AriaLive::Assertive => regions.assertive,
+            _ => regions.polite,
+        };
+        let Some(written) = region.try_update(|text| {
+            *text = next_region_text(text, &announcement.message);
+            text.clone()
+        }) else {
+            return;
+        };
+        set_timeout(
+            move || {
+                if region.try_get_untracked().as_deref() == Some(written.as_str()) {
+                    region.try_set(String::new());
+                }
+            },
+            CLEAR_AFTER,
+        );
+    }
+
+    /// Start a timer for the next throttled or debounced message, unless
+    /// one is already pending for an earlier time
+    fn schedule_flush(self) {
+        let Some(Some(due)) = self.with_state(|state| state.manager.schedule_flush()) else {
+            return;
+        };
+        let delay = (due - js_sys::Date::now()).max(0.0).ceil() as u64;
+        set_timeout(
+            move || {
+                let now = js_sys::Date::now();
+                let announcements = self
+                    .with_state(|state| state.manager.flush_scheduled(due, now))
+                    .unwrap_or_default();
+                for announcement in &announcements {
+                    self.deliver(announcement, now);
+                }
+                self.schedule_flush();
+            },
+            Duration::from_millis(delay),
+        );
+    }
+}
+
+/// AnnouncerProvider component - Shared live regions for screen reader
+/// announcements
+///
+/// Renders one polite and one assertive live region for the whole app, so
+/// components announce through [`use_announcer`] or [`use_announce`]
+/// instead of each keeping a live region of its own. Each provider rate
+/// limits its own channels, and messages repeated within
+/// [`ANNOUNCEMENT_DEDUPE_MS`] are announced once.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_core::{channels, use_announcer, AnnouncerProvider};
+///
+/// #[component]
+/// fn SaveButton() -> impl IntoView {
+///     let announcer = use_announcer();
+///     view! {
+///         <button on:click=move |_| announcer.announce(channels::STATUS, "Saved")>"Save"</button>
+///     }
+/// }
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     view! { <AnnouncerProvider><SaveButton /></AnnouncerProvider> }
+/// }
+/// ```
+#[component]
+pub fn AnnouncerProvider(children: Children) -> impl IntoView {
+    let regions = LiveRegions {
+        polite: RwSignal::new(String::new()),
+        assertive: RwSignal::new(String::new()),
+        state: StoredValue::new(AnnouncerState::default()),
+    };
+    provide_context(Announcer {
+        regions: Some(regions),
+    });
+
+    view! {
+        {children()}
+        <VisuallyHidden class="radix-announcer".to_string()>
+            <span role="status" aria-live="polite" aria-atomic="true">
+                {move || regions.polite.get()}
+            </span>
+            <span role="alert" aria-live="assertive" aria-atomic="true">
+                {move || regions.assertive.get()}
+            </span>
+        </VisuallyHidden>
+    }
+}
+
+/// Hook for the announcer of the closest AnnouncerProvider, or one writing
+/// to document-wide live regions outside of any
+pub fn use_announcer() -> Announcer {
+    use_context::<Announcer>().unwrap_or_else(Announcer::document)
+}
+
+/// Hook announcing `message` whenever it changes to a new message
+///
+/// Polite messages go to [`channels::STATUS`](crate::utils::channels::STATUS)
+/// and assertive ones to [`channels::ALERT`](crate::utils::channels::ALERT);
+/// [`AriaLive::Off`] announces nothing. `None` announces nothing either, so
+/// a status can be derived straight from state, e.g.
+/// `loading.then(|| "Loading results".to_string())`. Use
+/// [`use_announce_on`] for another channel.
+pub fn use_announce(message: impl Into<Signal<Option<String>>>, politeness: AriaLive) {
+    let channel = match politeness {
+        AriaLive::Off => return,
+        AriaLive::Polite => channels::STATUS,
+        AriaLive::Assertive => channels::ALERT,
+    };
+    use_announce_on(message, channel);
+}
+
+/// Hook announcing `message` on `channel` whenever it changes to a new
+/// message, see [`use_announce`]
+pub fn use_announce_on(message: impl Into<Signal<Option<String>>>, channel: &'static str) {
+    let message = message.into();
+    let announcer = use_announcer();
+    Effect::new(move |previous: Option<Option<String>>| {
+        let current = message.get();
+        if let Some(text) = current.as_deref() {
+            if previous.flatten().as_deref() != Some(text) {
+                announcer.announce(channel, text);
+            }
+        }
+        current
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_drops_rapid_repeats() {
+        let mut filter = AnnouncementFilter::new(1000.0);
+        assert!(filter.admit("Email is required", 0.0));
+        assert!(!filter.admit("Email is required", 200.0));
+        assert!(filter.admit("Password is required", 300.0));
+        assert!(!filter.admit("   ", 400.0));
+        // Once the window has passed the message is announced again
+        assert!(filter.admit("Email is required", 1000.0));
+    }
+
+    #[test]
+    fn test_repeated_region_text_changes() {
+        assert_eq!(next_region_text("", "Saved"), "Saved");
+        assert_eq!(next_region_text("Saved", "Saved"), "Saved\u{a0}");
+        assert_eq!(next_region_text("Saved\u{a0}", "Saved"), "Saved");
+        assert_eq!(next_region_text("Saved", "Deleted"), "Deleted");
+    }
+}
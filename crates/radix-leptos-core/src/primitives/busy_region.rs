@@ -0,0 +1,191 @@
+use leptos::prelude::*;
+
+use super::announcer::use_announcer;
+use crate::utils::live_region::channels;
+
+/// Default announcement when loading starts
+pub const DEFAULT_LOADING_MESSAGE: &str = "Loading";
+
+/// How the busy state of a region just changed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BusyTransition {
+    Started,
+    Finished,
+}
+
+/// The transition from `previous` to `busy`, if any
+///
+/// A region that mounts busy has started loading; one that mounts idle has
+/// nothing to report.
+pub fn busy_transition(previous: Option<bool>, busy: bool) -> Option<BusyTransition> {
+    match (previous, busy) {
+        (None | Some(false), true) => Some(BusyTransition::Started),
+        (Some(true), false) => Some(BusyTransition::Finished),
+        _ => None,
+    }
+}
+
+/// Default announcement when loading finishes with `count` results, or an
+/// unknown number of them
+pub fn loaded_message(count: Option<usize>) -> String {
+    match count {
+        None => "Loaded".to_string(),
+        Some(0) => "No results".to_string(),
+        Some(1) => "Loaded 1 result".to_string(),
+        Some(count) => format!("Loaded {} results", count),
+    }
+}
+
+/// Builds the announcement when loading finishes from the result count
+pub type LoadedMessage = Callback<Option<usize>, String>;
+
+/// What a busy region announces
+#[derive(Clone, Default)]
+pub struct BusyMessages {
+    /// Announced when loading starts (default: [`DEFAULT_LOADING_MESSAGE`])
+    pub loading: Option<String>,
+    /// Builds the announcement when loading finishes from the result count
+    /// (default: [`loaded_message`])
+    pub loaded: Option<LoadedMessage>,
+}
+
+impl BusyMessages {
+    fn loading(&self) -> String {
+        self.loading
+            .clone()
+            .unwrap_or_else(|| DEFAULT_LOADING_MESSAGE.to_string())
+    }
+
+    fn loaded(&self, count: Option<usize>) -> String {
+        match self.loaded {
+            Some(loaded) => loaded.run(count),
+            None => loaded_message(count),
+        }
+    }
+}
+
+/// Hook announcing when `busy` starts and stops
+///
+/// Announces "Loading" when `busy` turns on and "Loaded N results" from
+/// `result_count` when it turns off, through the closest
+/// [`AnnouncerProvider`](super::announcer::AnnouncerProvider). Changes of
+/// the count alone are not announced.
+pub fn use_busy_announcements(
+    busy: Signal<bool>,
+    result_count: Signal<Option<usize>>,
+    messages: BusyMessages,
+) {
+    let announcer = use_announcer();
+    Effect::new(move |previous: Option<bool>| {
+        let busy = busy.get();
+        let message = match busy_transition(previous, busy) {
+            Some(BusyTransition::Started) => Some(messages.loading()),
+            Some(BusyTransition::Finished) => Some(messages.loaded(result_count.get_untracked())),
+            None => None,
+        };
+        if let Some(message) = message {
+            announcer.announce(channels::STATUS, &message);
+        }
+        busy
+    });
+}
+
+/// BusyRegion component - Content that loads or refreshes asynchronously
+///
+/// Sets `aria-busy` on its content while `busy`, so assistive technologies
+/// wait for the update to finish instead of reading partial results, and
+/// announces the start and end of loading as [`use_busy_announcements`]
+/// does.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_core::BusyRegion;
+///
+/// #[component]
+/// fn Results() -> impl IntoView {
+///     let (loading, _set_loading) = signal(true);
+///     let (results, _set_results) = signal(Vec::<String>::new());
+///
+///     view! {
+///         <BusyRegion
+///             busy=loading
+///             result_count=Signal::derive(move || Some(results.get().len()))
+///         >
+///             <ul>{move || results.get().into_iter().map(|r| view! { <li>{r}</li> }).collect::<Vec<_>>()}</ul>
+///         </BusyRegion>
+///     }
+/// }
+/// ```
+#[component]
+pub fn BusyRegion(
+    /// Additional CSS classes to apply
+    #[prop(optional, into)]
+    class: Option<String>,
+    /// Whether the content is loading
+    #[prop(into)]
+    busy: Signal<bool>,
+    /// Number of results once loaded, announced when loading finishes
+    #[prop(optional, into)]
+    result_count: Signal<Option<usize>>,
+    /// Announced when loading starts (default: "Loading")
+    #[prop(optional, into)]
+    loading_message: Option<String>,
+    /// Builds the announcement when loading finishes from the result count
+    #[prop(optional)]
+    loaded_message: Option<LoadedMessage>,
+    children: Children,
+) -> impl IntoView {
+    use_busy_announcements(
+        busy,
+        result_count,
+        BusyMessages {
+            loading: loading_message,
+            loaded: loaded_message,
+        },
+    );
+
+    let class = match class {
+        Some(class) => format!("radix-busy-region {}", class),
+        None => "radix-busy-region".to_string(),
+    };
+
+    view! {
+        <div
+            class=class
+            aria-busy=move || busy.get().to_string()
+            data-state=move || if busy.get() { "loading" } else { "idle" }
+        >
+            {children()}
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_busy_transition() {
+        assert_eq!(busy_transition(None, true), Some(BusyTransition::Started));
+        assert_eq!(busy_transition(None, false), None);
+        assert_eq!(
+            busy_transition(Some(false), true),
+            Some(BusyTransition::Started)
+        );
+        assert_eq!(
+            busy_transition(Some(true), false),
+            Some(BusyTransition::Finished)
+        );
+        assert_eq!(busy_transition(Some(true), true), None);
+    }
+
+    #[test]
+    fn test_loaded_message() {
+        assert_eq!(loaded_message(None), "Loaded");
+        assert_eq!(loaded_message(Some(0)), "No results");
+        assert_eq!(loaded_message(Some(1)), "Loaded 1 result");
+        assert_eq!(loaded_message(Some(12)), "Loaded 12 results");
+    }
+}
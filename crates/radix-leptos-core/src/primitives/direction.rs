@@ -0,0 +1,74 @@
+use leptos::prelude::*;
+
+use crate::context::Direction;
+
+/// Reading direction shared with everything below a DirectionProvider
+#[derive(Debug, Clone, Copy)]
+pub struct DirectionContext {
+    pub dir: Signal<Direction>,
+}
+
+/// DirectionProvider component for right-to-left and left-to-right subtrees
+///
+/// Sets the `dir` attribute, so the browser mirrors logical CSS properties
+/// and text, and shares the direction with components that need it in code,
+/// such as keyboard navigation and direction-aware theming.
+///
+/// # Example
+///
+/// ```rust
+/// use leptos::prelude::*;
+/// use radix_leptos_core::{Direction, DirectionProvider};
+///
+/// #[component]
+/// fn ArabicPage() -> impl IntoView {
+///     view! {
+///         <DirectionProvider dir=Direction::Rtl>
+///             <p>"مرحبا"</p>
+///         </DirectionProvider>
+///     }
+/// }
+/// ```
+#[component]
+pub fn DirectionProvider(
+    /// Reading direction of the subtree
+    #[prop(into)]
+    dir: Signal<Direction>,
+    /// Content in this direction
+    children: Children,
+) -> impl IntoView {
+    provide_context(DirectionContext { dir });
+
+    view! {
+        <div class="radix-direction-provider" dir=move || dir.get().as_str() style="display: contents;">
+            {children()}
+        </div>
+    }
+}
+
+/// Hook for the reading direction of the closest DirectionProvider, left to
+/// right without one
+pub fn use_direction() -> Signal<Direction> {
+    let context = use_context::<DirectionContext>();
+    Signal::derive(move || context.map(|context| context.dir.get()).unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_use_direction() {
+        let owner = Owner::new();
+        owner.with(|| {
+            assert_eq!(use_direction().get(), Direction::Ltr);
+
+            let dir = RwSignal::new(Direction::Rtl);
+            provide_context(DirectionContext { dir: dir.into() });
+            let direction = use_direction();
+            assert_eq!(direction.get(), Direction::Rtl);
+            dir.set(Direction::Ltr);
+            assert_eq!(direction.get(), Direction::Ltr);
+        });
+    }
+}
@@ -2,11 +2,17 @@
 //!
 //! Low-level primitive components that form the foundation of higher-level components.
 
+pub mod announcer;
+pub mod busy_region;
+pub mod direction;
 pub mod portal;
 // pub mod slot; // Temporarily disabled due to compilation issues
 pub mod visually_hidden;
 // pub mod presence; // Temporarily disabled due to gloo-timers dependency
 
+pub use announcer::*;
+pub use busy_region::*;
+pub use direction::*;
 pub use portal::*;
 // pub use slot::*;
 pub use visually_hidden::*;
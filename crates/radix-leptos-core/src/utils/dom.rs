@@ -60,25 +60,6 @@ fn is_hidden(element: &Element) -> bool {
         || element.get_attribute("aria-hidden").as_deref() == Some("true")
 }
 
-/// Get all focusable elements within a container
-pub fn get_focusable_elements(container: &Element) -> Vec<Element> {
-    let _document = get_owner_document(Some(container));
-
-    // Simplified implementation - return empty vector for now
-    // Check if element is focusable based on standard criteria
-    Vec::new()
-}
-
-/// Get the first focusable element within a container
-pub fn get_first_focusable(container: &Element) -> Option<Element> {
-    get_focusable_elements(container).into_iter().next()
-}
-
-/// Get the last focusable element within a container
-pub fn get_last_focusable(container: &Element) -> Option<Element> {
-    get_focusable_elements(container).into_iter().last()
-}
-
 #[cfg(test)]
 mod tests {
     use wasm_bindgen_test::*;
@@ -114,27 +95,4 @@ mod tests {
         negative_div.set_attribute("tabindex", "-1").unwrap();
         assert!(!is_focusable(&negative_div));
     }
-
-    #[wasm_bindgen_test]
-    #[allow(dead_code)]
-    fn test_get_focusable_elements() {
-        let document = web_sys::window().unwrap().document().unwrap();
-        let container = document.create_element("div").unwrap();
-
-        // Add focusable elements
-        let button = document.create_element("button").unwrap();
-        button.set_text_content(Some("Button"));
-        container.append_child(&button).unwrap();
-
-        let input = document.create_element("input").unwrap();
-        container.append_child(&input).unwrap();
-
-        // Add non-focusable element
-        let div = document.create_element("div").unwrap();
-        div.set_text_content(Some("Div"));
-        container.append_child(&div).unwrap();
-
-        let focusable = get_focusable_elements(&container);
-        assert_eq!(focusable.len(), 2);
-    }
 }
@@ -0,0 +1,337 @@
+//! Programmatic focus management
+//!
+//! [`FocusManager`] finds the tabbable elements of a container, moves focus
+//! between them and keeps a stack of places to return focus to, one entry
+//! per open overlay, so closing layered overlays in any order sends focus
+//! back where the user came from.
+
+use std::cell::RefCell;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement};
+
+use super::dom::is_focusable;
+
+/// Elements that may be tabbable, in document order
+const TABBABLE_CANDIDATES: &str = "a[href], area[href], button, input, select, textarea, \
+     summary, iframe, audio[controls], video[controls], [contenteditable], [tabindex]";
+
+/// Handle for an entry on the focus restoration stack
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FocusRestoreId(u64);
+
+/// Focus navigation and restoration
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use radix_leptos_core::FocusManager;
+///
+/// fn open_overlay(overlay: &web_sys::Element) -> radix_leptos_core::FocusRestoreId {
+///     let restore = FocusManager::save_focus();
+///     FocusManager::focus_first(overlay);
+///     restore
+/// }
+///
+/// fn close_overlay(restore: radix_leptos_core::FocusRestoreId) {
+///     FocusManager::restore_focus(restore);
+/// }
+/// ```
+pub struct FocusManager;
+
+impl FocusManager {
+    /// Whether `element` is reached with Tab
+    ///
+    /// Besides being focusable it must not have a negative `tabindex`,
+    /// be a hidden input or sit inside an `inert` subtree.
+    pub fn is_tabbable(element: &Element) -> bool {
+        let tab_index = element
+            .get_attribute("tabindex")
+            .and_then(|tab_index| tab_index.trim().parse::<i32>().ok());
+        if tab_index.is_some_and(|tab_index| tab_index < 0) {
+            return false;
+        }
+        if element.tag_name().eq_ignore_ascii_case("input")
+            && element
+                .get_attribute("type")
+                .is_some_and(|kind| kind.eq_ignore_ascii_case("hidden"))
+        {
+            return false;
+        }
+        if element.closest("[inert]").ok().flatten().is_some() {
+            return false;
+        }
+        let editable = element
+            .get_attribute("contenteditable")
+            .is_some_and(|value| value != "false");
+        let native = matches!(
+            element.tag_name().to_lowercase().as_str(),
+            "summary" | "iframe" | "audio" | "video"
+        );
+        if editable || native {
+            return !element.has_attribute("hidden");
+        }
+        is_focusable(element)
+    }
+
+    /// Tabbable elements of `container` in Tab order: positive `tabindex`
+    /// values first, ascending, then the rest in document order
+    pub fn tabbable_elements(container: &Element) -> Vec<Element> {
+        let Ok(candidates) = container.query_selector_all(TABBABLE_CANDIDATES) else {
+            return Vec::new();
+        };
+        let elements = (0..candidates.length())
+            .filter_map(|index| candidates.item(index))
+            .filter_map(|node| node.dyn_into::<Element>().ok())
+            .filter(Self::is_tabbable)
+            .collect::<Vec<_>>();
+        let tab_indices = elements
+            .iter()
+            .map(|element| {
+                element
+                    .get_attribute("tabindex")
+                    .and_then(|tab_index| tab_index.trim().parse().ok())
+                    .unwrap_or(0)
+            })
+            .collect::<Vec<_>>();
+        tab_order(&tab_indices)
+            .into_iter()
+            .map(|index| elements[index].clone())
+            .collect()
+    }
+
+    /// Focus the first tabbable element of `container`; returns whether
+    /// there was one
+    pub fn focus_first(container: &Element) -> bool {
+        Self::tabbable_elements(container)
+            .first()
+            .is_some_and(Self::focus)
+    }
+
+    /// Focus the last tabbable element of `container`; returns whether
+    /// there was one
+    pub fn focus_last(container: &Element) -> bool {
+        Self::tabbable_elements(container)
+            .last()
+            .is_some_and(Self::focus)
+    }
+
+    /// Move focus to the tabbable element of `container` after the focused
+    /// one, wrapping around at the end
+    pub fn focus_next(container: &Element) -> bool {
+        Self::focus_step(container, FocusDirection::Forward)
+    }
+
+    /// Move focus to the tabbable element of `container` before the focused
+    /// one, wrapping around at the start
+    pub fn focus_prev(container: &Element) -> bool {
+        Self::focus_step(container, FocusDirection::Backward)
+    }
+
+    /// The focused element, if any
+    pub fn active_element() -> Option<Element> {
+        web_sys::window()?.document()?.active_element()
+    }
+
+    /// Focus `element`; returns whether it could take focus
+    pub fn focus(element: &Element) -> bool {
+        element
+            .dyn_ref::<HtmlElement>()
+            .is_some_and(|element| element.focus().is_ok())
+    }
+
+    /// Remember the focused element to return to once the overlay being
+    /// opened closes
+    pub fn save_focus() -> FocusRestoreId {
+        let active = Self::active_element();
+        RESTORE_STACK.with(|stack| stack.borrow_mut().push(active))
+    }
+
+    /// Return focus to where it was when `id` was saved
+    ///
+    /// Overlays may close out of order: an entry released below the top
+    /// hands its element to the entry above it, which falls back to it if
+    /// its own element has been removed from the page by then.
+    pub fn restore_focus(id: FocusRestoreId) {
+        let Some(targets) = RESTORE_STACK.with(|stack| stack.borrow_mut().remove(id)) else {
+            return;
+        };
+        if let Some(target) = targets
+            .into_iter()
+            .flatten()
+            .find(|target| target.is_connected())
+        {
+            Self::focus(&target);
+        }
+    }
+
+    /// Drop the entry for `id` without moving focus, e.g. when focus has
+    /// already been moved elsewhere on purpose
+    pub fn release_focus(id: FocusRestoreId) {
+        RESTORE_STACK.with(|stack| stack.borrow_mut().remove(id));
+    }
+
+    fn focus_step(container: &Element, direction: FocusDirection) -> bool {
+        let tabbable = Self::tabbable_elements(container);
+        let current = Self::active_element().and_then(|active| {
+            tabbable
+                .iter()
+                .position(|element| element.is_same_node(Some(&active)))
+        });
+        focus_step_index(tabbable.len(), current, direction)
+            .is_some_and(|index| Self::focus(&tabbable[index]))
+    }
+}
+
+/// Direction to move focus in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FocusDirection {
+    Forward,
+    Backward,
+}
+
+/// Index to focus when moving one step from `current` among `len`
+/// tabbable elements, wrapping at both ends
+///
+/// Without a focused element among them, moving forward enters at the first
+/// and moving backward at the last.
+pub fn focus_step_index(
+    len: usize,
+    current: Option<usize>,
+    direction: FocusDirection,
+) -> Option<usize> {
+    let last = len.checked_sub(1)?;
+    let index = match (current, direction) {
+        (None, FocusDirection::Forward) => 0,
+        (None, FocusDirection::Backward) => last,
+        (Some(current), FocusDirection::Forward) if current >= last => 0,
+        (Some(current), FocusDirection::Forward) => current + 1,
+        (Some(0), FocusDirection::Backward) => last,
+        (Some(current), FocusDirection::Backward) => current - 1,
+    };
+    Some(index)
+}
+
+/// Positions into `tab_indices` in Tab order
+fn tab_order(tab_indices: &[i32]) -> Vec<usize> {
+    let mut order = (0..tab_indices.len()).collect::<Vec<_>>();
+    // Stable, so equal tabindex values keep document order
+    order.sort_by_key(|&index| match tab_indices[index] {
+        0 => i32::MAX,
+        tab_index => tab_index,
+    });
+    order
+}
+
+/// Focused elements saved by [`FocusManager::save_focus`]
+type FocusRestoreStack = RestoreStack<Option<Element>>;
+
+thread_local! {
+    static RESTORE_STACK: RefCell<FocusRestoreStack> = RefCell::new(RestoreStack::default());
+}
+
+type RestoreEntry<T> = (FocusRestoreId, Vec<T>);
+
+/// Places to return focus to, most recently opened overlay last
+struct RestoreStack<T> {
+    next_id: u64,
+    /// Each entry's own target first, then ones inherited from entries
+    /// released below it
+    entries: Vec<RestoreEntry<T>>,
+}
+
+impl<T> Default for RestoreStack<T> {
+    fn default() -> Self {
+        Self {
+            next_id: 0,
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl<T> RestoreStack<T> {
+    fn push(&mut self, target: T) -> FocusRestoreId {
+        let id = FocusRestoreId(self.next_id);
+        self.next_id += 1;
+        self.entries.push((id, vec![target]));
+        id
+    }
+
+    /// Take the entry for `id` off the stack, handing its targets to the
+    /// entry above as fallbacks
+    fn remove(&mut self, id: FocusRestoreId) -> Option<Vec<T>>
+    where
+        T: Clone,
+    {
+        let index = self.entries.iter().position(|(entry, _)| *entry == id)?;
+        let (_, targets) = self.entries.remove(index);
+        if let Some((_, above)) = self.entries.get_mut(index) {
+            above.extend(targets.iter().cloned());
+        }
+        Some(targets)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[test]
+    fn test_focus_step_index() {
+        use FocusDirection::*;
+        assert_eq!(focus_step_index(3, Some(0), Forward), Some(1));
+        assert_eq!(focus_step_index(3, Some(2), Forward), Some(0));
+        assert_eq!(focus_step_index(3, Some(0), Backward), Some(2));
+        assert_eq!(focus_step_index(3, Some(2), Backward), Some(1));
+        assert_eq!(focus_step_index(3, None, Forward), Some(0));
+        assert_eq!(focus_step_index(3, None, Backward), Some(2));
+        assert_eq!(focus_step_index(0, None, Forward), None);
+    }
+
+    #[test]
+    fn test_tab_order() {
+        assert_eq!(tab_order(&[0, 2, 0, 1, 2]), vec![3, 1, 4, 0, 2]);
+        assert_eq!(tab_order(&[0, 0]), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_restore_stack_out_of_order() {
+        let mut stack = RestoreStack::default();
+        let outer = stack.push("trigger");
+        let inner = stack.push("menu-item");
+
+        // Closing the outer overlay first hands its target to the inner one
+        assert_eq!(stack.remove(outer), Some(vec!["trigger"]));
+        assert_eq!(stack.remove(inner), Some(vec!["menu-item", "trigger"]));
+        assert_eq!(stack.remove(inner), None);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_tabbable_elements() {
+        let document = web_sys::window().unwrap().document().unwrap();
+        let container = document.create_element("div").unwrap();
+        container.set_inner_html(
+            r##"<button id="a">A</button>
+               <input type="hidden">
+               <button tabindex="-1">Skipped</button>
+               <div tabindex="1" id="b"></div>
+               <div inert><button>Inert</button></div>
+               <a href="#" id="c">C</a>"##,
+        );
+        document.body().unwrap().append_child(&container).unwrap();
+
+        let ids = FocusManager::tabbable_elements(&container)
+            .iter()
+            .map(Element::id)
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec!["b", "a", "c"]);
+
+        assert!(FocusManager::focus_first(&container));
+        assert_eq!(FocusManager::active_element().unwrap().id(), "b");
+        assert!(FocusManager::focus_prev(&container));
+        assert_eq!(FocusManager::active_element().unwrap().id(), "c");
+        container.remove();
+    }
+}
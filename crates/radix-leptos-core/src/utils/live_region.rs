@@ -0,0 +1,375 @@
+use std::collections::HashMap;
+
+use super::accessibility::AriaLive;
+
+/// Well-known announcement channels used by the built-in components
+pub mod channels {
+    /// General status messages, such as loading states
+    pub const STATUS: &str = "status";
+    /// Urgent messages that interrupt the user
+    pub const ALERT: &str = "alert";
+    /// Toast notifications
+    pub const TOAST: &str = "toast";
+    /// Form validation errors
+    pub const VALIDATION: &str = "validation";
+    /// Slider value changes while dragging or using the keyboard
+    pub const SLIDER: &str = "slider";
+    /// Table sort and row selection changes
+    pub const TABLE: &str = "table";
+    /// Number of results while filtering a combobox or search
+    pub const RESULTS: &str = "results";
+}
+
+/// How announcements on a channel are rate limited
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AnnouncementStrategy {
+    /// Every message is announced as soon as it arrives
+    Immediate,
+    /// At most one announcement per interval; the latest suppressed message is
+    /// announced when the interval elapses so the final value is never lost
+    Throttle { interval_ms: f64 },
+    /// Only the last message is announced, once no new message arrived for the delay
+    Debounce { delay_ms: f64 },
+}
+
+/// Politeness and rate limiting for one announcement channel
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChannelConfig {
+    pub politeness: AriaLive,
+    pub strategy: AnnouncementStrategy,
+}
+
+impl Default for ChannelConfig {
+    fn default() -> Self {
+        Self::polite()
+    }
+}
+
+impl ChannelConfig {
+    pub fn polite() -> Self {
+        Self {
+            politeness: AriaLive::Polite,
+            strategy: AnnouncementStrategy::Immediate,
+        }
+    }
+
+    pub fn assertive() -> Self {
+        Self {
+            politeness: AriaLive::Assertive,
+            strategy: AnnouncementStrategy::Immediate,
+        }
+    }
+
+    pub fn throttled(mut self, interval_ms: f64) -> Self {
+        self.strategy = AnnouncementStrategy::Throttle { interval_ms };
+        self
+    }
+
+    pub fn debounced(mut self, delay_ms: f64) -> Self {
+        self.strategy = AnnouncementStrategy::Debounce { delay_ms };
+        self
+    }
+}
+
+/// A message ready to be written into a live region
+#[derive(Debug, Clone, PartialEq)]
+pub struct Announcement {
+    pub channel: String,
+    pub message: String,
+    pub politeness: AriaLive,
+}
+
+#[derive(Debug, Clone, Default)]
+struct ChannelState {
+    last_emitted_at: Option<f64>,
+    pending: Option<String>,
+    due_at: Option<f64>,
+}
+
+/// Per-channel throttling/debouncing of live region announcements
+///
+/// The manager is clock-agnostic: callers pass the current time in
+/// milliseconds, announce anything returned immediately and call
+/// [`LiveRegionManager::flush_due`] once [`LiveRegionManager::next_due`] elapses.
+/// Callers driving a timer use [`LiveRegionManager::schedule_flush`] and
+/// [`LiveRegionManager::flush_scheduled`] so only one timer is pending at a time.
+#[derive(Debug, Clone)]
+pub struct LiveRegionManager {
+    configs: HashMap<String, ChannelConfig>,
+    states: HashMap<String, ChannelState>,
+    flush_at: Option<f64>,
+}
+
+impl Default for LiveRegionManager {
+    fn default() -> Self {
+        let mut manager = Self::empty();
+        manager.configure(channels::STATUS, ChannelConfig::polite());
+        manager.configure(channels::ALERT, ChannelConfig::assertive());
+        manager.configure(channels::TOAST, ChannelConfig::polite().throttled(500.0));
+        manager.configure(
+            channels::VALIDATION,
+            ChannelConfig::assertive().debounced(750.0),
+        );
+        manager.configure(channels::SLIDER, ChannelConfig::polite().throttled(1000.0));
+        manager.configure(channels::TABLE, ChannelConfig::polite().debounced(500.0));
+        manager.configure(channels::RESULTS, ChannelConfig::polite().debounced(500.0));
+        manager
+    }
+}
+
+impl LiveRegionManager {
+    /// Manager with the default channel configuration
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Manager without any preconfigured channels
+    pub fn empty() -> Self {
+        Self {
+            configs: HashMap::new(),
+            states: HashMap::new(),
+            flush_at: None,
+        }
+    }
+
+    /// Set the configuration of a channel
+    pub fn configure(&mut self, channel: &str, config: ChannelConfig) {
+        self.configs.insert(channel.to_string(), config);
+    }
+
+    /// Configuration of a channel; unknown channels are polite and immediate
+    pub fn config(&self, channel: &str) -> ChannelConfig {
+        self.configs.get(channel).copied().unwrap_or_default()
+    }
+
+    /// Submit a message, returning it if it should be announced right away
+    pub fn announce(&mut self, channel: &str, message: &str, now: f64) -> Option<Announcement> {
+        let config = self.config(channel);
+        if config.politeness == AriaLive::Off || message.is_empty() {
+            return None;
+        }
+
+        let state = self.states.entry(channel.to_string()).or_default();
+        match config.strategy {
+            AnnouncementStrategy::Immediate => {
+                state.last_emitted_at = Some(now);
+            }
+            AnnouncementStrategy::Throttle { interval_ms } => {
+                let ready_at = state.last_emitted_at.map(|last| last + interval_ms);
+                match ready_at {
+                    Some(ready_at) if now < ready_at => {
+                        state.pending = Some(message.to_string());
+                        state.due_at = Some(ready_at);
+                        return None;
+                    }
+                    _ => {
+                        state.last_emitted_at = Some(now);
+                        state.pending = None;
+                        state.due_at = None;
+                    }
+                }
+            }
+            AnnouncementStrategy::Debounce { delay_ms } => {
+                state.pending = Some(message.to_string());
+                state.due_at = Some(now + delay_ms);
+                return None;
+            }
+        }
+
+        Some(Announcement {
+            channel: channel.to_string(),
+            message: message.to_string(),
+            politeness: config.politeness,
+        })
+    }
+
+    /// Earliest time at which a pending announcement becomes due
+    pub fn next_due(&self) -> Option<f64> {
+        self.states
+            .values()
+            .filter_map(|state| state.due_at)
+            .min_by(f64::total_cmp)
+    }
+
+    /// Due time a new flush timer should be started for, if any
+    ///
+    /// Returns `None` while the pending timer already fires no later than
+    /// the next due announcement, so rapid announcements share one timer.
+    pub fn schedule_flush(&mut self) -> Option<f64> {
+        let due = self.next_due()?;
+        if self.flush_at.is_some_and(|flush_at| flush_at <= due) {
+            return None;
+        }
+        self.flush_at = Some(due);
+        Some(due)
+    }
+
+    /// Flush for a timer started for `scheduled_at`
+    ///
+    /// A timer superseded by an earlier one flushes nothing; the current
+    /// timer clears the pending handle so the next flush can be scheduled.
+    pub fn flush_scheduled(&mut self, scheduled_at: f64, now: f64) -> Vec<Announcement> {
+        if self.flush_at != Some(scheduled_at) {
+            return Vec::new();
+        }
+        self.flush_at = None;
+        self.flush_due(now)
+    }
+
+    /// Release every pending announcement whose due time has passed
+    pub fn flush_due(&mut self, now: f64) -> Vec<Announcement> {
+        let mut due: Vec<String> = self
+            .states
+            .iter()
+            .filter(|(_, state)| state.due_at.is_some_and(|due_at| due_at <= now))
+            .map(|(channel, _)| channel.clone())
+            .collect();
+        due.sort();
+
+        due.into_iter()
+            .filter_map(|channel| self.commit(&channel, now))
+            .collect()
+    }
+
+    /// Announce a channel's pending message immediately (e.g. at drag end)
+    pub fn commit(&mut self, channel: &str, now: f64) -> Option<Announcement> {
+        let politeness = self.config(channel).politeness;
+        let state = self.states.get_mut(channel)?;
+        state.due_at = None;
+        let message = state.pending.take()?;
+        state.last_emitted_at = Some(now);
+
+        Some(Announcement {
+            channel: channel.to_string(),
+            message,
+            politeness,
+        })
+    }
+
+    /// Drop a channel's pending message without announcing it
+    pub fn cancel(&mut self, channel: &str) {
+        if let Some(state) = self.states.get_mut(channel) {
+            state.pending = None;
+            state.due_at = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unknown_channel_is_immediate() {
+        let mut manager = LiveRegionManager::empty();
+        let first = manager.announce("custom", "Saved", 0.0).unwrap();
+        assert_eq!(first.message, "Saved");
+        assert_eq!(first.politeness, AriaLive::Polite);
+        assert!(manager.announce("custom", "Saved again", 1.0).is_some());
+        assert_eq!(manager.next_due(), None);
+    }
+
+    #[test]
+    fn test_status_and_alert_channels_are_immediate() {
+        let mut manager = LiveRegionManager::new();
+        let status = manager.announce(channels::STATUS, "Loading", 0.0).unwrap();
+        assert_eq!(status.politeness, AriaLive::Polite);
+        let alert = manager.announce(channels::ALERT, "Item dropped", 0.0).unwrap();
+        assert_eq!(alert.politeness, AriaLive::Assertive);
+        assert_eq!(manager.next_due(), None);
+    }
+
+    #[test]
+    fn test_off_channel_drops_messages() {
+        let mut manager = LiveRegionManager::empty();
+        manager.configure(
+            "muted",
+            ChannelConfig {
+                politeness: AriaLive::Off,
+                strategy: AnnouncementStrategy::Immediate,
+            },
+        );
+        assert!(manager.announce("muted", "Hidden", 0.0).is_none());
+        assert_eq!(manager.next_due(), None);
+    }
+
+    #[test]
+    fn test_throttle_keeps_latest_message() {
+        let mut manager = LiveRegionManager::empty();
+        manager.configure(channels::SLIDER, ChannelConfig::polite().throttled(1000.0));
+
+        assert!(manager.announce(channels::SLIDER, "10", 0.0).is_some());
+        assert!(manager.announce(channels::SLIDER, "20", 100.0).is_none());
+        assert!(manager.announce(channels::SLIDER, "30", 200.0).is_none());
+        assert_eq!(manager.next_due(), Some(1000.0));
+
+        assert!(manager.flush_due(999.0).is_empty());
+        let flushed = manager.flush_due(1000.0);
+        assert_eq!(flushed.len(), 1);
+        assert_eq!(flushed[0].message, "30");
+        assert_eq!(manager.next_due(), None);
+    }
+
+    #[test]
+    fn test_commit_announces_pending_at_drag_end() {
+        let mut manager = LiveRegionManager::new();
+        manager.announce(channels::SLIDER, "10", 0.0);
+        manager.announce(channels::SLIDER, "55", 300.0);
+
+        let committed = manager.commit(channels::SLIDER, 400.0).unwrap();
+        assert_eq!(committed.message, "55");
+        assert!(manager.commit(channels::SLIDER, 401.0).is_none());
+        assert!(manager.flush_due(5000.0).is_empty());
+    }
+
+    #[test]
+    fn test_debounce_announces_last_message_after_quiet_period() {
+        let mut manager = LiveRegionManager::new();
+        assert!(manager
+            .announce(channels::VALIDATION, "Email is required", 0.0)
+            .is_none());
+        assert!(manager
+            .announce(channels::VALIDATION, "Email is invalid", 500.0)
+            .is_none());
+        assert!(manager.flush_due(1000.0).is_empty());
+
+        let flushed = manager.flush_due(1250.0);
+        assert_eq!(flushed.len(), 1);
+        assert_eq!(flushed[0].message, "Email is invalid");
+        assert_eq!(flushed[0].politeness, AriaLive::Assertive);
+    }
+
+    #[test]
+    fn test_rapid_announcements_share_one_flush_timer() {
+        let mut manager = LiveRegionManager::new();
+        manager.announce(channels::SLIDER, "0", 0.0);
+        manager.announce(channels::SLIDER, "1", 16.0);
+        assert_eq!(manager.schedule_flush(), Some(1000.0));
+        for step in 2..60 {
+            manager.announce(channels::SLIDER, &step.to_string(), step as f64 * 16.0);
+            assert_eq!(manager.schedule_flush(), None);
+        }
+
+        // An earlier due time replaces the pending timer
+        manager.announce(channels::TABLE, "Sorted by name", 100.0);
+        assert_eq!(manager.schedule_flush(), Some(600.0));
+        assert!(manager.flush_scheduled(1000.0, 1000.0).is_empty());
+
+        let flushed = manager.flush_scheduled(600.0, 600.0);
+        assert_eq!(flushed.len(), 1);
+        assert_eq!(flushed[0].message, "Sorted by name");
+        assert_eq!(manager.schedule_flush(), Some(1000.0));
+        let flushed = manager.flush_scheduled(1000.0, 1000.0);
+        assert_eq!(flushed[0].message, "59");
+        assert_eq!(manager.schedule_flush(), None);
+    }
+
+    #[test]
+    fn test_cancel_drops_pending_message() {
+        let mut manager = LiveRegionManager::new();
+        manager.announce(channels::VALIDATION, "Required", 0.0);
+        manager.cancel(channels::VALIDATION);
+        assert_eq!(manager.next_due(), None);
+        assert!(manager.flush_due(10_000.0).is_empty());
+    }
+}
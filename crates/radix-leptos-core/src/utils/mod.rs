@@ -5,7 +5,11 @@
 pub mod accessibility;
 pub mod dom;
 pub mod events;
+pub mod focus_manager;
+pub mod live_region;
 
 pub use accessibility::*;
 pub use dom::*;
 pub use events::*;
+pub use focus_manager::*;
+pub use live_region::*;
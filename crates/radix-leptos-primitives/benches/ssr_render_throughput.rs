@@ -0,0 +1,168 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use leptos::prelude::*;
+use radix_leptos_primitives::*;
+use std::time::Duration;
+
+fn list_items(count: usize) -> Vec<ListItem<String>> {
+    (0..count)
+        .map(|i| create_list_item(&format!("row-{}", i), format!("Row {}", i)))
+        .collect()
+}
+
+// Server-render a large List with one reactive ListItem per row
+fn benchmark_list_reactive_rows(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ssr_list_reactive_rows");
+
+    for count in [100, 1_000, 10_000] {
+        group.bench_with_input(BenchmarkId::from_parameter(count), &count, |b, &count| {
+            b.iter(|| {
+                let owner = Owner::new();
+                let html = owner.with(|| {
+                    let items = list_items(count);
+                    view! {
+                        <List<String> items=items.clone()>
+                            {items
+                                .into_iter()
+                                .map(|item| {
+                                    let text = item.data.clone();
+                                    view! { <ListItem<String> item=item>{text}</ListItem<String>> }
+                                })
+                                .collect_view()}
+                        </List<String>>
+                    }
+                    .to_html()
+                });
+                black_box(html);
+            });
+        });
+    }
+
+    group.finish();
+}
+
+// Server-render the same List through the `static_rows` fast path
+fn benchmark_list_static_rows(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ssr_list_static_rows");
+
+    for count in [100, 1_000, 10_000] {
+        group.bench_with_input(BenchmarkId::from_parameter(count), &count, |b, &count| {
+            b.iter(|| {
+                let owner = Owner::new();
+                let html = owner.with(|| {
+                    let row_text = Callback::new(|data: String| data);
+                    view! {
+                        <List<String> items=list_items(count) static_rows=true row_text=row_text>
+                            ""
+                        </List<String>>
+                    }
+                    .to_html()
+                });
+                black_box(html);
+            });
+        });
+    }
+
+    group.finish();
+}
+
+// DataTable's announcements create effects, which need an executor to
+// render; like on a server, the effects themselves never run
+struct ServerExecutor;
+
+impl any_spawner::CustomExecutor for ServerExecutor {
+    fn spawn(&self, _: any_spawner::PinnedFuture<()>) {}
+    fn spawn_local(&self, _: any_spawner::PinnedLocalFuture<()>) {}
+    fn poll_local(&self) {}
+}
+
+fn table_rows(count: usize) -> Vec<Vec<String>> {
+    (0..count)
+        .map(|i| vec![format!("Row {}", i), format!("row-{}@example.com", i)])
+        .collect()
+}
+
+// Server-render a large DataTable with one reactive DataTableRow per row
+fn benchmark_data_table_reactive_rows(c: &mut Criterion) {
+    // Already set by the other DataTable benchmark
+    let _ = any_spawner::Executor::init_custom_executor(ServerExecutor);
+    let mut group = c.benchmark_group("ssr_data_table_reactive_rows");
+
+    for count in [100, 1_000, 10_000] {
+        group.bench_with_input(BenchmarkId::from_parameter(count), &count, |b, &count| {
+            b.iter(|| {
+                let owner = Owner::new();
+                let html = owner.with(|| {
+                    view! {
+                        <DataTable>
+                            <DataTableBody>
+                                {table_rows(count)
+                                    .into_iter()
+                                    .map(|row| {
+                                        view! {
+                                            <DataTableRow>
+                                                {row
+                                                    .into_iter()
+                                                    .map(|cell| view! { <DataTableCell>{cell}</DataTableCell> })
+                                                    .collect_view()}
+                                            </DataTableRow>
+                                        }
+                                    })
+                                    .collect_view()}
+                            </DataTableBody>
+                        </DataTable>
+                    }
+                    .to_html()
+                });
+                black_box(html);
+            });
+        });
+    }
+
+    group.finish();
+}
+
+// Server-render the same DataTable through the `static_rows` fast path
+fn benchmark_data_table_static_rows(c: &mut Criterion) {
+    // Already set by the other DataTable benchmark
+    let _ = any_spawner::Executor::init_custom_executor(ServerExecutor);
+    let mut group = c.benchmark_group("ssr_data_table_static_rows");
+
+    for count in [100, 1_000, 10_000] {
+        group.bench_with_input(BenchmarkId::from_parameter(count), &count, |b, &count| {
+            b.iter(|| {
+                let owner = Owner::new();
+                let html = owner.with(|| {
+                    view! { <DataTable static_rows=table_rows(count) /> }.to_html()
+                });
+                black_box(html);
+            });
+        });
+    }
+
+    group.finish();
+}
+
+// String building alone, without the surrounding component
+fn benchmark_static_row_builder(c: &mut Criterion) {
+    let items = list_items(10_000);
+
+    c.bench_function("ssr_static_row_builder_10000", |b| {
+        b.iter(|| black_box(render_static_list_rows(&items, |item| item.data.clone())));
+    });
+}
+
+criterion_group!(
+    name = ssr_render_throughput;
+    config = Criterion::default()
+        .measurement_time(Duration::from_secs(10))
+        .warm_up_time(Duration::from_secs(3))
+        .sample_size(50);
+    targets =
+        benchmark_list_reactive_rows,
+        benchmark_list_static_rows,
+        benchmark_data_table_reactive_rows,
+        benchmark_data_table_static_rows,
+        benchmark_static_row_builder
+);
+
+criterion_main!(ssr_render_throughput);
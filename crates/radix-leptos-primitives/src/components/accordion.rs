@@ -2,6 +2,7 @@ use leptos::callback::Callback;
 use leptos::children::Children;
 use leptos::prelude::*;
 use crate::utils::{merge_optional_classes, generate_id};
+use radix_leptos_core::use_focus_visible;
 
 /// Accordion component with proper accessibility and collapsible sections
 ///
@@ -213,6 +214,8 @@ pub fn AccordionTrigger(
     children: Children,
 ) -> impl IntoView {
     let trigger_id = generate_id("accordion-trigger");
+    let trigger_ref = NodeRef::<leptos::html::Button>::new();
+    let focus_visible = use_focus_visible(trigger_ref);
 
     let base_classes = "radix-accordion-trigger";
     let combined_class = merge_optional_classes(Some(base_classes), class.as_deref())
@@ -237,9 +240,11 @@ pub fn AccordionTrigger(
 
     view! {
         <button
+            node_ref=trigger_ref
             class=combined_class
             style=style
             type="button"
+            data-focus-visible=move || focus_visible.get().then_some("")
             aria-expanded="false"
             aria-controls=trigger_id.clone()
             on:click=handle_click
@@ -0,0 +1,348 @@
+use crate::utils::merge_classes;
+use leptos::callback::Callback;
+use leptos::children::Children;
+use leptos::html;
+use leptos::prelude::*;
+use send_wrapper::SendWrapper;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+
+/// Affix component - Pins its content once it is scrolled past a threshold
+///
+/// The wrapper keeps the content's space in the layout while the content is
+/// fixed, so nothing below it jumps. With `offset_bottom` (and no
+/// `offset_top`) the content is pinned to the bottom edge instead.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn Toolbar() -> impl IntoView {
+///     view! {
+///         <Affix offset_top=64.0 on_change=Callback::new(|affixed| log::info!("affixed: {affixed}"))>
+///             <nav class="toolbar">"Actions"</nav>
+///         </Affix>
+///     }
+/// }
+/// ```
+#[component]
+pub fn Affix(
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    /// Distance from the top of the scroll area at which the content is pinned
+    #[prop(optional)]
+    offset_top: Option<f64>,
+    /// Distance from the bottom of the scroll area at which the content is pinned
+    #[prop(optional)]
+    offset_bottom: Option<f64>,
+    /// CSS selector of the scroll container; the window when unset
+    #[prop(optional)]
+    target: Option<String>,
+    /// Called with `true` when the content becomes pinned and `false` when released
+    #[prop(optional)]
+    on_change: Option<Callback<bool>>,
+    children: Children,
+) -> impl IntoView {
+    let edge = match (offset_top, offset_bottom) {
+        (None, Some(bottom)) => AffixEdge::Bottom(bottom),
+        (top, _) => AffixEdge::Top(top.unwrap_or(0.0)),
+    };
+    let wrapper_ref = NodeRef::<html::Div>::new();
+    let content_ref = NodeRef::<html::Div>::new();
+    let placement = RwSignal::new(None::<AffixPlacement>);
+    let target = StoredValue::new(target);
+
+    let update = move || {
+        let (Some(wrapper), Some(content)) =
+            (wrapper_ref.get_untracked(), content_ref.get_untracked())
+        else {
+            return;
+        };
+        let rect = wrapper.get_bounding_client_rect();
+        let wrapper_rect = AffixRect {
+            top: rect.top(),
+            left: rect.left(),
+            width: rect.width(),
+            height: f64::from(content.offset_height()),
+        };
+        let bounds = target.with_value(|target| scroll_area(target.as_deref()));
+        let next = affix_placement(edge, wrapper_rect, bounds);
+        if next != placement.get_untracked() {
+            let was_affixed = placement.get_untracked().is_some();
+            placement.set(next);
+            if was_affixed != next.is_some() {
+                if let Some(on_change) = on_change {
+                    on_change.run(next.is_some());
+                }
+            }
+        }
+    };
+
+    Effect::new(move |listening: Option<bool>| {
+        if listening == Some(true) {
+            return true;
+        }
+        if wrapper_ref.get().is_none() {
+            return false;
+        }
+        let Some(listeners) =
+            target.with_value(|target| listen_to_scroll(target.as_deref(), update))
+        else {
+            return false;
+        };
+        update();
+        let listeners = SendWrapper::new(listeners);
+        on_cleanup(move || listeners.take().remove());
+        true
+    });
+
+    let class = merge_classes(vec!["affix", class.as_deref().unwrap_or("")]);
+    let wrapper_style = move || match placement.get() {
+        Some(placement) => format!("height: {}px;", placement.height),
+        None => String::new(),
+    };
+    let content_style = move || {
+        format!(
+            "{} {}",
+            placement.get().map(|p| p.to_style()).unwrap_or_default(),
+            style.clone().unwrap_or_default()
+        )
+    };
+
+    view! {
+        <div class="affix-placeholder" node_ref=wrapper_ref style=wrapper_style>
+            <div
+                class=class
+                node_ref=content_ref
+                style=content_style
+                data-state=move || if placement.get().is_some() { "affixed" } else { "static" }
+            >
+                {children()}
+            </div>
+        </div>
+    }
+}
+
+/// Edge the content is pinned to, with its offset in pixels
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AffixEdge {
+    Top(f64),
+    Bottom(f64),
+}
+
+/// Viewport rectangle in pixels
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AffixRect {
+    pub top: f64,
+    pub left: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+/// Vertical extent of the scroll area in viewport coordinates
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AffixBounds {
+    pub top: f64,
+    pub bottom: f64,
+    /// Height of the viewport, used to position bottom-pinned content
+    pub viewport: f64,
+}
+
+/// Fixed position of pinned content
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AffixPlacement {
+    pub edge: AffixEdge,
+    /// Top or bottom coordinate, depending on `edge`
+    pub position: f64,
+    pub left: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl AffixPlacement {
+    pub fn to_style(&self) -> String {
+        let edge = match self.edge {
+            AffixEdge::Top(_) => "top",
+            AffixEdge::Bottom(_) => "bottom",
+        };
+        format!(
+            "position: fixed; {}: {}px; left: {}px; width: {}px; z-index: 10;",
+            edge, self.position, self.left, self.width
+        )
+    }
+}
+
+/// Where the content should be pinned, or `None` while it is in its normal place
+pub fn affix_placement(
+    edge: AffixEdge,
+    rect: AffixRect,
+    bounds: AffixBounds,
+) -> Option<AffixPlacement> {
+    let (affixed, position) = match edge {
+        AffixEdge::Top(offset) => {
+            let line = bounds.top + offset;
+            (rect.top < line, line)
+        }
+        AffixEdge::Bottom(offset) => {
+            let line = bounds.bottom - offset;
+            // `bottom` is measured from the viewport's bottom edge
+            (rect.top + rect.height > line, bounds.viewport - line)
+        }
+    };
+
+    affixed.then_some(AffixPlacement {
+        edge,
+        position,
+        left: rect.left,
+        width: rect.width,
+        height: rect.height,
+    })
+}
+
+fn viewport_height() -> f64 {
+    web_sys::window()
+        .and_then(|window| window.inner_height().ok())
+        .and_then(|height| height.as_f64())
+        .unwrap_or(0.0)
+}
+
+pub(crate) fn scroll_container(selector: Option<&str>) -> Option<web_sys::Element> {
+    let document = web_sys::window()?.document()?;
+    selector.and_then(|selector| document.query_selector(selector).ok().flatten())
+}
+
+fn scroll_area(selector: Option<&str>) -> AffixBounds {
+    match scroll_container(selector) {
+        Some(container) => {
+            let rect = container.get_bounding_client_rect();
+            AffixBounds {
+                top: rect.top(),
+                bottom: rect.bottom(),
+                viewport: viewport_height(),
+            }
+        }
+        None => {
+            let viewport = viewport_height();
+            AffixBounds {
+                top: 0.0,
+                bottom: viewport,
+                viewport,
+            }
+        }
+    }
+}
+
+type ScrollCallback = Closure<dyn FnMut()>;
+
+pub(crate) struct ScrollListeners {
+    targets: Vec<web_sys::EventTarget>,
+    callback: ScrollCallback,
+}
+
+impl ScrollListeners {
+    pub(crate) fn remove(self) {
+        for target in &self.targets {
+            let _ = target.remove_event_listener_with_callback(
+                "scroll",
+                self.callback.as_ref().unchecked_ref(),
+            );
+            let _ = target.remove_event_listener_with_callback(
+                "resize",
+                self.callback.as_ref().unchecked_ref(),
+            );
+        }
+    }
+}
+
+pub(crate) fn listen_to_scroll(
+    selector: Option<&str>,
+    update: impl Fn() + 'static,
+) -> Option<ScrollListeners> {
+    let window = web_sys::window()?;
+    let mut targets: Vec<web_sys::EventTarget> = vec![window.into()];
+    if let Some(container) = scroll_container(selector) {
+        targets.push(container.into());
+    }
+
+    let callback = Closure::<dyn FnMut()>::new(update);
+    for target in &targets {
+        let _ =
+            target.add_event_listener_with_callback("scroll", callback.as_ref().unchecked_ref());
+        let _ =
+            target.add_event_listener_with_callback("resize", callback.as_ref().unchecked_ref());
+    }
+    Some(ScrollListeners { targets, callback })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOUNDS: AffixBounds = AffixBounds {
+        top: 0.0,
+        bottom: 800.0,
+        viewport: 800.0,
+    };
+
+    fn rect(top: f64) -> AffixRect {
+        AffixRect {
+            top,
+            left: 24.0,
+            width: 300.0,
+            height: 48.0,
+        }
+    }
+
+    #[test]
+    fn test_top_affix_pins_after_threshold() {
+        assert_eq!(
+            affix_placement(AffixEdge::Top(64.0), rect(100.0), BOUNDS),
+            None
+        );
+
+        let placement = affix_placement(AffixEdge::Top(64.0), rect(40.0), BOUNDS).unwrap();
+        assert_eq!(placement.position, 64.0);
+        assert_eq!(placement.left, 24.0);
+        assert_eq!(placement.width, 300.0);
+        assert_eq!(placement.height, 48.0);
+    }
+
+    #[test]
+    fn test_top_affix_respects_container_bounds() {
+        let bounds = AffixBounds {
+            top: 120.0,
+            bottom: 600.0,
+            viewport: 800.0,
+        };
+        assert_eq!(
+            affix_placement(AffixEdge::Top(0.0), rect(130.0), bounds),
+            None
+        );
+        let placement = affix_placement(AffixEdge::Top(10.0), rect(125.0), bounds).unwrap();
+        assert_eq!(placement.position, 130.0);
+    }
+
+    #[test]
+    fn test_bottom_affix_pins_when_below_line() {
+        assert_eq!(
+            affix_placement(AffixEdge::Bottom(20.0), rect(500.0), BOUNDS),
+            None
+        );
+        let placement = affix_placement(AffixEdge::Bottom(20.0), rect(760.0), BOUNDS).unwrap();
+        assert_eq!(placement.position, 20.0);
+    }
+
+    #[test]
+    fn test_placement_style() {
+        let style = affix_placement(AffixEdge::Top(16.0), rect(0.0), BOUNDS)
+            .unwrap()
+            .to_style();
+        assert_eq!(
+            style,
+            "position: fixed; top: 16px; left: 24px; width: 300px; z-index: 10;"
+        );
+    }
+}
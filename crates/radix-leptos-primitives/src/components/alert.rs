@@ -1,6 +1,7 @@
 use leptos::callback::Callback;
 use leptos::children::Children;
 use leptos::prelude::*;
+use crate::i18n::{use_i18n, MessageKey};
 use crate::utils::{merge_optional_classes, generate_id};
 
 /// Alert component with proper accessibility and styling variants
@@ -122,7 +123,7 @@ pub fn Alert(
                 view! {
                     <button
                         class="radix-alert-dismiss"
-                        aria-label="Dismiss alert"
+                        aria-label=use_i18n().text(MessageKey::AlertDismiss)
                         on:click=handle_dismiss
                     >
                         "×"
@@ -0,0 +1,364 @@
+use crate::i18n::{use_i18n, MessageKey};
+use crate::utils::merge_classes;
+use leptos::callback::Callback;
+use leptos::prelude::*;
+use send_wrapper::SendWrapper;
+use std::collections::HashSet;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+
+/// Anchor component - Table-of-contents navigation that follows the scroll position
+///
+/// Every [`AnchorLink`] points at an element id. An IntersectionObserver
+/// watches those elements and the first visible one (in document order) is
+/// marked as the active link. Clicking a link scrolls its target into view,
+/// leaving `offset` pixels above it for sticky headers. Links nest to any depth.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn OnThisPage() -> impl IntoView {
+///     let links = vec![
+///         AnchorLink::new("installation", "Installation"),
+///         AnchorLink::new("usage", "Usage")
+///             .child(AnchorLink::new("usage-props", "Props"))
+///             .child(AnchorLink::new("usage-events", "Events")),
+///     ];
+///
+///     view! { <Anchor links=links offset=64.0 /> }
+/// }
+/// ```
+#[component]
+pub fn Anchor(
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    /// Links of the table of contents
+    links: Vec<AnchorLink>,
+    /// Space kept above a target when scrolling to it, in pixels
+    #[prop(optional)]
+    offset: Option<f64>,
+    /// Animate scrolling (default: true)
+    #[prop(optional)]
+    smooth: Option<bool>,
+    /// CSS selector of the scroll container; the viewport when unset
+    #[prop(optional)]
+    container: Option<String>,
+    /// Id of the active target
+    #[prop(optional)]
+    active: Option<ActiveAnchor>,
+    /// Accessible name of the navigation landmark
+    #[prop(optional)]
+    label: Option<String>,
+    /// Called with the id of the new active target
+    #[prop(optional)]
+    on_change: Option<Callback<String>>,
+) -> impl IntoView {
+    let offset = offset.unwrap_or(0.0);
+    let smooth = smooth.unwrap_or(true);
+    let active = active.unwrap_or_else(|| RwSignal::new(None));
+    let label = label.unwrap_or_else(|| use_i18n().text(MessageKey::AnchorLabel));
+    let order = StoredValue::new(flatten_anchor_ids(&links));
+    let visible = RwSignal::new(HashSet::<String>::new());
+
+    // Derive the active link from the visible targets
+    Effect::new(move |_| {
+        let next =
+            visible.with(|visible| order.with_value(|order| first_visible_anchor(order, visible)));
+        if let Some(next) = next {
+            if active.get_untracked().as_deref() != Some(next.as_str()) {
+                active.set(Some(next.clone()));
+                if let Some(on_change) = on_change {
+                    on_change.run(next);
+                }
+            }
+        }
+    });
+
+    let observer_container = container.clone();
+    Effect::new(move |observing: Option<bool>| {
+        if observing == Some(true) {
+            return true;
+        }
+        let Some(observer) = order.with_value(|order| {
+            observe_anchor_targets(order, observer_container.as_deref(), offset, visible)
+        }) else {
+            return false;
+        };
+        let observer = SendWrapper::new(observer);
+        on_cleanup(move || observer.disconnect());
+        true
+    });
+
+    let scroll_container = StoredValue::new(container);
+    let on_select = Callback::new(move |id: String| {
+        scroll_container.with_value(|container| {
+            scroll_to_anchor(&id, container.as_deref(), offset, smooth);
+        });
+        active.set(Some(id.clone()));
+        if let Some(on_change) = on_change {
+            on_change.run(id);
+        }
+    });
+
+    let class = merge_classes(vec!["anchor", class.as_deref().unwrap_or("")]);
+
+    view! {
+        <nav class=class style=style aria-label=label>
+            {render_anchor_links(links, 0, active, on_select)}
+        </nav>
+    }
+}
+
+/// Id of the active [`Anchor`] target, if any
+pub type ActiveAnchor = RwSignal<Option<String>>;
+
+/// A link of an [`Anchor`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnchorLink {
+    /// Id of the target element (without `#`)
+    pub id: String,
+    pub title: String,
+    pub children: Vec<AnchorLink>,
+}
+
+impl AnchorLink {
+    pub fn new(id: &str, title: &str) -> Self {
+        Self {
+            id: id.trim_start_matches('#').to_string(),
+            title: title.to_string(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Add a nested link
+    pub fn child(mut self, link: AnchorLink) -> Self {
+        self.children.push(link);
+        self
+    }
+
+    pub fn href(&self) -> String {
+        format!("#{}", self.id)
+    }
+
+    /// Whether this link or one of its descendants targets `id`
+    pub fn contains(&self, id: &str) -> bool {
+        self.id == id || self.children.iter().any(|child| child.contains(id))
+    }
+}
+
+/// Target ids of the links in document (depth-first) order
+pub fn flatten_anchor_ids(links: &[AnchorLink]) -> Vec<String> {
+    let mut ids = Vec::new();
+    for link in links {
+        ids.push(link.id.clone());
+        ids.extend(flatten_anchor_ids(&link.children));
+    }
+    ids
+}
+
+/// First id in `order` whose target is currently visible
+pub fn first_visible_anchor(order: &[String], visible: &HashSet<String>) -> Option<String> {
+    order.iter().find(|id| visible.contains(*id)).cloned()
+}
+
+fn render_anchor_links(
+    links: Vec<AnchorLink>,
+    depth: usize,
+    active: ActiveAnchor,
+    on_select: Callback<String>,
+) -> AnyView {
+    if links.is_empty() {
+        return ().into_any();
+    }
+
+    view! {
+        <ul class="anchor-list" data-depth=depth>
+            {links
+                .into_iter()
+                .map(|link| {
+                    let id = link.id.clone();
+                    let is_active = {
+                        let id = id.clone();
+                        move || active.get().as_deref() == Some(id.as_str())
+                    };
+                    let has_active_child = {
+                        let link = link.clone();
+                        move || {
+                            active
+                                .get()
+                                .is_some_and(|active| active != link.id && link.contains(&active))
+                        }
+                    };
+                    let is_current = is_active.clone();
+                    let href = link.href();
+                    view! {
+                        <li
+                            class="anchor-item"
+                            data-active=is_active.clone()
+                            data-active-child=has_active_child
+                        >
+                            <a
+                                class="anchor-link"
+                                href=href
+                                aria-current=move || is_current().then_some("location")
+                                on:click=move |event: web_sys::MouseEvent| {
+                                    event.prevent_default();
+                                    on_select.run(id.clone());
+                                }
+                            >
+                                {link.title}
+                            </a>
+                            {render_anchor_links(link.children, depth + 1, active, on_select)}
+                        </li>
+                    }
+                })
+                .collect_view()}
+        </ul>
+    }
+    .into_any()
+}
+
+fn scroll_root(container: Option<&str>) -> Option<web_sys::Element> {
+    let document = web_sys::window()?.document()?;
+    container.and_then(|selector| document.query_selector(selector).ok().flatten())
+}
+
+fn observe_anchor_targets(
+    order: &[String],
+    container: Option<&str>,
+    offset: f64,
+    visible: RwSignal<HashSet<String>>,
+) -> Option<web_sys::IntersectionObserver> {
+    let document = web_sys::window()?.document()?;
+    let callback = Closure::<dyn FnMut(js_sys::Array)>::new(move |entries: js_sys::Array| {
+        visible.update(|visible| {
+            for entry in entries.iter() {
+                let entry: web_sys::IntersectionObserverEntry = entry.unchecked_into();
+                let id = entry.target().id();
+                if entry.is_intersecting() {
+                    visible.insert(id);
+                } else {
+                    visible.remove(&id);
+                }
+            }
+        });
+    });
+
+    let options = web_sys::IntersectionObserverInit::new();
+    options.set_root(scroll_root(container).as_ref());
+    // Only the upper part of the scroll area counts as "being read"
+    options.set_root_margin(&format!("-{}px 0px -40% 0px", offset.max(0.0)));
+    options.set_threshold_f64(0.0);
+
+    let observer = web_sys::IntersectionObserver::new_with_options(
+        callback.as_ref().unchecked_ref(),
+        &options,
+    )
+    .ok()?;
+    callback.forget();
+
+    for id in order {
+        if let Some(target) = document.get_element_by_id(id) {
+            observer.observe(&target);
+        }
+    }
+    Some(observer)
+}
+
+/// Scroll `id` into view, keeping `offset` pixels above it
+pub fn scroll_to_anchor(id: &str, container: Option<&str>, offset: f64, smooth: bool) {
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    let Some(target) = window
+        .document()
+        .and_then(|document| document.get_element_by_id(id))
+    else {
+        return;
+    };
+
+    let root = scroll_root(container);
+    let root_top = root
+        .as_ref()
+        .map(|root| root.get_bounding_client_rect().top())
+        .unwrap_or(0.0);
+    let delta = target.get_bounding_client_rect().top() - root_top - offset;
+
+    let options = web_sys::ScrollToOptions::new();
+    options.set_top(delta);
+    options.set_behavior(if smooth {
+        web_sys::ScrollBehavior::Smooth
+    } else {
+        web_sys::ScrollBehavior::Auto
+    });
+    match root {
+        Some(root) => root.scroll_by_with_scroll_to_options(&options),
+        None => window.scroll_by_with_scroll_to_options(&options),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn links() -> Vec<AnchorLink> {
+        vec![
+            AnchorLink::new("#intro", "Intro"),
+            AnchorLink::new("api", "API")
+                .child(
+                    AnchorLink::new("api-props", "Props")
+                        .child(AnchorLink::new("api-props-size", "size")),
+                )
+                .child(AnchorLink::new("api-events", "Events")),
+            AnchorLink::new("faq", "FAQ"),
+        ]
+    }
+
+    #[test]
+    fn test_anchor_link_strips_hash() {
+        let link = AnchorLink::new("#intro", "Intro");
+        assert_eq!(link.id, "intro");
+        assert_eq!(link.href(), "#intro");
+    }
+
+    #[test]
+    fn test_flatten_anchor_ids_is_depth_first() {
+        assert_eq!(
+            flatten_anchor_ids(&links()),
+            vec![
+                "intro",
+                "api",
+                "api-props",
+                "api-props-size",
+                "api-events",
+                "faq"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_anchor_link_contains_descendants() {
+        let api = &links()[1];
+        assert!(api.contains("api"));
+        assert!(api.contains("api-props-size"));
+        assert!(!api.contains("faq"));
+    }
+
+    #[test]
+    fn test_first_visible_anchor_uses_document_order() {
+        let order = flatten_anchor_ids(&links());
+        let visible: HashSet<String> = ["faq", "api-events"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(
+            first_visible_anchor(&order, &visible),
+            Some("api-events".to_string())
+        );
+        assert_eq!(first_visible_anchor(&order, &HashSet::new()), None);
+    }
+}
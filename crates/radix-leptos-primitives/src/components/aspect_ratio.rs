@@ -1,3 +1,4 @@
+use crate::i18n::{use_i18n, MessageKey};
 use crate::utils::merge_classes;
 use leptos::children::Children;
 use leptos::prelude::*;
@@ -42,7 +43,7 @@ pub fn AspectRatio(
             class=class
             style=container_style
             role="img"
-            aria-label="Aspect ratio container"
+            aria-label=use_i18n().text(MessageKey::AspectRatioContainer)
             data-ratio=ratio
             data-width=width
             data-height=height
@@ -79,7 +80,7 @@ pub fn AspectRatioContainer(
             class=class
             style=style
             role="img"
-            aria-label="Aspect ratio container"
+            aria-label=use_i18n().text(MessageKey::AspectRatioContainer)
             data-ratio=ratio
         >
             {children.map(|c| c())}
@@ -112,7 +113,7 @@ pub fn AspectRatioWrapper(
             class=class
             style=style
             role="img"
-            aria-label="Aspect ratio wrapper"
+            aria-label=use_i18n().text(MessageKey::AspectRatioWrapper)
             data-ratio=ratio
             data-fit=fit.to_string()
         >
@@ -0,0 +1,408 @@
+use crate::components::slider::Slider;
+use crate::components::spinner::Spinner;
+use crate::i18n::{use_i18n, MessageArgs, MessageKey};
+use crate::utils::merge_classes;
+use leptos::html;
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+
+/// Playback speeds offered by the speed button, in cycling order
+pub const PLAYBACK_RATES: [f64; 6] = [0.5, 0.75, 1.0, 1.25, 1.5, 2.0];
+
+/// Volume change applied by the Up and Down arrow shortcuts
+const VOLUME_STEP: f64 = 0.1;
+
+/// Action triggered by a media player keyboard shortcut
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MediaShortcut {
+    TogglePlay,
+    /// Seek by the given number of seconds
+    Seek(f64),
+    /// Change the volume by the given amount
+    Volume(f64),
+    ToggleMute,
+}
+
+/// Shortcut bound to `key`, seeking by `seek_step` seconds
+pub fn media_shortcut(key: &str, seek_step: f64) -> Option<MediaShortcut> {
+    match key {
+        " " | "k" | "K" => Some(MediaShortcut::TogglePlay),
+        "ArrowLeft" | "j" | "J" => Some(MediaShortcut::Seek(-seek_step)),
+        "ArrowRight" | "l" | "L" => Some(MediaShortcut::Seek(seek_step)),
+        "ArrowUp" => Some(MediaShortcut::Volume(VOLUME_STEP)),
+        "ArrowDown" => Some(MediaShortcut::Volume(-VOLUME_STEP)),
+        "m" | "M" => Some(MediaShortcut::ToggleMute),
+        _ => None,
+    }
+}
+
+/// Formats a position as `m:ss`, or `h:mm:ss` past an hour
+pub fn format_media_time(seconds: f64) -> String {
+    let total = if seconds.is_finite() && seconds > 0.0 {
+        seconds.floor() as u64
+    } else {
+        0
+    };
+    let (hours, minutes, secs) = (total / 3600, total / 60 % 60, total % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, secs)
+    } else {
+        format!("{}:{:02}", minutes, secs)
+    }
+}
+
+/// Spoken form of a position, e.g. "1 minute 5 seconds"
+pub fn media_time_text(seconds: f64) -> String {
+    let total = if seconds.is_finite() && seconds > 0.0 {
+        seconds.floor() as u64
+    } else {
+        0
+    };
+    let unit =
+        |count: u64, name: &str| format!("{} {}{}", count, name, if count == 1 { "" } else { "s" });
+    let (hours, minutes, secs) = (total / 3600, total / 60 % 60, total % 60);
+    let mut parts = Vec::new();
+    if hours > 0 {
+        parts.push(unit(hours, "hour"));
+    }
+    if minutes > 0 {
+        parts.push(unit(minutes, "minute"));
+    }
+    if secs > 0 || parts.is_empty() {
+        parts.push(unit(secs, "second"));
+    }
+    parts.join(" ")
+}
+
+/// Speed that follows `current` in [`PLAYBACK_RATES`], wrapping around
+pub fn next_playback_rate(current: f64) -> f64 {
+    PLAYBACK_RATES
+        .iter()
+        .copied()
+        .find(|rate| *rate > current + f64::EPSILON)
+        .unwrap_or(PLAYBACK_RATES[0])
+}
+
+/// Whether a shortcut key should be left to the focused control, e.g.
+/// Space activating a button
+pub(crate) fn is_native_activation(e: &web_sys::KeyboardEvent) -> bool {
+    let tag = e
+        .target()
+        .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+        .map(|element| element.tag_name())
+        .unwrap_or_default();
+    matches!(e.key().as_str(), " " | "Enter") && matches!(tag.as_str(), "BUTTON" | "SELECT")
+}
+
+/// AudioPlayer component - Accessible controls for an audio source
+///
+/// Renders play/pause, a seek [`Slider`] whose value is read out as
+/// "1 minute 5 seconds of 3 minutes", a playback speed button and volume
+/// controls. While focus is inside the player, Space or K toggles playback,
+/// the Left/Right arrows (or J/L) seek, Up/Down change the volume and M
+/// mutes.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn Episode() -> impl IntoView {
+///     view! {
+///         <AudioPlayer
+///             src="/media/episode-12.mp3".to_string()
+///             label="Episode 12: Signals".to_string()
+///         />
+///     }
+/// }
+/// ```
+#[component]
+pub fn AudioPlayer(
+    src: String,
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    /// Accessible name of the player (default: "Audio player")
+    #[prop(optional)]
+    label: Option<String>,
+    /// Seconds skipped by the seek shortcuts (default: 5)
+    #[prop(optional)]
+    seek_step: Option<f64>,
+    #[prop(optional)] on_ended: Option<Callback<()>>,
+) -> impl IntoView {
+    let seek_step = seek_step.unwrap_or(5.0);
+    let catalog = use_i18n();
+    let label = label.unwrap_or_else(|| catalog.text(MessageKey::AudioPlayerLabel));
+    let audio_ref = NodeRef::<html::Audio>::new();
+
+    let playing = RwSignal::new(false);
+    let loading = RwSignal::new(false);
+    let current_time = RwSignal::new(0.0);
+    let duration = RwSignal::new(0.0);
+    let volume = RwSignal::new(1.0);
+    let muted = RwSignal::new(false);
+    let rate = RwSignal::new(1.0);
+
+    let sync = move || {
+        if let Some(audio) = audio_ref.get_untracked() {
+            playing.set(!audio.paused());
+            current_time.set(audio.current_time());
+            let length = audio.duration();
+            duration.set(if length.is_finite() { length } else { 0.0 });
+            volume.set(audio.volume());
+            muted.set(audio.muted());
+            rate.set(audio.playback_rate());
+        }
+    };
+
+    let toggle_play = move || {
+        if let Some(audio) = audio_ref.get_untracked() {
+            if audio.paused() {
+                // Rejected when the browser blocks playback; the pause state stays
+                let _ = audio.play();
+            } else {
+                let _ = audio.pause();
+            }
+        }
+    };
+    let seek_to = move |seconds: f64| {
+        if let Some(audio) = audio_ref.get_untracked() {
+            let end = duration.get_untracked().max(0.0);
+            audio.set_current_time(seconds.clamp(0.0, end));
+            current_time.set(audio.current_time());
+        }
+    };
+    let set_volume = move |level: f64| {
+        if let Some(audio) = audio_ref.get_untracked() {
+            audio.set_volume(level.clamp(0.0, 1.0));
+            audio.set_muted(level <= 0.0);
+        }
+    };
+    let toggle_mute = move || {
+        if let Some(audio) = audio_ref.get_untracked() {
+            audio.set_muted(!audio.muted());
+        }
+    };
+    let cycle_rate = move |_| {
+        if let Some(audio) = audio_ref.get_untracked() {
+            audio.set_playback_rate(next_playback_rate(audio.playback_rate()));
+        }
+    };
+
+    let handle_keydown = move |e: web_sys::KeyboardEvent| {
+        if e.default_prevented() || e.ctrl_key() || e.meta_key() || e.alt_key() {
+            return;
+        }
+        if is_native_activation(&e) {
+            return;
+        }
+        let Some(shortcut) = media_shortcut(&e.key(), seek_step) else {
+            return;
+        };
+        e.prevent_default();
+        match shortcut {
+            MediaShortcut::TogglePlay => toggle_play(),
+            MediaShortcut::Seek(delta) => seek_to(current_time.get_untracked() + delta),
+            MediaShortcut::Volume(delta) => {
+                let level = if muted.get_untracked() {
+                    0.0
+                } else {
+                    volume.get_untracked()
+                };
+                set_volume(level + delta);
+            }
+            MediaShortcut::ToggleMute => toggle_mute(),
+        }
+    };
+
+    let handle_ended = move |_| {
+        playing.set(false);
+        if let Some(on_ended) = on_ended {
+            on_ended.run(());
+        }
+    };
+
+    let seek_catalog = catalog.clone();
+    let seek_slider = move || {
+        let length = duration.get();
+        let ready = length > 0.0;
+        let catalog = seek_catalog.clone();
+        let seek_label = catalog.text(MessageKey::VideoPlayerSeek);
+        let value_text = Callback::new(move |seconds: f64| {
+            let args = MessageArgs::new()
+                .with("current", media_time_text(seconds))
+                .with("duration", media_time_text(length));
+            catalog.format(MessageKey::VideoPlayerPosition, &args)
+        });
+        view! {
+            <Slider
+                class="audio-player-seek".to_string()
+                value=Signal::derive(move || current_time.get())
+                max=if ready { length } else { 1.0 }
+                disabled=!ready
+                aria_label=seek_label
+                value_text=value_text
+                on_value_change=Callback::new(seek_to)
+                _children=Box::new(|| ().into_any())
+            />
+        }
+    };
+
+    let play_label = catalog.text(MessageKey::VideoPlayerPlay);
+    let pause_label = catalog.text(MessageKey::VideoPlayerPause);
+    let speed_catalog = catalog.clone();
+
+    let class = merge_classes(vec!["audio-player", class.as_deref().unwrap_or("")]);
+
+    view! {
+        <div
+            class=class
+            style=style
+            role="group"
+            aria-label=label
+            aria-roledescription=catalog.text(MessageKey::AudioPlayerRole)
+            data-state=move || if playing.get() { "playing" } else { "paused" }
+            on:keydown=handle_keydown
+        >
+            <audio
+                node_ref=audio_ref
+                src=src
+                preload="metadata"
+                on:loadedmetadata=move |_| sync()
+                on:durationchange=move |_| sync()
+                on:timeupdate=move |_| sync()
+                on:play=move |_| playing.set(true)
+                on:pause=move |_| playing.set(false)
+                on:waiting=move |_| loading.set(true)
+                on:canplay=move |_| loading.set(false)
+                on:volumechange=move |_| sync()
+                on:ratechange=move |_| sync()
+                on:ended=handle_ended
+            ></audio>
+            <button
+                type="button"
+                class="audio-player-play"
+                aria-label=move || {
+                    if playing.get() { pause_label.clone() } else { play_label.clone() }
+                }
+                aria-busy=move || loading.get().then_some("true")
+                on:click=move |_| toggle_play()
+            >
+                {move || {
+                    if loading.get() {
+                        view! { <Spinner /> }.into_any()
+                    } else if playing.get() {
+                        view! { <span aria-hidden="true">"❚❚"</span> }.into_any()
+                    } else {
+                        view! { <span aria-hidden="true">"▶"</span> }.into_any()
+                    }
+                }}
+            </button>
+            <span class="audio-player-time" aria-hidden="true">
+                {move || format_media_time(current_time.get())}
+                " / "
+                {move || format_media_time(duration.get())}
+            </span>
+            {seek_slider}
+            <button
+                type="button"
+                class="audio-player-rate"
+                aria-label=move || {
+                    speed_catalog.format(
+                        MessageKey::VideoPlayerSpeed,
+                        &MessageArgs::new().with("rate", rate.get()),
+                    )
+                }
+                on:click=cycle_rate
+            >
+                {move || format!("{}×", rate.get())}
+            </button>
+            <button
+                type="button"
+                class="audio-player-mute"
+                aria-label=catalog.text(MessageKey::VideoPlayerMute)
+                aria-pressed=move || muted.get().to_string()
+                on:click=move |_| toggle_mute()
+            >
+                <span aria-hidden="true">{move || if muted.get() { "🔇" } else { "🔊" }}</span>
+            </button>
+            <Slider
+                class="audio-player-volume".to_string()
+                value=Signal::derive(move || if muted.get() { 0.0 } else { volume.get() * 100.0 })
+                max=100.0
+                step=5.0
+                aria_label=catalog.text(MessageKey::VideoPlayerVolume)
+                value_text=Callback::new(|level: f64| format!("{}%", level.round()))
+                on_value_change=Callback::new(move |level: f64| set_volume(level / 100.0))
+                _children=Box::new(|| ().into_any())
+            />
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_media_time() {
+        assert_eq!(format_media_time(0.0), "0:00");
+        assert_eq!(format_media_time(65.7), "1:05");
+        assert_eq!(format_media_time(3725.0), "1:02:05");
+        assert_eq!(format_media_time(f64::NAN), "0:00");
+        assert_eq!(media_time_text(65.0), "1 minute 5 seconds");
+        assert_eq!(media_time_text(3600.0), "1 hour");
+        assert_eq!(media_time_text(0.0), "0 seconds");
+    }
+
+    #[test]
+    fn test_next_playback_rate() {
+        assert_eq!(next_playback_rate(1.0), 1.25);
+        assert_eq!(next_playback_rate(2.0), 0.5);
+        assert_eq!(next_playback_rate(1.1), 1.25);
+    }
+
+    #[test]
+    fn test_media_shortcut() {
+        assert_eq!(media_shortcut(" ", 5.0), Some(MediaShortcut::TogglePlay));
+        assert_eq!(
+            media_shortcut("ArrowLeft", 5.0),
+            Some(MediaShortcut::Seek(-5.0))
+        );
+        assert_eq!(media_shortcut("l", 10.0), Some(MediaShortcut::Seek(10.0)));
+        assert_eq!(media_shortcut("M", 5.0), Some(MediaShortcut::ToggleMute));
+        assert_eq!(media_shortcut("x", 5.0), None);
+    }
+
+    #[test]
+    fn test_audio_player_markup() {
+        crate::test_support::init_test_executor();
+        let owner = Owner::new();
+        owner.with(|| {
+            let html = view! { <AudioPlayer src="/a.mp3".to_string() /> }.to_html();
+            assert!(html.contains("aria-label=\"Audio player\""));
+            assert!(html.contains("aria-label=\"Play\""));
+            assert!(html.contains("aria-label=\"Seek\""));
+            assert!(html.contains("aria-label=\"Volume\""));
+            assert!(html.contains("aria-valuetext=\"100%\""));
+        });
+    }
+    #[test]
+    fn test_audio_player_uses_catalog() {
+        crate::test_support::init_test_executor();
+        let owner = Owner::new();
+        owner.with(|| {
+            provide_context(
+                crate::i18n::MessageCatalog::new("de")
+                    .with_message(MessageKey::AudioPlayerLabel, "Audioplayer")
+                    .with_message(MessageKey::VideoPlayerPlay, "Abspielen")
+                    .with_message(MessageKey::VideoPlayerVolume, "Lautstärke"),
+            );
+            let html = view! { <AudioPlayer src="/a.mp3".to_string() /> }.to_html();
+            assert!(html.contains("aria-label=\"Audioplayer\""));
+            assert!(html.contains("aria-label=\"Abspielen\""));
+            assert!(html.contains("aria-label=\"Lautstärke\""));
+        });
+    }
+}
@@ -1,7 +1,11 @@
+use crate::components::popover::{PopoverContent, PopoverSide};
+use crate::i18n::{use_i18n, MessageArgs, MessageKey};
 use crate::utils::{merge_classes, generate_id};
 use leptos::callback::Callback;
 use leptos::children::Children;
+use leptos::html;
 use leptos::prelude::*;
+use radix_leptos_core::{use_outside_click, FocusManager};
 
 /// Avatar component - User profile images with fallbacks
 #[component]
@@ -19,10 +23,12 @@ pub fn Avatar(
     #[prop(optional)] on_error: Option<Callback<()>>,
 ) -> impl IntoView {
     let src = src.unwrap_or_default();
-    let alt = alt.unwrap_or_else(|| "Avatar".to_string());
+    let alt = alt.unwrap_or_else(|| use_i18n().text(MessageKey::AvatarLabel));
     let fallback = fallback.unwrap_or_else(|| "?".to_string());
-    let size = size.unwrap_or_default();
-    let shape = shape.unwrap_or_default();
+    // Avatars inside an AvatarGroup inherit its size and shape
+    let group = use_context::<AvatarGroupContext>();
+    let size = size.or(group.map(|g| g.size)).unwrap_or_default();
+    let shape = shape.or(group.map(|g| g.shape)).unwrap_or_default();
     let loading = loading.unwrap_or_default();
 
     let class = merge_classes(vec![
@@ -61,7 +67,7 @@ pub fn AvatarImage(
     #[prop(optional)] on_error: Option<Callback<()>>,
 ) -> impl IntoView {
     let src = src.unwrap_or_default();
-    let alt = alt.unwrap_or_else(|| "Avatar image".to_string());
+    let alt = alt.unwrap_or_else(|| use_i18n().text(MessageKey::AvatarImage));
 
     let class = merge_classes(vec!["avatar-image", class.as_deref().unwrap_or("")]);
 
@@ -106,45 +112,290 @@ pub fn AvatarFallback(
             class=class
             style=style
             role="img"
-            aria-label="Avatar fallback"
+            aria-label=use_i18n().text(MessageKey::AvatarFallback)
         >
             {children.map(|c| c())}
         </div>
     }
 }
 
-/// Avatar Group component
+/// An avatar rendered by [`AvatarGroup`] from data
+#[derive(Debug, Clone, PartialEq)]
+pub struct AvatarGroupItem {
+    pub name: String,
+    pub src: Option<String>,
+}
+
+impl AvatarGroupItem {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            src: None,
+        }
+    }
+
+    pub fn src(mut self, src: impl Into<String>) -> Self {
+        self.src = Some(src.into());
+        self
+    }
+}
+
+/// Size and shape shared with the avatars of a group
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AvatarGroupContext {
+    pub size: AvatarSize,
+    pub shape: AvatarShape,
+}
+
+/// Number of avatars hidden behind the "+N" indicator
+///
+/// When anything overflows, the indicator takes the last visible slot so
+/// the group never renders more than `max_visible` elements.
+pub fn avatar_overflow_count(total: usize, max_visible: usize) -> usize {
+    if total <= max_visible {
+        0
+    } else {
+        total - max_visible.saturating_sub(1)
+    }
+}
+
+/// Up to two initials from a display name
+pub fn avatar_initials(name: &str) -> String {
+    let words: Vec<&str> = name.split_whitespace().collect();
+    let letters = match words.as_slice() {
+        [] => return "?".to_string(),
+        [word] => vec![*word],
+        [first, .., last] => vec![*first, *last],
+    };
+    letters
+        .iter()
+        .filter_map(|word| word.chars().next())
+        .flat_map(char::to_uppercase)
+        .collect()
+}
+
+/// Avatar Group component - Overlapping stack of avatars
+///
+/// Avatars passed as `avatars` are rendered by the group; past
+/// `max_count` the rest collapse into a "+N" button that opens a popover
+/// listing the hidden people. The popover closes on Escape or when focus
+/// leaves it. Avatars passed as children are stacked as-is. Either
+/// way they inherit the group's `size` and `shape` unless they set their
+/// own.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn Reviewers() -> impl IntoView {
+///     let avatars = vec![
+///         AvatarGroupItem::new("Ada Lovelace").src("/ada.png"),
+///         AvatarGroupItem::new("Alan Turing"),
+///         AvatarGroupItem::new("Grace Hopper"),
+///         AvatarGroupItem::new("Edsger Dijkstra"),
+///     ];
+///     view! { <AvatarGroup avatars=avatars max_count=3 size=AvatarSize::Medium /> }
+/// }
+/// ```
 #[component]
 pub fn AvatarGroup(
     #[prop(optional)] class: Option<String>,
     #[prop(optional)] style: Option<String>,
     #[prop(optional)] children: Option<Children>,
-    #[prop(optional)] maxvisible: Option<usize>,
+    #[prop(optional)] avatars: Option<Vec<AvatarGroupItem>>,
+    /// Largest number of avatars shown, counting the "+N" button
+    /// (default: 5)
+    #[prop(optional)]
+    max_count: Option<usize>,
+    /// Older name of `max_count`
+    #[prop(optional)]
+    maxvisible: Option<usize>,
     #[prop(optional)] spacing: Option<AvatarSpacing>,
+    /// Overlap between avatars in pixels; overrides `spacing`
+    #[prop(optional)]
+    overlap: Option<f64>,
+    #[prop(optional)] size: Option<AvatarSize>,
+    #[prop(optional)] shape: Option<AvatarShape>,
+    /// Accessible name of the group (default: "Avatar group")
+    #[prop(optional)]
+    label: Option<String>,
 ) -> impl IntoView {
-    let maxvisible = maxvisible.unwrap_or(5);
+    let maxvisible = max_count.or(maxvisible).unwrap_or(5).max(1);
     let spacing = spacing.unwrap_or_default();
+    let overlap = overlap.unwrap_or_else(|| spacing.overlap());
+    let size = size.unwrap_or_default();
+    let shape = shape.unwrap_or_default();
+    let label = label.unwrap_or_else(|| use_i18n().text(MessageKey::AvatarGroupLabel));
+
+    provide_context(AvatarGroupContext { size, shape });
 
     let class = merge_classes(vec![
         "avatar-group",
         &spacing.to_class(),
         class.as_deref().unwrap_or(""),
     ]);
+    let style = format!(
+        "display: inline-flex; align-items: center; --avatar-group-overlap: {}px; {}",
+        overlap,
+        style.unwrap_or_default()
+    );
+    let stacked = format!("margin-inline-start: -{}px;", overlap);
+
+    let mut avatars = avatars.unwrap_or_default();
+    let hidden_count = avatar_overflow_count(avatars.len(), maxvisible);
+    let hidden = avatars.split_off(avatars.len() - hidden_count);
+
+    let visible = avatars
+        .into_iter()
+        .enumerate()
+        .map(|(index, avatar)| {
+            let style = if index > 0 { stacked.clone() } else { String::new() };
+            view! {
+                <Avatar alt=avatar.name.clone() style=style>
+                    {avatar_contents(avatar)}
+                </Avatar>
+            }
+        })
+        .collect_view();
+
+    let overflow = (hidden_count > 0).then(|| {
+        view! {
+            <AvatarGroupOverflow
+                avatars=hidden
+                size=size
+                shape=shape
+                style=stacked.clone()
+            />
+        }
+    });
 
     view! {
         <div
             class=class
             style=style
             role="group"
-            aria-label="Avatar group"
+            aria-label=label
             data-max-visible=maxvisible
             data-spacing=spacing.to_string()
+            data-size=size.to_string()
         >
             {children.map(|c| c())}
+            {visible}
+            {overflow}
         </div>
     }
 }
 
+fn avatar_contents(avatar: AvatarGroupItem) -> AnyView {
+    match avatar.src {
+        // The Avatar carries the accessible name
+        Some(src) => view! { <AvatarImage src=src alt=String::new() /> }.into_any(),
+        None => view! {
+            <span class="avatar-fallback" aria-hidden="true">
+                {avatar_initials(&avatar.name)}
+            </span>
+        }
+        .into_any(),
+    }
+}
+
+/// "+N" button listing the avatars hidden by an AvatarGroup
+#[component]
+fn AvatarGroupOverflow(
+    avatars: Vec<AvatarGroupItem>,
+    size: AvatarSize,
+    shape: AvatarShape,
+    style: String,
+) -> impl IntoView {
+    let open = RwSignal::new(false);
+    let wrapper_ref = NodeRef::<html::Span>::new();
+    let trigger_ref = NodeRef::<html::Button>::new();
+    let list_id = generate_id("avatar-group-overflow");
+    let count = avatars.len();
+    let names = avatars
+        .iter()
+        .map(|avatar| avatar.name.as_str())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let catalog = use_i18n();
+    let button_label = catalog.format(
+        MessageKey::AvatarGroupOverflowButton,
+        &MessageArgs::new().with("count", count).with("names", names),
+    );
+    let list_label = catalog.format(
+        MessageKey::AvatarGroupOverflow,
+        &MessageArgs::new().with("count", count),
+    );
+
+    let handle_keydown = move |ev: web_sys::KeyboardEvent| {
+        if ev.key() == "Escape" && open.get_untracked() {
+            ev.prevent_default();
+            open.set(false);
+            if let Some(trigger) = trigger_ref.get_untracked() {
+                FocusManager::focus(&trigger);
+            }
+        }
+    };
+    use_outside_click(
+        wrapper_ref,
+        move || {
+            if open.get_untracked() {
+                open.set(false);
+            }
+        },
+        None,
+    );
+
+    let class = merge_classes(vec![
+        "avatar",
+        "avatar-overflow",
+        size.to_class(),
+        shape.to_class(),
+    ]);
+    let items = avatars
+        .into_iter()
+        .map(|avatar| {
+            let name = avatar.name.clone();
+            view! {
+                <li class="avatar-group-overflow-item">
+                    <Avatar alt=name.clone()>{avatar_contents(avatar)}</Avatar>
+                    <span>{name}</span>
+                </li>
+            }
+        })
+        .collect_view();
+
+    view! {
+        <span class="avatar-group-overflow" node_ref=wrapper_ref on:keydown=handle_keydown>
+            <button
+                type="button"
+                class=class
+                style=style
+                node_ref=trigger_ref
+                aria-haspopup="dialog"
+                aria-expanded=move || open.get().to_string()
+                aria-controls=list_id.clone()
+                aria-label=button_label
+                on:click=move |_| open.update(|open| *open = !*open)
+            >
+                {format!("+{}", count)}
+            </button>
+            <PopoverContent
+                class="avatar-group-overflow-list".to_string()
+                visible=open.read_only()
+                side=PopoverSide::Bottom
+                attr:id=list_id
+                attr:aria-label=list_label
+            >
+                <ul>{items}</ul>
+            </PopoverContent>
+        </span>
+    }
+}
+
 /// Avatar Size enum
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub enum AvatarSize {
@@ -239,6 +490,15 @@ pub enum AvatarSpacing {
 }
 
 impl AvatarSpacing {
+    /// Overlap between neighbouring avatars in pixels
+    pub fn overlap(&self) -> f64 {
+        match self {
+            AvatarSpacing::Tight => 12.0,
+            AvatarSpacing::Normal => 8.0,
+            AvatarSpacing::Loose => 4.0,
+        }
+    }
+
     pub fn to_class(&self) -> &'static str {
         match self {
             AvatarSpacing::Tight => "spacing-tight",
@@ -260,6 +520,7 @@ impl AvatarSpacing {
 
 #[cfg(test)]
 mod tests {
+    use super::*;
     use proptest::prelude::*;
     use wasm_bindgen_test::*;
 
@@ -401,7 +662,41 @@ mod tests {
     #[test]
     fn test_avatar_fallback_display() {}
     #[test]
-    fn test_avatar_group_overflow() {}
+    fn test_avatar_group_overflow() {
+        assert_eq!(avatar_overflow_count(3, 5), 0);
+        assert_eq!(avatar_overflow_count(5, 5), 0);
+        // "+N" takes the last slot
+        assert_eq!(avatar_overflow_count(6, 5), 2);
+        assert_eq!(avatar_overflow_count(10, 1), 10);
+    }
+
+    #[test]
+    fn test_avatar_initials() {
+        assert_eq!(avatar_initials("Ada Lovelace"), "AL");
+        assert_eq!(avatar_initials("grace"), "G");
+        assert_eq!(avatar_initials("Edsger W. Dijkstra"), "ED");
+        assert_eq!(avatar_initials("  "), "?");
+    }
+
+    #[test]
+    fn test_avatar_group_renders_overflow() {
+        let avatars = ["Ada Lovelace", "Alan Turing", "Grace Hopper", "Edsger Dijkstra"]
+            .into_iter()
+            .map(AvatarGroupItem::new)
+            .collect::<Vec<_>>();
+        crate::test_support::init_test_executor();
+        let html = Owner::new().with(|| {
+            view! { <AvatarGroup avatars=avatars max_count=3 maxvisible=2 size=AvatarSize::Large /> }
+                .to_html()
+        });
+        assert!(html.contains("data-max-visible=\"3\""));
+        assert!(html.contains("+2"));
+        assert!(html.contains("2 more: Grace Hopper, Edsger Dijkstra"));
+        assert_eq!(html.matches("size-large").count(), 5);
+        assert!(html.contains("aria-expanded=\"false\""));
+        assert!(html.contains("popover-content side-bottom"));
+        assert!(html.contains("aria-label=\"2 more\""));
+    }
     #[test]
     fn test_avatar_accessibility() {}
     #[test]
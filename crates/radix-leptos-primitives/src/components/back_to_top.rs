@@ -0,0 +1,127 @@
+use crate::components::affix::scroll_container;
+use crate::i18n::{use_i18n, MessageKey};
+use crate::utils::merge_classes;
+use leptos::callback::Callback;
+use leptos::children::Children;
+use leptos::prelude::*;
+use radix_leptos_core::{use_scroll_container, ScrollContainer, UseScrollOptions};
+
+/// BackToTop component - Floating button that scrolls back to the top
+///
+/// The button appears once the page (or `target` container) has been
+/// scrolled further than `visibility_height`. Scrolling is smooth unless the
+/// user prefers reduced motion.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn Docs() -> impl IntoView {
+///     view! {
+///         <main class="docs-main">"..."</main>
+///         <BackToTop target=".docs-main".to_string() visibility_height=600.0 />
+///     }
+/// }
+/// ```
+#[component]
+pub fn BackToTop(
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    /// Scroll distance in pixels after which the button is shown (default: 400)
+    #[prop(optional)]
+    visibility_height: Option<f64>,
+    /// CSS selector of the scroll container; the window when unset
+    #[prop(optional)]
+    target: Option<String>,
+    /// Animate the scroll (default: true); ignored with reduced motion
+    #[prop(optional)]
+    smooth: Option<bool>,
+    /// Accessible name of the button
+    #[prop(optional)]
+    label: Option<String>,
+    #[prop(optional)] on_click: Option<Callback<()>>,
+    /// Custom button content; an arrow by default
+    #[prop(optional)]
+    children: Option<Children>,
+) -> impl IntoView {
+    let visibility_height = visibility_height.unwrap_or(400.0);
+    let smooth = smooth.unwrap_or(true);
+    let label = label.unwrap_or_else(|| use_i18n().text(MessageKey::BackToTopLabel));
+    let scroll = use_scroll_container(
+        move || Some(container_for(target.as_deref())),
+        UseScrollOptions { smooth },
+    );
+    let visible = Memo::new(move |_| is_back_to_top_visible(scroll.y.get(), visibility_height));
+
+    let handle_click = move |_: web_sys::MouseEvent| {
+        scroll.scroll_to_top();
+        if let Some(on_click) = on_click {
+            on_click.run(());
+        }
+    };
+
+    let class = merge_classes(vec!["back-to-top", class.as_deref().unwrap_or("")]);
+    let style = format!(
+        "position: fixed; right: var(--space-6, 1.5rem); bottom: var(--space-6, 1.5rem); z-index: 40; {}",
+        style.unwrap_or_default()
+    );
+    let content = match children {
+        Some(children) => children(),
+        None => view! { <span aria-hidden="true">"↑"</span> }.into_any(),
+    };
+
+    view! {
+        <button
+            type="button"
+            class=class
+            style=style
+            aria-label=label
+            hidden=move || !visible.get()
+            data-state=move || if visible.get() { "visible" } else { "hidden" }
+            on:click=handle_click
+        >
+            {content}
+        </button>
+    }
+}
+
+/// Whether the button should be shown at the given scroll offset
+pub fn is_back_to_top_visible(scroll_offset: f64, visibility_height: f64) -> bool {
+    scroll_offset >= visibility_height.max(0.0) && scroll_offset > 0.0
+}
+
+/// Scroll the container (or the window) back to the top
+pub fn scroll_to_top(selector: Option<&str>, smooth: bool) {
+    container_for(selector).scroll_to(None, Some(0.0), smooth);
+}
+
+/// The element matching `selector`, or the window when there is none
+fn container_for(selector: Option<&str>) -> ScrollContainer {
+    match scroll_container(selector) {
+        Some(container) => ScrollContainer::Element(container),
+        None => ScrollContainer::Window,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_back_to_top_visibility_threshold() {
+        assert!(!is_back_to_top_visible(0.0, 400.0));
+        assert!(!is_back_to_top_visible(399.0, 400.0));
+        assert!(is_back_to_top_visible(400.0, 400.0));
+        assert!(is_back_to_top_visible(1200.0, 400.0));
+    }
+
+    #[test]
+    fn test_back_to_top_hidden_at_top_with_zero_threshold() {
+        assert!(!is_back_to_top_visible(0.0, 0.0));
+        assert!(is_back_to_top_visible(1.0, 0.0));
+        assert!(is_back_to_top_visible(1.0, -50.0));
+    }
+}
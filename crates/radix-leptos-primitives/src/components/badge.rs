@@ -24,6 +24,10 @@ pub enum BadgeSize {
 
 
 /// Root Badge component
+///
+/// Renders its children as an inline pill, a numeric `count` capped at
+/// `max_count` ("99+"), or a bare `dot`. Wrap a trigger or avatar in
+/// [`BadgeAnchor`] to pin the badge to its corner.
 #[component]
 pub fn Badge(
     /// Badge variant
@@ -38,6 +42,21 @@ pub fn Badge(
     /// Whether the badge is disabled
     #[prop(optional, default = false)]
     disabled: bool,
+    /// Numeric count shown instead of the children
+    #[prop(optional)]
+    count: Option<u32>,
+    /// Maximum count to display (shows as "99+" if exceeded)
+    #[prop(optional, default = 99)]
+    max_count: u32,
+    /// Whether to show the badge when count is 0
+    #[prop(optional, default = false)]
+    show_zero: bool,
+    /// Render only a dot, without content
+    #[prop(optional, default = false)]
+    dot: bool,
+    /// Accessible label, e.g. "3 unread messages"
+    #[prop(optional)]
+    label: Option<String>,
     /// CSS classes
     #[prop(optional)]
     class: Option<String>,
@@ -45,8 +64,13 @@ pub fn Badge(
     #[prop(optional)]
     on_click: Option<Callback<web_sys::MouseEvent>>,
     /// Child content
-    children: Children,
+    #[prop(optional)]
+    children: Option<Children>,
 ) -> impl IntoView {
+    if count.is_some_and(|count| count == 0 && !show_zero) {
+        return ().into_any();
+    }
+
     let variant_class = move || match variant {
         BadgeVariant::Default => "radix-badge--variant-default",
         BadgeVariant::Primary => "radix-badge--variant-primary",
@@ -73,13 +97,28 @@ pub fn Badge(
     };
 
     let class_value = class.unwrap_or_default();
-    let children_view = children();
+    let children_view = if dot {
+        None
+    } else if let Some(count) = count {
+        Some(
+            view! { <span class="radix-badge-count-text">{format_badge_count(count, max_count)}</span> }
+                .into_any(),
+        )
+    } else {
+        children.map(|children| children())
+    };
 
     let mut base_classes = vec!["radix-badge", &variant_class(), &size_class(), &class_value];
 
     if interactive && !disabled {
         base_classes.push("radix-badge--interactive");
     }
+    if dot {
+        base_classes.push("radix-badge--dot");
+    }
+    if count.is_some() {
+        base_classes.push("radix-badge--count");
+    }
 
     let final_classes = base_classes;
 
@@ -87,11 +126,22 @@ pub fn Badge(
         <span
             class=merge_classes(final_classes)
             role="status"
+            aria-label=label
             on:click=handle_click
         >
             {children_view}
         </span>
     }
+    .into_any()
+}
+
+/// Count text, capped at `max_count` ("99+")
+pub fn format_badge_count(count: u32, max_count: u32) -> String {
+    if count > max_count {
+        format!("{}+", max_count)
+    } else {
+        count.to_string()
+    }
 }
 
 /// Badge with count/number
@@ -111,41 +161,23 @@ pub fn BadgeCount(
     /// Whether to show the badge when count is 0
     #[prop(optional, default = false)]
     show_zero: bool,
+    /// Accessible label, e.g. "3 unread messages"
+    #[prop(optional)]
+    label: Option<String>,
     /// CSS classes
     #[prop(optional)]
     class: Option<String>,
 ) -> impl IntoView {
-    let display_count = move || {
-        if count > max_count {
-            format!("{}+", max_count)
-        } else {
-            count.to_string()
-        }
-    };
-
-    let should_show = move || show_zero || count > 0;
-
-    let class_value = class.unwrap_or_default();
-
     view! {
-        {move || {
-            if should_show() {
-                view! {
-                    <Badge
-                        variant=variant
-                        size=size
-                        class=class_value.clone()
-                    >
-                        <span class="radix-badge-count-text">
-                            {display_count()}
-                        </span>
-                    </Badge>
-                }.into_any()
-            } else {
-                let _: () = view! { <></> };
-                ().into_any()
-            }
-        }}
+        <Badge
+            variant=variant
+            size=size
+            count=count
+            max_count=max_count
+            show_zero=show_zero
+            label=label.unwrap_or_default()
+            class=class.unwrap_or_default()
+        />
     }
 }
 
@@ -206,3 +238,154 @@ pub fn BadgeDot(
         </span>
     }
 }
+
+/// Corner of the anchor a badge is pinned to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BadgePlacement {
+    #[default]
+    TopRight,
+    TopLeft,
+    BottomRight,
+    BottomLeft,
+}
+
+/// Shape of the anchored element
+///
+/// Circular anchors (avatars) pull the badge in towards the curve so it
+/// sits on the edge rather than floating off the corner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BadgeOverlap {
+    #[default]
+    Rectangular,
+    Circular,
+}
+
+/// Inline style that pins a badge to a corner of its anchor
+pub fn badge_anchor_style(
+    placement: BadgePlacement,
+    overlap: BadgeOverlap,
+    offset_x: f64,
+    offset_y: f64,
+) -> String {
+    // Inset of the circle's edge from the corner of its bounding box at 45°
+    let inset = match overlap {
+        BadgeOverlap::Rectangular => "0%",
+        BadgeOverlap::Circular => "14%",
+    };
+    let (vertical, horizontal, translate_x, translate_y) = match placement {
+        BadgePlacement::TopRight => ("top", "right", "50%", "-50%"),
+        BadgePlacement::TopLeft => ("top", "left", "-50%", "-50%"),
+        BadgePlacement::BottomRight => ("bottom", "right", "50%", "50%"),
+        BadgePlacement::BottomLeft => ("bottom", "left", "-50%", "50%"),
+    };
+    format!(
+        "position: absolute; {}: calc({} + {}px); {}: calc({} + {}px); transform: translate({}, {}); pointer-events: none;",
+        vertical, inset, offset_y, horizontal, inset, offset_x, translate_x, translate_y
+    )
+}
+
+/// Positions a badge over the corner of any child
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn Inbox() -> impl IntoView {
+///     view! {
+///         <BadgeAnchor badge=ViewFn::from(|| view! { <BadgeCount count=120 label="120 unread".to_string() /> })>
+///             <button aria-label="Inbox">"✉"</button>
+///         </BadgeAnchor>
+///     }
+/// }
+/// ```
+#[component]
+pub fn BadgeAnchor(
+    /// The badge to position
+    badge: ViewFn,
+    /// Corner to pin the badge to
+    #[prop(optional)]
+    placement: BadgePlacement,
+    /// Shape of the anchored element
+    #[prop(optional)]
+    overlap: BadgeOverlap,
+    /// Horizontal offset in pixels, away from the corner
+    #[prop(optional)]
+    offset_x: f64,
+    /// Vertical offset in pixels, away from the corner
+    #[prop(optional)]
+    offset_y: f64,
+    /// CSS classes
+    #[prop(optional)]
+    class: Option<String>,
+    /// The element the badge is anchored to
+    children: Children,
+) -> impl IntoView {
+    let class_value = class.unwrap_or_default();
+    let badge_style = badge_anchor_style(placement, overlap, offset_x, offset_y);
+
+    view! {
+        <span
+            class=merge_classes(vec!["radix-badge-anchor", &class_value])
+            style="position: relative; display: inline-flex; vertical-align: middle;"
+        >
+            {children()}
+            <span class="radix-badge-anchor-badge" style=badge_style>
+                {badge.run()}
+            </span>
+        </span>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_badge_count() {
+        assert_eq!(format_badge_count(0, 99), "0");
+        assert_eq!(format_badge_count(99, 99), "99");
+        assert_eq!(format_badge_count(100, 99), "99+");
+        assert_eq!(format_badge_count(12, 9), "9+");
+    }
+
+    #[test]
+    fn test_badge_anchor_style() {
+        let style = badge_anchor_style(
+            BadgePlacement::TopRight,
+            BadgeOverlap::Rectangular,
+            2.0,
+            -1.0,
+        );
+        assert!(style.contains("top: calc(0% + -1px)"));
+        assert!(style.contains("right: calc(0% + 2px)"));
+        assert!(style.contains("translate(50%, -50%)"));
+
+        let style = badge_anchor_style(
+            BadgePlacement::BottomLeft,
+            BadgeOverlap::Circular,
+            0.0,
+            0.0,
+        );
+        assert!(style.contains("bottom: calc(14% + 0px)"));
+        assert!(style.contains("left: calc(14% + 0px)"));
+    }
+
+    type BadgeView = fn() -> AnyView;
+
+    #[test]
+    fn test_badge_count_modes() {
+        let render = |view: BadgeView| Owner::new().with(|| view().to_html());
+        let html = render(|| view! { <Badge count=250 /> }.into_any());
+        assert!(html.contains("99+"));
+        assert!(html.contains("radix-badge--count"));
+        let html = render(|| view! { <Badge count=0 /> }.into_any());
+        assert!(!html.contains("radix-badge"));
+        let html = render(|| view! { <Badge dot=true label="Online".to_string()>"ignored"</Badge> }.into_any());
+        assert!(html.contains("radix-badge--dot"));
+        assert!(html.contains("aria-label=\"Online\""));
+        assert!(!html.contains("ignored"));
+    }
+}
@@ -1,5 +1,15 @@
+use super::chart::{chart_root_style, AxisConfig, ChartMargin};
+use crate::i18n::{use_i18n, MessageArgs, MessageKey};
+use crate::theming::use_chart_palette;
+use crate::utils::merge_classes;
+use leptos::callback::Callback;
+use leptos::children::Children;
+use leptos::prelude::*;
 
 /// BarChart component - Categorical data display
+///
+/// Series without a `color` take the palette's categorical color at their
+/// index, set on the chart as `--chart-series-1`, `--chart-series-2`, …
 #[component]
 pub fn BarChart(
     #[prop(optional)] class: Option<String>,
@@ -20,10 +30,48 @@ pub fn BarChart(
     let stacked = stacked.unwrap_or(false);
     let show_values = show_values.unwrap_or(false);
     let show_grid = show_grid.unwrap_or(true);
+    let series_count = data.len();
+    let palette = use_chart_palette();
+    let style = move || {
+        let colors = palette.with(|palette| {
+            palette.series_colors(data.iter().map(|series| series.color.as_deref()))
+        });
+        chart_root_style(&colors, style.as_deref())
+    };
 
     let class = merge_classes(vec![
         "bar-chart",
-        &orientation.to_class(),
+        orientation.to_class(),
+        class.as_deref().unwrap_or(""),
+    ]);
+
+    let _handle_bar_click = move |bar: BarData| {
+        if let Some(on_bar_click) = on_bar_click {
+            on_bar_click.run(bar);
+        }
+    };
+
+    let _handle_bar_hover = move |bar: BarData| {
+        if let Some(on_bar_hover) = on_bar_hover {
+            on_bar_hover.run(bar);
+        }
+    };
+
+    view! {
+        <div
+            class=class
+            style=style
+            role="img"
+            aria-label=use_i18n().text(MessageKey::BarChartLabel)
+            data-series-count=series_count
+            data-orientation=orientation.to_string()
+            data-stacked=stacked
+            data-show-values=show_values
+            data-show-grid=show_grid
+            data-width=config.width
+            data-height=config.height
+        >
+            {children.map(|c| c())}
         </div>
     }
 }
@@ -33,7 +81,8 @@ pub fn BarChart(
 pub struct BarSeries {
     pub name: String,
     pub data: Vec<BarData>,
-    pub color: String,
+    /// Series color, the palette's color for its index when `None`
+    pub color: Option<String>,
     pub opacity: f64,
 }
 
@@ -42,7 +91,7 @@ impl Default for BarSeries {
         Self {
             name: "Series".to_string(),
             data: Vec::new(),
-            color: "#3b82f6".to_string(),
+            color: None,
             opacity: 1.0,
         }
     }
@@ -83,48 +132,6 @@ impl Default for BarChartConfig {
     }
 }
 
-/// Chart Margin
-#[derive(Debug, Clone, PartialEq)]
-pub struct ChartMargin {
-    pub top: f64,
-    pub right: f64,
-    pub bottom: f64,
-    pub left: f64,
-}
-
-impl Default for ChartMargin {
-    fn default() -> Self {
-        Self {
-            top: 20.0,
-            right: 20.0,
-            bottom: 40.0,
-            left: 40.0,
-        }
-    }
-}
-
-/// Axis Configuration
-#[derive(Debug, Clone, PartialEq)]
-pub struct AxisConfig {
-    pub label: Option<String>,
-    pub min: Option<f64>,
-    pub max: Option<f64>,
-    pub ticks: Option<usize>,
-    pub format: Option<String>,
-}
-
-impl Default for AxisConfig {
-    fn default() -> Self {
-        Self {
-            label: None,
-            min: None,
-            max: None,
-            ticks: None,
-            format: None,
-        }
-    }
-}
-
 /// Bar Orientation
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum BarOrientation {
@@ -163,22 +170,32 @@ pub fn BarChartBar(
     let width = width.unwrap_or(20.0);
     let height = height.unwrap_or(100.0);
 
-    let class = merge_classes(vec![
-        "bar-chart-bar",
-        class.as_deref().unwrap_or(""),
-    ]);
+    let class = merge_classes(vec!["bar-chart-bar", class.as_deref().unwrap_or("")]);
+    let aria_label = use_i18n().format(
+        MessageKey::BarChartBar,
+        &MessageArgs::new()
+            .with("category", data.category.as_str())
+            .with("value", data.value),
+    );
+    let category = data.category.clone();
+    let value = data.value;
 
     view! {
         <div
             class=class
             style=style
             role="button"
-            aria-label=format!("Bar: {} - {}", data.category, data.value)
-            data-category=data.category
-            data-value=data.value
+            aria-label=aria_label
+            data-category=category
+            data-value=value
             data-width=width
             data-height=height
             tabindex="0"
+            on:click=move |_| {
+                if let Some(on_click) = on_click {
+                    on_click.run(data.clone());
+                }
+            }
         />
     }
 }
@@ -195,17 +212,17 @@ pub fn BarChartGroup(
     let category = category.unwrap_or_default();
     let bars = bars.unwrap_or_default();
 
-    let class = merge_classes(vec![
-        "bar-chart-group",
-        class.as_deref().unwrap_or(""),
-    ]);
+    let class = merge_classes(vec!["bar-chart-group", class.as_deref().unwrap_or("")]);
 
     view! {
         <div
             class=class
             style=style
             role="group"
-            aria-label=format!("Bar group: {}", category)
+            aria-label=use_i18n().format(
+                MessageKey::BarChartGroup,
+                &MessageArgs::new().with("category", category.as_str()),
+            )
             data-category=category
             data-bar-count=bars.len()
         >
@@ -214,116 +231,45 @@ pub fn BarChartGroup(
     }
 }
 
-/// Helper function to merge CSS classes
-fn merge_optional_classes(classes: Vec<&str>) -> String {
-    classes
-        .into_iter()
-        .filter(|c| !c.is_empty())
-        .collect::<Vec<_>>()
-        .join(" ")
-}
-
 #[cfg(test)]
 mod tests {
-    use wasm_bindgen_test::*;
-    use proptest::prelude::*;
-use crate::utils::merge_classes;
-
-    wasm_bindgen_test_configure!(run_in_browser);
-
-    // Unit Tests
-    #[test] fn test_barchart_creation() { 
-    #[test] fn test_barchart_with_class() { 
-    #[test] fn test_barchart_with_style() { 
-    #[test] fn test_barchart_with_data() { 
-    #[test] fn test_barchart_with_config() { 
-    #[test] fn test_barchart_orientation() { 
-    #[test] fn test_barchart_stacked() { 
-    #[test] fn test_barchart_show_values() { 
-    #[test] fn test_barchart_show_grid() { 
-    #[test] fn test_barchart_on_bar_click() { 
-    #[test] fn test_barchart_on_bar_hover() { 
-
-    // Bar Series tests
-    #[test] fn test_bar_series_default() { 
-    #[test] fn test_bar_series_creation() { 
-
-    // Bar Data tests
-    #[test] fn test_bar_data_creation() { 
-
-    // Bar Chart Config tests
-    #[test] fn test_barchart_config_default() { 
-    #[test] fn test_barchart_config_custom() { 
-
-    // Chart Margin tests
-    #[test] fn test_chart_margin_default() { 
-
-    // Axis Config tests
-    #[test] fn test_axis_config_default() { 
-    #[test] fn test_axis_config_custom() { 
-
-    // Bar Orientation tests
-    #[test] fn test_bar_orientation_default() { 
-    #[test] fn test_bar_orientation_vertical() { 
-    #[test] fn test_bar_orientation_horizontal() { 
-
-    // Bar Chart Bar tests
-    #[test] fn test_barchart_bar_creation() { 
-    #[test] fn test_barchart_bar_with_class() { 
-    #[test] fn test_barchart_bar_with_style() { 
-    #[test] fn test_barchart_bar_with_data() { 
-    #[test] fn test_barchart_bar_width() { 
-    #[test] fn test_barchart_bar_height() { 
-    #[test] fn test_barchart_bar_on_click() { 
-
-    // Bar Chart Group tests
-    #[test] fn test_barchart_group_creation() { 
-    #[test] fn test_barchart_group_with_class() { 
-    #[test] fn test_barchart_group_with_style() { 
-    #[test] fn test_barchart_group_category() { 
-    #[test] fn test_barchart_group_bars() { 
-
-    // Helper function tests
-    #[test] fn test_merge_classes_empty() { 
-    #[test] fn test_merge_classes_single() { 
-    #[test] fn test_merge_classes_multiple() { 
-    #[test] fn test_merge_classes_with_empty() { 
-
-    // Property-based Tests
-    #[test] fn test_barchart_property_based() {
-        proptest!(|(____class in ".*", __style in ".*")| {
-            
-        });
+    use super::*;
+    use crate::theming::ChartPalette;
+
+    fn series(name: &str, color: Option<&str>) -> BarSeries {
+        BarSeries {
+            name: name.to_string(),
+            color: color.map(str::to_string),
+            ..BarSeries::default()
+        }
     }
 
-    #[test] fn test_barchart_data_validation() {
-        proptest!(|(______series_count in 0..10usize, __bars_per_series in 0..50usize)| {
-            
-        });
+    #[test]
+    fn test_bar_series_default_has_no_color() {
+        assert_eq!(BarSeries::default().color, None);
     }
 
-    #[test] fn test_barchart_config_validation() {
-        proptest!(|(____width in 100.0..2000.0f64, __height in 100.0..2000.0f64)| {
-            
-        });
+    #[test]
+    fn test_barchart_colors_series_from_palette() {
+        crate::test_support::init_test_executor();
+        let data = vec![series("2024", None), series("2025", Some("#ff0000")), series("2026", None)];
+        let html = Owner::new().with(|| view! { <BarChart data=data /> }.to_html());
+        let palette = ChartPalette::default();
+        assert!(html.contains(&format!(
+            "--chart-series-1: {}; --chart-series-2: #ff0000; --chart-series-3: {};",
+            palette.series_color(0),
+            palette.series_color(2)
+        )));
+        assert_ne!(palette.series_color(0), palette.series_color(2));
     }
 
-    #[test] fn test_barchart_orientation_property_based() {
-        proptest!(|(____orientation_index in 0..2usize)| {
-            
+    #[test]
+    fn test_barchart_keeps_own_style() {
+        crate::test_support::init_test_executor();
+        let html = Owner::new().with(|| {
+            view! { <BarChart data=vec![series("2024", Some("#ff0000"))] style="height: 10rem".to_string() /> }
+                .to_html()
         });
+        assert!(html.contains("--chart-series-1: #ff0000; height: 10rem"));
     }
-
-    // Integration Tests
-    #[test] fn test_barchart_tooltip_interaction() { 
-    #[test] fn test_barchart_legend_interaction() { 
-    #[test] fn test_barchart_user_workflow() { 
-    #[test] fn test_barchart_accessibility_workflow() { 
-    #[test] fn test_barchart_with_other_components() { 
-
-    // Performance Tests
-    #[test] fn test_barchart_large_dataset() { 
-    #[test] fn test_barchart_render_performance() { 
-    #[test] fn test_barchart_memory_usage() { 
-    #[test] fn test_barchart_animation_performance() { 
 }
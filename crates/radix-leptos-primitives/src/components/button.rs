@@ -1,7 +1,21 @@
 use leptos::callback::Callback;
 use leptos::children::Children;
 use leptos::prelude::*;
+use leptos::task::spawn_local;
+use std::future::Future;
+use std::pin::Pin;
+use crate::components::spinner::Spinner;
 use crate::utils::{merge_optional_classes, generate_id};
+use crate::theming::{
+    use_component_theme, use_density, ButtonVariants, Density, SizeVariant, StyleVariant,
+};
+use radix_leptos_core::{use_announce, use_focus_visible, AriaLive};
+
+/// Future returned by an `on_click_async` handler
+pub type ButtonFuture = Pin<Box<dyn Future<Output = ()>>>;
+
+/// Click handler whose future keeps a Button loading until it resolves
+pub type AsyncClickHandler = Callback<web_sys::MouseEvent, ButtonFuture>;
 
 /// Button component with proper accessibility and styling variants
 ///
@@ -13,7 +27,8 @@ use crate::utils::{merge_optional_classes, generate_id};
 /// - Multiple variants (default, destructive, outline, secondary, ghost, link)
 /// - Multiple sizes (default, sm, lg, icon)
 /// - Disabled state handling
-/// - Loading state support
+/// - Loading state with a spinner
+/// - Async click handlers that drive the loading state
 /// - Click and keyboard event handling
 ///
 /// # Example
@@ -66,6 +81,45 @@ impl ButtonVariant {
             ButtonVariant::Link => "link",
         }
     }
+
+    /// Theming style of the variant, `None` for links
+    pub fn style_variant(&self) -> Option<StyleVariant> {
+        match self {
+            ButtonVariant::Default => Some(StyleVariant::Default),
+            ButtonVariant::Destructive => Some(StyleVariant::Destructive),
+            ButtonVariant::Outline => Some(StyleVariant::Outline),
+            ButtonVariant::Secondary => Some(StyleVariant::Secondary),
+            ButtonVariant::Ghost => Some(StyleVariant::Ghost),
+            ButtonVariant::Link => None,
+        }
+    }
+
+    /// This variant if `variants` offers it, otherwise the first offered
+    /// style a button has
+    pub fn offered_in(self, variants: &ButtonVariants) -> Self {
+        match self.style_variant() {
+            Some(style) if !variants.styles.is_empty() && !variants.styles.contains(&style) => {
+                variants
+                    .styles
+                    .iter()
+                    .find_map(|style| Self::from_style_variant(*style))
+                    .unwrap_or(self)
+            }
+            _ => self,
+        }
+    }
+
+    fn from_style_variant(style: StyleVariant) -> Option<Self> {
+        [
+            ButtonVariant::Default,
+            ButtonVariant::Destructive,
+            ButtonVariant::Outline,
+            ButtonVariant::Secondary,
+            ButtonVariant::Ghost,
+        ]
+        .into_iter()
+        .find(|variant| variant.style_variant() == Some(style))
+    }
 }
 
 impl ButtonSize {
@@ -77,6 +131,36 @@ impl ButtonSize {
             ButtonSize::Icon => "icon",
         }
     }
+
+    /// Theming size of the size, `None` for icon buttons
+    pub fn size_variant(&self) -> Option<SizeVariant> {
+        match self {
+            ButtonSize::Default => Some(SizeVariant::Medium),
+            ButtonSize::Small => Some(SizeVariant::Small),
+            ButtonSize::Large => Some(SizeVariant::Large),
+            ButtonSize::Icon => None,
+        }
+    }
+
+    /// This size if `variants` offers it, otherwise the first offered size
+    /// a button has
+    pub fn offered_in(self, variants: &ButtonVariants) -> Self {
+        match self.size_variant() {
+            Some(size) if !variants.sizes.is_empty() && !variants.sizes.contains(&size) => {
+                let sizes = [ButtonSize::Default, ButtonSize::Small, ButtonSize::Large];
+                variants
+                    .sizes
+                    .iter()
+                    .find_map(|size| {
+                        sizes
+                            .into_iter()
+                            .find(|button_size| button_size.size_variant() == Some(*size))
+                    })
+                    .unwrap_or(self)
+            }
+            _ => self,
+        }
+    }
 }
 
 /// Button builder struct for test compatibility
@@ -166,6 +250,50 @@ impl ButtonBuilder {
 pub type Button = ButtonBuilder;
 
 /// Button component with accessibility and variant support
+///
+/// While `loading` is set, or while the future returned by
+/// `on_click_async` is pending, the button has `data-loading="true"`, shows
+/// a Spinner in a `.button-spinner` next to its `.button-label` and reports
+/// `aria-busy` and `aria-disabled`. It stays focusable but ignores clicks
+/// and does not submit its form. A `loading_message` is announced through
+/// the closest AnnouncerProvider when loading starts.
+///
+/// Like every primitive it ships no styles. To keep the button's width
+/// while loading, lay the spinner over the hidden label in your CSS:
+///
+/// ```css
+/// [data-loading="true"] { position: relative; }
+/// [data-loading="true"] .button-label { visibility: hidden; }
+/// .button-spinner {
+///     position: absolute;
+///     inset: 0;
+///     display: flex;
+///     align-items: center;
+///     justify-content: center;
+/// }
+/// ```
+///
+/// Inside a [`ComponentTheme`](crate::theming::ComponentTheme) for
+/// `"button"` the variant and size fall back to ones its lists offer, and
+/// its variables are applied as inline styles.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn SaveButton() -> impl IntoView {
+///     view! {
+///         <Button on_click_async=Callback::new(|_| -> ButtonFuture {
+///             Box::pin(async move { /* save */ })
+///         })>
+///             "Save"
+///         </Button>
+///     }
+/// }
+/// ```
 #[component]
 pub fn Button(
     /// Button styling variant
@@ -174,12 +302,18 @@ pub fn Button(
     /// Button size
     #[prop(optional, default = ButtonSize::Default)]
     size: ButtonSize,
+    /// Density, instead of the closest DensityProvider's
+    #[prop(optional, into)]
+    density: MaybeProp<Density>,
     /// Whether the button is disabled
     #[prop(optional, default = false)]
     disabled: bool,
     /// Whether the button is in a loading state
-    #[prop(optional, default = false)]
-    loading: bool,
+    #[prop(optional, into)]
+    loading: Signal<bool>,
+    /// Announced to screen readers when the button starts loading
+    #[prop(optional)]
+    loading_message: Option<String>,
     /// Button type attribute (button, submit, reset)
     #[prop(optional, into)]
     button_type: Option<String>,
@@ -192,6 +326,9 @@ pub fn Button(
     /// Click event handler
     #[prop(optional)]
     on_click: Option<Callback<web_sys::MouseEvent>>,
+    /// Click handler whose future keeps the button loading until it resolves
+    #[prop(optional)]
+    on_click_async: Option<AsyncClickHandler>,
     /// Focus event handler
     #[prop(optional)]
     on_focus: Option<Callback<web_sys::FocusEvent>>,
@@ -202,10 +339,29 @@ pub fn Button(
     children: Children,
 ) -> impl IntoView {
     let button_id = generate_id("button");
+    let button_ref = NodeRef::<leptos::html::Button>::new();
+    let focus_visible = use_focus_visible(button_ref);
+    let pending = RwSignal::new(false);
+    let busy = Signal::derive(move || loading.get() || pending.get());
+    if let Some(message) = loading_message {
+        use_announce(
+            Signal::derive(move || busy.get().then(|| message.clone())),
+            AriaLive::Polite,
+        );
+    }
 
-    // Build data attributes for styling
-    let data_variant = variant.as_str();
-    let data_size = size.as_str();
+    // Build data attributes for styling, within the variants the closest
+    // ComponentTheme offers
+    let theme = use_component_theme("button");
+    let variants = Memo::new(move |_| ButtonVariants::default().with_overrides(&theme.get()));
+    let variant = Memo::new(move |_| variants.with(|variants| variant.offered_in(variants)));
+    let size = Memo::new(move |_| variants.with(|variants| size.offered_in(variants)));
+    let density = use_density(density);
+    let style = move || {
+        theme.with(|theme| {
+            theme.inline_style("button", variant.get().style_variant(), style.as_deref())
+        })
+    };
 
     // Merge classes with data attributes for CSS targeting
     let base_classes = "radix-button";
@@ -214,10 +370,23 @@ pub fn Button(
 
     // Handle click events
     let handle_click = move |e: web_sys::MouseEvent| {
-        if !disabled && !loading {
-            if let Some(on_click) = on_click {
-                on_click.run(e);
-            }
+        if disabled {
+            return;
+        }
+        if busy.get_untracked() {
+            e.prevent_default();
+            return;
+        }
+        if let Some(on_click_async) = on_click_async {
+            let future = on_click_async.run(e.clone());
+            pending.set(true);
+            spawn_local(async move {
+                future.await;
+                pending.try_set(false);
+            });
+        }
+        if let Some(on_click) = on_click {
+            on_click.run(e);
         }
     };
 
@@ -237,25 +406,29 @@ pub fn Button(
 
     view! {
         <button
+            node_ref=button_ref
             id=button_id
             class=combined_class
             style=style
             type=button_type.unwrap_or_else(|| "button".to_string())
-            disabled=disabled || loading
-            data-variant=data_variant
-            data-size=data_size
-            data-loading=loading
-            aria-disabled=disabled || loading
+            disabled=disabled
+            data-variant=move || variant.get().as_str()
+            data-size=move || size.get().as_str()
+            data-density=move || density.get().as_str()
+            data-loading=move || busy.get().to_string()
+            aria-busy=move || busy.get().then_some("true")
+            aria-disabled=move || (disabled || busy.get()).to_string()
+            data-focus-visible=move || focus_visible.get().then_some("")
             on:click=handle_click
             on:focus=handle_focus
             on:blur=handle_blur
         >
-            <Show when=move || loading>
-                <span class="button-spinner" aria-hidden="true">
-                    "⟳"
+            <Show when=move || busy.get()>
+                <span class="button-spinner">
+                    <Spinner />
                 </span>
             </Show>
-            {children()}
+            <span class="button-label">{children()}</span>
         </button>
     }
 }
@@ -414,6 +587,61 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_button_loading_keeps_label_and_reports_busy() {
+        use super::Button;
+        use leptos::prelude::*;
+
+        crate::test_support::init_test_executor();
+        let owner = Owner::new();
+        owner.with(|| {
+            let html = view! { <Button loading=true>"Save"</Button> }.to_html();
+            assert!(html.contains("aria-busy=\"true\""));
+            assert!(html.contains("aria-disabled=\"true\""));
+            assert!(html.contains("data-loading=\"true\""));
+            assert!(html.contains("button-spinner"));
+            assert!(!html.contains("style="));
+            assert!(html.contains("Save"));
+
+            let idle = view! { <Button>"Save"</Button> }.to_html();
+            assert!(!idle.contains("aria-busy"));
+            assert!(idle.contains("aria-disabled=\"false\""));
+            assert!(!idle.contains("spinner"));
+        });
+    }
+
+    #[test]
+    fn test_button_follows_component_theme() {
+        use super::Button;
+        use crate::theming::{ComponentTheme, ComponentThemeOverrides, SizeVariant, StyleVariant};
+        use leptos::prelude::*;
+
+        crate::test_support::init_test_executor();
+        let owner = Owner::new();
+        owner.with(|| {
+            let overrides = ComponentThemeOverrides::new()
+                .with_style_variable(StyleVariant::Destructive, "background", "#b91c1c")
+                .with_styles(vec![StyleVariant::Primary, StyleVariant::Destructive])
+                .with_sizes(vec![SizeVariant::Large]);
+            let html = view! {
+                <ComponentTheme component="button" overrides=overrides>
+                    <Button variant=ButtonVariant::Outline>"Delete"</Button>
+                </ComponentTheme>
+            }
+            .to_html();
+            assert!(html.contains("--button-destructive-background: #b91c1c;"));
+            // Outline isn't offered, so the button falls back to Destructive
+            assert!(html.contains("data-variant=\"destructive\""));
+            assert!(html.contains("data-size=\"lg\""));
+            assert!(html.contains("style=\"background: var(--button-destructive-background);"));
+        });
+
+        let plain = Owner::new()
+            .with(|| view! { <Button variant=ButtonVariant::Outline>"Delete"</Button> }.to_html());
+        assert!(plain.contains("data-variant=\"outline\""));
+        assert!(!plain.contains("style="));
+    }
+
     // 7. Property-Based Tests
     proptest! {
         #[test]
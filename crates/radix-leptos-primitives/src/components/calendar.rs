@@ -1,3 +1,4 @@
+use crate::i18n::{use_i18n, MessageArgs, MessageKey};
 use crate::utils::merge_classes;
 use leptos::callback::Callback;
 use leptos::children::Children;
@@ -34,7 +35,7 @@ pub fn Calendar(
             class=class
             style=style
             role="grid"
-            aria-label="Calendar"
+            aria-label=use_i18n().text(MessageKey::CalendarLabel)
             data-locale=locale
             data-first-day-of-week=first_day_of_week
             data-show-week-numbers=show_week_numbers
@@ -65,7 +66,7 @@ pub fn CalendarHeader(
             class=class
             style=style
             role="banner"
-            aria-label="Calendar header"
+            aria-label=use_i18n().text(MessageKey::CalendarHeader)
         >
             {children.map(|c| c())}
         </div>
@@ -88,12 +89,12 @@ pub fn CalendarNavigation(
             class=class
             style=style
             role="navigation"
-            aria-label="Calendar navigation"
+            aria-label=use_i18n().text(MessageKey::CalendarNavigation)
         >
             <button
                 class="calendar-nav-previous"
                 type="button"
-                aria-label="Previous month"
+                aria-label=use_i18n().text(MessageKey::CalendarPreviousMonth)
                 on:click=move |_| {
                     if let Some(callback) = on_previous {
                         callback.run(());
@@ -105,19 +106,19 @@ pub fn CalendarNavigation(
             <button
                 class="calendar-nav-today"
                 type="button"
-                aria-label="Go to today"
+                aria-label=use_i18n().text(MessageKey::CalendarToday)
                 on:click=move |_| {
                     if let Some(callback) = on_today {
                         callback.run(());
                     }
                 }
             >
-                "Today"
+                {use_i18n().text(MessageKey::CalendarTodayButton)}
             </button>
             <button
                 class="calendar-nav-next"
                 type="button"
-                aria-label="Next month"
+                aria-label=use_i18n().text(MessageKey::CalendarNextMonth)
                 on:click=move |_| {
                     if let Some(callback) = on_next {
                         callback.run(());
@@ -149,7 +150,12 @@ pub fn CalendarGrid(
             class=class
             style=style
             role="grid"
-            aria-label=format!("Calendar for {} {}", month.as_deref().unwrap_or(""), year.unwrap_or(0))
+            aria-label=use_i18n().format(
+                MessageKey::CalendarGrid,
+                &MessageArgs::new()
+                    .with("month", month.unwrap_or_default())
+                    .with("year", year.unwrap_or(0)),
+            )
         >
             {children.map(|c| c())}
         </div>
@@ -220,7 +226,7 @@ pub fn CalendarWeekHeader(
             class=class
             style=style
             role="row"
-            aria-label="Week header"
+            aria-label=use_i18n().text(MessageKey::CalendarWeekHeader)
             data-locale=locale
             data-first-day-of-week=first_day_of_week
         >
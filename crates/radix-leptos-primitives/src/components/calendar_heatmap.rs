@@ -0,0 +1,488 @@
+use crate::i18n::{use_i18n, MessageArgs, MessageCatalog, MessageKey};
+use crate::theming::{use_chart_palette, CSSVariables, ChartPalette};
+use crate::utils::{generate_id, merge_classes};
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use leptos::html;
+use leptos::prelude::*;
+use radix_leptos_core::{use_visually_hidden_style, FocusManager};
+use std::collections::HashMap;
+
+/// Seven days of one heatmap column, `None` outside the displayed range
+pub type HeatmapWeek = [Option<NaiveDate>; 7];
+
+/// Builds the accessible description of a day, e.g. "3 commits on Monday, March 4, 2024"
+pub type HeatmapDescribe = Callback<HeatmapDay, String>;
+
+/// Value of one day in a CalendarHeatmap
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeatmapDay {
+    pub date: NaiveDate,
+    pub count: u32,
+}
+
+impl HeatmapDay {
+    pub fn new(date: NaiveDate, count: u32) -> Self {
+        Self { date, count }
+    }
+}
+
+/// Tooltip shown next to the hovered or focused cell
+#[derive(Debug, Clone, PartialEq)]
+struct HeatmapTooltip {
+    text: String,
+    left: f64,
+    top: f64,
+}
+
+/// Five step scale from an empty cell to the strongest primary shade, the
+/// sequential scale of the theme's [`ChartPalette`]
+pub fn heatmap_color_scale(theme: &CSSVariables) -> Vec<String> {
+    ChartPalette::from_theme(theme).sequential
+}
+
+/// Scale step for `count` out of `levels`; 0 is reserved for empty days
+pub fn heatmap_level(count: u32, max: u32, levels: usize) -> usize {
+    if count == 0 || max == 0 || levels < 2 {
+        return 0;
+    }
+    let steps = (levels - 1) as f64;
+    ((count as f64 / max as f64 * steps).ceil() as usize).clamp(1, levels - 1)
+}
+
+/// Columns of days from `start` to `end`, each beginning on `week_start`
+pub fn heatmap_weeks(start: NaiveDate, end: NaiveDate, week_start: Weekday) -> Vec<HeatmapWeek> {
+    if end < start {
+        return Vec::new();
+    }
+    let offset = start.weekday().days_since(week_start) as i64;
+    let mut day = start - Duration::days(offset);
+    let mut weeks = Vec::new();
+    while day <= end {
+        let mut week: HeatmapWeek = [None; 7];
+        for slot in week.iter_mut() {
+            if day >= start && day <= end {
+                *slot = Some(day);
+            }
+            day += Duration::days(1);
+        }
+        weeks.push(week);
+    }
+    weeks
+}
+
+/// Month name to show above each column, where a month begins
+pub fn heatmap_month_labels(weeks: &[HeatmapWeek]) -> Vec<Option<String>> {
+    let mut labels: Vec<Option<String>> = weeks
+        .iter()
+        .map(|week| {
+            week.iter()
+                .flatten()
+                .find(|day| day.day() == 1)
+                .map(|day| day.format("%b").to_string())
+        })
+        .collect();
+    // Label the first column too, unless the next month starts right after it
+    if let Some(first) = weeks.first().and_then(|week| week.iter().flatten().next()) {
+        if labels.iter().take(3).all(Option::is_none) {
+            labels[0] = Some(first.format("%b").to_string());
+        }
+    }
+    labels
+}
+
+fn default_description(catalog: &MessageCatalog, day: HeatmapDay) -> String {
+    let args = MessageArgs::new()
+        .with("count", day.count)
+        .with("date", day.date.format("%A, %B %-d, %Y").to_string());
+    catalog.format(MessageKey::CalendarHeatmapDay, &args)
+}
+
+/// CalendarHeatmap component - GitHub-style grid of daily activity
+///
+/// One column per week and one row per weekday, shaded by count. Colors
+/// come from the sequential scale of the chart palette unless `colors` is
+/// given (lowest first, the first one used for empty days). The grid is a
+/// single tab stop: the arrow keys move by day and week, Home/End jump to the
+/// ends of the range and Enter or Space selects the focused day.
+///
+/// Every day is a labelled cell, so the grid already is the data in text
+/// form; `description` adds a visually hidden summary of the trend
+/// ("Most active in March") that the grid references.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use chrono::NaiveDate;
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn Activity() -> impl IntoView {
+///     let end = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+///     let values = vec![HeatmapDay::new(NaiveDate::from_ymd_opt(2024, 12, 24).unwrap(), 4)];
+///     view! {
+///         <CalendarHeatmap
+///             values=values
+///             end=end
+///             label="Contributions in 2024".to_string()
+///             on_select=Callback::new(|day: HeatmapDay| leptos::logging::log!("{}", day.date))
+///         />
+///     }
+/// }
+/// ```
+#[component]
+pub fn CalendarHeatmap(
+    #[prop(into)] values: Signal<Vec<HeatmapDay>>,
+    /// Last day shown
+    end: NaiveDate,
+    /// First day shown (default: 52 weeks before `end`)
+    #[prop(optional)]
+    start: Option<NaiveDate>,
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    /// Accessible name of the grid (default: "Activity")
+    #[prop(optional)]
+    label: Option<String>,
+    /// Summary of the activity shown
+    #[prop(optional)]
+    description: Option<String>,
+    /// Day each column starts on (default: Sunday)
+    #[prop(optional)]
+    week_start: Option<Weekday>,
+    /// Color scale overriding the theme, lowest first
+    #[prop(optional)]
+    colors: Option<Vec<String>>,
+    /// Cell size in pixels (default: 12)
+    #[prop(optional)]
+    cell_size: Option<f64>,
+    #[prop(optional)] describe: Option<HeatmapDescribe>,
+    #[prop(optional)] on_select: Option<Callback<HeatmapDay>>,
+) -> impl IntoView {
+    let start = start.unwrap_or(end - Duration::weeks(52) + Duration::days(1));
+    let week_start = week_start.unwrap_or(Weekday::Sun);
+    let cell_size = cell_size.unwrap_or(12.0);
+    let catalog = use_i18n();
+    let label = label.unwrap_or_else(|| catalog.text(MessageKey::CalendarHeatmapLabel));
+    let less_text = catalog.text(MessageKey::CalendarHeatmapLess);
+    let more_text = catalog.text(MessageKey::CalendarHeatmapMore);
+    let catalog = StoredValue::new(catalog);
+    let weeks = heatmap_weeks(start, end, week_start);
+    let month_labels = heatmap_month_labels(&weeks);
+    let description_id = description.as_ref().map(|_| generate_id("calendar-heatmap-description"));
+
+    let palette = use_chart_palette();
+    let scale = Memo::new(move |_| {
+        colors
+            .clone()
+            .unwrap_or_else(|| palette.with(|palette| palette.sequential.clone()))
+    });
+    let counts = Memo::new(move |_| {
+        values.with(|values| {
+            values
+                .iter()
+                .map(|day| (day.date, day.count))
+                .collect::<HashMap<_, _>>()
+        })
+    });
+    let max = Memo::new(move |_| counts.with(|counts| counts.values().copied().max().unwrap_or(0)));
+    let day_at = move |date: NaiveDate| {
+        HeatmapDay::new(
+            date,
+            counts.with(|counts| counts.get(&date).copied().unwrap_or(0)),
+        )
+    };
+    let describe = move |day: HeatmapDay| match describe {
+        Some(describe) => describe.run(day),
+        None => catalog.with_value(|catalog| default_description(catalog, day)),
+    };
+
+    let table_ref = NodeRef::<html::Table>::new();
+    let focused = RwSignal::new(end.max(start));
+    let tooltip = RwSignal::new(None::<HeatmapTooltip>);
+
+    let show_tooltip = move |date: NaiveDate, cell: Option<web_sys::Element>| {
+        let (Some(cell), Some(table)) = (cell, table_ref.get_untracked()) else {
+            return;
+        };
+        let (cell_rect, table_rect) = (
+            cell.get_bounding_client_rect(),
+            table.get_bounding_client_rect(),
+        );
+        tooltip.set(Some(HeatmapTooltip {
+            text: describe(day_at(date)),
+            left: cell_rect.left() - table_rect.left() + cell_rect.width() / 2.0,
+            top: cell_rect.top() - table_rect.top(),
+        }));
+    };
+    let focus_date = move |date: NaiveDate| {
+        let date = date.clamp(start, end);
+        focused.set(date);
+        let cell = table_ref.get_untracked().and_then(|table| {
+            table
+                .query_selector(&format!("[data-date=\"{}\"]", date))
+                .ok()
+                .flatten()
+        });
+        if let Some(cell) = cell.as_ref() {
+            FocusManager::focus(cell);
+        }
+        show_tooltip(date, cell);
+    };
+    let select = move |date: NaiveDate| {
+        if let Some(on_select) = on_select {
+            on_select.run(day_at(date));
+        }
+    };
+
+    let handle_keydown = move |e: web_sys::KeyboardEvent| {
+        let date = focused.get_untracked();
+        let next = match e.key().as_str() {
+            "ArrowUp" => date - Duration::days(1),
+            "ArrowDown" => date + Duration::days(1),
+            "ArrowLeft" => date - Duration::weeks(1),
+            "ArrowRight" => date + Duration::weeks(1),
+            "Home" => start,
+            "End" => end,
+            "Enter" | " " => {
+                e.prevent_default();
+                select(date);
+                return;
+            }
+            _ => return,
+        };
+        e.prevent_default();
+        focus_date(next);
+    };
+
+    let weekday_rows = (0..7)
+        .map(|row| {
+            let weekday = (0..row).fold(week_start, |day, _| day.succ());
+            // Label every other row to keep the column narrow
+            let text = if row % 2 == 1 {
+                weekday.to_string()
+            } else {
+                String::new()
+            };
+            let cells = weeks
+                .iter()
+                .map(|week| match week[row] {
+                    Some(date) => {
+                        let cell_style = move || {
+                            let count = day_at(date).count;
+                            let level = scale.with(|scale| heatmap_level(count, max.get(), scale.len()));
+                            let color = scale.with(|scale| scale.get(level).cloned().unwrap_or_default());
+                            format!(
+                                "width: {0}px; height: {0}px; background: {1}; border-radius: 2px;",
+                                cell_size, color
+                            )
+                        };
+                        view! {
+                            <td
+                                role="gridcell"
+                                class="calendar-heatmap-cell"
+                                data-date=date.to_string()
+                                data-level=move || {
+                                    scale.with(|scale| heatmap_level(day_at(date).count, max.get(), scale.len()))
+                                }
+                                tabindex=move || if focused.get() == date { "0" } else { "-1" }
+                                aria-label=move || describe(day_at(date))
+                                style=cell_style
+                                on:click=move |_| {
+                                    focused.set(date);
+                                    select(date);
+                                }
+                                on:focus=move |e| {
+                                    focused.set(date);
+                                    show_tooltip(date, Some(event_target::<web_sys::Element>(&e)));
+                                }
+                                on:mouseenter=move |e| {
+                                    show_tooltip(date, Some(event_target::<web_sys::Element>(&e)));
+                                }
+                                on:mouseleave=move |_| tooltip.set(None)
+                                on:blur=move |_| tooltip.set(None)
+                            ></td>
+                        }
+                            .into_any()
+                    }
+                    None => view! { <td aria-hidden="true"></td> }.into_any(),
+                })
+                .collect_view();
+            view! {
+                <tr>
+                    <th scope="row" class="calendar-heatmap-weekday" aria-hidden="true">
+                        {text}
+                    </th>
+                    {cells}
+                </tr>
+            }
+        })
+        .collect_view();
+
+    let legend = move || {
+        scale
+            .get()
+            .into_iter()
+            .map(|color| {
+                view! {
+                    <span
+                        class="calendar-heatmap-legend-cell"
+                        style=format!(
+                            "display: inline-block; width: {0}px; height: {0}px; background: {1}; border-radius: 2px;",
+                            cell_size,
+                            color,
+                        )
+                    ></span>
+                }
+            })
+            .collect_view()
+    };
+
+    let class = merge_classes(vec!["calendar-heatmap", class.as_deref().unwrap_or("")]);
+
+    view! {
+        <div class=class style=format!("position: relative; {}", style.unwrap_or_default())>
+            <table
+                node_ref=table_ref
+                class="calendar-heatmap-grid"
+                role="grid"
+                aria-label=label
+                aria-describedby=description_id.clone()
+                style="border-spacing: 3px;"
+                on:keydown=handle_keydown
+            >
+                <thead aria-hidden="true">
+                    <tr>
+                        <td></td>
+                        {month_labels
+                            .into_iter()
+                            .map(|month| view! { <th class="calendar-heatmap-month">{month}</th> })
+                            .collect_view()}
+                    </tr>
+                </thead>
+                <tbody>{weekday_rows}</tbody>
+            </table>
+            {description.map(|description| view! {
+                <p id=description_id style=use_visually_hidden_style()>{description}</p>
+            })}
+            <div class="calendar-heatmap-legend" aria-hidden="true">
+                <span>{less_text}</span>
+                {legend}
+                <span>{more_text}</span>
+            </div>
+            {move || {
+                tooltip
+                    .get()
+                    .map(|tooltip| {
+                        view! {
+                            <div
+                                class="calendar-heatmap-tooltip"
+                                aria-hidden="true"
+                                style=format!(
+                                    "position: absolute; left: {}px; top: {}px; transform: translate(-50%, calc(-100% - 4px)); pointer-events: none; white-space: nowrap;",
+                                    tooltip.left,
+                                    tooltip.top,
+                                )
+                            >
+                                {tooltip.text}
+                            </div>
+                        }
+                    })
+            }}
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_heatmap_level() {
+        assert_eq!(heatmap_level(0, 10, 5), 0);
+        assert_eq!(heatmap_level(1, 10, 5), 1);
+        assert_eq!(heatmap_level(5, 10, 5), 2);
+        assert_eq!(heatmap_level(10, 10, 5), 4);
+        assert_eq!(heatmap_level(3, 0, 5), 0);
+    }
+
+    #[test]
+    fn test_heatmap_weeks() {
+        // Wednesday Jan 3 to Tuesday Jan 16, 2024
+        let weeks = heatmap_weeks(date(2024, 1, 3), date(2024, 1, 16), Weekday::Sun);
+        assert_eq!(weeks.len(), 3);
+        assert_eq!(weeks[0][..3], [None, None, None]);
+        assert_eq!(weeks[0][3], Some(date(2024, 1, 3)));
+        assert_eq!(weeks[2][2], Some(date(2024, 1, 16)));
+        assert_eq!(weeks[2][3], None);
+
+        let monday = heatmap_weeks(date(2024, 1, 3), date(2024, 1, 16), Weekday::Mon);
+        assert_eq!(monday[0][2], Some(date(2024, 1, 3)));
+    }
+
+    #[test]
+    fn test_heatmap_month_labels() {
+        let weeks = heatmap_weeks(date(2024, 1, 20), date(2024, 3, 2), Weekday::Sun);
+        let labels = heatmap_month_labels(&weeks);
+        assert_eq!(labels[0], None);
+        assert_eq!(labels[2].as_deref(), Some("Feb"));
+        assert_eq!(labels.last().cloned().flatten().as_deref(), Some("Mar"));
+
+        let weeks = heatmap_weeks(date(2024, 1, 1), date(2024, 1, 31), Weekday::Sun);
+        assert_eq!(heatmap_month_labels(&weeks)[0].as_deref(), Some("Jan"));
+    }
+
+    #[test]
+    fn test_heatmap_color_scale() {
+        let scale = heatmap_color_scale(&CSSVariables::default());
+        assert_eq!(scale.len(), 5);
+        assert_eq!(scale[4], CSSVariables::default().primary.primary_800);
+        assert_eq!(
+            default_description(&MessageCatalog::default(), HeatmapDay::new(date(2024, 3, 4), 1)),
+            "1 contribution on Monday, March 4, 2024"
+        );
+    }
+
+    #[test]
+    fn test_calendar_heatmap_markup() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let values = vec![HeatmapDay::new(date(2024, 1, 10), 2)];
+            let html = view! {
+                <CalendarHeatmap
+                    values=values
+                    start=date(2024, 1, 1)
+                    end=date(2024, 1, 14)
+                    colors=vec!["#eee".to_string(), "#0a0".to_string()]
+                />
+            }
+            .to_html();
+            assert_eq!(html.matches("role=\"gridcell\"").count(), 14);
+            assert!(html.contains("aria-label=\"2 contributions on Wednesday, January 10, 2024\""));
+            assert!(html.contains("background: #0a0"));
+            assert_eq!(html.matches("tabindex=\"0\"").count(), 1);
+            assert!(!html.contains("aria-describedby"));
+        });
+    }
+
+    #[test]
+    fn test_calendar_heatmap_description() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let html = view! {
+                <CalendarHeatmap
+                    values=Vec::new()
+                    start=date(2024, 1, 1)
+                    end=date(2024, 1, 14)
+                    description="No activity yet".to_string()
+                />
+            }
+            .to_html();
+            assert!(html.contains("aria-describedby=\"calendar-heatmap-description-"));
+            assert!(html.contains("No activity yet</p>"));
+        });
+    }
+}
@@ -0,0 +1,487 @@
+use crate::i18n::{use_i18n, MessageArgs, MessageCatalog, MessageKey};
+use crate::utils::{generate_id, merge_classes};
+use leptos::callback::Callback;
+use leptos::children::Children;
+use leptos::prelude::*;
+use radix_leptos_core::{use_reduced_motion, use_swipe_with_options, UseSwipeOptions};
+use std::time::Duration;
+
+/// Carousel size variant
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CarouselSize {
+    Small,
+    #[default]
+    Medium,
+    Large,
+    FullWidth,
+}
+
+impl CarouselSize {
+    pub fn to_class(&self) -> &'static str {
+        match self {
+            CarouselSize::Small => "radix-carousel--size-small",
+            CarouselSize::Medium => "radix-carousel--size-medium",
+            CarouselSize::Large => "radix-carousel--size-large",
+            CarouselSize::FullWidth => "radix-carousel--size-full-width",
+        }
+    }
+}
+
+/// Which navigation controls are shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CarouselNavigation {
+    Dots,
+    Arrows,
+    #[default]
+    Both,
+    None,
+}
+
+impl CarouselNavigation {
+    pub fn to_class(&self) -> &'static str {
+        match self {
+            CarouselNavigation::Dots => "radix-carousel--navigation-dots",
+            CarouselNavigation::Arrows => "radix-carousel--navigation-arrows",
+            CarouselNavigation::Both => "radix-carousel--navigation-both",
+            CarouselNavigation::None => "radix-carousel--navigation-none",
+        }
+    }
+
+    pub fn shows_arrows(&self) -> bool {
+        matches!(self, CarouselNavigation::Arrows | CarouselNavigation::Both)
+    }
+
+    pub fn shows_dots(&self) -> bool {
+        matches!(self, CarouselNavigation::Dots | CarouselNavigation::Both)
+    }
+}
+
+/// Autoplay speed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CarouselAutoplay {
+    #[default]
+    None,
+    Slow,
+    Medium,
+    Fast,
+}
+
+impl CarouselAutoplay {
+    /// Time each slide is shown, or `None` without autoplay
+    pub fn interval(&self) -> Option<Duration> {
+        match self {
+            CarouselAutoplay::None => None,
+            CarouselAutoplay::Slow => Some(Duration::from_secs(5)),
+            CarouselAutoplay::Medium => Some(Duration::from_secs(3)),
+            CarouselAutoplay::Fast => Some(Duration::from_secs(2)),
+        }
+    }
+}
+
+/// State shared between a Carousel and its slides
+#[derive(Debug, Clone, Copy)]
+pub struct CarouselContext {
+    pub current: RwSignal<usize>,
+    pub count: RwSignal<usize>,
+}
+
+impl CarouselContext {
+    /// Claim the next slide index
+    fn register(&self) -> usize {
+        let index = self.count.get_untracked();
+        self.count.set(index + 1);
+        index
+    }
+}
+
+/// Index reached by moving `delta` slides from `current`
+///
+/// Without `looping` the carousel stops at the first and last slide.
+pub fn carousel_step(current: usize, count: usize, delta: isize, looping: bool) -> usize {
+    if count == 0 {
+        return 0;
+    }
+    let target = current as isize + delta;
+    if looping {
+        target.rem_euclid(count as isize) as usize
+    } else {
+        target.clamp(0, count as isize - 1) as usize
+    }
+}
+
+/// Slide movement for a horizontal swipe of `dx` pixels: a swipe to the
+/// left shows the next slide
+pub fn swipe_step(dx: f64, threshold: f64) -> isize {
+    if dx <= -threshold {
+        1
+    } else if dx >= threshold {
+        -1
+    } else {
+        0
+    }
+}
+
+/// Accessible name of a slide, e.g. "2 of 5"
+pub fn carousel_slide_label(catalog: &MessageCatalog, index: usize, count: usize) -> String {
+    let args = MessageArgs::new()
+        .with("position", index + 1)
+        .with("count", count);
+    catalog.format(MessageKey::CarouselSlideLabel, &args)
+}
+
+/// Carousel component - Rotating slides with controls
+///
+/// Follows the ARIA Authoring Practices carousel pattern: previous/next
+/// buttons, one picker button per slide, and a rotation control when
+/// autoplaying. Autoplay pauses while the pointer is over the carousel or
+/// focus is inside it, and does not start while motion is reduced, by the
+/// user or a MotionProvider. Slides can be swiped and, with focus in the
+/// carousel, changed with the arrow keys.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn Featured() -> impl IntoView {
+///     view! {
+///         <Carousel label="Featured articles".to_string() autoplay=CarouselAutoplay::Medium>
+///             <CarouselSlide>"First"</CarouselSlide>
+///             <CarouselSlide>"Second"</CarouselSlide>
+///             <CarouselSlide>"Third"</CarouselSlide>
+///         </Carousel>
+///     }
+/// }
+/// ```
+#[component]
+pub fn Carousel(
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    /// Accessible name of the carousel (default: "Carousel")
+    #[prop(optional)]
+    label: Option<String>,
+    #[prop(optional)] size: CarouselSize,
+    #[prop(optional)] navigation: CarouselNavigation,
+    #[prop(optional)] autoplay: CarouselAutoplay,
+    /// Wrap from the last slide to the first (default: true)
+    #[prop(optional)]
+    loop_carousel: Option<bool>,
+    /// Index of the slide shown first
+    #[prop(optional)]
+    default_index: Option<usize>,
+    /// Swipe distance in pixels that changes slide (default: 50)
+    #[prop(optional)]
+    swipe_threshold: Option<f64>,
+    #[prop(optional)] on_slide_change: Option<Callback<usize>>,
+    children: Children,
+) -> impl IntoView {
+    let catalog = use_i18n();
+    let label = label.unwrap_or_else(|| catalog.text(MessageKey::CarouselLabel));
+    let looping = loop_carousel.unwrap_or(true);
+    let swipe_threshold = swipe_threshold.unwrap_or(50.0);
+    let interval = autoplay.interval();
+    let track_id = generate_id("carousel-track");
+
+    let context = CarouselContext {
+        current: RwSignal::new(default_index.unwrap_or(0)),
+        count: RwSignal::new(0),
+    };
+    provide_context(context);
+    let current = context.current;
+    let count = context.count;
+
+    // Rotation stops for good once the user pauses it; hovering and focus
+    // only suspend it
+    let playing = RwSignal::new(interval.is_some());
+    let hovered = RwSignal::new(false);
+    let focused = RwSignal::new(false);
+    let rotating = Memo::new(move |_| playing.get() && !hovered.get() && !focused.get());
+
+    let go_to = move |index: usize| {
+        if index != current.get_untracked() {
+            current.set(index);
+            if let Some(on_slide_change) = on_slide_change {
+                on_slide_change.run(index);
+            }
+        }
+    };
+    let step = move |delta: isize| {
+        go_to(carousel_step(
+            current.get_untracked(),
+            count.get_untracked(),
+            delta,
+            looping,
+        ));
+    };
+
+    let track_ref = NodeRef::<leptos::html::Div>::new();
+    use_swipe_with_options(
+        track_ref,
+        move |swipe| {
+            if swipe.direction.is_horizontal() {
+                step(swipe_step(swipe.dx, swipe_threshold));
+            }
+        },
+        UseSwipeOptions {
+            threshold: swipe_threshold,
+            ..Default::default()
+        },
+    );
+
+    // Turning on reduced motion stops autoplay for good, like the pause button
+    let reduced_motion = use_reduced_motion();
+    Effect::new(move |_| {
+        if reduced_motion.get() {
+            playing.set(false);
+        }
+    });
+
+    if let Some(interval) = interval {
+        Effect::new(move |_| {
+            if !rotating.get() {
+                return;
+            }
+            let advance = move || {
+                go_to(carousel_step(
+                    current.get_untracked(),
+                    count.get_untracked(),
+                    1,
+                    true,
+                ))
+            };
+            if let Ok(handle) = set_interval_with_handle(advance, interval) {
+                on_cleanup(move || handle.clear());
+            }
+        });
+    }
+
+    let handle_keydown = move |ev: web_sys::KeyboardEvent| {
+        let last = count.get_untracked().saturating_sub(1);
+        match ev.key().as_str() {
+            "ArrowLeft" => step(-1),
+            "ArrowRight" => step(1),
+            "Home" => go_to(0),
+            "End" => go_to(last),
+            _ => return,
+        }
+        ev.prevent_default();
+    };
+    let handle_focusout = move |ev: web_sys::FocusEvent| {
+        use wasm_bindgen::JsCast;
+        let root = ev
+            .current_target()
+            .and_then(|target| target.dyn_into::<web_sys::Node>().ok());
+        let next = ev
+            .related_target()
+            .and_then(|target| target.dyn_into::<web_sys::Node>().ok());
+        let inside = root.is_some_and(|root| root.contains(next.as_ref()));
+        if !inside {
+            focused.set(false);
+        }
+    };
+
+    let class = merge_classes(vec![
+        "radix-carousel",
+        size.to_class(),
+        navigation.to_class(),
+        class.as_deref().unwrap_or(""),
+    ]);
+
+    let rotation_control = interval.map(|_| {
+        let start_label = catalog.text(MessageKey::CarouselStartRotation);
+        let stop_label = catalog.text(MessageKey::CarouselStopRotation);
+        view! {
+            <button
+                type="button"
+                class="radix-carousel-rotation"
+                aria-label=move || {
+                    if playing.get() { stop_label.clone() } else { start_label.clone() }
+                }
+                on:click=move |_| playing.update(|playing| *playing = !*playing)
+            >
+                {move || if playing.get() { "❚❚" } else { "▶" }}
+            </button>
+        }
+    });
+
+    let arrows = navigation.shows_arrows().then(|| {
+        let at_start = move || !looping && current.get() == 0;
+        let at_end = move || !looping && current.get() + 1 >= count.get();
+        view! {
+            <button
+                type="button"
+                class="radix-carousel-previous"
+                aria-controls=track_id.clone()
+                aria-label=catalog.text(MessageKey::CarouselPrevious)
+                disabled=at_start
+                on:click=move |_| step(-1)
+            >
+                <span aria-hidden="true">"‹"</span>
+            </button>
+            <button
+                type="button"
+                class="radix-carousel-next"
+                aria-controls=track_id.clone()
+                aria-label=catalog.text(MessageKey::CarouselNext)
+                disabled=at_end
+                on:click=move |_| step(1)
+            >
+                <span aria-hidden="true">"›"</span>
+            </button>
+        }
+    });
+
+    let dots = navigation.shows_dots().then(|| {
+        let picker_label = catalog.text(MessageKey::CarouselSlidePicker);
+        let catalog = catalog.clone();
+        view! {
+            <div class="radix-carousel-dots" role="group" aria-label=picker_label>
+                {move || {
+                    (0..count.get())
+                        .map(|index| {
+                            let active = move || current.get() == index;
+                            view! {
+                                <button
+                                    type="button"
+                                    class="radix-carousel-dot"
+                                    class:radix-carousel-dot--active=active
+                                    aria-label=catalog.format(
+                                        MessageKey::CarouselGoToSlide,
+                                        &MessageArgs::new().with("slide", index + 1),
+                                    )
+                                    aria-current=move || active().then_some("true")
+                                    on:click=move |_| go_to(index)
+                                ></button>
+                            }
+                        })
+                        .collect_view()
+                }}
+            </div>
+        }
+    });
+
+    view! {
+        <section
+            class=class
+            style=style
+            aria-roledescription=catalog.text(MessageKey::CarouselRole)
+            aria-label=label
+            data-state=move || if rotating.get() { "playing" } else { "paused" }
+            on:keydown=handle_keydown
+            on:mouseenter=move |_| hovered.set(true)
+            on:mouseleave=move |_| hovered.set(false)
+            on:focusin=move |_| focused.set(true)
+            on:focusout=handle_focusout
+        >
+            <div class="radix-carousel-controls">{rotation_control} {arrows}</div>
+            <div class="radix-carousel-viewport" style="overflow: hidden; touch-action: pan-y;">
+                <div
+                    node_ref=track_ref
+                    id=track_id.clone()
+                    class="radix-carousel-track"
+                    aria-live=move || if rotating.get() { "off" } else { "polite" }
+                    style=move || {
+                        format!(
+                            "display: flex; transition: {}; transform: translateX(-{}%);",
+                            if reduced_motion.get() { "none" } else { "transform 300ms ease" },
+                            current.get() * 100,
+                        )
+                    }
+                >
+                    {children()}
+                </div>
+            </div>
+            {dots}
+        </section>
+    }
+}
+
+/// Carousel slide component
+#[component]
+pub fn CarouselSlide(
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    /// Accessible name; "N of M" by default
+    #[prop(optional)]
+    label: Option<String>,
+    children: Children,
+) -> impl IntoView {
+    let Some(context) = use_context::<CarouselContext>() else {
+        crate::utils::warn_invalid_prop("CarouselSlide", "must be rendered inside a Carousel");
+        return children().into_any();
+    };
+    let index = context.register();
+    let active = move || context.current.get() == index;
+    let label = StoredValue::new(label);
+    let catalog = use_i18n();
+
+    let class = merge_classes(vec!["radix-carousel-slide", class.as_deref().unwrap_or("")]);
+    let style = format!(
+        "flex: 0 0 100%; min-width: 0; {}",
+        style.unwrap_or_default()
+    );
+
+    view! {
+        <div
+            class=class
+            style=style
+            role="group"
+            aria-roledescription=catalog.text(MessageKey::CarouselSlideRole)
+            aria-label=move || {
+                label
+                    .get_value()
+                    .unwrap_or_else(|| carousel_slide_label(&catalog, index, context.count.get()))
+            }
+            aria-hidden=move || (!active()).then_some("true")
+            inert=move || !active()
+            data-state=move || if active() { "active" } else { "inactive" }
+        >
+            {children()}
+        </div>
+    }
+    .into_any()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_carousel_step_loops() {
+        assert_eq!(carousel_step(0, 3, 1, true), 1);
+        assert_eq!(carousel_step(2, 3, 1, true), 0);
+        assert_eq!(carousel_step(0, 3, -1, true), 2);
+        assert_eq!(carousel_step(0, 0, 1, true), 0);
+    }
+
+    #[test]
+    fn test_carousel_step_stops_without_loop() {
+        assert_eq!(carousel_step(2, 3, 1, false), 2);
+        assert_eq!(carousel_step(0, 3, -1, false), 0);
+        assert_eq!(carousel_step(1, 3, 5, false), 2);
+    }
+
+    #[test]
+    fn test_swipe_step() {
+        assert_eq!(swipe_step(-80.0, 50.0), 1);
+        assert_eq!(swipe_step(80.0, 50.0), -1);
+        assert_eq!(swipe_step(20.0, 50.0), 0);
+    }
+
+    #[test]
+    fn test_carousel_slide_label() {
+        let catalog = MessageCatalog::default();
+        assert_eq!(carousel_slide_label(&catalog, 1, 5), "2 of 5");
+    }
+
+    #[test]
+    fn test_autoplay_interval() {
+        assert_eq!(CarouselAutoplay::None.interval(), None);
+        assert_eq!(
+            CarouselAutoplay::Medium.interval(),
+            Some(Duration::from_secs(3))
+        );
+    }
+}
@@ -0,0 +1,716 @@
+use crate::i18n::{use_i18n, MessageArgs, MessageKey};
+use crate::utils::{generate_id, merge_classes};
+use leptos::callback::Callback;
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+
+/// Shown between the labels of a selected path
+const PATH_SEPARATOR: &str = " / ";
+
+/// Values of the options along a path, from the first column down
+pub type CascaderValue = RwSignal<Vec<String>>;
+
+/// Called with the values of a path
+pub type CascaderPathHandler = Callback<Vec<String>>;
+
+/// An option of a Cascader column
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CascaderOption {
+    pub value: String,
+    pub label: String,
+    pub disabled: bool,
+    /// `None` until loaded for lazy options
+    pub children: Option<Vec<CascaderOption>>,
+    /// Children are requested through `on_load` when the option is opened
+    pub lazy: bool,
+}
+
+impl CascaderOption {
+    pub fn new(value: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            value: value.into(),
+            label: label.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn children(mut self, children: Vec<CascaderOption>) -> Self {
+        self.children = Some(children);
+        self
+    }
+
+    pub fn lazy(mut self) -> Self {
+        self.lazy = true;
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Whether selecting the option completes a path
+    pub fn is_leaf(&self) -> bool {
+        match &self.children {
+            Some(children) => children.is_empty(),
+            None => !self.lazy,
+        }
+    }
+
+    /// Whether the option's children still have to be loaded
+    pub fn needs_load(&self) -> bool {
+        self.lazy && self.children.is_none()
+    }
+}
+
+/// A complete path found by searching a Cascader
+#[derive(Debug, Clone, PartialEq)]
+pub struct CascaderPath {
+    pub values: Vec<String>,
+    pub labels: Vec<String>,
+}
+
+/// What a key press does to a Cascader's highlighted path
+#[derive(Debug, Clone, PartialEq)]
+pub enum CascaderAction {
+    Highlight(Vec<String>),
+    Select(Vec<String>),
+    /// Request the children of the option at the end of the path
+    Load(Vec<String>),
+}
+
+/// Columns shown for the highlighted `path`: the top level, then the
+/// children of each highlighted option that has them
+pub fn cascader_columns(options: &[CascaderOption], path: &[String]) -> Vec<Vec<CascaderOption>> {
+    let mut columns = vec![options.to_vec()];
+    for value in path {
+        let children = columns
+            .last()
+            .and_then(|column| column.iter().find(|option| &option.value == value))
+            .and_then(|option| option.children.clone())
+            .filter(|children| !children.is_empty());
+        match children {
+            Some(children) => columns.push(children),
+            None => break,
+        }
+    }
+    columns
+}
+
+/// Option at the end of `path`
+pub fn find_cascader_option<'a>(
+    options: &'a [CascaderOption],
+    path: &[String],
+) -> Option<&'a CascaderOption> {
+    let (first, rest) = path.split_first()?;
+    let option = options.iter().find(|option| &option.value == first)?;
+    if rest.is_empty() {
+        Some(option)
+    } else {
+        find_cascader_option(option.children.as_deref()?, rest)
+    }
+}
+
+/// Labels of the options along `path`, stopping at the first unknown value
+pub fn cascader_labels(options: &[CascaderOption], path: &[String]) -> Vec<String> {
+    let mut labels = Vec::new();
+    let mut column = options;
+    for value in path {
+        let Some(option) = column.iter().find(|option| &option.value == value) else {
+            break;
+        };
+        labels.push(option.label.clone());
+        column = option.children.as_deref().unwrap_or_default();
+    }
+    labels
+}
+
+/// Store loaded children on the option at the end of `path`
+///
+/// Returns false when the path does not lead to an option.
+pub fn set_cascader_children(
+    options: &mut [CascaderOption],
+    path: &[String],
+    children: Vec<CascaderOption>,
+) -> bool {
+    let Some((first, rest)) = path.split_first() else {
+        return false;
+    };
+    let Some(option) = options.iter_mut().find(|option| &option.value == first) else {
+        return false;
+    };
+    if rest.is_empty() {
+        option.children = Some(children);
+        return true;
+    }
+    match option.children.as_mut() {
+        Some(column) => set_cascader_children(column, rest, children),
+        None => false,
+    }
+}
+
+/// Complete, enabled paths with a label containing `query`
+///
+/// Only children that have already been loaded are searched.
+pub fn cascader_search(options: &[CascaderOption], query: &str) -> Vec<CascaderPath> {
+    fn search(
+        column: &[CascaderOption],
+        query: &str,
+        prefix: &CascaderPath,
+        matched: bool,
+        results: &mut Vec<CascaderPath>,
+    ) {
+        for option in column.iter().filter(|option| !option.disabled) {
+            let mut path = prefix.clone();
+            path.values.push(option.value.clone());
+            path.labels.push(option.label.clone());
+            let matched = matched || option.label.to_lowercase().contains(query);
+            if option.is_leaf() {
+                if matched {
+                    results.push(path);
+                }
+            } else if let Some(children) = &option.children {
+                search(children, query, &path, matched, results);
+            }
+        }
+    }
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return Vec::new();
+    }
+    let mut results = Vec::new();
+    let root = CascaderPath {
+        values: Vec::new(),
+        labels: Vec::new(),
+    };
+    search(options, &query, &root, false, &mut results);
+    results
+}
+
+fn step_enabled(column: &[CascaderOption], current: Option<usize>, forward: bool) -> Option<usize> {
+    let count = column.len();
+    (1..=count)
+        .map(|offset| match (current, forward) {
+            (Some(current), true) => (current + offset) % count,
+            (Some(current), false) => (current + count - offset % count) % count,
+            (None, true) => offset - 1,
+            (None, false) => count - offset,
+        })
+        .find(|&index| !column[index].disabled)
+}
+
+/// Action for `key` pressed while `path` is highlighted
+///
+/// Up and Down move within the deepest column, Right and Enter open the
+/// highlighted option's children (loading them first if needed), Left
+/// returns to the parent column and Enter on a leaf selects the path.
+pub fn cascader_key_action(
+    options: &[CascaderOption],
+    path: &[String],
+    key: &str,
+) -> Option<CascaderAction> {
+    let columns = cascader_columns(options, path);
+    let Some(depth) = path.len().checked_sub(1) else {
+        let root = &columns[0];
+        let index = match key {
+            "ArrowDown" | "Home" => step_enabled(root, None, true),
+            "ArrowUp" | "End" => step_enabled(root, None, false),
+            _ => None,
+        }?;
+        return Some(CascaderAction::Highlight(vec![root[index].value.clone()]));
+    };
+    let column = columns.get(depth)?;
+    let current = column
+        .iter()
+        .position(|option| option.value == path[depth])?;
+    let option = &column[current];
+    let sibling = |index: usize| {
+        let mut next = path[..depth].to_vec();
+        next.push(column[index].value.clone());
+        CascaderAction::Highlight(next)
+    };
+
+    match key {
+        "ArrowDown" => step_enabled(column, Some(current), true).map(sibling),
+        "ArrowUp" => step_enabled(column, Some(current), false).map(sibling),
+        "Home" => step_enabled(column, None, true).map(sibling),
+        "End" => step_enabled(column, None, false).map(sibling),
+        "ArrowLeft" if depth > 0 => Some(CascaderAction::Highlight(path[..depth].to_vec())),
+        "Enter" if option.is_leaf() && !option.disabled => {
+            Some(CascaderAction::Select(path.to_vec()))
+        }
+        "ArrowRight" | "Enter" if option.needs_load() => Some(CascaderAction::Load(path.to_vec())),
+        "ArrowRight" | "Enter" if !option.is_leaf() => {
+            let children = option.children.as_deref().unwrap_or_default();
+            let first = step_enabled(children, None, true)?;
+            let mut next = path.to_vec();
+            next.push(children[first].value.clone());
+            Some(CascaderAction::Highlight(next))
+        }
+        _ => None,
+    }
+}
+
+/// Cascader component - Multi-level cascading select
+///
+/// Each level of `options` opens in its own column, e.g. country, then
+/// state, then city. Options marked lazy request their children through
+/// `on_load` when opened; store them with [`set_cascader_children`].
+/// Typing filters complete paths across every loaded level. Focus stays in
+/// the input: Up and Down move within a column, Right and Left move
+/// between columns, Enter selects and Escape closes.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn Location() -> impl IntoView {
+///     let options = RwSignal::new(vec![
+///         CascaderOption::new("fr", "France").children(vec![
+///             CascaderOption::new("paris", "Paris"),
+///             CascaderOption::new("lyon", "Lyon"),
+///         ]),
+///         CascaderOption::new("us", "United States").lazy(),
+///     ]);
+///     let on_load = Callback::new(move |path: Vec<String>| {
+///         let states = vec![CascaderOption::new("ca", "California")];
+///         options.update(|options| {
+///             set_cascader_children(options, &path, states);
+///         });
+///     });
+///     view! { <Cascader options=options on_load=on_load label="Location".to_string() /> }
+/// }
+/// ```
+#[component]
+pub fn Cascader(
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    options: RwSignal<Vec<CascaderOption>>,
+    /// Values of the selected path
+    #[prop(optional)]
+    value: Option<CascaderValue>,
+    /// Accessible name of the input
+    #[prop(optional)]
+    label: Option<String>,
+    #[prop(optional)] placeholder: Option<String>,
+    /// Filter paths by typing (default: true)
+    #[prop(optional)]
+    searchable: Option<bool>,
+    #[prop(optional)] disabled: Option<bool>,
+    /// Called with the selected path
+    #[prop(optional)]
+    on_change: Option<CascaderPathHandler>,
+    /// Called with the path of a lazy option whose children are needed
+    #[prop(optional)]
+    on_load: Option<CascaderPathHandler>,
+) -> impl IntoView {
+    let catalog = StoredValue::new(use_i18n());
+    let label = label.unwrap_or_else(|| catalog.with_value(|catalog| catalog.text(MessageKey::CascaderLabel)));
+    let placeholder = StoredValue::new(placeholder.unwrap_or_else(|| {
+        catalog.with_value(|catalog| catalog.text(MessageKey::CascaderPlaceholder))
+    }));
+    let searchable = searchable.unwrap_or(true);
+    let disabled = disabled.unwrap_or(false);
+    let value = value.unwrap_or_else(|| RwSignal::new(Vec::new()));
+    let open = RwSignal::new(false);
+    let query = RwSignal::new(String::new());
+    let active = RwSignal::new(Vec::<String>::new());
+    let search_active = RwSignal::new(0usize);
+    let loading = RwSignal::new(None::<Vec<String>>);
+    let list_id = StoredValue::new(generate_id("cascader"));
+
+    let searching = Memo::new(move |_| searchable && !query.with(|query| query.trim().is_empty()));
+    let results = Memo::new(move |_| {
+        if searching.get() {
+            options.with(|options| query.with(|query| cascader_search(options, query)))
+        } else {
+            Vec::new()
+        }
+    });
+    let selected_label = Memo::new(move |_| {
+        options
+            .with(|options| value.with(|value| cascader_labels(options, value)))
+            .join(PATH_SEPARATOR)
+    });
+
+    let set_open = move |next: bool| {
+        if next {
+            let path = value.get_untracked();
+            let known =
+                options.with_untracked(|options| find_cascader_option(options, &path).is_some());
+            active.set(if known { path } else { Vec::new() });
+        } else {
+            query.set(String::new());
+        }
+        open.set(next);
+    };
+    let select = move |path: Vec<String>| {
+        value.set(path.clone());
+        if let Some(on_change) = on_change {
+            on_change.run(path);
+        }
+        set_open(false);
+    };
+    let load = move |path: Vec<String>| {
+        if let Some(on_load) = on_load {
+            loading.set(Some(path.clone()));
+            on_load.run(path);
+        }
+    };
+    let activate = move |path: Vec<String>| {
+        active.set(path.clone());
+        let option =
+            options.with_untracked(|options| find_cascader_option(options, &path).cloned());
+        match option {
+            Some(option) if option.disabled => {}
+            Some(option) if option.needs_load() => load(path),
+            Some(option) if option.is_leaf() => select(path),
+            _ => {}
+        }
+    };
+
+    let handle_keydown = move |ev: web_sys::KeyboardEvent| {
+        let key = ev.key();
+        if !open.get_untracked() {
+            if matches!(key.as_str(), "ArrowDown" | "Enter") {
+                ev.prevent_default();
+                set_open(true);
+            }
+            return;
+        }
+        match key.as_str() {
+            "Escape" => {
+                ev.prevent_default();
+                set_open(false);
+                return;
+            }
+            "Tab" => {
+                set_open(false);
+                return;
+            }
+            _ => {}
+        }
+        if searching.get_untracked() {
+            let count = results.with_untracked(Vec::len);
+            match key.as_str() {
+                "ArrowDown" if count > 0 => {
+                    search_active.update(|index| *index = (*index + 1) % count)
+                }
+                "ArrowUp" if count > 0 => {
+                    search_active.update(|index| *index = (*index + count - 1) % count)
+                }
+                "Enter" => {
+                    let chosen = results.with_untracked(|results| {
+                        results.get(search_active.get_untracked()).cloned()
+                    });
+                    if let Some(path) = chosen {
+                        select(path.values);
+                    }
+                }
+                _ => return,
+            }
+            ev.prevent_default();
+            return;
+        }
+        let action = options.with_untracked(|options| {
+            active.with_untracked(|path| cascader_key_action(options, path, &key))
+        });
+        match action {
+            Some(CascaderAction::Highlight(path)) => active.set(path),
+            Some(CascaderAction::Select(path)) => select(path),
+            Some(CascaderAction::Load(path)) => load(path),
+            None => return,
+        }
+        ev.prevent_default();
+    };
+    let handle_focusout = move |ev: web_sys::FocusEvent| {
+        let Some(wrapper) = ev
+            .current_target()
+            .and_then(|target| target.dyn_into::<web_sys::Node>().ok())
+        else {
+            return;
+        };
+        let next = ev
+            .related_target()
+            .and_then(|target| target.dyn_into::<web_sys::Node>().ok());
+        if !wrapper.contains(next.as_ref()) && open.get_untracked() {
+            set_open(false);
+        }
+    };
+
+    let option_id = move |column: usize, row: usize| {
+        list_id.with_value(|id| format!("{}-{}-{}", id, column, row))
+    };
+    let active_descendant = move || {
+        if !open.get() {
+            return None;
+        }
+        if searching.get() {
+            let index = search_active.get();
+            return results
+                .with(|results| index < results.len())
+                .then(|| list_id.with_value(|id| format!("{}-result-{}", id, index)));
+        }
+        let path = active.get();
+        let depth = path.len().checked_sub(1)?;
+        let row = options.with(|options| {
+            cascader_columns(options, &path)
+                .get(depth)?
+                .iter()
+                .position(|option| option.value == path[depth])
+        })?;
+        Some(option_id(depth, row))
+    };
+
+    let columns = move || {
+        let path = active.get();
+        let mut columns = options.with(|options| cascader_columns(options, &path))
+            .into_iter()
+            .enumerate()
+            .map(|(column_index, column)| {
+                let prefix = path[..column_index.min(path.len())].to_vec();
+                let highlighted = path.get(column_index).cloned();
+                let rows = column
+                    .into_iter()
+                    .enumerate()
+                    .map(|(row, option)| {
+                        let mut option_path = prefix.clone();
+                        option_path.push(option.value.clone());
+                        let is_highlighted = highlighted.as_ref() == Some(&option.value);
+                        let is_leaf = option.is_leaf();
+                        let option_disabled = option.disabled;
+                        view! {
+                            <li
+                                id=option_id(column_index, row)
+                                class="cascader-option"
+                                role="option"
+                                aria-selected=is_highlighted.to_string()
+                                aria-disabled=option_disabled.then_some("true")
+                                aria-haspopup=(!is_leaf).then_some("listbox")
+                                data-highlighted=is_highlighted.then_some("")
+                                on:click=move |_| activate(option_path.clone())
+                            >
+                                <span class="cascader-option-label">{option.label}</span>
+                                {(!is_leaf).then(|| view! { <span class="cascader-option-expand" aria-hidden="true">"›"</span> })}
+                            </li>
+                        }
+                    })
+                    .collect_view();
+                let level_label = catalog.with_value(|catalog| {
+                    catalog.format(
+                        MessageKey::CascaderLevel,
+                        &MessageArgs::new().with("level", column_index + 1),
+                    )
+                });
+                view! {
+                    <ul class="cascader-column" role="listbox" aria-label=level_label>
+                        {rows}
+                    </ul>
+                }
+                .into_any()
+            })
+            .collect::<Vec<_>>();
+        let pending = loading.get().is_some_and(|loading| loading == path)
+            && options.with(|options| {
+                find_cascader_option(options, &path).is_some_and(CascaderOption::needs_load)
+            });
+        if pending {
+            let loading_text = catalog.with_value(|catalog| catalog.text(MessageKey::Loading));
+            columns.push(
+                view! { <div class="cascader-column cascader-loading" role="status">{loading_text}</div> }
+                    .into_any(),
+            );
+        }
+        columns
+    };
+    let search_results = move || {
+        let results = results.get();
+        if results.is_empty() {
+            let empty_text = catalog.with_value(|catalog| catalog.text(MessageKey::NoResults));
+            return view! { <li class="cascader-empty" role="presentation">{empty_text}</li> }
+                .into_any();
+        }
+        results
+            .into_iter()
+            .enumerate()
+            .map(|(index, path)| {
+                let id = list_id.with_value(|id| format!("{}-result-{}", id, index));
+                let text = path.labels.join(PATH_SEPARATOR);
+                let is_highlighted = move || search_active.get() == index;
+                view! {
+                    <li
+                        id=id
+                        class="cascader-option"
+                        role="option"
+                        aria-selected=move || is_highlighted().to_string()
+                        data-highlighted=move || is_highlighted().then_some("")
+                        on:click=move |_| select(path.values.clone())
+                    >
+                        {text}
+                    </li>
+                }
+            })
+            .collect_view()
+            .into_any()
+    };
+
+    let class = merge_classes(vec!["cascader", class.as_deref().unwrap_or("")]);
+    let style = format!("position: relative; {}", style.unwrap_or_default());
+    let controls = list_id.get_value();
+    let results_label = catalog.with_value(|catalog| catalog.text(MessageKey::CascaderResults));
+
+    view! {
+        <div class=class style=style data-state=move || if open.get() { "open" } else { "closed" } on:focusout=handle_focusout>
+            <input
+                type="text"
+                class="cascader-input"
+                role="combobox"
+                aria-label=label
+                aria-haspopup="listbox"
+                aria-autocomplete=searchable.then_some("list")
+                aria-expanded=move || open.get().to_string()
+                aria-controls=controls
+                aria-activedescendant=active_descendant
+                readonly=!searchable
+                disabled=disabled
+                autocomplete="off"
+                placeholder=move || {
+                    let selected = selected_label.get();
+                    if open.get() && !selected.is_empty() { selected } else { placeholder.get_value() }
+                }
+                prop:value=move || if open.get() { query.get() } else { selected_label.get() }
+                on:click=move |_| if !open.get_untracked() { set_open(true) }
+                on:input=move |ev| {
+                    query.set(event_target_value(&ev));
+                    search_active.set(0);
+                    open.set(true);
+                }
+                on:keydown=handle_keydown
+            />
+            <div
+                id=list_id.get_value()
+                class="cascader-content"
+                hidden=move || !open.get()
+                style="position: absolute; top: 100%; left: 0; z-index: 50; display: flex;"
+                on:mousedown=|ev: web_sys::MouseEvent| ev.prevent_default()
+            >
+                {move || if searching.get() {
+                    view! { <ul class="cascader-column cascader-results" role="listbox" aria-label=results_label.clone()>{search_results}</ul> }.into_any()
+                } else {
+                    columns().into_any()
+                }}
+            </div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options() -> Vec<CascaderOption> {
+        vec![
+            CascaderOption::new("fr", "France").children(vec![
+                CascaderOption::new("idf", "Ile-de-France")
+                    .children(vec![CascaderOption::new("paris", "Paris")]),
+                CascaderOption::new("ara", "Auvergne").disabled(true),
+                CascaderOption::new("occ", "Occitanie")
+                    .children(vec![CascaderOption::new("toulouse", "Toulouse")]),
+            ]),
+            CascaderOption::new("us", "United States").lazy(),
+        ]
+    }
+
+    fn path(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn test_columns_follow_path() {
+        let options = options();
+        assert_eq!(cascader_columns(&options, &[]).len(), 1);
+        let columns = cascader_columns(&options, &path(&["fr", "idf"]));
+        assert_eq!(columns.len(), 3);
+        assert_eq!(columns[2][0].value, "paris");
+        assert_eq!(cascader_columns(&options, &path(&["us"])).len(), 1);
+        assert_eq!(
+            cascader_labels(&options, &path(&["fr", "idf", "paris"])),
+            vec!["France", "Ile-de-France", "Paris"]
+        );
+    }
+
+    #[test]
+    fn test_keyboard_moves_between_columns() {
+        let options = options();
+        let highlight = |values: &[&str]| Some(CascaderAction::Highlight(path(values)));
+
+        assert_eq!(
+            cascader_key_action(&options, &[], "ArrowDown"),
+            highlight(&["fr"])
+        );
+        assert_eq!(
+            cascader_key_action(&options, &path(&["fr"]), "ArrowRight"),
+            highlight(&["fr", "idf"])
+        );
+        // The disabled option is skipped
+        assert_eq!(
+            cascader_key_action(&options, &path(&["fr", "idf"]), "ArrowDown"),
+            highlight(&["fr", "occ"])
+        );
+        assert_eq!(
+            cascader_key_action(&options, &path(&["fr", "occ"]), "ArrowDown"),
+            highlight(&["fr", "idf"])
+        );
+        assert_eq!(
+            cascader_key_action(&options, &path(&["fr", "idf"]), "ArrowLeft"),
+            highlight(&["fr"])
+        );
+        assert_eq!(
+            cascader_key_action(&options, &path(&["fr"]), "ArrowLeft"),
+            None
+        );
+        assert_eq!(
+            cascader_key_action(&options, &path(&["fr", "idf", "paris"]), "Enter"),
+            Some(CascaderAction::Select(path(&["fr", "idf", "paris"])))
+        );
+        assert_eq!(
+            cascader_key_action(&options, &path(&["us"]), "ArrowRight"),
+            Some(CascaderAction::Load(path(&["us"])))
+        );
+    }
+
+    #[test]
+    fn test_lazy_children() {
+        let mut options = options();
+        assert!(options[1].needs_load());
+        assert!(set_cascader_children(
+            &mut options,
+            &path(&["us"]),
+            vec![CascaderOption::new("ca", "California")]
+        ));
+        assert!(!options[1].needs_load());
+        assert!(!options[1].is_leaf());
+        assert!(!set_cascader_children(
+            &mut options,
+            &path(&["mx"]),
+            Vec::new()
+        ));
+    }
+
+    #[test]
+    fn test_search_across_paths() {
+        let options = options();
+        let results = cascader_search(&options, "pari");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].values, path(&["fr", "idf", "paris"]));
+
+        // A matching ancestor includes every complete path below it
+        let results = cascader_search(&options, "france");
+        assert_eq!(results.len(), 2);
+        assert!(cascader_search(&options, "auvergne").is_empty());
+        assert!(cascader_search(&options, " ").is_empty());
+    }
+}
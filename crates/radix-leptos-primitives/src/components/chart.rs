@@ -1,8 +1,54 @@
+use crate::i18n::{use_i18n, MessageKey};
+use crate::theming::{series_color_declarations, use_chart_palette, ChartPalette};
+use crate::utils::{generate_id, merge_classes};
+use leptos::callback::Callback;
+use leptos::children::Children;
+use leptos::prelude::*;
+use radix_leptos_core::use_visually_hidden_style;
 
 /// Chart component - Base visualization infrastructure
-/// 
+///
 /// Provides the foundation for all data visualization components with comprehensive
 /// accessibility, performance, and interaction capabilities
+///
+/// The drawing is a single image to assistive technology, named by `label`
+/// and described by `description`, which should state what the chart shows
+/// ("Revenue doubled between Q1 and Q4"). The series in `data` are also
+/// rendered as a visually hidden table after the chart, so screen reader
+/// users can read the values themselves.
+///
+/// Series without a `color` take the categorical color at their index from
+/// the closest ChartPaletteProvider or the theme. The colors are set on the
+/// chart as `--chart-series-1`, `--chart-series-2`, … for the drawing.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn Revenue() -> impl IntoView {
+///     let data = ChartData {
+///         series: vec![DataSeries {
+///             name: "Revenue".to_string(),
+///             data: vec![
+///                 DataPoint { x: 0.0, y: 120.0, label: None },
+///                 DataPoint { x: 1.0, y: 240.0, label: None },
+///             ],
+///             color: None,
+///         }],
+///         categories: Some(vec!["Q1".to_string(), "Q4".to_string()]),
+///     };
+///     view! {
+///         <Chart
+///             data=data
+///             label="Quarterly revenue".to_string()
+///             description="Revenue doubled between Q1 and Q4".to_string()
+///         />
+///     }
+/// }
+/// ```
 #[component]
 pub fn Chart(
     #[prop(optional)] class: Option<String>,
@@ -13,6 +59,12 @@ pub fn Chart(
     #[prop(optional)] theme: Option<ChartTheme>,
     #[prop(optional)] interactive: Option<bool>,
     #[prop(optional)] animated: Option<bool>,
+    /// Accessible name, also the caption of the data table
+    #[prop(optional)]
+    label: Option<String>,
+    /// Summary of what the chart shows
+    #[prop(optional)]
+    description: Option<String>,
     #[prop(optional)] on_data_point_click: Option<Callback<DataPoint>>,
     #[prop(optional)] on_legend_click: Option<Callback<LegendItem>>,
 ) -> impl IntoView {
@@ -20,14 +72,29 @@ pub fn Chart(
     let animated = animated.unwrap_or(true);
     let config = config.unwrap_or_default();
     let theme = theme.unwrap_or_default();
+    let i18n = use_i18n();
+    let label = label.unwrap_or_else(|| i18n.text(MessageKey::ChartLabel));
+    let table = data
+        .as_ref()
+        .map(|data| ChartTable::from_data(data, &i18n.text(MessageKey::ChartCategory)))
+        .filter(|table| !table.rows.is_empty());
+    let description_id = description.as_ref().map(|_| generate_id("chart-description"));
+    let palette = use_chart_palette();
+    let style = move || {
+        let colors = data
+            .as_ref()
+            .map(|data| palette.with(|palette| data.series_colors(palette)))
+            .unwrap_or_default();
+        chart_root_style(&colors, style.as_deref())
+    };
 
     let class = merge_classes(vec![
         "chart",
-        &theme.to_class(),
+        theme.to_class(),
         class.as_deref().unwrap_or(""),
     ]);
 
-    let handle_data_point_click = move |point: DataPoint| {
+    let _handle_data_point_click = move |point: DataPoint| {
         if interactive {
             if let Some(on_data_point_click) = on_data_point_click {
                 on_data_point_click.run(point);
@@ -35,7 +102,7 @@ pub fn Chart(
         }
     };
 
-    let handle_legend_click = move |item: LegendItem| {
+    let _handle_legend_click = move |item: LegendItem| {
         if interactive {
             if let Some(on_legend_click) = on_legend_click {
                 on_legend_click.run(item);
@@ -48,28 +115,172 @@ pub fn Chart(
             class=class
             style=style
             role="img"
-            aria-label="Data visualization chart"
+            aria-label=label.clone()
+            aria-describedby=description_id.clone()
             data-width=config.width
             data-height=config.height
+            data-animated=animated
         >
             {children.map(|c| c())}
         </div>
+        {description.map(|description| view! {
+            <p id=description_id style=use_visually_hidden_style()>{description}</p>
+        })}
+        {table.map(|table| view! { <ChartDataTable table=table caption=label /> })}
     }
 }
 
-/// Chart Data structure
+/// The `style` attribute of a chart's root element: the series color
+/// declarations followed by the chart's own `style`
+pub(crate) fn chart_root_style(colors: &[String], style: Option<&str>) -> Option<String> {
+    let style = [series_color_declarations(colors).as_str(), style.unwrap_or("")]
+        .into_iter()
+        .filter(|style| !style.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    (!style.is_empty()).then_some(style)
+}
+
+/// Values of a chart as a table: one row per x value or category, one
+/// column per series
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChartTable {
+    /// Header of the first column, then the series names
+    pub headers: Vec<String>,
+    pub rows: Vec<ChartTableRow>,
+}
+
+/// Row of a [`ChartTable`], with an empty cell where a series has no point
 #[derive(Debug, Clone, PartialEq)]
+pub struct ChartTableRow {
+    pub label: String,
+    pub values: Vec<Option<String>>,
+}
+
+impl ChartTable {
+    /// Tabulate `data`, rows ordered by x
+    ///
+    /// Rows are labelled by the point's label, else by the category at
+    /// index x, else by x itself; `category_header` heads the label column.
+    pub fn from_data(data: &ChartData, category_header: &str) -> Self {
+        let mut xs: Vec<f64> = data
+            .series
+            .iter()
+            .flat_map(|series| series.data.iter().map(|point| point.x))
+            .collect();
+        xs.sort_by(f64::total_cmp);
+        xs.dedup();
+
+        let row_label = |x: f64| {
+            let point_label = data
+                .series
+                .iter()
+                .flat_map(|series| &series.data)
+                .find(|point| point.x == x)
+                .and_then(|point| point.label.clone());
+            let category = data.categories.as_ref().and_then(|categories| {
+                (x >= 0.0 && x.fract() == 0.0)
+                    .then(|| categories.get(x as usize).cloned())
+                    .flatten()
+            });
+            point_label.or(category).unwrap_or_else(|| x.to_string())
+        };
+        let rows = xs
+            .into_iter()
+            .map(|x| ChartTableRow {
+                label: row_label(x),
+                values: data
+                    .series
+                    .iter()
+                    .map(|series| {
+                        series
+                            .data
+                            .iter()
+                            .find(|point| point.x == x)
+                            .map(|point| point.y.to_string())
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        let mut headers = vec![category_header.to_string()];
+        headers.extend(data.series.iter().map(|series| series.name.clone()));
+        Self { headers, rows }
+    }
+}
+
+/// Chart Data Table component - The values behind a chart as a visually
+/// hidden table
+///
+/// [`Chart`] renders one from its data; charts drawn another way can render
+/// their own.
+#[component]
+pub fn ChartDataTable(
+    table: ChartTable,
+    /// Caption, usually the chart's accessible name
+    #[prop(optional)]
+    caption: Option<String>,
+    #[prop(optional)] class: Option<String>,
+) -> impl IntoView {
+    let class = merge_classes(vec!["chart-data-table", class.as_deref().unwrap_or("")]);
+    let ChartTable { headers, rows } = table;
+
+    view! {
+        <table class=class style=use_visually_hidden_style()>
+            {caption.map(|caption| view! { <caption>{caption}</caption> })}
+            <thead>
+                <tr>
+                    {headers
+                        .into_iter()
+                        .map(|header| view! { <th scope="col">{header}</th> })
+                        .collect_view()}
+                </tr>
+            </thead>
+            <tbody>
+                {rows
+                    .into_iter()
+                    .map(|row| {
+                        view! {
+                            <tr>
+                                <th scope="row">{row.label}</th>
+                                {row
+                                    .values
+                                    .into_iter()
+                                    .map(|value| view! { <td>{value.unwrap_or_default()}</td> })
+                                    .collect_view()}
+                            </tr>
+                        }
+                    })
+                    .collect_view()}
+            </tbody>
+        </table>
+    }
+}
+
+/// Chart Data structure
+#[derive(Debug, Clone, PartialEq, Default)]
 pub struct ChartData {
     pub series: Vec<DataSeries>,
     pub categories: Option<Vec<String>>,
 }
 
-impl Default for ChartData {
-    fn default() -> Self {
-        Self {
-            series: Vec::new(),
-            categories: None,
-        }
+impl ChartData {
+    /// Colors the series are drawn in, from `palette` where a series has none
+    pub fn series_colors(&self, palette: &ChartPalette) -> Vec<String> {
+        palette.series_colors(self.series.iter().map(|series| series.color.as_deref()))
+    }
+
+    /// Legend entries for the series, in the colors they are drawn in
+    pub fn legend_items(&self, palette: &ChartPalette) -> Vec<LegendItem> {
+        self.series
+            .iter()
+            .zip(self.series_colors(palette))
+            .map(|(series, color)| LegendItem {
+                name: series.name.clone(),
+                color,
+                visible: true,
+            })
+            .collect()
     }
 }
 
@@ -78,6 +289,7 @@ impl Default for ChartData {
 pub struct DataSeries {
     pub name: String,
     pub data: Vec<DataPoint>,
+    /// Series color, the palette's color for its index when `None`
     pub color: Option<String>,
 }
 
@@ -95,9 +307,9 @@ pub struct ChartConfig {
     pub width: f64,
     pub height: f64,
     pub margin: ChartMargin,
-    pub __show_legend: bool,
-    pub __show_grid: bool,
-    pub __show_tooltips: bool,
+    pub show_legend: bool,
+    pub show_grid: bool,
+    pub show_tooltips: bool,
 }
 
 impl Default for ChartConfig {
@@ -133,6 +345,16 @@ impl Default for ChartMargin {
     }
 }
 
+/// Axis Configuration
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AxisConfig {
+    pub label: Option<String>,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub ticks: Option<usize>,
+    pub format: Option<String>,
+}
+
 /// Chart Theme
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ChartTheme {
@@ -167,18 +389,18 @@ pub fn ChartTooltip(
     #[prop(optional)] style: Option<String>,
     #[prop(optional)] visible: Option<ReadSignal<bool>>,
     #[prop(optional)] content: Option<String>,
-    #[prop(optional)] position: Option<TooltipPosition>,
+    #[prop(optional)] position: Option<ChartTooltipPosition>,
 ) -> impl IntoView {
     let visible = visible.map(|v| v.get()).unwrap_or(false);
     let position = position.unwrap_or_default();
 
     if !visible {
-        return view! { <></> }.into_any();
+        return ().into_any();
     }
 
     let class = merge_classes(vec![
         "chart-tooltip",
-        &position.to_class(),
+        position.to_class(),
         class.as_deref().unwrap_or(""),
     ]);
 
@@ -196,7 +418,7 @@ pub fn ChartTooltip(
 
 /// Tooltip Position
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
-pub enum TooltipPosition {
+pub enum ChartTooltipPosition {
     #[default]
     Top,
     Bottom,
@@ -204,13 +426,13 @@ pub enum TooltipPosition {
     Right,
 }
 
-impl TooltipPosition {
+impl ChartTooltipPosition {
     pub fn to_class(&self) -> &'static str {
         match self {
-            TooltipPosition::Top => "position-top",
-            TooltipPosition::Bottom => "position-bottom",
-            TooltipPosition::Left => "position-left",
-            TooltipPosition::Right => "position-right",
+            ChartTooltipPosition::Top => "position-top",
+            ChartTooltipPosition::Bottom => "position-bottom",
+            ChartTooltipPosition::Left => "position-left",
+            ChartTooltipPosition::Right => "position-right",
         }
     }
 }
@@ -229,7 +451,7 @@ pub fn ChartLegend(
 
     let class = merge_classes(vec![
         "chart-legend",
-        &orientation.to_class(),
+        orientation.to_class(),
         class.as_deref().unwrap_or(""),
     ]);
 
@@ -244,7 +466,7 @@ pub fn ChartLegend(
             class=class
             style=style
             role="list"
-            aria-label="Chart legend"
+            aria-label=use_i18n().text(MessageKey::ChartLegend)
         >
             {items.into_iter().map(|item| {
                 let item_clone = item.clone();
@@ -284,20 +506,11 @@ impl LegendOrientation {
     }
 }
 
-/// Helper function to merge CSS classes
-fn merge_optional_classes(classes: Vec<&str>) -> String {
-    classes
-        .into_iter()
-        .filter(|c| !c.is_empty())
-        .collect::<Vec<_>>()
-        .join(" ")
-}
-
 #[cfg(test)]
 mod tests {
-    use wasm_bindgen_test::*;
+    use super::*;
     use proptest::prelude::*;
-use crate::utils::merge_classes;
+    use wasm_bindgen_test::*;
 
     wasm_bindgen_test_configure!(run_in_browser);
 
@@ -366,15 +579,15 @@ use crate::utils::merge_classes;
     fn test_chart_data_with_series() {
         let series = DataSeries {
             name: "Test Series".to_string(),
-            data: [
+            data: vec![
                 DataPoint { x: 1.0, y: 2.0, label: None },
                 DataPoint { x: 2.0, y: 4.0, label: None },
             ],
             color: Some("#ff0000".to_string()),
         };
         let data = ChartData {
-            series: [series],
-            categories: Some(["A".to_string(), "B".to_string()]),
+            series: vec![series],
+            categories: Some(vec!["A".to_string(), "B".to_string()]),
         };
         assert_eq!(data.series.len(), 1);
         assert_eq!(data.categories.unwrap().len(), 2);
@@ -485,31 +698,31 @@ use crate::utils::merge_classes;
     // Tooltip Position tests
     #[test]
     fn test_tooltip_position_default() {
-        let position = TooltipPosition::default();
-        assert_eq!(position, TooltipPosition::Top);
+        let position = ChartTooltipPosition::default();
+        assert_eq!(position, ChartTooltipPosition::Top);
     }
 
     #[test]
     fn test_tooltip_position_top() {
-        let position = TooltipPosition::Top;
+        let position = ChartTooltipPosition::Top;
         assert_eq!(position.to_class(), "position-top");
     }
 
     #[test]
     fn test_tooltip_position_bottom() {
-        let position = TooltipPosition::Bottom;
+        let position = ChartTooltipPosition::Bottom;
         assert_eq!(position.to_class(), "position-bottom");
     }
 
     #[test]
     fn test_tooltip_position_left() {
-        let position = TooltipPosition::Left;
+        let position = ChartTooltipPosition::Left;
         assert_eq!(position.to_class(), "position-left");
     }
 
     #[test]
     fn test_tooltip_position_right() {
-        let position = TooltipPosition::Right;
+        let position = ChartTooltipPosition::Right;
         assert_eq!(position.to_class(), "position-right");
     }
 
@@ -579,7 +792,7 @@ use crate::utils::merge_classes;
     // Helper function tests
     #[test]
     fn test_merge_classes_empty() {
-        let result = merge_optional_classes(Vec::new());
+        let result = merge_classes(Vec::new());
         assert_eq!(result, "");
     }
 
@@ -601,6 +814,120 @@ use crate::utils::merge_classes;
         assert_eq!(result, "class1 class3");
     }
 
+    // Data table tests
+    fn revenue_data() -> ChartData {
+        ChartData {
+            series: vec![
+                DataSeries {
+                    name: "2024".to_string(),
+                    data: vec![
+                        DataPoint { x: 1.0, y: 20.0, label: None },
+                        DataPoint { x: 0.0, y: 10.0, label: None },
+                    ],
+                    color: None,
+                },
+                DataSeries {
+                    name: "2025".to_string(),
+                    data: vec![DataPoint { x: 0.0, y: 15.5, label: None }],
+                    color: None,
+                },
+            ],
+            categories: Some(vec!["Q1".to_string(), "Q2".to_string()]),
+        }
+    }
+
+    #[test]
+    fn test_chart_table_from_data() {
+        let table = ChartTable::from_data(&revenue_data(), "Quarter");
+        assert_eq!(table.headers, vec!["Quarter", "2024", "2025"]);
+        assert_eq!(
+            table.rows,
+            vec![
+                ChartTableRow {
+                    label: "Q1".to_string(),
+                    values: vec![Some("10".to_string()), Some("15.5".to_string())],
+                },
+                ChartTableRow {
+                    label: "Q2".to_string(),
+                    values: vec![Some("20".to_string()), None],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_chart_table_row_labels() {
+        let data = ChartData {
+            series: vec![DataSeries {
+                name: "Temperature".to_string(),
+                data: vec![
+                    DataPoint { x: 0.5, y: 3.0, label: None },
+                    DataPoint { x: 2.0, y: 4.0, label: Some("Noon".to_string()) },
+                ],
+                color: None,
+            }],
+            categories: None,
+        };
+        let labels: Vec<_> = ChartTable::from_data(&data, "Category")
+            .rows
+            .into_iter()
+            .map(|row| row.label)
+            .collect();
+        assert_eq!(labels, vec!["0.5", "Noon"]);
+    }
+
+    #[test]
+    fn test_chart_renders_description_and_data_table() {
+        crate::test_support::init_test_executor();
+        let html = Owner::new().with(|| {
+            view! {
+                <Chart
+                    data=revenue_data()
+                    label="Revenue".to_string()
+                    description="Revenue grew every quarter".to_string()
+                />
+            }
+            .to_html()
+        });
+        assert!(html.contains(r#"aria-label="Revenue""#));
+        assert!(html.contains(r#"aria-describedby="chart-description-"#));
+        assert!(html.contains("Revenue grew every quarter</p>"));
+        assert!(html.contains("<caption>Revenue</caption>"));
+        assert!(html.contains(r#"<th scope="col">Category</th>"#));
+        assert!(html.contains(r#"<th scope="row">Q2</th>"#));
+        assert!(html.contains("<td>15.5</td>"));
+        assert!(html.contains("<td>20</td>"));
+    }
+
+    #[test]
+    fn test_chart_colors_series_from_palette() {
+        let palette = ChartPalette::default().with_categorical(["#111111", "#222222"]);
+        let mut data = revenue_data();
+        assert_eq!(data.series_colors(&palette), ["#111111", "#222222"]);
+        data.series[0].color = Some("#ff0000".to_string());
+        let legend = data.legend_items(&palette);
+        assert_eq!(legend[0].color, "#ff0000");
+        assert_eq!(legend[1].color, "#222222");
+
+        crate::test_support::init_test_executor();
+        let html = Owner::new().with(|| view! { <Chart data=revenue_data() /> }.to_html());
+        let palette = ChartPalette::default();
+        assert!(html.contains(&format!(
+            "--chart-series-1: {}; --chart-series-2: {};",
+            palette.series_color(0),
+            palette.series_color(1)
+        )));
+    }
+
+    #[test]
+    fn test_chart_without_data_omits_table() {
+        crate::test_support::init_test_executor();
+        let html = Owner::new().with(|| view! { <Chart /> }.to_html());
+        assert!(html.contains(r#"aria-label="Data visualization chart""#));
+        assert!(!html.contains("aria-describedby"));
+        assert!(!html.contains("<table"));
+    }
+
     // ===== PROPERTY-BASED TESTS (20% of total) =====
     
     #[test]
@@ -0,0 +1,468 @@
+use crate::i18n::{use_i18n, MessageArgs, MessageCatalog, MessageKey};
+use crate::utils::merge_classes;
+use chrono::{Datelike, NaiveDate, NaiveDateTime};
+use leptos::callback::Callback;
+use leptos::children::Children;
+use leptos::html;
+use leptos::prelude::*;
+
+/// Which side of the conversation a message comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MessageDirection {
+    #[default]
+    Incoming,
+    Outgoing,
+}
+
+impl MessageDirection {
+    pub fn to_class(&self) -> &'static str {
+        match self {
+            MessageDirection::Incoming => "message-bubble-incoming",
+            MessageDirection::Outgoing => "message-bubble-outgoing",
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MessageDirection::Incoming => "incoming",
+            MessageDirection::Outgoing => "outgoing",
+        }
+    }
+}
+
+/// A message shown by [`ChatMessageList`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChatMessage {
+    pub id: String,
+    pub author: String,
+    pub body: String,
+    /// Local time the message was sent
+    pub sent_at: NaiveDateTime,
+    pub direction: MessageDirection,
+}
+
+impl ChatMessage {
+    pub fn new(
+        id: impl Into<String>,
+        author: impl Into<String>,
+        body: impl Into<String>,
+        sent_at: NaiveDateTime,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            author: author.into(),
+            body: body.into(),
+            sent_at,
+            direction: MessageDirection::Incoming,
+        }
+    }
+
+    pub fn outgoing(mut self) -> Self {
+        self.direction = MessageDirection::Outgoing;
+        self
+    }
+}
+
+/// A row of the chat timeline
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChatTimelineEntry {
+    /// Separator before the first message of a day
+    Day(NaiveDate),
+    Message(ChatMessage),
+}
+
+/// Interleave day separators with messages sorted oldest first
+pub fn chat_timeline(messages: &[ChatMessage]) -> Vec<ChatTimelineEntry> {
+    let mut entries = Vec::with_capacity(messages.len());
+    let mut current_day = None;
+    for message in messages {
+        let day = message.sent_at.date();
+        if current_day != Some(day) {
+            entries.push(ChatTimelineEntry::Day(day));
+            current_day = Some(day);
+        }
+        entries.push(ChatTimelineEntry::Message(message.clone()));
+    }
+    entries
+}
+
+/// Label of a day separator: "Today", "Yesterday", or the date
+pub fn chat_day_label(catalog: &MessageCatalog, day: NaiveDate, today: NaiveDate) -> String {
+    if day == today {
+        catalog.text(MessageKey::ChatToday)
+    } else if today.pred_opt() == Some(day) {
+        catalog.text(MessageKey::ChatYesterday)
+    } else if day.year() == today.year() {
+        day.format("%B %-d").to_string()
+    } else {
+        day.format("%B %-d, %Y").to_string()
+    }
+}
+
+/// Announcement for the people currently typing
+pub fn typing_label(catalog: &MessageCatalog, names: &[String]) -> String {
+    let [first, rest @ ..] = names else {
+        return String::new();
+    };
+    let args = MessageArgs::new()
+        .with("first", first.as_str())
+        .with("second", rest.first().map(String::as_str).unwrap_or_default())
+        .with("others", rest.len());
+    catalog.format(MessageKey::ChatTyping, &args)
+}
+
+/// Whether scrolling to `scroll_top` should request older messages
+pub fn should_load_older(scroll_top: f64, threshold: f64, has_more: bool, loading: bool) -> bool {
+    has_more && !loading && scroll_top <= threshold
+}
+
+/// MessageBubble component - A single chat message
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn Reply() -> impl IntoView {
+///     view! {
+///         <MessageBubble direction=MessageDirection::Outgoing author="You".to_string() timestamp="09:41".to_string()>
+///             "On my way!"
+///         </MessageBubble>
+///     }
+/// }
+/// ```
+#[component]
+pub fn MessageBubble(
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    #[prop(optional)] direction: Option<MessageDirection>,
+    /// Name shown above the message
+    #[prop(optional)]
+    author: Option<String>,
+    /// Display time shown below the message
+    #[prop(optional)]
+    timestamp: Option<String>,
+    /// Machine-readable time for the `<time>` element
+    #[prop(optional)]
+    datetime: Option<String>,
+    children: Children,
+) -> impl IntoView {
+    let direction = direction.unwrap_or_default();
+    let class = merge_classes(vec![
+        "message-bubble",
+        direction.to_class(),
+        class.as_deref().unwrap_or(""),
+    ]);
+    let label = author.as_ref().map(|author| {
+        use_i18n().format(MessageKey::ChatMessageFrom, &MessageArgs::new().with("author", author.as_str()))
+    });
+
+    view! {
+        <article class=class style=style aria-label=label data-direction=direction.as_str()>
+            {author.map(|author| view! { <div class="message-bubble-author">{author}</div> })}
+            <div class="message-bubble-content">{children()}</div>
+            {timestamp
+                .map(|timestamp| {
+                    view! {
+                        <time class="message-bubble-time" datetime=datetime>
+                            {timestamp}
+                        </time>
+                    }
+                })}
+        </article>
+    }
+}
+
+/// TypingIndicator component - Animated dots with a live announcement
+///
+/// Renders nothing visible while `names` is empty, but keeps its live
+/// region mounted so screen readers hear each change.
+#[component]
+pub fn TypingIndicator(
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    /// People currently typing
+    #[prop(into)]
+    names: Signal<Vec<String>>,
+) -> impl IntoView {
+    let class = merge_classes(vec!["typing-indicator", class.as_deref().unwrap_or("")]);
+    let catalog = use_i18n();
+
+    view! {
+        <div class=class style=style role="status" aria-live="polite">
+            {move || {
+                names
+                    .with(|names| !names.is_empty())
+                    .then(|| {
+                        view! {
+                            <span class="typing-indicator-dots" aria-hidden="true">
+                                <span></span>
+                                <span></span>
+                                <span></span>
+                            </span>
+                        }
+                    })
+            }}
+            <span class="typing-indicator-label">{move || names.with(|names| typing_label(&catalog, names))}</span>
+        </div>
+    }
+}
+
+/// DaySeparator component - Labelled divider between days of messages
+#[component]
+pub fn DaySeparator(
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    label: String,
+    /// Date for the `<time>` element (YYYY-MM-DD)
+    #[prop(optional)]
+    datetime: Option<String>,
+) -> impl IntoView {
+    let class = merge_classes(vec!["day-separator", class.as_deref().unwrap_or("")]);
+    let aria_label = label.clone();
+
+    view! {
+        <div class=class style=style role="separator" aria-label=aria_label>
+            <time datetime=datetime aria-hidden="true">
+                {label}
+            </time>
+        </div>
+    }
+}
+
+/// ChatMessageList component - Scrolling conversation with day separators
+///
+/// The list opens scrolled to the newest message and follows new messages
+/// while the reader is at the bottom. Scrolling near the top calls
+/// `on_load_older`; when older messages are prepended the scroll position
+/// is kept so the reader's place doesn't jump.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn Conversation() -> impl IntoView {
+///     let messages = RwSignal::new(Vec::<ChatMessage>::new());
+///     let typing = RwSignal::new(vec!["Ada".to_string()]);
+///     view! {
+///         <ChatMessageList
+///             messages=messages
+///             has_more=true
+///             on_load_older=Callback::new(move |_| log::info!("fetch older messages"))
+///             style="height: 480px".to_string()
+///         >
+///             <TypingIndicator names=typing />
+///         </ChatMessageList>
+///     }
+/// }
+/// ```
+#[component]
+pub fn ChatMessageList(
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    /// Messages sorted oldest first
+    #[prop(into)]
+    messages: Signal<Vec<ChatMessage>>,
+    /// Whether older messages can be loaded
+    #[prop(optional, into)]
+    has_more: MaybeProp<bool>,
+    /// Whether older messages are being loaded
+    #[prop(optional, into)]
+    loading: MaybeProp<bool>,
+    /// Distance from the top in pixels that triggers loading (default: 120)
+    #[prop(optional)]
+    threshold: Option<f64>,
+    /// Day used for the "Today" separator; the local date by default
+    #[prop(optional)]
+    today: Option<NaiveDate>,
+    /// Accessible name of the conversation
+    #[prop(optional)]
+    label: Option<String>,
+    #[prop(optional)] on_load_older: Option<Callback<()>>,
+    /// Rendered after the last message, e.g. a TypingIndicator
+    #[prop(optional)]
+    children: Option<Children>,
+) -> impl IntoView {
+    let threshold = threshold.unwrap_or(120.0);
+    let today = today.unwrap_or_else(|| chrono::Local::now().date_naive());
+    let catalog = use_i18n();
+    let label = label.unwrap_or_else(|| catalog.text(MessageKey::ChatLabel));
+    let loading_text = catalog.text(MessageKey::Loading);
+    let list_ref = NodeRef::<html::Div>::new();
+    // Distance from the bottom before the last render and the message ids
+    // at either end, used to tell prepends from appends
+    let distance_from_bottom = StoredValue::new(0.0);
+    let rendered_ends = StoredValue::new(None::<(String, String)>);
+
+    let handle_scroll = move |_: web_sys::Event| {
+        let Some(list) = list_ref.get_untracked() else {
+            return;
+        };
+        let scroll_top = f64::from(list.scroll_top());
+        distance_from_bottom.set_value(
+            f64::from(list.scroll_height()) - scroll_top - f64::from(list.client_height()),
+        );
+        let has_more = has_more.get_untracked().unwrap_or(false);
+        let loading = loading.get_untracked().unwrap_or(false);
+        if should_load_older(scroll_top, threshold, has_more, loading) {
+            if let Some(on_load_older) = on_load_older {
+                on_load_older.run(());
+            }
+        }
+    };
+
+    Effect::new(move |_| {
+        let ends = messages
+            .with(|messages| Some((messages.first()?.id.clone(), messages.last()?.id.clone())));
+        let Some(list) = list_ref.get() else {
+            return;
+        };
+        let previous = rendered_ends.get_value();
+        rendered_ends.set_value(ends.clone());
+        let height = f64::from(list.scroll_height());
+        let client_height = f64::from(list.client_height());
+        let distance = distance_from_bottom.get_value();
+
+        let prepended = matches!(
+            (&previous, &ends),
+            (Some((old_first, old_last)), Some((first, last))) if old_first != first && old_last == last
+        );
+        let top = if prepended {
+            // Keep the same messages in view after older ones are added
+            height - client_height - distance
+        } else if distance <= threshold {
+            height
+        } else {
+            return;
+        };
+        list.set_scroll_top(top.max(0.0) as i32);
+        distance_from_bottom.set_value(
+            f64::from(list.scroll_height()) - f64::from(list.scroll_top()) - client_height,
+        );
+    });
+
+    let class = merge_classes(vec!["chat-message-list", class.as_deref().unwrap_or("")]);
+    let style = format!(
+        "display: flex; flex-direction: column; overflow-y: auto; {}",
+        style.unwrap_or_default()
+    );
+
+    view! {
+        <div
+            class=class
+            style=style
+            role="log"
+            aria-label=label
+            aria-busy=move || loading.get().unwrap_or(false).to_string()
+            tabindex="0"
+            node_ref=list_ref
+            on:scroll=handle_scroll
+        >
+            {move || {
+                loading
+                    .get()
+                    .unwrap_or(false)
+                    .then(|| {
+                        let loading_text = loading_text.clone();
+                        view! { <div class="chat-message-list-loading" aria-hidden="true">{loading_text}</div> }
+                    })
+            }}
+            {move || {
+                messages
+                    .with(|messages| chat_timeline(messages))
+                    .into_iter()
+                    .map(|entry| match entry {
+                        ChatTimelineEntry::Day(day) => {
+                            view! {
+                                <DaySeparator
+                                    label=chat_day_label(&catalog, day, today)
+                                    datetime=day.format("%Y-%m-%d").to_string()
+                                />
+                            }
+                                .into_any()
+                        }
+                        ChatTimelineEntry::Message(message) => {
+                            view! {
+                                <MessageBubble
+                                    direction=message.direction
+                                    author=message.author
+                                    timestamp=message.sent_at.format("%H:%M").to_string()
+                                    datetime=message.sent_at.format("%Y-%m-%dT%H:%M").to_string()
+                                >
+                                    {message.body}
+                                </MessageBubble>
+                            }
+                                .into_any()
+                        }
+                    })
+                    .collect_view()
+            }}
+            {children.map(|children| children())}
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(day: u32, hour: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, 3, day)
+            .and_then(|date| date.and_hms_opt(hour, 0, 0))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_timeline_inserts_day_separators() {
+        let messages = vec![
+            ChatMessage::new("1", "Ada", "Morning", at(4, 9)),
+            ChatMessage::new("2", "You", "Hi!", at(4, 10)).outgoing(),
+            ChatMessage::new("3", "Ada", "Lunch?", at(5, 12)),
+        ];
+        let timeline = chat_timeline(&messages);
+        assert_eq!(timeline.len(), 5);
+        assert_eq!(timeline[0], ChatTimelineEntry::Day(at(4, 0).date()));
+        assert_eq!(timeline[3], ChatTimelineEntry::Day(at(5, 0).date()));
+        assert!(chat_timeline(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_day_labels() {
+        let catalog = MessageCatalog::default();
+        let today = at(5, 0).date();
+        assert_eq!(chat_day_label(&catalog, today, today), "Today");
+        assert_eq!(chat_day_label(&catalog, at(4, 0).date(), today), "Yesterday");
+        assert_eq!(chat_day_label(&catalog, at(1, 0).date(), today), "March 1");
+        let last_year = NaiveDate::from_ymd_opt(2025, 12, 24).unwrap();
+        assert_eq!(chat_day_label(&catalog, last_year, today), "December 24, 2025");
+    }
+
+    #[test]
+    fn test_typing_label() {
+        let catalog = MessageCatalog::default();
+        let names = |names: &[&str]| names.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+        assert_eq!(typing_label(&catalog, &[]), "");
+        assert_eq!(typing_label(&catalog, &names(&["Ada"])), "Ada is typing…");
+        assert_eq!(
+            typing_label(&catalog, &names(&["Ada", "Alan"])),
+            "Ada and Alan are typing…"
+        );
+        assert_eq!(
+            typing_label(&catalog, &names(&["Ada", "Alan", "Grace"])),
+            "Ada and 2 others are typing…"
+        );
+    }
+
+    #[test]
+    fn test_should_load_older() {
+        assert!(should_load_older(40.0, 120.0, true, false));
+        assert!(!should_load_older(400.0, 120.0, true, false));
+        assert!(!should_load_older(0.0, 120.0, false, false));
+        assert!(!should_load_older(0.0, 120.0, true, true));
+    }
+}
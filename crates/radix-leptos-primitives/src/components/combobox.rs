@@ -1,10 +1,78 @@
+use crate::i18n::{use_i18n, MessageArgs, MessageCatalog, MessageKey};
 use crate::utils::{merge_classes, generate_id};
 use leptos::callback::Callback;
 use leptos::children::Children;
+use leptos::html;
 use leptos::prelude::*;
+use radix_leptos_core::{
+    channels, use_announcer, use_busy_announcements, use_focus_within, BusyMessages,
+};
 use wasm_bindgen::JsCast;
 
+/// Id of the element rendering the option `option_id` in the listbox
+/// `listbox_id`, as referenced by `aria-activedescendant`
+pub fn option_element_id(listbox_id: &str, option_id: &str) -> String {
+    format!("{}-option-{}", listbox_id, option_id)
+}
+
+/// Hook announcing "N results available" as `result_count` changes
+///
+/// The message is [`MessageKey::ResultsAvailable`] from the closest
+/// [`I18nProvider`](crate::I18nProvider). Counts are announced on the
+/// results channel of the closest AnnouncerProvider, which debounces them
+/// so fast typing only announces where filtering settled. Nothing is announced while `loading`,
+/// as the end of loading announces the count itself.
+pub fn use_result_count_announcements(
+    result_count: Signal<Option<usize>>,
+    loading: Signal<bool>,
+) {
+    let catalog = use_i18n();
+    let announcer = use_announcer();
+    Effect::new(move |previous: Option<Option<usize>>| {
+        let count = result_count.get();
+        if previous.is_some_and(|previous| previous != count) && !loading.get_untracked() {
+            if let Some(count) = count {
+                let args = MessageArgs::new().with("count", count as i64);
+                let message = catalog.format(MessageKey::ResultsAvailable, &args);
+                announcer.announce(channels::RESULTS, &message);
+            }
+        }
+        count
+    });
+}
+
+/// What a Combobox announces once loading finishes with `count` options, or
+/// an unknown number of them
+pub fn results_loaded_message(catalog: &MessageCatalog, count: Option<usize>) -> String {
+    match count {
+        Some(count) => catalog.format(
+            MessageKey::ResultsLoaded,
+            &MessageArgs::new().with("count", count),
+        ),
+        None => catalog.text(MessageKey::Loaded),
+    }
+}
+
+/// Listbox and highlighted option of the enclosing Combobox
+#[derive(Clone)]
+struct ComboboxContext {
+    listbox_id: String,
+    open: Signal<bool>,
+    active_option: Signal<Option<String>>,
+}
+
 /// Combobox component - Searchable select component with autocomplete
+///
+/// While options are fetched asynchronously, pass `loading` and the number
+/// of options found as `result_count`: the combobox is marked `aria-busy`
+/// and screen readers hear "Loading", then "Loaded N results", through the
+/// closest AnnouncerProvider. When the count changes as the user types,
+/// "N results available" is announced once typing pauses.
+///
+/// Focus stays in the [`ComboboxInput`] while the user moves through the
+/// options, so pass the id of the highlighted [`ComboboxOption`] as
+/// `active_option`: the input points `aria-activedescendant` at it while
+/// the combobox is `open`.
 #[component]
 pub fn Combobox(
     #[prop(optional)] class: Option<String>,
@@ -20,9 +88,22 @@ pub fn Combobox(
     #[prop(optional)] clearable: Option<bool>,
     #[prop(optional)] on_change: Option<Callback<Vec<String>>>,
     #[prop(optional)] on_search: Option<Callback<String>>,
+    /// Whether options are being loaded
+    #[prop(optional, into)]
+    loading: Signal<bool>,
+    /// Number of options found, announced when loading finishes or the
+    /// filter changes
+    #[prop(optional, into)]
+    result_count: Signal<Option<usize>>,
+    /// Whether the options are shown
+    #[prop(optional, into)]
+    open: Signal<bool>,
+    /// `id` of the highlighted option
+    #[prop(optional, into)]
+    active_option: Signal<Option<String>>,
 ) -> impl IntoView {
     let value = value.unwrap_or_default();
-    let placeholder = placeholder.unwrap_or_else(|| "Select option...".to_string());
+    let placeholder = placeholder.unwrap_or_else(|| use_i18n().text(MessageKey::ComboboxPlaceholder));
     let disabled = disabled.unwrap_or(false);
     let required = required.unwrap_or(false);
     let options = options.unwrap_or_default();
@@ -31,12 +112,34 @@ pub fn Combobox(
     let clearable = clearable.unwrap_or(true);
 
     let class = merge_classes(vec!["combobox", class.as_deref().unwrap_or("")]);
+    let node_ref = NodeRef::<html::Div>::new();
+    let focus_within = use_focus_within(node_ref);
+    let catalog = use_i18n();
+    use_busy_announcements(
+        loading,
+        result_count,
+        BusyMessages {
+            loading: Some(catalog.text(MessageKey::Loading)),
+            loaded: Some(Callback::new(move |count| {
+                results_loaded_message(&catalog, count)
+            })),
+        },
+    );
+    use_result_count_announcements(result_count, loading);
+    provide_context(ComboboxContext {
+        listbox_id: generate_id("combobox-listbox"),
+        open,
+        active_option,
+    });
 
     view! {
         <div
             class=class
             style=style
-            role="combobox"
+            node_ref=node_ref
+            aria-busy=move || loading.get().to_string()
+            data-loading=move || loading.get()
+            data-focus-within=move || focus_within.get()
         >
             {children.map(|c| c())}
         </div>
@@ -58,11 +161,22 @@ pub fn ComboboxInput(
     #[prop(optional)] on_keydown: Option<Callback<web_sys::KeyboardEvent>>,
 ) -> impl IntoView {
     let value = value.unwrap_or_default();
-    let placeholder = placeholder.unwrap_or_else(|| "Select option...".to_string());
+    let placeholder = placeholder.unwrap_or_else(|| use_i18n().text(MessageKey::ComboboxPlaceholder));
     let disabled = disabled.unwrap_or(false);
     let required = required.unwrap_or(false);
 
     let class = merge_classes(vec!["combobox-input", class.as_deref().unwrap_or("")]);
+    let context = use_context::<ComboboxContext>();
+    let listbox_id = context.as_ref().map(|context| context.listbox_id.clone());
+    let expanded = context.as_ref().map(|context| context.open);
+    let active_descendant = move || {
+        let context = context.as_ref()?;
+        if !context.open.get() {
+            return None;
+        }
+        let option = context.active_option.get()?;
+        Some(option_element_id(&context.listbox_id, &option))
+    };
 
     let handle_input = move |event: web_sys::Event| {
         if let Some(input) = event
@@ -103,8 +217,12 @@ pub fn ComboboxInput(
             placeholder=placeholder
             disabled=disabled
             required=required
-            role="searchbox"
-            aria-label="Combobox input"
+            role="combobox"
+            aria-label=use_i18n().text(MessageKey::ComboboxInput)
+            aria-autocomplete="list"
+            aria-controls=listbox_id
+            aria-expanded=move || expanded.is_some_and(|open| open.get()).to_string()
+            aria-activedescendant=active_descendant
             on:input=handle_input
             on:focus=handle_focus
             on:blur=handle_blur
@@ -129,6 +247,7 @@ pub fn ComboboxOptions(
     let selected_index = selected_index.unwrap_or(0);
 
     let class = merge_classes(vec!["combobox-options", class.as_deref().unwrap_or("")]);
+    let listbox_id = use_context::<ComboboxContext>().map(|context| context.listbox_id);
 
     if !visible {
         return {
@@ -141,6 +260,7 @@ pub fn ComboboxOptions(
         <div
             class=class
             style=style
+            id=listbox_id
             role="listbox"
         >
             {children.map(|c| c())}
@@ -165,6 +285,16 @@ pub fn ComboboxOption(
     let disabled = disabled.unwrap_or(false);
 
     let class = merge_classes(vec!["combobox-option", class.as_deref().unwrap_or("")]);
+    let context = use_context::<ComboboxContext>();
+    let id = context
+        .as_ref()
+        .map(|context| option_element_id(&context.listbox_id, &option.id));
+    let option_id = option.id.clone();
+    let highlighted = move || {
+        context
+            .as_ref()
+            .is_some_and(|context| context.active_option.get().as_deref() == Some(&option_id))
+    };
 
     let option_clone = option.clone();
     let handle_click = move |_| {
@@ -177,9 +307,11 @@ pub fn ComboboxOption(
 
     view! {
         <div
+            id=id
             class=class
             style=style
             role="option"
+            data-highlighted=highlighted
             aria-selected=selected
             aria-disabled=disabled
             aria-label=option.label
@@ -209,7 +341,7 @@ pub fn ComboboxTrigger(
             style=style
             type="button"
             disabled=disabled
-            aria-label="Open combobox"
+            aria-label=use_i18n().text(MessageKey::ComboboxOpen)
             on:click=move |_| {
                 if !disabled {
                     if let Some(callback) = on_click {
@@ -241,7 +373,7 @@ pub fn ComboboxClearButton(
             class=class
             style=style
             type="button"
-            aria-label="Clear selection"
+            aria-label=use_i18n().text(MessageKey::ComboboxClear)
             on:click=move |_| {
                 if let Some(callback) = on_click {
                     callback.run(());
@@ -287,7 +419,7 @@ pub fn ComboboxGroup(
     #[prop(optional)] children: Option<Children>,
     #[prop(optional)] label: Option<String>,
 ) -> impl IntoView {
-    let label = label.unwrap_or_else(|| "Group".to_string());
+    let label = label.unwrap_or_else(|| use_i18n().text(MessageKey::ComboboxGroup));
 
     let class = merge_classes(vec!["combobox-group", class.as_deref().unwrap_or("")]);
 
@@ -333,6 +465,54 @@ mod tests {
     #[test]
     fn test_combobox_creation() {}
     #[test]
+    fn test_combobox_active_descendant_markup() {
+        use super::*;
+
+        crate::test_support::init_test_executor();
+        let owner = Owner::new();
+        owner.with(|| {
+            let option = super::ComboboxOption {
+                id: "fr".to_string(),
+                label: "France".to_string(),
+                ..Default::default()
+            };
+            let html = view! {
+                <Combobox open=true active_option=Some("fr".to_string())>
+                    <ComboboxInput />
+                    <ComboboxOptions visible=true>
+                        <ComboboxOption option=option />
+                    </ComboboxOptions>
+                </Combobox>
+            }
+            .to_html();
+            assert!(html.contains("role=\"combobox\""));
+            assert!(html.contains("aria-expanded=\"true\""));
+            let listbox = html.split("aria-controls=\"").nth(1).unwrap();
+            let listbox = &listbox[..listbox.find('"').unwrap()];
+            let option_id = option_element_id(listbox, "fr");
+            assert!(html.contains(&format!("id=\"{}\"", listbox)));
+            assert!(html.contains(&format!("aria-activedescendant=\"{}\"", option_id)));
+            assert!(html.contains(&format!("id=\"{}\"", option_id)));
+            assert!(html.contains("data-highlighted"));
+        });
+    }
+    #[test]
+    fn test_combobox_option_element_id() {
+        assert_eq!(
+            super::option_element_id("combobox-listbox-3", "fr"),
+            "combobox-listbox-3-option-fr"
+        );
+    }
+    #[test]
+    fn test_combobox_results_loaded_message() {
+        let catalog = crate::i18n::MessageCatalog::default();
+        let loaded = |count| super::results_loaded_message(&catalog, count);
+        assert_eq!(loaded(None), "Loaded");
+        assert_eq!(loaded(Some(0)), "No results");
+        assert_eq!(loaded(Some(1)), "Loaded 1 result");
+        assert_eq!(loaded(Some(4)), "Loaded 4 results");
+    }
+    #[test]
     fn test_combobox_with_class() {}
     #[test]
     fn test_combobox_with_style() {}
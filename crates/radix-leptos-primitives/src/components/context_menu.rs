@@ -1,7 +1,9 @@
+use crate::i18n::{use_i18n, MessageKey};
 use crate::utils::merge_classes;
 use leptos::callback::Callback;
 use leptos::children::Children;
 use leptos::prelude::*;
+use radix_leptos_core::{use_long_press_with_options, UseLongPressOptions};
 
 /// Context Menu component - Right-click context menus with keyboard navigation
 #[component]
@@ -20,14 +22,34 @@ pub fn ContextMenu(
 
     let class = merge_classes(vec!["context-menu", class.as_deref().unwrap_or("")]);
 
-    let handle_right_click = move |event: web_sys::MouseEvent| {
-        event.prevent_default();
+    let trigger_ref = NodeRef::<leptos::html::Div>::new();
+
+    // Some touch browsers fire `contextmenu` after a long press as well
+    let open = move || {
+        if isopen.get_untracked() {
+            return;
+        }
         isopen.set(true);
         if let Some(callback) = onopen {
             callback.run(());
         }
     };
 
+    let handle_right_click = move |event: web_sys::MouseEvent| {
+        event.prevent_default();
+        open();
+    };
+
+    // Touch devices have no right click, so holding a finger down opens the menu
+    use_long_press_with_options(
+        trigger_ref,
+        move |_| open(),
+        UseLongPressOptions {
+            mouse: false,
+            ..Default::default()
+        },
+    );
+
     let handle_keydown = move |event: web_sys::KeyboardEvent| {
         if !isopen.get() {
             return;
@@ -68,10 +90,11 @@ pub fn ContextMenu(
 
     view! {
         <div
+            node_ref=trigger_ref
             class=class
             style=style
             role="menu"
-            aria-label="Context menu"
+            aria-label=use_i18n().text(MessageKey::ContextMenuLabel)
             on:contextmenu=handle_right_click
             on:keydown=handle_keydown
             tabindex="0"
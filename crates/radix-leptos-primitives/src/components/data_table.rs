@@ -1,5 +1,246 @@
+use crate::i18n::{use_i18n, MessageArgs, MessageCatalog, MessageKey};
+use crate::utils::{escape_html, merge_classes};
+use crate::StateSnapshot;
+use leptos::callback::Callback;
+use leptos::children::Children;
+use leptos::prelude::*;
+use radix_leptos_core::{
+    channels, use_announcer, use_busy_announcements, BusyMessages, LoadedMessage,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+/// Direction a DataTable column is sorted in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum SortDirection {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+impl SortDirection {
+    /// Value of `aria-sort` for the sorted column
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SortDirection::Ascending => "ascending",
+            SortDirection::Descending => "descending",
+        }
+    }
+
+    pub fn reversed(&self) -> Self {
+        match self {
+            SortDirection::Ascending => SortDirection::Descending,
+            SortDirection::Descending => SortDirection::Ascending,
+        }
+    }
+}
+
+/// The column a DataTable is sorted by, named as in its header
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TableSort {
+    pub column: String,
+    pub direction: SortDirection,
+}
+
+impl TableSort {
+    pub fn new(column: impl Into<String>, direction: SortDirection) -> Self {
+        Self {
+            column: column.into(),
+            direction,
+        }
+    }
+
+    /// Sort after activating the header of `column`: the same column flips
+    /// direction, another one starts ascending
+    pub fn toggled(current: Option<&TableSort>, column: &str) -> Self {
+        match current {
+            Some(current) if current.column == column => {
+                Self::new(column, current.direction.reversed())
+            }
+            _ => Self::new(column, SortDirection::Ascending),
+        }
+    }
+}
+
+/// What a DataTable announces when it is sorted
+pub fn sort_message(catalog: &MessageCatalog, sort: &TableSort) -> String {
+    let args = MessageArgs::new()
+        .with("column", sort.column.as_str())
+        .with("direction", sort.direction.as_str());
+    catalog.format(MessageKey::TableSort, &args)
+}
+
+/// What a DataTable announces when `count` rows are selected
+pub fn selection_message(catalog: &MessageCatalog, count: usize) -> String {
+    catalog.format(
+        MessageKey::TableSelectionCount,
+        &MessageArgs::new().with("count", count),
+    )
+}
+
+/// Serializable snapshot of a data table, see [`DataTableController`]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DataTableState {
+    pub sort: Option<TableSort>,
+    /// Keys of the selected rows
+    pub selected: BTreeSet<String>,
+    /// Zero-based page shown
+    pub page: usize,
+}
+
+/// Reactive state of a [`DataTable`]
+///
+/// Pass it to `DataTable` through the `controller` prop; the table then
+/// sorts through it, and `DataTableRow`s with a `row_key` show whether they
+/// are selected. The page is kept for the app's pagination. Implements
+/// [`StateSnapshot`] for undo and restore-on-reload.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DataTableController {
+    pub sort: RwSignal<Option<TableSort>>,
+    pub selected: RwSignal<BTreeSet<String>>,
+    pub page: RwSignal<usize>,
+}
+
+impl Default for DataTableController {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DataTableController {
+    pub fn new() -> Self {
+        Self {
+            sort: RwSignal::new(None),
+            selected: RwSignal::new(BTreeSet::new()),
+            page: RwSignal::new(0),
+        }
+    }
+
+    /// Whether the row with `key` is selected
+    pub fn is_selected(&self, key: &str) -> bool {
+        self.selected.with(|selected| selected.contains(key))
+    }
+
+    /// Select or deselect the row with `key`
+    pub fn set_selected(&self, key: &str, selected: bool) {
+        self.selected.update(|rows| {
+            if selected {
+                rows.insert(key.to_string());
+            } else {
+                rows.remove(key);
+            }
+        });
+    }
+
+    /// Clear the sort and selection and go back to the first page
+    pub fn reset(&self) {
+        self.set_state(DataTableState::default());
+    }
+}
+
+impl StateSnapshot for DataTableController {
+    type State = DataTableState;
+    const COMPONENT: &'static str = "DataTable";
+
+    fn get_state(&self) -> DataTableState {
+        DataTableState {
+            sort: self.sort.get_untracked(),
+            selected: self.selected.get_untracked(),
+            page: self.page.get_untracked(),
+        }
+    }
+
+    fn set_state(&self, state: DataTableState) {
+        self.sort.set(state.sort);
+        self.selected.set(state.selected);
+        self.page.set(state.page);
+    }
+}
+
+/// Sort state shared with the header cells
+#[derive(Clone, Copy)]
+struct DataTableContext {
+    sort: Signal<Option<TableSort>>,
+    on_sort_change: Option<Callback<TableSort>>,
+}
+
+/// Announce changes of `value` after the first render on the table channel
+/// of the closest AnnouncerProvider
+fn announce_changes<T>(value: Signal<T>, message: impl Fn(&T) -> Option<String> + 'static)
+where
+    T: Clone + PartialEq + Send + Sync + 'static,
+{
+    let announcer = use_announcer();
+    Effect::new(move |previous: Option<T>| {
+        let value = value.get();
+        if previous.is_some_and(|previous| previous != value) {
+            if let Some(message) = message(&value) {
+                announcer.announce(channels::TABLE, &message);
+            }
+        }
+        value
+    });
+}
+
+/// What a DataTable announces once a refresh finishes with `count` rows, or
+/// an unknown number of them
+pub fn table_loaded_message(catalog: &MessageCatalog, count: Option<usize>) -> String {
+    match count {
+        Some(count) => catalog.format(
+            MessageKey::TableLoaded,
+            &MessageArgs::new().with("count", count),
+        ),
+        None => catalog.text(MessageKey::TableUpdated),
+    }
+}
 
 /// DataTable component - Advanced table with sorting, filtering, and pagination
+///
+/// While `loading` the table is marked `aria-busy` and screen readers hear
+/// "Loading", then "Loaded N rows" from `row_count` once the refresh
+/// finishes, through the closest AnnouncerProvider.
+///
+/// Header cells with a `column` show the current `sort` in `aria-sort` and,
+/// given `on_sort_change` or a `controller`, become sort buttons. With a
+/// [`DataTableController`] the sort and selected count come from it. Changes of the sort and of
+/// `selected_count` are announced, e.g. "Sorted by Name ascending" or
+/// "3 rows selected", on the table live region channel, which debounces
+/// quick successive changes to the last one.
+///
+/// For large server-rendered tables, `static_rows` renders body rows from
+/// plain cell text as one HTML string instead of a reactive row per entry.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn Users() -> impl IntoView {
+///     let (loading, _set_loading) = signal(false);
+///     let (users, _set_users) = signal(vec!["Ada".to_string(), "Grace".to_string()]);
+///
+///     view! {
+///         <DataTable
+///             caption="Users".to_string()
+///             loading=loading
+///             row_count=Signal::derive(move || Some(users.get().len()))
+///         >
+///             <DataTableHeader>
+///                 <DataTableRow>
+///                     <DataTableHeaderCell>"Name"</DataTableHeaderCell>
+///                 </DataTableRow>
+///             </DataTableHeader>
+///             <DataTableBody>
+///                 {move || users.get().into_iter().map(|name| view! {
+///                     <DataTableRow><DataTableCell>{name}</DataTableCell></DataTableRow>
+///                 }).collect::<Vec<_>>()}
+///             </DataTableBody>
+///         </DataTable>
+///     }
+/// }
+/// ```
 #[component]
 pub fn DataTable(
     #[prop(optional)] class: Option<String>,
@@ -8,54 +249,407 @@ pub fn DataTable(
     #[prop(optional)] sortable: Option<bool>,
     #[prop(optional)] filterable: Option<bool>,
     #[prop(optional)] selectable: Option<bool>,
+    /// Visible caption naming the table
+    #[prop(optional)]
+    caption: Option<String>,
+    /// Whether the rows are being loaded or refreshed
+    #[prop(optional, into)]
+    loading: Signal<bool>,
+    /// Number of rows once loaded, announced when a refresh finishes
+    #[prop(optional, into)]
+    row_count: Signal<Option<usize>>,
+    /// Announced when a refresh starts (default: "Loading")
+    #[prop(optional)]
+    loading_message: Option<String>,
+    /// Builds the announcement when a refresh finishes from the row count
+    /// (default: [`table_loaded_message`])
+    #[prop(optional)]
+    loaded_message: Option<LoadedMessage>,
+    /// Column the rows are sorted by
+    #[prop(optional, into)]
+    sort: Signal<Option<TableSort>>,
+    /// Called with the new sort when a column header is activated
+    #[prop(optional)]
+    on_sort_change: Option<Callback<TableSort>>,
+    /// Number of selected rows
+    #[prop(optional, into)]
+    selected_count: Signal<usize>,
+    /// Reactive sort, selection and page shared with the rows
+    #[prop(optional)]
+    controller: Option<DataTableController>,
+    /// Body rows, as cell text, rendered as static markup after `children`
+    ///
+    /// The rows are built as one HTML string, so they have no handlers and
+    /// don't report controller selection.
+    #[prop(optional)]
+    static_rows: Vec<Vec<String>>,
 ) -> impl IntoView {
     let sortable = sortable.unwrap_or(true);
     let filterable = filterable.unwrap_or(true);
     let selectable = selectable.unwrap_or(false);
+    let static_html = (!static_rows.is_empty()).then(|| render_static_table_rows(&static_rows));
+
+    let catalog = use_i18n();
+    use_busy_announcements(
+        loading,
+        row_count,
+        BusyMessages {
+            loading: Some(loading_message.unwrap_or_else(|| catalog.text(MessageKey::Loading))),
+            loaded: Some(loaded_message.unwrap_or_else(|| {
+                let catalog = catalog.clone();
+                Callback::new(move |count| table_loaded_message(&catalog, count))
+            })),
+        },
+    );
+
+    let (sort, selected_count, on_sort_change) = match controller {
+        Some(controller) => {
+            provide_context(controller);
+            let on_sort_change = Callback::new(move |sort: TableSort| {
+                controller.sort.set(Some(sort.clone()));
+                if let Some(on_sort_change) = on_sort_change {
+                    on_sort_change.run(sort);
+                }
+            });
+            (
+                controller.sort.into(),
+                Signal::derive(move || controller.selected.with(BTreeSet::len)),
+                Some(on_sort_change),
+            )
+        }
+        None => (sort, selected_count, on_sort_change),
+    };
+
+    provide_context(DataTableContext {
+        sort,
+        on_sort_change: on_sort_change.filter(|_| sortable),
+    });
+    announce_changes(sort, {
+        let catalog = catalog.clone();
+        move |sort| sort.as_ref().map(|sort| sort_message(&catalog, sort))
+    });
+    announce_changes(selected_count, move |count| {
+        Some(selection_message(&catalog, *count))
+    });
+
+    let class = merge_classes(vec!["data-table", class.as_deref().unwrap_or("")]);
+
+    view! {
+        <table
+            class=class
+            style=style.unwrap_or_default()
+            aria-busy=move || loading.get().to_string()
+            data-state=move || if loading.get() { "loading" } else { "idle" }
+            data-sortable=sortable
+            data-filterable=filterable
+            data-selectable=selectable
+        >
+            {caption.map(|caption| view! { <caption class="data-table-caption">{caption}</caption> })}
+            {children.map(|c| c())}
+            {static_html.map(|html| view! {
+                <tbody class="data-table-body data-table-static-rows" inner_html=html></tbody>
+            })}
+        </table>
+    }
+}
+
+/// Render table body rows as a single HTML string
+///
+/// Used by `DataTable` for `static_rows`; the output mirrors the markup of
+/// `DataTableRow` and `DataTableCell` so the same styles apply.
+pub fn render_static_table_rows<'a, I>(rows: I) -> String
+where
+    I: IntoIterator<Item = &'a Vec<String>>,
+{
+    let mut html = String::new();
+    for row in rows {
+        html.push_str("<tr class=\"data-table-row\">");
+        for cell in row {
+            html.push_str("<td class=\"data-table-cell\">");
+            html.push_str(&escape_html(cell));
+            html.push_str("</td>");
+        }
+        html.push_str("</tr>");
+    }
+    html
+}
 
+/// DataTable Header component
+#[component]
+pub fn DataTableHeader(
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] children: Option<Children>,
+) -> impl IntoView {
+    let class = merge_classes(vec!["data-table-header", class.as_deref().unwrap_or("")]);
+
+    view! { <thead class=class>{children.map(|c| c())}</thead> }
+}
+
+/// DataTable Body component
+#[component]
+pub fn DataTableBody(
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] children: Option<Children>,
+) -> impl IntoView {
+    let class = merge_classes(vec!["data-table-body", class.as_deref().unwrap_or("")]);
+
+    view! { <tbody class=class>{children.map(|c| c())}</tbody> }
+}
+
+/// DataTable Row component
+///
+/// With a `row_key` in a table with a [`DataTableController`] the row
+/// reports whether it is selected in `aria-selected`.
+#[component]
+pub fn DataTableRow(
+    #[prop(optional)] class: Option<String>,
+    /// Key of the row in the controller's selection
+    #[prop(optional, into)]
+    row_key: Option<String>,
+    #[prop(optional)] children: Option<Children>,
+) -> impl IntoView {
+    let class = merge_classes(vec!["data-table-row", class.as_deref().unwrap_or("")]);
+    let controller = use_context::<DataTableController>();
+    let aria_selected = move || {
+        let key = row_key.as_deref()?;
+        Some(controller?.is_selected(key).to_string())
+    };
+
+    view! { <tr class=class aria-selected=aria_selected>{children.map(|c| c())}</tr> }
+}
+
+/// DataTable Header Cell component
+///
+/// With a `column` the cell reports whether the table is sorted by it in
+/// `aria-sort`, and its content becomes a button that sorts by the column
+/// when the table has `on_sort_change`.
+#[component]
+pub fn DataTableHeaderCell(
+    #[prop(optional)] class: Option<String>,
+    /// Column name, as used in [`TableSort`] and the sort announcement
+    #[prop(optional, into)]
+    column: Option<String>,
+    #[prop(optional)] children: Option<Children>,
+) -> impl IntoView {
     let class = merge_classes(vec![
-        "data-table",
-        </div>
+        "data-table-header-cell",
+        class.as_deref().unwrap_or(""),
+    ]);
+    let context = use_context::<DataTableContext>();
+    let children = children.map(|c| c());
+
+    let (Some(column), Some(context)) = (column, context) else {
+        return view! { <th class=class scope="col">{children}</th> }.into_any();
+    };
+
+    let sorted_column = column.clone();
+    let aria_sort = move || {
+        context
+            .sort
+            .get()
+            .filter(|sort| sort.column == sorted_column)
+            .map(|sort| sort.direction.as_str())
+    };
+
+    match context.on_sort_change {
+        Some(on_sort_change) => {
+            let sort = move |_| {
+                let current = context.sort.get_untracked();
+                on_sort_change.run(TableSort::toggled(current.as_ref(), &column));
+            };
+            view! {
+                <th class=class scope="col" aria-sort=aria_sort>
+                    <button type="button" class="data-table-sort-button" on:click=sort>
+                        {children}
+                    </button>
+                </th>
+            }
+            .into_any()
+        }
+        None => {
+            view! { <th class=class scope="col" aria-sort=aria_sort>{children}</th> }.into_any()
+        }
     }
 }
 
-/// Helper function to merge CSS classes
-fn merge_optional_classes(classes: Vec<&str>) -> String {
-    classes
-        .into_iter()
-        .filter(|c| !c.is_empty())
-        .collect::<Vec<_>>()
-        .join(" ")
+/// DataTable Cell component
+#[component]
+pub fn DataTableCell(
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] children: Option<Children>,
+) -> impl IntoView {
+    let class = merge_classes(vec!["data-table-cell", class.as_deref().unwrap_or("")]);
+
+    view! { <td class=class>{children.map(|c| c())}</td> }
 }
 
 #[cfg(test)]
 mod tests {
-    use wasm_bindgen_test::*;
+    use super::*;
+    use crate::ComponentSnapshot;
     use proptest::prelude::*;
-use crate::utils::merge_classes;
+    use wasm_bindgen_test::*;
 
     wasm_bindgen_test_configure!(run_in_browser);
 
     // Unit Tests
-    #[test] fn test_datatable_creation() { 
-    #[test] fn test_datatable_with_class() { 
-    #[test] fn test_datatable_with_style() { 
-    #[test] fn test_datatable_sortable() { 
-    #[test] fn test_datatable_filterable() { 
-    #[test] fn test_datatable_selectable() { 
+    #[test]
+    fn test_datatable_creation() {}
+    #[test]
+    fn test_datatable_with_class() {}
+    #[test]
+    fn test_datatable_with_style() {}
+    #[test]
+    fn test_datatable_sortable() {}
+    #[test]
+    fn test_datatable_filterable() {}
+    #[test]
+    fn test_datatable_selectable() {}
+
+    #[test]
+    fn test_datatable_loaded_message() {
+        let catalog = MessageCatalog::default();
+        assert_eq!(table_loaded_message(&catalog, None), "Table updated");
+        assert_eq!(table_loaded_message(&catalog, Some(0)), "No rows");
+        assert_eq!(table_loaded_message(&catalog, Some(1)), "Loaded 1 row");
+        assert_eq!(table_loaded_message(&catalog, Some(25)), "Loaded 25 rows");
+    }
+
+    #[test]
+    fn test_datatable_sort_toggles() {
+        let first = TableSort::toggled(None, "Name");
+        assert_eq!(first, TableSort::new("Name", SortDirection::Ascending));
+        let flipped = TableSort::toggled(Some(&first), "Name");
+        assert_eq!(flipped.direction, SortDirection::Descending);
+        let other = TableSort::toggled(Some(&flipped), "Role");
+        assert_eq!(other, TableSort::new("Role", SortDirection::Ascending));
+    }
+
+    #[test]
+    fn test_datatable_announcements() {
+        let catalog = MessageCatalog::default();
+        assert_eq!(
+            sort_message(&catalog, &TableSort::new("Name", SortDirection::Ascending)),
+            "Sorted by Name ascending"
+        );
+        assert_eq!(
+            sort_message(&catalog, &TableSort::new("Role", SortDirection::Descending)),
+            "Sorted by Role descending"
+        );
+        assert_eq!(selection_message(&catalog, 0), "No rows selected");
+        assert_eq!(selection_message(&catalog, 1), "1 row selected");
+        assert_eq!(selection_message(&catalog, 3), "3 rows selected");
+    }
+
+    #[test]
+    fn test_datatable_state_snapshot_round_trip() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let table = DataTableController::new();
+            table.sort.set(Some(TableSort::new("Name", SortDirection::Descending)));
+            table.set_selected("ada", true);
+            table.page.set(2);
+
+            let json = ComponentSnapshot::capture(&table).unwrap().to_json().unwrap();
+            table.reset();
+            assert_eq!(table.get_state(), DataTableState::default());
+
+            ComponentSnapshot::from_json(&json)
+                .unwrap()
+                .restore(&table)
+                .unwrap();
+            assert_eq!(
+                table.sort.get_untracked(),
+                Some(TableSort::new("Name", SortDirection::Descending))
+            );
+            assert!(table.is_selected("ada"));
+            assert_eq!(table.page.get_untracked(), 2);
+        });
+    }
+
+    #[test]
+    fn test_datatable_renders_controller_state() {
+        crate::test_support::init_test_executor();
+        let html = Owner::new().with(|| {
+            let table = DataTableController::new();
+            table.sort.set(Some(TableSort::new("Name", SortDirection::Ascending)));
+            table.set_selected("ada", true);
+            view! {
+                <DataTable controller=table>
+                    <DataTableHeader>
+                        <DataTableRow>
+                            <DataTableHeaderCell column="Name">"Name"</DataTableHeaderCell>
+                        </DataTableRow>
+                    </DataTableHeader>
+                    <DataTableBody>
+                        <DataTableRow row_key="ada">
+                            <DataTableCell>"Ada"</DataTableCell>
+                        </DataTableRow>
+                        <DataTableRow row_key="grace">
+                            <DataTableCell>"Grace"</DataTableCell>
+                        </DataTableRow>
+                    </DataTableBody>
+                </DataTable>
+            }
+            .to_html()
+        });
+        assert!(html.contains("aria-sort=\"ascending\""));
+        assert!(html.contains("class=\"data-table-sort-button\""));
+        assert!(html.contains("aria-selected=\"true\""));
+        assert!(html.contains("aria-selected=\"false\""));
+    }
+
+    #[test]
+    fn test_render_static_table_rows() {
+        let rows = vec![
+            vec!["Ada".to_string(), "1815".to_string()],
+            vec!["Bread & <Butter>".to_string(), "\"2\"".to_string()],
+        ];
+        let html = render_static_table_rows(&rows);
+
+        assert_eq!(html.matches("<tr class=\"data-table-row\">").count(), 2);
+        assert!(html.contains("<td class=\"data-table-cell\">Ada</td><td class=\"data-table-cell\">1815</td>"));
+        assert!(html.contains(">Bread &amp; &lt;Butter&gt;</td>"));
+        assert!(html.contains(">&quot;2&quot;</td>"));
+        assert_eq!(render_static_table_rows(&Vec::new()), "");
+    }
+
+    #[test]
+    fn test_datatable_renders_static_rows() {
+        crate::test_support::init_test_executor();
+        let html = Owner::new().with(|| {
+            view! {
+                <DataTable static_rows=vec![vec!["Ada".to_string()], vec!["Grace".to_string()]]>
+                    <DataTableHeader>
+                        <DataTableRow>
+                            <DataTableHeaderCell>"Name"</DataTableHeaderCell>
+                        </DataTableRow>
+                    </DataTableHeader>
+                </DataTable>
+            }
+            .to_html()
+        });
+        assert!(html.contains("<tbody class=\"data-table-body data-table-static-rows\">"));
+        assert!(html.contains("<td class=\"data-table-cell\">Grace</td></tr></tbody></table>"));
+    }
 
     // Property-based Tests
-    #[test] fn test_datatable_property_based() {
+    #[test]
+    fn test_datatable_property_based() {
         proptest!(|(____class in ".*", __style in ".*")| {
-            
+
         });
     }
 
     // Integration Tests
-    #[test] fn test_datatable_user_workflow() { 
-    #[test] fn test_datatable_accessibility_workflow() { 
+    #[test]
+    fn test_datatable_user_workflow() {}
+    #[test]
+    fn test_datatable_accessibility_workflow() {}
 
     // Performance Tests
-    #[test] fn test_datatable_large_dataset() { 
-    #[test] fn test_datatable_memory_usage() { 
-}
\ No newline at end of file
+    #[test]
+    fn test_datatable_large_dataset() {}
+    #[test]
+    fn test_datatable_memory_usage() {}
+}
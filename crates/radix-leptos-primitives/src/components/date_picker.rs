@@ -1,3 +1,4 @@
+use crate::i18n::{use_i18n, MessageKey};
 use crate::utils::merge_classes;
 use leptos::callback::Callback;
 use leptos::children::Children;
@@ -22,7 +23,8 @@ pub fn DatePicker(
     #[prop(optional)] _on_validation: Option<Callback<DateValidation>>,
 ) -> impl IntoView {
     let _value = value.unwrap_or_default();
-    let _placeholder = placeholder.unwrap_or_else(|| "Select date".to_string());
+    let _placeholder =
+        placeholder.unwrap_or_else(|| use_i18n().text(MessageKey::DatePickerPlaceholder));
     let _min_date = min_date.unwrap_or_default();
     let _max_date = max_date.unwrap_or_default();
     let disabled = disabled.unwrap_or(false);
@@ -43,7 +45,7 @@ pub fn DatePicker(
             class=class
             style=style
             role="combobox"
-            aria-label="Date picker"
+            aria-label=use_i18n().text(MessageKey::DatePickerLabel)
             data-format=format
             data-locale=locale
             data-min-date=_min_date
@@ -69,7 +71,8 @@ pub fn DatePickerInput(
     #[prop(optional)] on_blur: Option<Callback<()>>,
 ) -> impl IntoView {
     let value = value.unwrap_or_default();
-    let placeholder = placeholder.unwrap_or_else(|| "Select date".to_string());
+    let placeholder =
+        placeholder.unwrap_or_else(|| use_i18n().text(MessageKey::DatePickerPlaceholder));
     let disabled = disabled.unwrap_or(false);
     let required = required.unwrap_or(false);
     let format = format.unwrap_or_else(|| "YYYY-MM-DD".to_string());
@@ -136,7 +139,7 @@ pub fn DatePickerTrigger(
             style=style
             type="button"
             disabled=disabled
-            aria-label="Open date picker"
+            aria-label=use_i18n().text(MessageKey::DatePickerOpen)
             on:click=move |_| {
                 if let Some(callback) = on_click {
                     callback.run(());
@@ -170,7 +173,7 @@ pub fn DatePickerCalendar(
             class=class
             style=style
             role="dialog"
-            aria-label="Date picker calendar"
+            aria-label=use_i18n().text(MessageKey::DatePickerCalendar)
             data-value=value
             data-min-date=min_date
             data-max-date=max_date
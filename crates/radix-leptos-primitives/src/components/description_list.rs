@@ -0,0 +1,393 @@
+use crate::theming::Breakpoint;
+use crate::utils::merge_classes;
+use leptos::children::Children;
+use leptos::prelude::*;
+
+/// DescriptionList component - Semantic key/value pairs for detail pages
+///
+/// Renders a `<dl>` whose items lay out in a responsive grid. Labels can be
+/// given a fixed width and the list can be bordered or striped.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn Details() -> impl IntoView {
+///     view! {
+///         <DescriptionList
+///             columns=DescriptionListColumns::new(1).at(Breakpoint::Medium, 2)
+///             variant=DescriptionListVariant::Bordered
+///         >
+///             <DescriptionListItem term="Name".to_string()>"Ada Lovelace"</DescriptionListItem>
+///             <DescriptionListItem term="Role".to_string()>"Engineer"</DescriptionListItem>
+///         </DescriptionList>
+///     }
+/// }
+/// ```
+#[component]
+pub fn DescriptionList(
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    #[prop(optional)] children: Option<Children>,
+    /// Items rendered before any children
+    #[prop(optional)]
+    items: Option<Vec<DescriptionItem>>,
+    /// Responsive column configuration
+    #[prop(optional)]
+    columns: Option<DescriptionListColumns>,
+    /// Visual variant
+    #[prop(optional)]
+    variant: Option<DescriptionListVariant>,
+    /// Whether terms sit beside (horizontal) or above (vertical) their details
+    #[prop(optional)]
+    layout: Option<DescriptionListLayout>,
+    /// Fixed width for term labels in horizontal layout (any CSS length)
+    #[prop(optional)]
+    label_width: Option<String>,
+) -> impl IntoView {
+    let items = items.unwrap_or_default();
+    let columns = columns.unwrap_or_default();
+    let variant = variant.unwrap_or_default();
+    let layout = layout.unwrap_or_default();
+
+    let column_classes = columns.to_classes();
+    let mut class_list = vec!["description-list", variant.to_class(), layout.to_class()];
+    class_list.extend(column_classes.iter().map(String::as_str));
+    class_list.push(class.as_deref().unwrap_or(""));
+    let class = merge_classes(class_list);
+
+    let style = build_description_list_style(&columns, label_width.as_deref(), style.as_deref());
+
+    view! {
+        <dl
+            class=class
+            style=style
+            data-variant=variant.as_str()
+            data-layout=layout.as_str()
+            data-columns=columns.base
+        >
+            {items
+                .into_iter()
+                .map(|item| {
+                    view! {
+                        <div
+                            class="description-list-item"
+                            style=build_description_item_style(item.span, None)
+                            data-span=item.span
+                        >
+                            <dt class="description-list-term">{item.term}</dt>
+                            <dd class="description-list-details">{item.details}</dd>
+                        </div>
+                    }
+                })
+                .collect::<Vec<_>>()}
+            {children.map(|c| c())}
+        </dl>
+    }
+}
+
+/// DescriptionListItem component - A single term with its details
+#[component]
+pub fn DescriptionListItem(
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    /// The term (label)
+    term: String,
+    /// Number of grid columns this item spans
+    #[prop(optional)]
+    span: Option<usize>,
+    /// The details (value)
+    children: Children,
+) -> impl IntoView {
+    let span = span.unwrap_or(1).max(1);
+    let class = merge_classes(vec![
+        "description-list-item",
+        class.as_deref().unwrap_or(""),
+    ]);
+    let style = build_description_item_style(span, style.as_deref());
+
+    view! {
+        <div class=class style=style data-span=span>
+            <dt class="description-list-term">{term}</dt>
+            <dd class="description-list-details">{children()}</dd>
+        </div>
+    }
+}
+
+/// Data-driven description list entry
+#[derive(Debug, Clone, PartialEq)]
+pub struct DescriptionItem {
+    pub term: String,
+    pub details: String,
+    pub span: usize,
+}
+
+impl DescriptionItem {
+    pub fn new(term: impl Into<String>, details: impl Into<String>) -> Self {
+        Self {
+            term: term.into(),
+            details: details.into(),
+            span: 1,
+        }
+    }
+
+    pub fn with_span(mut self, span: usize) -> Self {
+        self.span = span.max(1);
+        self
+    }
+}
+
+/// Column count applied from a breakpoint upward
+pub type BreakpointColumns = (Breakpoint, usize);
+
+/// Responsive column counts, keyed by breakpoint
+#[derive(Debug, Clone, PartialEq)]
+pub struct DescriptionListColumns {
+    /// Columns below the first breakpoint
+    pub base: usize,
+    /// Column overrides from a breakpoint upward
+    pub responsive: Vec<BreakpointColumns>,
+}
+
+impl Default for DescriptionListColumns {
+    fn default() -> Self {
+        Self::new(1)
+    }
+}
+
+impl DescriptionListColumns {
+    pub fn new(base: usize) -> Self {
+        Self {
+            base: base.max(1),
+            responsive: Vec::new(),
+        }
+    }
+
+    /// Use `columns` from `breakpoint` upward
+    pub fn at(mut self, breakpoint: Breakpoint, columns: usize) -> Self {
+        self.responsive
+            .retain(|(existing, _)| *existing != breakpoint);
+        self.responsive.push((breakpoint, columns.max(1)));
+        self.responsive
+            .sort_by(|a, b| a.0.min_width().total_cmp(&b.0.min_width()));
+        self
+    }
+
+    /// Column count in effect for a viewport width
+    pub fn columns_for_width(&self, width: f64) -> usize {
+        self.responsive
+            .iter()
+            .rfind(|(breakpoint, _)| width >= breakpoint.min_width())
+            .map(|(_, columns)| *columns)
+            .unwrap_or(self.base)
+    }
+
+    /// CSS classes such as `cols-1 md:cols-2 lg:cols-3`
+    pub fn to_classes(&self) -> Vec<String> {
+        std::iter::once(format!("cols-{}", self.base))
+            .chain(
+                self.responsive.iter().map(|(breakpoint, columns)| {
+                    format!("{}:cols-{}", breakpoint.as_str(), columns)
+                }),
+            )
+            .collect()
+    }
+}
+
+/// DescriptionList visual variant
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DescriptionListVariant {
+    #[default]
+    Default,
+    Bordered,
+    Striped,
+}
+
+impl DescriptionListVariant {
+    pub fn to_class(&self) -> &'static str {
+        match self {
+            DescriptionListVariant::Default => "variant-default",
+            DescriptionListVariant::Bordered => "variant-bordered",
+            DescriptionListVariant::Striped => "variant-striped",
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DescriptionListVariant::Default => "default",
+            DescriptionListVariant::Bordered => "bordered",
+            DescriptionListVariant::Striped => "striped",
+        }
+    }
+}
+
+/// Placement of terms relative to their details
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DescriptionListLayout {
+    #[default]
+    Horizontal,
+    Vertical,
+}
+
+impl DescriptionListLayout {
+    pub fn to_class(&self) -> &'static str {
+        match self {
+            DescriptionListLayout::Horizontal => "layout-horizontal",
+            DescriptionListLayout::Vertical => "layout-vertical",
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DescriptionListLayout::Horizontal => "horizontal",
+            DescriptionListLayout::Vertical => "vertical",
+        }
+    }
+}
+
+/// Build the inline style exposing column and label-width custom properties
+fn build_description_list_style(
+    columns: &DescriptionListColumns,
+    label_width: Option<&str>,
+    style: Option<&str>,
+) -> String {
+    let mut css = format!("--description-list-columns: {};", columns.base);
+    for (breakpoint, count) in &columns.responsive {
+        css.push_str(&format!(
+            " --description-list-columns-{}: {};",
+            breakpoint.as_str(),
+            count
+        ));
+    }
+    if let Some(width) = label_width.filter(|width| !width.is_empty()) {
+        css.push_str(&format!(" --description-list-label-width: {};", width));
+    }
+    if let Some(style) = style.filter(|style| !style.is_empty()) {
+        css.push(' ');
+        css.push_str(style);
+    }
+    css
+}
+
+/// Build the inline style of an item, spanning `span` grid columns
+fn build_description_item_style(span: usize, style: Option<&str>) -> Option<String> {
+    let style = style.filter(|style| !style.is_empty());
+    match (span > 1, style) {
+        (true, Some(style)) => Some(format!("grid-column: span {}; {}", span, style)),
+        (true, None) => Some(format!("grid-column: span {};", span)),
+        (false, style) => style.map(str::to_string),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_description_list_columns_default() {
+        let columns = DescriptionListColumns::default();
+        assert_eq!(columns.base, 1);
+        assert_eq!(columns.to_classes(), vec!["cols-1".to_string()]);
+    }
+
+    #[test]
+    fn test_description_list_columns_responsive() {
+        let columns = DescriptionListColumns::new(1)
+            .at(Breakpoint::Large, 3)
+            .at(Breakpoint::Medium, 2);
+        assert_eq!(
+            columns.to_classes(),
+            vec!["cols-1", "md:cols-2", "lg:cols-3"]
+        );
+        assert_eq!(columns.columns_for_width(320.0), 1);
+        assert_eq!(columns.columns_for_width(800.0), 2);
+        assert_eq!(columns.columns_for_width(1440.0), 3);
+    }
+
+    #[test]
+    fn test_description_list_columns_replace_breakpoint() {
+        let columns = DescriptionListColumns::new(0)
+            .at(Breakpoint::Medium, 2)
+            .at(Breakpoint::Medium, 4);
+        assert_eq!(columns.base, 1);
+        assert_eq!(columns.responsive, vec![(Breakpoint::Medium, 4)]);
+    }
+
+    #[test]
+    fn test_description_list_style() {
+        let columns = DescriptionListColumns::new(2).at(Breakpoint::Small, 3);
+        let style = build_description_list_style(&columns, Some("12rem"), Some("color: red;"));
+        assert!(style.contains("--description-list-columns: 2;"));
+        assert!(style.contains("--description-list-columns-sm: 3;"));
+        assert!(style.contains("--description-list-label-width: 12rem;"));
+        assert!(style.ends_with("color: red;"));
+    }
+
+    #[test]
+    fn test_description_item_style() {
+        assert_eq!(build_description_item_style(1, None), None);
+        assert_eq!(
+            build_description_item_style(1, Some("color: red;")).as_deref(),
+            Some("color: red;")
+        );
+        assert_eq!(
+            build_description_item_style(2, None).as_deref(),
+            Some("grid-column: span 2;")
+        );
+        assert_eq!(
+            build_description_item_style(3, Some("color: red;")).as_deref(),
+            Some("grid-column: span 3; color: red;")
+        );
+    }
+
+    #[test]
+    fn test_description_list_items_span() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let items = vec![
+                DescriptionItem::new("Name", "Ada"),
+                DescriptionItem::new("Bio", "Mathematician").with_span(2),
+            ];
+            let html = view! { <DescriptionList items=items /> }.to_html();
+            assert!(html.contains(r#"style="grid-column: span 2;"#));
+            assert_eq!(html.matches("grid-column").count(), 1);
+        });
+    }
+
+    #[test]
+    fn test_description_list_variants() {
+        assert_eq!(
+            DescriptionListVariant::default(),
+            DescriptionListVariant::Default
+        );
+        assert_eq!(
+            DescriptionListVariant::Bordered.to_class(),
+            "variant-bordered"
+        );
+        assert_eq!(DescriptionListVariant::Striped.as_str(), "striped");
+        assert_eq!(
+            DescriptionListLayout::Vertical.to_class(),
+            "layout-vertical"
+        );
+    }
+
+    #[test]
+    fn test_description_item() {
+        let item = DescriptionItem::new("Name", "Ada").with_span(0);
+        assert_eq!(item.term, "Name");
+        assert_eq!(item.details, "Ada");
+        assert_eq!(item.span, 1);
+    }
+
+    proptest! {
+        #[test]
+        fn test_columns_for_width_never_zero(base in 0..6usize, md in 0..6usize, width in 0.0..4000.0f64) {
+            let columns = DescriptionListColumns::new(base).at(Breakpoint::Medium, md);
+            prop_assert!(columns.columns_for_width(width) >= 1);
+        }
+    }
+}
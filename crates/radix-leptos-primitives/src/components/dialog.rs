@@ -1,7 +1,9 @@
 use leptos::callback::Callback;
 use leptos::children::Children;
 use leptos::prelude::*;
+use radix_leptos_core::use_presence;
 use wasm_bindgen::JsCast;
+use crate::theming::PresenceAnimation;
 use crate::utils::{merge_optional_classes, generate_id};
 
 /// Dialog component with proper accessibility and styling variants
@@ -100,8 +102,8 @@ impl DialogSize {
 #[component]
 pub fn Dialog(
     /// Whether the dialog is open
-    #[prop(optional, default = false)]
-    open: bool,
+    #[prop(optional, into)]
+    open: MaybeProp<bool>,
     /// Dialog styling variant
     #[prop(optional, default = DialogVariant::Default)]
     variant: DialogVariant,
@@ -155,12 +157,20 @@ pub fn Dialog(
         }
     };
 
+    // Closing switches data-state first so the dialog can animate out
+    let node_ref = NodeRef::<leptos::html::Div>::new();
+    let presence = use_presence(Signal::derive(move || open.get().unwrap_or(false)), node_ref);
+
     view! {
         <div
+            node_ref=node_ref
             class=combined_class
             style=style
             data-variant=data_variant
             data-size=data_size
+            data-state=move || presence.state.get().as_str()
+            data-motion=PresenceAnimation::Scale.as_str()
+            hidden=move || !presence.is_present.get()
             on:keydown=handle_keydown
             on:click=handle_backdrop_click
         >
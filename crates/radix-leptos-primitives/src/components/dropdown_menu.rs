@@ -1,8 +1,10 @@
+use crate::components::roving_focus::{roving_tab_index, RovingFocusGroup};
 use crate::utils::merge_classes;
 use leptos::callback::Callback;
 use leptos::children::Children;
 use leptos::html;
 use leptos::prelude::*;
+use radix_leptos_core::use_typeahead;
 use wasm_bindgen::JsCast;
 use web_sys::{KeyboardEvent, MouseEvent};
 
@@ -134,7 +136,8 @@ pub fn DropdownMenuTrigger(
             class=final_class
             style=style
             role="button"
-            tabindex="0"
+            tabindex=roving_tab_index(Some("0"))
+            data-roving-focus-item=RovingFocusGroup::item_attribute()
             aria-haspopup="true"
             aria-expanded="false"
             data-radix-dropdown-menu-trigger=""
@@ -146,6 +149,10 @@ pub fn DropdownMenuTrigger(
     }
 }
 
+/// Items of a dropdown menu, whatever their kind
+const MENU_ITEM_SELECTOR: &str =
+    "[role='menuitem'], [role='menuitemcheckbox'], [role='menuitemradio']";
+
 #[component]
 pub fn DropdownMenuContent(
     #[prop(optional)] class: Option<String>,
@@ -179,6 +186,15 @@ pub fn DropdownMenuContent(
     let classes = merge_classes(base_classes.to_vec());
     let final_class = format!("{} {}", classes, class_value);
 
+    // Typing the start of an item's label moves focus to it
+    let content_ref = NodeRef::<html::Div>::new();
+    let typeahead = use_typeahead();
+    let handle_keydown = move |e: KeyboardEvent| {
+        if let Some(content) = content_ref.get_untracked() {
+            typeahead.handle_keydown(&e, &content, MENU_ITEM_SELECTOR);
+        }
+    };
+
     view! {
         <div
             class=final_class
@@ -188,6 +204,8 @@ pub fn DropdownMenuContent(
             data-radix-dropdown-menu-content=""
             role="menu"
             aria-orientation="vertical"
+            node_ref=content_ref
+            on:keydown=handle_keydown
         >
             {children()}
         </div>
@@ -269,6 +287,7 @@ pub fn DropdownMenuItem(
             style=style
             role="menuitem"
             tabindex="-1"
+            aria-disabled=disabled.unwrap_or(false).then_some("true")
             data-radix-dropdown-menu-item=""
             on:click=handle_click
             on:keydown=handle_keydown
@@ -1,3 +1,4 @@
+use crate::i18n::{use_i18n, MessageArgs, MessageKey};
 use crate::utils::merge_classes;
 use leptos::callback::Callback;
 use leptos::children::Children;
@@ -56,7 +57,7 @@ pub fn FileUpload(
             class=class
             style=style
             role="button"
-            aria-label="File upload area"
+            aria-label=use_i18n().text(MessageKey::FileUploadArea)
             data-multiple=multiple
             data-accept=_accept
             data-max-size=_max_size
@@ -147,7 +148,7 @@ pub fn FileUploadDropZone(
             class=class
             style=style
             role="button"
-            aria-label="File drop zone"
+            aria-label=use_i18n().text(MessageKey::FileUploadDropZone)
             on:drop=move |event: web_sys::DragEvent| {
                 if !disabled {
                     event.prevent_default();
@@ -184,7 +185,7 @@ pub fn FileUploadList(
             class=class
             style=style
             role="list"
-            aria-label="Uploaded files"
+            aria-label=use_i18n().text(MessageKey::FileUploadList)
         >
             {children.map(|c| c())}
         </div>
@@ -208,6 +209,14 @@ pub fn FileUploadItem(
         class.as_deref().unwrap_or(""),
     ]);
 
+    let label = use_i18n().format(
+        MessageKey::FileUploadItemLabel,
+        &MessageArgs::new()
+            .with("name", file.name.as_str())
+            .with("status", file.status.as_str())
+            .with("percent", file.progress.round()),
+    );
+
     let file_id = file.id.clone();
     let handle_remove = move |_: web_sys::MouseEvent| {
         if let Some(callback) = on_remove {
@@ -220,7 +229,7 @@ pub fn FileUploadItem(
             class=class
             style=style
             role="listitem"
-            aria-label=format!("File: {}", file.name)
+            aria-label=label
             data-file-id=file.id
             data-file-name=file.name
             data-file-size=file.size
@@ -278,6 +287,16 @@ impl FileStatus {
             FileStatus::Cancelled => "status-cancelled",
         }
     }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FileStatus::Pending => "pending",
+            FileStatus::Uploading => "uploading",
+            FileStatus::Completed => "completed",
+            FileStatus::Error => "error",
+            FileStatus::Cancelled => "cancelled",
+        }
+    }
 }
 
 /// Upload Progress structure
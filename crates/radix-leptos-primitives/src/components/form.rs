@@ -1,7 +1,17 @@
 use leptos::callback::Callback;
 use leptos::children::Children;
+use leptos::html;
 use leptos::prelude::*;
+use radix_leptos_core::use_focus_within;
+use radix_leptos_core::{channels, use_announcer, Announcer};
+use crate::i18n::{use_i18n, MessageArgs, MessageCatalog, MessageKey};
 use crate::utils::{merge_optional_classes, generate_id};
+use crate::theming::{
+    offered_variant, use_component_theme, InputVariants, SizeVariant, StyleVariant,
+};
+use crate::StateSnapshot;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
 
 /// Form component with proper accessibility and validation
 ///
@@ -93,20 +103,201 @@ impl FormSize {
 }
 
 /// Form data structure
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct FormData {
     pub fields: std::collections::HashMap<String, String>,
 }
 
 /// Form errors structure
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct FormErrors {
     pub field_errors: std::collections::HashMap<String, String>,
     pub global_errors: Vec<String>,
 }
 
+/// Serializable snapshot of a form, see [`FormController`]
+///
+/// Ordered maps keep the serialized output stable between captures.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FormState {
+    pub values: BTreeMap<String, String>,
+    pub field_errors: BTreeMap<String, String>,
+    pub global_errors: Vec<String>,
+    pub touched: BTreeSet<String>,
+    pub submitted: bool,
+}
+
+/// Reactive state of a [`Form`]
+///
+/// Pass it to `Form` through the `controller` prop; `FormInput`s with a
+/// `name` then read their value from and write it back to the controller.
+/// Implements [`StateSnapshot`] for undo and restore-on-reload.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FormController {
+    pub data: RwSignal<FormData>,
+    pub errors: RwSignal<FormErrors>,
+    pub touched: RwSignal<BTreeSet<String>>,
+    pub submitted: RwSignal<bool>,
+}
+
+impl Default for FormController {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FormController {
+    pub fn new() -> Self {
+        Self {
+            data: RwSignal::new(FormData::default()),
+            errors: RwSignal::new(FormErrors::default()),
+            touched: RwSignal::new(BTreeSet::new()),
+            submitted: RwSignal::new(false),
+        }
+    }
+
+    /// Current value of a field
+    pub fn value(&self, name: &str) -> Option<String> {
+        self.data.with(|data| data.fields.get(name).cloned())
+    }
+
+    /// Set the value of a field and mark it as touched
+    pub fn set_value(&self, name: &str, value: impl Into<String>) {
+        let value = value.into();
+        self.data.update(|data| {
+            data.fields.insert(name.to_string(), value);
+        });
+        self.touched.update(|touched| {
+            touched.insert(name.to_string());
+        });
+    }
+
+    /// Error message of a field
+    pub fn error(&self, name: &str) -> Option<String> {
+        self.errors.with(|errors| errors.field_errors.get(name).cloned())
+    }
+
+    pub fn set_error(&self, name: &str, message: impl Into<String>) {
+        let message = message.into();
+        self.errors.update(|errors| {
+            errors.field_errors.insert(name.to_string(), message);
+        });
+    }
+
+    pub fn clear_error(&self, name: &str) {
+        self.errors.update(|errors| {
+            errors.field_errors.remove(name);
+        });
+    }
+
+    /// Clear values, errors and the touched/submitted flags
+    pub fn reset(&self) {
+        self.set_state(FormState::default());
+    }
+}
+
+impl StateSnapshot for FormController {
+    type State = FormState;
+    const COMPONENT: &'static str = "Form";
+
+    fn get_state(&self) -> FormState {
+        let (field_errors, global_errors) = self.errors.with_untracked(|errors| {
+            (
+                errors.field_errors.clone().into_iter().collect(),
+                errors.global_errors.clone(),
+            )
+        });
+        FormState {
+            values: self
+                .data
+                .with_untracked(|data| data.fields.clone().into_iter().collect()),
+            field_errors,
+            global_errors,
+            touched: self.touched.get_untracked(),
+            submitted: self.submitted.get_untracked(),
+        }
+    }
+
+    fn set_state(&self, state: FormState) {
+        self.data.set(FormData {
+            fields: state.values.into_iter().collect(),
+        });
+        self.errors.set(FormErrors {
+            field_errors: state.field_errors.into_iter().collect(),
+            global_errors: state.global_errors,
+        });
+        self.touched.set(state.touched);
+        self.submitted.set(state.submitted);
+    }
+}
+
+/// Label of a field for error summaries, from its name: `first_name`
+/// becomes "First name"
+pub fn field_label(name: &str) -> String {
+    let words = name.replace(['_', '-'], " ");
+    let mut chars = words.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => words,
+    }
+}
+
+/// Announcement for the fields with errors, e.g. "2 errors: Email, Password"
+pub fn error_summary(catalog: &MessageCatalog, labels: &[String]) -> Option<String> {
+    if labels.is_empty() {
+        return None;
+    }
+    let args = MessageArgs::new()
+        .with("count", labels.len())
+        .with("fields", labels.join(", "));
+    Some(catalog.format(MessageKey::FormErrorSummary, &args))
+}
+
+/// Name and label of a field, see [`failing_labels`]
+type FieldLabel = (String, String);
+
+/// Labels of the fields in `errors`, in form order; errors for fields not
+/// rendered through a [`FormField`] come last, by name
+fn failing_labels(fields: &[FieldLabel], errors: &FormErrors) -> Vec<String> {
+    let mut labels: Vec<String> = fields
+        .iter()
+        .filter(|(name, _)| errors.field_errors.contains_key(name))
+        .map(|(_, label)| label.clone())
+        .collect();
+    let mut unknown: Vec<&String> = errors
+        .field_errors
+        .keys()
+        .filter(|name| !fields.iter().any(|(field, _)| field == *name))
+        .collect();
+    unknown.sort();
+    labels.extend(unknown.into_iter().map(|name| field_label(name)));
+    labels
+}
+
+fn announce_errors(announcer: Announcer, catalog: StoredValue<MessageCatalog>, labels: &[String]) {
+    if let Some(message) = catalog.with_value(|catalog| error_summary(catalog, labels)) {
+        announcer.announce(channels::VALIDATION, &message);
+    }
+}
+
+/// Names and labels of the fields of the enclosing Form, in render order
+#[derive(Clone, Copy)]
+struct FormFields(StoredValue<Vec<FieldLabel>>);
+
+/// Name and error element id of the enclosing FormField
+#[derive(Clone)]
+struct FormFieldContext {
+    name: String,
+    error_id: String,
+}
 
 /// Form root component
+///
+/// With a `controller`, errors are announced on the validation channel of
+/// the closest AnnouncerProvider as a summary such as "2 errors: Email,
+/// Password": on submit, after `on_submit` has run, and whenever the set of
+/// fields with errors changes. Messages changing as the user types are not
+/// announced again; the field's [`FormError`] shows them.
 #[component]
 pub fn Form(
     /// Form styling variant
@@ -127,6 +318,9 @@ pub fn Form(
     /// Form errors
     #[prop(optional)]
     _errors: Option<FormErrors>,
+    /// Reactive form state shared with the inputs
+    #[prop(optional)]
+    controller: Option<FormController>,
     /// Submit event handler
     #[prop(optional)]
     on_submit: Option<Callback<web_sys::SubmitEvent>>,
@@ -147,16 +341,48 @@ pub fn Form(
     let combined_class = merge_optional_classes(Some(base_classes), class.as_deref())
         .unwrap_or_else(|| base_classes.to_string());
 
+    let catalog = StoredValue::new(use_i18n());
+    let announcer = use_announcer();
+    let fields = FormFields(StoredValue::new(Vec::new()));
+    provide_context(fields);
+    let failing = move |controller: FormController| {
+        controller
+            .errors
+            .with(|errors| fields.0.with_value(|fields| failing_labels(fields, errors)))
+    };
+
+    if let Some(controller) = controller {
+        provide_context(controller);
+        Effect::new(move |previous: Option<Vec<String>>| {
+            let labels = failing(controller);
+            if previous.is_some_and(|previous| previous != labels) {
+                announce_errors(announcer, catalog, &labels);
+            }
+            labels
+        });
+    }
+
     // Handle form submission
     let handle_submit = move |e: web_sys::SubmitEvent| {
         e.prevent_default();
+        if let Some(controller) = controller {
+            controller.submitted.set(true);
+        }
         if let Some(on_submit) = on_submit {
             on_submit.run(e);
         }
+        // Validation ran in on_submit; the validation channel merges this with the
+        // announcement of the change
+        if let Some(controller) = controller {
+            announce_errors(announcer, catalog, &untrack(|| failing(controller)));
+        }
     };
 
     // Handle form reset
     let handle_reset = move |e: web_sys::Event| {
+        if let Some(controller) = controller {
+            controller.reset();
+        }
         if let Some(on_reset) = on_reset {
             on_reset.run(e);
         }
@@ -179,10 +405,18 @@ pub fn Form(
 }
 
 /// Form field component
+///
+/// Its [`FormInput`] and [`FormError`] take their name from the field, so
+/// the input gets `aria-invalid` and `aria-describedby` pointing at the
+/// error while the form controller has an error for the field.
 #[component]
 pub fn FormField(
     /// Field name
     name: String,
+    /// Name of the field in error summaries, defaults to the name
+    /// capitalized
+    #[prop(optional)]
+    label: Option<String>,
     /// Whether the field is required
     #[prop(optional, default = false)]
     required: bool,
@@ -195,18 +429,31 @@ pub fn FormField(
     /// Child content
     children: Children,
 ) -> impl IntoView {
-    let __field_id = generate_id(&format!("field-{}", name));
+    let field_id = generate_id(&format!("field-{}", name));
+    if let Some(fields) = use_context::<FormFields>() {
+        let label = label.unwrap_or_else(|| field_label(&name));
+        fields.0.update_value(|fields| fields.push((name.clone(), label)));
+    }
+    provide_context(FormFieldContext {
+        name: name.clone(),
+        error_id: format!("{}-error", field_id),
+    });
 
     let base_classes = "radix-form-field";
     let combined_class = merge_optional_classes(Some(base_classes), class.as_deref())
         .unwrap_or_else(|| base_classes.to_string());
+    // Lets a field style its label and border while any of its inputs has focus
+    let node_ref = NodeRef::<html::Div>::new();
+    let focus_within = use_focus_within(node_ref);
 
     view! {
         <div
             class=combined_class
             style=style
+            node_ref=node_ref
             data-field-name=name
             data-required=required
+            data-focus-within=move || focus_within.get()
         >
             {children()}
         </div>
@@ -237,6 +484,11 @@ pub fn FormLabel(
 }
 
 /// Form input component
+///
+/// Inside a [`ComponentTheme`](crate::theming::ComponentTheme) for
+/// `"input"` it renders in the first style and size the theme's lists
+/// offer when they leave out the defaults, and its variables are applied
+/// as inline styles.
 #[component]
 pub fn FormInput(
     /// Input type
@@ -273,8 +525,44 @@ pub fn FormInput(
     let combined_class = merge_optional_classes(Some(base_classes), class.as_deref())
         .unwrap_or_else(|| base_classes.to_string());
 
+    let theme = use_component_theme("input");
+    let variants = Memo::new(move |_| InputVariants::default().with_overrides(&theme.get()));
+    let variant = Memo::new(move |_| {
+        variants.with(|variants| offered_variant(StyleVariant::Default, &variants.styles))
+    });
+    let size = Memo::new(move |_| {
+        variants.with(|variants| offered_variant(SizeVariant::Medium, &variants.sizes))
+    });
+    let style = move || {
+        theme.with(|theme| theme.inline_style("input", Some(variant.get()), style.as_deref()))
+    };
+
+    // Bind to the form controller when the input or its field is named
+    let controller = use_context::<FormController>();
+    let field = use_context::<FormFieldContext>();
+    let name = name.or_else(|| field.as_ref().map(|field| field.name.clone()));
+    let bound_name = name.clone().filter(|_| controller.is_some());
+    let invalid = {
+        let bound_name = bound_name.clone();
+        Signal::derive(move || match (controller, bound_name.as_deref()) {
+            (Some(controller), Some(name)) => controller.error(name).is_some(),
+            _ => false,
+        })
+    };
+    let error_id = field.map(|field| field.error_id);
+    let bound_value = {
+        let bound_name = bound_name.clone();
+        move || match (controller, bound_name.as_deref()) {
+            (Some(controller), Some(name)) => controller.value(name).or_else(|| value.clone()),
+            _ => value.clone(),
+        }
+    };
+
     // Handle input change
     let handle_change = move |e: web_sys::Event| {
+        if let (Some(controller), Some(name)) = (controller, bound_name.as_deref()) {
+            controller.set_value(name, event_target_value(&e));
+        }
         if let Some(on_change) = on_change {
             on_change.run(e);
         }
@@ -285,21 +573,28 @@ pub fn FormInput(
             id=input_id
             type=type_
             name=name
-            value=value
+            prop:value=bound_value
             placeholder=placeholder
             required=required
             disabled=disabled
+            aria-invalid=move || invalid.get().then_some("true")
+            aria-describedby=move || error_id.clone().filter(|_| invalid.get())
             class=combined_class
             style=style
+            data-variant=move || variant.get().as_str()
+            data-size=move || size.get().as_str()
             on:change=handle_change
         />
     }
 }
 
 /// Form error component
+///
+/// Inside a [`FormField`] of a form with a controller it shows the field's
+/// error, and leaves announcing it to the form's error summary.
 #[component]
 pub fn FormError(
-    /// Error message
+    /// Error message, instead of the controller's
     #[prop(optional)]
     message: Option<String>,
     /// CSS classes
@@ -313,9 +608,21 @@ pub fn FormError(
     let combined_class = merge_optional_classes(Some(base_classes), class.as_deref())
         .unwrap_or_else(|| base_classes.to_string());
 
+    let field = use_context::<FormFieldContext>();
+    let id = field.as_ref().map(|field| field.error_id.clone());
+    let bound = field.zip(use_context::<FormController>());
+    let role = bound.is_none().then_some("alert");
+    let message = move || {
+        message.clone().or_else(|| {
+            bound
+                .as_ref()
+                .and_then(|(field, controller)| controller.error(&field.name))
+        })
+    };
+
     view! {
-        <div class=combined_class style=style role="alert">
-            {message.unwrap_or_default()}
+        <div id=id class=combined_class style=style role=role>
+            {message}
         </div>
     }
 }
@@ -345,7 +652,11 @@ pub fn FormSubmit(
 
 #[cfg(test)]
 mod tests {
-    use crate::{FormData, FormErrors, FormSize, FormVariant};
+    use crate::{
+        ComponentSnapshot, FormController, FormData, FormErrors, FormSize, FormState,
+        FormVariant, StateSnapshot,
+    };
+    use leptos::prelude::*;
     use proptest::prelude::*;
 use crate::utils::{merge_optional_classes, generate_id};
 
@@ -507,6 +818,91 @@ use crate::utils::{merge_optional_classes, generate_id};
         });
     }
 
+    #[test]
+    fn test_form_error_summary() {
+        use super::{error_summary, failing_labels, field_label};
+        use crate::MessageCatalog;
+
+        let catalog = MessageCatalog::default();
+        assert_eq!(field_label("first_name"), "First name");
+        assert_eq!(error_summary(&catalog, &[]), None);
+        assert_eq!(
+            error_summary(&catalog, &["Email".to_string()]).as_deref(),
+            Some("1 error: Email")
+        );
+
+        let fields = vec![
+            ("email".to_string(), "Email".to_string()),
+            ("password".to_string(), "Password".to_string()),
+        ];
+        let mut errors = FormErrors::default();
+        for name in ["password", "terms", "email"] {
+            errors
+                .field_errors
+                .insert(name.to_string(), "Required".to_string());
+        }
+        let labels = failing_labels(&fields, &errors);
+        assert_eq!(labels, ["Email", "Password", "Terms"]);
+        assert_eq!(
+            error_summary(&catalog, &labels).as_deref(),
+            Some("3 errors: Email, Password, Terms")
+        );
+    }
+
+    #[test]
+    fn test_form_input_describes_error() {
+        use crate::{Form, FormError, FormField, FormInput};
+
+        crate::test_support::init_test_executor();
+        let owner = Owner::new();
+        owner.with(|| {
+            let controller = FormController::new();
+            controller.set_error("email", "Enter an email address");
+            let html = view! {
+                <Form controller=controller>
+                    <FormField name="email".to_string()>
+                        <FormInput />
+                        <FormError />
+                    </FormField>
+                    <FormField name="password".to_string()>
+                        <FormInput />
+                    </FormField>
+                </Form>
+            }
+            .to_html();
+
+            let error_id = html.split("aria-describedby=\"").nth(1).unwrap();
+            let error_id = &error_id[..error_id.find('"').unwrap()];
+            assert!(error_id.starts_with("field-email"));
+            assert_eq!(html.matches("aria-invalid=\"true\"").count(), 1);
+            assert!(html.contains(&format!("id=\"{}\"", error_id)));
+            assert!(html.contains("Enter an email address"));
+            assert!(!html.contains("role=\"alert\""));
+        });
+    }
+
+    #[test]
+    fn test_form_input_follows_component_theme() {
+        use crate::theming::{ComponentTheme, ComponentThemeOverrides, StyleVariant};
+        use crate::FormInput;
+
+        crate::test_support::init_test_executor();
+        let owner = Owner::new();
+        owner.with(|| {
+            let overrides = ComponentThemeOverrides::new()
+                .with_style_variable(StyleVariant::Filled, "background", "#f4f4f5")
+                .with_styles(vec![StyleVariant::Filled]);
+            let html = view! {
+                <ComponentTheme component="input" overrides=overrides>
+                    <FormInput />
+                </ComponentTheme>
+            }
+            .to_html();
+            assert!(html.contains("data-variant=\"filled\""));
+            assert!(html.contains("style=\"background: var(--input-filled-background);"));
+        });
+    }
+
     // 6. Edge Case Tests
     #[test]
     fn test_form_edge_cases() {
@@ -522,7 +918,44 @@ use crate::utils::{merge_optional_classes, generate_id};
         });
     }
 
-    // 7. Property-Based Tests
+    // 7. State Snapshot Tests
+    #[test]
+    fn test_form_state_snapshot_round_trip() {
+        run_test(|| {
+            let form = FormController::new();
+            form.set_value("email", "ada@example.com");
+            form.set_error("email", "Already registered");
+            form.submitted.set(true);
+
+            let json = ComponentSnapshot::capture(&form).unwrap().to_json().unwrap();
+            form.reset();
+            assert_eq!(form.get_state(), FormState::default());
+
+            ComponentSnapshot::from_json(&json)
+                .unwrap()
+                .restore(&form)
+                .unwrap();
+            assert_eq!(form.value("email").as_deref(), Some("ada@example.com"));
+            assert_eq!(form.error("email").as_deref(), Some("Already registered"));
+            assert!(form.get_state().touched.contains("email"));
+            assert!(form.submitted.get_untracked());
+        });
+    }
+
+    #[test]
+    fn test_form_state_serialization_is_stable() {
+        run_test(|| {
+            let form = FormController::new();
+            for name in ["zip", "city", "address", "name"] {
+                form.set_value(name, name.to_uppercase());
+            }
+            let first = ComponentSnapshot::capture(&form).unwrap().to_json().unwrap();
+            let second = ComponentSnapshot::capture(&form).unwrap().to_json().unwrap();
+            assert_eq!(first, second);
+        });
+    }
+
+    // 8. Property-Based Tests
     proptest! {
         #[test]
         fn test_form_properties(
@@ -1,6 +1,8 @@
+use crate::i18n::{use_i18n, MessageArgs, MessageCatalog, MessageKey};
 use crate::utils::merge_classes;
 use leptos::callback::Callback;
 use leptos::prelude::*;
+use radix_leptos_core::{channels, use_announce_on};
 use std::collections::HashMap;
 
 use super::validation::{FormValidationState, FormError, ValidationMode, FieldError};
@@ -38,14 +40,33 @@ pub fn FormValidationProvider(
             class=class
             style=style
             role="form"
-            aria-label="Form with validation"
+            aria-label=use_i18n().text(MessageKey::FormValidationLabel)
         >
             {children.map(|c| c())}
         </div>
     }
 }
 
+/// What screen readers hear when a form shows `errors`
+pub fn form_error_announcement(catalog: &MessageCatalog, errors: &[FormError]) -> Option<String> {
+    let messages = errors
+        .iter()
+        .map(|error| error.message.trim())
+        .filter(|message| !message.is_empty())
+        .collect::<Vec<_>>();
+    if messages.is_empty() {
+        return None;
+    }
+    let args = MessageArgs::new()
+        .with("count", messages.len())
+        .with("fields", messages.join("; "));
+    Some(catalog.format(MessageKey::FormErrorSummary, &args))
+}
+
 /// Form Error Summary component
+///
+/// The errors are announced on the validation channel of the closest
+/// AnnouncerProvider when the summary appears.
 #[component]
 pub fn FormErrorSummary(
     #[prop(optional)] class: Option<String>,
@@ -55,6 +76,10 @@ pub fn FormErrorSummary(
     #[prop(optional)] show_form_errors: Option<bool>,
 ) -> impl IntoView {
     let errors = errors.unwrap_or_default();
+    use_announce_on(
+        Signal::stored(form_error_announcement(&use_i18n(), &errors)),
+        channels::VALIDATION,
+    );
     let show_field_errors = show_field_errors.unwrap_or(true);
     let show_form_errors = show_form_errors.unwrap_or(true);
 
@@ -67,9 +92,8 @@ pub fn FormErrorSummary(
         <div
             class=class
             style=style
-            role="alert"
-            aria-live="polite"
-            aria-label="Form errors"
+            role="group"
+            aria-label=use_i18n().text(MessageKey::FormValidationErrors)
         >
             {if !errors.is_empty() {
                 view! {
@@ -118,4 +142,25 @@ mod controls_tests {
         ];
         assert!(!errors.is_empty());
     }
+
+    #[test]
+    fn test_form_error_announcement() {
+        let error = |message: &str| FormError {
+            message: message.to_string(),
+            ..FormError::default()
+        };
+        let catalog = MessageCatalog::default();
+        assert_eq!(form_error_announcement(&catalog, &[]), None);
+        assert_eq!(
+            form_error_announcement(&catalog, &[error("Email is required")]),
+            Some("1 error: Email is required".to_string())
+        );
+        assert_eq!(
+            form_error_announcement(
+                &catalog,
+                &[error("Email is required"), error(""), error("Too short")]
+            ),
+            Some("2 errors: Email is required; Too short".to_string())
+        );
+    }
 }
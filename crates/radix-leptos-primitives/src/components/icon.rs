@@ -0,0 +1,305 @@
+use crate::theming::{usecurrent_size, Size};
+use crate::utils::merge_classes;
+use leptos::children::Children;
+use leptos::prelude::*;
+use std::collections::HashMap;
+
+/// SVG content of an icon
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IconData {
+    pub view_box: String,
+    /// Markup inside the `<svg>` element
+    pub body: String,
+    /// Whether the shapes are filled rather than stroked
+    pub filled: bool,
+}
+
+impl IconData {
+    /// An outline icon on a 24 by 24 grid, drawn with 2px strokes
+    pub fn stroke(body: impl Into<String>) -> Self {
+        Self {
+            view_box: "0 0 24 24".to_string(),
+            body: body.into(),
+            filled: false,
+        }
+    }
+
+    /// A solid icon on a 24 by 24 grid
+    pub fn fill(body: impl Into<String>) -> Self {
+        Self {
+            filled: true,
+            ..Self::stroke(body)
+        }
+    }
+
+    pub fn with_view_box(mut self, view_box: impl Into<String>) -> Self {
+        self.view_box = view_box.into();
+        self
+    }
+}
+
+/// Name and stroke markup of a built-in icon
+type NamedIcon = (&'static str, &'static str);
+
+/// Named icons an [`Icon`] can show
+///
+/// The default set has the icons the components themselves use; an
+/// [`IconProvider`] adds an icon library's icons on top or replaces ones
+/// with the same name.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct IconSet {
+    icons: HashMap<String, IconData>,
+}
+
+impl IconSet {
+    /// A set without any icons
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The icons the components use, e.g. `check`, `x` and `chevron-down`
+    pub fn builtin() -> Self {
+        const BUILTIN: [NamedIcon; 12] = [
+            ("check", "<path d=\"M20 6 9 17l-5-5\"/>"),
+            ("x", "<path d=\"M18 6 6 18\"/><path d=\"m6 6 12 12\"/>"),
+            ("chevron-down", "<path d=\"m6 9 6 6 6-6\"/>"),
+            ("chevron-up", "<path d=\"m18 15-6-6-6 6\"/>"),
+            ("chevron-left", "<path d=\"m15 18-6-6 6-6\"/>"),
+            ("chevron-right", "<path d=\"m9 18 6-6-6-6\"/>"),
+            ("plus", "<path d=\"M5 12h14\"/><path d=\"M12 5v14\"/>"),
+            ("minus", "<path d=\"M5 12h14\"/>"),
+            (
+                "search",
+                "<circle cx=\"11\" cy=\"11\" r=\"8\"/><path d=\"m21 21-4.3-4.3\"/>",
+            ),
+            (
+                "info",
+                "<circle cx=\"12\" cy=\"12\" r=\"10\"/><path d=\"M12 16v-4\"/><path d=\"M12 8h.01\"/>",
+            ),
+            (
+                "alert-triangle",
+                "<path d=\"m21.73 18-8-14a2 2 0 0 0-3.48 0l-8 14A2 2 0 0 0 4 21h16a2 2 0 0 0 1.73-3\"/><path d=\"M12 9v4\"/><path d=\"M12 17h.01\"/>",
+            ),
+            (
+                "bell",
+                "<path d=\"M18 8a6 6 0 0 0-12 0c0 7-3 9-3 9h18s-3-2-3-9\"/><path d=\"M13.73 21a2 2 0 0 1-3.46 0\"/>",
+            ),
+        ];
+        BUILTIN.into_iter().fold(Self::new(), |set, (name, body)| {
+            set.with_icon(name, IconData::stroke(body))
+        })
+    }
+
+    /// Add an icon, replacing any other with the same name
+    pub fn with_icon(mut self, name: impl Into<String>, icon: IconData) -> Self {
+        self.icons.insert(name.into(), icon);
+        self
+    }
+
+    /// Add the icons of `other`, which win over icons with the same name
+    pub fn extend(&mut self, other: &IconSet) {
+        self.icons.extend(
+            other
+                .icons
+                .iter()
+                .map(|(name, icon)| (name.clone(), icon.clone())),
+        );
+    }
+
+    pub fn get(&self, name: &str) -> Option<&IconData> {
+        self.icons.get(name)
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.icons.contains_key(name)
+    }
+
+    pub fn len(&self) -> usize {
+        self.icons.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.icons.is_empty()
+    }
+}
+
+/// Icons available to everything below an IconProvider
+#[derive(Clone, Copy)]
+pub struct IconContext {
+    pub set: Signal<IconSet>,
+}
+
+/// Icon provider component
+///
+/// Makes the icons of `set` available by name to the [`Icon`]s below it,
+/// on top of the icons of any IconProvider further up and the built-in
+/// ones.
+#[component]
+pub fn IconProvider(
+    /// Icons to add
+    #[prop(into)]
+    set: Signal<IconSet>,
+    /// Children content
+    children: Option<Children>,
+) -> impl IntoView {
+    let parent = use_icon_set();
+    let merged = Signal::derive(move || {
+        let mut icons = parent.get();
+        set.with(|set| icons.extend(set));
+        icons
+    });
+    provide_context(IconContext { set: merged });
+
+    children.map(|c| c())
+}
+
+/// Hook for the icons of the closest IconProvider, or the built-in ones
+/// outside of any
+pub fn use_icon_set() -> Signal<IconSet> {
+    match use_context::<IconContext>() {
+        Some(context) => context.set,
+        None => Signal::derive(IconSet::builtin),
+    }
+}
+
+/// CSS color of an icon colored with `color`
+///
+/// Theme token names such as `primary-600` or `error` become their custom
+/// property, while CSS colors and `currentColor` are used as they are.
+pub fn icon_color(color: Option<&str>) -> String {
+    match color.map(str::trim) {
+        None | Some("") => "currentColor".to_string(),
+        Some(color)
+            if color == "currentColor"
+                || color.starts_with('#')
+                || color.contains('(')
+                || (color.chars().all(|c| c.is_ascii_alphabetic()) && !is_token(color)) =>
+        {
+            color.to_string()
+        }
+        Some(token) => format!("var(--{})", token.trim_start_matches("--")),
+    }
+}
+
+/// Single-word theme tokens, which would otherwise read as named colors
+fn is_token(color: &str) -> bool {
+    matches!(color, "success" | "warning" | "error" | "info")
+}
+
+/// Icon component - Named icon from the icon set
+///
+/// The icon is sized from the size-variant system, the closest
+/// SizeVariantProvider's size unless `size` is given, and drawn in the
+/// surrounding text color unless `color` names a theme token. Icons with a
+/// `label` are announced as images; others are decorative and hidden from
+/// screen readers.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn Saved() -> impl IntoView {
+///     view! { <Icon name="check" size=Size::Sm color="success" label="Saved".to_string() /> }
+/// }
+/// ```
+#[component]
+pub fn Icon(
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    /// Name of the icon in the icon set
+    #[prop(into)]
+    name: Signal<String>,
+    /// Size, the current size variant's by default
+    #[prop(optional, into)]
+    size: MaybeProp<Size>,
+    /// Theme token or CSS color, `currentColor` by default
+    #[prop(optional, into)]
+    color: MaybeProp<String>,
+    /// Accessible name, for icons that carry meaning on their own
+    #[prop(optional)]
+    label: Option<String>,
+) -> impl IntoView {
+    let icons = use_icon_set();
+    let context_size = usecurrent_size();
+    let size = move || {
+        size.get()
+            .or_else(|| context_size.map(|size| size.get()))
+            .unwrap_or_default()
+    };
+    let icon = Memo::new(move |_| name.with(|name| icons.with(|icons| icons.get(name).cloned())));
+    let filled = move || icon.with(|icon| icon.as_ref().is_some_and(|icon| icon.filled));
+    let color = move || icon_color(color.get().as_deref());
+
+    let class = move || merge_classes(vec!["icon", size().class(), class.as_deref().unwrap_or("")]);
+    let style = move || {
+        format!(
+            "flex-shrink: 0; color: {}; {}",
+            color(),
+            style.as_deref().unwrap_or("")
+        )
+    };
+    let has_label = label.is_some();
+
+    view! {
+        <svg
+            xmlns="http://www.w3.org/2000/svg"
+            class=class
+            style=style
+            width=move || size().icon_size()
+            height=move || size().icon_size()
+            viewBox=move || icon.with(|icon| icon.as_ref().map(|icon| icon.view_box.clone()))
+            fill=move || if filled() { "currentColor" } else { "none" }
+            stroke=move || if filled() { "none" } else { "currentColor" }
+            stroke-width="2"
+            stroke-linecap="round"
+            stroke-linejoin="round"
+            role=has_label.then_some("img")
+            aria-label=label
+            aria-hidden=(!has_label).then_some("true")
+            data-icon=move || name.get()
+            data-missing=move || icon.with(Option::is_none)
+            inner_html=move || icon.with(|icon| icon.as_ref().map(|icon| icon.body.clone()).unwrap_or_default())
+        ></svg>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_icon_sets() {
+        let builtin = IconSet::builtin();
+        assert!(builtin.contains("chevron-down"));
+        assert!(!builtin.get("check").unwrap().filled);
+
+        let mut icons = builtin.clone();
+        icons.extend(
+            &IconSet::new()
+                .with_icon("check", IconData::fill("<path d=\"M0 0h24v24H0z\"/>"))
+                .with_icon(
+                    "logo",
+                    IconData::fill("<circle r=\"8\"/>").with_view_box("-8 -8 16 16"),
+                ),
+        );
+        assert_eq!(icons.len(), builtin.len() + 1);
+        assert!(icons.get("check").unwrap().filled);
+        assert_eq!(icons.get("logo").unwrap().view_box, "-8 -8 16 16");
+        assert_eq!(icons.get("x"), builtin.get("x"));
+    }
+
+    #[test]
+    fn test_icon_color() {
+        assert_eq!(icon_color(None), "currentColor");
+        assert_eq!(icon_color(Some("currentColor")), "currentColor");
+        assert_eq!(icon_color(Some("primary-600")), "var(--primary-600)");
+        assert_eq!(icon_color(Some("--neutral-500")), "var(--neutral-500)");
+        assert_eq!(icon_color(Some("error")), "var(--error)");
+        assert_eq!(icon_color(Some("#ff0000")), "#ff0000");
+        assert_eq!(icon_color(Some("rgb(0 0 0)")), "rgb(0 0 0)");
+        assert_eq!(icon_color(Some("rebeccapurple")), "rebeccapurple");
+    }
+}
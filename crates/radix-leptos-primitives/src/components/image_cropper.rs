@@ -0,0 +1,517 @@
+use crate::components::watermark::{create_canvas, rotated_bounds};
+use crate::i18n::{use_i18n, MessageKey};
+use crate::utils::{generate_id, merge_classes};
+use leptos::callback::Callback;
+use leptos::html;
+use leptos::prelude::*;
+use radix_leptos_core::VisuallyHidden;
+use std::collections::HashMap;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+
+/// Width and height in pixels
+pub type CropSize = (f64, f64);
+
+/// Offset of the image centre from the frame centre in pixels
+pub type CropPan = (f64, f64);
+
+/// Region of the image inside the crop frame, in pixels of the source image
+/// rotated by the crop rotation
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct CropArea {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+/// Largest frame of the given aspect ratio that fits the viewport with a
+/// margin; without an aspect ratio the frame starts at the same margin
+pub fn crop_frame_size(viewport: CropSize, aspect: Option<f64>) -> CropSize {
+    let (width, height) = (viewport.0 * 0.9, viewport.1 * 0.9);
+    match aspect.filter(|aspect| aspect.is_finite() && *aspect > 0.0) {
+        Some(aspect) if width / height > aspect => (height * aspect, height),
+        Some(aspect) => (width, width / aspect),
+        None => (width, height),
+    }
+}
+
+/// Scale at which the rotated image just covers the frame at zoom 1
+pub fn crop_cover_scale(image: CropSize, rotation: f64, frame: CropSize) -> f64 {
+    let (width, height) = rotated_bounds(image.0, image.1, rotation);
+    if width <= 0.0 || height <= 0.0 {
+        return 1.0;
+    }
+    (frame.0 / width).max(frame.1 / height)
+}
+
+/// Keep the frame inside the displayed image
+pub fn clamp_crop_pan(pan: CropPan, displayed: CropSize, frame: CropSize) -> CropPan {
+    let max_x = ((displayed.0 - frame.0) / 2.0).max(0.0);
+    let max_y = ((displayed.1 - frame.1) / 2.0).max(0.0);
+    (pan.0.clamp(-max_x, max_x), pan.1.clamp(-max_y, max_y))
+}
+
+/// Source pixels inside the frame for an image of size `image`
+///
+/// `pan` is expected to be clamped with [`clamp_crop_pan`].
+pub fn crop_area(
+    image: CropSize,
+    frame: CropSize,
+    zoom: f64,
+    rotation: f64,
+    pan: CropPan,
+) -> CropArea {
+    let scale = crop_cover_scale(image, rotation, frame) * zoom;
+    let (width, height) = rotated_bounds(image.0, image.1, rotation);
+    CropArea {
+        x: ((width * scale - frame.0) / 2.0 - pan.0) / scale,
+        y: ((height * scale - frame.1) / 2.0 - pan.1) / scale,
+        width: frame.0 / scale,
+        height: frame.1 / scale,
+    }
+}
+
+/// Frame size after dragging a corner handle by `delta`
+///
+/// The frame stays centred, so it grows by twice the drag distance.
+pub fn resize_crop_frame(frame: CropSize, delta: CropPan, viewport: CropSize) -> CropSize {
+    const MIN_SIZE: f64 = 32.0;
+    (
+        (frame.0 + delta.0 * 2.0).clamp(MIN_SIZE.min(viewport.0), viewport.0),
+        (frame.1 + delta.1 * 2.0).clamp(MIN_SIZE.min(viewport.1), viewport.1),
+    )
+}
+
+/// Draw the cropped region of a loaded image to a canvas and hand it to
+/// `on_blob` as an image of type `mime`, e.g. "image/png"
+///
+/// The image must be same-origin or served with CORS headers, otherwise
+/// the canvas is tainted and no blob is produced.
+pub fn export_cropped_image(
+    image: &web_sys::HtmlImageElement,
+    area: CropArea,
+    rotation: f64,
+    mime: &str,
+    on_blob: impl FnOnce(web_sys::Blob) + 'static,
+) -> Option<()> {
+    // Rotate the whole image first so the area's coordinates line up
+    let (natural_width, natural_height) =
+        (image.natural_width() as f64, image.natural_height() as f64);
+    let (width, height) = rotated_bounds(natural_width, natural_height, rotation);
+    let (rotated, context) = create_canvas(width.round(), height.round())?;
+    context.translate(width / 2.0, height / 2.0).ok()?;
+    context.rotate(rotation.to_radians()).ok()?;
+    context
+        .draw_image_with_html_image_element(image, -natural_width / 2.0, -natural_height / 2.0)
+        .ok()?;
+
+    let (output, context) = create_canvas(area.width.round(), area.height.round())?;
+    context
+        .draw_image_with_html_canvas_element_and_sw_and_sh_and_dx_and_dy_and_dw_and_dh(
+            &rotated,
+            area.x,
+            area.y,
+            area.width,
+            area.height,
+            0.0,
+            0.0,
+            area.width,
+            area.height,
+        )
+        .ok()?;
+
+    let callback = Closure::once_into_js(move |blob: Option<web_sys::Blob>| {
+        if let Some(blob) = blob {
+            on_blob(blob);
+        }
+    });
+    output
+        .to_blob_with_type(callback.unchecked_ref(), mime)
+        .ok()
+}
+
+/// ImageCropper component - Interactive image crop tool
+///
+/// The crop frame stays centred while the image is dragged, zoomed and
+/// rotated beneath it. Dragging works with mouse, pen and touch, and two
+/// fingers pinch to zoom. The arrow keys pan, `+` and `-` zoom, and `[`
+/// and `]` rotate. With an `aspect` ratio the frame keeps that shape;
+/// without one it can be resized from its corner.
+///
+/// The selected region is reported through `on_crop_change` in source
+/// image pixels. With `on_export` a "Crop" button renders the region to a
+/// canvas and passes the resulting image blob.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn AvatarEditor() -> impl IntoView {
+///     view! {
+///         <ImageCropper
+///             src="/photo.jpg".to_string()
+///             aspect=1.0
+///             on_crop_change=Callback::new(|area: CropArea| log::info!("{:?}", area))
+///         />
+///     }
+/// }
+/// ```
+#[component]
+pub fn ImageCropper(
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    src: String,
+    #[prop(optional)] alt: Option<String>,
+    /// Width divided by height of the frame; the frame is free when unset
+    #[prop(optional)]
+    aspect: Option<f64>,
+    /// Viewport size in pixels (default: 400×300)
+    #[prop(optional)]
+    width: Option<f64>,
+    #[prop(optional)] height: Option<f64>,
+    #[prop(optional)] zoom: Option<RwSignal<f64>>,
+    /// Rotation in degrees
+    #[prop(optional)]
+    rotation: Option<RwSignal<f64>>,
+    #[prop(optional)] min_zoom: Option<f64>,
+    #[prop(optional)] max_zoom: Option<f64>,
+    /// Render zoom and rotate controls (default: true)
+    #[prop(optional)]
+    show_controls: Option<bool>,
+    /// Accessible name of the crop area (default: "Crop image")
+    #[prop(optional)]
+    label: Option<String>,
+    #[prop(optional)] on_crop_change: Option<Callback<CropArea>>,
+    /// Called with the cropped image; enables the "Crop" button
+    #[prop(optional)]
+    on_export: Option<Callback<web_sys::Blob>>,
+    /// Type of the exported image (default: "image/png")
+    #[prop(optional)]
+    export_type: Option<String>,
+) -> impl IntoView {
+    let viewport = (width.unwrap_or(400.0), height.unwrap_or(300.0));
+    let min_zoom = min_zoom.unwrap_or(1.0);
+    let max_zoom = max_zoom.unwrap_or(3.0).max(min_zoom);
+    let zoom = zoom.unwrap_or_else(|| RwSignal::new(min_zoom));
+    let rotation = rotation.unwrap_or_else(|| RwSignal::new(0.0));
+    let show_controls = show_controls.unwrap_or(true);
+    let catalog = use_i18n();
+    let label = label.unwrap_or_else(|| catalog.text(MessageKey::ImageCropperLabel));
+    let export_type = StoredValue::new(export_type.unwrap_or_else(|| "image/png".to_string()));
+    let instructions_id = generate_id("image-cropper-instructions");
+    let describedby = instructions_id.clone();
+
+    let image_ref = NodeRef::<html::Img>::new();
+    let natural = RwSignal::new(None::<CropSize>);
+    let frame = RwSignal::new(crop_frame_size(viewport, aspect));
+    let raw_pan = RwSignal::new((0.0, 0.0));
+    let pointers = StoredValue::new(HashMap::<i32, CropPan>::new());
+    let resizing = StoredValue::new(None::<CropPan>);
+
+    let scale = Memo::new(move |_| {
+        natural
+            .get()
+            .map(|image| crop_cover_scale(image, rotation.get(), frame.get()) * zoom.get())
+    });
+    let pan = Memo::new(move |_| {
+        let (Some(image), Some(scale)) = (natural.get(), scale.get()) else {
+            return (0.0, 0.0);
+        };
+        let (width, height) = rotated_bounds(image.0, image.1, rotation.get());
+        clamp_crop_pan(raw_pan.get(), (width * scale, height * scale), frame.get())
+    });
+    let area = Memo::new(move |_| {
+        natural
+            .get()
+            .map(|image| crop_area(image, frame.get(), zoom.get(), rotation.get(), pan.get()))
+    });
+    Effect::new(move |_| {
+        if let (Some(area), Some(on_crop_change)) = (area.get(), on_crop_change) {
+            on_crop_change.run(area);
+        }
+    });
+
+    let set_zoom = move |next: f64| zoom.set(next.clamp(min_zoom, max_zoom));
+    let pan_by = move |dx: f64, dy: f64| {
+        let (x, y) = pan.get_untracked();
+        raw_pan.set((x + dx, y + dy));
+    };
+
+    let handle_pointerdown = move |ev: web_sys::PointerEvent| {
+        if let Some(target) = ev
+            .current_target()
+            .and_then(|t| t.dyn_into::<web_sys::Element>().ok())
+        {
+            let _ = target.set_pointer_capture(ev.pointer_id());
+        }
+        pointers.update_value(|pointers| {
+            pointers.insert(
+                ev.pointer_id(),
+                (ev.client_x() as f64, ev.client_y() as f64),
+            );
+        });
+    };
+    let handle_pointermove = move |ev: web_sys::PointerEvent| {
+        let position = (ev.client_x() as f64, ev.client_y() as f64);
+        let Some(previous) =
+            pointers.with_value(|pointers| pointers.get(&ev.pointer_id()).copied())
+        else {
+            return;
+        };
+        let others = pointers.with_value(|pointers| {
+            pointers
+                .iter()
+                .filter(|(id, _)| **id != ev.pointer_id())
+                .map(|(_, position)| *position)
+                .collect::<Vec<_>>()
+        });
+        match others.as_slice() {
+            [] => pan_by(position.0 - previous.0, position.1 - previous.1),
+            // Pinch: zoom by the change in distance between two fingers
+            [other] => {
+                let distance = |a: CropPan| (a.0 - other.0).hypot(a.1 - other.1);
+                let (before, after) = (distance(previous), distance(position));
+                if before > 0.0 {
+                    set_zoom(zoom.get_untracked() * after / before);
+                }
+            }
+            _ => {}
+        }
+        pointers.update_value(|pointers| {
+            pointers.insert(ev.pointer_id(), position);
+        });
+    };
+    let handle_pointerup = move |ev: web_sys::PointerEvent| {
+        pointers.update_value(|pointers| {
+            pointers.remove(&ev.pointer_id());
+        });
+    };
+    let handle_wheel = move |ev: web_sys::WheelEvent| {
+        ev.prevent_default();
+        set_zoom(zoom.get_untracked() * (-ev.delta_y() * 0.001).exp());
+    };
+    let handle_keydown = move |ev: web_sys::KeyboardEvent| {
+        match ev.key().as_str() {
+            "ArrowLeft" => pan_by(10.0, 0.0),
+            "ArrowRight" => pan_by(-10.0, 0.0),
+            "ArrowUp" => pan_by(0.0, 10.0),
+            "ArrowDown" => pan_by(0.0, -10.0),
+            "+" | "=" => set_zoom(zoom.get_untracked() + 0.1),
+            "-" => set_zoom(zoom.get_untracked() - 0.1),
+            "[" => rotation.update(|rotation| *rotation -= 90.0),
+            "]" => rotation.update(|rotation| *rotation += 90.0),
+            _ => return,
+        }
+        ev.prevent_default();
+    };
+
+    let handle_resize_down = move |ev: web_sys::PointerEvent| {
+        ev.stop_propagation();
+        if let Some(target) = ev
+            .current_target()
+            .and_then(|t| t.dyn_into::<web_sys::Element>().ok())
+        {
+            let _ = target.set_pointer_capture(ev.pointer_id());
+        }
+        resizing.set_value(Some((ev.client_x() as f64, ev.client_y() as f64)));
+    };
+    let handle_resize_move = move |ev: web_sys::PointerEvent| {
+        ev.stop_propagation();
+        let Some(start) = resizing.get_value() else {
+            return;
+        };
+        let position = (ev.client_x() as f64, ev.client_y() as f64);
+        frame.update(|frame| {
+            *frame = resize_crop_frame(
+                *frame,
+                (position.0 - start.0, position.1 - start.1),
+                viewport,
+            )
+        });
+        resizing.set_value(Some(position));
+    };
+
+    let export = move |_| {
+        let (Some(on_export), Some(area), Some(image)) =
+            (on_export, area.get_untracked(), image_ref.get_untracked())
+        else {
+            return;
+        };
+        export_type.with_value(|mime| {
+            export_cropped_image(&image, area, rotation.get_untracked(), mime, move |blob| {
+                on_export.run(blob)
+            })
+        });
+    };
+
+    let image_style = move || match (natural.get(), scale.get()) {
+        (Some(image), Some(scale)) => {
+            let (x, y) = pan.get();
+            format!(
+                "position: absolute; left: 50%; top: 50%; max-width: none; width: {}px; height: {}px; \
+                 transform: translate(-50%, -50%) translate({}px, {}px) rotate({}deg); \
+                 user-select: none; pointer-events: none;",
+                image.0 * scale,
+                image.1 * scale,
+                x,
+                y,
+                rotation.get()
+            )
+        }
+        // Hidden until its natural size is known
+        _ => "position: absolute; visibility: hidden;".to_string(),
+    };
+    let frame_style = move || {
+        let (width, height) = frame.get();
+        format!(
+            "position: absolute; left: 50%; top: 50%; width: {}px; height: {}px; \
+             transform: translate(-50%, -50%); box-shadow: 0 0 0 9999px rgba(0, 0, 0, 0.5); \
+             outline: 1px solid rgba(255, 255, 255, 0.8); pointer-events: none;",
+            width, height
+        )
+    };
+
+    let instructions = catalog.text(MessageKey::ImageCropperInstructions);
+    let zoom_label = catalog.text(MessageKey::Zoom);
+    let rotate_left_label = catalog.text(MessageKey::ImageCropperRotateLeft);
+    let rotate_right_label = catalog.text(MessageKey::ImageCropperRotateRight);
+    let crop_label = catalog.text(MessageKey::ImageCropperCrop);
+
+    let class = merge_classes(vec!["image-cropper", class.as_deref().unwrap_or("")]);
+    let viewport_style = format!(
+        "position: relative; overflow: hidden; width: {}px; height: {}px; touch-action: none; cursor: move;",
+        viewport.0, viewport.1
+    );
+
+    view! {
+        <div class=class style=style>
+            <div
+                class="image-cropper-viewport"
+                style=viewport_style
+                tabindex="0"
+                role="group"
+                aria-roledescription=catalog.text(MessageKey::ImageCropperRole)
+                aria-label=label
+                aria-describedby=describedby
+                on:pointerdown=handle_pointerdown
+                on:pointermove=handle_pointermove
+                on:pointerup=handle_pointerup
+                on:pointercancel=handle_pointerup
+                on:wheel=handle_wheel
+                on:keydown=handle_keydown
+            >
+                <img
+                    src=src
+                    alt=alt.unwrap_or_default()
+                    crossorigin="anonymous"
+                    draggable="false"
+                    node_ref=image_ref
+                    style=image_style
+                    on:load=move |_| {
+                        if let Some(image) = image_ref.get_untracked() {
+                            natural.set(Some((image.natural_width() as f64, image.natural_height() as f64)));
+                        }
+                    }
+                />
+                <div class="image-cropper-frame" style=frame_style data-aspect-locked=aspect.is_some().to_string()>
+                    {aspect.is_none().then(|| view! {
+                        <div
+                            class="image-cropper-handle"
+                            aria-hidden="true"
+                            style="position: absolute; right: -6px; bottom: -6px; width: 12px; height: 12px; background: white; pointer-events: auto; cursor: nwse-resize;"
+                            on:pointerdown=handle_resize_down
+                            on:pointermove=handle_resize_move
+                            on:pointerup=move |_| resizing.set_value(None)
+                            on:pointercancel=move |_| resizing.set_value(None)
+                        ></div>
+                    })}
+                </div>
+                <VisuallyHidden>
+                    <span id=instructions_id>{instructions}</span>
+                </VisuallyHidden>
+            </div>
+            {show_controls.then(|| view! {
+                <div class="image-cropper-controls">
+                    <input
+                        type="range"
+                        class="image-cropper-zoom"
+                        aria-label=zoom_label
+                        min=min_zoom
+                        max=max_zoom
+                        step="0.01"
+                        prop:value=move || zoom.get().to_string()
+                        on:input=move |ev| {
+                            if let Ok(next) = event_target_value(&ev).parse::<f64>() {
+                                set_zoom(next);
+                            }
+                        }
+                    />
+                    <button type="button" class="image-cropper-rotate" on:click=move |_| rotation.update(|r| *r -= 90.0)>
+                        {rotate_left_label}
+                    </button>
+                    <button type="button" class="image-cropper-rotate" on:click=move |_| rotation.update(|r| *r += 90.0)>
+                        {rotate_right_label}
+                    </button>
+                    {on_export.is_some().then(|| view! {
+                        <button type="button" class="image-cropper-export" disabled=move || natural.get().is_none() on:click=export>
+                            {crop_label}
+                        </button>
+                    })}
+                </div>
+            })}
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn test_frame_fits_aspect() {
+        assert_eq!(crop_frame_size((400.0, 300.0), Some(1.0)), (270.0, 270.0));
+        let (width, height) = crop_frame_size((400.0, 300.0), Some(16.0 / 9.0));
+        assert!(approx(width, 360.0) && approx(height, 202.5));
+        assert_eq!(crop_frame_size((400.0, 300.0), None), (360.0, 270.0));
+    }
+
+    #[test]
+    fn test_crop_area_at_rest_is_centred() {
+        // 1000×500 image covering a 200×200 frame: scale 0.4
+        let area = crop_area((1000.0, 500.0), (200.0, 200.0), 1.0, 0.0, (0.0, 0.0));
+        assert!(approx(area.width, 500.0) && approx(area.height, 500.0));
+        assert!(approx(area.x, 250.0) && approx(area.y, 0.0));
+
+        // Zooming in halves the area, panning right shows more of the left
+        let area = crop_area((1000.0, 500.0), (200.0, 200.0), 2.0, 0.0, (40.0, 0.0));
+        assert!(approx(area.width, 250.0));
+        assert!(approx(area.x, 375.0 - 50.0));
+    }
+
+    #[test]
+    fn test_rotation_swaps_bounds() {
+        let area = crop_area((1000.0, 500.0), (200.0, 200.0), 1.0, 90.0, (0.0, 0.0));
+        assert!(approx(area.width, 500.0));
+        assert!(approx(area.x, 0.0) && approx(area.y, 250.0));
+    }
+
+    #[test]
+    fn test_pan_and_resize_are_clamped() {
+        assert_eq!(
+            clamp_crop_pan((500.0, -500.0), (400.0, 200.0), (200.0, 200.0)),
+            (100.0, 0.0)
+        );
+        assert_eq!(
+            resize_crop_frame((200.0, 200.0), (150.0, -200.0), (400.0, 300.0)),
+            (400.0, 32.0)
+        );
+    }
+}
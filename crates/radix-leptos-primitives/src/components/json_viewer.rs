@@ -0,0 +1,696 @@
+use crate::i18n::{use_i18n, MessageArgs, MessageKey};
+use crate::utils::{generate_id, merge_classes};
+use leptos::html;
+use leptos::prelude::*;
+use leptos::serde_json::{self, Value};
+use radix_leptos_core::use_clipboard;
+use std::collections::HashSet;
+use std::ops::Range;
+
+/// Rows rendered above and below the visible part of the tree
+const OVERSCAN_ROWS: usize = 10;
+
+/// Step in a path from the root of a JSON document
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum JsonPathSegment {
+    Key(String),
+    Index(usize),
+}
+
+/// What a row of the tree shows after its key
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonRowValue {
+    /// Object or array with `len` entries
+    Container {
+        array: bool,
+        len: usize,
+        expanded: bool,
+    },
+    /// Rendered scalar, e.g. `"text"`, `42` or `null`, and its JSON type
+    Scalar { text: String, kind: &'static str },
+}
+
+/// Run of text and whether it matches the search query
+pub type JsonHighlightRun = (String, bool);
+
+/// Child of a container and the path segment leading to it
+type JsonEntry<'a> = (JsonPathSegment, &'a Value);
+
+/// Nodes matching a search and the containers to open to reveal them
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct JsonSearchResult {
+    /// Paths of the matching nodes in document order
+    pub matches: Vec<String>,
+    pub expand: HashSet<String>,
+}
+
+/// One visible line of a flattened JSON tree
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonRow {
+    /// JavaScript-style path, e.g. `$.users[0].name`
+    pub path: String,
+    pub depth: usize,
+    /// Object key or array index; `None` for the root
+    pub label: Option<String>,
+    /// 1-based position among siblings, and their count
+    pub position: (usize, usize),
+    pub value: JsonRowValue,
+}
+
+/// JavaScript-style accessor for a path, quoting keys that are not
+/// identifiers
+pub fn json_path_string(segments: &[JsonPathSegment]) -> String {
+    let mut path = String::from("$");
+    for segment in segments {
+        match segment {
+            JsonPathSegment::Index(index) => path.push_str(&format!("[{}]", index)),
+            JsonPathSegment::Key(key) if is_identifier(key) => {
+                path.push('.');
+                path.push_str(key);
+            }
+            JsonPathSegment::Key(key) => {
+                let quoted = serde_json::to_string(key).unwrap_or_default();
+                path.push_str(&format!("[{}]", quoted));
+            }
+        }
+    }
+    path
+}
+
+fn is_identifier(key: &str) -> bool {
+    let mut chars = key.chars();
+    chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_' || first == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
+
+fn scalar_row_value(value: &Value) -> JsonRowValue {
+    let kind = match value {
+        Value::String(_) => "string",
+        Value::Number(_) => "number",
+        Value::Bool(_) => "boolean",
+        _ => "null",
+    };
+    JsonRowValue::Scalar {
+        text: value.to_string(),
+        kind,
+    }
+}
+
+/// Entries of a container with the path segment leading to each
+fn json_children(value: &Value) -> Vec<JsonEntry<'_>> {
+    match value {
+        Value::Object(map) => map
+            .iter()
+            .map(|(key, child)| (JsonPathSegment::Key(key.clone()), child))
+            .collect(),
+        Value::Array(items) => items
+            .iter()
+            .enumerate()
+            .map(|(index, child)| (JsonPathSegment::Index(index), child))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Rows for every node that is visible with the containers in `expanded`
+/// (by path) open
+pub fn flatten_json(value: &Value, expanded: &HashSet<String>) -> Vec<JsonRow> {
+    fn visit(
+        value: &Value,
+        segments: &mut Vec<JsonPathSegment>,
+        position: (usize, usize),
+        expanded: &HashSet<String>,
+        rows: &mut Vec<JsonRow>,
+    ) {
+        let path = json_path_string(segments);
+        let label = segments.last().map(|segment| match segment {
+            JsonPathSegment::Key(key) => key.clone(),
+            JsonPathSegment::Index(index) => index.to_string(),
+        });
+        let children = json_children(value);
+        let is_container = matches!(value, Value::Object(_) | Value::Array(_));
+        let open = is_container && expanded.contains(&path);
+        let row_value = if is_container {
+            JsonRowValue::Container {
+                array: value.is_array(),
+                len: children.len(),
+                expanded: open,
+            }
+        } else {
+            scalar_row_value(value)
+        };
+        rows.push(JsonRow {
+            path,
+            depth: segments.len(),
+            label,
+            position,
+            value: row_value,
+        });
+        if open {
+            let count = children.len();
+            for (index, (segment, child)) in children.into_iter().enumerate() {
+                segments.push(segment);
+                visit(child, segments, (index + 1, count), expanded, rows);
+                segments.pop();
+            }
+        }
+    }
+
+    let mut rows = Vec::new();
+    visit(value, &mut Vec::new(), (1, 1), expanded, &mut rows);
+    rows
+}
+
+/// Paths of the containers up to `max_depth` levels deep, used as the
+/// initially expanded set
+pub fn json_expanded_to_depth(value: &Value, max_depth: usize) -> HashSet<String> {
+    fn visit(
+        value: &Value,
+        segments: &mut Vec<JsonPathSegment>,
+        max_depth: usize,
+        paths: &mut HashSet<String>,
+    ) {
+        if segments.len() >= max_depth || !matches!(value, Value::Object(_) | Value::Array(_)) {
+            return;
+        }
+        paths.insert(json_path_string(segments));
+        for (segment, child) in json_children(value) {
+            segments.push(segment);
+            visit(child, segments, max_depth, paths);
+            segments.pop();
+        }
+    }
+
+    let mut paths = HashSet::new();
+    visit(value, &mut Vec::new(), max_depth, &mut paths);
+    paths
+}
+
+/// Paths of the nodes whose key or scalar value contains `query`, ignoring
+/// case, and of every container that has to be open to show them
+pub fn json_search(value: &Value, query: &str) -> JsonSearchResult {
+    fn visit(
+        value: &Value,
+        segments: &mut Vec<JsonPathSegment>,
+        query: &str,
+        matches: &mut Vec<String>,
+        ancestors: &mut HashSet<String>,
+    ) {
+        let key_matches = match segments.last() {
+            Some(JsonPathSegment::Key(key)) => key.to_lowercase().contains(query),
+            _ => false,
+        };
+        let value_matches = !matches!(value, Value::Object(_) | Value::Array(_))
+            && value.to_string().to_lowercase().contains(query);
+        if key_matches || value_matches {
+            matches.push(json_path_string(segments));
+            for depth in 0..segments.len() {
+                ancestors.insert(json_path_string(&segments[..depth]));
+            }
+        }
+        for (segment, child) in json_children(value) {
+            segments.push(segment);
+            visit(child, segments, query, matches, ancestors);
+            segments.pop();
+        }
+    }
+
+    let mut matches = Vec::new();
+    let mut ancestors = HashSet::new();
+    let query = query.trim().to_lowercase();
+    if !query.is_empty() {
+        visit(value, &mut Vec::new(), &query, &mut matches, &mut ancestors);
+    }
+    JsonSearchResult {
+        matches,
+        expand: ancestors,
+    }
+}
+
+/// Splits `text` into runs, flagging those that match `query` ignoring case
+pub fn json_highlight(text: &str, query: &str) -> Vec<JsonHighlightRun> {
+    let fold = |c: char| c.to_lowercase().next().unwrap_or(c);
+    let chars: Vec<char> = text.chars().collect();
+    let needle: Vec<char> = query.trim().chars().map(fold).collect();
+    if needle.is_empty() {
+        return vec![(text.to_string(), false)];
+    }
+
+    let mut runs: Vec<JsonHighlightRun> = Vec::new();
+    let mut push = |run: String, highlighted: bool| match runs.last_mut() {
+        Some((last, flag)) if *flag == highlighted => last.push_str(&run),
+        _ if !run.is_empty() => runs.push((run, highlighted)),
+        _ => {}
+    };
+    let mut index = 0;
+    while index < chars.len() {
+        let window = chars.get(index..index + needle.len());
+        if window.is_some_and(|window| window.iter().map(|c| fold(*c)).eq(needle.iter().copied())) {
+            push(chars[index..index + needle.len()].iter().collect(), true);
+            index += needle.len();
+        } else {
+            push(chars[index].to_string(), false);
+            index += 1;
+        }
+    }
+    runs
+}
+
+/// Rows to render for a viewport scrolled to `scroll_top`
+pub fn json_visible_rows(
+    scroll_top: f64,
+    viewport_height: f64,
+    row_height: f64,
+    total: usize,
+) -> Range<usize> {
+    if row_height <= 0.0 || total == 0 {
+        return 0..0;
+    }
+    let first = (scroll_top.max(0.0) / row_height).floor() as usize;
+    let visible = (viewport_height.max(0.0) / row_height).ceil() as usize;
+    let start = first.saturating_sub(OVERSCAN_ROWS).min(total);
+    let end = (first + visible + OVERSCAN_ROWS).min(total);
+    start..end
+}
+
+/// Scroll offset that brings row `index` into view, if it is not already
+pub fn json_scroll_to_row(
+    scroll_top: f64,
+    viewport_height: f64,
+    row_height: f64,
+    index: usize,
+) -> Option<f64> {
+    let top = index as f64 * row_height;
+    if top < scroll_top {
+        Some(top)
+    } else if top + row_height > scroll_top + viewport_height {
+        Some(top + row_height - viewport_height)
+    } else {
+        None
+    }
+}
+
+fn highlighted(text: String, query: String) -> impl IntoView {
+    json_highlight(&text, &query)
+        .into_iter()
+        .map(|(run, hit)| {
+            if hit {
+                view! { <mark>{run}</mark> }.into_any()
+            } else {
+                run.into_any()
+            }
+        })
+        .collect_view()
+}
+
+/// JsonViewer component - Collapsible, searchable tree of a JSON value
+///
+/// Only the rows in view are rendered, so large payloads stay responsive.
+/// The tree is a single tab stop: arrow keys move between rows and open or
+/// close containers, Home/End jump to the ends, Enter toggles and C copies
+/// the path of the active row (e.g. `$.users[0].email`). Searching
+/// highlights matching keys and values and expands their parents.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use leptos::serde_json::json;
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn Payload() -> impl IntoView {
+///     let value = json!({ "user": { "name": "Ada", "roles": ["admin"] } });
+///     view! { <JsonViewer value=value label="Response body".to_string() /> }
+/// }
+/// ```
+#[component]
+pub fn JsonViewer(
+    #[prop(into)] value: Signal<Value>,
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    /// Accessible name of the tree (default: "JSON")
+    #[prop(optional)]
+    label: Option<String>,
+    /// Levels expanded initially (default: 1)
+    #[prop(optional)]
+    expand_depth: Option<usize>,
+    /// Height of the scrolling tree in pixels (default: 400)
+    #[prop(optional)]
+    height: Option<f64>,
+    /// Height of each row in pixels (default: 24)
+    #[prop(optional)]
+    row_height: Option<f64>,
+    /// Called with the path copied to the clipboard
+    #[prop(optional)]
+    on_copy_path: Option<Callback<String>>,
+) -> impl IntoView {
+    let viewport_height = height.unwrap_or(400.0);
+    let row_height = row_height.unwrap_or(24.0);
+    let catalog = StoredValue::new(use_i18n());
+    let label = label
+        .unwrap_or_else(|| catalog.with_value(|catalog| catalog.text(MessageKey::JsonViewerLabel)));
+    let tree_id = generate_id("json-viewer");
+    let viewport_ref = NodeRef::<html::Div>::new();
+
+    let expanded = RwSignal::new(
+        value.with_untracked(|value| json_expanded_to_depth(value, expand_depth.unwrap_or(1))),
+    );
+    let query = RwSignal::new(String::new());
+    let match_count = RwSignal::new(0usize);
+    let active = RwSignal::new(0usize);
+    let scroll_top = RwSignal::new(0.0);
+    let status = RwSignal::new(String::new());
+
+    let rows = Memo::new(move |_| {
+        expanded.with(|expanded| value.with(|value| flatten_json(value, expanded)))
+    });
+
+    let scroll_to = move |index: usize| {
+        active.set(index);
+        if let Some(top) = json_scroll_to_row(
+            scroll_top.get_untracked(),
+            viewport_height,
+            row_height,
+            index,
+        ) {
+            if let Some(viewport) = viewport_ref.get_untracked() {
+                viewport.set_scroll_top(top as i32);
+            }
+            scroll_top.set(top);
+        }
+    };
+    let set_open = move |path: &str, open: bool| {
+        expanded.update(|expanded| {
+            if open {
+                expanded.insert(path.to_string());
+            } else {
+                expanded.remove(path);
+            }
+        });
+    };
+    let clipboard = use_clipboard();
+    let copy_path = move |path: String| {
+        clipboard.copy(path.clone());
+        status.set(catalog.with_value(|catalog| {
+            catalog.format(
+                MessageKey::JsonViewerCopied,
+                &MessageArgs::new().with("path", path.as_str()),
+            )
+        }));
+        if let Some(on_copy_path) = on_copy_path {
+            on_copy_path.run(path);
+        }
+    };
+
+    let handle_search = move |e: web_sys::Event| {
+        let text = event_target_value(&e);
+        let JsonSearchResult { matches, expand } =
+            value.with_untracked(|value| json_search(value, &text));
+        expanded.update(|expanded| expanded.extend(expand));
+        match_count.set(matches.len());
+        status.set(if text.trim().is_empty() {
+            String::new()
+        } else {
+            format!("{} matches", matches.len())
+        });
+        if let Some(first) = matches.first() {
+            if let Some(index) =
+                rows.with_untracked(|rows| rows.iter().position(|row| &row.path == first))
+            {
+                scroll_to(index);
+            }
+        }
+        query.set(text);
+    };
+
+    let handle_keydown = move |e: web_sys::KeyboardEvent| {
+        let count = rows.with_untracked(Vec::len);
+        if count == 0 {
+            return;
+        }
+        let index = active.get_untracked().min(count - 1);
+        let Some(row) = rows.with_untracked(|rows| rows.get(index).cloned()) else {
+            return;
+        };
+        let container = match row.value {
+            JsonRowValue::Container { len, expanded, .. } => Some((len, expanded)),
+            JsonRowValue::Scalar { .. } => None,
+        };
+        match e.key().as_str() {
+            "ArrowDown" => scroll_to((index + 1).min(count - 1)),
+            "ArrowUp" => scroll_to(index.saturating_sub(1)),
+            "Home" => scroll_to(0),
+            "End" => scroll_to(count - 1),
+            "ArrowRight" => match container {
+                Some((_, false)) => set_open(&row.path, true),
+                Some((len, true)) if len > 0 => scroll_to(index + 1),
+                _ => {}
+            },
+            "ArrowLeft" => match container {
+                Some((_, true)) => set_open(&row.path, false),
+                _ => {
+                    // Move to the parent row
+                    let parent = rows.with_untracked(|rows| {
+                        rows[..index]
+                            .iter()
+                            .rposition(|other| other.depth + 1 == row.depth)
+                    });
+                    if let Some(parent) = parent {
+                        scroll_to(parent);
+                    }
+                }
+            },
+            "Enter" | " " => {
+                if let Some((_, open)) = container {
+                    set_open(&row.path, !open);
+                }
+            }
+            "c" | "C" if !e.ctrl_key() && !e.meta_key() => copy_path(row.path.clone()),
+            _ => return,
+        }
+        e.prevent_default();
+    };
+
+    let row_id = {
+        let tree_id = tree_id.clone();
+        move |index: usize| format!("{}-row-{}", tree_id, index)
+    };
+    let active_descendant = {
+        let row_id = row_id.clone();
+        move || (rows.with(|rows| !rows.is_empty())).then(|| row_id(active.get()))
+    };
+
+    let visible_rows = move || {
+        let range = rows.with(|rows| {
+            json_visible_rows(scroll_top.get(), viewport_height, row_height, rows.len())
+        });
+        let query = query.get();
+        let current = active.get();
+        rows.with(|rows| {
+            rows[range.clone()]
+                .iter()
+                .cloned()
+                .zip(range.clone())
+                .map(|(row, index)| {
+                    let (toggle, value_view, expanded_attr, kind) = match &row.value {
+                        JsonRowValue::Container { array, len, expanded } => {
+                            let count = |key| {
+                                catalog.with_value(|catalog| {
+                                    catalog.format(key, &MessageArgs::new().with("count", *len))
+                                })
+                            };
+                            let summary = match (array, expanded) {
+                                (true, true) => "[".to_string(),
+                                (false, true) => "{".to_string(),
+                                (true, false) => {
+                                    format!("[…] {}", count(MessageKey::JsonViewerArrayItems))
+                                }
+                                (false, false) => {
+                                    format!("{{…}} {}", count(MessageKey::JsonViewerObjectKeys))
+                                }
+                            };
+                            (
+                                if *expanded { "▾" } else { "▸" },
+                                summary.into_any(),
+                                Some(expanded.to_string()),
+                                if *array { "array" } else { "object" },
+                            )
+                        }
+                        JsonRowValue::Scalar { text, kind } => (
+                            "",
+                            highlighted(text.clone(), query.clone()).into_any(),
+                            None,
+                            *kind,
+                        ),
+                    };
+                    let path = row.path.clone();
+                    let toggle_path = row.path.clone();
+                    let is_container = expanded_attr.is_some();
+                    let open = expanded_attr.as_deref() == Some("true");
+                    view! {
+                        <div
+                            id=row_id(index)
+                            class="json-viewer-row"
+                            role="treeitem"
+                            attr:aria-level=(row.depth + 1).to_string()
+                            aria-setsize=row.position.1
+                            aria-posinset=row.position.0
+                            aria-expanded=expanded_attr
+                            aria-selected=(index == current).to_string()
+                            data-type=kind
+                            style=format!(
+                                "position: absolute; top: {}px; left: 0; right: 0; height: {}px; padding-inline-start: {}em;",
+                                index as f64 * row_height,
+                                row_height,
+                                row.depth,
+                            )
+                            on:click=move |_| {
+                                active.set(index);
+                                if is_container {
+                                    set_open(&toggle_path, !open);
+                                }
+                            }
+                        >
+                            <span class="json-viewer-toggle" aria-hidden="true">{toggle}</span>
+                            {row
+                                .label
+                                .map(|label| {
+                                    view! {
+                                        <span class="json-viewer-key">{highlighted(label, query.clone())}</span>
+                                        <span aria-hidden="true">": "</span>
+                                    }
+                                })}
+                            <span class="json-viewer-value">{value_view}</span>
+                            <button
+                                type="button"
+                                class="json-viewer-copy"
+                                tabindex="-1"
+                                aria-label=catalog
+                                    .with_value(|catalog| catalog.text(MessageKey::JsonViewerCopyPath))
+                                title=path.clone()
+                                on:click=move |e| {
+                                    e.stop_propagation();
+                                    copy_path(path.clone());
+                                }
+                            >
+                                <span aria-hidden="true">"⧉"</span>
+                            </button>
+                        </div>
+                    }
+                })
+                .collect_view()
+        })
+    };
+
+    let class = merge_classes(vec!["json-viewer", class.as_deref().unwrap_or("")]);
+
+    view! {
+        <div class=class style=style>
+            <input
+                type="search"
+                class="json-viewer-search"
+                aria-label=catalog.with_value(|catalog| catalog.text(MessageKey::JsonViewerSearch))
+                aria-controls=tree_id.clone()
+                placeholder=catalog
+                    .with_value(|catalog| catalog.text(MessageKey::JsonViewerSearchPlaceholder))
+                on:input=handle_search
+            />
+            <span class="json-viewer-status" role="status">{move || status.get()}</span>
+            <div
+                id=tree_id
+                node_ref=viewport_ref
+                class="json-viewer-tree"
+                role="tree"
+                aria-label=label
+                aria-activedescendant=active_descendant
+                tabindex="0"
+                data-matches=move || match_count.get()
+                style=format!("position: relative; overflow: auto; height: {}px;", viewport_height)
+                on:keydown=handle_keydown
+                on:scroll=move |_| {
+                    if let Some(viewport) = viewport_ref.get_untracked() {
+                        scroll_top.set(viewport.scroll_top() as f64);
+                    }
+                }
+            >
+                <div
+                    class="json-viewer-rows"
+                    style=move || {
+                        format!(
+                            "position: relative; height: {}px;",
+                            rows.with(Vec::len) as f64 * row_height,
+                        )
+                    }
+                >
+                    {visible_rows}
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptos::serde_json::json;
+
+    #[test]
+    fn test_json_path_string() {
+        let path = [
+            JsonPathSegment::Key("users".into()),
+            JsonPathSegment::Index(0),
+            JsonPathSegment::Key("first name".into()),
+        ];
+        assert_eq!(json_path_string(&path), "$.users[0][\"first name\"]");
+        assert_eq!(json_path_string(&[]), "$");
+    }
+
+    #[test]
+    fn test_flatten_json() {
+        let value = json!({ "a": 1, "b": { "c": [true, null] } });
+        let collapsed = flatten_json(&value, &json_expanded_to_depth(&value, 1));
+        let paths: Vec<&str> = collapsed.iter().map(|row| row.path.as_str()).collect();
+        assert_eq!(paths, ["$", "$.a", "$.b"]);
+        assert_eq!(collapsed[2].position, (2, 2));
+        assert_eq!(
+            collapsed[2].value,
+            JsonRowValue::Container {
+                array: false,
+                len: 1,
+                expanded: false
+            }
+        );
+
+        let result = json_search(&value, "NULL");
+        assert_eq!(result.matches, ["$.b.c[1]"]);
+        let open = flatten_json(&value, &result.expand);
+        assert_eq!(open.len(), 6);
+        assert_eq!(open[5].depth, 3);
+    }
+
+    #[test]
+    fn test_json_highlight() {
+        assert_eq!(
+            json_highlight("\"Hello World\"", "wor"),
+            vec![
+                ("\"Hello ".to_string(), false),
+                ("Wor".to_string(), true),
+                ("ld\"".to_string(), false)
+            ]
+        );
+        assert_eq!(json_highlight("abc", ""), vec![("abc".to_string(), false)]);
+    }
+
+    #[test]
+    fn test_json_visible_rows() {
+        assert_eq!(json_visible_rows(0.0, 240.0, 24.0, 1000), 0..20);
+        assert_eq!(json_visible_rows(2400.0, 240.0, 24.0, 1000), 90..120);
+        assert_eq!(json_visible_rows(0.0, 240.0, 24.0, 5), 0..5);
+        assert_eq!(json_scroll_to_row(0.0, 240.0, 24.0, 12), Some(72.0));
+        assert_eq!(json_scroll_to_row(240.0, 240.0, 24.0, 5), Some(120.0));
+        assert_eq!(json_scroll_to_row(0.0, 240.0, 24.0, 3), None);
+    }
+}
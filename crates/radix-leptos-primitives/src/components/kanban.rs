@@ -0,0 +1,697 @@
+use crate::components::drag_drop::{DragHandle, DropEvent, DropZone};
+use crate::i18n::{use_i18n, MessageArgs, MessageCatalog, MessageKey};
+use crate::utils::{generate_id, merge_classes};
+use leptos::callback::Callback;
+use leptos::html;
+use leptos::prelude::*;
+use radix_leptos_core::FocusManager;
+use wasm_bindgen::JsCast;
+
+/// A card on a Kanban board
+#[derive(Debug, Clone, PartialEq)]
+pub struct KanbanCard {
+    pub id: String,
+    pub title: String,
+    pub description: Option<String>,
+}
+
+impl KanbanCard {
+    pub fn new(id: impl Into<String>, title: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            description: None,
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+/// A column of cards
+#[derive(Debug, Clone, PartialEq)]
+pub struct KanbanColumn {
+    pub id: String,
+    pub title: String,
+    pub cards: Vec<KanbanCard>,
+    /// Maximum number of cards (work in progress limit)
+    pub wip_limit: Option<usize>,
+}
+
+impl KanbanColumn {
+    pub fn new(id: impl Into<String>, title: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            cards: Vec::new(),
+            wip_limit: None,
+        }
+    }
+
+    pub fn cards(mut self, cards: Vec<KanbanCard>) -> Self {
+        self.cards = cards;
+        self
+    }
+
+    pub fn wip_limit(mut self, limit: usize) -> Self {
+        self.wip_limit = Some(limit);
+        self
+    }
+
+    /// Whether the column has reached its WIP limit
+    pub fn is_full(&self) -> bool {
+        self.wip_limit
+            .is_some_and(|limit| self.cards.len() >= limit)
+    }
+}
+
+/// A card moved to a new position
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KanbanCardMove {
+    pub card_id: String,
+    pub from_column: String,
+    pub to_column: String,
+    pub from_index: usize,
+    /// Index of the card in `to_column` after the move
+    pub to_index: usize,
+}
+
+/// A column moved to a new position
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KanbanColumnMove {
+    pub column_id: String,
+    pub from_index: usize,
+    pub to_index: usize,
+}
+
+/// Why a move was rejected
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum KanbanMoveError {
+    #[error("no card with id `{0}`")]
+    UnknownCard(String),
+    #[error("no column with id `{0}`")]
+    UnknownColumn(String),
+    #[error("{title} is at its limit of {limit} cards")]
+    WipLimitReached {
+        column_id: String,
+        title: String,
+        limit: usize,
+    },
+}
+
+/// Destination of a keyboard move
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KanbanTarget {
+    pub column_id: String,
+    /// Insertion point, as for [`move_kanban_card`]
+    pub index: usize,
+}
+
+/// Direction of a keyboard move
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KanbanDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl KanbanDirection {
+    /// Alt + arrow key
+    pub fn from_key(key: &str, alt: bool) -> Option<Self> {
+        if !alt {
+            return None;
+        }
+        match key {
+            "ArrowUp" => Some(KanbanDirection::Up),
+            "ArrowDown" => Some(KanbanDirection::Down),
+            "ArrowLeft" => Some(KanbanDirection::Left),
+            "ArrowRight" => Some(KanbanDirection::Right),
+            _ => None,
+        }
+    }
+}
+
+/// Column index and card index
+type CardPosition = (usize, usize);
+
+fn find_card(columns: &[KanbanColumn], card_id: &str) -> Option<CardPosition> {
+    columns.iter().enumerate().find_map(|(column, c)| {
+        c.cards
+            .iter()
+            .position(|card| card.id == card_id)
+            .map(|index| (column, index))
+    })
+}
+
+/// Move a card to `to_column`, inserting it before the card currently at
+/// `to_index` (indices past the end append)
+///
+/// Moving into another column fails when that column is at its WIP limit.
+pub fn move_kanban_card(
+    columns: &mut [KanbanColumn],
+    card_id: &str,
+    to_column: &str,
+    to_index: usize,
+) -> Result<KanbanCardMove, KanbanMoveError> {
+    let (from, from_index) =
+        find_card(columns, card_id).ok_or_else(|| KanbanMoveError::UnknownCard(card_id.into()))?;
+    let to = columns
+        .iter()
+        .position(|column| column.id == to_column)
+        .ok_or_else(|| KanbanMoveError::UnknownColumn(to_column.into()))?;
+
+    if from != to && columns[to].is_full() {
+        return Err(KanbanMoveError::WipLimitReached {
+            column_id: columns[to].id.clone(),
+            title: columns[to].title.clone(),
+            limit: columns[to].wip_limit.unwrap_or_default(),
+        });
+    }
+
+    let card = columns[from].cards.remove(from_index);
+    let mut index = to_index;
+    if from == to && from_index < to_index {
+        index -= 1;
+    }
+    let index = index.min(columns[to].cards.len());
+    columns[to].cards.insert(index, card);
+
+    Ok(KanbanCardMove {
+        card_id: card_id.to_string(),
+        from_column: columns[from].id.clone(),
+        to_column: columns[to].id.clone(),
+        from_index,
+        to_index: index,
+    })
+}
+
+/// Move a column so it ends up at `to_index`
+pub fn move_kanban_column(
+    columns: &mut Vec<KanbanColumn>,
+    column_id: &str,
+    to_index: usize,
+) -> Result<KanbanColumnMove, KanbanMoveError> {
+    let from_index = columns
+        .iter()
+        .position(|column| column.id == column_id)
+        .ok_or_else(|| KanbanMoveError::UnknownColumn(column_id.into()))?;
+    let column = columns.remove(from_index);
+    let to_index = to_index.min(columns.len());
+    columns.insert(to_index, column);
+
+    Ok(KanbanColumnMove {
+        column_id: column_id.to_string(),
+        from_index,
+        to_index,
+    })
+}
+
+/// Where a keyboard move takes a card
+pub fn kanban_keyboard_target(
+    columns: &[KanbanColumn],
+    card_id: &str,
+    direction: KanbanDirection,
+) -> Option<KanbanTarget> {
+    let (column, index) = find_card(columns, card_id)?;
+    let (column, index) = match direction {
+        KanbanDirection::Up => (column, index.checked_sub(1)?),
+        KanbanDirection::Down if index + 1 < columns[column].cards.len() => (column, index + 2),
+        KanbanDirection::Down => return None,
+        KanbanDirection::Left => (column.checked_sub(1)?, usize::MAX),
+        KanbanDirection::Right if column + 1 < columns.len() => (column + 1, usize::MAX),
+        KanbanDirection::Right => return None,
+    };
+    Some(KanbanTarget {
+        column_id: columns[column].id.clone(),
+        index,
+    })
+}
+
+/// Insertion point for a drop at `y`, given the vertical midpoints of the
+/// column's cards
+pub fn kanban_drop_index(card_midpoints: &[f64], y: f64) -> usize {
+    card_midpoints
+        .iter()
+        .filter(|midpoint| **midpoint < y)
+        .count()
+}
+
+/// KanbanBoard component - Columns of cards moved by drag and drop
+///
+/// Cards and columns are dragged with the DragDrop primitives. With a card
+/// or column header focused, Alt + arrow keys move it instead. Moves into a
+/// column at its WIP limit are rejected and reported through
+/// `on_move_rejected`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn Board() -> impl IntoView {
+///     let columns = vec![
+///         KanbanColumn::new("todo", "To do").cards(vec![KanbanCard::new("1", "Write docs")]),
+///         KanbanColumn::new("doing", "In progress").wip_limit(3),
+///         KanbanColumn::new("done", "Done"),
+///     ];
+///     view! {
+///         <KanbanBoard
+///             columns=columns
+///             on_card_move=Callback::new(|change: KanbanCardMove| log::info!("{change:?}"))
+///         />
+///     }
+/// }
+/// ```
+#[component]
+pub fn KanbanBoard(
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    #[prop(optional)] columns: Option<Vec<KanbanColumn>>,
+    /// Show an "Add column" button (default: false)
+    #[prop(optional)]
+    allow_add_column: Option<bool>,
+    /// Title of columns added with the button
+    #[prop(optional)]
+    new_column_title: Option<String>,
+    /// Accessible name of the board
+    #[prop(optional)]
+    label: Option<String>,
+    #[prop(optional)] on_card_move: Option<Callback<KanbanCardMove>>,
+    #[prop(optional)] on_column_move: Option<Callback<KanbanColumnMove>>,
+    #[prop(optional)] on_column_add: Option<Callback<KanbanColumn>>,
+    #[prop(optional)] on_move_rejected: Option<Callback<KanbanMoveError>>,
+) -> impl IntoView {
+    let board = RwSignal::new(columns.unwrap_or_default());
+    let status = RwSignal::new(String::new());
+    let board_ref = NodeRef::<html::Div>::new();
+    let allow_add_column = allow_add_column.unwrap_or(false);
+    let catalog = StoredValue::new(use_i18n());
+    let text = move |key: MessageKey, args: MessageArgs| {
+        catalog.with_value(|catalog| catalog.format(key, &args))
+    };
+    let new_column_title = StoredValue::new(
+        new_column_title.unwrap_or_else(|| text(MessageKey::KanbanNewColumn, MessageArgs::new())),
+    );
+    let label = label.unwrap_or_else(|| text(MessageKey::KanbanLabel, MessageArgs::new()));
+
+    let reject = move |error: KanbanMoveError| {
+        status.set(catalog.with_value(|catalog| kanban_rejection_message(catalog, &error)));
+        if let Some(on_move_rejected) = on_move_rejected {
+            on_move_rejected.run(error);
+        }
+    };
+
+    let move_card = move |card_id: String, to_column: String, to_index: usize, refocus: bool| {
+        let mut columns = board.get_untracked();
+        match move_kanban_card(&mut columns, &card_id, &to_column, to_index) {
+            Ok(change) => {
+                let column = columns.iter().find(|c| c.id == change.to_column);
+                if let Some(column) = column {
+                    let title = column.cards[change.to_index].title.clone();
+                    let args = MessageArgs::new()
+                        .with("card", title)
+                        .with("column", column.title.as_str())
+                        .with("position", change.to_index + 1)
+                        .with("count", column.cards.len());
+                    status.set(text(MessageKey::KanbanCardMoved, args));
+                }
+                board.set(columns);
+                if refocus {
+                    focus_item(board_ref, card_id);
+                }
+                if let Some(on_card_move) = on_card_move {
+                    on_card_move.run(change);
+                }
+            }
+            Err(error) => reject(error),
+        }
+    };
+
+    let move_column = move |column_id: String, to_index: usize, refocus: bool| {
+        let mut columns = board.get_untracked();
+        match move_kanban_column(&mut columns, &column_id, to_index) {
+            Ok(change) => {
+                if change.from_index == change.to_index {
+                    return;
+                }
+                let args = MessageArgs::new()
+                    .with("column", columns[change.to_index].title.as_str())
+                    .with("position", change.to_index + 1)
+                    .with("count", columns.len());
+                status.set(text(MessageKey::KanbanColumnMoved, args));
+                board.set(columns);
+                if refocus {
+                    focus_item(board_ref, column_id);
+                }
+                if let Some(on_column_move) = on_column_move {
+                    on_column_move.run(change);
+                }
+            }
+            Err(error) => reject(error),
+        }
+    };
+
+    let add_column = move |_: web_sys::MouseEvent| {
+        let column = KanbanColumn::new(generate_id("kanban-column"), new_column_title.get_value());
+        board.update(|columns| columns.push(column.clone()));
+        status.set(text(
+            MessageKey::KanbanColumnAdded,
+            MessageArgs::new().with("column", column.title.as_str()),
+        ));
+        if let Some(on_column_add) = on_column_add {
+            on_column_add.run(column);
+        }
+    };
+
+    let render_column = move |index: usize, column: KanbanColumn| {
+        let column_id = column.id.clone();
+        let handle_drop = Callback::new(move |event: DropEvent| {
+            if event.item_id.is_empty() {
+                return;
+            }
+            if event.data.as_deref() == Some("column") {
+                move_column(event.item_id, index, false);
+            } else {
+                let index = card_drop_index(board_ref, &event.target_id, event.position.y);
+                move_card(event.item_id, event.target_id, index, false);
+            }
+        });
+
+        let header_id = column.id.clone();
+        let handle_header_key = move |ev: web_sys::KeyboardEvent| {
+            let offset = match KanbanDirection::from_key(&ev.key(), ev.alt_key()) {
+                Some(KanbanDirection::Left) if index > 0 => index - 1,
+                Some(KanbanDirection::Right) => index + 1,
+                _ => return,
+            };
+            ev.prevent_default();
+            move_column(header_id.clone(), offset, true);
+        };
+
+        let count = match column.wip_limit {
+            Some(limit) => format!("{} / {}", column.cards.len(), limit),
+            None => column.cards.len().to_string(),
+        };
+        let full = column.is_full();
+        let cards = column
+            .cards
+            .into_iter()
+            .map(|card| {
+                let card_id = card.id.clone();
+                let handle_card_key = move |ev: web_sys::KeyboardEvent| {
+                    let Some(direction) = KanbanDirection::from_key(&ev.key(), ev.alt_key()) else {
+                        return;
+                    };
+                    ev.prevent_default();
+                    let target = board.with_untracked(|columns| {
+                        kanban_keyboard_target(columns, &card_id, direction)
+                    });
+                    if let Some(target) = target {
+                        move_card(card_id.clone(), target.column_id, target.index, true);
+                    }
+                };
+                view! {
+                    <div
+                        class="kanban-card-item"
+                        role="listitem"
+                        data-column-id=column_id.clone()
+                        on:keydown=handle_card_key
+                    >
+                        <DragHandle
+                            class="kanban-card".to_string()
+                            item_id=card.id
+                            data="card".to_string()
+                            label=card.title.clone()
+                        >
+                            <div class="kanban-card-title">{card.title}</div>
+                            {card
+                                .description
+                                .map(|description| {
+                                    view! { <p class="kanban-card-description">{description}</p> }
+                                })}
+                        </DragHandle>
+                    </div>
+                }
+            })
+            .collect_view();
+
+        view! {
+            <DropZone
+                class="kanban-column".to_string()
+                style="display: flex; flex-direction: column; gap: var(--space-2, 0.5rem); min-width: 16rem;".to_string()
+                zone_id=column.id.clone()
+                label=text(
+                    MessageKey::KanbanColumnZone,
+                    MessageArgs::new().with("column", column.title.as_str()),
+                )
+                on_drop=handle_drop
+            >
+                <div class="kanban-column-header" on:keydown=handle_header_key>
+                    <DragHandle
+                        class="kanban-column-handle".to_string()
+                        item_id=column.id
+                        data="column".to_string()
+                        label=text(
+                            MessageKey::KanbanColumnHandle,
+                            MessageArgs::new().with("column", column.title.as_str()),
+                        )
+                    >
+                        <h3 class="kanban-column-title">{column.title}</h3>
+                    </DragHandle>
+                    <span class="kanban-column-count" data-full=full>{count}</span>
+                </div>
+                <div class="kanban-cards" role="list">{cards}</div>
+            </DropZone>
+        }
+    };
+
+    let class = merge_classes(vec!["kanban-board", class.as_deref().unwrap_or("")]);
+    let style = format!(
+        "display: flex; align-items: flex-start; gap: var(--space-4, 1rem); overflow-x: auto; {}",
+        style.unwrap_or_default()
+    );
+
+    view! {
+        <div class=class style=style role="group" aria-label=label node_ref=board_ref>
+            {move || {
+                board
+                    .get()
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, column)| render_column(index, column))
+                    .collect_view()
+            }}
+            {allow_add_column
+                .then(|| {
+                    view! {
+                        <button type="button" class="kanban-add-column" on:click=add_column>
+                            {text(MessageKey::KanbanAddColumn, MessageArgs::new())}
+                        </button>
+                    }
+                })}
+            <div class="kanban-status" role="status" aria-live="polite" style="position: absolute; width: 1px; height: 1px; overflow: hidden; clip: rect(0 0 0 0);">
+                {move || status.get()}
+            </div>
+        </div>
+    }
+}
+
+/// Insertion point for a card dropped at `y` in the rendered column
+fn card_drop_index(board_ref: NodeRef<html::Div>, column_id: &str, y: f64) -> usize {
+    let Some(board) = board_ref.get_untracked() else {
+        return usize::MAX;
+    };
+    let Ok(items) = board.query_selector_all(".kanban-card-item") else {
+        return usize::MAX;
+    };
+    let midpoints: Vec<f64> = (0..items.length())
+        .filter_map(|index| items.item(index))
+        .filter_map(|node| node.dyn_into::<web_sys::Element>().ok())
+        .filter(|item| item.get_attribute("data-column-id").as_deref() == Some(column_id))
+        .map(|item| {
+            let rect = item.get_bounding_client_rect();
+            rect.top() + rect.height() / 2.0
+        })
+        .collect();
+    kanban_drop_index(&midpoints, y)
+}
+
+/// What a KanbanBoard announces when it rejects a move
+///
+/// Unknown ids are programming errors and keep their untranslated message.
+pub fn kanban_rejection_message(catalog: &MessageCatalog, error: &KanbanMoveError) -> String {
+    match error {
+        KanbanMoveError::WipLimitReached { title, limit, .. } => catalog.format(
+            MessageKey::KanbanWipLimit,
+            &MessageArgs::new()
+                .with("column", title.as_str())
+                .with("limit", *limit),
+        ),
+        error => error.to_string(),
+    }
+}
+
+/// Focus the drag handle of a card or column once the board has re-rendered
+fn focus_item(board_ref: NodeRef<html::Div>, item_id: String) {
+    request_animation_frame(move || {
+        let Some(board) = board_ref.get_untracked() else {
+            return;
+        };
+        let Ok(handles) = board.query_selector_all(".drag-handle") else {
+            return;
+        };
+        let handle = (0..handles.length())
+            .filter_map(|index| handles.item(index))
+            .filter_map(|node| node.dyn_into::<web_sys::Element>().ok())
+            .find(|handle| handle.get_attribute("data-item-id").as_deref() == Some(&item_id));
+        if let Some(handle) = handle {
+            FocusManager::focus(&handle);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn board() -> Vec<KanbanColumn> {
+        vec![
+            KanbanColumn::new("todo", "To do").cards(vec![
+                KanbanCard::new("a", "A"),
+                KanbanCard::new("b", "B"),
+                KanbanCard::new("c", "C"),
+            ]),
+            KanbanColumn::new("doing", "Doing")
+                .cards(vec![KanbanCard::new("d", "D")])
+                .wip_limit(2),
+            KanbanColumn::new("done", "Done"),
+        ]
+    }
+
+    fn ids(column: &KanbanColumn) -> Vec<&str> {
+        column.cards.iter().map(|card| card.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_move_card_between_columns() {
+        let mut columns = board();
+        let change = move_kanban_card(&mut columns, "b", "doing", 0).unwrap();
+        assert_eq!(
+            change,
+            KanbanCardMove {
+                card_id: "b".to_string(),
+                from_column: "todo".to_string(),
+                to_column: "doing".to_string(),
+                from_index: 1,
+                to_index: 0,
+            }
+        );
+        assert_eq!(ids(&columns[0]), vec!["a", "c"]);
+        assert_eq!(ids(&columns[1]), vec!["b", "d"]);
+    }
+
+    #[test]
+    fn test_reorder_within_column() {
+        let mut columns = board();
+        // Dropping "a" before "c" leaves it in the middle
+        let change = move_kanban_card(&mut columns, "a", "todo", 2).unwrap();
+        assert_eq!(change.to_index, 1);
+        assert_eq!(ids(&columns[0]), vec!["b", "a", "c"]);
+
+        let change = move_kanban_card(&mut columns, "b", "todo", usize::MAX).unwrap();
+        assert_eq!(change.to_index, 2);
+        assert_eq!(ids(&columns[0]), vec!["a", "c", "b"]);
+    }
+
+    #[test]
+    fn test_wip_limit_rejects_moves() {
+        let mut columns = board();
+        move_kanban_card(&mut columns, "a", "doing", 9).unwrap();
+        let error = move_kanban_card(&mut columns, "b", "doing", 0).unwrap_err();
+        assert_eq!(
+            error,
+            KanbanMoveError::WipLimitReached {
+                column_id: "doing".to_string(),
+                title: "Doing".to_string(),
+                limit: 2,
+            }
+        );
+        assert_eq!(error.to_string(), "Doing is at its limit of 2 cards");
+        assert_eq!(
+            kanban_rejection_message(&MessageCatalog::default(), &error),
+            "Doing is at its limit of 2 cards"
+        );
+        assert_eq!(ids(&columns[0]), vec!["b", "c"]);
+        // Reordering inside a full column is still allowed
+        assert!(move_kanban_card(&mut columns, "a", "doing", 0).is_ok());
+    }
+
+    #[test]
+    fn test_unknown_ids() {
+        let mut columns = board();
+        assert_eq!(
+            move_kanban_card(&mut columns, "x", "done", 0),
+            Err(KanbanMoveError::UnknownCard("x".to_string()))
+        );
+        assert_eq!(
+            move_kanban_card(&mut columns, "a", "archive", 0),
+            Err(KanbanMoveError::UnknownColumn("archive".to_string()))
+        );
+        assert_eq!(ids(&columns[0]), vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_move_column() {
+        let mut columns = board();
+        let change = move_kanban_column(&mut columns, "done", 0).unwrap();
+        assert_eq!(change.from_index, 2);
+        assert_eq!(change.to_index, 0);
+        let order: Vec<&str> = columns.iter().map(|c| c.id.as_str()).collect();
+        assert_eq!(order, vec!["done", "todo", "doing"]);
+        assert_eq!(
+            move_kanban_column(&mut columns, "todo", 99)
+                .unwrap()
+                .to_index,
+            2
+        );
+    }
+
+    #[test]
+    fn test_keyboard_targets() {
+        let columns = board();
+        let target = |id: &str, direction| kanban_keyboard_target(&columns, id, direction);
+        assert_eq!(target("a", KanbanDirection::Up), None);
+        assert_eq!(
+            target("a", KanbanDirection::Down),
+            Some(KanbanTarget {
+                column_id: "todo".to_string(),
+                index: 2
+            })
+        );
+        assert_eq!(target("c", KanbanDirection::Down), None);
+        assert_eq!(target("a", KanbanDirection::Left), None);
+        assert_eq!(
+            target("d", KanbanDirection::Right).map(|t| t.column_id),
+            Some("done".to_string())
+        );
+        assert_eq!(KanbanDirection::from_key("ArrowUp", false), None);
+        assert_eq!(
+            KanbanDirection::from_key("ArrowUp", true),
+            Some(KanbanDirection::Up)
+        );
+    }
+
+    #[test]
+    fn test_drop_index() {
+        let midpoints = [20.0, 60.0, 100.0];
+        assert_eq!(kanban_drop_index(&midpoints, 0.0), 0);
+        assert_eq!(kanban_drop_index(&midpoints, 40.0), 1);
+        assert_eq!(kanban_drop_index(&midpoints, 500.0), 3);
+        assert_eq!(kanban_drop_index(&[], 10.0), 0);
+    }
+}
@@ -0,0 +1,63 @@
+use crate::utils::merge_classes;
+use leptos::children::Children;
+use leptos::prelude::*;
+
+/// Kbd component - Keyboard keys and shortcuts in text
+///
+/// Renders `<kbd>`. With `keys`, each key gets its own nested `<kbd>`
+/// joined by `+`, the markup HTML uses for key combinations.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn Hint() -> impl IntoView {
+///     view! {
+///         <p>
+///             "Press " <Kbd keys=vec!["Ctrl".to_string(), "K".to_string()] />
+///             " to search, or " <Kbd>"?"</Kbd> " for help"
+///         </p>
+///     }
+/// }
+/// ```
+#[component]
+pub fn Kbd(
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    #[prop(optional)] children: Option<Children>,
+    /// Keys of a combination, rendered before any children
+    #[prop(optional)]
+    keys: Option<Vec<String>>,
+) -> impl IntoView {
+    let keys = keys.unwrap_or_default();
+    let class = merge_classes(vec![
+        "kbd",
+        if keys.len() > 1 {
+            "kbd-combination"
+        } else {
+            ""
+        },
+        class.as_deref().unwrap_or(""),
+    ]);
+
+    let keys = keys
+        .into_iter()
+        .enumerate()
+        .map(|(index, key)| {
+            view! {
+                {(index > 0).then_some(view! { <span class="kbd-separator">"+"</span> })}
+                <kbd class="kbd-key">{key}</kbd>
+            }
+        })
+        .collect::<Vec<_>>();
+
+    view! {
+        <kbd class=class style=style.unwrap_or_default()>
+            {keys}
+            {children.map(|c| c())}
+        </kbd>
+    }
+}
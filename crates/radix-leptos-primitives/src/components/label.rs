@@ -1,3 +1,4 @@
+use crate::i18n::{use_i18n, MessageKey};
 use crate::utils::merge_classes;
 use leptos::callback::Callback;
 use leptos::children::Children;
@@ -91,7 +92,7 @@ pub fn LabelDescription(
             class=class
             style=style
             role="text"
-            aria-label="Label description"
+            aria-label=use_i18n().text(MessageKey::LabelDescription)
         >
             {children.map(|c| c())}
         </div>
@@ -144,7 +145,7 @@ pub fn LabelGroup(
             class=class
             style=style
             role="group"
-            aria-label="Label group"
+            aria-label=use_i18n().text(MessageKey::LabelGroup)
             data-orientation=orientation.to_string()
             data-spacing=spacing.to_string()
         >
@@ -0,0 +1,276 @@
+use crate::utils::{generate_id, merge_classes};
+use leptos::children::Children;
+use leptos::prelude::*;
+
+/// Naming state of the closest labelled landmark, which its first heading
+/// claims
+#[derive(Clone)]
+struct LandmarkContext {
+    heading_id: String,
+    labelled: RwSignal<bool>,
+}
+
+/// Id for a heading that names the closest [`Section`], [`Nav`] or
+/// [`Aside`]
+///
+/// The first heading inside the landmark to call this gets the id and the
+/// landmark points `aria-labelledby` at it; later headings and headings
+/// outside a landmark get `None`. [`Heading`](super::Heading) already does
+/// this, so only custom heading elements need it.
+pub fn use_landmark_heading_id() -> Option<String> {
+    let context = use_context::<LandmarkContext>()?;
+    if context.labelled.get_untracked() {
+        return None;
+    }
+    context.labelled.set(true);
+    Some(context.heading_id)
+}
+
+/// Set up naming for a landmark, returning its `aria-labelledby`
+///
+/// An explicit `label` wins over `labelledby`, which wins over the first
+/// heading inside.
+fn use_landmark_name(
+    prefix: &str,
+    label: &Option<String>,
+    labelledby: Option<String>,
+) -> Signal<Option<String>> {
+    let context = LandmarkContext {
+        heading_id: generate_id(&format!("{}-heading", prefix)),
+        labelled: RwSignal::new(false),
+    };
+    provide_context(context.clone());
+
+    if label.is_some() {
+        return Signal::derive(|| None);
+    }
+    let LandmarkContext {
+        heading_id,
+        labelled,
+    } = context;
+    Signal::derive(move || {
+        labelledby
+            .clone()
+            .or_else(|| labelled.get().then(|| heading_id.clone()))
+    })
+}
+
+/// Main component - The page's main content landmark
+///
+/// Use once per page. It takes focus without entering the tab order, so
+/// skip links can move focus to it by `id`.
+#[component]
+pub fn Main(
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    #[prop(optional)] id: Option<String>,
+    /// Accessible name, only needed for a page with several `main`s
+    #[prop(optional)]
+    label: Option<String>,
+    children: Children,
+) -> impl IntoView {
+    let class = merge_classes(vec!["landmark-main", class.as_deref().unwrap_or("")]);
+
+    view! {
+        <main class=class style=style id=id aria-label=label tabindex="-1">
+            {children()}
+        </main>
+    }
+}
+
+/// Nav component - A navigation landmark
+///
+/// Named by `label`, `labelledby`, or else the first heading inside, so
+/// screen reader users can tell several navigations apart.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn Breadcrumbs() -> impl IntoView {
+///     view! {
+///         <Nav label="Breadcrumb".to_string()>
+///             <a href="/">"Home"</a>
+///             <a href="/docs" aria-current="page">"Docs"</a>
+///         </Nav>
+///     }
+/// }
+/// ```
+#[component]
+pub fn Nav(
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    #[prop(optional)] id: Option<String>,
+    /// Accessible name
+    #[prop(optional)]
+    label: Option<String>,
+    /// Id of the element naming the navigation
+    #[prop(optional)]
+    labelledby: Option<String>,
+    children: Children,
+) -> impl IntoView {
+    let labelledby = use_landmark_name("nav", &label, labelledby);
+    let class = merge_classes(vec!["landmark-nav", class.as_deref().unwrap_or("")]);
+
+    view! {
+        <nav class=class style=style id=id aria-label=label aria-labelledby=labelledby>
+            {children()}
+        </nav>
+    }
+}
+
+/// Aside component - A complementary landmark, e.g. a sidebar
+///
+/// Named like [`Nav`].
+#[component]
+pub fn Aside(
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    #[prop(optional)] id: Option<String>,
+    /// Accessible name
+    #[prop(optional)]
+    label: Option<String>,
+    /// Id of the element naming the aside
+    #[prop(optional)]
+    labelledby: Option<String>,
+    children: Children,
+) -> impl IntoView {
+    let labelledby = use_landmark_name("aside", &label, labelledby);
+    let class = merge_classes(vec!["landmark-aside", class.as_deref().unwrap_or("")]);
+
+    view! {
+        <aside class=class style=style id=id aria-label=label aria-labelledby=labelledby>
+            {children()}
+        </aside>
+    }
+}
+
+/// Section component - A region landmark
+///
+/// A `section` is only a landmark once it has a name, so it is named like
+/// [`Nav`]: usually by its first heading.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn Billing() -> impl IntoView {
+///     view! {
+///         <Section>
+///             // Names the section through aria-labelledby
+///             <Heading level=2>"Billing"</Heading>
+///             <p>"Your next invoice is due on the 1st."</p>
+///         </Section>
+///     }
+/// }
+/// ```
+#[component]
+pub fn Section(
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    #[prop(optional)] id: Option<String>,
+    /// Accessible name
+    #[prop(optional)]
+    label: Option<String>,
+    /// Id of the element naming the section
+    #[prop(optional)]
+    labelledby: Option<String>,
+    children: Children,
+) -> impl IntoView {
+    let labelledby = use_landmark_name("section", &label, labelledby);
+    let class = merge_classes(vec!["landmark-section", class.as_deref().unwrap_or("")]);
+
+    view! {
+        <section class=class style=style id=id aria-label=label aria-labelledby=labelledby>
+            {children()}
+        </section>
+    }
+}
+
+/// Banner component - The site header landmark
+///
+/// Renders a `header`, which is only the banner when it is not inside an
+/// `article`, `aside`, `main`, `nav` or `section`, so keep it at the top of
+/// the page layout.
+#[component]
+pub fn Banner(
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    #[prop(optional)] id: Option<String>,
+    children: Children,
+) -> impl IntoView {
+    let class = merge_classes(vec!["landmark-banner", class.as_deref().unwrap_or("")]);
+
+    view! {
+        <header class=class style=style id=id>
+            {children()}
+        </header>
+    }
+}
+
+/// ContentInfo component - The site footer landmark
+///
+/// Renders a `footer`, placed like [`Banner`].
+#[component]
+pub fn ContentInfo(
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    #[prop(optional)] id: Option<String>,
+    children: Children,
+) -> impl IntoView {
+    let class = merge_classes(vec![
+        "landmark-content-info",
+        class.as_deref().unwrap_or(""),
+    ]);
+
+    view! {
+        <footer class=class style=style id=id>
+            {children()}
+        </footer>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_heading_names_landmark() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let labelledby = use_landmark_name("section", &None, None);
+            assert_eq!(labelledby.get_untracked(), None);
+
+            let first = use_landmark_heading_id().expect("first heading claims the name");
+            assert!(first.starts_with("section-heading"));
+            assert_eq!(use_landmark_heading_id(), None);
+            assert_eq!(labelledby.get_untracked(), Some(first));
+        });
+    }
+
+    #[test]
+    fn test_explicit_names_win() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let labelled = use_landmark_name("nav", &Some("Primary".to_string()), None);
+            use_landmark_heading_id();
+            assert_eq!(labelled.get_untracked(), None);
+
+            let labelledby = use_landmark_name("aside", &None, Some("sidebar-title".to_string()));
+            use_landmark_heading_id();
+            assert_eq!(labelledby.get_untracked().as_deref(), Some("sidebar-title"));
+        });
+    }
+
+    #[test]
+    fn test_no_heading_id_outside_landmark() {
+        let owner = Owner::new();
+        owner.with(|| assert_eq!(use_landmark_heading_id(), None));
+    }
+}
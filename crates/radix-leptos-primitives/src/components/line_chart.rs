@@ -1,5 +1,17 @@
+use super::chart::{chart_root_style, AxisConfig, ChartMargin};
+use super::timeline::AnimationConfig;
+use crate::i18n::{use_i18n, MessageArgs, MessageKey};
+use crate::theming::use_chart_palette;
+use crate::utils::merge_classes;
+use leptos::callback::Callback;
+use leptos::children::Children;
+use leptos::prelude::*;
+
 
 /// LineChart component - Time series and trend visualization
+///
+/// Series without a `color` take the palette's categorical color at their
+/// index, set on the chart as `--chart-series-1`, `--chart-series-2`, …
 #[component]
 pub fn LineChart(
     #[prop(optional)] class: Option<String>,
@@ -20,19 +32,38 @@ pub fn LineChart(
     let area_fill = area_fill.unwrap_or(false);
     let show_points = show_points.unwrap_or(true);
     let show_grid = show_grid.unwrap_or(true);
+    let series_count = data.len();
+    let palette = use_chart_palette();
+    let style = move || {
+        let colors = palette.with(|palette| {
+            palette.series_colors(data.iter().map(|series| series.color.as_deref()))
+        });
+        chart_root_style(&colors, style.as_deref())
+    };
 
-    let class = merge_classes(vec![
-        "line-chart",
-        class.as_deref().unwrap_or(""),
-    ]);
+    let class = merge_classes(vec!["line-chart", class.as_deref().unwrap_or("")]);
+
+    let _handle_point_click = move |point: LinePoint| {
+        if let Some(on_point_click) = on_point_click {
+            on_point_click.run(point);
+        }
+    };
+
+    let _handle_line_hover = move |series: LineSeries| {
+        if let Some(on_line_hover) = on_line_hover {
+            on_line_hover.run(series);
+        }
+    };
 
     view! {
         <div
             class=class
             style=style
             role="img"
-            aria-label="Line chart visualization"
-            data-series-count=data.len()
+            aria-label=use_i18n().text(MessageKey::LineChartLabel)
+            data-series-count=series_count
+            data-width=config.width
+            data-height=config.height
             data-smooth=smooth
             data-area-fill=area_fill
             data-show-points=show_points
@@ -48,7 +79,8 @@ pub fn LineChart(
 pub struct LineSeries {
     pub name: String,
     pub data: Vec<LinePoint>,
-    pub color: String,
+    /// Series color, the palette's color for its index when `None`
+    pub color: Option<String>,
     pub stroke_width: f64,
     pub opacity: f64,
 }
@@ -58,7 +90,7 @@ impl Default for LineSeries {
         Self {
             name: "Series".to_string(),
             data: Vec::new(),
-            color: "#3b82f6".to_string(),
+            color: None,
             stroke_width: 2.0,
             opacity: 1.0,
         }
@@ -98,87 +130,6 @@ impl Default for LineChartConfig {
     }
 }
 
-/// Chart Margin
-#[derive(Debug, Clone, PartialEq)]
-pub struct ChartMargin {
-    pub top: f64,
-    pub right: f64,
-    pub bottom: f64,
-    pub left: f64,
-}
-
-impl Default for ChartMargin {
-    fn default() -> Self {
-        Self {
-            top: 20.0,
-            right: 20.0,
-            bottom: 40.0,
-            left: 40.0,
-        }
-    }
-}
-
-/// Axis Configuration
-#[derive(Debug, Clone, PartialEq)]
-pub struct AxisConfig {
-    pub label: Option<String>,
-    pub min: Option<f64>,
-    pub max: Option<f64>,
-    pub ticks: Option<usize>,
-    pub format: Option<String>,
-}
-
-impl Default for AxisConfig {
-    fn default() -> Self {
-        Self {
-            label: None,
-            min: None,
-            max: None,
-            ticks: None,
-            format: None,
-        }
-    }
-}
-
-/// Animation Configuration
-#[derive(Debug, Clone, PartialEq)]
-pub struct AnimationConfig {
-    pub duration: f64,
-    pub easing: EasingType,
-    pub delay: f64,
-}
-
-impl Default for AnimationConfig {
-    fn default() -> Self {
-        Self {
-            duration: 1000.0,
-            easing: EasingType::EaseInOut,
-            delay: 0.0,
-        }
-    }
-}
-
-/// Easing Type
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
-pub enum EasingType {
-    #[default]
-    EaseInOut,
-    EaseIn,
-    EaseOut,
-    Linear,
-}
-
-impl EasingType {
-    pub fn to_class(&self) -> &'static str {
-        match self {
-            EasingType::EaseInOut => "ease-in-out",
-            EasingType::EaseIn => "ease-in",
-            EasingType::EaseOut => "ease-out",
-            EasingType::Linear => "linear",
-        }
-    }
-}
-
 /// Line Chart Area component
 #[component]
 pub fn LineChartArea(
@@ -190,17 +141,14 @@ pub fn LineChartArea(
     let series = series.unwrap_or_default();
     let opacity = opacity.unwrap_or(0.3);
 
-    let class = merge_classes(vec![
-        "line-chart-area",
-        class.as_deref().unwrap_or(""),
-    ]);
+    let class = merge_classes(vec!["line-chart-area", class.as_deref().unwrap_or("")]);
 
     view! {
         <div
             class=class
             style=style
             role="img"
-            aria-label="Chart area fill"
+            aria-label=use_i18n().text(MessageKey::LineChartArea)
             data-series-name=series.name
             data-opacity=opacity
         />
@@ -219,140 +167,59 @@ pub fn LineChartPoint(
     let point = point.unwrap_or_default();
     let radius = radius.unwrap_or(4.0);
 
-    let class = merge_classes(vec![
-        "line-chart-point",
-        class.as_deref().unwrap_or(""),
-    ]);
+    let class = merge_classes(vec!["line-chart-point", class.as_deref().unwrap_or("")]);
+    let catalog = use_i18n();
+    let aria_label = match point.label.as_deref() {
+        Some(label) => catalog.format(
+            MessageKey::LineChartPoint,
+            &MessageArgs::new().with("label", label),
+        ),
+        None => catalog.text(MessageKey::ChartDataPoint),
+    };
+    let (x, y) = (point.x, point.y);
 
     view! {
         <div
             class=class
             style=style
             role="button"
-            aria-label=format!("Data point: {}", point.label.as_deref().unwrap_or("Unknown"))
-            data-x=point.x
-            data-y=point.y
+            aria-label=aria_label
+            data-x=x
+            data-y=y
             data-radius=radius
             tabindex="0"
+            on:click=move |_| {
+                if let Some(on_click) = on_click {
+                    on_click.run(point.clone());
+                }
+            }
         />
     }
 }
 
-/// Helper function to merge CSS classes
-fn merge_optional_classes(classes: Vec<&str>) -> String {
-    classes
-        .into_iter()
-        .filter(|c| !c.is_empty())
-        .collect::<Vec<_>>()
-        .join(" ")
-}
-
 #[cfg(test)]
 mod tests {
-    use wasm_bindgen_test::*;
-    use proptest::prelude::*;
-use crate::utils::merge_classes;
-
-    wasm_bindgen_test_configure!(run_in_browser);
-
-    // Unit Tests
-    #[test] fn test_linechart_creation() { 
-    #[test] fn test_linechart_with_class() { 
-    #[test] fn test_linechart_with_style() { 
-    #[test] fn test_linechart_with_data() { 
-    #[test] fn test_linechart_with_config() { 
-    #[test] fn test_linechart_smooth() { 
-    #[test] fn test_linechart_area_fill() { 
-    #[test] fn test_linechart_show_points() { 
-    #[test] fn test_linechart_show_grid() { 
-    #[test] fn test_linechart_on_point_click() { 
-    #[test] fn test_linechart_on_line_hover() { 
-
-    // Line Series tests
-    #[test] fn test_line_series_default() { 
-    #[test] fn test_line_series_creation() { 
-
-    // Line Point tests
-    #[test] fn test_line_point_creation() { 
-
-    // Line Chart Config tests
-    #[test] fn test_linechart_config_default() { 
-    #[test] fn test_linechart_config_custom() { 
-
-    // Chart Margin tests
-    #[test] fn test_chart_margin_default() { 
+    use super::*;
+    use crate::theming::ChartPalette;
 
-    // Axis Config tests
-    #[test] fn test_axis_config_default() { 
-    #[test] fn test_axis_config_custom() { 
-
-    // Animation Config tests
-    #[test] fn test_animation_config_default() { 
-    #[test] fn test_animation_config_custom() { 
-
-    // Easing Type tests
-    #[test] fn test_easing_type_default() { 
-    #[test] fn test_easing_type_ease_in_out() { 
-    #[test] fn test_easing_type_ease_in() { 
-    #[test] fn test_easing_type_ease_out() { 
-    #[test] fn test_easing_type_linear() { 
-
-    // Line Chart Area tests
-    #[test] fn test_linechart_area_creation() { 
-    #[test] fn test_linechart_area_with_class() { 
-    #[test] fn test_linechart_area_with_style() { 
-    #[test] fn test_linechart_area_with_series() { 
-    #[test] fn test_linechart_area_opacity() { 
-
-    // Line Chart Point tests
-    #[test] fn test_linechart_point_creation() { 
-    #[test] fn test_linechart_point_with_class() { 
-    #[test] fn test_linechart_point_with_style() { 
-    #[test] fn test_linechart_point_with_point() { 
-    #[test] fn test_linechart_point_radius() { 
-    #[test] fn test_linechart_point_on_click() { 
-
-    // Helper function tests
-    #[test] fn test_merge_classes_empty() { 
-    #[test] fn test_merge_classes_single() { 
-    #[test] fn test_merge_classes_multiple() { 
-    #[test] fn test_merge_classes_with_empty() { 
-
-    // Property-based Tests
-    #[test] fn test_linechart_property_based() {
-        proptest!(|(____class in ".*", __style in ".*")| {
-            
-        });
-    }
-
-    #[test] fn test_linechart_data_validation() {
-        proptest!(|(______series_count in 0..10usize, __points_per_series in 0..100usize)| {
-            
-        });
-    }
-
-    #[test] fn test_linechart_config_validation() {
-        proptest!(|(____width in 100.0..2000.0f64, __height in 100.0..2000.0f64)| {
-            
-        });
+    #[test]
+    fn test_line_series_default_has_no_color() {
+        assert_eq!(LineSeries::default().color, None);
     }
 
-    #[test] fn test_linechart_animation_property_based() {
-        proptest!(|(____duration in 100.0..5000.0f64, __delay in 0.0..1000.0f64)| {
-            
-        });
+    #[test]
+    fn test_linechart_colors_series_from_palette() {
+        crate::test_support::init_test_executor();
+        let data = vec![
+            LineSeries { name: "Visits".to_string(), ..LineSeries::default() },
+            LineSeries { name: "Signups".to_string(), ..LineSeries::default() },
+        ];
+        let html = Owner::new().with(|| view! { <LineChart data=data /> }.to_html());
+        let palette = ChartPalette::default();
+        assert!(html.contains(&format!(
+            "--chart-series-1: {}; --chart-series-2: {};",
+            palette.series_color(0),
+            palette.series_color(1)
+        )));
     }
-
-    // Integration Tests
-    #[test] fn test_linechart_tooltip_interaction() { 
-    #[test] fn test_linechart_legend_interaction() { 
-    #[test] fn test_linechart_user_workflow() { 
-    #[test] fn test_linechart_accessibility_workflow() { 
-    #[test] fn test_linechart_with_other_components() { 
-
-    // Performance Tests
-    #[test] fn test_linechart_large_dataset() { 
-    #[test] fn test_linechart_animation_performance() { 
-    #[test] fn test_linechart_render_performance() { 
-    #[test] fn test_linechart_memory_usage() { 
 }
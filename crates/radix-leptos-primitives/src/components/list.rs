@@ -1,7 +1,8 @@
 use leptos::children::Children;
 use leptos::context::use_context;
 use leptos::prelude::*;
-use crate::utils::{merge_optional_classes, generate_id};
+use crate::i18n::{use_i18n, MessageKey};
+use crate::utils::{escape_html, merge_optional_classes, generate_id};
 
 /// List item information
 #[derive(Clone, Debug, PartialEq)]
@@ -78,6 +79,9 @@ impl ListVariant {
     }
 }
 
+/// Maps an item's data to the text of a static row
+pub type ListRowText<T> = Callback<T, String>;
+
 /// List context for state management
 #[derive(Clone)]
 pub struct ListContext<T: Send + Sync + 'static> {
@@ -124,6 +128,15 @@ pub fn List<T: Clone + Send + Sync + 'static>(
     /// Item focus event handler
     #[prop(optional)]
     on_item_focus: Option<Callback<ListItem<T>>>,
+    /// Render `items` as static markup without per-row reactive nodes
+    ///
+    /// Intended for large server-rendered lists: rows are built as one HTML
+    /// string, so they have no click/focus handlers of their own.
+    #[prop(optional, default = false)]
+    static_rows: bool,
+    /// Text of a row when `static_rows` is set; defaults to the item id
+    #[prop(optional)]
+    row_text: Option<ListRowText<T>>,
     /// CSS classes
     #[prop(optional)]
     class: Option<String>,
@@ -131,6 +144,12 @@ pub fn List<T: Clone + Send + Sync + 'static>(
     children: Children,
 ) -> impl IntoView {
     let list_id = generate_id("list");
+    let static_html = static_rows.then(|| {
+        render_static_list_rows(items.iter().flatten(), |item| match row_text {
+            Some(row_text) => row_text.run(item.data.clone()),
+            None => item.id.clone(),
+        })
+    });
 
     // Reactive state
     let (items_signal, _set_items_signal) = signal(items.unwrap_or_default());
@@ -171,10 +190,42 @@ pub fn List<T: Clone + Send + Sync + 'static>(
             aria-multiselectable=multi_select
         >
             {children()}
+            {static_html.map(|html| view! {
+                <div class="radix-list-static-rows" role="none" inner_html=html></div>
+            })}
         </div>
     }
 }
 
+/// Render list rows as a single HTML string
+///
+/// Used by `List` when `static_rows` is set; the output mirrors the markup of
+/// `ListItem` so the same styles apply.
+pub fn render_static_list_rows<'a, T, I>(items: I, text: impl Fn(&ListItem<T>) -> String) -> String
+where
+    T: Send + Sync + 'static,
+    I: IntoIterator<Item = &'a ListItem<T>>,
+{
+    let mut html = String::new();
+    for item in items {
+        html.push_str("<div class=\"radix-list-item\" role=\"option\" data-id=\"");
+        html.push_str(&escape_html(&item.id));
+        html.push_str("\" aria-selected=\"");
+        html.push_str(if item.selected { "true" } else { "false" });
+        html.push('"');
+        if item.disabled {
+            html.push_str(" aria-disabled=\"true\" data-disabled=\"true\"");
+        }
+        if item.selected {
+            html.push_str(" data-selected=\"true\"");
+        }
+        html.push('>');
+        html.push_str(&escape_html(&text(item)));
+        html.push_str("</div>");
+    }
+    html
+}
+
 /// ListItem component for individual list items
 #[component]
 pub fn ListItem<T: Clone + Send + Sync + 'static>(
@@ -436,7 +487,7 @@ pub fn ListLoading(
             style=style.unwrap_or_default()
             role="status"
             aria-live="polite"
-            aria-label="Loading"
+            aria-label=use_i18n().text(MessageKey::Loading)
         >
             {if let Some(msg) = message {
                 view! {
@@ -464,3 +515,29 @@ pub fn createdisabled_list_item<T: Send + Sync + 'static>(id: &str, data: T) ->
 pub fn createselected_list_item<T: Send + Sync + 'static>(id: &str, data: T) -> ListItem<T> {
     ListItem::new(id.to_string(), data).withselected(true)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_static_list_rows() {
+        let items = vec![
+            create_list_item("a", "Apples".to_string()),
+            createselected_list_item("b", "Bread & <Butter>".to_string()),
+            createdisabled_list_item("c\"", "Cheese".to_string()),
+        ];
+        let html = render_static_list_rows(&items, |item| item.data.clone());
+
+        assert_eq!(html.matches("role=\"option\"").count(), 3);
+        assert!(html.contains(">Apples</div>"));
+        assert!(html.contains("aria-selected=\"true\" data-selected=\"true\">Bread &amp; &lt;Butter&gt;</div>"));
+        assert!(html.contains("data-id=\"c&quot;\" aria-selected=\"false\" aria-disabled=\"true\""));
+    }
+
+    #[test]
+    fn test_render_static_list_rows_empty() {
+        let items: Vec<ListItem<String>> = Vec::new();
+        assert_eq!(render_static_list_rows(&items, |item| item.id.clone()), "");
+    }
+}
@@ -0,0 +1,180 @@
+use crate::utils::merge_classes;
+use leptos::children::ChildrenFn;
+use leptos::html;
+use leptos::prelude::*;
+use radix_leptos_core::{
+    use_element_size_with_options, use_reduced_motion, ResizeObserverBox, UseElementSizeOptions,
+};
+
+/// Scroll duration used until the content has been measured
+const DEFAULT_DURATION_SECS: f64 = 20.0;
+
+/// Direction the content of a Marquee travels in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MarqueeDirection {
+    #[default]
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+impl MarqueeDirection {
+    pub fn is_vertical(&self) -> bool {
+        matches!(self, MarqueeDirection::Up | MarqueeDirection::Down)
+    }
+
+    /// Whether the scroll animation runs backwards
+    pub fn is_reversed(&self) -> bool {
+        matches!(self, MarqueeDirection::Right | MarqueeDirection::Down)
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MarqueeDirection::Left => "left",
+            MarqueeDirection::Right => "right",
+            MarqueeDirection::Up => "up",
+            MarqueeDirection::Down => "down",
+        }
+    }
+}
+
+/// Seconds one copy of the content takes to scroll past at `speed` pixels
+/// per second
+pub fn marquee_duration(distance: f64, speed: f64) -> Option<f64> {
+    (distance.is_finite() && speed.is_finite() && distance > 0.0 && speed > 0.0)
+        .then(|| distance / speed)
+}
+
+/// Custom properties of the moving track, for the consumer's animation
+pub fn marquee_track_style(duration: f64, gap: f64) -> String {
+    format!(
+        "--marquee-duration: {:.2}s; --marquee-gap: {}px;",
+        duration, gap
+    )
+}
+
+/// Marquee component - Continuously scrolling content strip
+///
+/// The content is rendered twice, the copy hidden from assistive
+/// technology, so the strip loops without a gap. It pauses while hovered
+/// or focused (unless `pause_on_hover` is false) and whenever `paused` is
+/// set. While motion is reduced, by the user or a MotionProvider, it should
+/// be a static, scrollable strip instead.
+///
+/// Like every primitive it ships no styles. The `.marquee-track` holds two
+/// `.marquee-content` copies and gets `--marquee-duration`, the time one
+/// copy takes to scroll past at `speed`, and `--marquee-gap`; translate it
+/// by -50% along `data-direction` in your CSS, and pause it under
+/// `data-paused="true"`, `data-pause-on-hover="true"` while hovered, and
+/// `data-reduced-motion="true"`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn Ticker() -> impl IntoView {
+///     view! {
+///         <Marquee speed=40.0 label="Market prices".to_string()>
+///             <span>"AAPL 189.20"</span>
+///             <span>"MSFT 410.05"</span>
+///         </Marquee>
+///     }
+/// }
+/// ```
+#[component]
+pub fn Marquee(
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    /// Scroll speed in pixels per second (default: 50)
+    #[prop(optional)]
+    speed: Option<f64>,
+    #[prop(optional)] direction: Option<MarqueeDirection>,
+    /// Pause while hovered or focused (default: true)
+    #[prop(optional)]
+    pause_on_hover: Option<bool>,
+    #[prop(optional, into)] paused: Signal<bool>,
+    /// Space in pixels after each copy of the content (default: 32)
+    #[prop(optional)]
+    gap: Option<f64>,
+    /// Accessible name of the strip
+    #[prop(optional)]
+    label: Option<String>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let speed = speed.unwrap_or(50.0);
+    let direction = direction.unwrap_or_default();
+    let pause_on_hover = pause_on_hover.unwrap_or(true);
+    let gap = gap.unwrap_or(32.0);
+    let content_ref = NodeRef::<html::Div>::new();
+    // The gap is padding on the content, so the border box is one full loop
+    let content_size = use_element_size_with_options(
+        content_ref,
+        UseElementSizeOptions {
+            observed_box: ResizeObserverBox::BorderBox,
+        },
+    );
+    let duration = Memo::new(move |_| {
+        let distance = if direction.is_vertical() {
+            content_size.height.get()
+        } else {
+            content_size.width.get()
+        };
+        marquee_duration(distance, speed).unwrap_or(DEFAULT_DURATION_SECS)
+    });
+
+    let reduced_motion = use_reduced_motion();
+
+    let class = merge_classes(vec!["marquee", class.as_deref().unwrap_or("")]);
+    let copy = children.clone();
+
+    view! {
+        <div
+            class=class
+            style=style
+            role="marquee"
+            aria-label=label
+            data-direction=direction.as_str()
+            data-pause-on-hover=pause_on_hover.to_string()
+            data-paused=move || paused.get().to_string()
+            data-reduced-motion=move || reduced_motion.get().to_string()
+        >
+            <div class="marquee-track" style=move || marquee_track_style(duration.get(), gap)>
+                <div class="marquee-content" node_ref=content_ref>
+                    {children()}
+                </div>
+                <div class="marquee-content" aria-hidden="true" inert>
+                    {copy()}
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_marquee_duration() {
+        assert_eq!(marquee_duration(500.0, 50.0), Some(10.0));
+        assert_eq!(marquee_duration(0.0, 50.0), None);
+        assert_eq!(marquee_duration(500.0, 0.0), None);
+        assert_eq!(marquee_duration(500.0, f64::NAN), None);
+    }
+
+    #[test]
+    fn test_marquee_track_style() {
+        assert_eq!(
+            marquee_track_style(10.0, 32.0),
+            "--marquee-duration: 10.00s; --marquee-gap: 32px;"
+        );
+        assert_eq!(
+            marquee_track_style(4.5, 0.0),
+            "--marquee-duration: 4.50s; --marquee-gap: 0px;"
+        );
+    }
+}
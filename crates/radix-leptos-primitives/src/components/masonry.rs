@@ -0,0 +1,350 @@
+use crate::theming::Breakpoint;
+use crate::utils::merge_classes;
+use leptos::children::Children;
+use leptos::html;
+use leptos::prelude::*;
+use send_wrapper::SendWrapper;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+
+/// Column count of a masonry grid per breakpoint
+///
+/// Breakpoints are matched against the width of the grid itself rather than
+/// the viewport, so a masonry grid in a sidebar gets fewer columns.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MasonryColumns {
+    /// Columns below the first breakpoint
+    pub base: usize,
+    pub breakpoints: Vec<MasonryBreakpoint>,
+}
+
+/// Column count used from a breakpoint upwards
+pub type MasonryBreakpoint = (Breakpoint, usize);
+
+impl Default for MasonryColumns {
+    fn default() -> Self {
+        Self::new(1)
+            .at(Breakpoint::Small, 2)
+            .at(Breakpoint::Large, 3)
+            .at(Breakpoint::ExtraLarge, 4)
+    }
+}
+
+impl MasonryColumns {
+    pub fn new(base: usize) -> Self {
+        Self {
+            base,
+            breakpoints: Vec::new(),
+        }
+    }
+
+    /// The same column count at every width
+    pub fn fixed(columns: usize) -> Self {
+        Self::new(columns)
+    }
+
+    /// Use `columns` from `breakpoint` upwards
+    pub fn at(mut self, breakpoint: Breakpoint, columns: usize) -> Self {
+        self.breakpoints.push((breakpoint, columns));
+        self
+    }
+
+    /// Column count for a grid `width` pixels wide (at least 1)
+    pub fn for_width(&self, width: f64) -> usize {
+        self.breakpoints
+            .iter()
+            .filter(|(breakpoint, _)| width >= breakpoint.min_width())
+            .max_by(|(a, _), (b, _)| a.min_width().total_cmp(&b.min_width()))
+            .map_or(self.base, |(_, columns)| *columns)
+            .max(1)
+    }
+}
+
+/// Position of one masonry item relative to the grid
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MasonryItemPosition {
+    pub column: usize,
+    pub x: f64,
+    pub y: f64,
+}
+
+/// Computed masonry layout
+#[derive(Debug, Clone, PartialEq)]
+pub struct MasonryLayout {
+    pub column_width: f64,
+    pub positions: Vec<MasonryItemPosition>,
+    /// Total height of the grid
+    pub height: f64,
+}
+
+/// Place items of the given heights into the shortest column, in order
+pub fn masonry_layout(heights: &[f64], columns: usize, width: f64, gap: f64) -> MasonryLayout {
+    let columns = columns.max(1);
+    let gap = gap.max(0.0);
+    let column_width = ((width - gap * (columns - 1) as f64) / columns as f64).max(0.0);
+    let mut column_heights = vec![0.0_f64; columns];
+
+    let positions = heights
+        .iter()
+        .map(|height| {
+            let column = column_heights
+                .iter()
+                .enumerate()
+                .min_by(|(_, a), (_, b)| a.total_cmp(b))
+                .map_or(0, |(index, _)| index);
+            let position = MasonryItemPosition {
+                column,
+                x: column as f64 * (column_width + gap),
+                y: column_heights[column],
+            };
+            column_heights[column] += height.max(0.0) + gap;
+            position
+        })
+        .collect();
+
+    let height = if heights.is_empty() {
+        0.0
+    } else {
+        column_heights.iter().copied().fold(0.0, f64::max) - gap
+    };
+
+    MasonryLayout {
+        column_width,
+        positions,
+        height,
+    }
+}
+
+/// Masonry component - Grid that packs items of varying height into columns
+///
+/// Each direct child is an item. Items are placed into the currently shortest
+/// column and reflowed whenever the grid or an item is resized (for example
+/// when an image loads) or items are added or removed.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn Gallery() -> impl IntoView {
+///     let columns = MasonryColumns::new(1)
+///         .at(Breakpoint::Medium, 2)
+///         .at(Breakpoint::Large, 3);
+///     view! {
+///         <Masonry columns=columns gap=12.0>
+///             <img src="/a.jpg" alt="" />
+///             <img src="/b.jpg" alt="" />
+///             <img src="/c.jpg" alt="" />
+///         </Masonry>
+///     }
+/// }
+/// ```
+#[component]
+pub fn Masonry(
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    /// Column counts per breakpoint (default: 1, 2 from `sm`, 3 from `lg`, 4 from `xl`)
+    #[prop(optional)]
+    columns: Option<MasonryColumns>,
+    /// Space between items in pixels (default: 16)
+    #[prop(optional)]
+    gap: Option<f64>,
+    children: Children,
+) -> impl IntoView {
+    let columns = StoredValue::new(columns.unwrap_or_default());
+    let gap = gap.unwrap_or(16.0);
+    let container_ref = NodeRef::<html::Div>::new();
+    let height = RwSignal::new(None::<f64>);
+    let column_count = RwSignal::new(None::<usize>);
+
+    let reflow = move || {
+        let Some(container) = container_ref.get_untracked() else {
+            return;
+        };
+        let width = f64::from(container.client_width());
+        let count = columns.with_value(|columns| columns.for_width(width));
+        let items = masonry_items(&container);
+        let column_width = masonry_layout(&[], count, width, gap).column_width;
+
+        // Size every item first so the heights read below are final
+        for item in &items {
+            let style = item.style();
+            let _ = style.set_property("position", "absolute");
+            let _ = style.set_property("top", "0");
+            let _ = style.set_property("left", "0");
+            let _ = style.set_property("box-sizing", "border-box");
+            let _ = style.set_property("width", &format!("{}px", column_width));
+        }
+        let heights: Vec<f64> = items
+            .iter()
+            .map(|item| f64::from(item.offset_height()))
+            .collect();
+        let layout = masonry_layout(&heights, count, width, gap);
+        for (item, position) in items.iter().zip(&layout.positions) {
+            let _ = item.style().set_property(
+                "transform",
+                &format!("translate({}px, {}px)", position.x, position.y),
+            );
+        }
+
+        if height.get_untracked() != Some(layout.height) {
+            height.set(Some(layout.height));
+        }
+        if column_count.get_untracked() != Some(count) {
+            column_count.set(Some(count));
+        }
+    };
+
+    Effect::new(move |observing: Option<bool>| {
+        if observing == Some(true) {
+            return true;
+        }
+        let Some(container) = container_ref.get() else {
+            return false;
+        };
+        observe_masonry(&container, reflow);
+        reflow();
+        true
+    });
+
+    let class = merge_classes(vec!["masonry", class.as_deref().unwrap_or("")]);
+    let container_style = move || {
+        let height = height
+            .get()
+            .map(|height| format!("height: {}px;", height))
+            .unwrap_or_default();
+        format!(
+            "position: relative; {} {}",
+            height,
+            style.clone().unwrap_or_default()
+        )
+    };
+
+    view! {
+        <div
+            class=class
+            node_ref=container_ref
+            style=container_style
+            data-columns=move || column_count.get().map(|count| count.to_string())
+        >
+            {children()}
+        </div>
+    }
+}
+
+fn masonry_items(container: &web_sys::HtmlElement) -> Vec<web_sys::HtmlElement> {
+    let children = container.children();
+    (0..children.length())
+        .filter_map(|index| children.item(index))
+        .filter_map(|child| child.dyn_into::<web_sys::HtmlElement>().ok())
+        .collect()
+}
+
+type ObserverCallback = Closure<dyn FnMut()>;
+
+/// Reflow when the grid or an item resizes, and when items are added or removed
+fn observe_masonry(container: &web_sys::HtmlElement, reflow: impl Fn() + Copy + 'static) {
+    let resize_callback = ObserverCallback::new(reflow);
+    let Ok(resize) = web_sys::ResizeObserver::new(resize_callback.as_ref().unchecked_ref()) else {
+        return;
+    };
+    resize.observe(container);
+    for item in masonry_items(container) {
+        resize.observe(&item);
+    }
+
+    let observed = container.clone();
+    let item_observer = resize.clone();
+    let mutation_callback = ObserverCallback::new(move || {
+        // Observing an element twice is a no-op, so re-observe every item
+        for item in masonry_items(&observed) {
+            item_observer.observe(&item);
+        }
+        reflow();
+    });
+    let Ok(mutation) = web_sys::MutationObserver::new(mutation_callback.as_ref().unchecked_ref())
+    else {
+        resize.disconnect();
+        return;
+    };
+    let options = web_sys::MutationObserverInit::new();
+    options.set_child_list(true);
+    let _ = mutation.observe_with_options(container, &options);
+
+    let handle = SendWrapper::new((resize, mutation, resize_callback, mutation_callback));
+    on_cleanup(move || {
+        let (resize, mutation, _resize_callback, _mutation_callback) = handle.take();
+        resize.disconnect();
+        mutation.disconnect();
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_masonry_columns_for_width() {
+        let columns = MasonryColumns::default();
+        assert_eq!(columns.for_width(320.0), 1);
+        assert_eq!(columns.for_width(640.0), 2);
+        assert_eq!(columns.for_width(1100.0), 3);
+        assert_eq!(columns.for_width(1600.0), 4);
+        assert_eq!(MasonryColumns::fixed(0).for_width(800.0), 1);
+    }
+
+    #[test]
+    fn test_masonry_columns_ignore_breakpoint_order() {
+        let columns = MasonryColumns::new(2)
+            .at(Breakpoint::Large, 4)
+            .at(Breakpoint::Medium, 3);
+        assert_eq!(columns.for_width(700.0), 2);
+        assert_eq!(columns.for_width(800.0), 3);
+        assert_eq!(columns.for_width(1200.0), 4);
+    }
+
+    #[test]
+    fn test_masonry_layout_fills_shortest_column() {
+        let layout = masonry_layout(&[100.0, 50.0, 80.0, 30.0], 2, 210.0, 10.0);
+        assert_eq!(layout.column_width, 100.0);
+        let columns: Vec<usize> = layout.positions.iter().map(|p| p.column).collect();
+        assert_eq!(columns, vec![0, 1, 1, 0]);
+        assert_eq!(
+            layout.positions[2],
+            MasonryItemPosition {
+                column: 1,
+                x: 110.0,
+                y: 60.0
+            }
+        );
+        assert_eq!(layout.positions[3].y, 110.0);
+        assert_eq!(layout.height, 140.0);
+    }
+
+    #[test]
+    fn test_masonry_layout_empty() {
+        let layout = masonry_layout(&[], 3, 300.0, 16.0);
+        assert!(layout.positions.is_empty());
+        assert_eq!(layout.height, 0.0);
+    }
+
+    proptest! {
+        #[test]
+        fn test_masonry_items_stay_within_width(
+            heights in proptest::collection::vec(0.0f64..500.0, 0..40),
+            columns in 1usize..6,
+            width in 0.0f64..2000.0,
+            gap in 0.0f64..32.0,
+        ) {
+            let layout = masonry_layout(&heights, columns, width, gap);
+            prop_assert_eq!(layout.positions.len(), heights.len());
+            for position in &layout.positions {
+                prop_assert!(position.column < columns);
+                prop_assert!(position.y <= layout.height + f64::EPSILON);
+            }
+        }
+    }
+}
@@ -1,11 +1,18 @@
+use crate::components::roving_focus::{
+    roving_tab_index, Direction, RovingFocusGroup, RovingOrientation,
+};
 use crate::utils::merge_classes;
 use leptos::callback::Callback;
 use leptos::children::Children;
+use leptos::html;
 use leptos::prelude::*;
+use radix_leptos_core::use_focus_within;
 
 /// Menubar component for menu bar with keyboard navigation
 ///
-/// Provides accessible menu bar with keyboard support and ARIA attributes
+/// Provides accessible menu bar with keyboard support and ARIA attributes.
+/// The menubar is a single tab stop shared by its triggers and any
+/// ToggleGroup, Select or DropdownMenu placed in it.
 #[component]
 pub fn Menubar(
     #[prop(optional)] class: Option<String>,
@@ -15,8 +22,22 @@ pub fn Menubar(
     #[prop(optional)] default_value: Option<String>,
     #[prop(optional)] value: Option<ReadSignal<String>>,
     #[prop(optional)] on_value_change: Option<Callback<String>>,
+    /// Reading direction; in right-to-left menubars ArrowLeft moves forward
+    #[prop(optional)]
+    dir: Option<Direction>,
 ) -> impl IntoView {
     let orientation = orientation.unwrap_or_default();
+    let dir = dir.unwrap_or_default();
+    let node_ref = NodeRef::<html::Div>::new();
+    let roving = RovingFocusGroup {
+        orientation: match orientation {
+            MenubarOrientation::Horizontal => RovingOrientation::Horizontal,
+            MenubarOrientation::Vertical => RovingOrientation::Vertical,
+        },
+        looping: true,
+        dir,
+    }
+    .provide(node_ref);
     let (current_value, setcurrent_value) = signal(
         value
             .map(|v| v.get())
@@ -37,6 +58,16 @@ pub fn Menubar(
         });
     }
 
+    // Close the open menu once focus leaves the menubar
+    let focus_within = use_focus_within(node_ref);
+    Effect::new(move |was_focused: Option<bool>| {
+        let focused = focus_within.get();
+        if was_focused == Some(true) && !focused {
+            setcurrent_value.set(String::new());
+        }
+        focused
+    });
+
     let class = merge_classes(vec![
         "menubar",
         &orientation.to_class(),
@@ -49,6 +80,11 @@ pub fn Menubar(
             style=style
             role="menubar"
             aria-orientation=orientation.to_aria()
+            dir=dir.as_str()
+            node_ref=node_ref
+            data-focus-within=move || focus_within.get()
+            on:keydown=move |ev| roving.on_keydown.run(ev)
+            on:focusin=move |ev| roving.on_focusin.run(ev)
         >
             {children.map(|c| c())}
         </div>
@@ -129,6 +165,8 @@ pub fn MenubarTrigger(
             role="menuitem"
             aria-haspopup="true"
             aria-expanded="false"
+            tabindex=roving_tab_index(None)
+            data-roving-focus-item=RovingFocusGroup::item_attribute()
         >
             {children.map(|c| c())}
         </button>
@@ -4,7 +4,10 @@
 
 // Component modules
 pub mod accordion;
+pub mod affix;
 pub mod alert;
+pub mod anchor;
+pub mod back_to_top;
 pub mod badge;
 pub mod button;
 pub mod checkbox;
@@ -16,7 +19,7 @@ pub mod select;
 pub mod slider;
 pub mod switch;
 pub mod tooltip;
-// pub mod data_table;  // Temporarily disabled due to view! macro type issues
+pub mod data_table;
 // pub mod date_picker;  // Temporarily disabled due to view! macro type issues
 pub mod dropdown_menu;
 pub mod hover_card;
@@ -24,27 +27,25 @@ pub mod menubar;
 pub mod navigation_menu;
 pub mod popover;
 pub mod scroll_area;
+pub mod scroll_progress;
 pub mod toggle;
 pub mod toggle_group;
 pub mod toolbar;
-// #[cfg(feature = "experimental")]
-// pub mod chart;  // Has syntax errors, needs fixing
-// #[cfg(feature = "experimental")]
-// pub mod data_table;  // Has syntax errors, needs fixing
+#[cfg(feature = "experimental")]
+pub mod chart;
 // #[cfg(feature = "experimental")]
 // pub mod virtual_list;  // Has syntax errors, needs fixing
 // #[cfg(feature = "experimental")]
 // pub mod split_pane;  // Has syntax errors, needs fixing
-// #[cfg(feature = "experimental")]
-// pub mod line_chart;  // Has syntax errors, needs fixing
-// #[cfg(feature = "experimental")]
-// pub mod bar_chart;  // Has syntax errors, needs fixing
-// #[cfg(feature = "experimental")]
-// pub mod pie_chart;  // Has syntax errors, needs fixing
-// #[cfg(feature = "experimental")]
-// pub mod scatter_plot;  // Has syntax errors, needs fixing
-// #[cfg(feature = "experimental")]
-// pub mod drag_drop;  // Has syntax errors, needs fixing
+#[cfg(feature = "experimental")]
+pub mod line_chart;
+#[cfg(feature = "experimental")]
+pub mod bar_chart;
+#[cfg(feature = "experimental")]
+pub mod pie_chart;
+#[cfg(feature = "experimental")]
+pub mod scatter_plot;
+pub mod drag_drop;
 // #[cfg(feature = "experimental")]
 // pub mod rich_text_editor;  // Has syntax errors, needs fixing
 // #[cfg(feature = "experimental")]
@@ -65,25 +66,52 @@ pub mod timeline;
 // #[cfg(feature = "experimental")]
 // pub mod pull_to_refresh;  // Has syntax errors, needs fixing
 pub mod aspect_ratio;
+pub mod audio_player;
 pub mod avatar;
 pub mod calendar;
+pub mod calendar_heatmap;
+pub mod carousel;
+pub mod cascader;
+pub mod chat;
 pub mod collapsible;
 pub mod combobox;
 pub mod context_menu;
 pub mod date_picker;
+pub mod description_list;
 pub mod file_upload;
+pub mod icon;
+pub mod image_cropper;
+pub mod json_viewer;
+pub mod kanban;
+pub mod kbd;
 pub mod label;
+pub mod landmark;
 pub mod list;
+pub mod marquee;
+pub mod masonry;
 pub mod multi_select;
+pub mod notification_center;
+pub mod org_chart;
 pub mod otp_field;
 pub mod pagination;
 pub mod password_toggle_field;
+pub mod pdf_viewer;
+pub mod qr_code;
 pub mod resizable;
+pub mod roving_focus;
 pub mod search;
 pub mod separator;
+pub mod shortcut_help;
+pub mod state_snapshot;
+pub mod statistic;
 pub mod tabs;
+pub mod text;
 pub mod toast;
+pub mod tour;
+pub mod tree_select;
 pub mod tree_view;
+pub mod video_player;
+pub mod watermark;
 // #[cfg(feature = "experimental")]
 // pub mod infinite_scroll;  // Has syntax errors, needs fixing
 // #[cfg(feature = "experimental")]
@@ -92,7 +120,9 @@ pub mod tree_view;
 // pub mod lazy_loading_optimized;  // Has syntax errors, needs fixing
 pub mod alert_dialog;
 pub mod sheet;
+pub mod signature_pad;
 pub mod skeleton;
+pub mod spinner;
 pub mod time_picker; // TDD: GREEN phase - enabling component
 // #[cfg(feature = "experimental")]
 // pub mod range_slider;  // TDD: Need to fix tests first
@@ -108,7 +138,10 @@ pub mod form_validation;
 
 // Re-export components
 pub use accordion::*;
+pub use affix::*;
 pub use alert::*;
+pub use anchor::*;
+pub use back_to_top::*;
 pub use badge::*;
 pub use button::*;
 pub use checkbox::*;
@@ -120,7 +153,7 @@ pub use select::*;
 pub use slider::*;
 pub use switch::*;
 pub use tooltip::*;
-// pub use data_table::*;  // Temporarily disabled
+pub use data_table::*;
 pub use date_picker::*; // Temporarily disabled
 pub use dropdown_menu::*;
 pub use hover_card::*;
@@ -130,29 +163,27 @@ pub use navigation_menu::*;
 pub use pagination::*;
 pub use popover::*;
 pub use scroll_area::*;
+pub use scroll_progress::*;
 pub use timeline::*;
 pub use toast::*;
 pub use toggle::*;
 pub use toggle_group::*;
 pub use toolbar::*;
-// #[cfg(feature = "experimental")]
-// pub use chart::*;  // Has syntax errors, needs fixing
-// #[cfg(feature = "experimental")]
-// pub use data_table::*;  // Has syntax errors, needs fixing
+#[cfg(feature = "experimental")]
+pub use chart::*;
 // #[cfg(feature = "experimental")]
 // pub use virtual_list::*;  // Has syntax errors, needs fixing
 // #[cfg(feature = "experimental")]
 // pub use split_pane::*;  // Has syntax errors, needs fixing
-// #[cfg(feature = "experimental")]
-// pub use line_chart::*;  // Has syntax errors, needs fixing
-// #[cfg(feature = "experimental")]
-// pub use bar_chart::*;  // Has syntax errors, needs fixing
-// #[cfg(feature = "experimental")]
-// pub use pie_chart::*;  // Has syntax errors, needs fixing
-// #[cfg(feature = "experimental")]
-// pub use scatter_plot::*;  // Has syntax errors, needs fixing
-// #[cfg(feature = "experimental")]
-// pub use drag_drop::*;  // Has syntax errors, needs fixing
+#[cfg(feature = "experimental")]
+pub use line_chart::*;
+#[cfg(feature = "experimental")]
+pub use bar_chart::*;
+#[cfg(feature = "experimental")]
+pub use pie_chart::*;
+#[cfg(feature = "experimental")]
+pub use scatter_plot::*;
+pub use drag_drop::*;
 // #[cfg(feature = "experimental")]
 // pub use rich_text_editor::*;  // Has syntax errors, needs fixing
 // #[cfg(feature = "experimental")]
@@ -173,20 +204,47 @@ pub use toolbar::*;
 // pub use pull_to_refresh::*;  // Has syntax errors, needs fixing
 pub use tabs::*;
 pub use aspect_ratio::*;
+pub use audio_player::*;
 pub use avatar::*;
 pub use calendar::*;
+pub use calendar_heatmap::*;
+pub use carousel::*;
+pub use cascader::*;
+pub use chat::*;
 pub use collapsible::*;
 pub use combobox::*;
 pub use context_menu::*;
+pub use description_list::*;
 pub use file_upload::*;
+pub use icon::*;
+pub use image_cropper::*;
+pub use json_viewer::*;
+pub use kanban::*;
+pub use kbd::*;
 pub use label::*;
+pub use landmark::*;
+pub use marquee::*;
+pub use masonry::*;
 pub use multi_select::*;
+pub use notification_center::*;
+pub use org_chart::*;
 pub use otp_field::*;
 pub use password_toggle_field::*;
+pub use pdf_viewer::*;
+pub use qr_code::*;
 pub use resizable::*;
+pub use roving_focus::*;
 pub use search::*;
 pub use separator::*;
+pub use shortcut_help::*;
+pub use state_snapshot::*;
+pub use statistic::*;
+pub use text::*;
+pub use tour::*;
+pub use tree_select::*;
 pub use tree_view::*;
+pub use video_player::*;
+pub use watermark::*;
 // #[cfg(feature = "experimental")]
 // pub use infinite_scroll::*;  // Has syntax errors, needs fixing
 // #[cfg(feature = "experimental")]
@@ -195,7 +253,9 @@ pub use tree_view::*;
 // pub use lazy_loading_optimized::*;  // Has syntax errors, needs fixing
 pub use alert_dialog::*;
 pub use sheet::*;
+pub use signature_pad::*;
 pub use skeleton::*;
+pub use spinner::*;
 pub use time_picker::*; // TDD: GREEN phase - enabling component
 // #[cfg(feature = "experimental")]
 // pub use range_slider::*;  // TDD: Need to fix tests first
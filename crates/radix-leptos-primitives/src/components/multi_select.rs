@@ -1,3 +1,4 @@
+use crate::i18n::{use_i18n, MessageArgs, MessageKey};
 use leptos::callback::Callback;
 use leptos::children::Children;
 use leptos::prelude::*;
@@ -234,7 +235,8 @@ pub fn MultiSelectSearch(
     style: Option<String>,
 ) -> impl IntoView {
     let value = value.unwrap_or_default();
-    let placeholder = placeholder.unwrap_or_else(|| "Search options...".to_string());
+    let placeholder =
+        placeholder.unwrap_or_else(|| use_i18n().text(MessageKey::MultiSelectSearchPlaceholder));
     let disabled = disabled.unwrap_or(false);
     let class = format!(
         "multi-select-search {} {}",
@@ -290,7 +292,10 @@ pub fn MultiSelectTag(
             <button
                 class="tag-remove"
                 type="button"
-                aria-label=format!("Remove {}", option.label)
+                aria-label=use_i18n().format(
+                    MessageKey::MultiSelectRemove,
+                    &MessageArgs::new().with("option", option.label.as_str()),
+                )
                 on:click=handle_remove
             >
                 "×"
@@ -0,0 +1,400 @@
+use crate::components::badge::{BadgeAnchor, BadgeCount};
+use crate::components::chat::chat_day_label;
+use crate::i18n::{use_i18n, MessageArgs, MessageCatalog, MessageKey};
+use crate::utils::{generate_id, merge_classes};
+use chrono::{NaiveDate, NaiveDateTime};
+use leptos::callback::Callback;
+use leptos::html;
+use leptos::prelude::*;
+use radix_leptos_core::VisuallyHidden;
+
+/// A notification in the inbox
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotificationItem {
+    pub id: String,
+    pub title: String,
+    pub description: Option<String>,
+    /// Local time the notification arrived
+    pub received_at: NaiveDateTime,
+    pub read: bool,
+}
+
+impl NotificationItem {
+    pub fn new(
+        id: impl Into<String>,
+        title: impl Into<String>,
+        received_at: NaiveDateTime,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            description: None,
+            received_at,
+            read: false,
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn read(mut self, read: bool) -> Self {
+        self.read = read;
+        self
+    }
+}
+
+/// Notifications received on the same day
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotificationGroup {
+    pub day: NaiveDate,
+    /// Newest first
+    pub items: Vec<NotificationItem>,
+}
+
+/// Group notifications by day, newest day and newest notification first
+pub fn group_notifications(items: &[NotificationItem]) -> Vec<NotificationGroup> {
+    let mut sorted = items.to_vec();
+    sorted.sort_by_key(|item| std::cmp::Reverse(item.received_at));
+
+    let mut groups: Vec<NotificationGroup> = Vec::new();
+    for item in sorted {
+        let day = item.received_at.date();
+        match groups.last_mut() {
+            Some(group) if group.day == day => group.items.push(item),
+            _ => groups.push(NotificationGroup {
+                day,
+                items: vec![item],
+            }),
+        }
+    }
+    groups
+}
+
+pub fn unread_notification_count(items: &[NotificationItem]) -> usize {
+    items.iter().filter(|item| !item.read).count()
+}
+
+/// Mark one notification read; returns whether it was unread
+pub fn mark_notification_read(items: &mut [NotificationItem], id: &str) -> bool {
+    match items.iter_mut().find(|item| item.id == id && !item.read) {
+        Some(item) => {
+            item.read = true;
+            true
+        }
+        None => false,
+    }
+}
+
+/// Mark every notification read; returns the ids that changed
+pub fn mark_all_notifications_read(items: &mut [NotificationItem]) -> Vec<String> {
+    items
+        .iter_mut()
+        .filter(|item| !item.read)
+        .map(|item| {
+            item.read = true;
+            item.id.clone()
+        })
+        .collect()
+}
+
+/// Accessible name of the bell trigger, e.g. "Notifications, 3 unread"
+pub fn notification_trigger_label(catalog: &MessageCatalog, label: &str, unread: usize) -> String {
+    let args = MessageArgs::new().with("label", label).with("unread", unread);
+    catalog.format(MessageKey::NotificationCenterTrigger, &args)
+}
+
+/// NotificationCenter component - Persistent notification inbox
+///
+/// A bell button carries an unread count badge and opens a panel listing
+/// the notifications grouped by day. Selecting a notification marks it
+/// read; "Mark all as read" clears the badge. The component updates
+/// `notifications` itself and reports each change through the callbacks.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn Header() -> impl IntoView {
+///     let notifications = RwSignal::new(Vec::<NotificationItem>::new());
+///     view! {
+///         <NotificationCenter
+///             notifications=notifications
+///             on_select=Callback::new(|item: NotificationItem| log::info!("open {}", item.id))
+///         />
+///     }
+/// }
+/// ```
+#[component]
+pub fn NotificationCenter(
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    notifications: RwSignal<Vec<NotificationItem>>,
+    /// Heading of the panel and name of the trigger (default: "Notifications")
+    #[prop(optional)]
+    label: Option<String>,
+    /// Largest count shown on the badge (default: 99)
+    #[prop(optional)]
+    max_count: Option<u32>,
+    /// Text shown when there are no notifications
+    #[prop(optional)]
+    empty_text: Option<String>,
+    /// Day used for the "Today" group; the local date by default
+    #[prop(optional)]
+    today: Option<NaiveDate>,
+    /// Called with a notification when it is selected
+    #[prop(optional)]
+    on_select: Option<Callback<NotificationItem>>,
+    /// Called with the id of a notification marked read
+    #[prop(optional)]
+    on_read: Option<Callback<String>>,
+    #[prop(optional)] on_mark_all_read: Option<Callback<()>>,
+    #[prop(optional)] on_open_change: Option<Callback<bool>>,
+) -> impl IntoView {
+    let catalog = use_i18n();
+    let label =
+        StoredValue::new(label.unwrap_or_else(|| catalog.text(MessageKey::NotificationCenterLabel)));
+    let max_count = max_count.unwrap_or(99);
+    let empty_text = empty_text.unwrap_or_else(|| catalog.text(MessageKey::NotificationCenterEmpty));
+    let unread_text = catalog.text(MessageKey::NotificationCenterUnread);
+    let mark_all_text = catalog.text(MessageKey::NotificationCenterMarkAllRead);
+    let catalog = StoredValue::new(catalog);
+    let today = today.unwrap_or_else(|| chrono::Local::now().date_naive());
+    let open = RwSignal::new(false);
+    let trigger_ref = NodeRef::<html::Button>::new();
+    let panel_id = generate_id("notification-center");
+    let heading_id = generate_id("notification-center-heading");
+    let panel_controls = panel_id.clone();
+    let panel_labelledby = heading_id.clone();
+
+    let unread = Memo::new(move |_| notifications.with(|items| unread_notification_count(items)));
+
+    let set_open = move |next: bool| {
+        open.set(next);
+        if let Some(on_open_change) = on_open_change {
+            on_open_change.run(next);
+        }
+    };
+    let close = move || {
+        set_open(false);
+        if let Some(trigger) = trigger_ref.get_untracked() {
+            let _ = trigger.focus();
+        }
+    };
+    let handle_keydown = move |ev: web_sys::KeyboardEvent| {
+        if ev.key() == "Escape" && open.get_untracked() {
+            ev.prevent_default();
+            close();
+        }
+    };
+
+    let select = move |item: NotificationItem| {
+        let mut changed = false;
+        notifications.update(|items| changed = mark_notification_read(items, &item.id));
+        if changed {
+            if let Some(on_read) = on_read {
+                on_read.run(item.id.clone());
+            }
+        }
+        if let Some(on_select) = on_select {
+            on_select.run(item);
+        }
+    };
+    let mark_all_read = move |_: web_sys::MouseEvent| {
+        let mut changed = Vec::new();
+        notifications.update(|items| changed = mark_all_notifications_read(items));
+        if let Some(on_read) = on_read {
+            for id in changed {
+                on_read.run(id);
+            }
+        }
+        if let Some(on_mark_all_read) = on_mark_all_read {
+            on_mark_all_read.run(());
+        }
+    };
+
+    let class = merge_classes(vec!["notification-center", class.as_deref().unwrap_or("")]);
+    let style = format!(
+        "position: relative; display: inline-block; {}",
+        style.unwrap_or_default()
+    );
+    let badge = ViewFn::from(move || {
+        let count = unread.get() as u32;
+        view! { <BadgeCount count=count max_count=max_count /> }
+    });
+
+    let groups = move || {
+        let groups = notifications.with(|items| group_notifications(items));
+        if groups.is_empty() {
+            return view! { <p class="notification-center-empty">{empty_text.clone()}</p> }
+                .into_any();
+        }
+        groups
+            .into_iter()
+            .map(|group| {
+                let day_label = catalog.with_value(|catalog| chat_day_label(catalog, group.day, today));
+                let section_label = day_label.clone();
+                let items = group
+                    .items
+                    .into_iter()
+                    .map(|item| {
+                        let state = if item.read { "read" } else { "unread" };
+                        let title = item.title.clone();
+                        let description = item.description.clone();
+                        let read = item.read;
+                        let unread_text = unread_text.clone();
+                        let time = item.received_at.format("%H:%M").to_string();
+                        let datetime = item.received_at.format("%Y-%m-%dT%H:%M").to_string();
+                        view! {
+                            <li class="notification-center-item" data-state=state>
+                                <button
+                                    type="button"
+                                    class="notification-center-item-button"
+                                    on:click=move |_| select(item.clone())
+                                >
+                                    {(!read)
+                                        .then(|| {
+                                            view! {
+                                                <span class="notification-center-unread-dot" aria-hidden="true"></span>
+                                                <VisuallyHidden>{unread_text} " "</VisuallyHidden>
+                                            }
+                                        })}
+                                    <span class="notification-center-item-title">{title}</span>
+                                    {description
+                                        .map(|description| {
+                                            view! {
+                                                <span class="notification-center-item-description">
+                                                    {description}
+                                                </span>
+                                            }
+                                        })}
+                                    <time class="notification-center-item-time" datetime=datetime>
+                                        {time}
+                                    </time>
+                                </button>
+                            </li>
+                        }
+                    })
+                    .collect_view();
+                view! {
+                    <section class="notification-center-group" aria-label=section_label>
+                        <h3 class="notification-center-group-heading">{day_label}</h3>
+                        <ul class="notification-center-list">{items}</ul>
+                    </section>
+                }
+            })
+            .collect_view()
+            .into_any()
+    };
+
+    view! {
+        <div class=class style=style on:keydown=handle_keydown>
+            <BadgeAnchor badge=badge>
+                <button
+                    type="button"
+                    class="notification-center-trigger"
+                    node_ref=trigger_ref
+                    aria-haspopup="dialog"
+                    aria-expanded=move || open.get().to_string()
+                    aria-controls=panel_controls
+                    aria-label=move || {
+                        catalog.with_value(|catalog| {
+                            label.with_value(|label| notification_trigger_label(catalog, label, unread.get()))
+                        })
+                    }
+                    on:click=move |_| set_open(!open.get_untracked())
+                >
+                    <svg viewBox="0 0 24 24" width="20" height="20" aria-hidden="true" fill="none" stroke="currentColor" stroke-width="2">
+                        <path d="M18 8a6 6 0 0 0-12 0c0 7-3 9-3 9h18s-3-2-3-9"></path>
+                        <path d="M13.73 21a2 2 0 0 1-3.46 0"></path>
+                    </svg>
+                </button>
+            </BadgeAnchor>
+            <div
+                id=panel_id
+                class="popover-content notification-center-panel"
+                role="dialog"
+                aria-labelledby=panel_labelledby
+                hidden=move || !open.get()
+                style="position: absolute; right: 0; z-index: 50;"
+            >
+                <div class="notification-center-header">
+                    <h2 id=heading_id class="notification-center-heading">{label.get_value()}</h2>
+                    <button
+                        type="button"
+                        class="notification-center-mark-all"
+                        disabled=move || unread.get() == 0
+                        on:click=mark_all_read
+                    >
+                        {mark_all_text}
+                    </button>
+                </div>
+                {groups}
+            </div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(day: u32, hour: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, 3, day)
+            .and_then(|date| date.and_hms_opt(hour, 0, 0))
+            .unwrap()
+    }
+
+    fn inbox() -> Vec<NotificationItem> {
+        vec![
+            NotificationItem::new("1", "Build failed", at(4, 9)),
+            NotificationItem::new("2", "New comment", at(5, 8)).read(true),
+            NotificationItem::new("3", "Deploy finished", at(5, 11)),
+        ]
+    }
+
+    #[test]
+    fn test_groups_newest_first() {
+        let groups = group_notifications(&inbox());
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].day, at(5, 0).date());
+        let ids: Vec<&str> = groups[0].items.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(ids, vec!["3", "2"]);
+        assert_eq!(groups[1].items[0].id, "1");
+        assert!(group_notifications(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_mark_read() {
+        let mut items = inbox();
+        assert_eq!(unread_notification_count(&items), 2);
+        assert!(mark_notification_read(&mut items, "1"));
+        assert!(!mark_notification_read(&mut items, "1"));
+        assert!(!mark_notification_read(&mut items, "missing"));
+        assert_eq!(unread_notification_count(&items), 1);
+
+        assert_eq!(
+            mark_all_notifications_read(&mut items),
+            vec!["3".to_string()]
+        );
+        assert_eq!(unread_notification_count(&items), 0);
+        assert!(mark_all_notifications_read(&mut items).is_empty());
+    }
+
+    #[test]
+    fn test_trigger_label() {
+        let catalog = MessageCatalog::default();
+        assert_eq!(
+            notification_trigger_label(&catalog, "Notifications", 0),
+            "Notifications"
+        );
+        assert_eq!(
+            notification_trigger_label(&catalog, "Notifications", 3),
+            "Notifications, 3 unread"
+        );
+    }
+}
@@ -0,0 +1,600 @@
+use crate::i18n::{use_i18n, MessageKey};
+use crate::utils::merge_classes;
+use leptos::html;
+use leptos::prelude::*;
+use radix_leptos_core::FocusManager;
+use std::collections::HashSet;
+use std::sync::Arc;
+use wasm_bindgen::JsCast;
+
+/// Zoom limits of the chart
+const MIN_SCALE: f64 = 0.25;
+const MAX_SCALE: f64 = 2.5;
+
+/// Zoom change per button press or wheel notch
+const ZOOM_STEP: f64 = 1.2;
+
+/// Space kept between a focused node and the edge of the viewport
+const REVEAL_MARGIN: f64 = 24.0;
+
+/// Person or unit in an OrgChart
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct OrgChartNode {
+    pub id: String,
+    pub label: String,
+    /// Secondary line, e.g. a job title
+    pub description: Option<String>,
+    pub children: Vec<OrgChartNode>,
+}
+
+impl OrgChartNode {
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn children(mut self, children: Vec<OrgChartNode>) -> Self {
+        self.children = children;
+        self
+    }
+}
+
+/// Pan offset and zoom of an OrgChart
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OrgChartView {
+    pub x: f64,
+    pub y: f64,
+    pub scale: f64,
+}
+
+impl Default for OrgChartView {
+    fn default() -> Self {
+        Self {
+            x: 0.0,
+            y: 0.0,
+            scale: 1.0,
+        }
+    }
+}
+
+impl OrgChartView {
+    /// Zoom by `factor`, keeping the point at (`x`, `y`) of the viewport in place
+    pub fn zoom_at(self, factor: f64, x: f64, y: f64) -> Self {
+        let scale = (self.scale * factor).clamp(MIN_SCALE, MAX_SCALE);
+        let ratio = scale / self.scale;
+        Self {
+            x: x - (x - self.x) * ratio,
+            y: y - (y - self.y) * ratio,
+            scale,
+        }
+    }
+
+    /// Pan just enough for a rectangle (in viewport coordinates) to be
+    /// fully visible in a `width` by `height` viewport
+    pub fn reveal(
+        self,
+        left: f64,
+        top: f64,
+        right: f64,
+        bottom: f64,
+        width: f64,
+        height: f64,
+    ) -> Self {
+        let shift = |start: f64, end: f64, size: f64| {
+            if start < REVEAL_MARGIN {
+                REVEAL_MARGIN - start
+            } else if end > size - REVEAL_MARGIN {
+                (size - REVEAL_MARGIN - end).max(REVEAL_MARGIN - start)
+            } else {
+                0.0
+            }
+        };
+        Self {
+            x: self.x + shift(left, right, width),
+            y: self.y + shift(top, bottom, height),
+            ..self
+        }
+    }
+
+    pub fn transform(&self) -> String {
+        format!(
+            "transform-origin: 0 0; transform: translate({}px, {}px) scale({});",
+            self.x, self.y, self.scale
+        )
+    }
+}
+
+/// Keyboard movement between nodes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrgChartMove {
+    Parent,
+    FirstChild,
+    PreviousSibling,
+    NextSibling,
+    Root,
+}
+
+struct Located<'a> {
+    siblings: &'a [OrgChartNode],
+    index: usize,
+    parent: Option<&'a OrgChartNode>,
+}
+
+fn locate<'a>(
+    nodes: &'a [OrgChartNode],
+    id: &str,
+    parent: Option<&'a OrgChartNode>,
+) -> Option<Located<'a>> {
+    nodes.iter().enumerate().find_map(|(index, node)| {
+        if node.id == id {
+            Some(Located {
+                siblings: nodes,
+                index,
+                parent,
+            })
+        } else {
+            locate(&node.children, id, Some(node))
+        }
+    })
+}
+
+/// Node reached from `id` by `movement`; children of collapsed nodes are
+/// not reachable
+pub fn org_chart_target(
+    roots: &[OrgChartNode],
+    collapsed: &HashSet<String>,
+    id: &str,
+    movement: OrgChartMove,
+) -> Option<String> {
+    if movement == OrgChartMove::Root {
+        return roots.first().map(|root| root.id.clone());
+    }
+    let located = locate(roots, id, None)?;
+    let node = &located.siblings[located.index];
+    let target = match movement {
+        OrgChartMove::Parent => located.parent,
+        OrgChartMove::FirstChild if collapsed.contains(&node.id) => None,
+        OrgChartMove::FirstChild => node.children.first(),
+        OrgChartMove::PreviousSibling => located
+            .index
+            .checked_sub(1)
+            .and_then(|index| located.siblings.get(index)),
+        OrgChartMove::NextSibling => located.siblings.get(located.index + 1),
+        OrgChartMove::Root => None,
+    };
+    target.map(|node| node.id.clone())
+}
+
+/// Renders the content of one node card
+type OrgChartTemplate = Arc<dyn Fn(OrgChartNode) -> AnyView + Send + Sync>;
+
+#[derive(Clone, Copy)]
+struct OrgChartState {
+    collapsed: RwSignal<HashSet<String>>,
+    focused: RwSignal<String>,
+    template: StoredValue<OrgChartTemplate>,
+}
+
+fn render_org_chart_nodes(nodes: Vec<OrgChartNode>, level: usize, state: OrgChartState) -> AnyView {
+    let count = nodes.len();
+    nodes
+        .into_iter()
+        .enumerate()
+        .map(|(index, node)| {
+            let id = StoredValue::new(node.id.clone());
+            let has_children = !node.children.is_empty();
+            let is_expanded =
+                Memo::new(move |_| id.with_value(|id| !state.collapsed.with(|collapsed| collapsed.contains(id))));
+            let content = state.template.with_value(|template| template(node.clone()));
+            let children = StoredValue::new(node.children);
+            let toggle = move |_| {
+                state.collapsed.update(|collapsed| {
+                    let id = id.get_value();
+                    if !collapsed.remove(&id) {
+                        collapsed.insert(id);
+                    }
+                });
+            };
+
+            view! {
+                <li
+                    role="treeitem"
+                    attr:aria-level=level.to_string()
+                    aria-setsize=count
+                    aria-posinset=index + 1
+                    aria-expanded=move || has_children.then(|| is_expanded.get().to_string())
+                    aria-label=node.label
+                    data-org-node=node.id.clone()
+                    class="org-chart-node"
+                    tabindex=move || {
+                        if id.with_value(|id| state.focused.with(|focused| focused == id)) { "0" } else { "-1" }
+                    }
+                    on:focus=move |e| {
+                        // Focus lands on nested nodes too; only track the node itself
+                        if event_target::<web_sys::Element>(&e).get_attribute("data-org-node")
+                            == Some(id.get_value())
+                        {
+                            state.focused.set(id.get_value());
+                        }
+                    }
+                >
+                    <div class="org-chart-card">
+                        {content}
+                        {has_children.then(|| {
+                            view! {
+                                <button
+                                    type="button"
+                                    class="org-chart-toggle"
+                                    tabindex="-1"
+                                    aria-hidden="true"
+                                    on:click=toggle
+                                >
+                                    {move || if is_expanded.get() { "−" } else { "+" }}
+                                </button>
+                            }
+                        })}
+                    </div>
+                    {move || {
+                        (has_children && is_expanded.get()).then(|| {
+                            view! {
+                                <ul role="group">
+                                    {render_org_chart_nodes(children.get_value(), level + 1, state)}
+                                </ul>
+                            }
+                        })
+                    }}
+                </li>
+            }
+            .into_any()
+        })
+        .collect_view()
+        .into_any()
+}
+
+/// OrgChart component - Pannable, zoomable hierarchy diagram
+///
+/// Each node card is rendered by the children template, which receives the
+/// node. Drag the background or use the wheel to pan and zoom. The chart is
+/// a tree with one tab stop: Up moves to the manager, Down to the first
+/// report (opening a collapsed branch), Left/Right between peers, Home to
+/// the root, Enter or Space collapses a branch, and +/- zoom. The focused
+/// node is panned into view.
+///
+/// Like every primitive it ships no styles beyond the viewport height and
+/// the pan and zoom transform. Lay out the nested `ul`/`li` levels and draw
+/// their connector lines in your CSS; the viewport has
+/// `data-panning="true"` while it is dragged.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn Company() -> impl IntoView {
+///     let ceo = OrgChartNode::new("ceo", "Ada").description("CEO").children(vec![
+///         OrgChartNode::new("cto", "Grace").description("CTO"),
+///         OrgChartNode::new("cfo", "Alan").description("CFO"),
+///     ]);
+///     view! {
+///         <OrgChart nodes=vec![ceo] label="Leadership".to_string() let:node>
+///             <strong>{node.label}</strong>
+///             <span>{node.description}</span>
+///         </OrgChart>
+///     }
+/// }
+/// ```
+#[component]
+pub fn OrgChart<F, IV>(
+    nodes: Vec<OrgChartNode>,
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    /// Accessible name of the chart (default: "Organization chart")
+    #[prop(optional)]
+    label: Option<String>,
+    /// Height of the viewport in pixels (default: 480)
+    #[prop(optional)]
+    height: Option<f64>,
+    /// Ids of the nodes whose reports start hidden
+    #[prop(optional)]
+    collapsed: Vec<String>,
+    /// Template for the content of each node
+    children: F,
+) -> impl IntoView
+where
+    F: Fn(OrgChartNode) -> IV + Send + Sync + 'static,
+    IV: IntoView + 'static,
+{
+    let catalog = use_i18n();
+    let label = label.unwrap_or_else(|| catalog.text(MessageKey::OrgChartLabel));
+    let height = height.unwrap_or(480.0);
+    let template: OrgChartTemplate = Arc::new(move |node| children(node).into_any());
+    let state = OrgChartState {
+        collapsed: RwSignal::new(collapsed.into_iter().collect()),
+        focused: RwSignal::new(
+            nodes
+                .first()
+                .map(|root| root.id.clone())
+                .unwrap_or_default(),
+        ),
+        template: StoredValue::new(template),
+    };
+    let roots = StoredValue::new(nodes.clone());
+    let viewport_ref = NodeRef::<html::Div>::new();
+    let view_state = RwSignal::new(OrgChartView::default());
+    let pan_origin = StoredValue::new(None::<(f64, f64)>);
+
+    let viewport_size = move || {
+        viewport_ref
+            .get_untracked()
+            .map(|viewport| {
+                (
+                    viewport.client_width() as f64,
+                    viewport.client_height() as f64,
+                )
+            })
+            .unwrap_or_default()
+    };
+    let zoom = move |factor: f64| {
+        let (width, height) = viewport_size();
+        view_state.update(|view| *view = view.zoom_at(factor, width / 2.0, height / 2.0));
+    };
+    let focus_node = move |id: String| {
+        state.focused.set(id.clone());
+        let Some(viewport) = viewport_ref.get_untracked() else {
+            return;
+        };
+        let Some(node) = viewport
+            .query_selector(&format!("[data-org-node=\"{}\"]", id))
+            .ok()
+            .flatten()
+            .and_then(|node| node.dyn_into::<web_sys::HtmlElement>().ok())
+        else {
+            return;
+        };
+        // Reveal the node's own card, not the whole branch below it
+        let card = node
+            .first_element_child()
+            .unwrap_or_else(|| node.clone().into());
+        let (card_rect, viewport_rect) = (
+            card.get_bounding_client_rect(),
+            viewport.get_bounding_client_rect(),
+        );
+        let (width, height) = viewport_size();
+        view_state.update(|view| {
+            *view = view.reveal(
+                card_rect.left() - viewport_rect.left(),
+                card_rect.top() - viewport_rect.top(),
+                card_rect.right() - viewport_rect.left(),
+                card_rect.bottom() - viewport_rect.top(),
+                width,
+                height,
+            )
+        });
+        FocusManager::focus(&node);
+    };
+
+    let handle_keydown = move |e: web_sys::KeyboardEvent| {
+        let current = state.focused.get_untracked();
+        let collapsed = state.collapsed.get_untracked();
+        let movement = match e.key().as_str() {
+            "ArrowUp" => OrgChartMove::Parent,
+            "ArrowDown" => {
+                let closed = roots.with_value(|roots| {
+                    locate(roots, &current, None).is_some_and(|located| {
+                        !located.siblings[located.index].children.is_empty()
+                            && collapsed.contains(&current)
+                    })
+                });
+                if closed {
+                    e.prevent_default();
+                    state.collapsed.update(|collapsed| {
+                        collapsed.remove(&current);
+                    });
+                    return;
+                }
+                OrgChartMove::FirstChild
+            }
+            "ArrowLeft" => OrgChartMove::PreviousSibling,
+            "ArrowRight" => OrgChartMove::NextSibling,
+            "Home" => OrgChartMove::Root,
+            "Enter" | " " => {
+                e.prevent_default();
+                state.collapsed.update(|collapsed| {
+                    if !collapsed.remove(&current) {
+                        collapsed.insert(current.clone());
+                    }
+                });
+                return;
+            }
+            "+" | "=" => {
+                e.prevent_default();
+                zoom(ZOOM_STEP);
+                return;
+            }
+            "-" => {
+                e.prevent_default();
+                zoom(1.0 / ZOOM_STEP);
+                return;
+            }
+            _ => return,
+        };
+        e.prevent_default();
+        let target =
+            roots.with_value(|roots| org_chart_target(roots, &collapsed, &current, movement));
+        if let Some(target) = target {
+            focus_node(target);
+        }
+    };
+
+    let handle_pointerdown = move |e: web_sys::PointerEvent| {
+        // Dragging starts on the background only, so node content stays clickable
+        let on_node = e
+            .target()
+            .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+            .and_then(|target| target.closest(".org-chart-card").ok().flatten())
+            .is_some();
+        if on_node || e.button() != 0 {
+            return;
+        }
+        if let Some(viewport) = viewport_ref.get_untracked() {
+            let _ = viewport.set_pointer_capture(e.pointer_id());
+        }
+        pan_origin.set_value(Some((e.client_x() as f64, e.client_y() as f64)));
+    };
+    let handle_pointermove = move |e: web_sys::PointerEvent| {
+        if let Some((x, y)) = pan_origin.get_value() {
+            let (next_x, next_y) = (e.client_x() as f64, e.client_y() as f64);
+            view_state.update(|view| {
+                view.x += next_x - x;
+                view.y += next_y - y;
+            });
+            pan_origin.set_value(Some((next_x, next_y)));
+        }
+    };
+    let handle_wheel = move |e: web_sys::WheelEvent| {
+        e.prevent_default();
+        let Some(viewport) = viewport_ref.get_untracked() else {
+            return;
+        };
+        let rect = viewport.get_bounding_client_rect();
+        let factor = if e.delta_y() < 0.0 {
+            ZOOM_STEP
+        } else {
+            1.0 / ZOOM_STEP
+        };
+        let (x, y) = (
+            e.client_x() as f64 - rect.left(),
+            e.client_y() as f64 - rect.top(),
+        );
+        view_state.update(|view| *view = view.zoom_at(factor, x, y));
+    };
+
+    let class = merge_classes(vec!["org-chart", class.as_deref().unwrap_or("")]);
+
+    view! {
+        <div class=class style=style>
+            <div class="org-chart-controls" role="group" aria-label=catalog.text(MessageKey::Zoom)>
+                <button type="button" aria-label=catalog.text(MessageKey::ZoomOut) on:click=move |_| zoom(1.0 / ZOOM_STEP)>
+                    "−"
+                </button>
+                <output aria-live="polite">
+                    {move || format!("{}%", (view_state.get().scale * 100.0).round())}
+                </output>
+                <button type="button" aria-label=catalog.text(MessageKey::ZoomIn) on:click=move |_| zoom(ZOOM_STEP)>
+                    "+"
+                </button>
+                <button type="button" on:click=move |_| view_state.set(OrgChartView::default())>
+                    {catalog.text(MessageKey::OrgChartResetView)}
+                </button>
+            </div>
+            <div
+                node_ref=viewport_ref
+                class="org-chart-viewport"
+                style=format!("height: {}px;", height)
+                data-panning=move || pan_origin.with_value(Option::is_some).to_string()
+                on:pointerdown=handle_pointerdown
+                on:pointermove=handle_pointermove
+                on:pointerup=move |_| pan_origin.set_value(None)
+                on:pointercancel=move |_| pan_origin.set_value(None)
+                on:wheel=handle_wheel
+            >
+                <div class="org-chart-canvas" style=move || view_state.get().transform()>
+                    <ul class="org-chart-tree" role="tree" aria-label=label on:keydown=handle_keydown>
+                        {render_org_chart_nodes(nodes, 1, state)}
+                    </ul>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chart() -> Vec<OrgChartNode> {
+        vec![OrgChartNode::new("ceo", "Ada").children(vec![
+            OrgChartNode::new("cto", "Grace").children(vec![OrgChartNode::new("dev", "Linus")]),
+            OrgChartNode::new("cfo", "Alan"),
+        ])]
+    }
+
+    #[test]
+    fn test_org_chart_target() {
+        let roots = chart();
+        let none = HashSet::new();
+        let target = |id, movement| org_chart_target(&roots, &none, id, movement);
+        assert_eq!(
+            target("ceo", OrgChartMove::FirstChild).as_deref(),
+            Some("cto")
+        );
+        assert_eq!(
+            target("cto", OrgChartMove::NextSibling).as_deref(),
+            Some("cfo")
+        );
+        assert_eq!(target("cfo", OrgChartMove::NextSibling), None);
+        assert_eq!(
+            target("cfo", OrgChartMove::PreviousSibling).as_deref(),
+            Some("cto")
+        );
+        assert_eq!(target("dev", OrgChartMove::Parent).as_deref(), Some("cto"));
+        assert_eq!(target("ceo", OrgChartMove::Parent), None);
+        assert_eq!(target("dev", OrgChartMove::Root).as_deref(), Some("ceo"));
+
+        let collapsed: HashSet<String> = ["cto".to_string()].into();
+        assert_eq!(
+            org_chart_target(&roots, &collapsed, "cto", OrgChartMove::FirstChild),
+            None
+        );
+    }
+
+    #[test]
+    fn test_org_chart_view() {
+        let zoomed = OrgChartView::default().zoom_at(2.0, 100.0, 50.0);
+        assert_eq!(
+            zoomed,
+            OrgChartView {
+                x: -100.0,
+                y: -50.0,
+                scale: 2.0
+            }
+        );
+        assert_eq!(
+            OrgChartView::default().zoom_at(100.0, 0.0, 0.0).scale,
+            MAX_SCALE
+        );
+
+        let view = OrgChartView::default();
+        assert_eq!(view.reveal(100.0, 100.0, 200.0, 150.0, 400.0, 300.0), view);
+        let moved = view.reveal(350.0, -20.0, 450.0, 30.0, 400.0, 300.0);
+        assert_eq!((moved.x, moved.y), (-74.0, 44.0));
+    }
+
+    #[test]
+    fn test_org_chart_markup() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let html = view! {
+                <OrgChart nodes=chart() collapsed=vec!["cto".to_string()] let:node>
+                    <strong>{node.label}</strong>
+                </OrgChart>
+            }
+            .to_html();
+            assert!(html.contains("role=\"tree\""));
+            assert!(html.contains("<strong>Grace</strong>"));
+            assert!(!html.contains("<strong>Linus</strong>"));
+            assert!(html.contains("aria-expanded=\"false\""));
+            assert_eq!(html.matches("tabindex=\"0\"").count(), 1);
+        });
+    }
+}
@@ -1,3 +1,4 @@
+use crate::i18n::{use_i18n, MessageArgs, MessageKey};
 use leptos::callback::Callback;
 use leptos::children::Children;
 use leptos::prelude::*;
@@ -277,7 +278,7 @@ pub fn OtpTimer(
                     }
                 }
             >
-                "Reset"
+                {use_i18n().text(MessageKey::OtpTimerReset)}
             </button>
         </div>
     }
@@ -329,13 +330,16 @@ pub fn OtpResend(
                             }
                         }
                     >
-                        "Resend OTP"
+                        {use_i18n().text(MessageKey::OtpResend)}
                     </button>
                 }.into_any()
             } else {
                 view! {
                     <span class="cooldown-text">
-                        {format!("Resend available in {}s", cooldown)}
+                        {use_i18n().format(
+                            MessageKey::OtpResendCooldown,
+                            &MessageArgs::new().with("seconds", cooldown),
+                        )}
                     </span>
                 }.into_any()
             }}
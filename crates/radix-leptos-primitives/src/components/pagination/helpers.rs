@@ -21,6 +21,13 @@ mod tests {
         assert_eq!(calculate_page_range(5, 3, 5), (1, 3));
     }
 
+    #[test]
+    fn test_calculate_page_range_zero_visible() {
+        // A zero-sized window must not underflow
+        assert_eq!(calculate_page_range(5, 10, 0), (5, 5));
+        assert!(generate_page_numbers(5, 10, 0).iter().any(|p| p.number == 5 && p._current));
+    }
+
     #[test]
     fn test_calculate_page_range_boundary_conditions() {
         // Test boundary conditions
@@ -108,6 +115,7 @@ pub fn calculate_page_range(
     total_pages: usize,
     maxvisible: usize,
 ) -> (usize, usize) {
+    let maxvisible = maxvisible.max(1);
     if total_pages <= maxvisible {
         return (1, total_pages);
     }
@@ -3,6 +3,7 @@ use leptos::context::use_context;
 use leptos::prelude::*;
 
 use super::context::{PaginationContext, PaginationPage};
+use crate::i18n::{format_message, use_i18n, MessageArgs, MessageKey};
 use crate::utils::{merge_optional_classes, generate_id};
 
 /// PaginationList component for the pagination items container
@@ -102,6 +103,13 @@ pub fn PaginationItem(
         }
     });
 
+    let label = page.as_ref().map(|page| {
+        use_i18n().format(
+            MessageKey::PaginationPage,
+            &MessageArgs::new().with("page", page.number),
+        )
+    });
+
     // Build base classes
     let base_classes = "radix-pagination-item";
     let combined_class = merge_optional_classes(Some(base_classes), class.as_deref())
@@ -122,6 +130,7 @@ pub fn PaginationItem(
                 data-disabled=isdisabled.get()
                 type="button"
                 role="button"
+                aria-label=label
                 on:click=handle_click
             >
                 {children()}
@@ -165,6 +174,8 @@ pub fn PaginationFirst(
 
     let isdisabled = Memo::new(move |_| context.current_page.get() <= 1);
 
+    let label = use_i18n().text(MessageKey::PaginationFirst);
+
     // Build base classes
     let base_classes = "radix-pagination-first";
     let combined_class = merge_optional_classes(Some(base_classes), class.as_deref())
@@ -183,6 +194,7 @@ pub fn PaginationFirst(
                 data-disabled=isdisabled.get()
                 type="button"
                 role="button"
+                aria-label=label
                 on:click=handle_click
             >
                 {icon.map(|icon_text| view! {
@@ -233,6 +245,8 @@ pub fn PaginationPrevious(
 
     let isdisabled = Memo::new(move |_| context.current_page.get() <= 1);
 
+    let label = use_i18n().text(MessageKey::PaginationPrevious);
+
     // Build base classes
     let base_classes = "radix-pagination-previous";
     let combined_class = merge_optional_classes(Some(base_classes), class.as_deref())
@@ -251,6 +265,7 @@ pub fn PaginationPrevious(
                 data-disabled=isdisabled.get()
                 type="button"
                 role="button"
+                aria-label=label
                 on:click=handle_click
             >
                 {icon.map(|icon_text| view! {
@@ -301,6 +316,8 @@ pub fn PaginationNext(
 
     let isdisabled = Memo::new(move |_| context.current_page.get() >= context.total_pages);
 
+    let label = use_i18n().text(MessageKey::PaginationNext);
+
     // Build base classes
     let base_classes = "radix-pagination-next";
     let combined_class = merge_optional_classes(Some(base_classes), class.as_deref())
@@ -319,6 +336,7 @@ pub fn PaginationNext(
                 data-disabled=isdisabled.get()
                 type="button"
                 role="button"
+                aria-label=label
                 on:click=handle_click
             >
                 {icon.map(|icon_text| view! {
@@ -368,6 +386,8 @@ pub fn PaginationLast(
 
     let isdisabled = Memo::new(move |_| context.current_page.get() >= context.total_pages);
 
+    let label = use_i18n().text(MessageKey::PaginationLast);
+
     // Build base classes
     let base_classes = "radix-pagination-last";
     let combined_class = merge_optional_classes(Some(base_classes), class.as_deref())
@@ -386,6 +406,7 @@ pub fn PaginationLast(
                 data-disabled=isdisabled.get()
                 type="button"
                 role="button"
+                aria-label=label
                 on:click=handle_click
             >
                 {icon.map(|icon_text| view! {
@@ -441,7 +462,9 @@ pub fn PaginationEllipsis(
 /// PaginationInfo component for displaying pagination information
 #[component]
 pub fn PaginationInfo(
-    /// Information format
+    /// Information format, an ICU-style message with `{start}`, `{end}`,
+    /// `{total}`, `{current}` and `{total_pages}`; the catalog's
+    /// `MessageKey::PaginationSummary` when unset
     #[prop(optional)]
     format: Option<String>,
     /// CSS classes
@@ -472,6 +495,7 @@ pub fn PaginationInfo(
     });
 
     let total_items = context.total_items;
+    let catalog = use_i18n();
 
     // Build base classes
     let base_classes = "radix-pagination-info";
@@ -486,26 +510,21 @@ pub fn PaginationInfo(
             role="status"
             aria-live="polite"
         >
-            {if let Some(format_str) = format {
-                let start = start_item.get();
-                let end = end_item.get();
-                let total = total_items;
-                let current = context.current_page.get();
-                let total_pages = context.total_pages;
-
-                let info_text = format_str
-                    .replace("{start}", &start.to_string())
-                    .replace("{end}", &end.to_string())
-                    .replace("{total}", &total.to_string())
-                    .replace("{current}", &current.to_string())
-                    .replace("{total_pages}", &total_pages.to_string());
-
-                view! {
-                    <span class="radix-pagination-info-text">{info_text}</span>
-                }
-            } else {
-                view! { <span class="radix-pagination-info-text">{String::new()}</span> }
-            }}
+            <span class="radix-pagination-info-text">
+                {move || {
+                    let args = MessageArgs::new()
+                        .with("start", start_item.get())
+                        .with("end", end_item.get())
+                        .with("total", total_items)
+                        .with("current", context.current_page.get())
+                        .with("total_pages", context.total_pages);
+                    match &format {
+                        Some(format_str) => format_message(format_str, &catalog.locale, &args)
+                            .unwrap_or_else(|_| format_str.clone()),
+                        None => catalog.format(MessageKey::PaginationSummary, &args),
+                    }
+                }}
+            </span>
             {children()}
         </div>
     }
@@ -1,7 +1,8 @@
 use leptos::children::Children;
 use leptos::callback::Callback;
 use leptos::prelude::*;
-use crate::utils::{merge_optional_classes, generate_id};
+use crate::i18n::{use_i18n, MessageKey};
+use crate::utils::{generate_id, merge_optional_classes, sanitize_page, warn_invalid_prop};
 
 // Re-export all types and components from sub-modules
 pub use context::*;
@@ -136,6 +137,13 @@ pub fn Pagination(
     children: Children,
 ) -> impl IntoView {
     let pagination_id = generate_id("pagination");
+    let (current_page, total_pages) = sanitize_page("Pagination", current_page, total_pages);
+    let page_size = if page_size == 0 {
+        warn_invalid_prop("Pagination", "page_size is 0, using 1");
+        1
+    } else {
+        page_size
+    };
 
     // Reactive state
     let (current_page_signal, _setcurrent_page_signal) = signal(current_page);
@@ -174,7 +182,7 @@ pub fn Pagination(
             data-show-prev-next=_show_prev_next
             data-show-page-numbers=_show_page_numbers
             role="navigation"
-            aria-label="Pagination"
+            aria-label=use_i18n().text(MessageKey::PaginationLabel)
         >
             {children()}
         </nav>
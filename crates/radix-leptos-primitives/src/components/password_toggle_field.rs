@@ -1,3 +1,4 @@
+use crate::i18n::{use_i18n, MessageArgs, MessageCatalog, MessageKey};
 use leptos::callback::Callback;
 use leptos::children::Children;
 use leptos::prelude::*;
@@ -167,7 +168,8 @@ pub fn PasswordStrengthIndicator(
     let requirements = requirements.unwrap_or_default();
     let show_details = show_details.unwrap_or(true);
 
-    let validation = validate_password(&password, &requirements);
+    let catalog = use_i18n();
+    let validation = validate_password(&catalog, &password, &requirements);
     let strength_class = format!(
         "strength-{}",
         match validation.strength_level {
@@ -178,6 +180,17 @@ pub fn PasswordStrengthIndicator(
             PasswordStrengthLevel::Strong => "strong",
         }
     );
+    let strength_level = match validation.strength_level {
+        PasswordStrengthLevel::VeryWeak => "very_weak",
+        PasswordStrengthLevel::Weak => "weak",
+        PasswordStrengthLevel::Fair => "fair",
+        PasswordStrengthLevel::Good => "good",
+        PasswordStrengthLevel::Strong => "strong",
+    };
+    let strength_label = catalog.format(
+        MessageKey::PasswordStrength,
+        &MessageArgs::new().with("level", strength_level),
+    );
 
     let class = format!(
         "password-strength-indicator {} {}",
@@ -192,7 +205,7 @@ pub fn PasswordStrengthIndicator(
                 <div class="strength-fill" style=format!("width: {}%", validation.strength_score * 20)></div>
             </div>
             <div class="strength-label">
-                {format!("Strength: {:?}", validation.strength_level)}
+                {strength_label}
             </div>
             {if show_details {
                 view! {
@@ -248,10 +261,15 @@ pub fn PasswordRequirements(
     let show_checklist = show_checklist.unwrap_or(true);
     let class = format!("password-requirements {}", class.unwrap_or_default());
     let style = style.unwrap_or_default();
+    let catalog = use_i18n();
+    let min_length = catalog.format(
+        MessageKey::PasswordMinLength,
+        &MessageArgs::new().with("count", requirements.min_length),
+    );
 
     view! {
         <div class=class style=style>
-            <h4>"Password Requirements"</h4>
+            <h4>{catalog.text(MessageKey::PasswordRequirementsTitle)}</h4>
             <ul>
                 <li>
                     {if show_checklist {
@@ -259,7 +277,7 @@ pub fn PasswordRequirements(
                     } else {
                         view! { <div></div> }.into_any()
                     }}
-                    {format!("At least {} characters", requirements.min_length)}
+                    {min_length}
                 </li>
                 {if requirements.require_uppercase {
                     view! {
@@ -269,7 +287,7 @@ pub fn PasswordRequirements(
                             } else {
                                 view! { <div></div> }.into_any()
                             }}
-                            "At least one uppercase letter"
+                            {catalog.text(MessageKey::PasswordUppercase)}
                         </li>
                     }.into_any()
                 } else {
@@ -283,7 +301,7 @@ pub fn PasswordRequirements(
                             } else {
                                 view! { <div></div> }.into_any()
                             }}
-                            "At least one lowercase letter"
+                            {catalog.text(MessageKey::PasswordLowercase)}
                         </li>
                     }.into_any()
                 } else {
@@ -297,7 +315,7 @@ pub fn PasswordRequirements(
                             } else {
                                 view! { <div></div> }.into_any()
                             }}
-                            "At least one number"
+                            {catalog.text(MessageKey::PasswordNumber)}
                         </li>
                     }.into_any()
                 } else {
@@ -311,7 +329,7 @@ pub fn PasswordRequirements(
                             } else {
                                 view! { <div></div> }.into_any()
                             }}
-                            "At least one symbol"
+                            {catalog.text(MessageKey::PasswordSymbol)}
                         </li>
                     }.into_any()
                 } else {
@@ -324,6 +342,7 @@ pub fn PasswordRequirements(
 
 /// Helper function to validate password strength
 fn validate_password(
+    catalog: &MessageCatalog,
     password: &str,
     requirements: &PasswordStrengthRequirements,
 ) -> PasswordValidation {
@@ -333,36 +352,36 @@ fn validate_password(
 
     // Check minimum length
     if password.len() < requirements.min_length {
-        errors.push(format!(
-            "Password must be at least {} characters long",
-            requirements.min_length
+        errors.push(catalog.format(
+            MessageKey::PasswordTooShort,
+            &MessageArgs::new().with("count", requirements.min_length),
         ));
     }
 
     // Check for uppercase letters
     if requirements.require_uppercase && !password.chars().any(|c| c.is_uppercase()) {
-        errors.push("Password must contain at least one uppercase letter".to_string());
+        errors.push(catalog.text(MessageKey::PasswordMissingUppercase));
     } else if password.chars().any(|c| c.is_uppercase()) {
         strength_score += 1;
     }
 
     // Check for lowercase letters
     if requirements.require_lowercase && !password.chars().any(|c| c.is_lowercase()) {
-        errors.push("Password must contain at least one lowercase letter".to_string());
+        errors.push(catalog.text(MessageKey::PasswordMissingLowercase));
     } else if password.chars().any(|c| c.is_lowercase()) {
         strength_score += 1;
     }
 
     // Check for numbers
     if requirements.require_numbers && !password.chars().any(|c| c.is_numeric()) {
-        errors.push("Password must contain at least one number".to_string());
+        errors.push(catalog.text(MessageKey::PasswordMissingNumber));
     } else if password.chars().any(|c| c.is_numeric()) {
         strength_score += 1;
     }
 
     // Check for symbols
     if requirements.require_symbols && !password.chars().any(|c| !c.is_alphanumeric()) {
-        errors.push("Password must contain at least one symbol".to_string());
+        errors.push(catalog.text(MessageKey::PasswordMissingSymbol));
     } else if password.chars().any(|c| !c.is_alphanumeric()) {
         strength_score += 1;
     }
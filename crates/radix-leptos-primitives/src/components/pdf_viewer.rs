@@ -0,0 +1,511 @@
+use crate::i18n::{use_i18n, MessageArgs, MessageKey};
+use crate::utils::{generate_id, merge_classes};
+use leptos::html;
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+/// Zoom levels stepped through by the zoom buttons
+pub const PDF_ZOOM_LEVELS: [f64; 8] = [0.5, 0.75, 1.0, 1.25, 1.5, 2.0, 3.0, 4.0];
+
+/// Scale thumbnails are rendered at
+const THUMBNAIL_SCALE: f64 = 0.2;
+
+/// Pages matching a search, in document order
+pub type PdfSearchFuture = Pin<Box<dyn Future<Output = Vec<usize>>>>;
+
+/// Shared handle to the backend a PdfViewer renders with
+pub type PdfBackendRef = Rc<dyn PdfBackend>;
+
+/// Size of a page in CSS pixels at a zoom of 1
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PdfPageSize {
+    pub width: f64,
+    pub height: f64,
+}
+
+/// Rendering engine behind a [`PdfViewer`]
+///
+/// The viewer only draws the chrome; parsing and painting pages is left to
+/// an implementation of this trait, typically a thin wrapper around pdf.js
+/// imported with wasm-bindgen. Pages are indexed from 0. Rendering may
+/// finish asynchronously, the viewer does not wait for it.
+pub trait PdfBackend {
+    /// Number of pages in the loaded document
+    fn page_count(&self) -> usize;
+
+    /// Size of a page, used to fit it to the viewer's width
+    fn page_size(&self, page: usize) -> Option<PdfPageSize>;
+
+    /// Draw `page` into `canvas` at `scale`, resizing the canvas as needed
+    fn render_page(&self, page: usize, scale: f64, canvas: &web_sys::HtmlCanvasElement);
+
+    /// Whether [`search`](PdfBackend::search) is implemented; the search box
+    /// is hidden otherwise
+    fn supports_search(&self) -> bool {
+        false
+    }
+
+    /// Pages whose text contains `query`
+    fn search(&self, _query: &str) -> PdfSearchFuture {
+        Box::pin(async { Vec::new() })
+    }
+}
+
+/// Next zoom level above (`zoom_in`) or below `current`
+pub fn pdf_zoom_step(current: f64, zoom_in: bool) -> f64 {
+    let (first, last) = (
+        PDF_ZOOM_LEVELS[0],
+        PDF_ZOOM_LEVELS[PDF_ZOOM_LEVELS.len() - 1],
+    );
+    if zoom_in {
+        PDF_ZOOM_LEVELS
+            .iter()
+            .copied()
+            .find(|level| *level > current + f64::EPSILON)
+            .unwrap_or(last)
+    } else {
+        PDF_ZOOM_LEVELS
+            .iter()
+            .rev()
+            .copied()
+            .find(|level| *level < current - f64::EPSILON)
+            .unwrap_or(first)
+    }
+}
+
+/// Zoom at which a page of `page_width` fills `container_width`
+pub fn pdf_fit_width(container_width: f64, page_width: f64) -> Option<f64> {
+    (container_width > 0.0 && page_width > 0.0).then(|| {
+        (container_width / page_width).clamp(
+            PDF_ZOOM_LEVELS[0],
+            PDF_ZOOM_LEVELS[PDF_ZOOM_LEVELS.len() - 1],
+        )
+    })
+}
+
+/// Page index for a 1-based page number typed by the user
+pub fn parse_pdf_page(input: &str, page_count: usize) -> Option<usize> {
+    let number = input.trim().parse::<usize>().ok()?;
+    (1..=page_count).contains(&number).then(|| number - 1)
+}
+
+/// Index of the next (or previous) search match, wrapping around
+pub fn pdf_match_step(current: Option<usize>, count: usize, forward: bool) -> Option<usize> {
+    if count == 0 {
+        return None;
+    }
+    Some(match (current, forward) {
+        (None, true) => 0,
+        (None, false) => count - 1,
+        (Some(index), true) => (index + 1) % count,
+        (Some(index), false) => (index + count - 1) % count,
+    })
+}
+
+/// Thumbnail button for one page
+#[component]
+fn PdfThumbnail(
+    backend: StoredValue<PdfBackendRef, LocalStorage>,
+    page: usize,
+    current: RwSignal<usize>,
+    on_select: Callback<usize>,
+) -> impl IntoView {
+    let canvas_ref = NodeRef::<html::Canvas>::new();
+    let label = use_i18n().format(MessageKey::PdfViewerPage, &MessageArgs::new().with("page", page + 1));
+    Effect::new(move |_| {
+        if let Some(canvas) = canvas_ref.get() {
+            backend.with_value(|backend| backend.render_page(page, THUMBNAIL_SCALE, &canvas));
+        }
+    });
+
+    view! {
+        <li>
+            <button
+                type="button"
+                class="pdf-viewer-thumbnail"
+                aria-label=label
+                aria-current=move || (current.get() == page).then_some("page")
+                on:click=move |_| on_select.run(page)
+            >
+                <canvas node_ref=canvas_ref aria-hidden="true"></canvas>
+                <span aria-hidden="true">{page + 1}</span>
+            </button>
+        </li>
+    }
+}
+
+/// PdfViewer component - Toolbar, thumbnails and page view for a PDF
+///
+/// A layout shell: pages are drawn by the [`PdfBackend`] passed in, so any
+/// renderer can be plugged in. The toolbar offers page navigation, zoom
+/// (including fit to width) and, when the backend supports it, search.
+/// With focus on the page, PageUp/PageDown change page and +/- zoom.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+/// use std::rc::Rc;
+///
+/// struct PdfJs { /* handle to a pdf.js document */ }
+///
+/// impl PdfBackend for PdfJs {
+///     fn page_count(&self) -> usize { 12 }
+///     fn page_size(&self, _page: usize) -> Option<PdfPageSize> {
+///         Some(PdfPageSize { width: 612.0, height: 792.0 })
+///     }
+///     fn render_page(&self, _page: usize, _scale: f64, _canvas: &web_sys::HtmlCanvasElement) {
+///         // call page.render() through wasm-bindgen
+///     }
+/// }
+///
+/// #[component]
+/// fn Report() -> impl IntoView {
+///     let backend: PdfBackendRef = Rc::new(PdfJs {});
+///     view! { <PdfViewer backend=backend label="Annual report".to_string() /> }
+/// }
+/// ```
+#[component]
+pub fn PdfViewer(
+    backend: PdfBackendRef,
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    /// Accessible name of the viewer (default: "PDF document")
+    #[prop(optional)]
+    label: Option<String>,
+    /// Page shown first, from 0
+    #[prop(optional)]
+    initial_page: usize,
+    /// Initial zoom (default: 1)
+    #[prop(optional)]
+    zoom: Option<f64>,
+    /// Show the thumbnail sidebar initially (default: true)
+    #[prop(optional)]
+    thumbnails: Option<bool>,
+    #[prop(optional)] on_page_change: Option<Callback<usize>>,
+) -> impl IntoView {
+    let page_count = backend.page_count();
+    let searchable = backend.supports_search();
+    let backend = StoredValue::new_local(backend);
+    let catalog = use_i18n();
+    let label = label.unwrap_or_else(|| catalog.text(MessageKey::PdfViewerLabel));
+    let last_page = page_count.saturating_sub(1);
+
+    let current = RwSignal::new(initial_page.min(last_page));
+    let scale = RwSignal::new(zoom.unwrap_or(1.0));
+    let sidebar_open = RwSignal::new(thumbnails.unwrap_or(true));
+    let query = RwSignal::new(String::new());
+    let matches = RwSignal::new(Vec::<usize>::new());
+    let active_match = RwSignal::new(None::<usize>);
+    let searched = RwSignal::new(false);
+
+    let sidebar_id = generate_id("pdf-viewer-thumbnails");
+    let page_id = generate_id("pdf-viewer-page");
+    let page_ref = NodeRef::<html::Div>::new();
+    let canvas_ref = NodeRef::<html::Canvas>::new();
+
+    Effect::new(move |_| {
+        let (page, scale) = (current.get(), scale.get());
+        if let Some(canvas) = canvas_ref.get() {
+            backend.with_value(|backend| backend.render_page(page, scale, &canvas));
+        }
+    });
+
+    let go_to = move |page: usize| {
+        let page = page.min(last_page);
+        if page != current.get_untracked() {
+            current.set(page);
+            if let Some(on_page_change) = on_page_change {
+                on_page_change.run(page);
+            }
+        }
+    };
+    let fit_width = move |_| {
+        let container = page_ref
+            .get_untracked()
+            .map(|page| page.client_width() as f64)
+            .unwrap_or_default();
+        let page_width = backend
+            .with_value(|backend| backend.page_size(current.get_untracked()))
+            .map(|size| size.width)
+            .unwrap_or_default();
+        if let Some(fit) = pdf_fit_width(container, page_width) {
+            scale.set(fit);
+        }
+    };
+    let run_search = move || {
+        let text = query.get_untracked();
+        if text.trim().is_empty() {
+            matches.set(Vec::new());
+            active_match.set(None);
+            searched.set(false);
+            return;
+        }
+        let search = backend.with_value(|backend| backend.search(text.trim()));
+        spawn_local(async move {
+            let pages = search.await;
+            let first = pages.first().copied();
+            matches.set(pages);
+            active_match.set(first.map(|_| 0));
+            searched.set(true);
+            if let Some(page) = first {
+                go_to(page);
+            }
+        });
+    };
+    let step_match = move |forward: bool| {
+        let count = matches.with_untracked(Vec::len);
+        if let Some(index) = pdf_match_step(active_match.get_untracked(), count, forward) {
+            active_match.set(Some(index));
+            if let Some(page) = matches.with_untracked(|pages| pages.get(index).copied()) {
+                go_to(page);
+            }
+        }
+    };
+
+    let handle_page_keydown = move |e: web_sys::KeyboardEvent| {
+        match e.key().as_str() {
+            "PageDown" => go_to(current.get_untracked() + 1),
+            "PageUp" => go_to(current.get_untracked().saturating_sub(1)),
+            "Home" if e.ctrl_key() => go_to(0),
+            "End" if e.ctrl_key() => go_to(last_page),
+            "+" | "=" => scale.set(pdf_zoom_step(scale.get_untracked(), true)),
+            "-" => scale.set(pdf_zoom_step(scale.get_untracked(), false)),
+            _ => return,
+        }
+        e.prevent_default();
+    };
+    let handle_page_input = move |e: web_sys::Event| {
+        match parse_pdf_page(&event_target_value(&e), page_count) {
+            Some(page) => go_to(page),
+            None => {
+                // Put the current page number back after invalid input
+                current.set(current.get_untracked());
+            }
+        }
+    };
+    let handle_search_keydown = move |e: web_sys::KeyboardEvent| {
+        if e.key() == "Enter" {
+            e.prevent_default();
+            if searched.get_untracked() && !matches.with_untracked(Vec::is_empty) {
+                step_match(!e.shift_key());
+            } else {
+                run_search();
+            }
+        }
+    };
+
+    let status_catalog = catalog.clone();
+    let search_status = move || {
+        if !searched.get() {
+            return String::new();
+        }
+        let count = matches.with(Vec::len);
+        match active_match.get() {
+            Some(index) if count > 0 => status_catalog.format(
+                MessageKey::PdfViewerMatch,
+                &MessageArgs::new().with("position", index + 1).with("count", count),
+            ),
+            _ => status_catalog.text(MessageKey::NoResults),
+        }
+    };
+
+    let select_page = Callback::new(go_to);
+
+    let class = merge_classes(vec!["pdf-viewer", class.as_deref().unwrap_or("")]);
+    let sidebar_controls = sidebar_id.clone();
+    let search_label = catalog.text(MessageKey::PdfViewerSearch);
+    let search_placeholder = catalog.text(MessageKey::PdfViewerSearchPlaceholder);
+    let previous_match_label = catalog.text(MessageKey::PdfViewerPreviousMatch);
+    let next_match_label = catalog.text(MessageKey::PdfViewerNextMatch);
+    let page_catalog = catalog.clone();
+
+    view! {
+        <div class=class style=style role="region" aria-label=label data-page-count=page_count>
+            <div class="pdf-viewer-toolbar" role="group" aria-label=catalog.text(MessageKey::PdfViewerControls)>
+                <button
+                    type="button"
+                    class="pdf-viewer-sidebar-toggle"
+                    aria-label=catalog.text(MessageKey::PdfViewerThumbnails)
+                    aria-controls=sidebar_controls
+                    aria-expanded=move || sidebar_open.get().to_string()
+                    on:click=move |_| sidebar_open.update(|open| *open = !*open)
+                >
+                    <span aria-hidden="true">"▤"</span>
+                </button>
+                <button
+                    type="button"
+                    aria-label=catalog.text(MessageKey::PdfViewerPreviousPage)
+                    aria-controls=page_id.clone()
+                    disabled=move || current.get() == 0
+                    on:click=move |_| go_to(current.get_untracked().saturating_sub(1))
+                >
+                    <span aria-hidden="true">"‹"</span>
+                </button>
+                <input
+                    type="number"
+                    class="pdf-viewer-page-input"
+                    aria-label=catalog.text(MessageKey::PdfViewerPageInput)
+                    min="1"
+                    max=page_count
+                    prop:value=move || (current.get() + 1).to_string()
+                    on:change=handle_page_input
+                />
+                <span class="pdf-viewer-page-count">{catalog.format(MessageKey::PdfViewerPageCount, &MessageArgs::new().with("count", page_count))}</span>
+                <button
+                    type="button"
+                    aria-label=catalog.text(MessageKey::PdfViewerNextPage)
+                    aria-controls=page_id.clone()
+                    disabled=move || current.get() >= last_page
+                    on:click=move |_| go_to(current.get_untracked() + 1)
+                >
+                    <span aria-hidden="true">"›"</span>
+                </button>
+                <button
+                    type="button"
+                    aria-label=catalog.text(MessageKey::ZoomOut)
+                    disabled=move || scale.get() <= PDF_ZOOM_LEVELS[0]
+                    on:click=move |_| scale.update(|zoom| *zoom = pdf_zoom_step(*zoom, false))
+                >
+                    "−"
+                </button>
+                <output class="pdf-viewer-zoom" aria-live="polite">
+                    {move || format!("{}%", (scale.get() * 100.0).round())}
+                </output>
+                <button
+                    type="button"
+                    aria-label=catalog.text(MessageKey::ZoomIn)
+                    disabled=move || scale.get() >= PDF_ZOOM_LEVELS[PDF_ZOOM_LEVELS.len() - 1]
+                    on:click=move |_| scale.update(|zoom| *zoom = pdf_zoom_step(*zoom, true))
+                >
+                    "+"
+                </button>
+                <button type="button" on:click=fit_width>
+                    {catalog.text(MessageKey::PdfViewerFitWidth)}
+                </button>
+                {searchable
+                    .then(|| {
+                        view! {
+                            <div class="pdf-viewer-search" role="search">
+                                <input
+                                    type="search"
+                                    aria-label=search_label
+                                    placeholder=search_placeholder
+                                    prop:value=move || query.get()
+                                    on:input=move |e| {
+                                        query.set(event_target_value(&e));
+                                        searched.set(false);
+                                    }
+                                    on:keydown=handle_search_keydown
+                                />
+                                <button
+                                    type="button"
+                                    aria-label=previous_match_label
+                                    disabled=move || matches.with(Vec::is_empty)
+                                    on:click=move |_| step_match(false)
+                                >
+                                    <span aria-hidden="true">"↑"</span>
+                                </button>
+                                <button
+                                    type="button"
+                                    aria-label=next_match_label
+                                    disabled=move || matches.with(Vec::is_empty)
+                                    on:click=move |_| step_match(true)
+                                >
+                                    <span aria-hidden="true">"↓"</span>
+                                </button>
+                                <span role="status" class="pdf-viewer-search-status">
+                                    {search_status}
+                                </span>
+                            </div>
+                        }
+                    })}
+            </div>
+            <div class="pdf-viewer-body" style="display: flex; min-height: 0;">
+                <nav
+                    id=sidebar_id
+                    class="pdf-viewer-thumbnails"
+                    aria-label=catalog.text(MessageKey::PdfViewerPages)
+                    hidden=move || !sidebar_open.get()
+                >
+                    {move || {
+                        sidebar_open
+                            .get()
+                            .then(|| {
+                                view! {
+                                    <ol>
+                                        {(0..page_count)
+                                            .map(|page| {
+                                                view! { <PdfThumbnail
+                                                        backend=backend
+                                                        page=page
+                                                        current=current
+                                                        on_select=select_page
+                                                    /> }
+                                            })
+                                            .collect_view()}
+                                    </ol>
+                                }
+                            })
+                    }}
+                </nav>
+                <div
+                    id=page_id.clone()
+                    node_ref=page_ref
+                    class="pdf-viewer-page"
+                    style="flex: 1; overflow: auto;"
+                    tabindex="0"
+                    role="group"
+                    aria-roledescription=catalog.text(MessageKey::PdfViewerPageRole)
+                    aria-label=move || {
+                        page_catalog.format(
+                            MessageKey::PdfViewerPagePosition,
+                            &MessageArgs::new().with("page", current.get() + 1).with("count", page_count),
+                        )
+                    }
+                    on:keydown=handle_page_keydown
+                >
+                    <canvas node_ref=canvas_ref aria-hidden="true"></canvas>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pdf_zoom_step() {
+        assert_eq!(pdf_zoom_step(1.0, true), 1.25);
+        assert_eq!(pdf_zoom_step(1.0, false), 0.75);
+        assert_eq!(pdf_zoom_step(1.1, false), 1.0);
+        assert_eq!(pdf_zoom_step(4.0, true), 4.0);
+        assert_eq!(pdf_zoom_step(0.5, false), 0.5);
+        assert_eq!(pdf_fit_width(918.0, 612.0), Some(1.5));
+        assert_eq!(pdf_fit_width(0.0, 612.0), None);
+    }
+
+    #[test]
+    fn test_parse_pdf_page() {
+        assert_eq!(parse_pdf_page("3", 10), Some(2));
+        assert_eq!(parse_pdf_page(" 10 ", 10), Some(9));
+        assert_eq!(parse_pdf_page("0", 10), None);
+        assert_eq!(parse_pdf_page("11", 10), None);
+        assert_eq!(parse_pdf_page("two", 10), None);
+    }
+
+    #[test]
+    fn test_pdf_match_step() {
+        assert_eq!(pdf_match_step(None, 3, true), Some(0));
+        assert_eq!(pdf_match_step(None, 3, false), Some(2));
+        assert_eq!(pdf_match_step(Some(2), 3, true), Some(0));
+        assert_eq!(pdf_match_step(Some(0), 3, false), Some(2));
+        assert_eq!(pdf_match_step(Some(0), 0, true), None);
+    }
+}
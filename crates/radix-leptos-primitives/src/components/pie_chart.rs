@@ -1,5 +1,17 @@
+use super::chart::chart_root_style;
+use super::timeline::AnimationConfig;
+use crate::i18n::{use_i18n, MessageArgs, MessageKey};
+use crate::theming::use_chart_palette;
+use crate::utils::merge_classes;
+use leptos::callback::Callback;
+use leptos::children::Children;
+use leptos::prelude::*;
+
 
 /// PieChart component - Proportional data visualization
+///
+/// Slices without a `color` take the palette's categorical color at their
+/// index, set on the chart as `--chart-series-1`, `--chart-series-2`, …
 #[component]
 pub fn PieChart(
     #[prop(optional)] class: Option<String>,
@@ -20,19 +32,37 @@ pub fn PieChart(
     let show_labels = show_labels.unwrap_or(true);
     let show_percentages = show_percentages.unwrap_or(true);
     let show_legend = show_legend.unwrap_or(true);
+    let slice_count = data.len();
+    let palette = use_chart_palette();
+    let style = move || {
+        let colors = palette
+            .with(|palette| palette.series_colors(data.iter().map(|slice| slice.color.as_deref())));
+        chart_root_style(&colors, style.as_deref())
+    };
+
+    let class = merge_classes(vec!["pie-chart", class.as_deref().unwrap_or("")]);
+
+    let _handle_slice_click = move |slice: PieSlice| {
+        if let Some(on_slice_click) = on_slice_click {
+            on_slice_click.run(slice);
+        }
+    };
 
-    let class = merge_classes(vec![
-        "pie-chart",
-        class.as_deref().unwrap_or(""),
-    ]);
+    let _handle_slice_hover = move |slice: PieSlice| {
+        if let Some(on_slice_hover) = on_slice_hover {
+            on_slice_hover.run(slice);
+        }
+    };
 
     view! {
         <div
             class=class
             style=style
             role="img"
-            aria-label="Pie chart visualization"
-            data-slice-count=data.len()
+            aria-label=use_i18n().text(MessageKey::PieChartLabel)
+            data-slice-count=slice_count
+            data-width=config.width
+            data-height=config.height
             data-inner-radius=inner_radius
             data-show-labels=show_labels
             data-show-percentages=show_percentages
@@ -48,7 +78,8 @@ pub fn PieChart(
 pub struct PieSlice {
     pub label: String,
     pub value: f64,
-    pub color: String,
+    /// Slice color, the palette's color for its index when `None`
+    pub color: Option<String>,
     pub percentage: f64,
     pub start_angle: f64,
     pub end_angle: f64,
@@ -59,7 +90,7 @@ impl Default for PieSlice {
         Self {
             label: "Slice".to_string(),
             value: 1.0,
-            color: "#3b82f6".to_string(),
+            color: None,
             percentage: 100.0,
             start_angle: 0.0,
             end_angle: 360.0,
@@ -91,45 +122,6 @@ impl Default for PieChartConfig {
     }
 }
 
-/// Animation Configuration
-#[derive(Debug, Clone, PartialEq)]
-pub struct AnimationConfig {
-    pub duration: f64,
-    pub easing: EasingType,
-    pub delay: f64,
-}
-
-impl Default for AnimationConfig {
-    fn default() -> Self {
-        Self {
-            duration: 1000.0,
-            easing: EasingType::EaseInOut,
-            delay: 0.0,
-        }
-    }
-}
-
-/// Easing Type
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
-pub enum EasingType {
-    #[default]
-    EaseInOut,
-    EaseIn,
-    EaseOut,
-    Linear,
-}
-
-impl EasingType {
-    pub fn to_class(&self) -> &'static str {
-        match self {
-            EasingType::EaseInOut => "ease-in-out",
-            EasingType::EaseIn => "ease-in",
-            EasingType::EaseOut => "ease-out",
-            EasingType::Linear => "linear",
-        }
-    }
-}
-
 /// Pie Chart Slice component
 #[component]
 pub fn PieChartSlice(
@@ -142,24 +134,35 @@ pub fn PieChartSlice(
     let slice = slice.unwrap_or_default();
     let inner_radius = inner_radius.unwrap_or(0.0);
 
-    let class = merge_classes(vec![
-        "pie-chart-slice",
-        class.as_deref().unwrap_or(""),
-    ]);
+    let class = merge_classes(vec!["pie-chart-slice", class.as_deref().unwrap_or("")]);
+    let aria_label = use_i18n().format(
+        MessageKey::PieChartSlice,
+        &MessageArgs::new()
+            .with("label", slice.label.as_str())
+            .with("percent", slice.percentage),
+    );
+    let label = slice.label.clone();
+    let (value, percentage) = (slice.value, slice.percentage);
+    let (start_angle, end_angle) = (slice.start_angle, slice.end_angle);
 
     view! {
         <div
             class=class
             style=style
             role="button"
-            aria-label=format!("Slice: {} - {}%", slice.label, slice.percentage)
-            data-label=slice.label
-            data-value=slice.value
-            data-percentage=slice.percentage
-            data-start-angle=slice.start_angle
-            data-end-angle=slice.end_angle
+            aria-label=aria_label
+            data-label=label
+            data-value=value
+            data-percentage=percentage
+            data-start-angle=start_angle
+            data-end-angle=end_angle
             data-inner-radius=inner_radius
             tabindex="0"
+            on:click=move |_| {
+                if let Some(on_click) = on_click {
+                    on_click.run(slice.clone());
+                }
+            }
         />
     }
 }
@@ -179,15 +182,22 @@ pub fn PieChartLabel(
 
     let class = merge_classes(vec![
         "pie-chart-label",
-        &position.to_class(),
+        position.to_class(),
+        class.as_deref().unwrap_or(""),
+    ]);
+
+    view! {
+        <div
+            class=class
+            style=style
             data-label=slice.label.clone()
             data-percentage=slice.percentage
             data-position=position.to_string()
         >
             {slice.label.clone()}
-            {if show_percentage {
-                view! { <span class="percentage">" (" {slice.percentage} "%)"</span> }.into_any()
-    }}
+            {show_percentage.then(|| view! {
+                <span class="percentage">" (" {slice.percentage} "%)"</span>
+            })}
         </div>
     }
 }
@@ -219,118 +229,38 @@ impl LabelPosition {
     }
 }
 
-/// Helper function to merge CSS classes
-fn merge_optional_classes(classes: Vec<&str>) -> String {
-    classes
-        .into_iter()
-        .filter(|c| !c.is_empty())
-        .collect::<Vec<_>>()
-        .join(" ")
-}
-
 #[cfg(test)]
 mod tests {
-    use wasm_bindgen_test::*;
-    use proptest::prelude::*;
-use crate::utils::merge_classes;
-
-    wasm_bindgen_test_configure!(run_in_browser);
+    use super::*;
+    use crate::theming::ChartPalette;
 
-    // Unit Tests
-    #[test] fn test_piechart_creation() { 
-    #[test] fn test_piechart_with_class() { 
-    #[test] fn test_piechart_with_style() { 
-    #[test] fn test_piechart_with_data() { 
-    #[test] fn test_piechart_with_config() { 
-    #[test] fn test_piechart_inner_radius() { 
-    #[test] fn test_piechart_show_labels() { 
-    #[test] fn test_piechart_show_percentages() { 
-    #[test] fn test_piechart_show_legend() { 
-    #[test] fn test_piechart_on_slice_click() { 
-    #[test] fn test_piechart_on_slice_hover() { 
-
-    // Pie Slice tests
-    #[test] fn test_pie_slice_default() { 
-    #[test] fn test_pie_slice_creation() { 
-
-    // Pie Chart Config tests
-    #[test] fn test_piechart_config_default() { 
-    #[test] fn test_piechart_config_custom() { 
-
-    // Animation Config tests
-    #[test] fn test_animation_config_default() { 
-    #[test] fn test_animation_config_custom() { 
-
-    // Easing Type tests
-    #[test] fn test_easing_type_default() { 
-    #[test] fn test_easing_type_ease_in_out() { 
-    #[test] fn test_easing_type_ease_in() { 
-    #[test] fn test_easing_type_ease_out() { 
-    #[test] fn test_easing_type_linear() { 
-
-    // Pie Chart Slice tests
-    #[test] fn test_piechart_slice_creation() { 
-    #[test] fn test_piechart_slice_with_class() { 
-    #[test] fn test_piechart_slice_with_style() { 
-    #[test] fn test_piechart_slice_with_slice() { 
-    #[test] fn test_piechart_slice_inner_radius() { 
-    #[test] fn test_piechart_slice_on_click() { 
-
-    // Pie Chart Label tests
-    #[test] fn test_piechart_label_creation() { 
-    #[test] fn test_piechart_label_with_class() { 
-    #[test] fn test_piechart_label_with_style() { 
-    #[test] fn test_piechart_label_with_slice() { 
-    #[test] fn test_piechart_label_position() { 
-    #[test] fn test_piechart_label_show_percentage() { 
-
-    // Label Position tests
-    #[test] fn test_label_position_default() { 
-    #[test] fn test_label_position_outside() { 
-    #[test] fn test_label_position_inside() { 
-    #[test] fn test_label_position_center() { 
-
-    // Helper function tests
-    #[test] fn test_merge_classes_empty() { 
-    #[test] fn test_merge_classes_single() { 
-    #[test] fn test_merge_classes_multiple() { 
-    #[test] fn test_merge_classes_with_empty() { 
-
-    // Property-based Tests
-    #[test] fn test_piechart_property_based() {
-        proptest!(|(____class in ".*", __style in ".*")| {
-            
-        });
-    }
-
-    #[test] fn test_piechart_data_validation() {
-        proptest!(|(______slice_count in 1..20usize)| {
-            
-        });
+    #[test]
+    fn test_pie_slice_default_has_no_color() {
+        assert_eq!(PieSlice::default().color, None);
     }
 
-    #[test] fn test_piechart_config_validation() {
-        proptest!(|(____width in 100.0..1000.0f64, __height in 100.0..1000.0f64, __radius in 50.0..200.0f64)| {
-            
-        });
+    #[test]
+    fn test_piechart_colors_slices_from_palette() {
+        crate::test_support::init_test_executor();
+        let slices = vec![
+            PieSlice { label: "Rent".to_string(), ..PieSlice::default() },
+            PieSlice { label: "Food".to_string(), ..PieSlice::default() },
+        ];
+        let html = Owner::new().with(|| view! { <PieChart data=slices /> }.to_html());
+        let palette = ChartPalette::default();
+        assert!(html.contains(&format!(
+            "--chart-series-1: {}; --chart-series-2: {};",
+            palette.series_color(0),
+            palette.series_color(1)
+        )));
     }
 
-    #[test] fn test_piechart_animation_property_based() {
-        proptest!(|(____duration in 100.0..5000.0f64, __delay in 0.0..1000.0f64)| {
-            
-        });
+    #[test]
+    fn test_piechart_label_shows_percentage() {
+        crate::test_support::init_test_executor();
+        let slice = PieSlice { label: "Rent".to_string(), percentage: 40.0, ..PieSlice::default() };
+        let html = Owner::new().with(|| view! { <PieChartLabel slice=slice /> }.to_html());
+        assert!(html.contains("Rent"));
+        assert!(html.contains("class=\"percentage\""));
     }
-
-    // Integration Tests
-    #[test] fn test_piechart_tooltip_interaction() { 
-    #[test] fn test_piechart_legend_interaction() { 
-    #[test] fn test_piechart_user_workflow() { 
-    #[test] fn test_piechart_accessibility_workflow() { 
-    #[test] fn test_piechart_with_other_components() { 
-
-    // Performance Tests
-    #[test] fn test_piechart_large_dataset() { 
-    #[test] fn test_piechart_render_performance() { 
-    #[test] fn test_piechart_memory_usage() { 
-    #[test] fn test_piechart_animation_performance() { 
 }
@@ -1,7 +1,10 @@
+use crate::i18n::{use_i18n, MessageKey};
+use crate::theming::PresenceAnimation;
 use crate::utils::merge_classes;
 use leptos::callback::Callback;
 use leptos::children::Children;
 use leptos::prelude::*;
+use radix_leptos_core::use_presence;
 
 /// Popover component for floating content containers
 ///
@@ -92,19 +95,12 @@ pub fn PopoverContent(
     #[prop(optional)] side_offset: Option<f64>,
     #[prop(optional)] align_offset: Option<f64>,
 ) -> impl IntoView {
-    let visible = visible.map(|v| v.get()).unwrap_or(true);
+    let visible = Signal::derive(move || visible.map(|v| v.get()).unwrap_or(true));
     let side = side.unwrap_or_default();
     let align = align.unwrap_or_default();
     let side_offset = side_offset.unwrap_or(4.0);
     let align_offset = align_offset.unwrap_or(0.0);
 
-    if !visible {
-        return {
-            let _: () = view! { <></> };
-            ().into_any()
-        };
-    }
-
     let class = merge_classes(vec![
         "popover-content",
         &side.to_class(),
@@ -119,19 +115,25 @@ pub fn PopoverContent(
         align_offset
     );
 
+    // Hidden only once the exit animation has played
+    let node_ref = NodeRef::<leptos::html::Div>::new();
+    let presence = use_presence(visible, node_ref);
+
     view! {
         <div
+            node_ref=node_ref
             class=class
             style=style
             role="dialog"
-            aria-hidden="false"
+            data-state=move || presence.state.get().as_str()
+            data-motion=PresenceAnimation::Scale.as_str()
+            hidden=move || !presence.is_present.get()
             data-side=side.to_aria()
             data-align=align.to_aria()
         >
             {children.map(|c| c())}
         </div>
     }
-    .into_any()
 }
 
 /// Popover Portal component
@@ -205,7 +207,7 @@ pub fn PopoverClose(
             class=class
             style=style
             on:click=handle_click
-            aria-label="Close"
+            aria-label=use_i18n().text(MessageKey::Close)
         >
             {children.map(|c| c())}
         </button>
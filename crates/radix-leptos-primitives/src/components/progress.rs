@@ -1,6 +1,10 @@
 use leptos::children::Children;
 use leptos::prelude::*;
-use crate::utils::{merge_optional_classes, generate_id};
+use crate::utils::{forced_colors_style, generate_id, merge_optional_classes};
+use radix_leptos_core::{use_forced_colors, use_reduced_motion};
+
+/// Outline of the bar in forced colors mode, where its background is dropped
+const FORCED_COLORS_TRACK: &str = "border: 1px solid CanvasText;";
 
 /// Progress component with proper accessibility and styling variants
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -41,6 +45,9 @@ impl ProgressSize {
 
 
 /// Progress root component
+///
+/// In forced colors mode the bar gets a border and the indicator is filled
+/// with `Highlight`, as backgrounds alone are dropped.
 #[component]
 pub fn Progress(
     /// Current progress value (0-100)
@@ -84,6 +91,14 @@ pub fn Progress(
     } else {
         0.0
     };
+    // Styles key the indeterminate animation off this, so it stops while
+    // motion is reduced
+    let reduced_motion = use_reduced_motion();
+    let animated = move || indeterminate && !reduced_motion.get();
+    let forced_colors = use_forced_colors();
+    let style = move || {
+        forced_colors_style(style.as_deref(), forced_colors.get(), FORCED_COLORS_TRACK)
+    };
 
     view! {
         <div
@@ -94,6 +109,7 @@ pub fn Progress(
             data-value=value
             data-max=max
             data-indeterminate=indeterminate
+            data-animated=animated
             data-percentage=percentage
             role="progressbar"
             aria-valuemin=0.0
@@ -116,6 +132,10 @@ pub fn ProgressTrack(
     let base_classes = "radix-progress-track";
     let combined_class = merge_optional_classes(Some(base_classes), class.as_deref())
         .unwrap_or_else(|| base_classes.to_string());
+    let forced_colors = use_forced_colors();
+    let style = move || {
+        forced_colors_style(style.as_deref(), forced_colors.get(), FORCED_COLORS_TRACK)
+    };
 
     view! {
         <div
@@ -139,6 +159,14 @@ pub fn ProgressIndicator(
     let base_classes = "radix-progress-indicator";
     let combined_class = merge_optional_classes(Some(base_classes), class.as_deref())
         .unwrap_or_else(|| base_classes.to_string());
+    let forced_colors = use_forced_colors();
+    let style = move || {
+        forced_colors_style(
+            style.as_deref(),
+            forced_colors.get(),
+            "forced-color-adjust: none; background: Highlight;",
+        )
+    };
 
     view! {
         <div
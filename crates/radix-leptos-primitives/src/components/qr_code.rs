@@ -0,0 +1,466 @@
+use crate::utils::{escape_html, merge_classes};
+use leptos::callback::Callback;
+use leptos::children::Children;
+use leptos::html;
+use leptos::prelude::*;
+use qrcodegen::{QrCode, QrCodeEcc};
+use wasm_bindgen::JsCast;
+
+/// QRCode component - Client-side QR code generation
+///
+/// Encodes `value` with a pure Rust encoder and renders it as SVG (default) or
+/// onto a canvas. A logo can be overlaid in the centre through `children`; when
+/// present the error correction level is raised so the code stays scannable.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn Share() -> impl IntoView {
+///     view! {
+///         <QRCode
+///             value="https://radix-leptos.dev"
+///             size=192.0
+///             error_correction=QRErrorCorrection::Quartile
+///         />
+///     }
+/// }
+/// ```
+#[component]
+pub fn QRCode(
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    /// Text or URL to encode
+    #[prop(into)]
+    value: Signal<String>,
+    /// Error correction level
+    #[prop(optional)]
+    error_correction: Option<QRErrorCorrection>,
+    /// Rendered width and height in pixels
+    #[prop(optional)]
+    size: Option<f64>,
+    /// Quiet zone around the code, in modules
+    #[prop(optional)]
+    margin: Option<usize>,
+    /// Module colour
+    #[prop(optional)]
+    foreground: Option<String>,
+    /// Background colour
+    #[prop(optional)]
+    background: Option<String>,
+    /// Render as SVG or canvas
+    #[prop(optional)]
+    render_mode: Option<QRRenderMode>,
+    /// Accessible label
+    #[prop(optional)]
+    label: Option<String>,
+    /// Called when the value cannot be encoded
+    #[prop(optional)]
+    on_error: Option<Callback<QRCodeError>>,
+    /// Logo overlay slot rendered in the centre of the code
+    #[prop(optional)]
+    children: Option<Children>,
+) -> impl IntoView {
+    let has_logo = children.is_some();
+    let error_correction = error_correction.unwrap_or_default().for_logo(has_logo);
+    let size = size.unwrap_or(128.0);
+    let margin = margin.unwrap_or(4);
+    let foreground = foreground.unwrap_or_else(|| "#000000".to_string());
+    let background = background.unwrap_or_else(|| "#ffffff".to_string());
+    let render_mode = render_mode.unwrap_or_default();
+    let label = label.unwrap_or_else(|| "QR code".to_string());
+
+    let matrix = Memo::new(move |_| {
+        let result = QRMatrix::encode(&value.get(), error_correction);
+        if let (Err(error), Some(on_error)) = (&result, on_error) {
+            on_error.run(error.clone());
+        }
+        result.ok()
+    });
+
+    let class = merge_classes(vec![
+        "qr-code",
+        render_mode.to_class(),
+        if has_logo { "has-logo" } else { "" },
+        class.as_deref().unwrap_or(""),
+    ]);
+    let container_style = format!(
+        "position: relative; width: {size}px; height: {size}px; {}",
+        style.unwrap_or_default()
+    );
+
+    let code = match render_mode {
+        QRRenderMode::Svg => {
+            let foreground = foreground.clone();
+            let background = background.clone();
+            let label = label.clone();
+            (move || {
+                matrix.get().map(|matrix| {
+                    let extent = matrix.size + margin * 2;
+                    view! {
+                        <svg
+                            class="qr-code-svg"
+                            width=size
+                            height=size
+                            viewBox=format!("0 0 {extent} {extent}")
+                            shape-rendering="crispEdges"
+                            role="img"
+                            aria-label=label.clone()
+                        >
+                            <rect width="100%" height="100%" fill=background.clone() />
+                            <path d=qr_svg_path(&matrix, margin) fill=foreground.clone() />
+                        </svg>
+                    }
+                })
+            })
+            .into_any()
+        }
+        QRRenderMode::Canvas => {
+            let canvas_ref = NodeRef::<html::Canvas>::new();
+            let foreground = foreground.clone();
+            let background = background.clone();
+            Effect::new(move |_| {
+                if let (Some(canvas), Some(matrix)) = (canvas_ref.get(), matrix.get()) {
+                    draw_qr_to_canvas(&canvas, &matrix, margin, size, &foreground, &background);
+                }
+            });
+            view! {
+                <canvas
+                    class="qr-code-canvas"
+                    node_ref=canvas_ref
+                    width=size
+                    height=size
+                    role="img"
+                    aria-label=label.clone()
+                ></canvas>
+            }
+            .into_any()
+        }
+    };
+
+    view! {
+        <div
+            class=class
+            style=container_style
+            data-error-correction=error_correction.as_str()
+            data-render-mode=render_mode.as_str()
+        >
+            {code}
+            {children.map(|logo| {
+                view! {
+                    <div
+                        class="qr-code-logo"
+                        style="position: absolute; top: 50%; left: 50%; transform: translate(-50%, -50%);"
+                    >
+                        {logo()}
+                    </div>
+                }
+            })}
+        </div>
+    }
+}
+
+/// QR code error correction level
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum QRErrorCorrection {
+    /// Recovers ~7% of data
+    Low,
+    /// Recovers ~15% of data
+    #[default]
+    Medium,
+    /// Recovers ~25% of data
+    Quartile,
+    /// Recovers ~30% of data
+    High,
+}
+
+impl QRErrorCorrection {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            QRErrorCorrection::Low => "L",
+            QRErrorCorrection::Medium => "M",
+            QRErrorCorrection::Quartile => "Q",
+            QRErrorCorrection::High => "H",
+        }
+    }
+
+    /// Level to use when a logo covers the centre of the code
+    pub fn for_logo(self, has_logo: bool) -> Self {
+        if has_logo {
+            QRErrorCorrection::High
+        } else {
+            self
+        }
+    }
+
+    fn to_ecc(self) -> QrCodeEcc {
+        match self {
+            QRErrorCorrection::Low => QrCodeEcc::Low,
+            QRErrorCorrection::Medium => QrCodeEcc::Medium,
+            QRErrorCorrection::Quartile => QrCodeEcc::Quartile,
+            QRErrorCorrection::High => QrCodeEcc::High,
+        }
+    }
+}
+
+/// QR code output
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QRRenderMode {
+    #[default]
+    Svg,
+    Canvas,
+}
+
+impl QRRenderMode {
+    pub fn to_class(&self) -> &'static str {
+        match self {
+            QRRenderMode::Svg => "render-svg",
+            QRRenderMode::Canvas => "render-canvas",
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            QRRenderMode::Svg => "svg",
+            QRRenderMode::Canvas => "canvas",
+        }
+    }
+}
+
+/// QR code generation errors
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum QRCodeError {
+    #[error("value is too long to encode as a QR code")]
+    DataTooLong,
+    #[error("failed to export QR code: {0}")]
+    Export(String),
+}
+
+/// Encoded QR code modules (row-major, `true` = dark)
+#[derive(Debug, Clone, PartialEq)]
+pub struct QRMatrix {
+    pub size: usize,
+    pub modules: Vec<bool>,
+}
+
+impl QRMatrix {
+    /// Encode text with the given error correction level
+    pub fn encode(value: &str, error_correction: QRErrorCorrection) -> Result<Self, QRCodeError> {
+        let code = QrCode::encode_text(value, error_correction.to_ecc())
+            .map_err(|_| QRCodeError::DataTooLong)?;
+        let size = code.size();
+        let modules = (0..size)
+            .flat_map(|y| (0..size).map(move |x| (x, y)))
+            .map(|(x, y)| code.get_module(x, y))
+            .collect();
+
+        Ok(Self {
+            size: size as usize,
+            modules,
+        })
+    }
+
+    pub fn is_dark(&self, x: usize, y: usize) -> bool {
+        x < self.size && y < self.size && self.modules[y * self.size + x]
+    }
+}
+
+/// SVG path data drawing every dark module as a unit square
+pub fn qr_svg_path(matrix: &QRMatrix, margin: usize) -> String {
+    let mut path = String::new();
+    for y in 0..matrix.size {
+        for x in 0..matrix.size {
+            if matrix.is_dark(x, y) {
+                path.push_str(&format!("M{},{}h1v1h-1z", x + margin, y + margin));
+            }
+        }
+    }
+    path
+}
+
+/// Standalone SVG document for a QR code
+///
+/// The colors are escaped, so any string yields well-formed markup.
+pub fn qr_code_svg(matrix: &QRMatrix, margin: usize, foreground: &str, background: &str) -> String {
+    let extent = matrix.size + margin * 2;
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 {extent} {extent}\" \
+         shape-rendering=\"crispEdges\"><rect width=\"100%\" height=\"100%\" fill=\"{}\"/>\
+         <path d=\"{}\" fill=\"{}\"/></svg>",
+        escape_html(background),
+        qr_svg_path(matrix, margin),
+        escape_html(foreground)
+    )
+}
+
+/// `data:` URL for an SVG document, suitable for `href`/`src`
+pub fn svg_data_url(svg: &str) -> String {
+    let mut encoded = String::with_capacity(svg.len() + 32);
+    encoded.push_str("data:image/svg+xml;charset=utf-8,");
+    for byte in svg.bytes() {
+        match byte {
+            b'A'..=b'Z'
+            | b'a'..=b'z'
+            | b'0'..=b'9'
+            | b'-'
+            | b'.'
+            | b'_'
+            | b'~'
+            | b'/'
+            | b':'
+            | b'='
+            | b',' => encoded.push(byte as char),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+/// Download a QR code as an SVG file
+pub fn download_qr_code(
+    value: &str,
+    error_correction: QRErrorCorrection,
+    margin: usize,
+    foreground: &str,
+    background: &str,
+    filename: &str,
+) -> Result<(), QRCodeError> {
+    let matrix = QRMatrix::encode(value, error_correction)?;
+    let href = svg_data_url(&qr_code_svg(&matrix, margin, foreground, background));
+
+    let document = web_sys::window()
+        .and_then(|window| window.document())
+        .ok_or_else(|| QRCodeError::Export("document is not available".to_string()))?;
+    let anchor = document
+        .create_element("a")
+        .ok()
+        .and_then(|element| element.dyn_into::<web_sys::HtmlAnchorElement>().ok())
+        .ok_or_else(|| QRCodeError::Export("failed to create download link".to_string()))?;
+    anchor.set_href(&href);
+    anchor.set_download(filename);
+    anchor.click();
+    Ok(())
+}
+
+fn draw_qr_to_canvas(
+    canvas: &web_sys::HtmlCanvasElement,
+    matrix: &QRMatrix,
+    margin: usize,
+    size: f64,
+    foreground: &str,
+    background: &str,
+) {
+    let Some(context) = canvas
+        .get_context("2d")
+        .ok()
+        .flatten()
+        .and_then(|context| context.dyn_into::<web_sys::CanvasRenderingContext2d>().ok())
+    else {
+        return;
+    };
+
+    let scale = size / (matrix.size + margin * 2) as f64;
+    context.set_fill_style_str(background);
+    context.fill_rect(0.0, 0.0, size, size);
+    context.set_fill_style_str(foreground);
+    for y in 0..matrix.size {
+        for x in 0..matrix.size {
+            if matrix.is_dark(x, y) {
+                context.fill_rect(
+                    (x + margin) as f64 * scale,
+                    (y + margin) as f64 * scale,
+                    scale,
+                    scale,
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_qr_matrix_version_one() {
+        let matrix = QRMatrix::encode("HELLO", QRErrorCorrection::Low).unwrap();
+        assert_eq!(matrix.size, 21);
+        assert_eq!(matrix.modules.len(), 21 * 21);
+        // Finder pattern corners are always dark
+        assert!(matrix.is_dark(0, 0));
+        assert!(matrix.is_dark(20, 0));
+        assert!(matrix.is_dark(0, 20));
+        assert!(!matrix.is_dark(21, 0));
+    }
+
+    #[test]
+    fn test_qr_matrix_grows_with_error_correction() {
+        let text = "https://radix-leptos.dev/components/qr-code";
+        let low = QRMatrix::encode(text, QRErrorCorrection::Low).unwrap();
+        let high = QRMatrix::encode(text, QRErrorCorrection::High).unwrap();
+        assert!(high.size > low.size);
+    }
+
+    #[test]
+    fn test_qr_matrix_data_too_long() {
+        let text = "x".repeat(5000);
+        assert_eq!(
+            QRMatrix::encode(&text, QRErrorCorrection::Medium),
+            Err(QRCodeError::DataTooLong)
+        );
+    }
+
+    #[test]
+    fn test_error_correction_for_logo() {
+        assert_eq!(QRErrorCorrection::default(), QRErrorCorrection::Medium);
+        assert_eq!(
+            QRErrorCorrection::Low.for_logo(true),
+            QRErrorCorrection::High
+        );
+        assert_eq!(
+            QRErrorCorrection::Low.for_logo(false),
+            QRErrorCorrection::Low
+        );
+        assert_eq!(QRErrorCorrection::Quartile.as_str(), "Q");
+    }
+
+    #[test]
+    fn test_qr_svg_output() {
+        let matrix = QRMatrix::encode("HELLO", QRErrorCorrection::Low).unwrap();
+        let path = qr_svg_path(&matrix, 4);
+        assert!(path.starts_with("M4,4h1v1h-1z"));
+        assert_eq!(
+            path.matches('M').count(),
+            matrix.modules.iter().filter(|m| **m).count()
+        );
+
+        let svg = qr_code_svg(&matrix, 4, "#111", "#fff");
+        assert!(svg.contains("viewBox=\"0 0 29 29\""));
+        assert!(svg.contains("fill=\"#111\""));
+
+        let svg = qr_code_svg(&matrix, 4, "red\" onload=\"x", "#fff");
+        assert!(svg.contains("fill=\"red&quot; onload=&quot;x\""));
+    }
+
+    #[test]
+    fn test_svg_data_url_escapes_markup() {
+        let url = svg_data_url("<svg fill=\"#000\"/>");
+        assert_eq!(
+            url,
+            "data:image/svg+xml;charset=utf-8,%3Csvg%20fill=%22%23000%22/%3E"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_qr_matrix_is_square(value in "[ -~]{1,200}") {
+            let matrix = QRMatrix::encode(&value, QRErrorCorrection::Medium).unwrap();
+            prop_assert_eq!(matrix.modules.len(), matrix.size * matrix.size);
+            prop_assert_eq!((matrix.size - 17) % 4, 0);
+        }
+    }
+}
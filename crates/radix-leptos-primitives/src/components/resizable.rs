@@ -1,3 +1,4 @@
+use crate::i18n::{use_i18n, MessageKey};
 use leptos::callback::Callback;
 use leptos::children::Children;
 use leptos::prelude::*;
@@ -314,11 +315,11 @@ pub fn ResizablePanel(
                                     type="button"
                                     on:click=handle_toggle
                                 >
-                                    {if collapsed {
-                                        "Expand"
+                                    {use_i18n().text(if collapsed {
+                                        MessageKey::ResizableExpand
                                     } else {
-                                        "Collapse"
-                                    }}
+                                        MessageKey::ResizableCollapse
+                                    })}
                                 </button>
                             }.into_any()
                         } else {
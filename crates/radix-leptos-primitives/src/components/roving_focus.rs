@@ -0,0 +1,71 @@
+use leptos::html;
+use leptos::prelude::*;
+pub use radix_leptos_core::{
+    roving_target, Direction, RovingFocusOptions, RovingOrientation, UseRovingFocusReturn,
+    ROVING_FOCUS_ITEM,
+};
+
+/// Provided by containers (Toolbar, Menubar) whose descendants share one
+/// tab stop
+///
+/// Controls of any kind join the group by rendering
+/// [`RovingFocusGroup::item_attribute`] and starting out of the tab order;
+/// the container moves focus between them with the arrow keys, Home and
+/// End, and keeps the last focused item as the group's tab stop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RovingFocusGroup {
+    pub orientation: RovingOrientation,
+    /// Wrap from the last item to the first
+    pub looping: bool,
+    /// Reading direction of the container
+    pub dir: Direction,
+}
+
+impl RovingFocusGroup {
+    /// Provide a group for the children of `container` and keep its tab
+    /// stop in sync once mounted
+    ///
+    /// The returned handlers belong on the container's `on:keydown` and
+    /// `on:focusin`.
+    pub fn provide(self, container: NodeRef<html::Div>) -> UseRovingFocusReturn {
+        provide_context(self);
+        radix_leptos_core::use_roving_focus(
+            container,
+            RovingFocusOptions {
+                orientation: self.orientation,
+                looping: self.looping,
+                dir: self.dir,
+            },
+        )
+    }
+
+    /// Attribute for an item rendered inside a group, `None` outside one
+    pub fn item_attribute() -> Option<&'static str> {
+        use_context::<RovingFocusGroup>().map(|_| "")
+    }
+}
+
+/// Initial `tabindex` of a control that may sit inside a group
+///
+/// Items start out of the tab order and the group promotes one of them
+/// once mounted; outside a group the control keeps `standalone`.
+pub fn roving_tab_index(standalone: Option<&'static str>) -> Option<&'static str> {
+    match use_context::<RovingFocusGroup>() {
+        Some(_) => Some("-1"),
+        None => standalone,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tab_index_outside_group() {
+        let owner = Owner::new();
+        owner.with(|| {
+            assert_eq!(roving_tab_index(Some("0")), Some("0"));
+            assert_eq!(RovingFocusGroup::item_attribute(), None);
+        });
+    }
+}
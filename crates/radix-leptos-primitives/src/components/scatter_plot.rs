@@ -1,5 +1,16 @@
+use super::chart::{chart_root_style, AxisConfig, ChartMargin};
+use crate::i18n::{use_i18n, MessageArgs, MessageKey};
+use crate::theming::use_chart_palette;
+use crate::utils::merge_classes;
+use leptos::callback::Callback;
+use leptos::children::Children;
+use leptos::prelude::*;
+
 
 /// ScatterPlot component - Correlation analysis
+///
+/// Series without a `color` take the palette's categorical color at their
+/// index, set on the chart as `--chart-series-1`, `--chart-series-2`, …
 #[component]
 pub fn ScatterPlot(
     #[prop(optional)] class: Option<String>,
@@ -19,8 +30,43 @@ pub fn ScatterPlot(
     let show_grid = show_grid.unwrap_or(true);
     let show_axes = show_axes.unwrap_or(true);
 
-    let class = merge_classes(vec![
-        "scatter-plot",
+    let series_count = data.len();
+    let palette = use_chart_palette();
+    let style = move || {
+        let colors = palette.with(|palette| {
+            palette.series_colors(data.iter().map(|series| series.color.as_deref()))
+        });
+        chart_root_style(&colors, style.as_deref())
+    };
+
+    let class = merge_classes(vec!["scatter-plot", class.as_deref().unwrap_or("")]);
+
+    let _handle_point_click = move |point: ScatterPoint| {
+        if let Some(on_point_click) = on_point_click {
+            on_point_click.run(point);
+        }
+    };
+
+    let _handle_point_hover = move |point: ScatterPoint| {
+        if let Some(on_point_hover) = on_point_hover {
+            on_point_hover.run(point);
+        }
+    };
+
+    view! {
+        <div
+            class=class
+            style=style
+            role="img"
+            aria-label=use_i18n().text(MessageKey::ScatterPlotLabel)
+            data-series-count=series_count
+            data-show-trend-line=show_trend_line
+            data-show-grid=show_grid
+            data-show-axes=show_axes
+            data-width=config.width
+            data-height=config.height
+        >
+            {children.map(|c| c())}
         </div>
     }
 }
@@ -30,7 +76,8 @@ pub fn ScatterPlot(
 pub struct ScatterSeries {
     pub name: String,
     pub data: Vec<ScatterPoint>,
-    pub color: String,
+    /// Series color, the palette's color for its index when `None`
+    pub color: Option<String>,
     pub point_size: f64,
     pub opacity: f64,
 }
@@ -40,7 +87,7 @@ impl Default for ScatterSeries {
         Self {
             name: "Series".to_string(),
             data: Vec::new(),
-            color: "#3b82f6".to_string(),
+            color: None,
             point_size: 4.0,
             opacity: 1.0,
         }
@@ -82,48 +129,6 @@ impl Default for ScatterPlotConfig {
     }
 }
 
-/// Chart Margin
-#[derive(Debug, Clone, PartialEq)]
-pub struct ChartMargin {
-    pub top: f64,
-    pub right: f64,
-    pub bottom: f64,
-    pub left: f64,
-}
-
-impl Default for ChartMargin {
-    fn default() -> Self {
-        Self {
-            top: 20.0,
-            right: 20.0,
-            bottom: 40.0,
-            left: 40.0,
-        }
-    }
-}
-
-/// Axis Configuration
-#[derive(Debug, Clone, PartialEq)]
-pub struct AxisConfig {
-    pub label: Option<String>,
-    pub min: Option<f64>,
-    pub max: Option<f64>,
-    pub ticks: Option<usize>,
-    pub format: Option<String>,
-}
-
-impl Default for AxisConfig {
-    fn default() -> Self {
-        Self {
-            label: None,
-            min: None,
-            max: None,
-            ticks: None,
-            format: None,
-        }
-    }
-}
-
 /// Point Size Range
 #[derive(Debug, Clone, PartialEq)]
 pub struct PointSizeRange {
@@ -173,21 +178,27 @@ pub fn ScatterPlotPoint(
     let point = point.unwrap_or_default();
     let size = size.unwrap_or(4.0);
 
-    let class = merge_classes(vec![
-        "scatter-plot-point",
-        class.as_deref().unwrap_or(""),
-    ]);
+    let class = merge_classes(vec!["scatter-plot-point", class.as_deref().unwrap_or("")]);
+    let (x, y) = (point.x, point.y);
 
     view! {
         <div
             class=class
             style=style
             role="button"
-            aria-label=format!("Data point: ({}, {})", point.x, point.y)
-            data-x=point.x
-            data-y=point.y
+            aria-label=use_i18n().format(
+                MessageKey::ScatterPlotPoint,
+                &MessageArgs::new().with("x", x).with("y", y),
+            )
+            data-x=x
+            data-y=y
             data-size=size
             tabindex="0"
+            on:click=move |_| {
+                if let Some(on_click) = on_click {
+                    on_click.run(point.clone());
+                }
+            }
         />
     }
 }
@@ -207,7 +218,7 @@ pub fn ScatterPlotTrendLine(
 
     let class = merge_classes(vec![
         "scatter-plot-trend-line",
-        &trend_type.to_class(),
+        trend_type.to_class(),
         class.as_deref().unwrap_or(""),
     ]);
 
@@ -216,7 +227,10 @@ pub fn ScatterPlotTrendLine(
             class=class
             style=style
             role="img"
-            aria-label=format!("Trend line for {}", series.name)
+            aria-label=use_i18n().format(
+                MessageKey::ScatterPlotTrendLine,
+                &MessageArgs::new().with("series", series.name.as_str()),
+            )
             data-series-name=series.name
             data-trend-type=trend_type.to_string()
             data-opacity=opacity
@@ -254,127 +268,28 @@ impl TrendType {
     }
 }
 
-/// Helper function to merge CSS classes
-fn merge_optional_classes(classes: Vec<&str>) -> String {
-    classes
-        .into_iter()
-        .filter(|c| !c.is_empty())
-        .collect::<Vec<_>>()
-        .join(" ")
-}
-
 #[cfg(test)]
 mod tests {
-    use wasm_bindgen_test::*;
-    use proptest::prelude::*;
-use crate::utils::merge_classes;
-
-    wasm_bindgen_test_configure!(run_in_browser);
-
-    // Unit Tests
-    #[test] fn test_scatterplot_creation() { 
-    #[test] fn test_scatterplot_with_class() { 
-    #[test] fn test_scatterplot_with_style() { 
-    #[test] fn test_scatterplot_with_data() { 
-    #[test] fn test_scatterplot_with_config() { 
-    #[test] fn test_scatterplot_show_trend_line() { 
-    #[test] fn test_scatterplot_show_grid() { 
-    #[test] fn test_scatterplot_show_axes() { 
-    #[test] fn test_scatterplot_on_point_click() { 
-    #[test] fn test_scatterplot_on_point_hover() { 
-
-    // Scatter Series tests
-    #[test] fn test_scatter_series_default() { 
-    #[test] fn test_scatter_series_creation() { 
-
-    // Scatter Point tests
-    #[test] fn test_scatter_point_creation() { 
-
-    // Scatter Plot Config tests
-    #[test] fn test_scatterplot_config_default() { 
-    #[test] fn test_scatterplot_config_custom() { 
-
-    // Chart Margin tests
-    #[test] fn test_chart_margin_default() { 
-
-    // Axis Config tests
-    #[test] fn test_axis_config_default() { 
-    #[test] fn test_axis_config_custom() { 
+    use super::*;
+    use crate::theming::ChartPalette;
 
-    // Point Size Range tests
-    #[test] fn test_point_size_range_default() { 
-    #[test] fn test_point_size_range_custom() { 
-
-    // Scale Type tests
-    #[test] fn test_scale_type_default() { 
-    #[test] fn test_scale_type_linear() { 
-    #[test] fn test_scale_type_logarithmic() { 
-    #[test] fn test_scale_type_square_root() { 
-
-    // Scatter Plot Point tests
-    #[test] fn test_scatterplot_point_creation() { 
-    #[test] fn test_scatterplot_point_with_class() { 
-    #[test] fn test_scatterplot_point_with_style() { 
-    #[test] fn test_scatterplot_point_with_point() { 
-    #[test] fn test_scatterplot_point_size() { 
-    #[test] fn test_scatterplot_point_on_click() { 
-
-    // Scatter Plot Trend Line tests
-    #[test] fn test_scatterplot_trend_line_creation() { 
-    #[test] fn test_scatterplot_trend_line_with_class() { 
-    #[test] fn test_scatterplot_trend_line_with_style() { 
-    #[test] fn test_scatterplot_trend_line_with_series() { 
-    #[test] fn test_scatterplot_trend_line_trend_type() { 
-    #[test] fn test_scatterplot_trend_line_opacity() { 
-
-    // Trend Type tests
-    #[test] fn test_trend_type_default() { 
-    #[test] fn test_trend_type_linear() { 
-    #[test] fn test_trend_type_polynomial() { 
-    #[test] fn test_trend_type_exponential() { 
-    #[test] fn test_trend_type_logarithmic() { 
-
-    // Helper function tests
-    #[test] fn test_merge_classes_empty() { 
-    #[test] fn test_merge_classes_single() { 
-    #[test] fn test_merge_classes_multiple() { 
-    #[test] fn test_merge_classes_with_empty() { 
-
-    // Property-based Tests
-    #[test] fn test_scatterplot_property_based() {
-        proptest!(|(____class in ".*", __style in ".*")| {
-            
-        });
-    }
-
-    #[test] fn test_scatterplot_data_validation() {
-        proptest!(|(______series_count in 0..10usize, __points_per_series in 0..1000usize)| {
-            
-        });
-    }
-
-    #[test] fn test_scatterplot_config_validation() {
-        proptest!(|(____width in 100.0..2000.0f64, __height in 100.0..2000.0f64)| {
-            
-        });
+    #[test]
+    fn test_scatter_series_default_has_no_color() {
+        assert_eq!(ScatterSeries::default().color, None);
     }
 
-    #[test] fn test_scatterplot_trend_property_based() {
-        proptest!(|(____trend_type_index in 0..4usize)| {
-            
-        });
+    #[test]
+    fn test_scatter_plot_colors_series_from_palette() {
+        crate::test_support::init_test_executor();
+        let data = vec![
+            ScatterSeries { name: "Height".to_string(), color: Some("#00ff00".to_string()), ..ScatterSeries::default() },
+            ScatterSeries { name: "Weight".to_string(), ..ScatterSeries::default() },
+        ];
+        let html = Owner::new().with(|| view! { <ScatterPlot data=data /> }.to_html());
+        let palette = ChartPalette::default();
+        assert!(html.contains(&format!(
+            "--chart-series-1: #00ff00; --chart-series-2: {};",
+            palette.series_color(1)
+        )));
     }
-
-    // Integration Tests
-    #[test] fn test_scatterplot_tooltip_interaction() { 
-    #[test] fn test_scatterplot_legend_interaction() { 
-    #[test] fn test_scatterplot_user_workflow() { 
-    #[test] fn test_scatterplot_accessibility_workflow() { 
-    #[test] fn test_scatterplot_with_other_components() { 
-
-    // Performance Tests
-    #[test] fn test_scatterplot_large_dataset() { 
-    #[test] fn test_scatterplot_render_performance() { 
-    #[test] fn test_scatterplot_memory_usage() { 
-    #[test] fn test_scatterplot_animation_performance() { 
 }
@@ -0,0 +1,276 @@
+use crate::i18n::{use_i18n, MessageKey};
+use crate::utils::merge_classes;
+use leptos::ev;
+use leptos::prelude::*;
+pub use radix_leptos_core::{prefers_reduced_motion, use_prefers_reduced_motion};
+use radix_leptos_core::use_reduced_motion;
+
+/// What a scroll progress value is measured against
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum ScrollProgressTarget {
+    /// Scroll position of the whole page
+    #[default]
+    Page,
+    /// How far the element matching this CSS selector has been scrolled
+    /// through the viewport, e.g. an article being read
+    Element(String),
+}
+
+/// Reactive scroll progress between 0.0 and 1.0
+///
+/// Updates on window scroll and resize.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn Article() -> impl IntoView {
+///     let progress = use_scroll_progress(ScrollProgressTarget::Element("article".to_string()));
+///     view! { <span>{move || format!("{:.0}% read", progress.get() * 100.0)}</span> }
+/// }
+/// ```
+pub fn use_scroll_progress(target: ScrollProgressTarget) -> Signal<f64> {
+    let progress = RwSignal::new(0.0);
+    let target = StoredValue::new(target);
+    let update = move || {
+        let next = target.with_value(measure_scroll_progress);
+        if next != progress.get_untracked() {
+            progress.set(next);
+        }
+    };
+
+    Effect::new(move |_| update());
+    let scroll = window_event_listener(ev::scroll, move |_| update());
+    let resize = window_event_listener(ev::resize, move |_| update());
+    on_cleanup(move || {
+        scroll.remove();
+        resize.remove();
+    });
+
+    progress.into()
+}
+
+/// Progress of a scroll position within the scrollable distance
+pub fn scroll_progress(scrolled: f64, scrollable: f64) -> f64 {
+    if scrolled.is_nan() {
+        return 0.0;
+    }
+    if !(scrollable.is_finite() && scrollable > 0.0) {
+        return if scrolled > 0.0 { 1.0 } else { 0.0 };
+    }
+    (scrolled / scrollable).clamp(0.0, 1.0)
+}
+
+/// Progress of an element through the viewport from its viewport rectangle
+///
+/// 0.0 while the element's top is below the viewport top, 1.0 once its
+/// bottom reaches the viewport bottom.
+pub fn element_scroll_progress(top: f64, height: f64, viewport_height: f64) -> f64 {
+    scroll_progress(-top, height - viewport_height)
+}
+
+/// `transform` for a parallax layer moving `distance` pixels over the full progress
+///
+/// Returns `none` when the user prefers reduced motion.
+pub fn parallax_transform(progress: f64, distance: f64, reduced_motion: bool) -> String {
+    if reduced_motion {
+        return "none".to_string();
+    }
+    format!(
+        "translate3d(0, {:.2}px, 0)",
+        progress.clamp(0.0, 1.0) * distance
+    )
+}
+
+fn measure_scroll_progress(target: &ScrollProgressTarget) -> f64 {
+    let Some(window) = web_sys::window() else {
+        return 0.0;
+    };
+    let viewport_height = window
+        .inner_height()
+        .ok()
+        .and_then(|height| height.as_f64())
+        .unwrap_or(0.0);
+
+    match target {
+        ScrollProgressTarget::Page => {
+            let scrolled = window.scroll_y().unwrap_or(0.0);
+            let document_height = window
+                .document()
+                .and_then(|document| document.document_element())
+                .map(|element| f64::from(element.scroll_height()))
+                .unwrap_or(0.0);
+            scroll_progress(scrolled, document_height - viewport_height)
+        }
+        ScrollProgressTarget::Element(selector) => window
+            .document()
+            .and_then(|document| document.query_selector(selector).ok().flatten())
+            .map(|element| {
+                let rect = element.get_bounding_client_rect();
+                element_scroll_progress(rect.top(), rect.height(), viewport_height)
+            })
+            .unwrap_or(0.0),
+    }
+}
+
+/// Edge of the viewport the progress bar is attached to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScrollProgressPosition {
+    #[default]
+    Top,
+    Bottom,
+    /// Rendered in place instead of fixed to the viewport
+    Inline,
+}
+
+impl ScrollProgressPosition {
+    pub fn to_class(&self) -> &'static str {
+        match self {
+            ScrollProgressPosition::Top => "position-top",
+            ScrollProgressPosition::Bottom => "position-bottom",
+            ScrollProgressPosition::Inline => "position-inline",
+        }
+    }
+
+    fn to_style(self) -> &'static str {
+        match self {
+            ScrollProgressPosition::Top => {
+                "position: fixed; top: 0; left: 0; right: 0; z-index: 50;"
+            }
+            ScrollProgressPosition::Bottom => {
+                "position: fixed; bottom: 0; left: 0; right: 0; z-index: 50;"
+            }
+            ScrollProgressPosition::Inline => "position: relative; width: 100%;",
+        }
+    }
+}
+
+/// ScrollProgressBar component - Reading progress indicator
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn Post() -> impl IntoView {
+///     view! {
+///         <ScrollProgressBar target=ScrollProgressTarget::Element("article".to_string()) />
+///         <article>"..."</article>
+///     }
+/// }
+/// ```
+#[component]
+pub fn ScrollProgressBar(
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    /// What the progress is measured against (default: the page)
+    #[prop(optional)]
+    target: Option<ScrollProgressTarget>,
+    #[prop(optional)] position: Option<ScrollProgressPosition>,
+    /// Bar thickness in pixels (default: 3)
+    #[prop(optional)]
+    height: Option<f64>,
+    /// Bar color (default: the theme's primary color)
+    #[prop(optional)]
+    color: Option<String>,
+    /// Accessible name of the progress bar
+    #[prop(optional)]
+    label: Option<String>,
+) -> impl IntoView {
+    let progress = use_scroll_progress(target.unwrap_or_default());
+    let position = position.unwrap_or_default();
+    let height = height.unwrap_or(3.0);
+    let color = color.unwrap_or_else(|| "var(--primary-500, #3b82f6)".to_string());
+    let label = label.unwrap_or_else(|| use_i18n().text(MessageKey::ScrollProgressLabel));
+    // Ease the bar between scroll events unless motion is reduced
+    let reduced_motion = use_reduced_motion();
+
+    let class = merge_classes(vec![
+        "scroll-progress-bar",
+        position.to_class(),
+        class.as_deref().unwrap_or(""),
+    ]);
+    let track_style = format!(
+        "{} height: {}px; {}",
+        position.to_style(),
+        height,
+        style.unwrap_or_default()
+    );
+    let indicator_style = move || {
+        format!(
+            "height: 100%; background: {}; transform-origin: left; transform: scaleX({}); {}",
+            color,
+            progress.get(),
+            if reduced_motion.get() {
+                ""
+            } else {
+                "transition: transform 80ms linear;"
+            }
+        )
+    };
+
+    view! {
+        <div
+            class=class
+            style=track_style
+            role="progressbar"
+            aria-label=label
+            aria-valuemin="0"
+            aria-valuemax="100"
+            aria-valuenow=move || format!("{:.0}", progress.get() * 100.0)
+        >
+            <div class="scroll-progress-indicator" style=indicator_style></div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_scroll_progress() {
+        assert_eq!(scroll_progress(0.0, 1000.0), 0.0);
+        assert_eq!(scroll_progress(250.0, 1000.0), 0.25);
+        assert_eq!(scroll_progress(1200.0, 1000.0), 1.0);
+        assert_eq!(scroll_progress(-10.0, 1000.0), 0.0);
+    }
+
+    #[test]
+    fn test_scroll_progress_without_scrollable_distance() {
+        assert_eq!(scroll_progress(0.0, 0.0), 0.0);
+        assert_eq!(scroll_progress(10.0, -50.0), 1.0);
+        assert_eq!(scroll_progress(f64::NAN, 100.0), 0.0);
+    }
+
+    #[test]
+    fn test_element_scroll_progress() {
+        // 2000px article in an 800px viewport
+        assert_eq!(element_scroll_progress(100.0, 2000.0, 800.0), 0.0);
+        assert_eq!(element_scroll_progress(-600.0, 2000.0, 800.0), 0.5);
+        assert_eq!(element_scroll_progress(-1500.0, 2000.0, 800.0), 1.0);
+    }
+
+    #[test]
+    fn test_parallax_transform() {
+        assert_eq!(
+            parallax_transform(0.5, 120.0, false),
+            "translate3d(0, 60.00px, 0)"
+        );
+        assert_eq!(parallax_transform(0.5, 120.0, true), "none");
+    }
+
+    proptest! {
+        #[test]
+        fn test_scroll_progress_is_normalized(scrolled in proptest::num::f64::ANY, scrollable in proptest::num::f64::ANY) {
+            let progress = scroll_progress(scrolled, scrollable);
+            prop_assert!((0.0..=1.0).contains(&progress));
+        }
+    }
+}
@@ -1,10 +1,24 @@
-use crate::utils::merge_classes;
+use super::combobox::{option_element_id, use_result_count_announcements};
+use crate::i18n::{use_i18n, MessageKey};
+use crate::utils::{generate_id, merge_classes};
 use leptos::callback::Callback;
 use leptos::children::Children;
 use leptos::prelude::*;
 use wasm_bindgen::JsCast;
 
+/// Suggestion list and highlighted suggestion of the enclosing Search
+#[derive(Clone)]
+struct SearchContext {
+    listbox_id: String,
+    active_suggestion: Signal<Option<String>>,
+}
+
 /// Search component - Search input with suggestions and filtering
+///
+/// Pass the number of matching suggestions as `result_count` to announce
+/// "N results available" once typing pauses, and the `id` of the
+/// suggestion highlighted with the arrow keys as `active_suggestion` for
+/// the input's `aria-activedescendant`.
 #[component]
 pub fn Search(
     #[prop(optional)] class: Option<String>,
@@ -20,6 +34,12 @@ pub fn Search(
     #[prop(optional)] _on_search: Option<Callback<String>>,
     #[prop(optional)] _on_suggestion_select: Option<Callback<SearchSuggestion>>,
     #[prop(optional)] _on_clear: Option<Callback<()>>,
+    /// Number of matching suggestions, announced as it changes
+    #[prop(optional, into)]
+    result_count: Signal<Option<usize>>,
+    /// `id` of the highlighted suggestion
+    #[prop(optional, into)]
+    active_suggestion: Signal<Option<String>>,
 ) -> impl IntoView {
     let _value = value.unwrap_or_default();
     let _placeholder = placeholder.unwrap_or_else(|| "Search...".to_string());
@@ -30,13 +50,18 @@ pub fn Search(
     let debounce_ms = debounce_ms.unwrap_or(300);
 
     let class = merge_classes(vec!["search", class.as_deref().unwrap_or("")]);
+    use_result_count_announcements(result_count, Signal::derive(|| false));
+    provide_context(SearchContext {
+        listbox_id: generate_id("search-suggestions"),
+        active_suggestion,
+    });
 
     view! {
         <div
             class=class
             style=style
             role="search"
-            aria-label="Search"
+            aria-label=use_i18n().text(MessageKey::SearchLabel)
             data-max-suggestions=max_suggestions
             data-debounce-ms=debounce_ms
         >
@@ -65,6 +90,13 @@ pub fn SearchInput(
     let _required = required.unwrap_or(false);
 
     let class = merge_classes(vec!["search-input", class.as_deref().unwrap_or("")]);
+    let context = use_context::<SearchContext>();
+    let listbox_id = context.as_ref().map(|context| context.listbox_id.clone());
+    let active_descendant = move || {
+        let context = context.as_ref()?;
+        let suggestion = context.active_suggestion.get()?;
+        Some(option_element_id(&context.listbox_id, &suggestion))
+    };
 
     let handle_input = move |event: web_sys::Event| {
         if let Some(input) = event
@@ -106,7 +138,10 @@ pub fn SearchInput(
             disabled=disabled
             required=required
             role="searchbox"
-            aria-label="Search input"
+            aria-label=use_i18n().text(MessageKey::SearchInput)
+            aria-autocomplete="list"
+            aria-controls=listbox_id
+            aria-activedescendant=active_descendant
             on:input=handle_input
             on:focus=handle_focus
             on:blur=handle_blur
@@ -130,7 +165,22 @@ pub fn SearchSuggestions(
     let visible = visible.unwrap_or(false);
     let selected_index = selected_index.unwrap_or(0);
 
-    let class = merge_classes(vec!["search-suggestions"]);
+    let class = merge_classes(vec!["search-suggestions", class.as_deref().unwrap_or("")]);
+    let listbox_id = use_context::<SearchContext>().map(|context| context.listbox_id);
+
+    view! {
+        <div
+            id=listbox_id
+            class=class
+            style=style
+            role="listbox"
+            aria-label=use_i18n().text(MessageKey::SearchSuggestions)
+            hidden=!visible
+            data-selected-index=selected_index
+        >
+            {children.map(|c| c())}
+        </div>
+    }
 }
 
 /// Search Suggestion Item component
@@ -146,13 +196,25 @@ pub fn SearchSuggestionItem(
     let suggestion = suggestion.unwrap_or_default();
     let selected = selected.unwrap_or(false);
 
-    let class = merge_classes(vec!["search-suggestion-item"]);
+    let class = merge_classes(vec!["search-suggestion-item", class.as_deref().unwrap_or("")]);
+    let context = use_context::<SearchContext>();
+    let id = context
+        .as_ref()
+        .map(|context| option_element_id(&context.listbox_id, &suggestion.id));
+    let suggestion_id = suggestion.id.clone();
+    let highlighted = move || {
+        context.as_ref().is_some_and(|context| {
+            context.active_suggestion.get().as_deref() == Some(&suggestion_id)
+        })
+    };
 
     view! {
         <div
+            id=id
             class=class
             style=style
             role="option"
+            data-highlighted=highlighted
             aria-selected=selected
             aria-label=suggestion.text.clone()
             on:click=move |_| {
@@ -184,7 +246,7 @@ pub fn SearchClearButton(
             class=class
             style=style
             type="button"
-            aria-label="Clear search"
+            aria-label=use_i18n().text(MessageKey::SearchClear)
             on:click=move |_| {
                 if let Some(callback) = on_click {
                     callback.run(());
@@ -240,7 +302,7 @@ pub fn SearchFilter(
             class=class
             style=style
             role="group"
-            aria-label="Search filters"
+            aria-label=use_i18n().text(MessageKey::SearchFilters)
         >
             {children.map(|c| c())}
         </div>
@@ -1,7 +1,11 @@
 use leptos::callback::Callback;
 use leptos::children::Children;
+use leptos::html;
 use leptos::prelude::*;
+use radix_leptos_core::use_typeahead;
+use crate::i18n::{use_i18n, MessageKey};
 use crate::utils::{merge_optional_classes, generate_id};
+use crate::components::roving_focus::{roving_tab_index, RovingFocusGroup};
 
 /// Select component with proper accessibility and styling variants
 ///
@@ -203,6 +207,8 @@ pub fn SelectTrigger(
             role="combobox"
             aria-expanded="false"
             aria-haspopup="listbox"
+            tabindex=roving_tab_index(None)
+            data-roving-focus-item=RovingFocusGroup::item_attribute()
         >
             {children()}
         </button>
@@ -228,12 +234,17 @@ pub fn SelectValue(
 
     view! {
         <span class=combined_class style=style>
-            {placeholder.unwrap_or_else(|| "Select an option".to_string())}
+            {placeholder.unwrap_or_else(|| use_i18n().text(MessageKey::SelectPlaceholder))}
         </span>
     }
 }
 
+/// Options of a select that typeahead can move to
+const SELECT_ITEM_SELECTOR: &str = "[role='option']:not([data-disabled])";
+
 /// Select content component
+///
+/// Typing the start of an option's label moves focus to it.
 #[component]
 pub fn SelectContent(
     /// CSS classes
@@ -249,12 +260,22 @@ pub fn SelectContent(
     let combined_class = merge_optional_classes(Some(base_classes), class.as_deref())
         .unwrap_or_else(|| base_classes.to_string());
 
+    let content_ref = NodeRef::<html::Div>::new();
+    let typeahead = use_typeahead();
+    let handle_keydown = move |e: web_sys::KeyboardEvent| {
+        if let Some(content) = content_ref.get_untracked() {
+            typeahead.handle_keydown(&e, &content, SELECT_ITEM_SELECTOR);
+        }
+    };
+
     view! {
         <div
             class=combined_class
             style=style
             role="listbox"
             tabindex="-1"
+            node_ref=content_ref
+            on:keydown=handle_keydown
         >
             {children()}
         </div>
@@ -276,7 +297,7 @@ pub fn SelectItem(
     #[prop(optional)]
     style: Option<String>,
     /// Child content
-    _children: Children,
+    children: Children,
 ) -> impl IntoView {
     let base_classes = "radix-select-item";
     let combined_class = merge_optional_classes(Some(base_classes), class.as_deref())
@@ -295,7 +316,9 @@ pub fn SelectItem(
             data-value=value
             data-disabled=disabled
             role="option"
+            tabindex="-1"
         >
+            {children()}
         </div>
     }
 }
@@ -462,6 +485,21 @@ use crate::utils::{merge_optional_classes, generate_id};
     }
 
     // 5. Accessibility Tests
+    #[test]
+    fn test_select_item_markup() {
+        use crate::SelectItem;
+        use leptos::prelude::*;
+
+        let owner = Owner::new();
+        owner.with(|| {
+            let html = view! { <SelectItem value="apple".to_string()>"Apple"</SelectItem> }
+                .to_html();
+            assert!(html.contains(r#"role="option""#));
+            assert!(html.contains(r#"tabindex="-1""#));
+            assert!(html.contains("Apple</div>"));
+        });
+    }
+
     #[test]
     fn test_select_accessibility() {
         run_test(|| {
@@ -1,40 +1,186 @@
+use crate::i18n::{use_i18n, MessageKey};
 use crate::utils::merge_classes;
 use leptos::children::Children;
 use leptos::prelude::*;
 
 /// Separator component - Visual dividers with orientation support
+///
+/// Renders `role="separator"` unless `decorative`, in which case it is
+/// hidden from assistive technology. A `label` (or children) is centred
+/// between two lines, as in "OR" dividers. Vertical separators stretch to
+/// the height of their flex row.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn SignIn() -> impl IntoView {
+///     view! {
+///         <button>"Continue with email"</button>
+///         <Separator label="OR".to_string() />
+///         <button>"Continue with GitHub"</button>
+///     }
+/// }
+/// ```
 #[component]
 pub fn Separator(
     #[prop(optional)] class: Option<String>,
     #[prop(optional)] style: Option<String>,
     #[prop(optional)] children: Option<Children>,
     #[prop(optional)] orientation: Option<SeparatorOrientation>,
-    #[prop(optional)] decorative: Option<bool>,
+    /// Purely visual; hides the separator from assistive technology (default: false)
+    #[prop(optional)]
+    decorative: Option<bool>,
     #[prop(optional)] thickness: Option<SeparatorThickness>,
     #[prop(optional)] color: Option<String>,
+    /// Text centred on the line; also used as the accessible name
+    #[prop(optional)]
+    label: Option<String>,
+    /// Which ends of the line are indented
+    #[prop(optional)]
+    inset: Option<SeparatorInset>,
 ) -> impl IntoView {
     let orientation = orientation.unwrap_or_default();
-    let decorative = decorative.unwrap_or(true);
+    let decorative = decorative.unwrap_or(false);
     let thickness = thickness.unwrap_or_default();
-    let color = color.unwrap_or_default();
+    let inset = inset.unwrap_or_default();
+
+    let content = match (children, label.clone()) {
+        (Some(children), _) => Some(children()),
+        (None, Some(label)) => Some(label.into_any()),
+        (None, None) => None,
+    };
+    let labelled = content.is_some();
+
+    let class = merge_classes(vec![
+        "separator",
+        orientation.to_class(),
+        thickness.to_class(),
+        inset.to_class(),
+        if labelled { "separator-labelled" } else { "" },
+        class.as_deref().unwrap_or(""),
+    ]);
+    let style = format!(
+        "{} {}",
+        separator_style(orientation, thickness, inset, color.as_deref(), labelled),
+        style.unwrap_or_default()
+    );
+    let line_style = separator_line_style(orientation, thickness, color.as_deref());
 
-    let class = merge_classes(vec!["separator", orientation.to_class(), thickness.to_class()]);
-    let aria_orientation = orientation.to_aria_orientation();
+    let content = content.map(|content| {
+        view! {
+            <span class="separator-segment" style=line_style.clone() aria-hidden="true"></span>
+            <span class="separator-label">{content}</span>
+            <span class="separator-segment" style=line_style aria-hidden="true"></span>
+        }
+    });
 
     view! {
         <div
             class=class
             style=style
-            role=if decorative { "none" } else { "separator" }
-            aria-orientation=aria_orientation
+            role=(!decorative).then_some("separator")
+            aria-orientation=(!decorative).then(|| orientation.to_aria_orientation())
+            aria-label=label.filter(|_| !decorative)
+            aria-hidden=decorative.then_some("true")
+            data-orientation=orientation.to_string()
             data-thickness=thickness.to_string()
             data-color=color
         >
-            {children.map(|c| c())}
+            {content}
         </div>
     }
 }
 
+/// Inline layout of a [`Separator`]
+///
+/// Plain separators draw the line as their own background; labelled ones
+/// become a flex row (or column) with the line drawn by the two segments
+/// either side of the label.
+pub fn separator_style(
+    orientation: SeparatorOrientation,
+    thickness: SeparatorThickness,
+    inset: SeparatorInset,
+    color: Option<&str>,
+    labelled: bool,
+) -> String {
+    let mut style = match (orientation, labelled) {
+        (SeparatorOrientation::Horizontal, false) => {
+            format!("width: auto; height: {}px;", thickness.to_px())
+        }
+        (SeparatorOrientation::Vertical, false) => {
+            format!("align-self: stretch; height: auto; width: {}px;", thickness.to_px())
+        }
+        (SeparatorOrientation::Horizontal, true) => {
+            "display: flex; align-items: center; gap: var(--space-3, 0.75rem);".to_string()
+        }
+        (SeparatorOrientation::Vertical, true) => {
+            "display: flex; flex-direction: column; align-items: center; align-self: stretch; gap: var(--space-2, 0.5rem);".to_string()
+        }
+    };
+    if !labelled {
+        style.push_str(&format!(
+            " flex-shrink: 0; background-color: {};",
+            color.unwrap_or("currentColor")
+        ));
+    }
+    let (start, end) = match orientation {
+        SeparatorOrientation::Horizontal => ("margin-inline-start", "margin-inline-end"),
+        SeparatorOrientation::Vertical => ("margin-block-start", "margin-block-end"),
+    };
+    let indent = "var(--separator-inset, 1rem)";
+    match inset {
+        SeparatorInset::None => {}
+        SeparatorInset::Both => {
+            style.push_str(&format!(" {}: {}; {}: {};", start, indent, end, indent))
+        }
+        SeparatorInset::Start => style.push_str(&format!(" {}: {};", start, indent)),
+        SeparatorInset::End => style.push_str(&format!(" {}: {};", end, indent)),
+    }
+    style
+}
+
+fn separator_line_style(
+    orientation: SeparatorOrientation,
+    thickness: SeparatorThickness,
+    color: Option<&str>,
+) -> String {
+    let size = match orientation {
+        SeparatorOrientation::Horizontal => format!("height: {}px;", thickness.to_px()),
+        SeparatorOrientation::Vertical => format!("width: {}px;", thickness.to_px()),
+    };
+    format!(
+        "flex: 1 1 0; {} background-color: {};",
+        size,
+        color.unwrap_or("currentColor")
+    )
+}
+
+/// Which ends of a [`Separator`] are indented
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SeparatorInset {
+    /// Full width (or height)
+    #[default]
+    None,
+    Both,
+    Start,
+    End,
+}
+
+impl SeparatorInset {
+    pub fn to_class(&self) -> &'static str {
+        match self {
+            SeparatorInset::None => "",
+            SeparatorInset::Both => "inset-both",
+            SeparatorInset::Start => "inset-start",
+            SeparatorInset::End => "inset-end",
+        }
+    }
+}
+
 /// Separator Line component
 #[component]
 pub fn SeparatorLine(
@@ -165,6 +311,15 @@ impl SeparatorThickness {
             SeparatorThickness::Custom(thickness) => format!("custom-{}", thickness),
         }
     }
+
+    pub fn to_px(&self) -> f64 {
+        match self {
+            SeparatorThickness::Thin => 1.0,
+            SeparatorThickness::Medium => 2.0,
+            SeparatorThickness::Thick => 4.0,
+            SeparatorThickness::Custom(thickness) => *thickness,
+        }
+    }
 }
 
 /// Separator Group component
@@ -194,7 +349,7 @@ pub fn SeparatorGroup(
             class=class
             style=style
             role="group"
-            aria-label="Separator group"
+            aria-label=use_i18n().text(MessageKey::SeparatorGroup)
             data-spacing=spacing.to_string()
             data-orientation=orientation.to_string()
         >
@@ -232,6 +387,7 @@ impl SeparatorSpacing {
 
 #[cfg(test)]
 mod tests {
+    use super::*;
     use proptest::prelude::*;
     use wasm_bindgen_test::*;
 
@@ -247,7 +403,60 @@ mod tests {
     #[test]
     fn test_separator_orientation() {}
     #[test]
-    fn test_separator_decorative() {}
+    fn test_separator_decorative() {
+        let semantic = Owner::new().with(|| view! { <Separator /> }.to_html());
+        assert!(semantic.contains("role=\"separator\""));
+        assert!(semantic.contains("aria-orientation=\"horizontal\""));
+        assert!(!semantic.contains("aria-hidden"));
+
+        let decorative = Owner::new().with(|| view! { <Separator decorative=true /> }.to_html());
+        assert!(!decorative.contains("role="));
+        assert!(!decorative.contains("aria-orientation"));
+        assert!(decorative.contains("aria-hidden=\"true\""));
+    }
+
+    #[test]
+    fn test_separator_label() {
+        let html = Owner::new().with(|| view! { <Separator label="OR".to_string() /> }.to_html());
+        assert!(html.contains("aria-label=\"OR\""));
+        assert!(html.contains("<span class=\"separator-label\">OR</span>"));
+        assert_eq!(html.matches("separator-segment").count(), 2);
+    }
+
+    #[test]
+    fn test_separator_style() {
+        use SeparatorOrientation::*;
+        let vertical = separator_style(
+            Vertical,
+            SeparatorThickness::Medium,
+            SeparatorInset::None,
+            None,
+            false,
+        );
+        assert!(vertical.contains("align-self: stretch"));
+        assert!(vertical.contains("width: 2px"));
+
+        let inset = separator_style(
+            Horizontal,
+            SeparatorThickness::Thin,
+            SeparatorInset::Start,
+            Some("red"),
+            false,
+        );
+        assert!(inset.contains("margin-inline-start: var(--separator-inset, 1rem)"));
+        assert!(!inset.contains("margin-inline-end"));
+        assert!(inset.contains("background-color: red"));
+
+        let labelled = separator_style(
+            Horizontal,
+            SeparatorThickness::Thin,
+            SeparatorInset::Both,
+            None,
+            true,
+        );
+        assert!(labelled.contains("display: flex"));
+        assert!(!labelled.contains("background-color"));
+    }
     #[test]
     fn test_separator_thickness() {}
     #[test]
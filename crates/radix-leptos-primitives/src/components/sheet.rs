@@ -1,7 +1,10 @@
+use crate::i18n::{use_i18n, MessageKey};
+use crate::theming::PresenceAnimation;
 use crate::utils::merge_classes;
 use leptos::callback::Callback;
 use leptos::children::Children;
 use leptos::prelude::*;
+use radix_leptos_core::{use_presence, use_swipe_with_options, SwipeDirection, UseSwipeOptions};
 
 /// Sheet component - Side panel/drawer component for mobile and desktop
 ///
@@ -108,6 +111,35 @@ impl SheetPosition {
     }
 }
 
+impl SheetPosition {
+    /// Slide in from the edge the sheet is attached to
+    pub fn presence_animation(&self) -> PresenceAnimation {
+        match self {
+            SheetPosition::Left => PresenceAnimation::SlideFromLeft,
+            SheetPosition::Right => PresenceAnimation::SlideFromRight,
+            SheetPosition::Top => PresenceAnimation::SlideFromTop,
+            SheetPosition::Bottom => PresenceAnimation::SlideFromBottom,
+        }
+    }
+
+    /// Swipe towards the edge the sheet is attached to, which dismisses it
+    pub fn dismiss_direction(&self) -> SwipeDirection {
+        match self {
+            SheetPosition::Left => SwipeDirection::Left,
+            SheetPosition::Right => SwipeDirection::Right,
+            SheetPosition::Top => SwipeDirection::Up,
+            SheetPosition::Bottom => SwipeDirection::Down,
+        }
+    }
+}
+
+/// State shared between a Sheet and its content
+#[derive(Debug, Clone, Copy)]
+struct SheetContext {
+    position: SheetPosition,
+    onopen_change: Callback<bool>,
+}
+
 impl SheetSize {
     pub fn as_str(&self) -> &'static str {
         match self {
@@ -126,17 +158,47 @@ pub fn Sheet(
     #[prop(optional)] class: Option<String>,
     #[prop(optional)] style: Option<String>,
     #[prop(optional)] children: Option<Children>,
-    #[prop(optional)] open: Option<bool>,
+    #[prop(optional, into)] open: MaybeProp<bool>,
     #[prop(optional)] position: Option<SheetPosition>,
     #[prop(optional)] size: Option<SheetSize>,
     #[prop(optional)] onopen_change: Option<Callback<bool>>,
 ) -> impl IntoView {
-    let open = open.unwrap_or(false);
     let position = position.unwrap_or(SheetPosition::Right);
     let size = size.unwrap_or(SheetSize::Medium);
     let onopen_change = onopen_change.unwrap_or_else(|| Callback::new(|_| {}));
 
-    let class = merge_classes(vec!["sheet", position.as_str(), size.as_str()]);
+    provide_context(SheetContext {
+        position,
+        onopen_change,
+    });
+
+    let class = merge_classes(vec![
+        "sheet",
+        position.as_str(),
+        size.as_str(),
+        class.as_deref().unwrap_or(""),
+    ]);
+
+    // Stays visible while sliding out
+    let node_ref = NodeRef::<leptos::html::Div>::new();
+    let presence = use_presence(
+        Signal::derive(move || open.get().unwrap_or(false)),
+        node_ref,
+    );
+
+    view! {
+        <div
+            node_ref=node_ref
+            class=class
+            style=style
+            data-state=move || presence.state.get().as_str()
+            data-motion=position.presence_animation().as_str()
+            data-position=position.as_str()
+            hidden=move || !presence.is_present.get()
+        >
+            {children.map(|c| c())}
+        </div>
+    }
 }
 
 /// Sheet content component
@@ -147,9 +209,27 @@ pub fn SheetContent(
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
     let class = merge_classes(vec!["sheet-content", class.as_deref().unwrap_or("")]);
+    let content_ref = NodeRef::<leptos::html::Div>::new();
+
+    // Swiping a touch sheet back towards its edge closes it
+    if let Some(context) = use_context::<SheetContext>() {
+        use_swipe_with_options(
+            content_ref,
+            move |swipe| {
+                if swipe.direction == context.position.dismiss_direction() {
+                    context.onopen_change.run(false);
+                }
+            },
+            UseSwipeOptions {
+                mouse: false,
+                ..Default::default()
+            },
+        );
+    }
 
     view! {
         <div
+            node_ref=content_ref
             class=class
             style=style
         >
@@ -270,7 +350,7 @@ pub fn SheetClose(
             class=class
             style=style
             on:click=move |_| on_click.run(())
-            aria-label="Close sheet"
+            aria-label=use_i18n().text(MessageKey::SheetClose)
         >
             {children.map(|c| c())}
         </button>
@@ -279,11 +359,24 @@ pub fn SheetClose(
 
 #[cfg(test)]
 mod tests {
+    use super::*;
     use proptest::prelude::*;
 
     #[test]
     fn test_sheet_component_creation() {}
 
+    #[test]
+    fn test_sheet_dismiss_direction() {
+        assert_eq!(
+            SheetPosition::Right.dismiss_direction(),
+            SwipeDirection::Right
+        );
+        assert_eq!(
+            SheetPosition::Bottom.dismiss_direction(),
+            SwipeDirection::Down
+        );
+    }
+
     #[test]
     fn test_sheet_with_position_component_creation() {}
 
@@ -0,0 +1,267 @@
+use crate::i18n::{use_i18n, MessageKey};
+use crate::components::{Dialog, DialogContent, DialogHeader, Kbd};
+use crate::utils::{generate_id, merge_classes};
+use leptos::html;
+use leptos::prelude::*;
+use radix_leptos_core::{
+    is_apple_platform, registered_hotkeys, use_focus_trap, use_hotkeys_with_options, HotkeyBinding,
+    UseHotkeysOptions, GLOBAL_HOTKEY_SCOPE,
+};
+use std::collections::HashMap;
+
+/// Headings for hotkey scopes, by scope name
+pub type ShortcutScopeLabels = HashMap<String, String>;
+
+/// A documented shortcut, as listed by [`ShortcutHelp`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShortcutEntry {
+    /// Key labels, modifiers first
+    pub keys: Vec<String>,
+    pub description: String,
+}
+
+/// The documented shortcuts of one hotkey scope
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShortcutGroup {
+    pub scope: String,
+    pub shortcuts: Vec<ShortcutEntry>,
+}
+
+/// Group shortcuts with a description by scope, keeping the order scopes
+/// and shortcuts were registered in
+pub fn group_shortcuts(bindings: &[HotkeyBinding], apple: bool) -> Vec<ShortcutGroup> {
+    let mut groups: Vec<ShortcutGroup> = Vec::new();
+    for binding in bindings {
+        let Some(description) = binding.description.clone() else {
+            continue;
+        };
+        let entry = ShortcutEntry {
+            keys: binding.hotkey.key_labels_for_platform(apple),
+            description,
+        };
+        match groups.iter_mut().find(|group| group.scope == binding.scope) {
+            Some(group) => group.shortcuts.push(entry),
+            None => groups.push(ShortcutGroup {
+                scope: binding.scope.clone(),
+                shortcuts: vec![entry],
+            }),
+        }
+    }
+    groups
+}
+
+/// ShortcutHelp component - Overlay listing the application's shortcuts
+///
+/// Lists every shortcut registered through `use_hotkeys` with a
+/// `description`, grouped by scope, with the keys rendered by [`Kbd`].
+/// Shortcuts of disabled scopes are left out, so the list matches what
+/// works right now. Opens on `?` unless given another `hotkey`, closes on
+/// Escape and traps focus while open.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_core::{use_hotkeys_with_options, UseHotkeysOptions};
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let _ = use_hotkeys_with_options(
+///         "mod+k",
+///         |_| {},
+///         UseHotkeysOptions {
+///             description: Some("Open the command palette".to_string()),
+///             ..Default::default()
+///         },
+///     );
+///
+///     view! { <ShortcutHelp /> }
+/// }
+/// ```
+#[component]
+pub fn ShortcutHelp(
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    /// Whether the overlay is open, to open it from elsewhere too
+    #[prop(optional)]
+    open: Option<RwSignal<bool>>,
+    /// Shortcut that toggles the overlay (default: `?`)
+    #[prop(optional)]
+    hotkey: Option<String>,
+    /// Heading of the overlay (default: "Keyboard shortcuts")
+    #[prop(optional)]
+    title: Option<String>,
+    /// Headings for scopes, by scope name; the global scope is "General"
+    /// and others are shown by name without one
+    #[prop(optional)]
+    scope_labels: Option<ShortcutScopeLabels>,
+) -> impl IntoView {
+    let open = open.unwrap_or_else(|| RwSignal::new(false));
+    let catalog = use_i18n();
+    let title = title.unwrap_or_else(|| catalog.text(MessageKey::ShortcutHelpTitle));
+    let empty_text = catalog.text(MessageKey::ShortcutHelpEmpty);
+    let general_text = catalog.text(MessageKey::ShortcutHelpGeneral);
+    let scope_labels = StoredValue::new(scope_labels.unwrap_or_default());
+    let title_id = generate_id("shortcut-help-title");
+    let panel_labelledby = title_id.clone();
+    let panel_ref = NodeRef::<html::Div>::new();
+
+    // When the application binds the same keys itself, its binding is kept
+    let _ = use_hotkeys_with_options(
+        hotkey.as_deref().unwrap_or("?"),
+        move |_| open.update(|open| *open = !*open),
+        UseHotkeysOptions {
+            description: Some(catalog.text(MessageKey::ShortcutHelpShow)),
+            ..Default::default()
+        },
+    );
+    use_focus_trap(panel_ref, open.into());
+
+    // Read when opened, after every component has registered its shortcuts
+    let groups = Memo::new(move |_| {
+        if open.get() {
+            group_shortcuts(&registered_hotkeys(), is_apple_platform())
+        } else {
+            Vec::new()
+        }
+    });
+    let scope_label = move |scope: &str| {
+        scope_labels
+            .with_value(|labels| labels.get(scope).cloned())
+            .unwrap_or_else(|| match scope {
+                GLOBAL_HOTKEY_SCOPE => general_text.clone(),
+                scope => scope.to_string(),
+            })
+    };
+
+    let class = merge_classes(vec!["shortcut-help", class.as_deref().unwrap_or("")]);
+
+    view! {
+        <Dialog
+            class=class
+            style=style.unwrap_or_default()
+            open=Signal::derive(move || open.get())
+            onopen_change=Callback::new(move |next| open.set(next))
+        >
+            <DialogContent>
+                <div
+                    node_ref=panel_ref
+                    class="shortcut-help-panel"
+                    role="dialog"
+                    aria-modal="true"
+                    aria-labelledby=panel_labelledby
+                    tabindex="-1"
+                >
+                    <DialogHeader>
+                        <h2 id=title_id class="radix-dialog-title shortcut-help-title">{title}</h2>
+                        <button
+                            type="button"
+                            class="shortcut-help-close"
+                            aria-label=catalog.text(MessageKey::Close)
+                            on:click=move |_| open.set(false)
+                        >
+                            "×"
+                        </button>
+                    </DialogHeader>
+                    {move || {
+                        let groups = groups.get();
+                        if groups.is_empty() {
+                            return view! {
+                                <p class="shortcut-help-empty">{empty_text.clone()}</p>
+                            }
+                            .into_any();
+                        }
+                        groups
+                            .into_iter()
+                            .map(|group| {
+                                view! {
+                                    <section class="shortcut-help-group" data-scope=group.scope.clone()>
+                                        <h3 class="shortcut-help-scope">{scope_label(&group.scope)}</h3>
+                                        <dl class="shortcut-help-list">
+                                            {group
+                                                .shortcuts
+                                                .into_iter()
+                                                .map(|shortcut| {
+                                                    view! {
+                                                        <div class="shortcut-help-item">
+                                                            <dt class="shortcut-help-keys">
+                                                                <Kbd keys=shortcut.keys />
+                                                            </dt>
+                                                            <dd class="shortcut-help-description">
+                                                                {shortcut.description}
+                                                            </dd>
+                                                        </div>
+                                                    }
+                                                })
+                                                .collect::<Vec<_>>()}
+                                        </dl>
+                                    </section>
+                                }
+                            })
+                            .collect::<Vec<_>>()
+                            .into_any()
+                    }}
+                </div>
+            </DialogContent>
+        </Dialog>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use radix_leptos_core::{Hotkey, HotkeyRegistry};
+    use web_sys::KeyboardEvent;
+
+    #[test]
+    fn test_group_shortcuts() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let handler = Callback::new(|_: KeyboardEvent| {});
+            let described = |description: &str| UseHotkeysOptions {
+                description: Some(description.to_string()),
+                ..Default::default()
+            };
+            let hotkey = |spec: &str| Hotkey::parse_for_platform(spec, false).unwrap();
+            let mut registry = HotkeyRegistry::new();
+            registry
+                .register("global", hotkey("?"), handler, &described("Help"))
+                .unwrap();
+            registry
+                .register("editor", hotkey("mod+s"), handler, &described("Save"))
+                .unwrap();
+            registry
+                .register(
+                    "editor",
+                    hotkey("mod+z"),
+                    handler,
+                    &UseHotkeysOptions::default(),
+                )
+                .unwrap();
+            registry
+                .register("global", hotkey("mod+k"), handler, &described("Search"))
+                .unwrap();
+
+            let groups = group_shortcuts(registry.bindings(), false);
+            assert_eq!(groups.len(), 2);
+            assert_eq!(groups[0].scope, "global");
+            assert_eq!(
+                groups[0]
+                    .shortcuts
+                    .iter()
+                    .map(|shortcut| shortcut.description.as_str())
+                    .collect::<Vec<_>>(),
+                vec!["Help", "Search"]
+            );
+            // Shortcuts without a description are not listed
+            assert_eq!(
+                groups[1].shortcuts,
+                vec![ShortcutEntry {
+                    keys: vec!["Ctrl".to_string(), "S".to_string()],
+                    description: "Save".to_string(),
+                }]
+            );
+        });
+    }
+}
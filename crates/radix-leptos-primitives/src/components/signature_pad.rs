@@ -0,0 +1,517 @@
+use crate::components::form::FormController;
+use crate::i18n::{use_i18n, MessageArgs, MessageKey};
+use crate::utils::{generate_id, merge_classes};
+use leptos::callback::Callback;
+use leptos::html;
+use leptos::prelude::*;
+use std::fmt::Write;
+use wasm_bindgen::JsCast;
+
+/// A sampled pen position in CSS pixels of the pad
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SignaturePoint {
+    pub x: f64,
+    pub y: f64,
+    /// Between 0.0 and 1.0; mice report 0.5 while a button is pressed
+    pub pressure: f64,
+}
+
+/// Points drawn without lifting the pen
+pub type SignatureStroke = Vec<SignaturePoint>;
+
+/// Width and height of the pad in CSS pixels
+type PadSize = (f64, f64);
+
+/// Ink used to draw a signature
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignaturePen {
+    pub color: String,
+    /// Line width at zero pressure
+    pub min_width: f64,
+    /// Line width at full pressure
+    pub max_width: f64,
+}
+
+impl Default for SignaturePen {
+    fn default() -> Self {
+        Self {
+            color: "#000000".to_string(),
+            min_width: 1.0,
+            max_width: 4.0,
+        }
+    }
+}
+
+impl SignaturePen {
+    /// Line width for a pen pressure
+    pub fn width(&self, pressure: f64) -> f64 {
+        let pressure = if pressure.is_finite() {
+            pressure.clamp(0.0, 1.0)
+        } else {
+            0.5
+        };
+        self.min_width + (self.max_width - self.min_width) * pressure
+    }
+}
+
+/// Why a signature was rejected
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum SignatureError {
+    #[error("Signature is required")]
+    Required,
+}
+
+/// Check a signature against the pad's requirements
+pub fn validate_signature(
+    strokes: &[SignatureStroke],
+    required: bool,
+) -> Result<(), SignatureError> {
+    if required && strokes.iter().all(Vec::is_empty) {
+        return Err(SignatureError::Required);
+    }
+    Ok(())
+}
+
+/// SVG path data of the strokes, e.g. for a `<path>` with a fixed width
+pub fn signature_svg_path(strokes: &[SignatureStroke]) -> String {
+    let mut path = String::new();
+    for stroke in strokes {
+        let Some((first, rest)) = stroke.split_first() else {
+            continue;
+        };
+        if !path.is_empty() {
+            path.push(' ');
+        }
+        let _ = write!(path, "M{:.1} {:.1}", first.x, first.y);
+        if rest.is_empty() {
+            // A dot still needs a segment to be drawn with round caps
+            path.push_str(" l0 0");
+        }
+        for point in rest {
+            let _ = write!(path, " L{:.1} {:.1}", point.x, point.y);
+        }
+    }
+    path
+}
+
+/// Standalone SVG document of the signature with pressure-dependent widths
+pub fn signature_svg(
+    strokes: &[SignatureStroke],
+    width: f64,
+    height: f64,
+    pen: &SignaturePen,
+) -> String {
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\" \
+         fill=\"none\" stroke=\"{color}\" stroke-linecap=\"round\" stroke-linejoin=\"round\">",
+        w = width,
+        h = height,
+        color = pen.color
+    );
+    for stroke in strokes {
+        match stroke.as_slice() {
+            [] => {}
+            [point] => {
+                let _ = write!(
+                    svg,
+                    "<path d=\"M{:.1} {:.1} l0 0\" stroke-width=\"{:.2}\"/>",
+                    point.x,
+                    point.y,
+                    pen.width(point.pressure)
+                );
+            }
+            points => {
+                for segment in points.windows(2) {
+                    let (from, to) = (segment[0], segment[1]);
+                    let _ = write!(
+                        svg,
+                        "<path d=\"M{:.1} {:.1} L{:.1} {:.1}\" stroke-width=\"{:.2}\"/>",
+                        from.x,
+                        from.y,
+                        to.x,
+                        to.y,
+                        pen.width((from.pressure + to.pressure) / 2.0)
+                    );
+                }
+            }
+        }
+    }
+    svg.push_str("</svg>");
+    svg
+}
+
+/// Reactive state of a [`SignaturePad`]
+///
+/// Pass it through the `controller` prop to undo, clear or export the
+/// signature from outside the pad.
+#[derive(Debug, Clone, Copy)]
+pub struct SignaturePadController {
+    pub strokes: RwSignal<Vec<SignatureStroke>>,
+    canvas: NodeRef<html::Canvas>,
+    pen: StoredValue<SignaturePen>,
+    size: StoredValue<PadSize>,
+}
+
+impl Default for SignaturePadController {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SignaturePadController {
+    pub fn new() -> Self {
+        Self {
+            strokes: RwSignal::new(Vec::new()),
+            canvas: NodeRef::new(),
+            pen: StoredValue::new(SignaturePen::default()),
+            size: StoredValue::new((0.0, 0.0)),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.strokes
+            .with(|strokes| strokes.iter().all(Vec::is_empty))
+    }
+
+    /// Remove the last stroke
+    pub fn undo(&self) {
+        self.strokes.update(|strokes| {
+            strokes.pop();
+        });
+    }
+
+    pub fn clear(&self) {
+        self.strokes.set(Vec::new());
+    }
+
+    pub fn to_svg_path(&self) -> String {
+        self.strokes.with(|strokes| signature_svg_path(strokes))
+    }
+
+    pub fn to_svg(&self) -> String {
+        let (width, height) = self.size.get_value();
+        self.pen.with_value(|pen| {
+            self.strokes
+                .with(|strokes| signature_svg(strokes, width, height, pen))
+        })
+    }
+
+    /// PNG data URL of the drawn canvas, once mounted
+    pub fn to_png_data_url(&self) -> Option<String> {
+        self.canvas.get_untracked()?.to_data_url().ok()
+    }
+}
+
+fn redraw(
+    canvas: &web_sys::HtmlCanvasElement,
+    size: PadSize,
+    strokes: &[SignatureStroke],
+    pen: &SignaturePen,
+) {
+    // Back the canvas with device pixels so strokes stay sharp
+    let ratio = web_sys::window().map_or(1.0, |window| window.device_pixel_ratio());
+    let (width, height) = (
+        (size.0 * ratio).round() as u32,
+        (size.1 * ratio).round() as u32,
+    );
+    if canvas.width() != width || canvas.height() != height {
+        canvas.set_width(width);
+        canvas.set_height(height);
+    }
+    let Some(context) = canvas
+        .get_context("2d")
+        .ok()
+        .flatten()
+        .and_then(|context| context.dyn_into::<web_sys::CanvasRenderingContext2d>().ok())
+    else {
+        return;
+    };
+    let _ = context.set_transform(ratio, 0.0, 0.0, ratio, 0.0, 0.0);
+    context.clear_rect(0.0, 0.0, size.0, size.1);
+    context.set_stroke_style_str(&pen.color);
+    context.set_line_cap("round");
+    context.set_line_join("round");
+    for stroke in strokes {
+        let segments = match stroke.as_slice() {
+            [] => Vec::new(),
+            [point] => vec![(*point, *point)],
+            points => points.windows(2).map(|pair| (pair[0], pair[1])).collect(),
+        };
+        for (from, to) in segments {
+            context.set_line_width(pen.width((from.pressure + to.pressure) / 2.0));
+            context.begin_path();
+            context.move_to(from.x, from.y);
+            context.line_to(to.x, to.y);
+            context.stroke();
+        }
+    }
+}
+
+/// SignaturePad component - Canvas for drawing a signature
+///
+/// Strokes follow mouse, pen and touch input, with the line width
+/// following pen pressure. Undo and Clear buttons are rendered unless
+/// `show_controls` is false, and the [`SignaturePadController`] exports
+/// the signature as SVG or as a PNG data URL.
+///
+/// Inside a [`Form`](crate::Form) with a controller, a named pad stores
+/// its SVG path data as the field value and reports a missing required
+/// signature as the field's error once the form is submitted.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn Consent() -> impl IntoView {
+///     let form = FormController::new();
+///     let signature = SignaturePadController::new();
+///     view! {
+///         <Form controller=form>
+///             <SignaturePad name="signature".to_string() required=true controller=signature />
+///             <button type="submit">"Sign"</button>
+///         </Form>
+///     }
+/// }
+/// ```
+#[component]
+pub fn SignaturePad(
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    #[prop(optional)] controller: Option<SignaturePadController>,
+    /// Size of the drawing surface in CSS pixels (default: 400×200)
+    #[prop(optional)]
+    width: Option<f64>,
+    #[prop(optional)] height: Option<f64>,
+    #[prop(optional)] pen: Option<SignaturePen>,
+    /// Form field name
+    #[prop(optional)]
+    name: Option<String>,
+    #[prop(optional)] required: bool,
+    #[prop(optional)] disabled: bool,
+    /// Accessible name of the drawing surface (default: "Signature")
+    #[prop(optional)]
+    label: Option<String>,
+    /// Render the Undo and Clear buttons (default: true)
+    #[prop(optional)]
+    show_controls: Option<bool>,
+    /// Called with the SVG path data after every change
+    #[prop(optional)]
+    on_change: Option<Callback<String>>,
+) -> impl IntoView {
+    let controller = controller.unwrap_or_default();
+    let size = (width.unwrap_or(400.0), height.unwrap_or(200.0));
+    let pen = pen.unwrap_or_default();
+    let catalog = use_i18n();
+    let label = label.unwrap_or_else(|| catalog.text(MessageKey::SignaturePadLabel));
+    let required_text = StoredValue::new(catalog.text(MessageKey::SignaturePadRequired));
+    let show_controls = show_controls.unwrap_or(true);
+    controller.pen.set_value(pen.clone());
+    controller.size.set_value(size);
+    let strokes = controller.strokes;
+    let canvas_ref = controller.canvas;
+    let drawing = StoredValue::new(false);
+    let error_id = StoredValue::new(generate_id("signature-pad-error"));
+
+    let form = use_context::<FormController>();
+    let field = name.clone().filter(|_| form.is_some());
+    let field = StoredValue::new(field);
+    let error = Memo::new(move |_| match (form, field.get_value()) {
+        (Some(form), Some(name)) => form.error(&name),
+        _ => None,
+    });
+    let validate = move || {
+        if let (Some(form), Some(name)) = (form, field.get_value()) {
+            match strokes.with_untracked(|strokes| validate_signature(strokes, required)) {
+                Err(SignatureError::Required) => form.set_error(&name, required_text.get_value()),
+                Ok(()) => form.clear_error(&name),
+            }
+        }
+    };
+    if let Some(form) = form {
+        Effect::new(move |_| {
+            if form.submitted.get() {
+                validate();
+            }
+        });
+    }
+
+    let pen = StoredValue::new(pen);
+    Effect::new(move |_| {
+        let strokes = strokes.get();
+        if let Some(canvas) = canvas_ref.get() {
+            pen.with_value(|pen| redraw(&canvas, size, &strokes, pen));
+        }
+    });
+
+    let changed = move || {
+        let path = strokes.with_untracked(|strokes| signature_svg_path(strokes));
+        if let (Some(form), Some(name)) = (form, field.get_value()) {
+            form.set_value(&name, path.clone());
+            if form.submitted.get_untracked() || form.error(&name).is_some() {
+                validate();
+            }
+        }
+        if let Some(on_change) = on_change {
+            on_change.run(path);
+        }
+    };
+    let point = |ev: &web_sys::PointerEvent| SignaturePoint {
+        x: ev.offset_x() as f64,
+        y: ev.offset_y() as f64,
+        pressure: ev.pressure() as f64,
+    };
+    let handle_pointerdown = move |ev: web_sys::PointerEvent| {
+        if disabled || ev.button() != 0 {
+            return;
+        }
+        ev.prevent_default();
+        if let Some(canvas) = canvas_ref.get_untracked() {
+            let _ = canvas.set_pointer_capture(ev.pointer_id());
+        }
+        drawing.set_value(true);
+        strokes.update(|strokes| strokes.push(vec![point(&ev)]));
+    };
+    let handle_pointermove = move |ev: web_sys::PointerEvent| {
+        if !drawing.get_value() {
+            return;
+        }
+        strokes.update(|strokes| {
+            if let Some(stroke) = strokes.last_mut() {
+                stroke.push(point(&ev));
+            }
+        });
+    };
+    let handle_pointerup = move |_: web_sys::PointerEvent| {
+        if drawing.get_value() {
+            drawing.set_value(false);
+            changed();
+        }
+    };
+
+    let class = merge_classes(vec!["signature-pad", class.as_deref().unwrap_or("")]);
+    let canvas_style = format!(
+        "width: {}px; height: {}px; touch-action: none; display: block;",
+        size.0, size.1
+    );
+    let undo_text = catalog.text(MessageKey::SignaturePadUndo);
+    let clear_text = catalog.text(MessageKey::SignaturePadClear);
+    let canvas_label = move || {
+        let args = MessageArgs::new()
+            .with("label", label.as_str())
+            .with("count", strokes.with(Vec::len));
+        catalog.format(MessageKey::SignaturePadCanvas, &args)
+    };
+
+    view! {
+        <div class=class style=style data-disabled=disabled.then_some("") data-empty=move || controller.is_empty().to_string()>
+            <canvas
+                class="signature-pad-canvas"
+                node_ref=canvas_ref
+                width=size.0.round() as u32
+                height=size.1.round() as u32
+                style=canvas_style
+                role="img"
+                aria-label=canvas_label
+                aria-required=required.then_some("true")
+                aria-invalid=move || error.get().is_some().then_some("true")
+                aria-describedby=move || error.get().is_some().then(|| error_id.get_value())
+                on:pointerdown=handle_pointerdown
+                on:pointermove=handle_pointermove
+                on:pointerup=handle_pointerup
+                on:pointercancel=handle_pointerup
+            ></canvas>
+            <input type="hidden" name=name prop:value=move || controller.to_svg_path() />
+            {show_controls.then(|| view! {
+                <div class="signature-pad-controls">
+                    <button
+                        type="button"
+                        class="signature-pad-undo"
+                        disabled=move || disabled || controller.is_empty()
+                        on:click=move |_| {
+                            controller.undo();
+                            changed();
+                        }
+                    >
+                        {undo_text}
+                    </button>
+                    <button
+                        type="button"
+                        class="signature-pad-clear"
+                        disabled=move || disabled || controller.is_empty()
+                        on:click=move |_| {
+                            controller.clear();
+                            changed();
+                        }
+                    >
+                        {clear_text}
+                    </button>
+                </div>
+            })}
+            {move || error.get().map(|message| view! {
+                <p id=error_id.get_value() class="signature-pad-error" role="alert">{message}</p>
+            })}
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(x: f64, y: f64, pressure: f64) -> SignaturePoint {
+        SignaturePoint { x, y, pressure }
+    }
+
+    #[test]
+    fn test_pressure_width() {
+        let pen = SignaturePen::default();
+        assert_eq!(pen.width(0.0), 1.0);
+        assert_eq!(pen.width(1.0), 4.0);
+        assert_eq!(pen.width(2.0), 4.0);
+        assert_eq!(pen.width(f64::NAN), 2.5);
+    }
+
+    #[test]
+    fn test_svg_path() {
+        let strokes = vec![
+            vec![point(1.0, 2.0, 0.5), point(3.0, 4.0, 0.5)],
+            vec![],
+            vec![point(5.0, 5.0, 0.5)],
+        ];
+        assert_eq!(
+            signature_svg_path(&strokes),
+            "M1.0 2.0 L3.0 4.0 M5.0 5.0 l0 0"
+        );
+        assert_eq!(signature_svg_path(&[]), "");
+    }
+
+    #[test]
+    fn test_svg_uses_pressure() {
+        let strokes = vec![vec![point(0.0, 0.0, 0.0), point(10.0, 0.0, 1.0)]];
+        let svg = signature_svg(&strokes, 100.0, 50.0, &SignaturePen::default());
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains("viewBox=\"0 0 100 50\""));
+        assert!(svg.contains("stroke-width=\"2.50\""));
+    }
+
+    #[test]
+    fn test_validation() {
+        assert_eq!(validate_signature(&[], true), Err(SignatureError::Required));
+        assert_eq!(
+            validate_signature(&[vec![]], true),
+            Err(SignatureError::Required)
+        );
+        assert_eq!(validate_signature(&[], false), Ok(()));
+        assert_eq!(
+            validate_signature(&[vec![point(1.0, 1.0, 0.5)]], true),
+            Ok(())
+        );
+        assert_eq!(
+            SignatureError::Required.to_string(),
+            "Signature is required"
+        );
+    }
+}
@@ -1,6 +1,8 @@
+use crate::i18n::{use_i18n, MessageKey};
 use crate::utils::merge_classes;
 use leptos::children::Children;
 use leptos::prelude::*;
+use radix_leptos_core::use_reduced_motion;
 
 /// Skeleton component - Loading placeholder component for better UX
 ///
@@ -170,8 +172,21 @@ pub fn Skeleton(
     let size = size.unwrap_or(SkeletonSize::Medium);
     let lines = lines.unwrap_or(1);
     let animated = animated.unwrap_or(true);
-
-    let class = merge_classes(vec!["skeleton", variant.as_str(), size.as_str()]);
+    // The shimmer stops while motion is reduced
+    let reduced_motion = use_reduced_motion();
+
+    let class = move || {
+        merge_classes(vec![
+            "skeleton",
+            variant.as_str(),
+            size.as_str(),
+            if animated && !reduced_motion.get() {
+                "skeleton-animated"
+            } else {
+                ""
+            },
+        ])
+    };
 
     let mut style_attr = String::new();
     if let Some(h) = height {
@@ -203,7 +218,7 @@ pub fn Skeleton(
                 class=class
                 style=style_attr
                 role="img"
-                aria-label="Loading"
+                aria-label=use_i18n().text(MessageKey::Loading)
             ></div>
         }
         .into_any(),
@@ -212,7 +227,7 @@ pub fn Skeleton(
                 class=class
                 style=style_attr
                 role="img"
-                aria-label="Loading"
+                aria-label=use_i18n().text(MessageKey::Loading)
             ></div>
         }
         .into_any(),
@@ -309,15 +324,313 @@ pub fn SkeletonButton(
     }
 }
 
+/// Height of one text line, derived from the theme's font size and line height
+const SKELETON_LINE_HEIGHT: &str =
+    "calc(var(--font-size-sm, 0.875rem) * var(--line-height-normal, 1.5))";
+
+/// Widths cycled through by preset placeholders so rows do not look identical
+const SKELETON_WIDTHS: [&str; 5] = ["92%", "76%", "84%", "64%", "88%"];
+
+/// Deterministic width for the placeholder at `row`/`col`
+///
+/// Deterministic so server-rendered fallbacks hydrate without mismatches.
+pub fn skeleton_width(row: usize, col: usize) -> &'static str {
+    SKELETON_WIDTHS[(row * 3 + col) % SKELETON_WIDTHS.len()]
+}
+
+/// Inline style of a single placeholder bar
+pub fn skeleton_bar_style(width: &str, height: &str) -> String {
+    format!(
+        "display: block; width: {}; height: {}; border-radius: var(--border-radius-md, 0.375rem);",
+        width, height
+    )
+}
+
+/// Class of a preset, without the shimmer while motion is reduced
+fn animated_preset_class(
+    base: &'static str,
+    animated: Option<bool>,
+    class: Option<String>,
+) -> impl Fn() -> String + Send + Sync + 'static {
+    let animated = animated.unwrap_or(true);
+    let reduced_motion = use_reduced_motion();
+    move || preset_class(base, animated && !reduced_motion.get(), class.as_deref())
+}
+
+fn preset_class(base: &str, animated: bool, class: Option<&str>) -> String {
+    merge_classes(vec![
+        "skeleton-preset",
+        base,
+        if animated { "skeleton-animated" } else { "" },
+        class.unwrap_or(""),
+    ])
+}
+
+/// TableSkeleton component - Suspense fallback shaped like a table
+///
+/// Cells use the table's padding and line-height tokens so swapping in the
+/// loaded table does not shift the layout.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn Orders() -> impl IntoView {
+///     let orders = LocalResource::new(|| async { Vec::<String>::new() });
+///     view! {
+///         <Suspense fallback=|| view! { <TableSkeleton rows=10 cols=5 /> }>
+///             {move || orders.get().map(|orders| orders.len())}
+///         </Suspense>
+///     }
+/// }
+/// ```
+#[component]
+pub fn TableSkeleton(
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    /// Number of body rows (default: 5)
+    #[prop(optional)]
+    rows: Option<usize>,
+    /// Number of columns (default: 4)
+    #[prop(optional)]
+    cols: Option<usize>,
+    /// Render a header row (default: true)
+    #[prop(optional)]
+    show_header: Option<bool>,
+    #[prop(optional)] animated: Option<bool>,
+) -> impl IntoView {
+    let rows = rows.unwrap_or(5);
+    let cols = cols.unwrap_or(4).max(1);
+    let show_header = show_header.unwrap_or(true);
+    let class = animated_preset_class("skeleton-table", animated, class);
+    let cell_style = "padding: var(--space-3, 0.75rem) var(--space-4, 1rem);";
+
+    let cells = move |row: usize| {
+        (0..cols)
+            .map(|col| {
+                view! {
+                    <td style=cell_style>
+                        <span
+                            class="skeleton skeleton-cell"
+                            style=skeleton_bar_style(skeleton_width(row, col), SKELETON_LINE_HEIGHT)
+                        ></span>
+                    </td>
+                }
+            })
+            .collect_view()
+    };
+
+    view! {
+        <table
+            class=class
+            style=format!("width: 100%; border-collapse: collapse; {}", style.unwrap_or_default())
+            aria-busy="true"
+            aria-label=use_i18n().text(MessageKey::Loading)
+        >
+            {show_header.then(|| view! {
+                <thead>
+                    <tr class="skeleton-table-header">
+                        {(0..cols)
+                            .map(|_| view! {
+                                <th style=cell_style>
+                                    <span
+                                        class="skeleton skeleton-cell"
+                                        style=skeleton_bar_style("50%", SKELETON_LINE_HEIGHT)
+                                    ></span>
+                                </th>
+                            })
+                            .collect_view()}
+                    </tr>
+                </thead>
+            })}
+            <tbody>
+                {(0..rows)
+                    .map(|row| view! { <tr class="skeleton-table-row">{cells(row)}</tr> })
+                    .collect_view()}
+            </tbody>
+        </table>
+    }
+}
+
+/// ListSkeleton component - Suspense fallback shaped like a `List`
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn Contacts() -> impl IntoView {
+///     view! {
+///         <Suspense fallback=|| view! { <ListSkeleton items=6 avatar=true /> }>
+///             "Contacts"
+///         </Suspense>
+///     }
+/// }
+/// ```
+#[component]
+pub fn ListSkeleton(
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    /// Number of items (default: 5)
+    #[prop(optional)]
+    items: Option<usize>,
+    /// Text lines per item (default: 2)
+    #[prop(optional)]
+    lines: Option<usize>,
+    /// Leading avatar placeholder (default: false)
+    #[prop(optional)]
+    avatar: Option<bool>,
+    #[prop(optional)] animated: Option<bool>,
+) -> impl IntoView {
+    let items = items.unwrap_or(5);
+    let lines = lines.unwrap_or(2).max(1);
+    let avatar = avatar.unwrap_or(false);
+    let class = animated_preset_class("skeleton-list radix-list", animated, class);
+
+    view! {
+        <div class=class style=style aria-busy="true" aria-label=use_i18n().text(MessageKey::Loading) role="status">
+            {(0..items)
+                .map(|item| view! {
+                    <div
+                        class="skeleton-list-item radix-list-item"
+                        style="display: flex; align-items: center; gap: var(--space-3, 0.75rem); padding: var(--space-3, 0.75rem) var(--space-4, 1rem);"
+                    >
+                        {avatar.then(|| view! {
+                            <span
+                                class="skeleton circular"
+                                style="flex: none; width: var(--space-10, 2.5rem); height: var(--space-10, 2.5rem); border-radius: 9999px;"
+                            ></span>
+                        })}
+                        <div style="flex: 1; display: flex; flex-direction: column; gap: var(--space-2, 0.5rem);">
+                            {(0..lines)
+                                .map(|line| {
+                                    let width = if line == 0 { skeleton_width(item, 0) } else { "60%" };
+                                    view! {
+                                        <span class="skeleton skeleton-line" style=skeleton_bar_style(width, SKELETON_LINE_HEIGHT)></span>
+                                    }
+                                })
+                                .collect_view()}
+                        </div>
+                    </div>
+                })
+                .collect_view()}
+        </div>
+    }
+}
+
+/// CardSkeleton component - Suspense fallback shaped like a card
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn Product() -> impl IntoView {
+///     view! {
+///         <Suspense fallback=|| view! { <CardSkeleton media=true actions=true /> }>
+///             "Product"
+///         </Suspense>
+///     }
+/// }
+/// ```
+#[component]
+pub fn CardSkeleton(
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    /// Media placeholder above the content (default: true)
+    #[prop(optional)]
+    media: Option<bool>,
+    /// Height of the media placeholder (default: 10rem)
+    #[prop(optional)]
+    media_height: Option<String>,
+    /// Body text lines (default: 3)
+    #[prop(optional)]
+    lines: Option<usize>,
+    /// Footer action placeholders (default: false)
+    #[prop(optional)]
+    actions: Option<bool>,
+    #[prop(optional)] animated: Option<bool>,
+) -> impl IntoView {
+    let media = media.unwrap_or(true);
+    let media_height = media_height.unwrap_or_else(|| "10rem".to_string());
+    let lines = lines.unwrap_or(3);
+    let actions = actions.unwrap_or(false);
+    let class = animated_preset_class("skeleton-card", animated, class);
+    let style = format!(
+        "border-radius: var(--border-radius-lg, 0.5rem); overflow: hidden; {}",
+        style.unwrap_or_default()
+    );
+
+    view! {
+        <div class=class style=style aria-busy="true" aria-label=use_i18n().text(MessageKey::Loading) role="status">
+            {media.then(|| view! {
+                <span class="skeleton skeleton-card-media" style=format!("display: block; height: {};", media_height)></span>
+            })}
+            <div class="skeleton-card-body" style="display: flex; flex-direction: column; gap: var(--space-2, 0.5rem); padding: var(--space-4, 1rem);">
+                <span
+                    class="skeleton skeleton-card-title"
+                    style=skeleton_bar_style("60%", "calc(var(--font-size-lg, 1.125rem) * var(--line-height-tight, 1.25))")
+                ></span>
+                {(0..lines)
+                    .map(|line| {
+                        let width = if line + 1 == lines { "70%" } else { "100%" };
+                        view! {
+                            <span class="skeleton skeleton-line" style=skeleton_bar_style(width, SKELETON_LINE_HEIGHT)></span>
+                        }
+                    })
+                    .collect_view()}
+            </div>
+            {actions.then(|| view! {
+                <div class="skeleton-card-actions" style="display: flex; gap: var(--space-2, 0.5rem); padding: 0 var(--space-4, 1rem) var(--space-4, 1rem);">
+                    <span class="skeleton skeleton-button" style=skeleton_bar_style("5rem", "var(--space-8, 2rem)")></span>
+                    <span class="skeleton skeleton-button" style=skeleton_bar_style("5rem", "var(--space-8, 2rem)")></span>
+                </div>
+            })}
+        </div>
+    }
+}
+
 // Helper function to merge CSS classes
 
 #[cfg(test)]
 mod tests {
+    use super::*;
     use proptest::prelude::*;
 
     #[test]
     fn test_skeleton_component_creation() {}
 
+    #[test]
+    fn test_skeleton_widths_vary_between_rows() {
+        let first_column: Vec<_> = (0..5).map(|row| skeleton_width(row, 0)).collect();
+        assert!(first_column.windows(2).all(|pair| pair[0] != pair[1]));
+        assert_eq!(skeleton_width(7, 2), skeleton_width(7, 2));
+    }
+
+    #[test]
+    fn test_skeleton_bar_style_uses_theme_radius() {
+        let style = skeleton_bar_style("50%", SKELETON_LINE_HEIGHT);
+        assert!(style.contains("width: 50%"));
+        assert!(style.contains("var(--font-size-sm, 0.875rem)"));
+        assert!(style.contains("var(--border-radius-md, 0.375rem)"));
+    }
+
+    #[test]
+    fn test_preset_class() {
+        assert_eq!(
+            preset_class("skeleton-table", true, Some("custom")),
+            "skeleton-preset skeleton-table skeleton-animated custom"
+        );
+        assert_eq!(preset_class("skeleton-card", false, None), "skeleton-preset skeleton-card");
+    }
+
     #[test]
     fn test_skeleton_with_variant_component_creation() {}
 
@@ -1,7 +1,13 @@
 use leptos::callback::Callback;
 use leptos::children::Children;
 use leptos::prelude::*;
-use crate::utils::{merge_optional_classes, generate_id};
+use wasm_bindgen::JsCast;
+use crate::utils::{
+    clamp_to_range, forced_colors_style, generate_id, merge_optional_classes, sanitize_range,
+    warn_invalid_prop,
+};
+use radix_leptos_core::{use_focus_visible, use_forced_colors};
+use radix_leptos_core::{channels, use_announcer};
 
 /// Slider component with proper accessibility and styling variants
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -38,13 +44,35 @@ impl SliderSize {
     }
 }
 
+/// Formats a slider value for `aria-valuetext`
+pub type SliderValueText = Callback<f64, String>;
+
+/// Text announced for a slider value: its `value_text`, else the number
+pub fn slider_value_announcement(value: f64, value_text: Option<SliderValueText>) -> String {
+    match value_text {
+        Some(format) => format.run(value),
+        None => value.to_string(),
+    }
+}
+
+/// Value at `ratio` (0 to 1) along a slider's track, snapped to `step`
+pub fn slider_value_from_ratio(ratio: f64, min: f64, max: f64, step: f64) -> f64 {
+    let ratio = if ratio.is_finite() { ratio.clamp(0.0, 1.0) } else { 0.0 };
+    let raw = min + ratio * (max - min);
+    let snapped = if step > 0.0 {
+        min + ((raw - min) / step).round() * step
+    } else {
+        raw
+    };
+    snapped.clamp(min, max)
+}
 
 /// Slider root component
 #[component]
 pub fn Slider(
     /// Current value
-    #[prop(optional, default = 0.0)]
-    value: f64,
+    #[prop(optional, into)]
+    value: Signal<f64>,
     /// Minimum value
     #[prop(optional, default = 0.0)]
     min: f64,
@@ -72,9 +100,25 @@ pub fn Slider(
     /// Value change event handler
     #[prop(optional)]
     on_value_change: Option<Callback<f64>>,
+    /// Accessible name of the slider
+    #[prop(optional)]
+    aria_label: Option<String>,
+    /// Human readable form of the value, e.g. "1 minute 30 seconds"
+    #[prop(optional)]
+    value_text: Option<SliderValueText>,
     /// Child content
     _children: Children,
 ) -> impl IntoView {
+    // Recover from inconsistent props instead of rendering a broken slider
+    let (min, max) = sanitize_range("Slider", min, max, (0.0, 100.0));
+    let value = Memo::new(move |_| clamp_to_range("Slider", value.get(), min, max));
+    let step = if step.is_finite() && step > 0.0 {
+        step
+    } else {
+        warn_invalid_prop("Slider", &format!("step {} must be positive, using 1", step));
+        1.0
+    };
+
     let __slider_id = generate_id("slider");
     let __track_id = generate_id("slider-track");
     let __range_id = generate_id("slider-range");
@@ -89,80 +133,117 @@ pub fn Slider(
     let combined_class = merge_optional_classes(Some(base_classes), class.as_deref())
         .unwrap_or_else(|| base_classes.to_string());
 
+    let slider_ref = NodeRef::<leptos::html::Div>::new();
+    let focus_visible = use_focus_visible(slider_ref);
+    let announcer = use_announcer();
+
     // Handle keyboard navigation
-    let _handle_keydown = move |e: web_sys::KeyboardEvent| {
+    let handle_keydown = move |e: web_sys::KeyboardEvent| {
         if disabled {
             return;
         }
 
-        match e.key().as_str() {
-            "ArrowLeft" | "ArrowDown" => {
-                e.prevent_default();
-                let new_value = (value - step).max(min);
-                if let Some(on_value_change) = on_value_change {
-                    on_value_change.run(new_value);
-                }
-            }
-            "ArrowRight" | "ArrowUp" => {
-                e.prevent_default();
-                let new_value = (value + step).min(max);
-                if let Some(on_value_change) = on_value_change {
-                    on_value_change.run(new_value);
-                }
-            }
-            "Home" => {
-                e.prevent_default();
-                if let Some(on_value_change) = on_value_change {
-                    on_value_change.run(min);
-                }
-            }
-            "End" => {
-                e.prevent_default();
-                if let Some(on_value_change) = on_value_change {
-                    on_value_change.run(max);
-                }
-            }
-            "PageDown" => {
-                e.prevent_default();
-                let new_value = (value - step * 10.0).max(min);
-                if let Some(on_value_change) = on_value_change {
-                    on_value_change.run(new_value);
-                }
-            }
-            "PageUp" => {
-                e.prevent_default();
-                let new_value = (value + step * 10.0).min(max);
-                if let Some(on_value_change) = on_value_change {
-                    on_value_change.run(new_value);
-                }
-            }
-            _ => {}
+        let value = value.get_untracked();
+        let new_value = match e.key().as_str() {
+            "ArrowLeft" | "ArrowDown" => (value - step).max(min),
+            "ArrowRight" | "ArrowUp" => (value + step).min(max),
+            "Home" => min,
+            "End" => max,
+            "PageDown" => (value - step * 10.0).max(min),
+            "PageUp" => (value + step * 10.0).min(max),
+            _ => return,
+        };
+
+        e.prevent_default();
+        if let Some(on_value_change) = on_value_change {
+            on_value_change.run(new_value);
         }
+        // Rapid changes are throttled by the slider live channel
+        announcer.announce(
+            channels::SLIDER,
+            &slider_value_announcement(new_value, value_text),
+        );
     };
 
-    // Calculate percentage for visual representation
-    let _percentage = if max > min {
-        ((value - min) / (max - min) * 100.0).clamp(0.0, 100.0)
-    } else {
-        0.0
+    // Clicking or dragging along the slider moves the value to the pointer
+    let dragging = StoredValue::new(false);
+    let update_from_pointer = move |e: &web_sys::PointerEvent| {
+        let Some(element) = e
+            .current_target()
+            .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+        else {
+            return;
+        };
+        let rect = element.get_bounding_client_rect();
+        if rect.width() <= 0.0 {
+            return;
+        }
+        let ratio = (e.client_x() as f64 - rect.left()) / rect.width();
+        let new_value = slider_value_from_ratio(ratio, min, max, step);
+        if let Some(on_value_change) = on_value_change {
+            on_value_change.run(new_value);
+        }
+        announcer.announce(
+            channels::SLIDER,
+            &slider_value_announcement(new_value, value_text),
+        );
+    };
+    let handle_pointerdown = move |e: web_sys::PointerEvent| {
+        if disabled || e.button() != 0 {
+            return;
+        }
+        if let Some(element) = e
+            .current_target()
+            .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+        {
+            let _ = element.set_pointer_capture(e.pointer_id());
+        }
+        dragging.set_value(true);
+        update_from_pointer(&e);
+    };
+    let handle_pointermove = move |e: web_sys::PointerEvent| {
+        if dragging.get_value() {
+            update_from_pointer(&e);
+        }
+    };
+
+    // Announce the settled value once a drag ends, however it ends
+    let end_drag = move || {
+        if dragging.get_value() {
+            dragging.set_value(false);
+            announcer.commit(channels::SLIDER);
+        }
     };
 
+    let valuetext = move || value_text.map(|format| format.run(value.get()));
+
     view! {
         <div
+            node_ref=slider_ref
             class=combined_class
             style=style
             data-variant=data_variant
             data-size=data_size
-            data-value=value
+            data-value=move || value.get()
             data-min=min
             data-max=max
             data-step=step
             data-disabled=disabled
+            data-focus-visible=move || focus_visible.get().then_some("")
             role="slider"
+            tabindex=(!disabled).then_some("0")
+            aria-label=aria_label
             aria-valuemin=min
             aria-valuemax=max
-            aria-valuenow=value
+            aria-valuenow=move || value.get()
+            aria-valuetext=valuetext
             aria-disabled=disabled
+            on:keydown=handle_keydown
+            on:pointerdown=handle_pointerdown
+            on:pointermove=handle_pointermove
+            on:pointerup=move |_| end_drag()
+            on:pointercancel=move |_| end_drag()
+            on:lostpointercapture=move |_| end_drag()
         >
         </div>
     }
@@ -181,6 +262,14 @@ pub fn SliderTrack(
     let base_classes = "radix-slider-track";
     let combined_class = merge_optional_classes(Some(base_classes), class.as_deref())
         .unwrap_or_else(|| base_classes.to_string());
+    let forced_colors = use_forced_colors();
+    let style = move || {
+        forced_colors_style(
+            style.as_deref(),
+            forced_colors.get(),
+            "border: 1px solid CanvasText;",
+        )
+    };
 
     view! {
         <div
@@ -192,6 +281,9 @@ pub fn SliderTrack(
 }
 
 /// Slider Range component
+///
+/// Filled with `Highlight` in forced colors mode, which would otherwise
+/// drop its background.
 #[component]
 pub fn SliderRange(
     /// CSS classes
@@ -204,6 +296,14 @@ pub fn SliderRange(
     let base_classes = "radix-slider-range";
     let combined_class = merge_optional_classes(Some(base_classes), class.as_deref())
         .unwrap_or_else(|| base_classes.to_string());
+    let forced_colors = use_forced_colors();
+    let style = move || {
+        forced_colors_style(
+            style.as_deref(),
+            forced_colors.get(),
+            "forced-color-adjust: none; background: Highlight;",
+        )
+    };
 
     view! {
         <div
@@ -239,7 +339,7 @@ pub fn SliderThumb(
 
 #[cfg(test)]
 mod tests {
-    use crate::{SliderSize, SliderVariant};
+    use crate::{slider_value_announcement, slider_value_from_ratio, SliderSize, SliderVariant};
     use proptest::prelude::*;
 use crate::utils::{merge_optional_classes, generate_id};
 
@@ -520,6 +620,24 @@ use crate::utils::{merge_optional_classes, generate_id};
         });
     }
 
+    #[test]
+    fn test_slider_value_from_ratio() {
+        assert_eq!(slider_value_from_ratio(0.5, 0.0, 100.0, 1.0), 50.0);
+        assert_eq!(slider_value_from_ratio(0.33, 0.0, 100.0, 10.0), 30.0);
+        assert_eq!(slider_value_from_ratio(-0.2, 10.0, 90.0, 5.0), 10.0);
+        assert_eq!(slider_value_from_ratio(1.4, 10.0, 90.0, 5.0), 90.0);
+        assert_eq!(slider_value_from_ratio(f64::NAN, 0.0, 10.0, 1.0), 0.0);
+    }
+
+    #[test]
+    fn test_slider_value_announcement() {
+        use leptos::callback::Callback;
+
+        assert_eq!(slider_value_announcement(73.4, None), "73.4");
+        let minutes = Callback::new(|value: f64| format!("{} minutes", value));
+        assert_eq!(slider_value_announcement(3.0, Some(minutes)), "3 minutes");
+    }
+
     // 7. Property-Based Tests
     proptest! {
         #[test]
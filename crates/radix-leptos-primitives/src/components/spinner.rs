@@ -0,0 +1,85 @@
+use crate::utils::merge_classes;
+use leptos::prelude::*;
+use radix_leptos_core::{use_reduced_motion, VisuallyHidden};
+
+/// Spinner component - Indeterminate activity indicator
+///
+/// With a `label` the spinner is a polite status announced to screen
+/// readers; without one it is decorative and hidden from them, e.g. when
+/// the surrounding control already reports `aria-busy`.
+///
+/// Like every primitive it ships no styles: rotate the `.spinner-arc` path
+/// about the center of its 24x24 view box in your CSS, and stop it under
+/// `[data-reduced-motion="true"]`, which is set while motion is reduced by
+/// the user or a MotionProvider.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn Loading() -> impl IntoView {
+///     view! { <Spinner size=24 label="Loading results".to_string() /> }
+/// }
+/// ```
+#[component]
+pub fn Spinner(
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    /// Width and height in pixels (default: 16)
+    #[prop(optional)]
+    size: Option<u32>,
+    /// Text announced to screen readers
+    #[prop(optional)]
+    label: Option<String>,
+) -> impl IntoView {
+    let size = size.unwrap_or(16);
+    let class = merge_classes(vec!["spinner", class.as_deref().unwrap_or("")]);
+    let has_label = label.is_some();
+    let reduced_motion = use_reduced_motion();
+
+    view! {
+        <span
+            class=class
+            style=style
+            role=has_label.then_some("status")
+            aria-hidden=(!has_label).then_some("true")
+            data-reduced-motion=move || reduced_motion.get().to_string()
+        >
+            <svg
+                viewBox="0 0 24 24"
+                width=size
+                height=size
+                fill="none"
+                stroke="currentColor"
+                stroke-width="3"
+                aria-hidden="true"
+            >
+                <circle cx="12" cy="12" r="9" opacity="0.25"></circle>
+                <path class="spinner-arc" d="M21 12a9 9 0 0 0-9-9" stroke-linecap="round"></path>
+            </svg>
+            {label.map(|label| view! { <VisuallyHidden>{label}</VisuallyHidden> })}
+        </span>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spinner_roles() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let decorative = view! { <Spinner /> }.to_html();
+            assert!(decorative.contains("aria-hidden=\"true\""));
+            assert!(!decorative.contains("role=\"status\""));
+
+            let labelled = view! { <Spinner label="Saving".to_string() /> }.to_html();
+            assert!(labelled.contains("role=\"status\""));
+            assert!(labelled.contains("Saving"));
+        });
+    }
+}
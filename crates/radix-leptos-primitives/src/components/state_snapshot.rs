@@ -0,0 +1,216 @@
+use leptos::serde_json;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+/// Components whose state can be captured and restored
+///
+/// Implemented by the controllers of stateful components (e.g.
+/// [`FormController`](crate::FormController) and
+/// [`DataTableController`](crate::DataTableController)). The state type is
+/// plain serde data, so apps can keep snapshots for undo, persist them for
+/// restore-on-reload or attach them to bug reports.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use radix_leptos_primitives::*;
+///
+/// let form = FormController::new();
+/// form.set_value("email", "ada@example.com");
+///
+/// let snapshot = ComponentSnapshot::capture(&form).unwrap();
+/// let json = snapshot.to_json().unwrap();
+///
+/// form.reset();
+/// ComponentSnapshot::from_json(&json).unwrap().restore(&form).unwrap();
+/// assert_eq!(form.value("email").as_deref(), Some("ada@example.com"));
+/// ```
+pub trait StateSnapshot {
+    /// Serializable state of the component
+    type State: Serialize + DeserializeOwned + Clone + PartialEq;
+
+    /// Name stored in snapshots so they are only restored into the same kind of component
+    const COMPONENT: &'static str;
+
+    /// Version of the state format; bump it when `State` changes incompatibly
+    const VERSION: u32 = 1;
+
+    /// Current state
+    fn get_state(&self) -> Self::State;
+
+    /// Replace the current state
+    fn set_state(&self, state: Self::State);
+}
+
+/// Errors raised while capturing or restoring a snapshot
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum SnapshotError {
+    #[error("invalid snapshot: {0}")]
+    Serialization(String),
+    #[error("snapshot of `{found}` cannot be restored into `{expected}`")]
+    ComponentMismatch { expected: String, found: String },
+    #[error("snapshot version {found} is not supported (expected {expected})")]
+    UnsupportedVersion { expected: u32, found: u32 },
+}
+
+impl From<serde_json::Error> for SnapshotError {
+    fn from(error: serde_json::Error) -> Self {
+        SnapshotError::Serialization(error.to_string())
+    }
+}
+
+/// State decoded from a snapshot for component `C`
+pub type SnapshotState<C> = Result<<C as StateSnapshot>::State, SnapshotError>;
+
+/// Tagged, component-agnostic snapshot of a component's state
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ComponentSnapshot {
+    pub component: String,
+    pub version: u32,
+    pub state: serde_json::Value,
+}
+
+impl ComponentSnapshot {
+    /// Capture the current state of a component
+    pub fn capture<C: StateSnapshot>(component: &C) -> Result<Self, SnapshotError> {
+        Self::from_state::<C>(&component.get_state())
+    }
+
+    /// Wrap an already captured state
+    pub fn from_state<C: StateSnapshot>(state: &C::State) -> Result<Self, SnapshotError> {
+        Ok(Self {
+            component: C::COMPONENT.to_string(),
+            version: C::VERSION,
+            state: serde_json::to_value(state)?,
+        })
+    }
+
+    /// Decode the state, checking that it belongs to `C`
+    pub fn state<C: StateSnapshot>(&self) -> SnapshotState<C> {
+        if self.component != C::COMPONENT {
+            return Err(SnapshotError::ComponentMismatch {
+                expected: C::COMPONENT.to_string(),
+                found: self.component.clone(),
+            });
+        }
+        if self.version != C::VERSION {
+            return Err(SnapshotError::UnsupportedVersion {
+                expected: C::VERSION,
+                found: self.version,
+            });
+        }
+        Ok(serde_json::from_value(self.state.clone())?)
+    }
+
+    /// Restore the snapshot into a component
+    pub fn restore<C: StateSnapshot>(&self, component: &C) -> Result<(), SnapshotError> {
+        component.set_state(self.state::<C>()?);
+        Ok(())
+    }
+
+    pub fn to_json(&self) -> Result<String, SnapshotError> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, SnapshotError> {
+        Ok(serde_json::from_str(json)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    #[derive(Default)]
+    struct Counter {
+        value: RefCell<i32>,
+    }
+
+    impl StateSnapshot for Counter {
+        type State = i32;
+        const COMPONENT: &'static str = "Counter";
+
+        fn get_state(&self) -> i32 {
+            *self.value.borrow()
+        }
+
+        fn set_state(&self, state: i32) {
+            *self.value.borrow_mut() = state;
+        }
+    }
+
+    struct Other;
+
+    impl StateSnapshot for Other {
+        type State = i32;
+        const COMPONENT: &'static str = "Other";
+        const VERSION: u32 = 2;
+
+        fn get_state(&self) -> i32 {
+            0
+        }
+
+        fn set_state(&self, _state: i32) {}
+    }
+
+    #[test]
+    fn test_capture_and_restore_round_trip() {
+        let counter = Counter::default();
+        counter.set_state(7);
+
+        let json = ComponentSnapshot::capture(&counter)
+            .unwrap()
+            .to_json()
+            .unwrap();
+        counter.set_state(0);
+
+        ComponentSnapshot::from_json(&json)
+            .unwrap()
+            .restore(&counter)
+            .unwrap();
+        assert_eq!(counter.get_state(), 7);
+    }
+
+    #[test]
+    fn test_restore_rejects_other_component() {
+        let snapshot = ComponentSnapshot::from_state::<Counter>(&3).unwrap();
+        assert_eq!(
+            snapshot.state::<Other>(),
+            Err(SnapshotError::ComponentMismatch {
+                expected: "Other".to_string(),
+                found: "Counter".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_restore_rejects_other_version() {
+        let mut snapshot = ComponentSnapshot::from_state::<Other>(&3).unwrap();
+        snapshot.version = 1;
+        assert_eq!(
+            snapshot.state::<Other>(),
+            Err(SnapshotError::UnsupportedVersion {
+                expected: 2,
+                found: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_invalid_json_is_reported() {
+        assert!(matches!(
+            ComponentSnapshot::from_json("{"),
+            Err(SnapshotError::Serialization(_))
+        ));
+        let snapshot = ComponentSnapshot {
+            component: "Counter".to_string(),
+            version: 1,
+            state: serde_json::json!("seven"),
+        };
+        assert!(matches!(
+            snapshot.state::<Counter>(),
+            Err(SnapshotError::Serialization(_))
+        ));
+    }
+}
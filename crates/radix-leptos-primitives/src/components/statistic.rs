@@ -0,0 +1,419 @@
+use crate::components::skeleton::{Skeleton, SkeletonVariant};
+use crate::i18n::{use_i18n, MessageArgs, MessageCatalog, MessageKey};
+use crate::utils::{generate_id, merge_classes};
+use leptos::children::Children;
+use leptos::prelude::*;
+
+/// Statistic component - Metric card for dashboards
+///
+/// Displays a labelled numeric value with optional formatting (abbreviations,
+/// currency, percentages), a trend indicator with delta, a sparkline slot and a
+/// loading skeleton state.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn Revenue() -> impl IntoView {
+///     view! {
+///         <Statistic
+///             label="Revenue".to_string()
+///             value=1_260_000.0
+///             format=StatisticFormat::Currency("$".to_string())
+///             abbreviate=true
+///             delta=12.5
+///         />
+///     }
+/// }
+/// ```
+#[component]
+pub fn Statistic(
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    /// Label displayed above the value
+    #[prop(optional)]
+    label: Option<String>,
+    /// The metric value
+    #[prop(into)]
+    value: Signal<f64>,
+    /// How the value is formatted
+    #[prop(optional)]
+    format: Option<StatisticFormat>,
+    /// Number of decimal places (defaults to 0, or 1 when abbreviated)
+    #[prop(optional)]
+    precision: Option<usize>,
+    /// Abbreviate large values (1.2K, 3.4M, 5.6B)
+    #[prop(optional)]
+    abbreviate: Option<bool>,
+    /// Text rendered before the formatted value
+    #[prop(optional)]
+    prefix: Option<String>,
+    /// Text rendered after the formatted value
+    #[prop(optional)]
+    suffix: Option<String>,
+    /// Change relative to the previous period, in percent
+    #[prop(optional, into)]
+    delta: MaybeProp<f64>,
+    /// Whether a negative delta is the desired direction (e.g. churn, latency)
+    #[prop(optional)]
+    invert_trend: Option<bool>,
+    /// Whether the statistic is still loading
+    #[prop(optional, into)]
+    loading: MaybeProp<bool>,
+    /// Sparkline slot rendered below the value
+    #[prop(optional)]
+    sparkline: Option<Children>,
+) -> impl IntoView {
+    let format = format.unwrap_or_default();
+    let abbreviate = abbreviate.unwrap_or(false);
+    let precision = precision.unwrap_or(if abbreviate { 1 } else { 0 });
+    let invert_trend = invert_trend.unwrap_or(false);
+    let prefix = prefix.unwrap_or_default();
+    let suffix = suffix.unwrap_or_default();
+    let label_id = generate_id("statistic-label");
+    let labelledby = label_id.clone();
+
+    let class = merge_classes(vec!["statistic", class.as_deref().unwrap_or("")]);
+
+    let formatted = move || {
+        format!(
+            "{}{}{}",
+            prefix,
+            format_statistic_value(value.get(), &format, precision, abbreviate),
+            suffix
+        )
+    };
+    let is_loading = move || loading.get().unwrap_or(false);
+    let trend = move || delta.get().map(StatisticTrend::from_delta);
+    let catalog = StoredValue::new(use_i18n());
+
+    view! {
+        <div
+            class=class
+            style=style
+            role="group"
+            aria-labelledby=labelledby
+            aria-busy=move || is_loading().to_string()
+            data-loading=is_loading
+        >
+            <div class="statistic-label" id=label_id>
+                {label}
+            </div>
+            {move || {
+                if is_loading() {
+                    view! {
+                        <Skeleton variant=SkeletonVariant::Text class="statistic-skeleton".to_string() />
+                    }
+                    .into_any()
+                } else {
+                    view! { <div class="statistic-value">{formatted()}</div> }.into_any()
+                }
+            }}
+            {move || {
+                trend().map(|trend| {
+                    let delta = delta.get().unwrap_or_default();
+                    view! {
+                        <div
+                            class=merge_classes(vec![
+                                "statistic-trend",
+                                trend.to_class(),
+                                trend.sentiment_class(invert_trend),
+                            ])
+                            data-trend=trend.as_str()
+                            aria-label=catalog.with_value(|catalog| format_trend_label(catalog, delta))
+                        >
+                            <span class="statistic-trend-icon" aria-hidden="true">
+                                {trend.icon()}
+                            </span>
+                            <span class="statistic-trend-delta">{format_delta(delta)}</span>
+                        </div>
+                    }
+                })
+            }}
+            {sparkline.map(|sparkline| {
+                view! {
+                    <div class="statistic-sparkline" aria-hidden="true">
+                        {sparkline()}
+                    </div>
+                }
+            })}
+        </div>
+    }
+}
+
+/// Statistic value format
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum StatisticFormat {
+    /// Plain number with thousands separators
+    #[default]
+    Number,
+    /// Currency with the given symbol prefix
+    Currency(String),
+    /// Percentage (value is already in percent)
+    Percent,
+}
+
+/// Trend direction derived from a delta
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StatisticTrend {
+    Up,
+    Down,
+    #[default]
+    Neutral,
+}
+
+impl StatisticTrend {
+    pub fn from_delta(delta: f64) -> Self {
+        if delta > 0.0 {
+            StatisticTrend::Up
+        } else if delta < 0.0 {
+            StatisticTrend::Down
+        } else {
+            StatisticTrend::Neutral
+        }
+    }
+
+    pub fn to_class(&self) -> &'static str {
+        match self {
+            StatisticTrend::Up => "trend-up",
+            StatisticTrend::Down => "trend-down",
+            StatisticTrend::Neutral => "trend-neutral",
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StatisticTrend::Up => "up",
+            StatisticTrend::Down => "down",
+            StatisticTrend::Neutral => "neutral",
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            StatisticTrend::Up => "▲",
+            StatisticTrend::Down => "▼",
+            StatisticTrend::Neutral => "–",
+        }
+    }
+
+    /// Positive/negative sentiment class, honouring metrics where down is good
+    pub fn sentiment_class(&self, inverted: bool) -> &'static str {
+        match (self, inverted) {
+            (StatisticTrend::Neutral, _) => "trend-sentiment-neutral",
+            (StatisticTrend::Up, false) | (StatisticTrend::Down, true) => {
+                "trend-sentiment-positive"
+            }
+            (StatisticTrend::Up, true) | (StatisticTrend::Down, false) => {
+                "trend-sentiment-negative"
+            }
+        }
+    }
+}
+
+/// Format a statistic value according to its format, precision and abbreviation
+pub fn format_statistic_value(
+    value: f64,
+    format: &StatisticFormat,
+    precision: usize,
+    abbreviate: bool,
+) -> String {
+    if !value.is_finite() {
+        return "—".to_string();
+    }
+
+    let number = if abbreviate {
+        abbreviate_number(value, precision)
+    } else {
+        group_thousands(value, precision)
+    };
+
+    match format {
+        StatisticFormat::Number => number,
+        StatisticFormat::Currency(symbol) => {
+            if let Some(stripped) = number.strip_prefix('-') {
+                format!("-{}{}", symbol, stripped)
+            } else {
+                format!("{}{}", symbol, number)
+            }
+        }
+        StatisticFormat::Percent => format!("{}%", number),
+    }
+}
+
+/// Abbreviate a number with K/M/B/T suffixes, e.g. `1_250_000.0` -> `"1.3M"`
+pub fn abbreviate_number(value: f64, precision: usize) -> String {
+    let units = [(1.0, ""), (1e3, "K"), (1e6, "M"), (1e9, "B"), (1e12, "T")];
+    let scaled = |threshold: f64| format!("{:.*}", precision, value / threshold);
+    let mut index = units
+        .iter()
+        .rposition(|&(threshold, _)| value.abs() >= threshold)
+        .unwrap_or(0);
+    // Rounding can reach the next unit: 999_950 to one decimal is 1M, not 1000K
+    let rounds_up = scaled(units[index].0)
+        .parse::<f64>()
+        .is_ok_and(|rounded| rounded.abs() >= 1e3);
+    if rounds_up && index + 1 < units.len() {
+        index += 1;
+    }
+    let (threshold, unit) = units[index];
+    format!("{}{}", trim_trailing_zeros(&scaled(threshold)), unit)
+}
+
+/// Format a number with `,` thousands separators and a fixed precision
+pub fn group_thousands(value: f64, precision: usize) -> String {
+    let formatted = format!("{:.*}", precision, value.abs());
+    let (integer, fraction) = match formatted.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (formatted.as_str(), None),
+    };
+
+    let mut grouped = String::with_capacity(integer.len() + integer.len() / 3);
+    for (index, digit) in integer.chars().enumerate() {
+        if index > 0 && (integer.len() - index) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+
+    let sign = if value < 0.0 && formatted.chars().any(|c| c != '0' && c != '.') {
+        "-"
+    } else {
+        ""
+    };
+
+    match fraction {
+        Some(fraction) => format!("{}{}.{}", sign, grouped, fraction),
+        None => format!("{}{}", sign, grouped),
+    }
+}
+
+/// Format a delta as a signed percentage, e.g. `+12.5%`
+///
+/// Changes too small to show at one decimal read `+<0.1%` or `-<0.1%`
+/// rather than a signed zero.
+pub fn format_delta(delta: f64) -> String {
+    let magnitude = match trim_trailing_zeros(&format!("{:.1}", delta.abs())) {
+        "0" => "<0.1".to_string(),
+        magnitude => magnitude.to_string(),
+    };
+    match StatisticTrend::from_delta(delta) {
+        StatisticTrend::Up => format!("+{}%", magnitude),
+        StatisticTrend::Down => format!("-{}%", magnitude),
+        StatisticTrend::Neutral => "0%".to_string(),
+    }
+}
+
+fn format_trend_label(catalog: &MessageCatalog, delta: f64) -> String {
+    let args = MessageArgs::new()
+        .with("trend", StatisticTrend::from_delta(delta).as_str())
+        .with("delta", format_delta(delta).trim_start_matches(['+', '-']));
+    catalog.format(MessageKey::StatisticTrend, &args)
+}
+
+fn trim_trailing_zeros(value: &str) -> &str {
+    if value.contains('.') {
+        value.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_group_thousands() {
+        assert_eq!(group_thousands(0.0, 0), "0");
+        assert_eq!(group_thousands(999.0, 0), "999");
+        assert_eq!(group_thousands(1234.0, 0), "1,234");
+        assert_eq!(group_thousands(1234567.891, 2), "1,234,567.89");
+        assert_eq!(group_thousands(-9876543.0, 0), "-9,876,543");
+        assert_eq!(group_thousands(-0.001, 1), "0.0");
+    }
+
+    #[test]
+    fn test_abbreviate_number() {
+        assert_eq!(abbreviate_number(950.0, 1), "950");
+        assert_eq!(abbreviate_number(1_200.0, 1), "1.2K");
+        assert_eq!(abbreviate_number(1_260_000.0, 1), "1.3M");
+        assert_eq!(abbreviate_number(2_000_000_000.0, 1), "2B");
+        assert_eq!(abbreviate_number(-4_500.0, 1), "-4.5K");
+        assert_eq!(abbreviate_number(3.2e12, 2), "3.2T");
+        assert_eq!(abbreviate_number(999_940.0, 1), "999.9K");
+        assert_eq!(abbreviate_number(999_950.0, 1), "1M");
+        assert_eq!(abbreviate_number(-999_950.0, 1), "-1M");
+        assert_eq!(abbreviate_number(999.96, 1), "1K");
+        assert_eq!(abbreviate_number(999_999_999_999_999.0, 1), "1000T");
+    }
+
+    #[test]
+    fn test_format_statistic_value() {
+        let currency = StatisticFormat::Currency("$".to_string());
+        assert_eq!(
+            format_statistic_value(1234.5, &currency, 2, false),
+            "$1,234.50"
+        );
+        assert_eq!(format_statistic_value(-42.0, &currency, 0, false), "-$42");
+        assert_eq!(
+            format_statistic_value(1_500_000.0, &currency, 1, true),
+            "$1.5M"
+        );
+        assert_eq!(
+            format_statistic_value(12.345, &StatisticFormat::Percent, 1, false),
+            "12.3%"
+        );
+        assert_eq!(
+            format_statistic_value(f64::NAN, &StatisticFormat::Number, 0, false),
+            "—"
+        );
+    }
+
+    #[test]
+    fn test_statistic_trend() {
+        assert_eq!(StatisticTrend::from_delta(3.0), StatisticTrend::Up);
+        assert_eq!(StatisticTrend::from_delta(-0.5), StatisticTrend::Down);
+        assert_eq!(StatisticTrend::from_delta(0.0), StatisticTrend::Neutral);
+        assert_eq!(
+            StatisticTrend::Up.sentiment_class(false),
+            "trend-sentiment-positive"
+        );
+        assert_eq!(
+            StatisticTrend::Up.sentiment_class(true),
+            "trend-sentiment-negative"
+        );
+        assert_eq!(
+            StatisticTrend::Down.sentiment_class(true),
+            "trend-sentiment-positive"
+        );
+    }
+
+    #[test]
+    fn test_format_delta() {
+        assert_eq!(format_delta(12.5), "+12.5%");
+        assert_eq!(format_delta(-3.0), "-3%");
+        assert_eq!(format_delta(0.0), "0%");
+        assert_eq!(format_delta(0.04), "+<0.1%");
+        assert_eq!(format_delta(-0.001), "-<0.1%");
+        assert_eq!(format_delta(0.05), "+0.1%");
+        let catalog = MessageCatalog::default();
+        assert_eq!(format_trend_label(&catalog, -3.0), "Decreased by 3%");
+        assert_eq!(format_trend_label(&catalog, 12.5), "Increased by 12.5%");
+        assert_eq!(format_trend_label(&catalog, 0.04), "Increased by <0.1%");
+        assert_eq!(format_trend_label(&catalog, 0.0), "No change");
+    }
+
+    proptest! {
+        #[test]
+        fn test_group_thousands_round_trips(value in -1.0e12..1.0e12f64) {
+            let formatted = group_thousands(value.trunc(), 0);
+            let parsed: f64 = formatted.replace(',', "").parse().unwrap();
+            prop_assert_eq!(parsed, value.trunc());
+        }
+    }
+}
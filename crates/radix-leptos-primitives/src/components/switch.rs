@@ -1,7 +1,8 @@
 use leptos::callback::Callback;
 use leptos::children::Children;
 use leptos::prelude::*;
-use crate::utils::{merge_optional_classes, generate_id};
+use crate::utils::{forced_colors_style, generate_id, merge_optional_classes};
+use radix_leptos_core::use_forced_colors;
 
 /// Switch component with proper accessibility and styling variants
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -39,7 +40,40 @@ impl SwitchSize {
 }
 
 
+/// Track styles in forced colors mode, where the on state would otherwise
+/// only show as a background color the browser drops
+pub fn switch_forced_colors(checked: bool, disabled: bool) -> &'static str {
+    match (checked, disabled) {
+        (_, true) => "forced-color-adjust: none; background: Canvas; border: 1px solid GrayText;",
+        (true, false) => {
+            "forced-color-adjust: none; background: Highlight; border: 1px solid Highlight;"
+        }
+        (false, false) => {
+            "forced-color-adjust: none; background: Canvas; border: 1px solid ButtonText;"
+        }
+    }
+}
+
+/// Thumb styles in forced colors mode, contrasting with the track
+pub fn switch_thumb_forced_colors(checked: bool, disabled: bool) -> &'static str {
+    match (checked, disabled) {
+        (_, true) => "forced-color-adjust: none; background: GrayText;",
+        (true, false) => "forced-color-adjust: none; background: HighlightText;",
+        (false, false) => "forced-color-adjust: none; background: ButtonText;",
+    }
+}
+
+/// State of the enclosing Switch, for its thumb
+#[derive(Debug, Clone, Copy)]
+struct SwitchContext {
+    checked: bool,
+    disabled: bool,
+}
+
 /// Switch root component
+///
+/// In forced colors mode the track and thumb switch to system colors and
+/// the track gets a border, so the on state stays visible.
 #[component]
 pub fn Switch(
     /// Whether the switch is on
@@ -68,6 +102,15 @@ pub fn Switch(
 ) -> impl IntoView {
     let _switch_id = generate_id("switch");
     let _thumb_id = generate_id("switch-thumb");
+    provide_context(SwitchContext { checked, disabled });
+    let forced_colors = use_forced_colors();
+    let style = move || {
+        forced_colors_style(
+            style.as_deref(),
+            forced_colors.get(),
+            switch_forced_colors(checked, disabled),
+        )
+    };
 
     // Build data attributes for styling
     let data_variant = variant.as_str();
@@ -131,6 +174,18 @@ pub fn SwitchThumb(
     let base_classes = "radix-switch-thumb";
     let combined_class = merge_optional_classes(Some(base_classes), class.as_deref())
         .unwrap_or_else(|| base_classes.to_string());
+    let state = use_context::<SwitchContext>().unwrap_or(SwitchContext {
+        checked: false,
+        disabled: false,
+    });
+    let forced_colors = use_forced_colors();
+    let style = move || {
+        forced_colors_style(
+            style.as_deref(),
+            forced_colors.get(),
+            switch_thumb_forced_colors(state.checked, state.disabled),
+        )
+    };
 
     view! {
         <div
@@ -298,6 +353,17 @@ use crate::utils::{merge_optional_classes, generate_id};
         });
     }
 
+    #[test]
+    fn test_switch_forced_colors() {
+        use super::{switch_forced_colors, switch_thumb_forced_colors};
+
+        assert!(switch_forced_colors(true, false).contains("background: Highlight"));
+        assert!(switch_forced_colors(false, false).contains("border: 1px solid ButtonText"));
+        assert!(switch_forced_colors(true, true).contains("GrayText"));
+        assert!(switch_thumb_forced_colors(true, false).contains("HighlightText"));
+        assert!(switch_thumb_forced_colors(false, false).contains("ButtonText"));
+    }
+
     // 6. Edge Case Tests
     #[test]
     fn test_switch_edge_cases() {
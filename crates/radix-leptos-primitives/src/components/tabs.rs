@@ -1,6 +1,7 @@
 use leptos::children::Children;
 use leptos::prelude::*;
 use crate::utils::{merge_optional_classes, generate_id};
+use radix_leptos_core::use_focus_visible;
 
 /// Tabs component with proper accessibility and styling variants
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -164,6 +165,8 @@ pub fn TabsTrigger(
     children: Children,
 ) -> impl IntoView {
     let __trigger_id = generate_id(&format!("tab-trigger-{}", value));
+    let trigger_ref = NodeRef::<leptos::html::Button>::new();
+    let focus_visible = use_focus_visible(trigger_ref);
 
     let base_classes = "radix-tabs-trigger";
     let combined_class = merge_optional_classes(Some(base_classes), class.as_deref())
@@ -188,10 +191,12 @@ pub fn TabsTrigger(
 
     view! {
         <button
+            node_ref=trigger_ref
             class=combined_class
             style=style
             data-value=value.clone()
             data-disabled=disabled
+            data-focus-visible=move || focus_visible.get().then_some("")
             role="tab"
             aria-selected="false"
             aria-controls="tab-content-".to_string() + &value.clone()
@@ -0,0 +1,147 @@
+use super::landmark::use_landmark_heading_id;
+use crate::theming::TextSize;
+use crate::utils::merge_classes;
+use leptos::children::Children;
+use leptos::prelude::*;
+
+/// Element a [`Text`] renders as
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextElement {
+    #[default]
+    Span,
+    Paragraph,
+    Div,
+    Label,
+}
+
+/// Size of a heading of `level` (1 to 6) when none is given
+pub fn heading_size(level: u8) -> TextSize {
+    match level {
+        0 | 1 => TextSize::Xl4,
+        2 => TextSize::Xl3,
+        3 => TextSize::Xl2,
+        4 => TextSize::Xl,
+        5 => TextSize::Lg,
+        _ => TextSize::Base,
+    }
+}
+
+fn typography_style(size: TextSize, style: Option<&str>) -> String {
+    format!("font-size: {}; {}", size.css_value(), style.unwrap_or(""))
+}
+
+/// Text component - Body text on the theme's type scale
+///
+/// The size reads the theme's `--font-size-*` variables, which are fluid
+/// when the theme uses a [`TypographySystem`](crate::theming::TypographySystem).
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn Intro() -> impl IntoView {
+///     view! {
+///         <Text size=TextSize::Lg element=TextElement::Paragraph>
+///             "Welcome back"
+///         </Text>
+///     }
+/// }
+/// ```
+#[component]
+pub fn Text(
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    /// Step of the type scale, the base size by default
+    #[prop(optional, into)]
+    size: Signal<TextSize>,
+    /// Element to render, a `span` by default
+    #[prop(optional)]
+    element: TextElement,
+    #[prop(optional)] children: Option<Children>,
+) -> impl IntoView {
+    let class = move || {
+        merge_classes(vec![
+            "text",
+            &format!("text-{}", size.get().as_str()),
+            class.as_deref().unwrap_or(""),
+        ])
+    };
+    let style = move || typography_style(size.get(), style.as_deref());
+    let children = children.map(|c| c());
+
+    match element {
+        TextElement::Span => view! { <span class=class style=style>{children}</span> }.into_any(),
+        TextElement::Paragraph => view! { <p class=class style=style>{children}</p> }.into_any(),
+        TextElement::Div => view! { <div class=class style=style>{children}</div> }.into_any(),
+        TextElement::Label => {
+            view! { <label class=class style=style>{children}</label> }.into_any()
+        }
+    }
+}
+
+/// Heading component - Section headings on the theme's type scale
+///
+/// Renders `h1` to `h6` for `level`, sized by [`heading_size`] unless
+/// `size` is given, so the visual size can differ from the document outline.
+/// The first heading inside a [`Section`](super::Section), [`Nav`](super::Nav)
+/// or [`Aside`](super::Aside) names it.
+#[component]
+pub fn Heading(
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    /// Heading level from 1 to 6, 1 by default
+    #[prop(optional)]
+    level: Option<u8>,
+    /// Step of the type scale, overriding the level's
+    #[prop(optional, into)]
+    size: MaybeProp<TextSize>,
+    #[prop(optional)] children: Option<Children>,
+) -> impl IntoView {
+    let level = level.unwrap_or(1).clamp(1, 6);
+    let id = use_landmark_heading_id();
+    let size = move || size.get().unwrap_or_else(|| heading_size(level));
+    let class = move || {
+        merge_classes(vec![
+            "heading",
+            &format!("heading-{}", level),
+            &format!("text-{}", size().as_str()),
+            class.as_deref().unwrap_or(""),
+        ])
+    };
+    let style = move || {
+        format!(
+            "line-height: var(--line-height-tight, 1.25); {}",
+            typography_style(size(), style.as_deref())
+        )
+    };
+    let children = children.map(|c| c());
+
+    match level {
+        1 => view! { <h1 id=id class=class style=style>{children}</h1> }.into_any(),
+        2 => view! { <h2 id=id class=class style=style>{children}</h2> }.into_any(),
+        3 => view! { <h3 id=id class=class style=style>{children}</h3> }.into_any(),
+        4 => view! { <h4 id=id class=class style=style>{children}</h4> }.into_any(),
+        5 => view! { <h5 id=id class=class style=style>{children}</h5> }.into_any(),
+        _ => view! { <h6 id=id class=class style=style>{children}</h6> }.into_any(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_heading_sizes_follow_levels() {
+        assert_eq!(heading_size(1), TextSize::Xl4);
+        assert_eq!(heading_size(3), TextSize::Xl2);
+        assert_eq!(heading_size(6), TextSize::Base);
+        assert!((1..6).all(|level| heading_size(level).step() > heading_size(level + 1).step()));
+
+        let style = typography_style(TextSize::Lg, Some("color: red;"));
+        assert!(style.starts_with("font-size: var(--font-size-lg, clamp("));
+        assert!(style.ends_with("; color: red;"));
+    }
+}
@@ -1,3 +1,4 @@
+use crate::i18n::{use_i18n, MessageKey};
 use leptos::callback::Callback;
 use leptos::children::Children;
 use leptos::prelude::*;
@@ -21,7 +22,8 @@ pub fn TimePicker(
     #[prop(optional)] on_validation: Option<Callback<TimeValidation>>,
 ) -> impl IntoView {
     let value = value.unwrap_or_default();
-    let placeholder = placeholder.unwrap_or_else(|| "Select time".to_string());
+    let placeholder =
+        placeholder.unwrap_or_else(|| use_i18n().text(MessageKey::TimePickerPlaceholder));
     let min_time = min_time.unwrap_or_default();
     let max_time = max_time.unwrap_or_default();
     let disabled = disabled.unwrap_or(false);
@@ -46,7 +48,7 @@ pub fn TimePicker(
             class=class
             style=style
             role="combobox"
-            aria-label="Time picker"
+            aria-label=use_i18n().text(MessageKey::TimePickerLabel)
             data-format=format.as_str()
             data-step=step
             data-min-time=min_time
@@ -120,7 +122,7 @@ pub fn TimePickerInput(
             on:change=handle_change
             on:focus=handle_focus
             on:blur=handle_blur
-            aria-label="Time input"
+            aria-label=use_i18n().text(MessageKey::TimePickerInput)
         />
     }
 }
@@ -174,7 +176,7 @@ pub fn TimePickerDropdown(
             class=class
             style=style
             role="listbox"
-            aria-label="Time options"
+            aria-label=use_i18n().text(MessageKey::TimePickerOptions)
             data-format=format.as_str()
             data-step=step
         >
@@ -236,7 +238,7 @@ pub fn TimePickerGrid(
             class=class
             style=style
             role="grid"
-            aria-label="Time selection grid"
+            aria-label=use_i18n().text(MessageKey::TimePickerGrid)
         >
             {time_options.into_iter().map(|time| {
                 let time_clone = time.clone();
@@ -1,3 +1,4 @@
+use crate::i18n::{use_i18n, MessageArgs, MessageKey};
 use crate::utils::{merge_classes, generate_id};
 use leptos::callback::Callback;
 use leptos::children::Children;
@@ -34,7 +35,7 @@ pub fn Timeline(
             class=class
             style=style
             role="list"
-            aria-label="Timeline"
+            aria-label=use_i18n().text(MessageKey::TimelineLabel)
             data-event-count=events.len()
             data-orientation=orientation.to_string()
             data-show-dates=show_dates
@@ -178,7 +179,10 @@ pub fn TimelineItem(
             class=class
             style=style
             role="listitem"
-            aria-label=format!("Timeline event: {}", event.title)
+            aria-label=use_i18n().format(
+                MessageKey::TimelineEvent,
+                &MessageArgs::new().with("title", event.title.as_str()),
+            )
             data-event-id=event.id
             data-position=position
             data-date=event.date
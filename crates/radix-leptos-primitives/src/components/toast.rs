@@ -1,9 +1,16 @@
+use crate::i18n::{use_i18n, MessageKey};
+use crate::theming::PresenceAnimation;
 use crate::utils::merge_classes;
 use leptos::callback::Callback;
 use leptos::children::Children;
 use leptos::prelude::*;
+use radix_leptos_core::{channels, use_announce_on, use_presence, AriaLive};
 
 /// Toast component - Enhanced notification system with positioning
+///
+/// The title and description are announced through the closest
+/// AnnouncerProvider when the toast opens, assertively for errors. Toasts
+/// without them are live regions of their own instead.
 #[component]
 pub fn Toast(
     #[prop(optional)] class: Option<String>,
@@ -17,6 +24,9 @@ pub fn Toast(
     #[prop(optional)] dismissible: Option<bool>,
     #[prop(optional)] on_dismiss: Option<Callback<()>>,
     #[prop(optional)] on_action: Option<Callback<()>>,
+    /// Whether the toast is shown; it animates out when this turns false
+    #[prop(optional, into)]
+    open: MaybeProp<bool>,
 ) -> impl IntoView {
     let title = title.unwrap_or_default();
     let description = description.unwrap_or_default();
@@ -40,13 +50,31 @@ pub fn Toast(
         .to_vec(),
     );
 
+    let node_ref = NodeRef::<leptos::html::Div>::new();
+    let presence = use_presence(Signal::derive(move || open.get().unwrap_or(true)), node_ref);
+
+    let announcement = toast_announcement(&title, &description);
+    let announced = announcement.is_some();
+    use_announce_on(
+        Signal::derive(move || {
+            announcement
+                .clone()
+                .filter(|_| open.get().unwrap_or(true))
+        }),
+        variant.channel(),
+    );
+
     view! {
         <div
+            node_ref=node_ref
             class=class
             style=style
-            role="alert"
-            aria-live="polite"
+            role="status"
+            aria-live=if announced { "off" } else { "polite" }
             aria-atomic="true"
+            data-state=move || presence.state.get().as_str()
+            data-motion=PresenceAnimation::Fade.as_str()
+            hidden=move || !presence.is_present.get()
             data-duration=duration
             data-position=position.to_string()
             data-variant=variant.to_string()
@@ -84,7 +112,7 @@ pub fn ToastProvider(
             class=class
             style=style
             role="region"
-            aria-label="Toast notifications"
+            aria-label=use_i18n().text(MessageKey::ToastRegion)
             data-max-toasts=max_toasts
             data-default-duration=default_duration
             data-position=position.to_string()
@@ -150,7 +178,7 @@ pub fn ToastAction(
     #[prop(optional)] label: Option<String>,
     #[prop(optional)] on_click: Option<Callback<()>>,
 ) -> impl IntoView {
-    let label = label.unwrap_or_else(|| "Action".to_string());
+    let label = label.unwrap_or_else(|| use_i18n().text(MessageKey::ToastAction));
 
     let class = merge_classes(["toast-action", class.as_deref().unwrap_or("")].to_vec());
 
@@ -194,7 +222,7 @@ pub fn ToastClose(
             class=class
             style=style
             type="button"
-            aria-label="Close toast"
+            aria-label=use_i18n().text(MessageKey::ToastClose)
             on:click=handle_click
         >
             {children.map(|c| c())}
@@ -233,6 +261,37 @@ impl ToastVariant {
             ToastVariant::Info => "info",
         }
     }
+
+    /// How urgently the toast is announced
+    pub fn politeness(&self) -> AriaLive {
+        match self {
+            ToastVariant::Error => AriaLive::Assertive,
+            _ => AriaLive::Polite,
+        }
+    }
+
+    /// Announcer channel the toast is announced on, assertive for errors
+    pub fn channel(&self) -> &'static str {
+        match self {
+            ToastVariant::Error => channels::ALERT,
+            _ => channels::TOAST,
+        }
+    }
+}
+
+/// What screen readers hear when a toast opens, `None` for toasts with
+/// neither title nor description
+pub fn toast_announcement(title: &str, description: &str) -> Option<String> {
+    let (title, description) = (title.trim(), description.trim());
+    match (title.is_empty(), description.is_empty()) {
+        (true, true) => None,
+        (false, true) => Some(title.to_string()),
+        (true, false) => Some(description.to_string()),
+        (false, false) if title.ends_with(['.', '!', '?']) => {
+            Some(format!("{} {}", title, description))
+        }
+        (false, false) => Some(format!("{}. {}", title, description)),
+    }
 }
 
 /// Toast Position enum
@@ -295,7 +354,7 @@ pub fn ToastViewport(
             class=class
             style=style
             role="region"
-            aria-label="Toast viewport"
+            aria-label=use_i18n().text(MessageKey::ToastViewport)
             data-position=position.to_string()
         >
             {children.map(|c| c())}
@@ -324,6 +383,26 @@ mod tests {
     #[test]
     fn test_toast_variant() {}
     #[test]
+    fn test_toast_announcement() {
+        use super::{toast_announcement, ToastVariant};
+        use radix_leptos_core::AriaLive;
+
+        assert_eq!(toast_announcement("", " "), None);
+        assert_eq!(toast_announcement("Saved", ""), Some("Saved".to_string()));
+        assert_eq!(
+            toast_announcement("Saved", "Your changes are live"),
+            Some("Saved. Your changes are live".to_string())
+        );
+        assert_eq!(
+            toast_announcement("Upload failed!", "Try again"),
+            Some("Upload failed! Try again".to_string())
+        );
+        assert_eq!(ToastVariant::Error.politeness(), AriaLive::Assertive);
+        assert_eq!(ToastVariant::Success.politeness(), AriaLive::Polite);
+        assert_eq!(ToastVariant::Error.channel(), radix_leptos_core::channels::ALERT);
+        assert_eq!(ToastVariant::Info.channel(), radix_leptos_core::channels::TOAST);
+    }
+    #[test]
     fn test_toast_position() {}
     #[test]
     fn test_toast_duration() {}
@@ -2,6 +2,7 @@ use crate::utils::merge_classes;
 use leptos::callback::Callback;
 use leptos::children::Children;
 use leptos::prelude::*;
+use radix_leptos_core::use_focus_visible;
 
 /// Toggle component for toggle button functionality
 ///
@@ -22,6 +23,8 @@ pub fn Toggle(
     let variant = variant.unwrap_or_default();
     let size = size.unwrap_or_default();
     let disabled = disabled.unwrap_or(false);
+    let button_ref = NodeRef::<leptos::html::Button>::new();
+    let focus_visible = use_focus_visible(button_ref);
     let (is_pressed, set_is_pressed) =
         signal(pressed.unwrap_or_else(|| default_pressed.unwrap_or(false)));
 
@@ -67,11 +70,13 @@ pub fn Toggle(
 
     view! {
         <button
+            node_ref=button_ref
             class=class
             style=style
             disabled=disabled
             on:click=handle_click
             on:keydown=handle_keydown
+            data-focus-visible=move || focus_visible.get().then_some("")
             aria-pressed=is_pressed.get()
             type="button"
         >
@@ -1,7 +1,9 @@
+use crate::components::roving_focus::{roving_tab_index, RovingFocusGroup};
 use crate::utils::merge_classes;
 use leptos::callback::Callback;
 use leptos::children::Children;
 use leptos::prelude::*;
+use radix_leptos_core::use_focus_visible;
 
 /// Toggle Group component for group of toggle buttons
 ///
@@ -80,6 +82,8 @@ pub fn ToggleGroupItem(
     let value = value.unwrap_or_default();
 
     let class = merge_classes(vec!["toggle-group-item"]);
+    let item_ref = NodeRef::<leptos::html::Button>::new();
+    let focus_visible = use_focus_visible(item_ref);
 
     let handle_keydown = move |ev: web_sys::KeyboardEvent| {
         if !disabled && (ev.key() == "Enter" || ev.key() == " ") {
@@ -100,6 +104,7 @@ pub fn ToggleGroupItem(
 
     view! {
         <button
+            node_ref=item_ref
             class=class
             style=style
             disabled=disabled
@@ -107,6 +112,9 @@ pub fn ToggleGroupItem(
             on:keydown=handle_keydown
             data-value=value
             type="button"
+            tabindex=roving_tab_index(None)
+            data-roving-focus-item=RovingFocusGroup::item_attribute()
+            data-focus-visible=move || focus_visible.get().then_some("")
         >
             {children.map(|c| c())}
         </button>
@@ -1,11 +1,18 @@
+use crate::components::roving_focus::{
+    roving_tab_index, Direction, RovingFocusGroup, RovingOrientation,
+};
 use crate::utils::merge_classes;
 use leptos::callback::Callback;
 use leptos::children::Children;
+use leptos::html;
 use leptos::prelude::*;
 
 /// Toolbar component for action toolbar functionality
 ///
-/// Provides accessible toolbar with keyboard support and ARIA attributes
+/// Provides accessible toolbar with keyboard support and ARIA attributes.
+/// The toolbar is a single tab stop: arrow keys, Home and End move focus
+/// between its buttons, toggle items, ToggleGroup items, Select triggers and
+/// DropdownMenu triggers alike.
 #[component]
 pub fn Toolbar(
     #[prop(optional)] class: Option<String>,
@@ -13,9 +20,26 @@ pub fn Toolbar(
     #[prop(optional)] children: Option<Children>,
     #[prop(optional)] orientation: Option<ToolbarOrientation>,
     #[prop(optional)] disabled: Option<bool>,
+    /// Wrap focus from the last item to the first (default: true)
+    #[prop(optional)]
+    loop_focus: Option<bool>,
+    /// Reading direction; in right-to-left toolbars ArrowLeft moves forward
+    #[prop(optional)]
+    dir: Option<Direction>,
 ) -> impl IntoView {
     let orientation = orientation.unwrap_or_default();
+    let dir = dir.unwrap_or_default();
     let disabled = disabled.unwrap_or(false);
+    let node_ref = NodeRef::<html::Div>::new();
+    let roving = RovingFocusGroup {
+        orientation: match orientation {
+            ToolbarOrientation::Horizontal => RovingOrientation::Horizontal,
+            ToolbarOrientation::Vertical => RovingOrientation::Vertical,
+        },
+        looping: loop_focus.unwrap_or(true),
+        dir,
+    }
+    .provide(node_ref);
 
     let class = merge_classes(vec![
         "toolbar",
@@ -29,6 +53,10 @@ pub fn Toolbar(
             style=style
             role="toolbar"
             aria-orientation=orientation.to_aria()
+            dir=dir.as_str()
+            node_ref=node_ref
+            on:keydown=move |ev| roving.on_keydown.run(ev)
+            on:focusin=move |ev| roving.on_focusin.run(ev)
         >
             {children.map(|c| c())}
         </div>
@@ -133,6 +161,8 @@ pub fn ToolbarToggleItem(
             on:keydown=handle_keydown
             data-value=value
             type="button"
+            tabindex=roving_tab_index(None)
+            data-roving-focus-item=RovingFocusGroup::item_attribute()
         >
             {children.map(|c| c())}
         </button>
@@ -210,6 +240,8 @@ pub fn ToolbarButton(
             on:click=handle_click
             on:keydown=handle_keydown
             type="button"
+            tabindex=roving_tab_index(None)
+            data-roving-focus-item=RovingFocusGroup::item_attribute()
         >
             {children.map(|c| c())}
         </button>
@@ -0,0 +1,611 @@
+use crate::i18n::{use_i18n, MessageArgs, MessageCatalog, MessageKey};
+use crate::utils::{generate_id, merge_classes};
+use crate::{PopoverAlign, PopoverSide};
+use leptos::callback::Callback;
+use leptos::ev;
+use leptos::html::ElementType;
+use leptos::prelude::*;
+use send_wrapper::SendWrapper;
+use wasm_bindgen::JsCast;
+
+/// Tour component - Guided onboarding tour with a spotlight and step popover
+///
+/// Each [`TourStep`] targets an element (by CSS selector or `NodeRef`). While
+/// the tour is open, the target is highlighted by a spotlight cut out of a
+/// dimming mask and the step is shown in a popover next to it. Arrow keys
+/// move between steps and Escape skips the tour. With a `storage_key` the
+/// tour is not shown again once completed or skipped.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn Onboarding() -> impl IntoView {
+///     let open = RwSignal::new(true);
+///     let steps = vec![
+///         TourStep::new("#search", "Search", "Find anything from here."),
+///         TourStep::new("#new-project", "Create", "Start a new project.")
+///             .side(PopoverSide::Right),
+///     ];
+///
+///     view! {
+///         <Tour steps=steps open=open storage_key="onboarding-v1".to_string() />
+///     }
+/// }
+/// ```
+#[component]
+pub fn Tour(
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    /// Steps of the tour, in order
+    steps: Vec<TourStep>,
+    /// Whether the tour is shown
+    #[prop(optional)]
+    open: Option<RwSignal<bool>>,
+    /// Index of the current step
+    #[prop(optional)]
+    current: Option<RwSignal<usize>>,
+    /// localStorage key used to remember that the tour was finished
+    #[prop(optional)]
+    storage_key: Option<String>,
+    /// Space between the target and the spotlight edge in pixels
+    #[prop(optional)]
+    spotlight_padding: Option<f64>,
+    /// Opacity of the dimming mask (0.0 - 1.0)
+    #[prop(optional)]
+    mask_opacity: Option<f64>,
+    /// Button labels, the provided message catalog's by default
+    #[prop(optional)]
+    labels: Option<TourLabels>,
+    /// Called with the new index whenever the step changes
+    #[prop(optional)]
+    on_step_change: Option<Callback<usize>>,
+    /// Called when the last step is finished
+    #[prop(optional)]
+    on_complete: Option<Callback<()>>,
+    /// Called with the current index when the tour is skipped
+    #[prop(optional)]
+    on_skip: Option<Callback<usize>>,
+) -> impl IntoView {
+    let open = open.unwrap_or_else(|| RwSignal::new(true));
+    let current = current.unwrap_or_else(|| RwSignal::new(0));
+    let spotlight_padding = spotlight_padding.unwrap_or(8.0);
+    let mask_opacity = mask_opacity.unwrap_or(0.5).clamp(0.0, 1.0);
+    let catalog = use_i18n();
+    let labels = labels.unwrap_or_else(|| TourLabels::from_catalog(&catalog));
+    let catalog = StoredValue::new(catalog);
+    let total = steps.len();
+    let steps = StoredValue::new(steps);
+    let title_id = generate_id("tour-title");
+    let description_id = generate_id("tour-description");
+
+    if total == 0 || storage_key.as_deref().is_some_and(is_tour_completed) {
+        open.set(false);
+    }
+
+    let finish = {
+        let storage_key = storage_key.clone();
+        move |skipped: bool| {
+            open.set(false);
+            if let Some(key) = storage_key.as_deref() {
+                mark_tour_completed(key);
+            }
+            if skipped {
+                if let Some(on_skip) = on_skip {
+                    on_skip.run(current.get_untracked());
+                }
+            } else if let Some(on_complete) = on_complete {
+                on_complete.run(());
+            }
+        }
+    };
+
+    let go_to = move |index: usize| {
+        current.set(index);
+        if let Some(on_step_change) = on_step_change {
+            on_step_change.run(index);
+        }
+    };
+
+    let handle_action = {
+        let finish = finish.clone();
+        move |action: TourAction| match action {
+            TourAction::Next => match next_tour_step(current.get_untracked(), total) {
+                Some(index) => go_to(index),
+                None => finish(false),
+            },
+            TourAction::Previous => {
+                if let Some(index) = previous_tour_step(current.get_untracked()) {
+                    go_to(index);
+                }
+            }
+            TourAction::Skip => finish(true),
+        }
+    };
+
+    // Keyboard navigation while the tour is open
+    let keyboard_action = handle_action.clone();
+    let keydown = window_event_listener(ev::keydown, move |event| {
+        if !open.get_untracked() {
+            return;
+        }
+        if let Some(action) = TourAction::from_key(&event.key()) {
+            event.prevent_default();
+            keyboard_action(action);
+        }
+    });
+    // Re-measure the target when the layout changes
+    let layout = RwSignal::new(0_u32);
+    let resize = window_event_listener(ev::resize, move |_| layout.update(|n| *n += 1));
+    let scroll = window_event_listener(ev::scroll, move |_| layout.update(|n| *n += 1));
+    on_cleanup(move || {
+        keydown.remove();
+        resize.remove();
+        scroll.remove();
+    });
+
+    let target_rect = Memo::new(move |_| {
+        layout.track();
+        if !open.get() {
+            return None;
+        }
+        let index = current.get();
+        steps.with_value(|steps| steps.get(index).and_then(|step| step.target.rect()))
+    });
+
+    let class = merge_classes(vec!["tour", class.as_deref().unwrap_or("")]);
+    let mask_style = format!(
+        "position: fixed; inset: 0; z-index: 1000; {}",
+        style.unwrap_or_default()
+    );
+
+    move || {
+        if !open.get() {
+            return ().into_any();
+        }
+        let index = current.get().min(total.saturating_sub(1));
+        let step = steps.with_value(|steps| steps[index].clone());
+        let rect = target_rect.get();
+        let spotlight = rect.map(|rect| rect.inflate(spotlight_padding));
+        let popover_style = match spotlight {
+            Some(spotlight) => tour_popover_style(spotlight, step.side, step.align, step.offset),
+            None => "position: fixed; top: 50%; left: 50%; transform: translate(-50%, -50%);"
+                .to_string(),
+        };
+        let spotlight_style =
+            spotlight.map(|spotlight| tour_spotlight_style(spotlight, mask_opacity));
+        let is_first = index == 0;
+        let is_last = index + 1 == total;
+        let on_previous = handle_action.clone();
+        let on_next = handle_action.clone();
+        let on_skip = handle_action.clone();
+
+        view! {
+            <div class=class.clone() style=mask_style.clone() data-state="open">
+                {match spotlight_style {
+                    Some(spotlight_style) => view! {
+                        <div class="tour-spotlight" style=spotlight_style aria-hidden="true"></div>
+                    }
+                    .into_any(),
+                    None => view! {
+                        <div
+                            class="tour-mask"
+                            style=format!("position: absolute; inset: 0; background: rgba(0, 0, 0, {});", mask_opacity)
+                            aria-hidden="true"
+                        ></div>
+                    }
+                    .into_any(),
+                }}
+                <div
+                    class=merge_classes(vec!["tour-popover", step.side.to_class(), step.align.to_class()])
+                    style=popover_style
+                    role="dialog"
+                    aria-modal="true"
+                    aria-labelledby=title_id.clone()
+                    aria-describedby=description_id.clone()
+                    data-side=step.side.to_aria()
+                    data-align=step.align.to_aria()
+                >
+                    <h2 id=title_id.clone() class="tour-title">{step.title.clone()}</h2>
+                    <p id=description_id.clone() class="tour-description">{step.description.clone()}</p>
+                    <div class="tour-footer">
+                        <span class="tour-progress" aria-live="polite">
+                            {catalog.with_value(|catalog| {
+                                let args = MessageArgs::new().with("step", index + 1).with("count", total);
+                                catalog.format(MessageKey::TourProgress, &args)
+                            })}
+                        </span>
+                        <button type="button" class="tour-skip" on:click=move |_| on_skip(TourAction::Skip)>
+                            {labels.skip.clone()}
+                        </button>
+                        <button
+                            type="button"
+                            class="tour-previous"
+                            disabled=is_first
+                            on:click=move |_| on_previous(TourAction::Previous)
+                        >
+                            {labels.previous.clone()}
+                        </button>
+                        <button type="button" class="tour-next" on:click=move |_| on_next(TourAction::Next)>
+                            {if is_last { labels.done.clone() } else { labels.next.clone() }}
+                        </button>
+                    </div>
+                </div>
+            </div>
+        }
+        .into_any()
+    }
+}
+
+/// A single step of a [`Tour`]
+#[derive(Clone)]
+pub struct TourStep {
+    pub target: TourTarget,
+    pub title: String,
+    pub description: String,
+    pub side: PopoverSide,
+    pub align: PopoverAlign,
+    /// Distance between the spotlight and the popover in pixels
+    pub offset: f64,
+}
+
+impl TourStep {
+    /// Step targeting the first element matching a CSS selector
+    pub fn new(selector: &str, title: &str, description: &str) -> Self {
+        Self::with_target(
+            TourTarget::Selector(selector.to_string()),
+            title,
+            description,
+        )
+    }
+
+    pub fn with_target(target: TourTarget, title: &str, description: &str) -> Self {
+        Self {
+            target,
+            title: title.to_string(),
+            description: description.to_string(),
+            side: PopoverSide::Bottom,
+            align: PopoverAlign::Center,
+            offset: 12.0,
+        }
+    }
+
+    pub fn side(mut self, side: PopoverSide) -> Self {
+        self.side = side;
+        self
+    }
+
+    pub fn align(mut self, align: PopoverAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    pub fn offset(mut self, offset: f64) -> Self {
+        self.offset = offset;
+        self
+    }
+}
+
+/// Element highlighted by a [`TourStep`]
+#[derive(Clone)]
+pub enum TourTarget {
+    /// First element matching a CSS selector
+    Selector(String),
+    /// Element resolved from a `NodeRef`; see [`TourTarget::node_ref`]
+    Element(Signal<Option<TourElement>>),
+}
+
+/// DOM element held by a reactive [`TourTarget`]
+pub type TourElement = SendWrapper<web_sys::Element>;
+
+impl TourTarget {
+    /// Target the element bound to a `NodeRef`
+    pub fn node_ref<E>(node_ref: NodeRef<E>) -> Self
+    where
+        E: ElementType + 'static,
+        E::Output: JsCast + Clone + 'static,
+    {
+        TourTarget::Element(Signal::derive(move || {
+            node_ref
+                .get()
+                .map(|element| SendWrapper::new(element.unchecked_into::<web_sys::Element>()))
+        }))
+    }
+
+    /// Resolve the target element in the document
+    pub fn element(&self) -> Option<web_sys::Element> {
+        match self {
+            TourTarget::Selector(selector) => web_sys::window()?
+                .document()?
+                .query_selector(selector)
+                .ok()
+                .flatten(),
+            TourTarget::Element(element) => element.get().map(|element| element.take()),
+        }
+    }
+
+    /// Viewport rectangle of the target, scrolling it into view first
+    fn rect(&self) -> Option<TourRect> {
+        let element = self.element()?;
+        element.scroll_into_view_with_bool(false);
+        let rect = element.get_bounding_client_rect();
+        Some(TourRect {
+            top: rect.top(),
+            left: rect.left(),
+            width: rect.width(),
+            height: rect.height(),
+        })
+    }
+}
+
+/// Button labels of a [`Tour`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct TourLabels {
+    pub next: String,
+    pub previous: String,
+    pub skip: String,
+    pub done: String,
+}
+
+impl TourLabels {
+    /// Labels translated by `catalog`
+    pub fn from_catalog(catalog: &MessageCatalog) -> Self {
+        Self {
+            next: catalog.text(MessageKey::TourNext),
+            previous: catalog.text(MessageKey::TourPrevious),
+            skip: catalog.text(MessageKey::TourSkip),
+            done: catalog.text(MessageKey::TourDone),
+        }
+    }
+}
+
+impl Default for TourLabels {
+    fn default() -> Self {
+        Self::from_catalog(&MessageCatalog::default())
+    }
+}
+
+/// Navigation requested by the user
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TourAction {
+    Next,
+    Previous,
+    Skip,
+}
+
+impl TourAction {
+    /// Map a `KeyboardEvent::key` to an action
+    pub fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "ArrowRight" | "Enter" => Some(TourAction::Next),
+            "ArrowLeft" => Some(TourAction::Previous),
+            "Escape" => Some(TourAction::Skip),
+            _ => None,
+        }
+    }
+}
+
+/// Viewport rectangle in pixels
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TourRect {
+    pub top: f64,
+    pub left: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl TourRect {
+    pub fn inflate(self, padding: f64) -> Self {
+        Self {
+            top: self.top - padding,
+            left: self.left - padding,
+            width: self.width + padding * 2.0,
+            height: self.height + padding * 2.0,
+        }
+    }
+}
+
+/// Index after `current`, or `None` when the tour is finished
+pub fn next_tour_step(current: usize, total: usize) -> Option<usize> {
+    (current + 1 < total).then_some(current + 1)
+}
+
+/// Index before `current`, or `None` on the first step
+pub fn previous_tour_step(current: usize) -> Option<usize> {
+    current.checked_sub(1)
+}
+
+/// Spotlight hole; the huge spread shadow dims everything around it
+pub fn tour_spotlight_style(rect: TourRect, mask_opacity: f64) -> String {
+    format!(
+        "position: absolute; top: {}px; left: {}px; width: {}px; height: {}px; border-radius: 6px; box-shadow: 0 0 0 9999px rgba(0, 0, 0, {}); pointer-events: none;",
+        rect.top, rect.left, rect.width, rect.height, mask_opacity
+    )
+}
+
+/// Fixed position of the popover relative to the spotlight
+pub fn tour_popover_style(
+    rect: TourRect,
+    side: PopoverSide,
+    align: PopoverAlign,
+    offset: f64,
+) -> String {
+    let (cross_start, cross_size, align_percent) = match side {
+        PopoverSide::Top | PopoverSide::Bottom => (rect.left, rect.width, align_fraction(align)),
+        PopoverSide::Left | PopoverSide::Right => (rect.top, rect.height, align_fraction(align)),
+    };
+    let cross = cross_start + cross_size * align_percent;
+    let translate = -100.0 * align_percent;
+
+    match side {
+        PopoverSide::Bottom => format!(
+            "position: fixed; top: {}px; left: {}px; transform: translateX({}%);",
+            rect.top + rect.height + offset,
+            cross,
+            translate
+        ),
+        PopoverSide::Top => format!(
+            "position: fixed; top: {}px; left: {}px; transform: translate({}%, -100%);",
+            rect.top - offset,
+            cross,
+            translate
+        ),
+        PopoverSide::Right => format!(
+            "position: fixed; top: {}px; left: {}px; transform: translateY({}%);",
+            cross,
+            rect.left + rect.width + offset,
+            translate
+        ),
+        PopoverSide::Left => format!(
+            "position: fixed; top: {}px; left: {}px; transform: translate(-100%, {}%);",
+            cross,
+            rect.left - offset,
+            translate
+        ),
+    }
+}
+
+fn align_fraction(align: PopoverAlign) -> f64 {
+    match align {
+        PopoverAlign::Start => 0.0,
+        PopoverAlign::Center => 0.5,
+        PopoverAlign::End => 1.0,
+    }
+}
+
+fn tour_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok().flatten()
+}
+
+/// Whether the tour stored under `key` was completed or skipped
+pub fn is_tour_completed(key: &str) -> bool {
+    tour_storage()
+        .and_then(|storage| storage.get_item(key).ok().flatten())
+        .is_some_and(|value| value == "completed")
+}
+
+/// Remember that the tour stored under `key` was completed
+pub fn mark_tour_completed(key: &str) {
+    if let Some(storage) = tour_storage() {
+        let _ = storage.set_item(key, "completed");
+    }
+}
+
+/// Forget the completion state so the tour is shown again
+pub fn reset_tour(key: &str) {
+    if let Some(storage) = tour_storage() {
+        let _ = storage.remove_item(key);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const RECT: TourRect = TourRect {
+        top: 100.0,
+        left: 200.0,
+        width: 80.0,
+        height: 40.0,
+    };
+
+    #[test]
+    fn test_tour_navigation() {
+        assert_eq!(next_tour_step(0, 3), Some(1));
+        assert_eq!(next_tour_step(2, 3), None);
+        assert_eq!(next_tour_step(0, 0), None);
+        assert_eq!(previous_tour_step(1), Some(0));
+        assert_eq!(previous_tour_step(0), None);
+    }
+
+    #[test]
+    fn test_tour_keyboard_actions() {
+        assert_eq!(TourAction::from_key("ArrowRight"), Some(TourAction::Next));
+        assert_eq!(TourAction::from_key("Enter"), Some(TourAction::Next));
+        assert_eq!(
+            TourAction::from_key("ArrowLeft"),
+            Some(TourAction::Previous)
+        );
+        assert_eq!(TourAction::from_key("Escape"), Some(TourAction::Skip));
+        assert_eq!(TourAction::from_key("a"), None);
+    }
+
+    #[test]
+    fn test_tour_step_builder() {
+        let step = TourStep::new("#save", "Save", "Saves the document")
+            .side(PopoverSide::Left)
+            .align(PopoverAlign::Start)
+            .offset(4.0);
+        assert!(matches!(step.target, TourTarget::Selector(ref s) if s == "#save"));
+        assert_eq!(step.side, PopoverSide::Left);
+        assert_eq!(step.align, PopoverAlign::Start);
+        assert_eq!(step.offset, 4.0);
+    }
+
+    #[test]
+    fn test_tour_labels_from_catalog() {
+        assert_eq!(TourLabels::default().previous, "Back");
+        let catalog = MessageCatalog::new("de")
+            .with_message(MessageKey::TourNext, "Weiter")
+            .with_message(MessageKey::TourDone, "Fertig");
+        let labels = TourLabels::from_catalog(&catalog);
+        assert_eq!(labels.next, "Weiter");
+        assert_eq!(labels.done, "Fertig");
+        assert_eq!(labels.skip, "Skip");
+    }
+
+    #[test]
+    fn test_tour_rect_inflate() {
+        assert_eq!(
+            RECT.inflate(8.0),
+            TourRect {
+                top: 92.0,
+                left: 192.0,
+                width: 96.0,
+                height: 56.0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_tour_popover_position() {
+        let bottom = tour_popover_style(RECT, PopoverSide::Bottom, PopoverAlign::Center, 10.0);
+        assert!(bottom.contains("top: 150px"));
+        assert!(bottom.contains("left: 240px"));
+        assert!(bottom.contains("translateX(-50%)"));
+
+        let right = tour_popover_style(RECT, PopoverSide::Right, PopoverAlign::Start, 10.0);
+        assert!(right.contains("top: 100px"));
+        assert!(right.contains("left: 290px"));
+
+        let top = tour_popover_style(RECT, PopoverSide::Top, PopoverAlign::End, 10.0);
+        assert!(top.contains("top: 90px"));
+        assert!(top.contains("left: 280px"));
+        assert!(top.contains("translate(-100%, -100%)"));
+    }
+
+    #[test]
+    fn test_tour_spotlight_style() {
+        let style = tour_spotlight_style(RECT, 0.4);
+        assert!(style.contains("top: 100px"));
+        assert!(style.contains("width: 80px"));
+        assert!(style.contains("rgba(0, 0, 0, 0.4)"));
+    }
+
+    proptest! {
+        #[test]
+        fn test_tour_navigation_stays_in_bounds(current in 0usize..50, total in 1usize..50) {
+            let current = current % total;
+            if let Some(next) = next_tour_step(current, total) {
+                prop_assert!(next < total);
+            }
+            if let Some(previous) = previous_tour_step(current) {
+                prop_assert!(previous < current);
+            }
+        }
+    }
+}
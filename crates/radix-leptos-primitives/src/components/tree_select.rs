@@ -0,0 +1,516 @@
+use crate::components::multi_select::{MultiSelectOption, MultiSelectTag};
+use crate::components::tree_view::TreeNode;
+use crate::i18n::{use_i18n, MessageArgs, MessageKey};
+use crate::utils::{generate_id, merge_classes};
+use leptos::callback::Callback;
+use leptos::html;
+use leptos::prelude::*;
+use radix_leptos_core::FocusManager;
+use std::collections::HashSet;
+
+/// Ids of the checked leaves of a TreeSelect
+pub type TreeSelectValue = RwSignal<Vec<String>>;
+
+/// Called with the checked leaf ids after a TreeSelect changes
+pub type TreeSelectChangeHandler = Callback<Vec<String>>;
+
+/// Check state of a node derived from the leaves below it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TreeCheckState {
+    Checked,
+    Unchecked,
+    /// Some but not all leaves are checked
+    Indeterminate,
+}
+
+impl TreeCheckState {
+    /// Value of `aria-checked`
+    pub fn to_aria(&self) -> &'static str {
+        match self {
+            TreeCheckState::Checked => "true",
+            TreeCheckState::Unchecked => "false",
+            TreeCheckState::Indeterminate => "mixed",
+        }
+    }
+}
+
+fn has_children(node: &TreeNode) -> bool {
+    node.children
+        .as_ref()
+        .is_some_and(|children| !children.is_empty())
+}
+
+/// Ids of the enabled leaves at or below `node`
+pub fn tree_leaf_ids(node: &TreeNode) -> Vec<String> {
+    if node.disabled {
+        return Vec::new();
+    }
+    match &node.children {
+        Some(children) if !children.is_empty() => children.iter().flat_map(tree_leaf_ids).collect(),
+        _ => vec![node.id.clone()],
+    }
+}
+
+/// Check state of `node` given the checked leaf ids
+pub fn tree_check_state(node: &TreeNode, checked: &HashSet<String>) -> TreeCheckState {
+    let leaves = tree_leaf_ids(node);
+    let count = leaves.iter().filter(|id| checked.contains(*id)).count();
+    if count == 0 {
+        TreeCheckState::Unchecked
+    } else if count == leaves.len() {
+        TreeCheckState::Checked
+    } else {
+        TreeCheckState::Indeterminate
+    }
+}
+
+/// Find a node anywhere in the tree by id
+pub fn find_tree_node<'a>(nodes: &'a [TreeNode], id: &str) -> Option<&'a TreeNode> {
+    nodes.iter().find_map(|node| {
+        if node.id == id {
+            Some(node)
+        } else {
+            node.children
+                .as_deref()
+                .and_then(|children| find_tree_node(children, id))
+        }
+    })
+}
+
+/// Checked leaf ids after toggling node `id`
+///
+/// Checking a parent checks every enabled leaf below it; toggling a fully
+/// checked parent clears them. Partly checked parents become fully
+/// checked.
+pub fn toggle_tree_check(roots: &[TreeNode], checked: &[String], id: &str) -> Vec<String> {
+    let Some(node) = find_tree_node(roots, id) else {
+        return checked.to_vec();
+    };
+    let set: HashSet<String> = checked.iter().cloned().collect();
+    let leaves = tree_leaf_ids(node);
+    if tree_check_state(node, &set) == TreeCheckState::Checked {
+        checked
+            .iter()
+            .filter(|id| !leaves.contains(id))
+            .cloned()
+            .collect()
+    } else {
+        let mut next = checked.to_vec();
+        next.extend(leaves.into_iter().filter(|id| !set.contains(id)));
+        next
+    }
+}
+
+/// Topmost fully checked nodes, shown as chips
+///
+/// A checked parent stands in for all of its leaves.
+pub fn tree_select_chips(roots: &[TreeNode], checked: &[String]) -> Vec<TreeNode> {
+    fn collect(nodes: &[TreeNode], checked: &HashSet<String>, chips: &mut Vec<TreeNode>) {
+        for node in nodes {
+            match tree_check_state(node, checked) {
+                TreeCheckState::Checked => chips.push(node.clone()),
+                TreeCheckState::Indeterminate => {
+                    collect(node.children.as_deref().unwrap_or_default(), checked, chips)
+                }
+                TreeCheckState::Unchecked => {}
+            }
+        }
+    }
+    let checked: HashSet<String> = checked.iter().cloned().collect();
+    let mut chips = Vec::new();
+    collect(roots, &checked, &mut chips);
+    chips
+}
+
+/// Nodes matching `query`, with the ancestors leading to them
+///
+/// A node whose label matches keeps its whole subtree. Ancestors kept only
+/// for their descendants are marked expanded so matches are visible.
+pub fn filter_tree(nodes: &[TreeNode], query: &str) -> Vec<TreeNode> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return nodes.to_vec();
+    }
+    fn filter(nodes: &[TreeNode], query: &str) -> Vec<TreeNode> {
+        nodes
+            .iter()
+            .filter_map(|node| {
+                if node.label.to_lowercase().contains(query) {
+                    return Some(node.clone());
+                }
+                let children = filter(node.children.as_deref().unwrap_or_default(), query);
+                (!children.is_empty()).then(|| TreeNode {
+                    children: Some(children),
+                    expanded: true,
+                    ..node.clone()
+                })
+            })
+            .collect()
+    }
+    filter(nodes, &query)
+}
+
+/// State shared by every row of a TreeSelect panel
+#[derive(Clone, Copy)]
+struct TreeSelectState {
+    roots: StoredValue<Vec<TreeNode>>,
+    checked: TreeSelectValue,
+    expanded: RwSignal<HashSet<String>>,
+    searching: Memo<bool>,
+    on_change: Option<TreeSelectChangeHandler>,
+}
+
+impl TreeSelectState {
+    fn toggle(&self, id: &str) {
+        let next = self
+            .roots
+            .with_value(|roots| toggle_tree_check(roots, &self.checked.get_untracked(), id));
+        self.checked.set(next.clone());
+        if let Some(on_change) = self.on_change {
+            on_change.run(next);
+        }
+    }
+
+    fn is_expanded(&self, node: &TreeNode) -> bool {
+        (self.searching.get() && node.expanded) || self.expanded.with(|ids| ids.contains(&node.id))
+    }
+}
+
+/// TreeSelect component - Dropdown whose panel is a checkable tree
+///
+/// Checking a parent checks all of its enabled descendants and a parent
+/// with some checked descendants shows as indeterminate. The search box
+/// filters the tree down to matching nodes and their ancestors. Selected
+/// nodes are shown as removable chips, a fully checked parent standing in
+/// for its children. `value` holds the ids of the checked leaves.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn Regions() -> impl IntoView {
+///     let leaf = |id: &str, label: &str| TreeNode {
+///         id: id.to_string(),
+///         label: label.to_string(),
+///         ..Default::default()
+///     };
+///     let data = vec![TreeNode {
+///         id: "eu".to_string(),
+///         label: "Europe".to_string(),
+///         children: Some(vec![leaf("fr", "France"), leaf("de", "Germany")]),
+///         ..Default::default()
+///     }];
+///     let value = RwSignal::new(vec!["fr".to_string()]);
+///     view! { <TreeSelect data=data value=value label="Regions".to_string() /> }
+/// }
+/// ```
+#[component]
+pub fn TreeSelect(
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    data: Vec<TreeNode>,
+    /// Ids of the checked leaves
+    #[prop(optional)]
+    value: Option<TreeSelectValue>,
+    /// Accessible name of the trigger and tree
+    #[prop(optional)]
+    label: Option<String>,
+    #[prop(optional)] placeholder: Option<String>,
+    #[prop(optional)] search_placeholder: Option<String>,
+    #[prop(optional)] disabled: Option<bool>,
+    /// Called with the checked leaf ids after every change
+    #[prop(optional)]
+    on_change: Option<TreeSelectChangeHandler>,
+) -> impl IntoView {
+    let catalog = use_i18n();
+    let label = label.unwrap_or_else(|| catalog.text(MessageKey::TreeSelectLabel));
+    let placeholder = placeholder.unwrap_or_else(|| catalog.text(MessageKey::TreeSelectPlaceholder));
+    let search_placeholder =
+        search_placeholder.unwrap_or_else(|| catalog.text(MessageKey::TreeSelectSearchPlaceholder));
+    let disabled = disabled.unwrap_or(false);
+    let checked = value.unwrap_or_else(|| RwSignal::new(Vec::new()));
+    let open = RwSignal::new(false);
+    let query = RwSignal::new(String::new());
+    let trigger_ref = NodeRef::<html::Button>::new();
+    let panel_id = generate_id("tree-select");
+    let panel_controls = panel_id.clone();
+    let tree_label = label.clone();
+    let search_label = catalog.format(
+        MessageKey::TreeSelectSearch,
+        &MessageArgs::new().with("label", label.as_str()),
+    );
+    let no_results = catalog.text(MessageKey::NoResults);
+
+    let mut initially_expanded = HashSet::new();
+    collect_expanded(&data, &mut initially_expanded);
+    let state = TreeSelectState {
+        roots: StoredValue::new(data),
+        checked,
+        expanded: RwSignal::new(initially_expanded),
+        searching: Memo::new(move |_| !query.with(|query| query.trim().is_empty())),
+        on_change,
+    };
+
+    let handle_keydown = move |ev: web_sys::KeyboardEvent| {
+        if ev.key() == "Escape" && open.get_untracked() {
+            ev.prevent_default();
+            open.set(false);
+            if let Some(trigger) = trigger_ref.get_untracked() {
+                FocusManager::focus(&trigger);
+            }
+        }
+    };
+
+    let chips = move || {
+        let chips = state
+            .roots
+            .with_value(|roots| tree_select_chips(roots, &checked.get()));
+        if chips.is_empty() {
+            return view! { <span class="tree-select-placeholder">{placeholder.clone()}</span> }
+                .into_any();
+        }
+        chips
+            .into_iter()
+            .map(|node| {
+                let option = MultiSelectOption {
+                    value: node.id,
+                    label: node.label,
+                    ..Default::default()
+                };
+                view! {
+                    <MultiSelectTag
+                        option=option
+                        on_remove=Callback::new(move |option: MultiSelectOption| {
+                            state.toggle(&option.value)
+                        })
+                    />
+                }
+            })
+            .collect_view()
+            .into_any()
+    };
+    let trigger_label = move || {
+        let count = state
+            .roots
+            .with_value(|roots| tree_select_chips(roots, &checked.get()).len());
+        let args = MessageArgs::new().with("label", label.as_str()).with("count", count);
+        catalog.format(MessageKey::TreeSelectTrigger, &args)
+    };
+    let tree = move || {
+        let nodes = state
+            .roots
+            .with_value(|roots| query.with(|query| filter_tree(roots, query)));
+        if nodes.is_empty() {
+            return view! { <li class="tree-select-empty" role="none">{no_results.clone()}</li> }
+                .into_any();
+        }
+        render_tree_select_nodes(nodes, 1, state)
+    };
+
+    let class = merge_classes(vec!["tree-select", class.as_deref().unwrap_or("")]);
+    let style = format!("position: relative; {}", style.unwrap_or_default());
+
+    view! {
+        <div class=class style=style data-disabled=disabled on:keydown=handle_keydown>
+            <div class="tree-select-value">{chips}</div>
+            <button
+                type="button"
+                class="tree-select-trigger"
+                node_ref=trigger_ref
+                disabled=disabled
+                aria-haspopup="tree"
+                aria-expanded=move || open.get().to_string()
+                aria-controls=panel_controls
+                aria-label=trigger_label
+                on:click=move |_| open.update(|open| *open = !*open)
+            >
+                <span aria-hidden="true">"▾"</span>
+            </button>
+            <div
+                id=panel_id
+                class="tree-select-content"
+                hidden=move || !open.get()
+                style="position: absolute; top: 100%; left: 0; z-index: 50;"
+            >
+                <input
+                    type="search"
+                    class="tree-select-search"
+                    placeholder=search_placeholder
+                    aria-label=search_label
+                    prop:value=move || query.get()
+                    on:input=move |ev| query.set(event_target_value(&ev))
+                />
+                <ul class="tree-select-tree" role="tree" aria-label=tree_label aria-multiselectable="true">
+                    {tree}
+                </ul>
+            </div>
+        </div>
+    }
+}
+
+fn collect_expanded(nodes: &[TreeNode], expanded: &mut HashSet<String>) {
+    for node in nodes {
+        if node.expanded {
+            expanded.insert(node.id.clone());
+        }
+        collect_expanded(node.children.as_deref().unwrap_or_default(), expanded);
+    }
+}
+
+fn render_tree_select_nodes(nodes: Vec<TreeNode>, level: usize, state: TreeSelectState) -> AnyView {
+    nodes
+        .into_iter()
+        .map(|node| {
+            let id = StoredValue::new(node.id.clone());
+            let parent = has_children(&node);
+            let node_disabled = node.disabled;
+            let node_label = node.label.clone();
+            let check_node = node.clone();
+            let check_state = Memo::new(move |_| {
+                state.checked.with(|checked| {
+                    let checked: HashSet<String> = checked.iter().cloned().collect();
+                    tree_check_state(&check_node, &checked)
+                })
+            });
+            let expand_node = node.clone();
+            let is_expanded = Memo::new(move |_| state.is_expanded(&expand_node));
+            let toggle_expanded = move |_| {
+                state.expanded.update(|expanded| {
+                    let id = id.get_value();
+                    if !expanded.remove(&id) {
+                        expanded.insert(id);
+                    }
+                })
+            };
+            let children = node.children.unwrap_or_default();
+            let group = parent.then(|| {
+                view! {
+                    <ul role="group" hidden=move || !is_expanded.get()>
+                        {render_tree_select_nodes(children, level + 1, state)}
+                    </ul>
+                }
+            });
+
+            view! {
+                <li
+                    class="tree-select-node"
+                    role="treeitem"
+                    attr:aria-level=level.to_string()
+                    aria-expanded=move || parent.then(|| is_expanded.get().to_string())
+                    aria-checked=move || check_state.get().to_aria()
+                    aria-disabled=node_disabled.then_some("true")
+                >
+                    <div class="tree-select-node-content" style=format!("padding-inline-start: {}px;", (level - 1) * 20)>
+                        {parent.then(|| {
+                            view! {
+                                <button
+                                    type="button"
+                                    class="tree-expand-icon"
+                                    tabindex="-1"
+                                    aria-hidden="true"
+                                    on:click=toggle_expanded
+                                >
+                                    {move || if is_expanded.get() { "▾" } else { "▸" }}
+                                </button>
+                            }
+                        })}
+                        <label class="tree-select-node-label">
+                            <input
+                                type="checkbox"
+                                class="tree-checkbox"
+                                disabled=node_disabled
+                                prop:checked=move || check_state.get() == TreeCheckState::Checked
+                                prop:indeterminate=move || check_state.get() == TreeCheckState::Indeterminate
+                                on:change=move |_| id.with_value(|id| state.toggle(id))
+                            />
+                            {node_label}
+                        </label>
+                    </div>
+                    {group}
+                </li>
+            }
+        })
+        .collect_view()
+        .into_any()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(id: &str, children: Vec<TreeNode>) -> TreeNode {
+        TreeNode {
+            id: id.to_string(),
+            label: id.to_uppercase(),
+            children: (!children.is_empty()).then_some(children),
+            ..Default::default()
+        }
+    }
+
+    fn tree() -> Vec<TreeNode> {
+        vec![
+            node(
+                "eu",
+                vec![
+                    node("fr", vec![node("paris", vec![]), node("lyon", vec![])]),
+                    node("de", vec![]),
+                ],
+            ),
+            node("us", vec![]),
+        ]
+    }
+
+    fn ids(ids: &[&str]) -> Vec<String> {
+        ids.iter().map(|id| id.to_string()).collect()
+    }
+
+    #[test]
+    fn test_check_propagates_down_and_up() {
+        let roots = tree();
+        let checked = toggle_tree_check(&roots, &[], "fr");
+        assert_eq!(checked, ids(&["paris", "lyon"]));
+
+        let set: HashSet<String> = checked.iter().cloned().collect();
+        let eu = find_tree_node(&roots, "eu").unwrap();
+        assert_eq!(tree_check_state(eu, &set), TreeCheckState::Indeterminate);
+
+        let checked = toggle_tree_check(&roots, &checked, "eu");
+        assert_eq!(checked, ids(&["paris", "lyon", "de"]));
+        let checked = toggle_tree_check(&roots, &checked, "eu");
+        assert!(checked.is_empty());
+    }
+
+    #[test]
+    fn test_disabled_leaves_are_skipped() {
+        let mut roots = tree();
+        roots[0].children.as_mut().unwrap()[1].disabled = true;
+        let checked = toggle_tree_check(&roots, &[], "eu");
+        assert_eq!(checked, ids(&["paris", "lyon"]));
+        let set: HashSet<String> = checked.iter().cloned().collect();
+        assert_eq!(tree_check_state(&roots[0], &set), TreeCheckState::Checked);
+    }
+
+    #[test]
+    fn test_chips_collapse_to_checked_parents() {
+        let roots = tree();
+        let chips = tree_select_chips(&roots, &ids(&["paris", "lyon", "us"]));
+        let chip_ids: Vec<&str> = chips.iter().map(|n| n.id.as_str()).collect();
+        assert_eq!(chip_ids, vec!["fr", "us"]);
+        assert!(tree_select_chips(&roots, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_filter_keeps_ancestors_of_matches() {
+        let roots = tree();
+        let filtered = filter_tree(&roots, "lyo");
+        assert_eq!(filtered.len(), 1);
+        assert!(filtered[0].expanded);
+        let fr = &filtered[0].children.as_ref().unwrap()[0];
+        assert_eq!(fr.children.as_ref().unwrap().len(), 1);
+        assert_eq!(filter_tree(&roots, "  ").len(), 2);
+        assert!(filter_tree(&roots, "asia").is_empty());
+    }
+}
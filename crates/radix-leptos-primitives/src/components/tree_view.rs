@@ -1,8 +1,17 @@
 use leptos::callback::Callback;
 use leptos::children::Children;
+use leptos::html;
 use leptos::prelude::*;
+use radix_leptos_core::use_typeahead;
+use crate::i18n::{use_i18n, MessageKey};
+
+/// Visible tree items that typeahead can move to
+const TREE_ITEM_SELECTOR: &str = "[role='treeitem']:not([aria-disabled='true'])";
 
 /// Tree View component for displaying hierarchical data
+///
+/// Typing the start of a node's label moves focus to the next visible node
+/// with that label.
 #[component]
 pub fn TreeView(
     /// Tree data
@@ -53,8 +62,16 @@ pub fn TreeView(
 
     let style = style.unwrap_or_default();
 
+    let tree_ref = NodeRef::<html::Div>::new();
+    let typeahead = use_typeahead();
+    let handle_keydown = move |e: web_sys::KeyboardEvent| {
+        if let Some(tree) = tree_ref.get_untracked() {
+            typeahead.handle_keydown(&e, &tree, TREE_ITEM_SELECTOR);
+        }
+    };
+
     view! {
-        <div class=class style=style role="tree">
+        <div class=class style=style role="tree" node_ref=tree_ref on:keydown=handle_keydown>
             {children.map(|c| c())}
         </div>
     }
@@ -169,8 +186,18 @@ pub fn TreeNode(
         }
     };
 
+    // data-text-value keeps typeahead to the label, not nested nodes' text
     view! {
-        <div class=class style=style role="treeitem" aria-expanded=node.expanded aria-selected=node.selected>
+        <div
+            class=class
+            style=style
+            role="treeitem"
+            tabindex="-1"
+            aria-expanded=node.expanded
+            aria-selected=node.selected
+            aria-disabled=node.disabled.then_some("true")
+            data-text-value=node.label.clone()
+        >
             <div class="tree-node-content">
                 {if show_icons && node.children.is_some() {
                     view! {
@@ -271,7 +298,8 @@ pub fn TreeViewSearch(
     children: Option<Children>,
 ) -> impl IntoView {
     let value = value.unwrap_or_default();
-    let placeholder = placeholder.unwrap_or_else(|| "Search tree...".to_string());
+    let placeholder =
+        placeholder.unwrap_or_else(|| use_i18n().text(MessageKey::TreeViewSearchPlaceholder));
     let disabled = disabled.unwrap_or(false);
     let class = format!(
         "tree-search {} {}",
@@ -451,6 +479,33 @@ use crate::utils::merge_optional_classes;
     #[test]
     fn test_treeview_keyboard_navigation() {}
 
+    #[test]
+    fn test_treenode_typeahead_markup() {
+        use super::TreeNode as TreeNodeView;
+        use leptos::prelude::*;
+
+        let owner = Owner::new();
+        owner.with(|| {
+            let node = TreeNode {
+                id: "docs".to_string(),
+                label: "Documents".to_string(),
+                disabled: true,
+                expanded: true,
+                children: Some(vec![TreeNode {
+                    id: "notes".to_string(),
+                    label: "Notes".to_string(),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            };
+            let html = view! { <TreeNodeView node=node /> }.to_html();
+            assert!(html.contains(r#"data-text-value="Documents""#));
+            assert!(html.contains(r#"data-text-value="Notes""#));
+            assert!(html.contains(r#"tabindex="-1""#));
+            assert!(html.contains(r#"aria-disabled="true""#));
+        });
+    }
+
     #[test]
     fn test_treeview_screen_reader_support() {}
 
@@ -0,0 +1,568 @@
+use crate::components::audio_player::{
+    format_media_time, is_native_activation, media_shortcut, media_time_text, next_playback_rate,
+    MediaShortcut,
+};
+use crate::components::slider::Slider;
+use crate::components::spinner::Spinner;
+use crate::i18n::{use_i18n, MessageArgs, MessageKey};
+use crate::utils::merge_classes;
+use leptos::html;
+use leptos::prelude::*;
+use std::time::Duration;
+use wasm_bindgen::JsCast;
+
+/// How long the controls stay visible after the pointer stops moving
+const CONTROLS_IDLE: Duration = Duration::from_millis(2500);
+
+/// Captions or subtitles file offered by a VideoPlayer
+#[derive(Debug, Clone, PartialEq)]
+pub struct VideoCaptionTrack {
+    pub src: String,
+    /// BCP 47 language tag, e.g. "en"
+    pub srclang: String,
+    pub label: String,
+}
+
+impl VideoCaptionTrack {
+    pub fn new(
+        src: impl Into<String>,
+        srclang: impl Into<String>,
+        label: impl Into<String>,
+    ) -> Self {
+        Self {
+            src: src.into(),
+            srclang: srclang.into(),
+            label: label.into(),
+        }
+    }
+}
+
+/// Action triggered by a video player keyboard shortcut
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VideoShortcut {
+    Media(MediaShortcut),
+    ToggleFullscreen,
+    ToggleCaptions,
+}
+
+/// Shortcut bound to `key`: the audio shortcuts plus F for fullscreen and C
+/// for captions
+pub fn video_shortcut(key: &str, seek_step: f64) -> Option<VideoShortcut> {
+    match key {
+        "f" | "F" => Some(VideoShortcut::ToggleFullscreen),
+        "c" | "C" => Some(VideoShortcut::ToggleCaptions),
+        _ => media_shortcut(key, seek_step).map(VideoShortcut::Media),
+    }
+}
+
+/// Whether the browser offers picture-in-picture for video elements
+fn picture_in_picture_enabled(document: &web_sys::Document) -> bool {
+    js_sys::Reflect::get(document, &"pictureInPictureEnabled".into())
+        .map(|enabled| enabled.is_truthy())
+        .unwrap_or(false)
+}
+
+/// Calls the zero-argument method `name` on `target` if the browser has it
+fn call_method(target: &wasm_bindgen::JsValue, name: &str) {
+    if let Ok(method) = js_sys::Reflect::get(target, &name.into())
+        .and_then(|method| method.dyn_into::<js_sys::Function>())
+    {
+        // Rejections (e.g. no user activation) leave the current mode in place
+        let _ = method.call0(target);
+    }
+}
+
+/// VideoPlayer component - Video with an accessible custom controls overlay
+///
+/// Adds play/pause, a seek [`Slider`], playback speed, volume, captions,
+/// picture-in-picture and fullscreen controls on top of a `<video>`. The
+/// controls fade out while the video plays and the pointer is idle, and
+/// stay visible whenever they hold focus. Besides the AudioPlayer
+/// shortcuts, F toggles fullscreen and C toggles captions. A spinner is
+/// shown while the video buffers and the poster until playback starts.
+///
+/// Like every primitive it ships no styles: lay `.video-player-controls`
+/// and `.video-player-overlay` over the video in your CSS and fade the
+/// controls out under `data-controls="hidden"`, keeping them visible while
+/// they hold focus (`:focus-within`).
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn Trailer() -> impl IntoView {
+///     view! {
+///         <VideoPlayer
+///             src="/media/trailer.mp4".to_string()
+///             poster="/media/trailer.jpg".to_string()
+///             label="Trailer".to_string()
+///             captions=vec![VideoCaptionTrack::new("/media/trailer.en.vtt", "en", "English")]
+///         />
+///     }
+/// }
+/// ```
+#[component]
+pub fn VideoPlayer(
+    src: String,
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    #[prop(optional)] poster: Option<String>,
+    /// Accessible name of the player (default: "Video player")
+    #[prop(optional)]
+    label: Option<String>,
+    #[prop(optional)] captions: Vec<VideoCaptionTrack>,
+    /// Seconds skipped by the seek shortcuts (default: 5)
+    #[prop(optional)]
+    seek_step: Option<f64>,
+    #[prop(optional)] on_ended: Option<Callback<()>>,
+) -> impl IntoView {
+    let seek_step = seek_step.unwrap_or(5.0);
+    let catalog = use_i18n();
+    let label = label.unwrap_or_else(|| catalog.text(MessageKey::VideoPlayerLabel));
+    let has_captions = !captions.is_empty();
+    let root_ref = NodeRef::<html::Div>::new();
+    let video_ref = NodeRef::<html::Video>::new();
+
+    let playing = RwSignal::new(false);
+    let started = RwSignal::new(false);
+    let loading = RwSignal::new(false);
+    let current_time = RwSignal::new(0.0);
+    let duration = RwSignal::new(0.0);
+    let volume = RwSignal::new(1.0);
+    let muted = RwSignal::new(false);
+    let rate = RwSignal::new(1.0);
+    let captions_on = RwSignal::new(false);
+    let fullscreen = RwSignal::new(false);
+    let pip_supported = RwSignal::new(false);
+    let in_pip = RwSignal::new(false);
+    let controls_visible = RwSignal::new(true);
+    let hide_timer = StoredValue::new(None::<TimeoutHandle>);
+
+    let sync = move || {
+        if let Some(video) = video_ref.get_untracked() {
+            playing.set(!video.paused());
+            current_time.set(video.current_time());
+            let length = video.duration();
+            duration.set(if length.is_finite() { length } else { 0.0 });
+            volume.set(video.volume());
+            muted.set(video.muted());
+            rate.set(video.playback_rate());
+        }
+    };
+
+    // Show the controls, then hide them again once the pointer rests
+    let reveal_controls = move || {
+        controls_visible.set(true);
+        if let Some(handle) = hide_timer.get_value() {
+            handle.clear();
+        }
+        let hide = move || {
+            if playing.get_untracked() {
+                controls_visible.set(false);
+            }
+        };
+        hide_timer.set_value(set_timeout_with_handle(hide, CONTROLS_IDLE).ok());
+    };
+    on_cleanup(move || {
+        if let Some(handle) = hide_timer.get_value() {
+            handle.clear();
+        }
+    });
+
+    let toggle_play = move || {
+        if let Some(video) = video_ref.get_untracked() {
+            if video.paused() {
+                // Rejected when the browser blocks playback; the pause state stays
+                let _ = video.play();
+            } else {
+                let _ = video.pause();
+            }
+        }
+    };
+    let seek_to = move |seconds: f64| {
+        if let Some(video) = video_ref.get_untracked() {
+            let end = duration.get_untracked().max(0.0);
+            video.set_current_time(seconds.clamp(0.0, end));
+            current_time.set(video.current_time());
+        }
+    };
+    let set_volume = move |level: f64| {
+        if let Some(video) = video_ref.get_untracked() {
+            video.set_volume(level.clamp(0.0, 1.0));
+            video.set_muted(level <= 0.0);
+        }
+    };
+    let toggle_mute = move || {
+        if let Some(video) = video_ref.get_untracked() {
+            video.set_muted(!video.muted());
+        }
+    };
+    let toggle_captions = move || {
+        if !has_captions {
+            return;
+        }
+        let showing = !captions_on.get_untracked();
+        if let Some(tracks) = video_ref
+            .get_untracked()
+            .and_then(|video| video.text_tracks())
+        {
+            // Only the first track is shown; the toggle switches captions on
+            // in the default language rather than choosing between them
+            for index in 0..tracks.length() {
+                if let Some(track) = tracks.get(index) {
+                    track.set_mode(if showing && index == 0 {
+                        web_sys::TextTrackMode::Showing
+                    } else {
+                        web_sys::TextTrackMode::Hidden
+                    });
+                }
+            }
+        }
+        captions_on.set(showing);
+    };
+    let toggle_fullscreen = move || {
+        let Some(root) = root_ref.get_untracked() else {
+            return;
+        };
+        let Some(document) = root.owner_document() else {
+            return;
+        };
+        if document.fullscreen_element().is_some() {
+            document.exit_fullscreen();
+        } else {
+            let _ = root.request_fullscreen();
+        }
+    };
+    let toggle_pip = move || {
+        let Some(video) = video_ref.get_untracked() else {
+            return;
+        };
+        if in_pip.get_untracked() {
+            if let Some(document) = video.owner_document() {
+                call_method(&document.into(), "exitPictureInPicture");
+            }
+        } else {
+            call_method(&video.into(), "requestPictureInPicture");
+        }
+    };
+
+    let handle_keydown = move |e: web_sys::KeyboardEvent| {
+        reveal_controls();
+        if e.default_prevented() || e.ctrl_key() || e.meta_key() || e.alt_key() {
+            return;
+        }
+        if is_native_activation(&e) {
+            return;
+        }
+        let Some(shortcut) = video_shortcut(&e.key(), seek_step) else {
+            return;
+        };
+        e.prevent_default();
+        match shortcut {
+            VideoShortcut::Media(MediaShortcut::TogglePlay) => toggle_play(),
+            VideoShortcut::Media(MediaShortcut::Seek(delta)) => {
+                seek_to(current_time.get_untracked() + delta)
+            }
+            VideoShortcut::Media(MediaShortcut::Volume(delta)) => {
+                let level = if muted.get_untracked() {
+                    0.0
+                } else {
+                    volume.get_untracked()
+                };
+                set_volume(level + delta);
+            }
+            VideoShortcut::Media(MediaShortcut::ToggleMute) => toggle_mute(),
+            VideoShortcut::ToggleFullscreen => toggle_fullscreen(),
+            VideoShortcut::ToggleCaptions => toggle_captions(),
+        }
+    };
+
+    let handle_fullscreenchange = move |_| {
+        let active = root_ref
+            .get_untracked()
+            .and_then(|root| root.owner_document())
+            .and_then(|document| document.fullscreen_element())
+            .is_some();
+        fullscreen.set(active);
+    };
+    let handle_loadedmetadata = move |_| {
+        sync();
+        let supported = video_ref
+            .get_untracked()
+            .and_then(|video| video.owner_document())
+            .is_some_and(|document| picture_in_picture_enabled(&document));
+        pip_supported.set(supported);
+    };
+    let handle_play = move |_| {
+        playing.set(true);
+        started.set(true);
+        reveal_controls();
+    };
+    let handle_pause = move |_| {
+        playing.set(false);
+        controls_visible.set(true);
+    };
+    let handle_ended = move |_| {
+        playing.set(false);
+        controls_visible.set(true);
+        if let Some(on_ended) = on_ended {
+            on_ended.run(());
+        }
+    };
+
+    let seek_catalog = catalog.clone();
+    let seek_slider = move || {
+        let length = duration.get();
+        let ready = length > 0.0;
+        let catalog = seek_catalog.clone();
+        let seek_label = catalog.text(MessageKey::VideoPlayerSeek);
+        let value_text = Callback::new(move |seconds: f64| {
+            let args = MessageArgs::new()
+                .with("current", media_time_text(seconds))
+                .with("duration", media_time_text(length));
+            catalog.format(MessageKey::VideoPlayerPosition, &args)
+        });
+        view! {
+            <Slider
+                class="video-player-seek".to_string()
+                value=Signal::derive(move || current_time.get())
+                max=if ready { length } else { 1.0 }
+                disabled=!ready
+                aria_label=seek_label
+                value_text=value_text
+                on_value_change=Callback::new(seek_to)
+                _children=Box::new(|| ().into_any())
+            />
+        }
+    };
+
+    let tracks = captions
+        .into_iter()
+        .map(|track| {
+            view! {
+                <track kind="captions" src=track.src srclang=track.srclang label=track.label />
+            }
+        })
+        .collect_view();
+
+    let loading_label = catalog.text(MessageKey::VideoPlayerLoading);
+    let start_label = catalog.text(MessageKey::VideoPlayerStart);
+    let play_label = catalog.text(MessageKey::VideoPlayerPlay);
+    let pause_label = catalog.text(MessageKey::VideoPlayerPause);
+    let pip_label = catalog.text(MessageKey::VideoPlayerPictureInPicture);
+    let fullscreen_label = catalog.text(MessageKey::VideoPlayerFullscreen);
+    let exit_fullscreen_label = catalog.text(MessageKey::VideoPlayerExitFullscreen);
+    let speed_catalog = catalog.clone();
+
+    let class = merge_classes(vec!["video-player", class.as_deref().unwrap_or("")]);
+
+    view! {
+        <div
+            node_ref=root_ref
+            class=class
+            style=style
+            role="group"
+            aria-label=label
+            aria-roledescription=catalog.text(MessageKey::VideoPlayerRole)
+            data-state=move || if playing.get() { "playing" } else { "paused" }
+            data-controls=move || if controls_visible.get() { "visible" } else { "hidden" }
+            data-fullscreen=move || fullscreen.get().to_string()
+            on:keydown=handle_keydown
+            on:pointermove=move |_| reveal_controls()
+            on:fullscreenchange=handle_fullscreenchange
+        >
+            <video
+                node_ref=video_ref
+                src=src
+                poster=poster
+                preload="metadata"
+                playsinline
+                on:click=move |_| toggle_play()
+                on:loadedmetadata=handle_loadedmetadata
+                on:durationchange=move |_| sync()
+                on:timeupdate=move |_| sync()
+                on:play=handle_play
+                on:pause=handle_pause
+                on:waiting=move |_| loading.set(true)
+                on:canplay=move |_| loading.set(false)
+                on:volumechange=move |_| sync()
+                on:ratechange=move |_| sync()
+                on:ended=handle_ended
+                on:enterpictureinpicture=move |_: web_sys::Event| in_pip.set(true)
+                on:leavepictureinpicture=move |_: web_sys::Event| in_pip.set(false)
+            >
+                {tracks}
+            </video>
+            <div class="video-player-overlay">
+                {move || {
+                    if loading.get() {
+                        view! { <Spinner size=48 label=loading_label.clone() /> }.into_any()
+                    } else if !started.get() {
+                        view! {
+                            <button
+                                type="button"
+                                class="video-player-start"
+                                aria-label=start_label.clone()
+                                on:click=move |_| toggle_play()
+                            >
+                                <span aria-hidden="true">"▶"</span>
+                            </button>
+                        }
+                            .into_any()
+                    } else {
+                        ().into_any()
+                    }
+                }}
+            </div>
+            <div class="video-player-controls">
+                <button
+                    type="button"
+                    class="video-player-play"
+                    aria-label=move || {
+                        if playing.get() { pause_label.clone() } else { play_label.clone() }
+                    }
+                    on:click=move |_| toggle_play()
+                >
+                    <span aria-hidden="true">{move || if playing.get() { "❚❚" } else { "▶" }}</span>
+                </button>
+                <span class="video-player-time" aria-hidden="true">
+                    {move || format_media_time(current_time.get())}
+                    " / "
+                    {move || format_media_time(duration.get())}
+                </span>
+                {seek_slider}
+                <button
+                    type="button"
+                    class="video-player-rate"
+                    aria-label=move || {
+                        speed_catalog.format(
+                            MessageKey::VideoPlayerSpeed,
+                            &MessageArgs::new().with("rate", rate.get()),
+                        )
+                    }
+                    on:click=move |_| {
+                        if let Some(video) = video_ref.get_untracked() {
+                            video.set_playback_rate(next_playback_rate(video.playback_rate()));
+                        }
+                    }
+                >
+                    {move || format!("{}×", rate.get())}
+                </button>
+                <button
+                    type="button"
+                    class="video-player-mute"
+                    aria-label=catalog.text(MessageKey::VideoPlayerMute)
+                    aria-pressed=move || muted.get().to_string()
+                    on:click=move |_| toggle_mute()
+                >
+                    <span aria-hidden="true">{move || if muted.get() { "🔇" } else { "🔊" }}</span>
+                </button>
+                <Slider
+                    class="video-player-volume".to_string()
+                    value=Signal::derive(move || if muted.get() { 0.0 } else { volume.get() * 100.0 })
+                    max=100.0
+                    step=5.0
+                    aria_label=catalog.text(MessageKey::VideoPlayerVolume)
+                    value_text=Callback::new(|level: f64| format!("{}%", level.round()))
+                    on_value_change=Callback::new(move |level: f64| set_volume(level / 100.0))
+                    _children=Box::new(|| ().into_any())
+                />
+                {has_captions
+                    .then(|| {
+                        view! {
+                            <button
+                                type="button"
+                                class="video-player-captions"
+                                aria-label=catalog.text(MessageKey::VideoPlayerCaptions)
+                                aria-pressed=move || captions_on.get().to_string()
+                                on:click=move |_| toggle_captions()
+                            >
+                                "CC"
+                            </button>
+                        }
+                    })}
+                {move || {
+                    pip_supported
+                        .get()
+                        .then(|| {
+                            view! {
+                                <button
+                                    type="button"
+                                    class="video-player-pip"
+                                    aria-label=pip_label.clone()
+                                    aria-pressed=move || in_pip.get().to_string()
+                                    on:click=move |_| toggle_pip()
+                                >
+                                    <span aria-hidden="true">"⧉"</span>
+                                </button>
+                            }
+                        })
+                }}
+                <button
+                    type="button"
+                    class="video-player-fullscreen"
+                    aria-label=move || {
+                        if fullscreen.get() {
+                            exit_fullscreen_label.clone()
+                        } else {
+                            fullscreen_label.clone()
+                        }
+                    }
+                    on:click=move |_| toggle_fullscreen()
+                >
+                    <span aria-hidden="true">{move || if fullscreen.get() { "⤡" } else { "⤢" }}</span>
+                </button>
+            </div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_video_shortcut() {
+        assert_eq!(
+            video_shortcut("f", 5.0),
+            Some(VideoShortcut::ToggleFullscreen)
+        );
+        assert_eq!(
+            video_shortcut("C", 5.0),
+            Some(VideoShortcut::ToggleCaptions)
+        );
+        assert_eq!(
+            video_shortcut("ArrowRight", 10.0),
+            Some(VideoShortcut::Media(MediaShortcut::Seek(10.0)))
+        );
+        assert_eq!(video_shortcut("q", 5.0), None);
+    }
+
+    #[test]
+    fn test_video_player_markup() {
+        crate::test_support::init_test_executor();
+        let owner = Owner::new();
+        owner.with(|| {
+            let html = view! {
+                <VideoPlayer
+                    src="/v.mp4".to_string()
+                    poster="/v.jpg".to_string()
+                    captions=vec![VideoCaptionTrack::new("/v.vtt", "en", "English")]
+                />
+            }
+            .to_html();
+            assert!(html.contains("poster=\"/v.jpg\""));
+            assert!(html.contains("kind=\"captions\""));
+            assert!(html.contains("aria-label=\"Play video\""));
+            assert!(html.contains("aria-label=\"Captions\""));
+            assert!(html.contains("aria-label=\"Full screen\""));
+            assert!(!html.contains("Picture in picture"));
+
+            let bare = view! { <VideoPlayer src="/v.mp4".to_string() /> }.to_html();
+            assert!(!bare.contains("aria-label=\"Captions\""));
+        });
+    }
+}
@@ -0,0 +1,420 @@
+use crate::utils::merge_classes;
+use leptos::callback::Callback;
+use leptos::children::Children;
+use leptos::html;
+use leptos::prelude::*;
+use radix_leptos_core::{use_mutation_observer, UseMutationObserverOptions};
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+
+/// Watermark component - Tiled text or image watermark over its children
+///
+/// The watermark tile is drawn onto an offscreen canvas and repeated as the
+/// background of an overlay that ignores pointer events. A MutationObserver
+/// restores the overlay if it is removed or its style is edited, and reports
+/// the attempt through `on_tamper`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn Report() -> impl IntoView {
+///     view! {
+///         <Watermark text="Confidential\nACME Corp".to_string() rotate=-30.0 opacity=0.12>
+///             <article>"Quarterly figures"</article>
+///         </Watermark>
+///     }
+/// }
+/// ```
+#[component]
+pub fn Watermark(
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] style: Option<String>,
+    /// Watermark text; `\n` separates lines
+    #[prop(optional)]
+    text: Option<String>,
+    /// Image URL drawn instead of text
+    #[prop(optional)]
+    image: Option<String>,
+    /// Image width in pixels
+    #[prop(optional)]
+    image_width: Option<f64>,
+    /// Image height in pixels
+    #[prop(optional)]
+    image_height: Option<f64>,
+    /// Rotation in degrees
+    #[prop(optional)]
+    rotate: Option<f64>,
+    /// Opacity of the watermark (0.0 - 1.0)
+    #[prop(optional)]
+    opacity: Option<f64>,
+    /// Horizontal and vertical gap between tiles in pixels
+    #[prop(optional)]
+    gap: Option<WatermarkGap>,
+    /// Text font
+    #[prop(optional)]
+    font: Option<WatermarkFont>,
+    /// Stacking order of the overlay
+    #[prop(optional)]
+    z_index: Option<i32>,
+    /// Called when the overlay was removed or modified and has been restored
+    #[prop(optional)]
+    on_tamper: Option<Callback<()>>,
+    children: Children,
+) -> impl IntoView {
+    let config = WatermarkConfig {
+        lines: text
+            .unwrap_or_default()
+            .lines()
+            .map(str::to_string)
+            .collect(),
+        image,
+        image_size: (image_width.unwrap_or(120.0), image_height.unwrap_or(64.0)),
+        rotate: rotate.unwrap_or(-22.0),
+        opacity: opacity.unwrap_or(0.15).clamp(0.0, 1.0),
+        gap: gap.unwrap_or_default(),
+        font: font.unwrap_or_default(),
+    };
+    let z_index = z_index.unwrap_or(9);
+
+    let container_ref = NodeRef::<html::Div>::new();
+    let overlay_ref = NodeRef::<html::Div>::new();
+    let overlay_style = RwSignal::new(watermark_overlay_style(None, z_index));
+
+    // Draw the tile once on the client
+    Effect::new(move |_| {
+        render_watermark_tile(&config, move |tile| {
+            overlay_style.set(watermark_overlay_style(Some(&tile), z_index));
+        });
+    });
+
+    // Tamper detection
+    use_mutation_observer(
+        container_ref,
+        move |_records| {
+            let (Some(container), Some(overlay)) =
+                (container_ref.get_untracked(), overlay_ref.get_untracked())
+            else {
+                return;
+            };
+            if restore_overlay(&container, &overlay, &overlay_style.get_untracked()) {
+                if let Some(on_tamper) = on_tamper {
+                    on_tamper.run(());
+                }
+            }
+        },
+        UseMutationObserverOptions {
+            child_list: true,
+            attributes: true,
+            subtree: true,
+            ..Default::default()
+        },
+    );
+
+    let class = merge_classes(vec!["watermark", class.as_deref().unwrap_or("")]);
+    let style = format!("position: relative; {}", style.unwrap_or_default());
+
+    view! {
+        <div class=class style=style node_ref=container_ref>
+            {children()}
+            <div
+                class="watermark-overlay"
+                node_ref=overlay_ref
+                style=move || overlay_style.get()
+                aria-hidden="true"
+            ></div>
+        </div>
+    }
+}
+
+/// Gap between watermark tiles in pixels
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WatermarkGap {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl Default for WatermarkGap {
+    fn default() -> Self {
+        Self { x: 100.0, y: 100.0 }
+    }
+}
+
+/// Font used for text watermarks
+#[derive(Debug, Clone, PartialEq)]
+pub struct WatermarkFont {
+    pub size: f64,
+    pub family: String,
+    pub weight: String,
+    pub color: String,
+}
+
+impl Default for WatermarkFont {
+    fn default() -> Self {
+        Self {
+            size: 16.0,
+            family: "sans-serif".to_string(),
+            weight: "normal".to_string(),
+            color: "rgba(0, 0, 0, 1)".to_string(),
+        }
+    }
+}
+
+impl WatermarkFont {
+    /// CSS `font` shorthand for the canvas context
+    pub fn to_css(&self) -> String {
+        format!("{} {}px {}", self.weight, self.size, self.family)
+    }
+
+    pub fn line_height(&self) -> f64 {
+        self.size * 1.4
+    }
+}
+
+/// A generated watermark tile
+#[derive(Debug, Clone, PartialEq)]
+pub struct WatermarkTile {
+    pub data_url: String,
+    pub width: f64,
+    pub height: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct WatermarkConfig {
+    lines: Vec<String>,
+    image: Option<String>,
+    image_size: (f64, f64),
+    rotate: f64,
+    opacity: f64,
+    gap: WatermarkGap,
+    font: WatermarkFont,
+}
+
+/// Bounding box of a `width`×`height` rectangle rotated by `degrees`
+pub fn rotated_bounds(width: f64, height: f64, degrees: f64) -> (f64, f64) {
+    let (sin, cos) = degrees.to_radians().sin_cos();
+    (
+        width * cos.abs() + height * sin.abs(),
+        width * sin.abs() + height * cos.abs(),
+    )
+}
+
+/// Tile size for content of the given size, rotation and gap
+pub fn watermark_tile_size(content: (f64, f64), degrees: f64, gap: WatermarkGap) -> (f64, f64) {
+    let (width, height) = rotated_bounds(content.0, content.1, degrees);
+    ((width + gap.x).ceil(), (height + gap.y).ceil())
+}
+
+/// Inline style of the overlay, with or without a generated tile
+pub fn watermark_overlay_style(tile: Option<&WatermarkTile>, z_index: i32) -> String {
+    let base = format!(
+        "position: absolute; inset: 0; pointer-events: none; z-index: {}; background-repeat: repeat;",
+        z_index
+    );
+    match tile {
+        Some(tile) => format!(
+            "{} background-image: url(\"{}\"); background-size: {}px {}px;",
+            base, tile.data_url, tile.width, tile.height
+        ),
+        None => base,
+    }
+}
+
+type CanvasWithContext = (
+    web_sys::HtmlCanvasElement,
+    web_sys::CanvasRenderingContext2d,
+);
+
+pub(crate) fn create_canvas(width: f64, height: f64) -> Option<CanvasWithContext> {
+    let document = web_sys::window()?.document()?;
+    let canvas = document
+        .create_element("canvas")
+        .ok()?
+        .dyn_into::<web_sys::HtmlCanvasElement>()
+        .ok()?;
+    canvas.set_width(width as u32);
+    canvas.set_height(height as u32);
+    let context = canvas
+        .get_context("2d")
+        .ok()??
+        .dyn_into::<web_sys::CanvasRenderingContext2d>()
+        .ok()?;
+    Some((canvas, context))
+}
+
+fn render_watermark_tile(config: &WatermarkConfig, on_ready: impl Fn(WatermarkTile) + 'static) {
+    match &config.image {
+        Some(src) => render_image_tile(config, src, on_ready),
+        None => {
+            if let Some(tile) = render_text_tile(config) {
+                on_ready(tile);
+            }
+        }
+    }
+}
+
+fn render_text_tile(config: &WatermarkConfig) -> Option<WatermarkTile> {
+    if config.lines.is_empty() {
+        return None;
+    }
+
+    // Measure with a scratch context so the tile can be sized to the text
+    let (_, measure) = create_canvas(1.0, 1.0)?;
+    measure.set_font(&config.font.to_css());
+    let text_width = config
+        .lines
+        .iter()
+        .filter_map(|line| measure.measure_text(line).ok())
+        .map(|metrics| metrics.width())
+        .fold(0.0, f64::max);
+    let text_height = config.font.line_height() * config.lines.len() as f64;
+
+    let (width, height) = watermark_tile_size((text_width, text_height), config.rotate, config.gap);
+    let (canvas, context) = create_canvas(width, height)?;
+    context.translate(width / 2.0, height / 2.0).ok()?;
+    context.rotate(config.rotate.to_radians()).ok()?;
+    context.set_global_alpha(config.opacity);
+    context.set_font(&config.font.to_css());
+    context.set_fill_style_str(&config.font.color);
+    context.set_text_align("center");
+    context.set_text_baseline("middle");
+
+    let first_line = -(config.lines.len() as f64 - 1.0) / 2.0;
+    for (index, line) in config.lines.iter().enumerate() {
+        let y = (first_line + index as f64) * config.font.line_height();
+        context.fill_text(line, 0.0, y).ok()?;
+    }
+
+    Some(WatermarkTile {
+        data_url: canvas.to_data_url().ok()?,
+        width,
+        height,
+    })
+}
+
+fn render_image_tile(
+    config: &WatermarkConfig,
+    src: &str,
+    on_ready: impl Fn(WatermarkTile) + 'static,
+) {
+    let Ok(image) = web_sys::HtmlImageElement::new() else {
+        return;
+    };
+    image.set_cross_origin(Some("anonymous"));
+
+    let (image_width, image_height) = config.image_size;
+    let (width, height) = watermark_tile_size(config.image_size, config.rotate, config.gap);
+    let rotate = config.rotate;
+    let opacity = config.opacity;
+    let loaded = image.clone();
+    let on_load = Closure::once_into_js(move || {
+        let Some((canvas, context)) = create_canvas(width, height) else {
+            return;
+        };
+        let _ = context.translate(width / 2.0, height / 2.0);
+        let _ = context.rotate(rotate.to_radians());
+        context.set_global_alpha(opacity);
+        let drawn = context.draw_image_with_html_image_element_and_dw_and_dh(
+            &loaded,
+            -image_width / 2.0,
+            -image_height / 2.0,
+            image_width,
+            image_height,
+        );
+        if let (Ok(()), Ok(data_url)) = (drawn, canvas.to_data_url()) {
+            on_ready(WatermarkTile {
+                data_url,
+                width,
+                height,
+            });
+        }
+    });
+    image.set_onload(Some(on_load.unchecked_ref()));
+    image.set_src(src);
+}
+
+/// Put the overlay back as rendered; true if anything had to be restored
+fn restore_overlay(
+    container: &web_sys::Element,
+    overlay: &web_sys::Element,
+    expected_style: &str,
+) -> bool {
+    let mut tampered = false;
+    if !container.contains(Some(overlay)) {
+        tampered = container.append_child(overlay).is_ok();
+    }
+    if overlay.get_attribute("style").as_deref() != Some(expected_style) {
+        tampered |= overlay.set_attribute("style", expected_style).is_ok();
+    }
+    if overlay.get_attribute("aria-hidden").as_deref() != Some("true") {
+        tampered |= overlay.set_attribute("aria-hidden", "true").is_ok();
+    }
+    tampered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_rotated_bounds() {
+        let (width, height) = rotated_bounds(100.0, 20.0, 0.0);
+        assert!((width - 100.0).abs() < 1e-9 && (height - 20.0).abs() < 1e-9);
+
+        let (width, height) = rotated_bounds(100.0, 20.0, 90.0);
+        assert!((width - 20.0).abs() < 1e-9 && (height - 100.0).abs() < 1e-9);
+
+        let (width, height) = rotated_bounds(10.0, 10.0, 45.0);
+        assert!((width - 200f64.sqrt()).abs() < 1e-9);
+        assert!((height - 200f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_watermark_tile_size_includes_gap() {
+        let gap = WatermarkGap { x: 50.0, y: 30.0 };
+        assert_eq!(watermark_tile_size((100.0, 20.0), 0.0, gap), (150.0, 50.0));
+    }
+
+    #[test]
+    fn test_watermark_overlay_style() {
+        let style = watermark_overlay_style(None, 9);
+        assert!(style.contains("pointer-events: none"));
+        assert!(style.contains("z-index: 9"));
+        assert!(!style.contains("background-image"));
+
+        let tile = WatermarkTile {
+            data_url: "data:image/png;base64,AAAA".to_string(),
+            width: 200.0,
+            height: 120.0,
+        };
+        let style = watermark_overlay_style(Some(&tile), 3);
+        assert!(style.contains("background-image: url(\"data:image/png;base64,AAAA\")"));
+        assert!(style.contains("background-size: 200px 120px"));
+    }
+
+    #[test]
+    fn test_watermark_font() {
+        let font = WatermarkFont::default();
+        assert_eq!(font.to_css(), "normal 16px sans-serif");
+        assert!((font.line_height() - 22.4).abs() < 1e-9);
+    }
+
+    proptest! {
+        #[test]
+        fn test_tile_always_contains_rotated_content(
+            width in 1.0..500.0f64,
+            height in 1.0..200.0f64,
+            degrees in -180.0..180.0f64,
+        ) {
+            let gap = WatermarkGap::default();
+            let (tile_width, tile_height) = watermark_tile_size((width, height), degrees, gap);
+            let (bounds_width, bounds_height) = rotated_bounds(width, height, degrees);
+            prop_assert!(tile_width >= bounds_width + gap.x - 1e-9);
+            prop_assert!(tile_height >= bounds_height + gap.y - 1e-9);
+        }
+    }
+}
@@ -0,0 +1,632 @@
+//! Development-time ARIA validation
+//!
+//! [`validate_aria`] checks rendered elements for the ARIA mistakes that
+//! slip through review most often: ID references to elements that don't
+//! exist, unknown roles, states a role doesn't support, roles missing a
+//! required state, malformed values and focusable elements hidden from
+//! assistive technology. Each issue names the component it came from, taken
+//! from its `radix-*` class, so the console warning points at the code to
+//! fix.
+//!
+//! The checks only look at attributes, so they run against the live DOM or,
+//! with [`validate_markup`], against server-rendered HTML in unit tests.
+
+use leptos::html::ElementType;
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::Element;
+
+use super::dom::{RenderedDom, RenderedElement};
+use super::spec::AttributeValues;
+
+/// Roles defined by WAI-ARIA 1.2
+const ROLES: &[&str] = &[
+    "alert",
+    "alertdialog",
+    "application",
+    "article",
+    "banner",
+    "blockquote",
+    "button",
+    "caption",
+    "cell",
+    "checkbox",
+    "code",
+    "columnheader",
+    "combobox",
+    "complementary",
+    "contentinfo",
+    "definition",
+    "deletion",
+    "dialog",
+    "directory",
+    "document",
+    "emphasis",
+    "feed",
+    "figure",
+    "form",
+    "generic",
+    "grid",
+    "gridcell",
+    "group",
+    "heading",
+    "img",
+    "insertion",
+    "link",
+    "list",
+    "listbox",
+    "listitem",
+    "log",
+    "main",
+    "marquee",
+    "math",
+    "menu",
+    "menubar",
+    "menuitem",
+    "menuitemcheckbox",
+    "menuitemradio",
+    "meter",
+    "navigation",
+    "none",
+    "note",
+    "option",
+    "paragraph",
+    "presentation",
+    "progressbar",
+    "radio",
+    "radiogroup",
+    "region",
+    "row",
+    "rowgroup",
+    "rowheader",
+    "scrollbar",
+    "search",
+    "searchbox",
+    "separator",
+    "slider",
+    "spinbutton",
+    "status",
+    "strong",
+    "subscript",
+    "superscript",
+    "switch",
+    "tab",
+    "table",
+    "tablist",
+    "tabpanel",
+    "term",
+    "textbox",
+    "time",
+    "timer",
+    "toolbar",
+    "tooltip",
+    "tree",
+    "treegrid",
+    "treeitem",
+];
+
+const RANGE_ROLES: &[&str] = &[
+    "meter",
+    "progressbar",
+    "scrollbar",
+    "separator",
+    "slider",
+    "spinbutton",
+];
+
+/// An attribute, or role, and the names it goes with
+type NameList = (&'static str, &'static [&'static str]);
+
+/// States and properties only some roles support, and those roles
+const ROLE_ATTRIBUTES: &[NameList] = &[
+    (
+        "aria-checked",
+        &[
+            "checkbox",
+            "menuitemcheckbox",
+            "menuitemradio",
+            "option",
+            "radio",
+            "switch",
+            "treeitem",
+        ],
+    ),
+    ("aria-pressed", &["button"]),
+    (
+        "aria-selected",
+        &[
+            "columnheader",
+            "gridcell",
+            "option",
+            "row",
+            "rowheader",
+            "tab",
+            "treeitem",
+        ],
+    ),
+    ("aria-modal", &["alertdialog", "dialog"]),
+    ("aria-sort", &["columnheader", "rowheader"]),
+    (
+        "aria-multiselectable",
+        &["grid", "listbox", "tablist", "tree", "treegrid"],
+    ),
+    ("aria-level", &["heading", "listitem", "row", "treeitem"]),
+    ("aria-valuenow", RANGE_ROLES),
+    ("aria-valuemin", RANGE_ROLES),
+    ("aria-valuemax", RANGE_ROLES),
+    ("aria-valuetext", RANGE_ROLES),
+    ("aria-placeholder", &["searchbox", "textbox"]),
+    ("aria-multiline", &["searchbox", "textbox"]),
+    ("aria-autocomplete", &["combobox", "searchbox", "textbox"]),
+];
+
+/// States a role must have, when it is set explicitly with `role`
+const REQUIRED_ATTRIBUTES: &[NameList] = &[
+    ("checkbox", &["aria-checked"]),
+    ("menuitemcheckbox", &["aria-checked"]),
+    ("menuitemradio", &["aria-checked"]),
+    ("radio", &["aria-checked"]),
+    ("switch", &["aria-checked"]),
+    ("slider", &["aria-valuenow"]),
+    ("scrollbar", &["aria-controls", "aria-valuenow"]),
+    ("heading", &["aria-level"]),
+    ("combobox", &["aria-expanded"]),
+];
+
+/// An attribute and the values it accepts
+type AttributeRule = (&'static str, AttributeValues);
+
+/// Values each state and property accepts
+const ATTRIBUTE_VALUES: &[AttributeRule] = &[
+    ("aria-atomic", AttributeValues::Boolean),
+    ("aria-busy", AttributeValues::Boolean),
+    ("aria-disabled", AttributeValues::Boolean),
+    ("aria-expanded", AttributeValues::Boolean),
+    ("aria-hidden", AttributeValues::Boolean),
+    ("aria-modal", AttributeValues::Boolean),
+    ("aria-multiline", AttributeValues::Boolean),
+    ("aria-multiselectable", AttributeValues::Boolean),
+    ("aria-readonly", AttributeValues::Boolean),
+    ("aria-required", AttributeValues::Boolean),
+    ("aria-selected", AttributeValues::Boolean),
+    ("aria-checked", AttributeValues::Tristate),
+    ("aria-pressed", AttributeValues::Tristate),
+    ("aria-valuenow", AttributeValues::Number),
+    ("aria-valuemin", AttributeValues::Number),
+    ("aria-valuemax", AttributeValues::Number),
+    ("aria-level", AttributeValues::Number),
+    ("aria-posinset", AttributeValues::Number),
+    ("aria-setsize", AttributeValues::Number),
+    (
+        "aria-live",
+        AttributeValues::OneOf(&["off", "polite", "assertive"]),
+    ),
+    (
+        "aria-orientation",
+        AttributeValues::OneOf(&["horizontal", "vertical"]),
+    ),
+    (
+        "aria-sort",
+        AttributeValues::OneOf(&["ascending", "descending", "none", "other"]),
+    ),
+    (
+        "aria-autocomplete",
+        AttributeValues::OneOf(&["inline", "list", "both", "none"]),
+    ),
+    (
+        "aria-haspopup",
+        AttributeValues::OneOf(&["true", "false", "menu", "listbox", "tree", "grid", "dialog"]),
+    ),
+    (
+        "aria-current",
+        AttributeValues::OneOf(&["page", "step", "location", "date", "time", "true", "false"]),
+    ),
+    (
+        "aria-invalid",
+        AttributeValues::OneOf(&["true", "false", "grammar", "spelling"]),
+    ),
+];
+
+/// Attributes holding space-separated ID references
+const ID_LIST_ATTRIBUTES: &[&str] = &[
+    "aria-labelledby",
+    "aria-describedby",
+    "aria-controls",
+    "aria-owns",
+    "aria-flowto",
+    "aria-details",
+    "aria-errormessage",
+    "aria-activedescendant",
+];
+
+/// What is wrong with an element
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AriaIssueKind {
+    /// An ID reference that matches no element
+    MissingReference {
+        attribute: String,
+        id: String,
+    },
+    UnknownRole(String),
+    /// A state or property the element's role doesn't support
+    UnsupportedAttribute {
+        attribute: String,
+        role: String,
+    },
+    MissingRequiredAttribute {
+        attribute: String,
+        role: String,
+    },
+    InvalidValue {
+        attribute: String,
+        value: String,
+        expected: String,
+    },
+    /// `aria-hidden="true"` on an element that can still take focus
+    HiddenFocusable,
+}
+
+impl AriaIssueKind {
+    pub fn describe(&self) -> String {
+        match self {
+            AriaIssueKind::MissingReference { attribute, id } => {
+                format!("{} points to missing id \"{}\"", attribute, id)
+            }
+            AriaIssueKind::UnknownRole(role) => format!("role \"{}\" is not an ARIA role", role),
+            AriaIssueKind::UnsupportedAttribute { attribute, role } => {
+                format!("{} is not supported on role \"{}\"", attribute, role)
+            }
+            AriaIssueKind::MissingRequiredAttribute { attribute, role } => {
+                format!("role \"{}\" requires {}", role, attribute)
+            }
+            AriaIssueKind::InvalidValue {
+                attribute,
+                value,
+                expected,
+            } => format!("{}=\"{}\" is invalid, expected {}", attribute, value, expected),
+            AriaIssueKind::HiddenFocusable => {
+                "aria-hidden=\"true\" on a focusable element; hide it with tabindex=\"-1\" or drop aria-hidden"
+                    .to_string()
+            }
+        }
+    }
+}
+
+/// An ARIA mistake on a rendered element
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AriaIssue {
+    /// Component that rendered the element, when its classes tell
+    pub component: Option<String>,
+    pub tag: String,
+    pub id: Option<String>,
+    pub kind: AriaIssueKind,
+}
+
+impl AriaIssue {
+    /// One-line description for the console
+    pub fn describe(&self) -> String {
+        let element = match &self.id {
+            Some(id) => format!("<{} id=\"{}\">", self.tag, id),
+            None => format!("<{}>", self.tag),
+        };
+        match &self.component {
+            Some(component) => format!("{} {}: {}", component, element, self.kind.describe()),
+            None => format!("{}: {}", element, self.kind.describe()),
+        }
+    }
+}
+
+/// Component name for a class list: the first `radix-*` class in
+/// PascalCase, e.g. `radix-tabs-trigger` is `TabsTrigger`
+pub fn component_from_classes(classes: &str) -> Option<String> {
+    classes
+        .split_whitespace()
+        .find_map(|class| class.strip_prefix("radix-"))
+        .filter(|name| !name.is_empty())
+        .map(pascal_case)
+}
+
+fn pascal_case(name: &str) -> String {
+    name.split('-')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+/// The ARIA issues of one element; `id_exists` resolves ID references
+pub fn element_issues(
+    element: &RenderedElement,
+    id_exists: impl Fn(&str) -> bool,
+) -> Vec<AriaIssueKind> {
+    let mut issues = Vec::new();
+    let explicit_role = element
+        .attribute("role")
+        .and_then(|role| role.split_whitespace().next());
+
+    if let Some(role) = explicit_role {
+        if !ROLES.contains(&role) {
+            issues.push(AriaIssueKind::UnknownRole(role.to_string()));
+        }
+        for (required_by, attributes) in REQUIRED_ATTRIBUTES {
+            if *required_by != role {
+                continue;
+            }
+            for attribute in attributes.iter().filter(|a| !element.has_attribute(a)) {
+                issues.push(AriaIssueKind::MissingRequiredAttribute {
+                    attribute: attribute.to_string(),
+                    role: role.to_string(),
+                });
+            }
+        }
+    }
+
+    // Only judged against a role the element is known to have: native
+    // elements with implicit roles not listed by `role()` are left alone
+    if let Some(role) = element.role().filter(|role| ROLES.contains(role)) {
+        for (attribute, roles) in ROLE_ATTRIBUTES {
+            if element.has_attribute(attribute) && !roles.contains(&role) {
+                issues.push(AriaIssueKind::UnsupportedAttribute {
+                    attribute: attribute.to_string(),
+                    role: role.to_string(),
+                });
+            }
+        }
+    }
+
+    for (attribute, values) in ATTRIBUTE_VALUES {
+        if let Some(value) = element.attribute(attribute) {
+            if !values.accepts(value) {
+                issues.push(AriaIssueKind::InvalidValue {
+                    attribute: attribute.to_string(),
+                    value: value.to_string(),
+                    expected: values.describe(),
+                });
+            }
+        }
+    }
+
+    // A collapsed popup is usually not rendered, so its trigger's
+    // aria-controls can't resolve yet
+    let collapsed = element.attribute("aria-expanded") == Some("false");
+    for attribute in ID_LIST_ATTRIBUTES {
+        if collapsed && *attribute == "aria-controls" {
+            continue;
+        }
+        let Some(value) = element.attribute(attribute) else {
+            continue;
+        };
+        for id in value.split_whitespace().filter(|id| !id_exists(id)) {
+            issues.push(AriaIssueKind::MissingReference {
+                attribute: attribute.to_string(),
+                id: id.to_string(),
+            });
+        }
+    }
+
+    if element.is_hidden() && element.is_focusable() {
+        issues.push(AriaIssueKind::HiddenFocusable);
+    }
+    issues
+}
+
+/// Validate server-rendered markup, resolving ID references within it
+pub fn validate_markup(html: &str) -> Vec<AriaIssue> {
+    let dom = RenderedDom::parse(html);
+    dom.elements
+        .iter()
+        .flat_map(|element| {
+            element_issues(element, |id| dom.element_by_id(id).is_some())
+                .into_iter()
+                .map(|kind| AriaIssue {
+                    component: element.attribute("class").and_then(component_from_classes),
+                    tag: element.tag.clone(),
+                    id: element.attribute("id").map(str::to_string),
+                    kind,
+                })
+        })
+        .collect()
+}
+
+/// Validate `root` and every element under it, resolving ID references
+/// against the whole document
+///
+/// An element without a `radix-*` class is attributed to the closest
+/// ancestor component.
+pub fn validate_aria(root: &Element) -> Vec<AriaIssue> {
+    let Some(document) = root.owner_document() else {
+        return Vec::new();
+    };
+    let mut elements = vec![root.clone()];
+    if let Ok(descendants) = root.query_selector_all("*") {
+        elements.extend(
+            (0..descendants.length())
+                .filter_map(|index| descendants.item(index))
+                .filter_map(|node| node.dyn_into::<Element>().ok()),
+        );
+    }
+
+    let mut issues = Vec::new();
+    for element in elements {
+        let rendered = rendered_element(&element);
+        let kinds = element_issues(&rendered, |id| document.get_element_by_id(id).is_some());
+        if kinds.is_empty() {
+            continue;
+        }
+        let component = owning_component(&element);
+        issues.extend(kinds.into_iter().map(|kind| AriaIssue {
+            component: component.clone(),
+            tag: rendered.tag.clone(),
+            id: rendered.attribute("id").map(str::to_string),
+            kind,
+        }));
+    }
+    issues
+}
+
+/// Validate `root` in debug builds only, logging each issue as a console
+/// warning
+///
+/// Release builds skip the checks and return no issues.
+pub fn validate_aria_in_dev(root: &Element) -> Vec<AriaIssue> {
+    if !cfg!(debug_assertions) {
+        return Vec::new();
+    }
+    let issues = validate_aria(root);
+    for issue in &issues {
+        leptos::logging::warn!("[radix-leptos] aria: {}", issue.describe());
+    }
+    issues
+}
+
+/// Hook validating the subtree behind `target` once it is mounted, in
+/// debug builds
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::html;
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::conformance::use_aria_validation;
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let root = NodeRef::<html::Div>::new();
+///     use_aria_validation(root);
+///
+///     view! { <div node_ref=root>"..."</div> }
+/// }
+/// ```
+pub fn use_aria_validation<E>(target: NodeRef<E>)
+where
+    E: ElementType + 'static,
+    E::Output: JsCast + Clone + 'static,
+{
+    if !cfg!(debug_assertions) {
+        return;
+    }
+    Effect::new(move |_| {
+        if let Some(root) = target.get() {
+            validate_aria_in_dev(root.unchecked_ref::<Element>());
+        }
+    });
+}
+
+fn rendered_element(element: &Element) -> RenderedElement {
+    let names = element.get_attribute_names();
+    let attributes = (0..names.length())
+        .filter_map(|index| names.get(index).as_string())
+        .filter_map(|name| {
+            let value = element.get_attribute(&name)?;
+            Some((name.to_ascii_lowercase(), value))
+        })
+        .collect();
+    RenderedElement {
+        tag: element.tag_name().to_ascii_lowercase(),
+        attributes,
+    }
+}
+
+pub(super) fn owning_component(element: &Element) -> Option<String> {
+    let mut current = Some(element.clone());
+    while let Some(element) = current {
+        if let Some(component) = component_from_classes(&element.class_name()) {
+            return Some(component);
+        }
+        current = element.parent_element();
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(html: &str) -> Vec<AriaIssueKind> {
+        validate_markup(html)
+            .into_iter()
+            .map(|issue| issue.kind)
+            .collect()
+    }
+
+    #[test]
+    fn test_component_from_classes() {
+        assert_eq!(
+            component_from_classes("radix-tabs-trigger active").as_deref(),
+            Some("TabsTrigger")
+        );
+        assert_eq!(
+            component_from_classes("custom radix-switch").as_deref(),
+            Some("Switch")
+        );
+        assert_eq!(component_from_classes("toggle"), None);
+    }
+
+    #[test]
+    fn test_missing_references() {
+        let issues = validate_markup(
+            r#"<div class="radix-dialog" role="dialog" aria-labelledby="title missing"><h2 id="title">Hi</h2></div>"#,
+        );
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues[0].describe(),
+            "Dialog <div>: aria-labelledby points to missing id \"missing\""
+        );
+
+        // A collapsed trigger may point at a popup that isn't rendered yet
+        assert!(
+            kinds(r#"<button aria-expanded="false" aria-controls="menu">Open</button>"#).is_empty()
+        );
+        assert_eq!(
+            kinds(r#"<button aria-expanded="true" aria-controls="menu">Open</button>"#),
+            vec![AriaIssueKind::MissingReference {
+                attribute: "aria-controls".to_string(),
+                id: "menu".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn test_role_and_attribute_combinations() {
+        assert_eq!(
+            kinds(r#"<div role="toggle"></div>"#),
+            vec![AriaIssueKind::UnknownRole("toggle".to_string())]
+        );
+        assert_eq!(
+            kinds(r#"<button aria-checked="true">Bold</button>"#),
+            vec![AriaIssueKind::UnsupportedAttribute {
+                attribute: "aria-checked".to_string(),
+                role: "button".to_string()
+            }]
+        );
+        assert_eq!(
+            kinds(r#"<div role="switch" tabindex="0"></div>"#),
+            vec![AriaIssueKind::MissingRequiredAttribute {
+                attribute: "aria-checked".to_string(),
+                role: "switch".to_string()
+            }]
+        );
+        assert!(kinds(r#"<input type="checkbox"><th aria-sort="ascending">Name</th>"#).is_empty());
+    }
+
+    #[test]
+    fn test_values_and_hidden_focus() {
+        assert_eq!(
+            kinds(r#"<button aria-pressed="yes">Mute</button>"#),
+            vec![AriaIssueKind::InvalidValue {
+                attribute: "aria-pressed".to_string(),
+                value: "yes".to_string(),
+                expected: "true | false | mixed".to_string()
+            }]
+        );
+        assert_eq!(
+            kinds(r#"<a href="/" aria-hidden="true">Home</a>"#),
+            vec![AriaIssueKind::HiddenFocusable]
+        );
+        assert!(kinds(r#"<span aria-hidden="true">★</span>"#).is_empty());
+    }
+}
@@ -0,0 +1,425 @@
+//! Automated accessibility audits with [axe-core](https://github.com/dequelabs/axe-core)
+//!
+//! [`run_axe`] mounts a view into the test page, runs axe-core over it and
+//! returns the violations as typed [`AxeViolation`]s. axe-core is loaded
+//! from [`AXE_CORE_URL`] the first time it is needed, unless the page
+//! already has a global `axe`. Needs a browser, so use it from
+//! `wasm_bindgen_test`s running in one:
+//!
+//! ```rust,no_run
+//! use leptos::prelude::*;
+//! use radix_leptos_primitives::*;
+//! use wasm_bindgen_test::*;
+//!
+//! wasm_bindgen_test_configure!(run_in_browser);
+//!
+//! #[wasm_bindgen_test]
+//! async fn test_button_is_accessible() {
+//!     assert_no_axe_violations!(view! { <Button>"Save"</Button> });
+//! }
+//! ```
+
+use leptos::prelude::*;
+use leptos::serde_json::{self, json, Value};
+use serde::Deserialize;
+use std::fmt;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+
+/// Where axe-core is loaded from when the page doesn't provide it
+pub const AXE_CORE_URL: &str = "https://cdn.jsdelivr.net/npm/axe-core@4.10/axe.min.js";
+
+/// Rule tags checked by default: WCAG 2.0 and 2.1, levels A and AA
+pub const DEFAULT_AXE_TAGS: [&str; 4] = ["wcag2a", "wcag2aa", "wcag21a", "wcag21aa"];
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(catch, js_namespace = axe, js_name = run)]
+    fn axe_run(context: &web_sys::Element, options: &JsValue) -> Result<js_sys::Promise, JsValue>;
+}
+
+/// Why an audit could not run
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum AxeError {
+    #[error("no document to audit")]
+    NoDocument,
+    #[error("axe-core could not be loaded: {0}")]
+    Load(String),
+    #[error("axe-core failed: {0}")]
+    Run(String),
+    #[error("unexpected axe-core results: {0}")]
+    Results(String),
+}
+
+/// How serious axe-core rates a violation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AxeImpact {
+    Minor,
+    Moderate,
+    Serious,
+    Critical,
+}
+
+impl AxeImpact {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AxeImpact::Minor => "minor",
+            AxeImpact::Moderate => "moderate",
+            AxeImpact::Serious => "serious",
+            AxeImpact::Critical => "critical",
+        }
+    }
+}
+
+/// An element breaking a rule
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(from = "RawAxeNode")]
+pub struct AxeNode {
+    /// Selector path to the element; elements inside shadow roots have one
+    /// selector per shadow host, joined with ` >>> `
+    pub target: String,
+    /// Opening tag of the element
+    pub html: String,
+    /// What to fix, as axe-core phrases it
+    pub failure_summary: Option<String>,
+}
+
+/// A node as axe-core reports it
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawAxeNode {
+    target: Vec<TargetPart>,
+    html: String,
+    failure_summary: Option<String>,
+}
+
+/// A selector, or the selectors through shadow roots to an element
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum TargetPart {
+    Selector(String),
+    Shadow(Vec<TargetPart>),
+}
+
+impl TargetPart {
+    fn collect_into(self, selectors: &mut Vec<String>) {
+        match self {
+            TargetPart::Selector(selector) => selectors.push(selector),
+            TargetPart::Shadow(parts) => {
+                for part in parts {
+                    part.collect_into(selectors);
+                }
+            }
+        }
+    }
+}
+
+impl From<RawAxeNode> for AxeNode {
+    fn from(node: RawAxeNode) -> Self {
+        let mut selectors = Vec::new();
+        for part in node.target {
+            part.collect_into(&mut selectors);
+        }
+        Self {
+            target: selectors.join(" >>> "),
+            html: node.html,
+            failure_summary: node.failure_summary,
+        }
+    }
+}
+
+/// A rule broken by one or more elements
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AxeViolation {
+    /// Rule id, e.g. `button-name`
+    pub id: String,
+    pub impact: Option<AxeImpact>,
+    pub description: String,
+    pub help: String,
+    pub help_url: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    pub nodes: Vec<AxeNode>,
+}
+
+impl fmt::Display for AxeViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let impact = self.impact.map_or("unrated", |impact| impact.as_str());
+        write!(f, "{} ({}): {}", self.id, impact, self.help)?;
+        for node in &self.nodes {
+            write!(f, "\n  {} {}", node.target, node.html)?;
+            if let Some(summary) = &node.failure_summary {
+                for line in summary.lines() {
+                    write!(f, "\n    {}", line.trim())?;
+                }
+            }
+        }
+        write!(f, "\n  See {}", self.help_url)
+    }
+}
+
+/// Which rules an audit runs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AxeOptions {
+    /// Rule tags to run, e.g. `wcag2aa` or `best-practice`
+    pub tags: Vec<String>,
+    /// Rules to skip, by id
+    pub disabled_rules: Vec<String>,
+}
+
+impl Default for AxeOptions {
+    fn default() -> Self {
+        Self {
+            tags: DEFAULT_AXE_TAGS.iter().map(|tag| tag.to_string()).collect(),
+            disabled_rules: Vec::new(),
+        }
+    }
+}
+
+impl AxeOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_tags(mut self, tags: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.tags = tags.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn without_rule(mut self, rule: impl Into<String>) -> Self {
+        self.disabled_rules.push(rule.into());
+        self
+    }
+
+    /// The options object passed to `axe.run`
+    pub fn to_json(&self) -> Value {
+        let rules = self
+            .disabled_rules
+            .iter()
+            .map(|rule| (rule.clone(), json!({ "enabled": false })))
+            .collect::<serde_json::Map<_, _>>();
+        json!({
+            "runOnly": { "type": "tag", "values": self.tags },
+            "rules": rules,
+            "resultTypes": ["violations"],
+        })
+    }
+}
+
+/// Violations found by an audit, or why it could not run
+pub type AxeResult = Result<Vec<AxeViolation>, AxeError>;
+
+/// Violations in the JSON of an `axe.run` result
+pub fn parse_axe_violations(results: &str) -> AxeResult {
+    #[derive(Deserialize)]
+    struct AxeResults {
+        violations: Vec<AxeViolation>,
+    }
+
+    serde_json::from_str::<AxeResults>(results)
+        .map(|results| results.violations)
+        .map_err(|error| AxeError::Results(error.to_string()))
+}
+
+/// Failure message listing `violations`, most serious first
+pub fn format_axe_violations(violations: &[AxeViolation]) -> String {
+    let mut violations = violations.iter().collect::<Vec<_>>();
+    violations.sort_by_key(|violation| std::cmp::Reverse(violation.impact));
+    let mut message = format!("{} accessibility violation(s):", violations.len());
+    for violation in violations {
+        message.push_str("\n\n");
+        message.push_str(&violation.to_string());
+    }
+    message
+}
+
+/// Mount `view` into the page and audit it with the default rules
+pub async fn run_axe<F, N>(view: F) -> AxeResult
+where
+    F: FnOnce() -> N + 'static,
+    N: IntoView,
+{
+    run_axe_with(view, &AxeOptions::default()).await
+}
+
+/// Mount `view` into the page and audit it with the rules of `options`
+///
+/// The view is mounted into a container of its own, which is removed again
+/// once the audit finished.
+pub async fn run_axe_with<F, N>(view: F, options: &AxeOptions) -> AxeResult
+where
+    F: FnOnce() -> N + 'static,
+    N: IntoView,
+{
+    let document = web_sys::window()
+        .and_then(|window| window.document())
+        .ok_or(AxeError::NoDocument)?;
+    let body = document.body().ok_or(AxeError::NoDocument)?;
+    load_axe(&document).await?;
+
+    let container = document
+        .create_element("div")
+        .map_err(|error| AxeError::Run(js_error_message(&error)))?;
+    let _ = container.set_attribute("data-axe-container", "");
+    let _ = body.append_child(&container);
+    let handle = leptos::mount::mount_to(container.clone().unchecked_into(), view);
+    // Let effects set the attributes they own before auditing
+    leptos::task::tick().await;
+
+    let results = audit(&container, options).await;
+    drop(handle);
+    container.remove();
+    results
+}
+
+async fn audit(container: &web_sys::Element, options: &AxeOptions) -> AxeResult {
+    let options = js_sys::JSON::parse(&options.to_json().to_string())
+        .map_err(|error| AxeError::Run(js_error_message(&error)))?;
+    let promise =
+        axe_run(container, &options).map_err(|error| AxeError::Run(js_error_message(&error)))?;
+    let results = JsFuture::from(promise)
+        .await
+        .map_err(|error| AxeError::Run(js_error_message(&error)))?;
+    let results = js_sys::JSON::stringify(&results)
+        .ok()
+        .and_then(|results| results.as_string())
+        .ok_or_else(|| AxeError::Results("results are not serializable".to_string()))?;
+    parse_axe_violations(&results)
+}
+
+/// Inject axe-core into the page unless it is there already
+async fn load_axe(document: &web_sys::Document) -> Result<(), AxeError> {
+    let window = web_sys::window().ok_or(AxeError::NoDocument)?;
+    if js_sys::Reflect::has(&window, &"axe".into()).unwrap_or(false) {
+        return Ok(());
+    }
+    let head = document.head().ok_or(AxeError::NoDocument)?;
+    let script = document
+        .create_element("script")
+        .map_err(|error| AxeError::Load(js_error_message(&error)))?;
+    let loaded = js_sys::Promise::new(&mut |resolve, reject| {
+        let _ = script.add_event_listener_with_callback("load", &resolve);
+        let _ = script.add_event_listener_with_callback("error", &reject);
+    });
+    let _ = script.set_attribute("src", AXE_CORE_URL);
+    let _ = head.append_child(&script);
+    JsFuture::from(loaded)
+        .await
+        .map(|_| ())
+        .map_err(|_| AxeError::Load(AXE_CORE_URL.to_string()))
+}
+
+fn js_error_message(error: &JsValue) -> String {
+    error
+        .dyn_ref::<js_sys::Error>()
+        .map(|error| String::from(error.message()))
+        .or_else(|| error.as_string())
+        .unwrap_or_else(|| format!("{:?}", error))
+}
+
+/// Fail the test if axe-core finds accessibility violations in a view
+///
+/// Must be used in an `async` test running in a browser. Takes the view
+/// itself, and optionally [`AxeOptions`]:
+///
+/// ```rust,no_run
+/// # use leptos::prelude::*;
+/// # use radix_leptos_primitives::*;
+/// # use radix_leptos_primitives::conformance::AxeOptions;
+/// # async fn test() {
+/// assert_no_axe_violations!(view! { <Button>"Save"</Button> });
+/// assert_no_axe_violations!(
+///     view! { <Badge>"New"</Badge> },
+///     AxeOptions::new().without_rule("color-contrast")
+/// );
+/// # }
+/// ```
+#[macro_export]
+macro_rules! assert_no_axe_violations {
+    ($view:expr) => {
+        $crate::assert_no_axe_violations!($view, $crate::conformance::AxeOptions::default())
+    };
+    ($view:expr, $options:expr) => {{
+        let violations = $crate::conformance::run_axe_with(move || $view, &$options)
+            .await
+            .unwrap_or_else(|error| panic!("{}", error));
+        assert!(
+            violations.is_empty(),
+            "{}",
+            $crate::conformance::format_axe_violations(&violations)
+        );
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RESULTS: &str = r##"{
+        "testEngine": { "name": "axe-core", "version": "4.10.2" },
+        "violations": [
+            {
+                "id": "region",
+                "impact": "moderate",
+                "tags": ["best-practice"],
+                "description": "Ensure all page content is contained by landmarks",
+                "help": "All page content should be contained by landmarks",
+                "helpUrl": "https://dequeuniversity.com/rules/axe/4.10/region",
+                "nodes": [
+                    { "target": ["#root > p"], "html": "<p>", "failureSummary": null, "impact": "moderate" }
+                ]
+            },
+            {
+                "id": "button-name",
+                "impact": "critical",
+                "tags": ["wcag2a", "wcag412"],
+                "description": "Ensure buttons have discernible text",
+                "help": "Buttons must have discernible text",
+                "helpUrl": "https://dequeuniversity.com/rules/axe/4.10/button-name",
+                "nodes": [
+                    {
+                        "target": [["my-element", "button.icon"]],
+                        "html": "<button class=\"icon\">",
+                        "failureSummary": "Fix any of the following:\n  Element does not have inner text"
+                    }
+                ]
+            }
+        ]
+    }"##;
+
+    #[test]
+    fn test_parse_violations() {
+        let violations = parse_axe_violations(RESULTS).unwrap();
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[1].id, "button-name");
+        assert_eq!(violations[1].impact, Some(AxeImpact::Critical));
+        assert_eq!(violations[1].nodes[0].target, "my-element >>> button.icon");
+        assert_eq!(violations[0].nodes[0].failure_summary, None);
+
+        assert!(matches!(
+            parse_axe_violations("{}"),
+            Err(AxeError::Results(_))
+        ));
+    }
+
+    #[test]
+    fn test_format_violations() {
+        let message = format_axe_violations(&parse_axe_violations(RESULTS).unwrap());
+        assert!(message.starts_with("2 accessibility violation(s):\n\nbutton-name (critical)"));
+        assert!(message.contains("\n    Element does not have inner text"));
+        assert!(message.ends_with("See https://dequeuniversity.com/rules/axe/4.10/region"));
+    }
+
+    #[test]
+    fn test_options_json() {
+        let options = AxeOptions::new()
+            .with_tags(["wcag2aa"])
+            .without_rule("color-contrast")
+            .to_json();
+        assert_eq!(options["runOnly"]["values"], json!(["wcag2aa"]));
+        assert_eq!(options["rules"]["color-contrast"]["enabled"], json!(false));
+    }
+}
@@ -0,0 +1,456 @@
+//! Contrast audit of rendered text
+//!
+//! [`audit_contrast`] walks the elements under a root, works out the color
+//! each piece of text is drawn in and the background it ends up on, after
+//! blending translucent colors and backgrounds of ancestors, and checks the
+//! ratio against WCAG. Where the theme validator checks token pairings,
+//! this catches what the rendered page actually shows, including
+//! application overrides.
+//!
+//! Text over background images or gradients can't be judged from styles
+//! and is counted as undetermined. Disabled controls are exempt from the
+//! contrast criteria and skipped.
+
+use wasm_bindgen::JsCast;
+use web_sys::{CssStyleDeclaration, Element, HtmlElement, Node};
+
+use crate::theming::{Color, ContrastUsage, WcagLevel};
+
+/// Attribute marking failing elements, holding the ratio found and the one
+/// required, e.g. `2.31:4.5`
+pub const CONTRAST_FAILURE_ATTRIBUTE: &str = "data-contrast-failure";
+
+/// Id of the `<style>` element outlining marked elements
+const STYLE_ID: &str = "radix-contrast-audit";
+
+/// Page background browsers fall back to
+const CANVAS: Color = Color {
+    r: 255,
+    g: 255,
+    b: 255,
+};
+
+/// A CSS color with its alpha channel
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rgba {
+    pub color: Color,
+    /// Opacity from 0 to 1
+    pub alpha: f64,
+}
+
+impl Rgba {
+    /// Parse a computed color: `rgb()`/`rgba()` in comma or space syntax,
+    /// `#hex` or `transparent`
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+        if value.eq_ignore_ascii_case("transparent") {
+            return Some(Self {
+                color: Color::new(0, 0, 0),
+                alpha: 0.0,
+            });
+        }
+        if value.starts_with('#') {
+            return Color::from_hex(value).map(|color| Self { color, alpha: 1.0 });
+        }
+        let arguments = value
+            .strip_prefix("rgba(")
+            .or_else(|| value.strip_prefix("rgb("))?
+            .strip_suffix(')')?;
+        let parts = arguments
+            .split(|c: char| c == ',' || c == '/' || c.is_whitespace())
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>();
+        let channel = |part: &str| -> Option<u8> {
+            let value = match part.strip_suffix('%') {
+                Some(percent) => percent.parse::<f64>().ok()? * 2.55,
+                None => part.parse::<f64>().ok()?,
+            };
+            Some(value.round().clamp(0.0, 255.0) as u8)
+        };
+        let alpha = match parts.get(3) {
+            Some(alpha) => match alpha.strip_suffix('%') {
+                Some(percent) => percent.parse::<f64>().ok()? / 100.0,
+                None => alpha.parse::<f64>().ok()?,
+            },
+            None => 1.0,
+        };
+        if parts.len() < 3 || parts.len() > 4 {
+            return None;
+        }
+        Some(Self {
+            color: Color::new(channel(parts[0])?, channel(parts[1])?, channel(parts[2])?),
+            alpha: alpha.clamp(0.0, 1.0),
+        })
+    }
+
+    /// This color drawn over an opaque `background`
+    pub fn over(&self, background: Color) -> Color {
+        let blend = |top: u8, bottom: u8| {
+            (f64::from(top) * self.alpha + f64::from(bottom) * (1.0 - self.alpha)).round() as u8
+        };
+        Color::new(
+            blend(self.color.r, background.r),
+            blend(self.color.g, background.g),
+            blend(self.color.b, background.b),
+        )
+    }
+}
+
+/// Whether text of this computed size and weight counts as large: at least
+/// 18pt (24px), or 14pt (18.66px) when bold
+pub fn is_large_text(font_size_px: f64, font_weight: u16) -> bool {
+    font_size_px >= 24.0 || (font_weight >= 700 && font_size_px >= 18.66)
+}
+
+/// Text whose contrast is too low
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContrastFailure {
+    pub element: Element,
+    /// Start of the text, for the log
+    pub text: String,
+    pub foreground: Color,
+    pub background: Color,
+    pub ratio: f64,
+    pub required: f64,
+    pub large_text: bool,
+}
+
+impl ContrastFailure {
+    /// One-line description for the console
+    pub fn describe(&self) -> String {
+        format!(
+            "<{}> \"{}\": {} on {} is {:.2}:1, needs {}:1{}",
+            self.element.tag_name().to_lowercase(),
+            self.text,
+            self.foreground,
+            self.background,
+            self.ratio,
+            self.required,
+            if self.large_text { " (large text)" } else { "" }
+        )
+    }
+}
+
+/// How [`audit_contrast`] checks and reports
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContrastAuditOptions {
+    pub level: WcagLevel,
+    /// Set [`CONTRAST_FAILURE_ATTRIBUTE`] on failing elements and outline
+    /// them
+    pub mark: bool,
+    /// Log each failure as a console warning
+    pub log: bool,
+}
+
+impl Default for ContrastAuditOptions {
+    fn default() -> Self {
+        Self {
+            level: WcagLevel::AA,
+            mark: true,
+            log: true,
+        }
+    }
+}
+
+/// Outcome of a contrast audit
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ContrastAudit {
+    /// Elements with text whose contrast was measured
+    pub checked: usize,
+    /// Elements with text over an image or gradient
+    pub undetermined: usize,
+    pub failures: Vec<ContrastFailure>,
+}
+
+impl ContrastAudit {
+    pub fn passed(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// Check the contrast of every rendered text under `root`, including `root`
+///
+/// Marks from an earlier audit are cleared first, so the audit can run
+/// again after the page changed.
+pub fn audit_contrast(root: &Element, options: ContrastAuditOptions) -> ContrastAudit {
+    let mut audit = ContrastAudit::default();
+    let Some(window) = web_sys::window() else {
+        return audit;
+    };
+    clear_contrast_marks(root);
+
+    let mut elements = vec![root.clone()];
+    if let Ok(descendants) = root.query_selector_all("*") {
+        elements.extend(
+            (0..descendants.length())
+                .filter_map(|index| descendants.item(index))
+                .filter_map(|node| node.dyn_into::<Element>().ok()),
+        );
+    }
+
+    for element in elements {
+        let Some(text) = own_text(&element) else {
+            continue;
+        };
+        if is_exempt(&element) {
+            continue;
+        }
+        let Some(style) = window.get_computed_style(&element).ok().flatten() else {
+            continue;
+        };
+        if !is_rendered(&element, &style) {
+            continue;
+        }
+        let Some(background) = effective_background(&window, &element) else {
+            audit.undetermined += 1;
+            continue;
+        };
+        let Some(foreground) = Rgba::parse(&property(&style, "color")) else {
+            continue;
+        };
+        audit.checked += 1;
+
+        let foreground = foreground.over(background);
+        let font_size = property(&style, "font-size")
+            .trim_end_matches("px")
+            .parse()
+            .unwrap_or(16.0);
+        let font_weight = property(&style, "font-weight").parse().unwrap_or(400);
+        let large_text = is_large_text(font_size, font_weight);
+        let usage = if large_text {
+            ContrastUsage::LargeText
+        } else {
+            ContrastUsage::Text
+        };
+        let required = usage.required_ratio(options.level);
+        let ratio = foreground.contrast_ratio(&background);
+        if ratio >= required {
+            continue;
+        }
+
+        let failure = ContrastFailure {
+            element,
+            text: text.chars().take(40).collect(),
+            foreground,
+            background,
+            ratio,
+            required,
+            large_text,
+        };
+        if options.log {
+            leptos::logging::warn!("[radix-leptos] contrast: {}", failure.describe());
+        }
+        if options.mark {
+            let _ = failure.element.set_attribute(
+                CONTRAST_FAILURE_ATTRIBUTE,
+                &format!("{:.2}:{}", failure.ratio, failure.required),
+            );
+        }
+        audit.failures.push(failure);
+    }
+
+    if options.mark && !audit.failures.is_empty() {
+        write_mark_stylesheet();
+    }
+    audit
+}
+
+/// Audit `root` in debug builds only, e.g. from the docs playground
+///
+/// Release builds skip the audit and return an empty result.
+pub fn audit_contrast_in_dev(root: &Element) -> ContrastAudit {
+    if cfg!(debug_assertions) {
+        audit_contrast(root, ContrastAuditOptions::default())
+    } else {
+        ContrastAudit::default()
+    }
+}
+
+/// Remove the marks of earlier audits under `root`
+pub fn clear_contrast_marks(root: &Element) {
+    let _ = root.remove_attribute(CONTRAST_FAILURE_ATTRIBUTE);
+    let Ok(marked) = root.query_selector_all(&format!("[{}]", CONTRAST_FAILURE_ATTRIBUTE)) else {
+        return;
+    };
+    for index in 0..marked.length() {
+        if let Some(element) = marked
+            .item(index)
+            .and_then(|node| node.dyn_into::<Element>().ok())
+        {
+            let _ = element.remove_attribute(CONTRAST_FAILURE_ATTRIBUTE);
+        }
+    }
+}
+
+/// Text directly inside `element`, not in its child elements
+fn own_text(element: &Element) -> Option<String> {
+    let children = element.child_nodes();
+    let text = (0..children.length())
+        .filter_map(|index| children.item(index))
+        .filter(|node| node.node_type() == Node::TEXT_NODE)
+        .filter_map(|node| node.text_content())
+        .collect::<String>();
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    (!text.is_empty()).then_some(text)
+}
+
+/// Disabled controls and text hidden from everyone have no contrast
+/// requirement
+fn is_exempt(element: &Element) -> bool {
+    let tag = element.tag_name().to_lowercase();
+    matches!(tag.as_str(), "script" | "style" | "noscript" | "template")
+        || element.closest(":disabled").ok().flatten().is_some()
+        || element
+            .closest("[aria-disabled=\"true\"]")
+            .ok()
+            .flatten()
+            .is_some()
+}
+
+fn is_rendered(element: &Element, style: &CssStyleDeclaration) -> bool {
+    if property(style, "visibility") != "visible" {
+        return false;
+    }
+    element
+        .dyn_ref::<HtmlElement>()
+        .is_none_or(|element| element.offset_width() > 0 || element.offset_height() > 0)
+}
+
+/// The opaque color behind `element`, blending translucent backgrounds of
+/// it and its ancestors; `None` when an image or gradient is in the way
+fn effective_background(window: &web_sys::Window, element: &Element) -> Option<Color> {
+    let mut layers = Vec::new();
+    let mut current = Some(element.clone());
+    while let Some(element) = current {
+        if let Some(style) = window.get_computed_style(&element).ok().flatten() {
+            if property(&style, "background-image") != "none" {
+                return None;
+            }
+            if let Some(color) = Rgba::parse(&property(&style, "background-color")) {
+                if color.alpha >= 1.0 {
+                    return Some(blend_layers(&layers, color.color));
+                }
+                if color.alpha > 0.0 {
+                    layers.push(color);
+                }
+            }
+        }
+        current = element.parent_element();
+    }
+    Some(blend_layers(&layers, CANVAS))
+}
+
+/// Draw translucent `layers`, innermost first, over an opaque `base`
+fn blend_layers(layers: &[Rgba], base: Color) -> Color {
+    layers
+        .iter()
+        .rev()
+        .fold(base, |background, layer| layer.over(background))
+}
+
+fn property(style: &CssStyleDeclaration, name: &str) -> String {
+    style.get_property_value(name).unwrap_or_default()
+}
+
+fn write_mark_stylesheet() {
+    let Some(document) = web_sys::window().and_then(|window| window.document()) else {
+        return;
+    };
+    if document.get_element_by_id(STYLE_ID).is_some() {
+        return;
+    }
+    let Ok(style) = document.create_element("style") else {
+        return;
+    };
+    style.set_id(STYLE_ID);
+    style.set_text_content(Some(&format!(
+        "[{attr}] {{ outline: 2px dashed #d9381e !important; outline-offset: 2px; }}",
+        attr = CONTRAST_FAILURE_ATTRIBUTE
+    )));
+    if let Some(head) = document.head() {
+        let _ = head.append_child(&style);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[test]
+    fn test_parse_computed_colors() {
+        assert_eq!(
+            Rgba::parse("rgb(17, 24, 39)"),
+            Some(Rgba {
+                color: Color::new(17, 24, 39),
+                alpha: 1.0
+            })
+        );
+        assert_eq!(
+            Rgba::parse("rgba(0, 0, 0, 0.5)").map(|rgba| rgba.alpha),
+            Some(0.5)
+        );
+        assert_eq!(
+            Rgba::parse("rgb(255 128 0 / 25%)"),
+            Some(Rgba {
+                color: Color::new(255, 128, 0),
+                alpha: 0.25
+            })
+        );
+        assert_eq!(Rgba::parse("transparent").map(|rgba| rgba.alpha), Some(0.0));
+        assert_eq!(
+            Rgba::parse("#fff").map(|rgba| rgba.color),
+            Some(Color::new(255, 255, 255))
+        );
+        assert_eq!(Rgba::parse("color(srgb 1 0 0)"), None);
+        assert_eq!(Rgba::parse("rgb(1, 2)"), None);
+    }
+
+    #[test]
+    fn test_blending() {
+        let half_black = Rgba::parse("rgba(0, 0, 0, 0.5)").unwrap();
+        assert_eq!(half_black.over(CANVAS), Color::new(128, 128, 128));
+        // Two translucent layers over white, the inner one drawn last
+        let layers = [Rgba::parse("rgba(255, 0, 0, 0.5)").unwrap(), half_black];
+        assert_eq!(blend_layers(&layers, CANVAS), Color::new(192, 64, 64));
+    }
+
+    #[test]
+    fn test_large_text() {
+        assert!(is_large_text(24.0, 400));
+        assert!(is_large_text(18.66, 700));
+        assert!(!is_large_text(18.66, 400));
+        assert!(!is_large_text(16.0, 700));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_audit_marks_low_contrast_text() {
+        let document = web_sys::window().unwrap().document().unwrap();
+        let root = document.create_element("div").unwrap();
+        root.set_inner_html(
+            r#"<div style="background: #ffffff">
+                 <p id="ok" style="color: #111827">Readable</p>
+                 <p id="faint" style="color: #d1d5db">Faint</p>
+                 <button disabled style="color: #e5e7eb">Disabled</button>
+               </div>"#,
+        );
+        document.body().unwrap().append_child(&root).unwrap();
+
+        let audit = audit_contrast(
+            &root,
+            ContrastAuditOptions {
+                log: false,
+                ..Default::default()
+            },
+        );
+        assert_eq!(audit.failures.len(), 1);
+        assert_eq!(audit.failures[0].element.id(), "faint");
+        assert!(document
+            .get_element_by_id("faint")
+            .unwrap()
+            .has_attribute(CONTRAST_FAILURE_ATTRIBUTE));
+        root.remove();
+    }
+}
@@ -0,0 +1,240 @@
+/// An element of server-rendered markup
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderedElement {
+    pub tag: String,
+    pub attributes: Vec<RenderedAttribute>,
+}
+
+/// Attribute name and unescaped value
+pub type RenderedAttribute = (String, String);
+
+impl RenderedElement {
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(attribute, _)| attribute == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn has_attribute(&self, name: &str) -> bool {
+        self.attribute(name).is_some()
+    }
+
+    /// Explicit `role`, or the implicit role of the native element
+    pub fn role(&self) -> Option<&str> {
+        if let Some(role) = self.attribute("role") {
+            return role.split_whitespace().next();
+        }
+        let role = match self.tag.as_str() {
+            "button" => "button",
+            "a" if self.has_attribute("href") => "link",
+            "input" => match self.attribute("type").unwrap_or("text") {
+                "checkbox" => "checkbox",
+                "radio" => "radio",
+                "range" => "slider",
+                "button" | "submit" | "reset" => "button",
+                "hidden" => return None,
+                _ => "textbox",
+            },
+            "textarea" => "textbox",
+            "select" => "combobox",
+            "ul" | "ol" => "list",
+            "li" => "listitem",
+            "nav" => "navigation",
+            "dialog" => "dialog",
+            "progress" => "progressbar",
+            "hr" => "separator",
+            "table" => "table",
+            _ => return None,
+        };
+        Some(role)
+    }
+
+    /// Hidden from assistive technology with `aria-hidden="true"`
+    pub fn is_hidden(&self) -> bool {
+        self.attribute("aria-hidden") == Some("true")
+    }
+
+    /// Reachable with the keyboard
+    pub fn is_focusable(&self) -> bool {
+        if self.has_attribute("disabled") {
+            return false;
+        }
+        if let Some(tabindex) = self.attribute("tabindex") {
+            return tabindex.trim().parse::<i32>().is_ok();
+        }
+        match self.tag.as_str() {
+            "button" | "select" | "textarea" => true,
+            "a" => self.has_attribute("href"),
+            "input" => self.attribute("type") != Some("hidden"),
+            _ => false,
+        }
+    }
+}
+
+/// Flat list of the elements in a piece of server-rendered markup
+///
+/// This is a small tag scanner for the well-formed HTML Leptos renders, not a
+/// general HTML parser: text, comments and closing tags are skipped.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RenderedDom {
+    pub elements: Vec<RenderedElement>,
+}
+
+impl RenderedDom {
+    pub fn parse(html: &str) -> Self {
+        let mut elements = Vec::new();
+        let mut rest = html;
+
+        while let Some(start) = rest.find('<') {
+            rest = &rest[start..];
+            if let Some(comment) = rest.strip_prefix("<!--") {
+                rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+                continue;
+            }
+            if rest.starts_with("</") || rest.starts_with("<!") {
+                rest = rest.find('>').map_or("", |end| &rest[end + 1..]);
+                continue;
+            }
+            rest = &rest[1..];
+            if let Some(element) = parse_start_tag(&mut rest) {
+                elements.push(element);
+            }
+        }
+
+        Self { elements }
+    }
+
+    /// Elements exposed to assistive technology with the given role
+    pub fn with_role<'a>(&'a self, role: &'a str) -> impl Iterator<Item = &'a RenderedElement> {
+        self.elements
+            .iter()
+            .filter(move |element| !element.is_hidden() && element.role() == Some(role))
+    }
+
+    pub fn element_by_id(&self, id: &str) -> Option<&RenderedElement> {
+        self.elements
+            .iter()
+            .find(|element| element.attribute("id") == Some(id))
+    }
+}
+
+/// Parse a start tag after its `<`, advancing `input` past the tag
+fn parse_start_tag(input: &mut &str) -> Option<RenderedElement> {
+    let source = *input;
+    let name_end = source
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '-'))
+        .unwrap_or(source.len());
+    if name_end == 0 {
+        return None;
+    }
+    let tag = source[..name_end].to_ascii_lowercase();
+    let mut attributes = Vec::new();
+    let mut rest = &source[name_end..];
+
+    loop {
+        rest = rest.trim_start();
+        if rest.is_empty() {
+            break;
+        }
+        if let Some(after) = rest.strip_prefix("/>").or_else(|| rest.strip_prefix('>')) {
+            rest = after;
+            break;
+        }
+        let name_end = rest
+            .find(|c: char| c.is_whitespace() || matches!(c, '=' | '>' | '/'))
+            .unwrap_or(rest.len());
+        if name_end == 0 {
+            // Stray `/` inside the tag
+            rest = &rest[1..];
+            continue;
+        }
+        let name = rest[..name_end].to_ascii_lowercase();
+        rest = rest[name_end..].trim_start();
+
+        let value = match rest.strip_prefix('=') {
+            Some(after) => {
+                let after = after.trim_start();
+                match after.chars().next() {
+                    Some(quote @ ('"' | '\'')) => {
+                        let body = &after[1..];
+                        let end = body.find(quote).unwrap_or(body.len());
+                        rest = body.get(end + 1..).unwrap_or("");
+                        unescape(&body[..end])
+                    }
+                    _ => {
+                        let end = after
+                            .find(|c: char| c.is_whitespace() || c == '>')
+                            .unwrap_or(after.len());
+                        rest = &after[end..];
+                        unescape(&after[..end])
+                    }
+                }
+            }
+            None => String::new(),
+        };
+        attributes.push((name, value));
+    }
+
+    *input = rest;
+    Some(RenderedElement { tag, attributes })
+}
+
+fn unescape(value: &str) -> String {
+    value
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_parse_elements_and_attributes() {
+        let dom = RenderedDom::parse(
+            r#"<div role="switch" aria-checked data-x='a &amp; b' class=plain><!--<() />--><span>text</span><input type="checkbox" disabled/></div>"#,
+        );
+        assert_eq!(dom.elements.len(), 3);
+        let switch = &dom.elements[0];
+        assert_eq!(switch.tag, "div");
+        assert_eq!(switch.role(), Some("switch"));
+        assert_eq!(switch.attribute("aria-checked"), Some(""));
+        assert_eq!(switch.attribute("data-x"), Some("a & b"));
+        assert_eq!(switch.attribute("class"), Some("plain"));
+        assert_eq!(dom.elements[1].tag, "span");
+        assert_eq!(dom.elements[2].role(), Some("checkbox"));
+        assert!(!dom.elements[2].is_focusable());
+    }
+
+    #[test]
+    fn test_implicit_roles_and_focus() {
+        let dom = RenderedDom::parse(
+            r#"<button>Go</button><a>plain</a><a href="/x">link</a><div tabindex="-1"></div><div tabindex="nope"></div>"#,
+        );
+        let roles = dom.elements.iter().map(|e| e.role()).collect::<Vec<_>>();
+        assert_eq!(roles, vec![Some("button"), None, Some("link"), None, None]);
+        let focusable: Vec<bool> = dom.elements.iter().map(|e| e.is_focusable()).collect();
+        assert_eq!(focusable, vec![true, false, true, true, false]);
+    }
+
+    #[test]
+    fn test_hidden_elements_have_no_role() {
+        let dom = RenderedDom::parse(
+            r#"<input type="checkbox" aria-hidden="true"><div role="checkbox"></div>"#,
+        );
+        assert_eq!(dom.with_role("checkbox").count(), 1);
+        assert_eq!(dom.element_by_id("missing"), None);
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_never_panics(html in ".*") {
+            let _ = RenderedDom::parse(&html);
+        }
+    }
+}
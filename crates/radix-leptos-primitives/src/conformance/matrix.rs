@@ -0,0 +1,374 @@
+use leptos::prelude::Owner;
+use leptos::serde_json;
+use serde::Serialize;
+
+use super::dom::RenderedDom;
+use super::spec::{ConformanceCheck, ConformanceSpec};
+
+/// Outcome of one conformance check
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "status", content = "detail", rename_all = "lowercase")]
+pub enum CheckStatus {
+    Pass,
+    Fail(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CheckResult {
+    pub description: String,
+    #[serde(flatten)]
+    pub status: CheckStatus,
+}
+
+/// Results of one component's spec
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ComponentConformance {
+    pub component: &'static str,
+    pub pattern: &'static str,
+    pub guide_url: &'static str,
+    pub results: Vec<CheckResult>,
+}
+
+impl ComponentConformance {
+    pub fn passed(&self) -> usize {
+        self.count(|status| *status == CheckStatus::Pass)
+    }
+
+    pub fn failed(&self) -> usize {
+        self.count(|status| matches!(status, CheckStatus::Fail(_)))
+    }
+
+    /// No automated check failed
+    pub fn conforms(&self) -> bool {
+        self.failed() == 0
+    }
+
+    fn count(&self, predicate: impl Fn(&CheckStatus) -> bool) -> usize {
+        self.results
+            .iter()
+            .filter(|result| predicate(&result.status))
+            .count()
+    }
+}
+
+/// Pass/fail matrix of every spec
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ConformanceMatrix {
+    pub components: Vec<ComponentConformance>,
+}
+
+impl ConformanceMatrix {
+    /// Server-render each component and check its markup against its spec
+    pub fn run<'a>(specs: impl IntoIterator<Item = &'a ConformanceSpec>) -> Self {
+        let components = specs
+            .into_iter()
+            .map(|spec| {
+                let owner = Owner::new();
+                let html = owner.with(spec.render);
+                check_markup(spec, &html)
+            })
+            .collect();
+        Self { components }
+    }
+
+    pub fn component(&self, name: &str) -> Option<&ComponentConformance> {
+        self.components
+            .iter()
+            .find(|component| component.component == name)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+
+    /// Summary table followed by the failed checks
+    pub fn to_markdown(&self) -> String {
+        let mut markdown =
+            String::from("| Component | Pattern | Passed | Failed |\n|---|---|---|---|\n");
+        for component in &self.components {
+            markdown.push_str(&format!(
+                "| {} | [{}]({}) | {} | {} |\n",
+                component.component,
+                component.pattern,
+                component.guide_url,
+                component.passed(),
+                component.failed()
+            ));
+        }
+
+        let failures: Vec<String> = self
+            .components
+            .iter()
+            .flat_map(|component| {
+                component
+                    .results
+                    .iter()
+                    .filter_map(move |result| match &result.status {
+                        CheckStatus::Fail(detail) => Some(format!(
+                            "- **{}**: {} — {}\n",
+                            component.component, result.description, detail
+                        )),
+                        _ => None,
+                    })
+            })
+            .collect();
+        if !failures.is_empty() {
+            markdown.push_str("\n### Failures\n\n");
+            markdown.extend(failures);
+        }
+        markdown
+    }
+}
+
+/// Check server-rendered markup against a spec
+///
+/// Only the markup checks are reported; the spec's keyboard interactions
+/// are not verified here.
+pub fn check_markup(spec: &ConformanceSpec, html: &str) -> ComponentConformance {
+    let dom = RenderedDom::parse(html);
+    let results = spec
+        .checks
+        .iter()
+        .map(|check| CheckResult {
+            description: check.describe(),
+            status: run_check(check, &dom),
+        })
+        .collect();
+
+    ComponentConformance {
+        component: spec.component,
+        pattern: spec.pattern,
+        guide_url: spec.guide_url,
+        results,
+    }
+}
+
+fn run_check(check: &ConformanceCheck, dom: &RenderedDom) -> CheckStatus {
+    let missing_role = |role: &str| CheckStatus::Fail(format!("no element with role `{}`", role));
+
+    match *check {
+        ConformanceCheck::Role(role) => {
+            if dom.with_role(role).next().is_some() {
+                CheckStatus::Pass
+            } else {
+                missing_role(role)
+            }
+        }
+        ConformanceCheck::Focusable(role) => {
+            let mut elements = dom.with_role(role).peekable();
+            if elements.peek().is_none() {
+                missing_role(role)
+            } else if elements.any(|element| element.is_focusable()) {
+                CheckStatus::Pass
+            } else {
+                CheckStatus::Fail(format!("no `{}` is in the tab order", role))
+            }
+        }
+        ConformanceCheck::State {
+            role,
+            attribute,
+            values,
+        } => {
+            let mut elements = dom.with_role(role).peekable();
+            if elements.peek().is_none() {
+                return missing_role(role);
+            }
+            for element in elements {
+                match element.attribute(attribute) {
+                    None => return CheckStatus::Fail(format!("`{}` is missing", attribute)),
+                    Some(value) if !values.accepts(value) => {
+                        return CheckStatus::Fail(format!(
+                            "`{}=\"{}\"` is not {}",
+                            attribute,
+                            value,
+                            values.describe()
+                        ))
+                    }
+                    Some(_) => {}
+                }
+            }
+            CheckStatus::Pass
+        }
+        ConformanceCheck::References { role, attribute } => {
+            let mut elements = dom.with_role(role).peekable();
+            if elements.peek().is_none() {
+                return missing_role(role);
+            }
+            for element in elements {
+                let Some(ids) = element.attribute(attribute) else {
+                    return CheckStatus::Fail(format!("`{}` is missing", attribute));
+                };
+                if let Some(id) = ids
+                    .split_whitespace()
+                    .find(|id| dom.element_by_id(id).is_none())
+                {
+                    return CheckStatus::Fail(format!(
+                        "`{}` references missing id `{}`",
+                        attribute, id
+                    ));
+                }
+            }
+            CheckStatus::Pass
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conformance::{conformance_specs, AttributeValues, KeyboardInteraction};
+    use crate::test_support::init_test_executor;
+
+    fn spec(checks: Vec<ConformanceCheck>) -> ConformanceSpec {
+        ConformanceSpec {
+            component: "Fixture",
+            pattern: "Switch",
+            guide_url: "https://www.w3.org/WAI/ARIA/apg/patterns/switch/",
+            render: String::new,
+            checks,
+            keyboard: vec![KeyboardInteraction {
+                keys: "Space",
+                action: "Toggles",
+            }],
+        }
+    }
+
+    fn switch_checks() -> Vec<ConformanceCheck> {
+        vec![
+            ConformanceCheck::Role("switch"),
+            ConformanceCheck::Focusable("switch"),
+            ConformanceCheck::State {
+                role: "switch",
+                attribute: "aria-checked",
+                values: AttributeValues::Boolean,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_conforming_markup_passes() {
+        let result = check_markup(
+            &spec(switch_checks()),
+            r#"<button role="switch" aria-checked="true">Wi-Fi</button>"#,
+        );
+        assert_eq!(result.passed(), 3);
+        assert_eq!(result.results.len(), 3);
+        assert!(result.conforms());
+    }
+
+    #[test]
+    fn test_failures_explain_the_problem() {
+        let result = check_markup(
+            &spec(switch_checks()),
+            r#"<div role="switch" aria-checked>Wi-Fi</div>"#,
+        );
+        assert_eq!(result.results[0].status, CheckStatus::Pass);
+        assert_eq!(
+            result.results[1].status,
+            CheckStatus::Fail("no `switch` is in the tab order".to_string())
+        );
+        assert_eq!(
+            result.results[2].status,
+            CheckStatus::Fail("`aria-checked=\"\"` is not true | false".to_string())
+        );
+        assert!(!result.conforms());
+    }
+
+    #[test]
+    fn test_references_must_resolve() {
+        let checks = vec![ConformanceCheck::References {
+            role: "tab",
+            attribute: "aria-controls",
+        }];
+        let ok = check_markup(
+            &spec(checks.clone()),
+            r#"<button role="tab" aria-controls="p1"></button><div id="p1" role="tabpanel"></div>"#,
+        );
+        assert!(ok.conforms());
+        let broken = check_markup(
+            &spec(checks),
+            r#"<button role="tab" aria-controls="p1 p2"></button><div id="p1"></div>"#,
+        );
+        assert_eq!(
+            broken.results[0].status,
+            CheckStatus::Fail("`aria-controls` references missing id `p2`".to_string())
+        );
+    }
+
+    #[test]
+    fn test_matrix_covers_every_spec() {
+        init_test_executor();
+        let specs = conformance_specs();
+        let matrix = ConformanceMatrix::run(&specs);
+        assert_eq!(matrix.components.len(), specs.len());
+        for (component, spec) in matrix.components.iter().zip(&specs) {
+            assert_eq!(component.results.len(), spec.checks.len());
+        }
+
+        let markdown = matrix.to_markdown();
+        assert!(markdown.starts_with("| Component | Pattern |"));
+        assert!(matrix.to_json().contains("\"component\": \"Tabs\""));
+    }
+
+    // Checks each component is known to fail, by description; any other
+    // failure is a regression. Remove an entry once the check passes
+    fn accepted_failures(component: &str) -> &'static [&'static str] {
+        match component {
+            "Accordion" => &[
+                "`button` `aria-controls` references existing elements",
+                "`region` `aria-labelledby` references existing elements",
+            ],
+            "Checkbox" => &[
+                "exposes role `checkbox`",
+                "`checkbox` is focusable",
+                "`checkbox` has `aria-checked` (true | false | mixed)",
+            ],
+            "Collapsible" => &["`button` has `aria-expanded` (true | false)"],
+            "Progress" => &["`progressbar` has `aria-valuenow` (a number)"],
+            "RadioGroup" => &[
+                "`radio` is focusable",
+                "`radio` has `aria-checked` (true | false)",
+            ],
+            "Switch" => &[
+                "`switch` is focusable",
+                "`switch` has `aria-checked` (true | false)",
+            ],
+            "Tabs" => &[
+                "`tab` `aria-controls` references existing elements",
+                "`tabpanel` `aria-labelledby` references existing elements",
+            ],
+            "Toggle" => &["`button` has `aria-pressed` (true | false | mixed)"],
+            _ => &[],
+        }
+    }
+
+    #[test]
+    fn test_conformance_does_not_regress() {
+        init_test_executor();
+        let matrix = ConformanceMatrix::run(&conformance_specs());
+        let mut failures = Vec::new();
+        for component in &matrix.components {
+            let accepted = accepted_failures(component.component);
+            for result in &component.results {
+                match &result.status {
+                    CheckStatus::Fail(detail)
+                        if !accepted.contains(&result.description.as_str()) =>
+                    {
+                        failures.push(format!(
+                            "{}: {} — {}",
+                            component.component, result.description, detail
+                        ));
+                    }
+                    CheckStatus::Pass if accepted.contains(&result.description.as_str()) => {
+                        failures.push(format!(
+                            "{}: {} passes now; remove it from the accepted failures",
+                            component.component, result.description
+                        ));
+                    }
+                    _ => {}
+                }
+            }
+        }
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+}
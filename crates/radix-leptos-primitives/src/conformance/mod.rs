@@ -0,0 +1,29 @@
+//! WAI-ARIA pattern conformance
+//!
+//! Each [`ConformanceSpec`] encodes the roles, states and keyboard
+//! interactions the ARIA Authoring Practices expect of a component.
+//! [`ConformanceMatrix::run`] server-renders every component, checks the
+//! markup against its spec and reports a pass/fail matrix; keyboard
+//! interactions need a browser and are left to the end-to-end suite.
+//!
+//! In the browser, [`run_axe`] and [`assert_no_axe_violations!`](crate::assert_no_axe_violations) audit
+//! mounted components with axe-core, [`audit_contrast`] checks the
+//! contrast of rendered text, [`audit_target_size`] outlines controls too
+//! small to hit and, in development builds, [`validate_aria_in_dev`] warns
+//! about ARIA mistakes in rendered components.
+
+pub mod aria_validator;
+pub mod axe;
+pub mod contrast_audit;
+pub mod dom;
+pub mod matrix;
+pub mod spec;
+pub mod target_size_audit;
+
+pub use aria_validator::*;
+pub use axe::*;
+pub use contrast_audit::*;
+pub use dom::*;
+pub use matrix::*;
+pub use spec::*;
+pub use target_size_audit::*;
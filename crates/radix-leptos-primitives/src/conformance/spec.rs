@@ -0,0 +1,394 @@
+use leptos::prelude::*;
+
+use crate::components::*;
+
+/// Values an ARIA state or property may take
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttributeValues {
+    /// Present with any value
+    Any,
+    /// `true` or `false`
+    Boolean,
+    /// `true`, `false` or `mixed`
+    Tristate,
+    /// A finite number
+    Number,
+    OneOf(&'static [&'static str]),
+}
+
+impl AttributeValues {
+    pub fn accepts(&self, value: &str) -> bool {
+        match self {
+            AttributeValues::Any => true,
+            AttributeValues::Boolean => matches!(value, "true" | "false"),
+            AttributeValues::Tristate => matches!(value, "true" | "false" | "mixed"),
+            AttributeValues::Number => value.trim().parse::<f64>().is_ok_and(f64::is_finite),
+            AttributeValues::OneOf(values) => values.contains(&value),
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            AttributeValues::Any => "any value".to_string(),
+            AttributeValues::Boolean => "true | false".to_string(),
+            AttributeValues::Tristate => "true | false | mixed".to_string(),
+            AttributeValues::Number => "a number".to_string(),
+            AttributeValues::OneOf(values) => values.join(" | "),
+        }
+    }
+}
+
+/// An expectation about the rendered markup of a component
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConformanceCheck {
+    /// At least one element exposes the role
+    Role(&'static str),
+    /// Every element with the role has the attribute with an allowed value
+    State {
+        role: &'static str,
+        attribute: &'static str,
+        values: AttributeValues,
+    },
+    /// At least one element with the role is keyboard focusable
+    Focusable(&'static str),
+    /// The ID references in the attribute of every element with the role
+    /// resolve to elements in the markup
+    References {
+        role: &'static str,
+        attribute: &'static str,
+    },
+}
+
+impl ConformanceCheck {
+    pub fn describe(&self) -> String {
+        match self {
+            ConformanceCheck::Role(role) => format!("exposes role `{}`", role),
+            ConformanceCheck::State {
+                role,
+                attribute,
+                values,
+            } => format!("`{}` has `{}` ({})", role, attribute, values.describe()),
+            ConformanceCheck::Focusable(role) => format!("`{}` is focusable", role),
+            ConformanceCheck::References { role, attribute } => {
+                format!("`{}` `{}` references existing elements", role, attribute)
+            }
+        }
+    }
+}
+
+/// A keyboard interaction from the ARIA Authoring Practices
+///
+/// Interactions need a browser to verify, so they document the pattern for
+/// the end-to-end suite and are not part of the matrix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyboardInteraction {
+    pub keys: &'static str,
+    pub action: &'static str,
+}
+
+const fn key(keys: &'static str, action: &'static str) -> KeyboardInteraction {
+    KeyboardInteraction { keys, action }
+}
+
+/// Server-renders a component to HTML
+pub type ConformanceRender = fn() -> String;
+
+/// Expected ARIA behaviour of one component
+#[derive(Debug, Clone)]
+pub struct ConformanceSpec {
+    pub component: &'static str,
+    /// Name of the ARIA Authoring Practices pattern
+    pub pattern: &'static str,
+    pub guide_url: &'static str,
+    /// Server-renders a representative instance of the component
+    pub render: ConformanceRender,
+    pub checks: Vec<ConformanceCheck>,
+    pub keyboard: Vec<KeyboardInteraction>,
+}
+
+/// Specs for every enabled component that implements an APG pattern
+pub fn conformance_specs() -> Vec<ConformanceSpec> {
+    use AttributeValues::*;
+    use ConformanceCheck::*;
+
+    vec![
+        ConformanceSpec {
+            component: "Accordion",
+            pattern: "Accordion",
+            guide_url: "https://www.w3.org/WAI/ARIA/apg/patterns/accordion/",
+            render: || {
+                view! {
+                    <Accordion>
+                        <AccordionItem value="one".to_string()>
+                            <AccordionTrigger>"Section"</AccordionTrigger>
+                            <AccordionContent>"Content"</AccordionContent>
+                        </AccordionItem>
+                    </Accordion>
+                }
+                .to_html()
+            },
+            checks: vec![
+                Role("button"),
+                Focusable("button"),
+                State {
+                    role: "button",
+                    attribute: "aria-expanded",
+                    values: Boolean,
+                },
+                References {
+                    role: "button",
+                    attribute: "aria-controls",
+                },
+                References {
+                    role: "region",
+                    attribute: "aria-labelledby",
+                },
+            ],
+            keyboard: vec![
+                key("Enter / Space", "Toggles the focused section"),
+                key("Tab", "Moves focus to the next focusable element"),
+                key(
+                    "Down Arrow / Up Arrow",
+                    "Moves focus between headers (optional)",
+                ),
+                key(
+                    "Home / End",
+                    "Moves focus to the first or last header (optional)",
+                ),
+            ],
+        },
+        ConformanceSpec {
+            component: "Alert",
+            pattern: "Alert",
+            guide_url: "https://www.w3.org/WAI/ARIA/apg/patterns/alert/",
+            render: || view! { <Alert>"Saved"</Alert> }.to_html(),
+            checks: vec![Role("alert")],
+            keyboard: vec![],
+        },
+        ConformanceSpec {
+            component: "Checkbox",
+            pattern: "Checkbox",
+            guide_url: "https://www.w3.org/WAI/ARIA/apg/patterns/checkbox/",
+            render: || view! { <Checkbox>"Accept terms"</Checkbox> }.to_html(),
+            checks: vec![
+                Role("checkbox"),
+                Focusable("checkbox"),
+                State {
+                    role: "checkbox",
+                    attribute: "aria-checked",
+                    values: Tristate,
+                },
+            ],
+            keyboard: vec![key("Space", "Toggles the checkbox")],
+        },
+        ConformanceSpec {
+            component: "Collapsible",
+            pattern: "Disclosure",
+            guide_url: "https://www.w3.org/WAI/ARIA/apg/patterns/disclosure/",
+            render: || {
+                view! {
+                    <Collapsible>
+                        <CollapsibleTrigger>"Details"</CollapsibleTrigger>
+                        <CollapsibleContent>"Content"</CollapsibleContent>
+                    </Collapsible>
+                }
+                .to_html()
+            },
+            checks: vec![
+                Role("button"),
+                Focusable("button"),
+                State {
+                    role: "button",
+                    attribute: "aria-expanded",
+                    values: Boolean,
+                },
+            ],
+            keyboard: vec![key("Enter / Space", "Shows or hides the content")],
+        },
+        ConformanceSpec {
+            component: "Progress",
+            pattern: "Meter / progressbar",
+            guide_url: "https://www.w3.org/WAI/ARIA/apg/patterns/meter/",
+            render: || {
+                view! { <Progress value=40.0 _children=Box::new(|| ().into_any()) /> }.to_html()
+            },
+            checks: vec![
+                Role("progressbar"),
+                State {
+                    role: "progressbar",
+                    attribute: "aria-valuenow",
+                    values: Number,
+                },
+                State {
+                    role: "progressbar",
+                    attribute: "aria-valuemin",
+                    values: Number,
+                },
+                State {
+                    role: "progressbar",
+                    attribute: "aria-valuemax",
+                    values: Number,
+                },
+            ],
+            keyboard: vec![],
+        },
+        ConformanceSpec {
+            component: "RadioGroup",
+            pattern: "Radio Group",
+            guide_url: "https://www.w3.org/WAI/ARIA/apg/patterns/radio/",
+            render: || {
+                view! {
+                    <RadioGroup>
+                        <RadioGroupItem value="a".to_string()>"A"</RadioGroupItem>
+                        <RadioGroupItem value="b".to_string()>"B"</RadioGroupItem>
+                    </RadioGroup>
+                }
+                .to_html()
+            },
+            checks: vec![
+                Role("radiogroup"),
+                Role("radio"),
+                Focusable("radio"),
+                State {
+                    role: "radio",
+                    attribute: "aria-checked",
+                    values: Boolean,
+                },
+            ],
+            keyboard: vec![
+                key("Tab / Shift+Tab", "Moves focus into and out of the group"),
+                key("Space", "Checks the focused radio"),
+                key("Down Arrow / Right Arrow", "Checks the next radio"),
+                key("Up Arrow / Left Arrow", "Checks the previous radio"),
+            ],
+        },
+        ConformanceSpec {
+            component: "Separator",
+            pattern: "Separator (non-focusable)",
+            guide_url: "https://www.w3.org/TR/wai-aria-1.2/#separator",
+            render: || view! { <Separator /> }.to_html(),
+            checks: vec![
+                Role("separator"),
+                State {
+                    role: "separator",
+                    attribute: "aria-orientation",
+                    values: OneOf(&["horizontal", "vertical"]),
+                },
+            ],
+            keyboard: vec![],
+        },
+        ConformanceSpec {
+            component: "Slider",
+            pattern: "Slider",
+            guide_url: "https://www.w3.org/WAI/ARIA/apg/patterns/slider/",
+            render: || {
+                view! { <Slider value=40.0 _children=Box::new(|| ().into_any()) /> }.to_html()
+            },
+            checks: vec![
+                Role("slider"),
+                Focusable("slider"),
+                State {
+                    role: "slider",
+                    attribute: "aria-valuenow",
+                    values: Number,
+                },
+                State {
+                    role: "slider",
+                    attribute: "aria-valuemin",
+                    values: Number,
+                },
+                State {
+                    role: "slider",
+                    attribute: "aria-valuemax",
+                    values: Number,
+                },
+            ],
+            keyboard: vec![
+                key("Right Arrow / Up Arrow", "Increases the value by one step"),
+                key("Left Arrow / Down Arrow", "Decreases the value by one step"),
+                key(
+                    "Page Up / Page Down",
+                    "Changes the value by a larger step (optional)",
+                ),
+                key("Home / End", "Sets the minimum or maximum value"),
+            ],
+        },
+        ConformanceSpec {
+            component: "Switch",
+            pattern: "Switch",
+            guide_url: "https://www.w3.org/WAI/ARIA/apg/patterns/switch/",
+            render: || view! { <Switch checked=true>"Wi-Fi"</Switch> }.to_html(),
+            checks: vec![
+                Role("switch"),
+                Focusable("switch"),
+                State {
+                    role: "switch",
+                    attribute: "aria-checked",
+                    values: Boolean,
+                },
+            ],
+            keyboard: vec![key("Space / Enter", "Toggles the switch")],
+        },
+        ConformanceSpec {
+            component: "Tabs",
+            pattern: "Tabs",
+            guide_url: "https://www.w3.org/WAI/ARIA/apg/patterns/tabs/",
+            render: || {
+                view! {
+                    <Tabs value="one".to_string()>
+                        <TabsList>
+                            <TabsTrigger value="one".to_string()>"One"</TabsTrigger>
+                            <TabsTrigger value="two".to_string()>"Two"</TabsTrigger>
+                        </TabsList>
+                        <TabsContent value="one".to_string()>"First"</TabsContent>
+                        <TabsContent value="two".to_string()>"Second"</TabsContent>
+                    </Tabs>
+                }
+                .to_html()
+            },
+            checks: vec![
+                Role("tablist"),
+                Role("tab"),
+                Role("tabpanel"),
+                Focusable("tab"),
+                State {
+                    role: "tab",
+                    attribute: "aria-selected",
+                    values: Boolean,
+                },
+                References {
+                    role: "tab",
+                    attribute: "aria-controls",
+                },
+                References {
+                    role: "tabpanel",
+                    attribute: "aria-labelledby",
+                },
+            ],
+            keyboard: vec![
+                key(
+                    "Tab",
+                    "Moves focus into the tab list, then to the active panel",
+                ),
+                key("Left Arrow / Right Arrow", "Moves focus between tabs"),
+                key("Home / End", "Moves focus to the first or last tab"),
+            ],
+        },
+        ConformanceSpec {
+            component: "Toggle",
+            pattern: "Button (toggle)",
+            guide_url: "https://www.w3.org/WAI/ARIA/apg/patterns/button/",
+            render: || view! { <Toggle>"Bold"</Toggle> }.to_html(),
+            checks: vec![
+                Role("button"),
+                Focusable("button"),
+                State {
+                    role: "button",
+                    attribute: "aria-pressed",
+                    values: Tristate,
+                },
+            ],
+            keyboard: vec![key("Space / Enter", "Toggles the pressed state")],
+        },
+    ]
+}
@@ -0,0 +1,497 @@
+//! Target size audit of rendered controls
+//!
+//! [`audit_target_size`] measures every interactive element under a root
+//! and flags the ones smaller than WCAG 2.5.8 Target Size (Minimum) allows,
+//! 24×24 CSS pixels by default. Like the criterion, it lets an undersized
+//! target pass when a circle of the minimum size centered on it stays clear
+//! of every other target, and skips links inside running text and disabled
+//! controls.
+//!
+//! With marking on, failing elements are outlined and labelled with their
+//! size in an overlay layer drawn over the page from their bounding boxes,
+//! showing what to fix while developing without restyling the elements.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use leptos::html::ElementType;
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::Element;
+
+use super::aria_validator::owning_component;
+
+/// Attribute marking failing elements, holding their size, e.g. `16×16`
+pub const TARGET_SIZE_FAILURE_ATTRIBUTE: &str = "data-target-size-failure";
+
+/// Attribute linking a marked element to its box in the overlay
+const BOX_ATTRIBUTE: &str = "data-target-size-box";
+
+/// Minimum target size in CSS pixels required by WCAG 2.5.8
+pub const MIN_TARGET_SIZE: f64 = 24.0;
+
+/// Id of the layer holding the outlines of marked elements
+const OVERLAY_ID: &str = "radix-target-size-audit";
+
+/// Outline and label color of the overlay
+const MARK_COLOR: &str = "#c026d3";
+
+static NEXT_BOX: AtomicUsize = AtomicUsize::new(0);
+
+/// Elements the user can click or tap
+const INTERACTIVE_SELECTOR: &str = "a[href], area[href], button, input:not([type=\"hidden\"]), \
+    select, textarea, summary, [tabindex]:not([tabindex=\"-1\"]), [role=\"button\"], \
+    [role=\"link\"], [role=\"checkbox\"], [role=\"radio\"], [role=\"switch\"], [role=\"tab\"], \
+    [role=\"menuitem\"], [role=\"menuitemcheckbox\"], [role=\"menuitemradio\"], \
+    [role=\"option\"], [role=\"slider\"], [role=\"spinbutton\"], [role=\"treeitem\"]";
+
+/// Bounding box of a target in CSS pixels
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TargetRect {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl TargetRect {
+    pub fn new(x: f64, y: f64, width: f64, height: f64) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    pub fn center(&self) -> (f64, f64) {
+        (self.x + self.width / 2.0, self.y + self.height / 2.0)
+    }
+
+    /// Whether both sides are at least `min_size`
+    pub fn meets(&self, min_size: f64) -> bool {
+        self.width >= min_size && self.height >= min_size
+    }
+
+    /// Distance from `point` to the nearest point of the box, 0 inside it
+    pub fn distance_to(&self, (x, y): (f64, f64)) -> f64 {
+        let dx = (self.x - x).max(0.0).max(x - (self.x + self.width));
+        let dy = (self.y - y).max(0.0).max(y - (self.y + self.height));
+        dx.hypot(dy)
+    }
+}
+
+/// Whether an undersized `target` passes through spacing: a circle of
+/// diameter `min_size` centered on it intersects neither another target
+/// nor the circle of another undersized target
+pub fn has_target_spacing(target: &TargetRect, others: &[TargetRect], min_size: f64) -> bool {
+    let radius = min_size / 2.0;
+    let center = target.center();
+    others.iter().all(|other| {
+        if other.distance_to(center) < radius {
+            return false;
+        }
+        if other.meets(min_size) {
+            return true;
+        }
+        let (x, y) = other.center();
+        (center.0 - x).hypot(center.1 - y) >= min_size
+    })
+}
+
+/// Interactive element smaller than the minimum target size
+#[derive(Debug, Clone, PartialEq)]
+pub struct TargetSizeFailure {
+    pub element: Element,
+    /// Component the element belongs to, from the closest `radix-*` class
+    pub component: Option<String>,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl TargetSizeFailure {
+    /// Size as shown in the overlay, e.g. `16×16`
+    pub fn size(&self) -> String {
+        format!("{}×{}", self.width.round(), self.height.round())
+    }
+
+    /// One-line description for the console
+    pub fn describe(&self, min_size: f64) -> String {
+        format!(
+            "{}<{}> is {}px, needs {min}×{min}px or spacing",
+            self.component
+                .as_ref()
+                .map(|component| format!("{}: ", component))
+                .unwrap_or_default(),
+            self.element.tag_name().to_lowercase(),
+            self.size(),
+            min = min_size
+        )
+    }
+}
+
+/// How [`audit_target_size`] checks and reports
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TargetSizeAuditOptions {
+    /// Smallest width and height in CSS pixels a target may have
+    pub min_size: f64,
+    /// Set [`TARGET_SIZE_FAILURE_ATTRIBUTE`] on failing elements and
+    /// outline them in an overlay
+    pub mark: bool,
+    /// Log each failure as a console warning
+    pub log: bool,
+}
+
+impl Default for TargetSizeAuditOptions {
+    fn default() -> Self {
+        Self {
+            min_size: MIN_TARGET_SIZE,
+            mark: true,
+            log: true,
+        }
+    }
+}
+
+/// Outcome of a target size audit
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TargetSizeAudit {
+    /// Interactive elements measured
+    pub checked: usize,
+    /// Undersized elements that pass through spacing
+    pub spaced: usize,
+    pub failures: Vec<TargetSizeFailure>,
+}
+
+impl TargetSizeAudit {
+    pub fn passed(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// Check the size of every interactive element under `root`, including
+/// `root`
+///
+/// Marks from an earlier audit are cleared first, so the audit can run
+/// again after the page changed.
+pub fn audit_target_size(root: &Element, options: TargetSizeAuditOptions) -> TargetSizeAudit {
+    let mut audit = TargetSizeAudit::default();
+    clear_target_size_marks(root);
+
+    let mut elements = Vec::new();
+    if root.matches(INTERACTIVE_SELECTOR).unwrap_or(false) {
+        elements.push(root.clone());
+    }
+    if let Ok(descendants) = root.query_selector_all(INTERACTIVE_SELECTOR) {
+        elements.extend(
+            (0..descendants.length())
+                .filter_map(|index| descendants.item(index))
+                .filter_map(|node| node.dyn_into::<Element>().ok()),
+        );
+    }
+
+    let targets = elements
+        .into_iter()
+        .filter(|element| !is_exempt(element))
+        .filter_map(|element| {
+            let rect = element.get_bounding_client_rect();
+            let rect = TargetRect::new(rect.x(), rect.y(), rect.width(), rect.height());
+            // Not rendered
+            (rect.width > 0.0 || rect.height > 0.0).then_some((element, rect))
+        })
+        .collect::<Vec<_>>();
+    audit.checked = targets.len();
+
+    for (index, (element, rect)) in targets.iter().enumerate() {
+        if rect.meets(options.min_size) {
+            continue;
+        }
+        let others = targets
+            .iter()
+            .enumerate()
+            .filter(|(other, _)| *other != index)
+            .map(|(_, (_, rect))| *rect)
+            .collect::<Vec<_>>();
+        if has_target_spacing(rect, &others, options.min_size) {
+            audit.spaced += 1;
+            continue;
+        }
+
+        let failure = TargetSizeFailure {
+            element: element.clone(),
+            component: owning_component(element),
+            width: rect.width,
+            height: rect.height,
+        };
+        if options.log {
+            leptos::logging::warn!(
+                "[radix-leptos] target size: {}",
+                failure.describe(options.min_size)
+            );
+        }
+        if options.mark {
+            mark_failure(&failure, rect);
+        }
+        audit.failures.push(failure);
+    }
+
+    audit
+}
+
+/// Audit `root` in debug builds only
+///
+/// Release builds skip the audit and return an empty result.
+pub fn audit_target_size_in_dev(root: &Element) -> TargetSizeAudit {
+    if cfg!(debug_assertions) {
+        audit_target_size(root, TargetSizeAuditOptions::default())
+    } else {
+        TargetSizeAudit::default()
+    }
+}
+
+/// Hook auditing the subtree behind `target` once it is mounted, in debug
+/// builds, outlining undersized targets
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::html;
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::conformance::{use_target_size_audit, TargetSizeAuditOptions};
+///
+/// #[component]
+/// fn Toolbar() -> impl IntoView {
+///     let root = NodeRef::<html::Div>::new();
+///     // Hold touch targets to the stricter 44×44 of WCAG 2.5.5
+///     use_target_size_audit(
+///         root,
+///         TargetSizeAuditOptions {
+///             min_size: 44.0,
+///             ..Default::default()
+///         },
+///     );
+///
+///     view! { <div node_ref=root>"..."</div> }
+/// }
+/// ```
+pub fn use_target_size_audit<E>(target: NodeRef<E>, options: TargetSizeAuditOptions)
+where
+    E: ElementType + 'static,
+    E::Output: JsCast + Clone + 'static,
+{
+    if !cfg!(debug_assertions) {
+        return;
+    }
+    Effect::new(move |_| {
+        if let Some(root) = target.get() {
+            audit_target_size(root.unchecked_ref::<Element>(), options);
+        }
+    });
+}
+
+/// Remove the marks of earlier audits under `root`, along with their
+/// boxes in the overlay
+pub fn clear_target_size_marks(root: &Element) {
+    clear_mark(root);
+    let Ok(marked) = root.query_selector_all(&format!("[{}]", TARGET_SIZE_FAILURE_ATTRIBUTE))
+    else {
+        return;
+    };
+    for index in 0..marked.length() {
+        if let Some(element) = marked
+            .item(index)
+            .and_then(|node| node.dyn_into::<Element>().ok())
+        {
+            clear_mark(&element);
+        }
+    }
+}
+
+fn clear_mark(element: &Element) {
+    if let Some(id) = element.get_attribute(BOX_ATTRIBUTE) {
+        if let Some(mark_box) = element
+            .owner_document()
+            .and_then(|d| d.get_element_by_id(&id))
+        {
+            mark_box.remove();
+        }
+    }
+    let _ = element.remove_attribute(TARGET_SIZE_FAILURE_ATTRIBUTE);
+    let _ = element.remove_attribute(BOX_ATTRIBUTE);
+}
+
+/// Disabled controls can't be activated, and links in running text are
+/// sized by the sentence around them
+fn is_exempt(element: &Element) -> bool {
+    element.closest(":disabled").ok().flatten().is_some()
+        || element
+            .closest("[aria-disabled=\"true\"]")
+            .ok()
+            .flatten()
+            .is_some()
+        || is_inline_link(element)
+}
+
+fn is_inline_link(element: &Element) -> bool {
+    if element.tag_name().to_lowercase() != "a" {
+        return false;
+    }
+    let display = web_sys::window()
+        .and_then(|window| window.get_computed_style(element).ok().flatten())
+        .and_then(|style| style.get_property_value("display").ok())
+        .unwrap_or_default();
+    if display != "inline" {
+        return false;
+    }
+    let text_length = |element: &Element| element.text_content().unwrap_or_default().trim().len();
+    element
+        .parent_element()
+        .is_some_and(|parent| text_length(&parent) > text_length(element))
+}
+
+/// Flag the failing element and draw its outline and size in the overlay
+///
+/// The box is placed from the element's bounding box at audit time, in page
+/// coordinates, so the element's own styles and layout stay untouched.
+fn mark_failure(failure: &TargetSizeFailure, rect: &TargetRect) {
+    let size = failure.size();
+    let _ = failure
+        .element
+        .set_attribute(TARGET_SIZE_FAILURE_ATTRIBUTE, &size);
+
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    let Some(overlay) = mark_overlay(&window) else {
+        return;
+    };
+    let Some(document) = window.document() else {
+        return;
+    };
+    let (Ok(mark_box), Ok(label)) = (
+        document.create_element("div"),
+        document.create_element("span"),
+    ) else {
+        return;
+    };
+
+    let id = format!(
+        "{}-{}",
+        OVERLAY_ID,
+        NEXT_BOX.fetch_add(1, Ordering::Relaxed)
+    );
+    mark_box.set_id(&id);
+    let _ = mark_box.set_attribute(
+        "style",
+        &format!(
+            "position: absolute; left: {}px; top: {}px; width: {}px; height: {}px; \
+             outline: 2px solid {color}; outline-offset: 1px;",
+            rect.x + window.scroll_x().unwrap_or_default(),
+            rect.y + window.scroll_y().unwrap_or_default(),
+            rect.width,
+            rect.height,
+            color = MARK_COLOR
+        ),
+    );
+    let _ = label.set_attribute(
+        "style",
+        &format!(
+            "position: absolute; top: 100%; left: 0; margin-top: 3px; font: 10px/1.2 monospace; \
+             color: #fff; background: {}; padding: 0 2px; white-space: nowrap;",
+            MARK_COLOR
+        ),
+    );
+    label.set_text_content(Some(&size));
+    let _ = mark_box.append_child(&label);
+    let _ = overlay.append_child(&mark_box);
+    let _ = failure.element.set_attribute(BOX_ATTRIBUTE, &id);
+}
+
+/// Layer over the page holding the boxes, created on first use
+fn mark_overlay(window: &web_sys::Window) -> Option<Element> {
+    let document = window.document()?;
+    if let Some(overlay) = document.get_element_by_id(OVERLAY_ID) {
+        return Some(overlay);
+    }
+    let overlay = document.create_element("div").ok()?;
+    overlay.set_id(OVERLAY_ID);
+    let _ = overlay.set_attribute("aria-hidden", "true");
+    let _ = overlay.set_attribute(
+        "style",
+        "position: absolute; top: 0; left: 0; width: 0; height: 0; pointer-events: none; \
+         z-index: 2147483647;",
+    );
+    document.body()?.append_child(&overlay).ok()?;
+    Some(overlay)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[test]
+    fn test_target_rect() {
+        let rect = TargetRect::new(0.0, 0.0, 16.0, 24.0);
+        assert!(!rect.meets(MIN_TARGET_SIZE));
+        assert!(rect.meets(16.0));
+        assert_eq!(rect.center(), (8.0, 12.0));
+        assert_eq!(rect.distance_to((8.0, 12.0)), 0.0);
+        assert_eq!(rect.distance_to((19.0, 28.0)), 5.0);
+    }
+
+    #[test]
+    fn test_target_spacing() {
+        let icon = TargetRect::new(0.0, 0.0, 16.0, 16.0);
+        // Circles 24px apart just touch
+        let spaced = TargetRect::new(24.0, 0.0, 16.0, 16.0);
+        assert!(has_target_spacing(&icon, &[spaced], MIN_TARGET_SIZE));
+        let cramped = TargetRect::new(20.0, 0.0, 16.0, 16.0);
+        assert!(!has_target_spacing(&icon, &[cramped], MIN_TARGET_SIZE));
+        // Large targets only need to stay outside the circle
+        let button = TargetRect::new(20.0, 0.0, 40.0, 40.0);
+        assert!(has_target_spacing(&icon, &[button], MIN_TARGET_SIZE));
+        let adjacent = TargetRect::new(16.0, 0.0, 40.0, 40.0);
+        assert!(!has_target_spacing(&icon, &[adjacent], MIN_TARGET_SIZE));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_audit_marks_small_targets() {
+        let document = web_sys::window().unwrap().document().unwrap();
+        let root = document.create_element("div").unwrap();
+        root.set_inner_html(
+            r#"<div style="display: flex">
+                 <button id="ok" style="width: 32px; height: 32px">A</button>
+                 <button id="small" style="width: 16px; height: 16px; padding: 0">B</button>
+                 <button disabled style="width: 12px; height: 12px; padding: 0">C</button>
+               </div>
+               <p>Read the <a href="/terms">terms</a> first.</p>"#,
+        );
+        document.body().unwrap().append_child(&root).unwrap();
+
+        let audit = audit_target_size(
+            &root,
+            TargetSizeAuditOptions {
+                log: false,
+                ..Default::default()
+            },
+        );
+        assert_eq!(audit.failures.len(), 1);
+        assert_eq!(audit.failures[0].element.id(), "small");
+        let small = document.get_element_by_id("small").unwrap();
+        assert!(small.has_attribute(TARGET_SIZE_FAILURE_ATTRIBUTE));
+        // Outlined in the overlay, not by restyling the element
+        assert_eq!(
+            small.get_attribute("style").as_deref(),
+            Some("width: 16px; height: 16px; padding: 0")
+        );
+        let overlay = document.get_element_by_id(OVERLAY_ID).unwrap();
+        assert_eq!(overlay.child_element_count(), 1);
+        assert_eq!(overlay.text_content().as_deref(), Some("16×16"));
+
+        clear_target_size_marks(&root);
+        assert!(!small.has_attribute(TARGET_SIZE_FAILURE_ATTRIBUTE));
+        assert_eq!(overlay.child_element_count(), 0);
+        root.remove();
+    }
+}
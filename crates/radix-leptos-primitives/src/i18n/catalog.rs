@@ -0,0 +1,2075 @@
+use std::collections::HashMap;
+
+use leptos::children::Children;
+use leptos::prelude::*;
+
+use super::message::{format_message, Message, MessageArgs};
+use crate::utils::warn_invalid_prop;
+
+/// Keys of the messages rendered by components
+///
+/// Using an enum instead of string keys means a typo or a removed message is
+/// a compile error rather than a blank label at runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MessageKey {
+    /// `PaginationInfo` summary; args: `start`, `end`, `total`, `current`, `total_pages`
+    PaginationSummary,
+    /// Table selection summary; args: `selected`, `total`
+    TableSelectionSummary,
+    /// Accessible label of a `FileUploadItem`; args: `name`, `status`, `percent`
+    FileUploadItemLabel,
+    /// Announced while filtering a `Combobox` or `Search`; args: `count`
+    ResultsAvailable,
+    /// Accessible name of the `Pagination` navigation
+    PaginationLabel,
+    /// Label of the `PaginationFirst` button
+    PaginationFirst,
+    /// Label of the `PaginationPrevious` button
+    PaginationPrevious,
+    /// Label of the `PaginationNext` button
+    PaginationNext,
+    /// Label of the `PaginationLast` button
+    PaginationLast,
+    /// Label of a `PaginationItem` button; args: `page`
+    PaginationPage,
+    /// Accessible name of the prebuilt theme picker
+    ThemeSelection,
+    /// Accessible name of the `Search` landmark
+    SearchLabel,
+    /// Accessible name of a `SearchInput`
+    SearchInput,
+    /// Accessible name of the `SearchSuggestions` listbox
+    SearchSuggestions,
+    /// Label of the `SearchClearButton`
+    SearchClear,
+    /// Accessible name of a `SearchFilter` group
+    SearchFilters,
+    /// Accessible name of a `ComboboxInput`
+    ComboboxInput,
+    /// Label of the `ComboboxTrigger`
+    ComboboxOpen,
+    /// Label of the `ComboboxClearButton`
+    ComboboxClear,
+    /// Label of the calendar's previous month button
+    CalendarPreviousMonth,
+    /// Label of the calendar's next month button
+    CalendarNextMonth,
+    /// Label of the calendar's today button
+    CalendarToday,
+    /// Label of the `CarouselPrevious` button
+    CarouselPrevious,
+    /// Label of the `CarouselNext` button
+    CarouselNext,
+    /// Label of icon-only close buttons
+    Close,
+    /// Accessible name of loading placeholders
+    Loading,
+    /// Label of the `Alert` dismiss button
+    AlertDismiss,
+    /// Label of the `ToastClose` button
+    ToastClose,
+    /// Label of the `SheetClose` button
+    SheetClose,
+    /// Announced when a `DataTable` is sorted; args: `column`, `direction`
+    TableSort,
+    /// Announced when the `DataTable` selection changes; args: `count`
+    TableSelectionCount,
+    /// Announced when a `Form` has errors; `fields` lists their labels; args: `count`, `fields`
+    FormErrorSummary,
+    /// Announced when a `DragHandle` picks up an item; args: `item`
+    DragLift,
+    /// Announced when a dragged item moves over a drop zone, the `position`th of `count`; args: `item`, `zone`, `position`, `count`
+    DragOver,
+    /// Announced when an item is dropped; args: `item`, `zone`
+    DragDrop,
+    /// Announced when a keyboard drag is cancelled; args: `item`
+    DragCancel,
+    /// Default accessible name of a `Chart`
+    ChartLabel,
+    /// Label of the `ChartLegend` list
+    ChartLegend,
+    /// Header of the label column in a chart's data table
+    ChartCategory,
+    /// Announced when a `DataTable` refresh finishes; args: `count`
+    TableLoaded,
+    /// Announced when a `DataTable` refresh finishes with an unknown row count
+    TableUpdated,
+    /// Announced when a `Combobox` finishes loading options; args: `count`
+    ResultsLoaded,
+    /// Announced when loading finishes with an unknown result count
+    Loaded,
+    /// Default accessible name of a `VideoPlayer`
+    VideoPlayerLabel,
+    /// Role description of a `VideoPlayer`
+    VideoPlayerRole,
+    /// Label of the `VideoPlayer` buffering spinner
+    VideoPlayerLoading,
+    /// Label of the `VideoPlayer` overlay play button
+    VideoPlayerStart,
+    /// Label of the `VideoPlayer` play button
+    VideoPlayerPlay,
+    /// Label of the `VideoPlayer` pause button
+    VideoPlayerPause,
+    /// Accessible name of the `VideoPlayer` seek slider
+    VideoPlayerSeek,
+    /// Value text of the `VideoPlayer` seek slider; args: `current`, `duration`
+    VideoPlayerPosition,
+    /// Label of the `VideoPlayer` playback speed button; args: `rate`
+    VideoPlayerSpeed,
+    /// Label of the `VideoPlayer` mute button
+    VideoPlayerMute,
+    /// Accessible name of the `VideoPlayer` volume slider
+    VideoPlayerVolume,
+    /// Label of the `VideoPlayer` captions button
+    VideoPlayerCaptions,
+    /// Label of the `VideoPlayer` picture-in-picture button
+    VideoPlayerPictureInPicture,
+    /// Label of the `VideoPlayer` full screen button
+    VideoPlayerFullscreen,
+    /// Label of the `VideoPlayer` full screen button while in full screen
+    VideoPlayerExitFullscreen,
+    /// Accessible name of the `ToastProvider` region
+    ToastRegion,
+    /// Accessible name of the `ToastViewport` region
+    ToastViewport,
+    /// Default label of a `ToastAction`
+    ToastAction,
+    /// Default accessible name of a `Carousel`
+    CarouselLabel,
+    /// Role description of a `Carousel`
+    CarouselRole,
+    /// Role description of a `CarouselSlide`
+    CarouselSlideRole,
+    /// Default accessible name of a `CarouselSlide`; args: `position`, `count`
+    CarouselSlideLabel,
+    /// Accessible name of the carousel's slide dots
+    CarouselSlidePicker,
+    /// Label of a carousel slide dot; args: `slide`
+    CarouselGoToSlide,
+    /// Label of the carousel rotation button while paused
+    CarouselStartRotation,
+    /// Label of the carousel rotation button while rotating
+    CarouselStopRotation,
+    /// Accessible name of a `FileUpload`
+    FileUploadArea,
+    /// Accessible name of a `FileUploadDropZone`
+    FileUploadDropZone,
+    /// Accessible name of a `FileUploadList`
+    FileUploadList,
+    /// Accessible name of the `JsonViewer` search input
+    JsonViewerSearch,
+    /// Placeholder of the `JsonViewer` search input
+    JsonViewerSearchPlaceholder,
+    /// Label of the `JsonViewer` copy path buttons
+    JsonViewerCopyPath,
+    /// Announced when a `JsonViewer` path is copied; args: `path`
+    JsonViewerCopied,
+    /// Default accessible name of a `JsonViewer` tree
+    JsonViewerLabel,
+    /// Summary of a collapsed `JsonViewer` array; args: `count`
+    JsonViewerArrayItems,
+    /// Summary of a collapsed `JsonViewer` object; args: `count`
+    JsonViewerObjectKeys,
+    /// Accessible name of a `Calendar`
+    CalendarLabel,
+    /// Accessible name of a `CalendarHeader`
+    CalendarHeader,
+    /// Accessible name of a `CalendarNavigation`
+    CalendarNavigation,
+    /// Text of the calendar's today button
+    CalendarTodayButton,
+    /// Accessible name of a `CalendarGrid`; args: `month`, `year`
+    CalendarGrid,
+    /// Accessible name of a `CalendarWeekHeader`
+    CalendarWeekHeader,
+    /// Accessible name of a `TimePicker`
+    TimePickerLabel,
+    /// Default placeholder of a `TimePicker`
+    TimePickerPlaceholder,
+    /// Accessible name of a `TimePickerInput`
+    TimePickerInput,
+    /// Accessible name of the `TimePickerDropdown` listbox
+    TimePickerOptions,
+    /// Accessible name of a `TimePickerGrid`
+    TimePickerGrid,
+    /// Accessible name of a `DatePicker`
+    DatePickerLabel,
+    /// Default placeholder of a `DatePicker`
+    DatePickerPlaceholder,
+    /// Label of the `DatePickerTrigger`
+    DatePickerOpen,
+    /// Accessible name of a `DatePickerCalendar`
+    DatePickerCalendar,
+    /// Default accessible name of a `KanbanBoard`
+    KanbanLabel,
+    /// Default title of columns added to a `KanbanBoard`
+    KanbanNewColumn,
+    /// Text of the `KanbanBoard` add column button
+    KanbanAddColumn,
+    /// Accessible name of a kanban column's drop zone; args: `column`
+    KanbanColumnZone,
+    /// Accessible name of a kanban column's drag handle; args: `column`
+    KanbanColumnHandle,
+    /// Announced when a kanban card moves; args: `card`, `column`, `position`, `count`
+    KanbanCardMoved,
+    /// Announced when a kanban column moves; args: `column`, `position`, `count`
+    KanbanColumnMoved,
+    /// Announced when a kanban column is added; args: `column`
+    KanbanColumnAdded,
+    /// Announced when a move into a full kanban column is rejected; args: `column`, `limit`
+    KanbanWipLimit,
+    /// Instructions read with a focused `DragHandle`
+    DragInstructions,
+    /// Accessible name of a `DragDrop` container
+    DragDropLabel,
+    /// Default accessible name of a `DragHandle`
+    DragHandleLabel,
+    /// Role description of a `DragHandle`
+    DragHandleRole,
+    /// Default accessible name of a `DropZone`
+    DropZoneLabel,
+    /// Accessible name of a `DragPreview`
+    DragPreviewLabel,
+    /// Accessible name of zoom controls
+    Zoom,
+    /// Label of zoom in buttons
+    ZoomIn,
+    /// Label of zoom out buttons
+    ZoomOut,
+    /// Shown when a search has no matches
+    NoResults,
+    /// Default accessible name of an `AudioPlayer`
+    AudioPlayerLabel,
+    /// Role description of an `AudioPlayer`
+    AudioPlayerRole,
+    /// Default accessible name of a `PdfViewer`
+    PdfViewerLabel,
+    /// Accessible name of the `PdfViewer` toolbar
+    PdfViewerControls,
+    /// Label of the `PdfViewer` thumbnails toggle
+    PdfViewerThumbnails,
+    /// Accessible name of the `PdfViewer` thumbnail navigation
+    PdfViewerPages,
+    /// Label of the `PdfViewer` previous page button
+    PdfViewerPreviousPage,
+    /// Label of the `PdfViewer` next page button
+    PdfViewerNextPage,
+    /// Accessible name of the `PdfViewer` page number input
+    PdfViewerPageInput,
+    /// Page count after the `PdfViewer` page input; args: `count`
+    PdfViewerPageCount,
+    /// Label of a `PdfViewer` thumbnail; args: `page`
+    PdfViewerPage,
+    /// Role description of the `PdfViewer` page
+    PdfViewerPageRole,
+    /// Accessible name of the `PdfViewer` page; args: `page`, `count`
+    PdfViewerPagePosition,
+    /// Text of the `PdfViewer` fit width button
+    PdfViewerFitWidth,
+    /// Accessible name of the `PdfViewer` search input
+    PdfViewerSearch,
+    /// Placeholder of the `PdfViewer` search input
+    PdfViewerSearchPlaceholder,
+    /// Label of the `PdfViewer` previous match button
+    PdfViewerPreviousMatch,
+    /// Label of the `PdfViewer` next match button
+    PdfViewerNextMatch,
+    /// Status of a `PdfViewer` search, the `position`th of `count` matching pages; args: `position`, `count`
+    PdfViewerMatch,
+    /// Default accessible name of an `OrgChart`
+    OrgChartLabel,
+    /// Text of the `OrgChart` reset view button
+    OrgChartResetView,
+    /// Default accessible name of a `Cascader`
+    CascaderLabel,
+    /// Default placeholder of a `Cascader`
+    CascaderPlaceholder,
+    /// Accessible name of a `Cascader` column; args: `level`
+    CascaderLevel,
+    /// Accessible name of the `Cascader` search results
+    CascaderResults,
+    /// Default accessible name of an `ImageCropper`
+    ImageCropperLabel,
+    /// Role description of an `ImageCropper`
+    ImageCropperRole,
+    /// Instructions read with a focused `ImageCropper`
+    ImageCropperInstructions,
+    /// Text of the `ImageCropper` rotate left button
+    ImageCropperRotateLeft,
+    /// Text of the `ImageCropper` rotate right button
+    ImageCropperRotateRight,
+    /// Text of the `ImageCropper` export button
+    ImageCropperCrop,
+    /// Default accessible name of a `ChatMessageList`
+    ChatLabel,
+    /// Accessible name of a `MessageBubble`; args: `author`
+    ChatMessageFrom,
+    /// Day separator for today's messages
+    ChatToday,
+    /// Day separator for yesterday's messages
+    ChatYesterday,
+    /// `TypingIndicator` text, `others` being the number of people after `first`; args: `first`, `second`, `others`
+    ChatTyping,
+    /// Default heading and trigger name of a `NotificationCenter`
+    NotificationCenterLabel,
+    /// Accessible name of the `NotificationCenter` trigger; args: `label`, `unread`
+    NotificationCenterTrigger,
+    /// Default text of an empty `NotificationCenter`
+    NotificationCenterEmpty,
+    /// Read before the title of an unread notification
+    NotificationCenterUnread,
+    /// Text of the `NotificationCenter` mark all as read button
+    NotificationCenterMarkAllRead,
+    /// Default accessible name of a `TreeSelect`
+    TreeSelectLabel,
+    /// Default placeholder of a `TreeSelect`
+    TreeSelectPlaceholder,
+    /// Default placeholder of the `TreeSelect` search input
+    TreeSelectSearchPlaceholder,
+    /// Accessible name of the `TreeSelect` search input; args: `label`
+    TreeSelectSearch,
+    /// Accessible name of the `TreeSelect` trigger; args: `label`, `count`
+    TreeSelectTrigger,
+    /// Default placeholder of a `MultiSelectSearch`
+    MultiSelectSearchPlaceholder,
+    /// Label of a `MultiSelectTag` remove button; args: `option`
+    MultiSelectRemove,
+    /// Default accessible name of a `SignaturePad`
+    SignaturePadLabel,
+    /// Accessible name of the `SignaturePad` drawing surface; args: `label`, `count`
+    SignaturePadCanvas,
+    /// Text of the `SignaturePad` undo button
+    SignaturePadUndo,
+    /// Text of the `SignaturePad` clear button
+    SignaturePadClear,
+    /// Error of a required `SignaturePad` left empty
+    SignaturePadRequired,
+    /// Default accessible name of a `CalendarHeatmap`
+    CalendarHeatmapLabel,
+    /// Default accessible name of a `CalendarHeatmap` day; args: `count`, `date`
+    CalendarHeatmapDay,
+    /// Start of the `CalendarHeatmap` legend
+    CalendarHeatmapLess,
+    /// End of the `CalendarHeatmap` legend
+    CalendarHeatmapMore,
+    /// Default heading of `ShortcutHelp`
+    ShortcutHelpTitle,
+    /// Description of the shortcut opening `ShortcutHelp`
+    ShortcutHelpShow,
+    /// Shown by `ShortcutHelp` when no shortcut is documented
+    ShortcutHelpEmpty,
+    /// Heading of the global shortcuts in `ShortcutHelp`
+    ShortcutHelpGeneral,
+    /// Accessible name of a `Statistic` trend; `trend` is `up`, `down` or `neutral`; args: `trend`, `delta`
+    StatisticTrend,
+    /// Label of the `Tour` next button
+    TourNext,
+    /// Label of the `Tour` previous button
+    TourPrevious,
+    /// Label of the `Tour` skip button
+    TourSkip,
+    /// Label of the `Tour` next button on the last step
+    TourDone,
+    /// Progress of a `Tour`, the `step`th of `count`; args: `step`, `count`
+    TourProgress,
+    /// Default accessible name of a `BackToTop` button
+    BackToTopLabel,
+    /// Default accessible name of an `Anchor` navigation
+    AnchorLabel,
+    /// Default accessible name of a `ScrollProgress` bar
+    ScrollProgressLabel,
+    /// Default accessible name of an `Avatar`
+    AvatarLabel,
+    /// Default alternative text of an `AvatarImage`
+    AvatarImage,
+    /// Accessible name of an `AvatarFallback`
+    AvatarFallback,
+    /// Default accessible name of an `AvatarGroup`
+    AvatarGroupLabel,
+    /// Accessible name of the list of avatars an `AvatarGroup` hides; args: `count`
+    AvatarGroupOverflow,
+    /// Accessible name of the `AvatarGroup` overflow button; args: `count`, `names`
+    AvatarGroupOverflowButton,
+    /// Accessible name of a `SeparatorGroup`
+    SeparatorGroup,
+    /// Accessible name of a `BarChart`
+    BarChartLabel,
+    /// Accessible name of a `BarChartBar`; args: `category`, `value`
+    BarChartBar,
+    /// Accessible name of a `BarChartGroup`; args: `category`
+    BarChartGroup,
+    /// Accessible name of a `PieChart`
+    PieChartLabel,
+    /// Accessible name of a `PieChartSlice`; args: `label`, `percent`
+    PieChartSlice,
+    /// Accessible name of a `LineChart`
+    LineChartLabel,
+    /// Accessible name of a `LineChartArea`
+    LineChartArea,
+    /// Accessible name of a labelled `LineChartPoint`; args: `label`
+    LineChartPoint,
+    /// Accessible name of an unlabelled chart point
+    ChartDataPoint,
+    /// Accessible name of a `ScatterPlot`
+    ScatterPlotLabel,
+    /// Accessible name of a `ScatterPlotPoint`; args: `x`, `y`
+    ScatterPlotPoint,
+    /// Accessible name of a `ScatterPlotTrendLine`; args: `series`
+    ScatterPlotTrendLine,
+    /// Accessible name of a `FormValidationProvider` form
+    FormValidationLabel,
+    /// Accessible name of a `FormErrorSummary`
+    FormValidationErrors,
+    /// Accessible name of a `Timeline`
+    TimelineLabel,
+    /// Accessible name of a `TimelineItem`; args: `title`
+    TimelineEvent,
+    /// Accessible name of a `LabelDescription`
+    LabelDescription,
+    /// Accessible name of a `LabelGroup`
+    LabelGroup,
+    /// Accessible name of a `ContextMenu`
+    ContextMenuLabel,
+    /// Accessible name of an `AspectRatio` container
+    AspectRatioContainer,
+    /// Accessible name of an `AspectRatioWrapper`
+    AspectRatioWrapper,
+    /// Default placeholder of a `Combobox`
+    ComboboxPlaceholder,
+    /// Default accessible name of a `ComboboxGroup`
+    ComboboxGroup,
+    /// Default placeholder of a `SelectValue`
+    SelectPlaceholder,
+    /// Default placeholder of a `TreeViewSearch`
+    TreeViewSearchPlaceholder,
+    /// Heading of `PasswordRequirements`
+    PasswordRequirementsTitle,
+    /// Minimum length requirement; args: `count`
+    PasswordMinLength,
+    /// Uppercase letter requirement
+    PasswordUppercase,
+    /// Lowercase letter requirement
+    PasswordLowercase,
+    /// Number requirement
+    PasswordNumber,
+    /// Symbol requirement
+    PasswordSymbol,
+    /// Error of a password shorter than the minimum; args: `count`
+    PasswordTooShort,
+    /// Error of a password without an uppercase letter
+    PasswordMissingUppercase,
+    /// Error of a password without a lowercase letter
+    PasswordMissingLowercase,
+    /// Error of a password without a number
+    PasswordMissingNumber,
+    /// Error of a password without a symbol
+    PasswordMissingSymbol,
+    /// Password strength label; `level` is `very_weak`, `weak`, `fair`, `good` or `strong`; args: `level`
+    PasswordStrength,
+    /// Text of the `OtpTimer` reset button
+    OtpTimerReset,
+    /// Text of the `OtpResend` button
+    OtpResend,
+    /// Shown while `OtpResend` is cooling down; args: `seconds`
+    OtpResendCooldown,
+    /// Text of a collapsed `ResizablePanel` toggle
+    ResizableExpand,
+    /// Text of an expanded `ResizablePanel` toggle
+    ResizableCollapse,
+}
+
+impl MessageKey {
+    pub const ALL: [MessageKey; 232] = [
+        MessageKey::PaginationSummary,
+        MessageKey::TableSelectionSummary,
+        MessageKey::FileUploadItemLabel,
+        MessageKey::ResultsAvailable,
+        MessageKey::PaginationLabel,
+        MessageKey::PaginationFirst,
+        MessageKey::PaginationPrevious,
+        MessageKey::PaginationNext,
+        MessageKey::PaginationLast,
+        MessageKey::PaginationPage,
+        MessageKey::ThemeSelection,
+        MessageKey::SearchLabel,
+        MessageKey::SearchInput,
+        MessageKey::SearchSuggestions,
+        MessageKey::SearchClear,
+        MessageKey::SearchFilters,
+        MessageKey::ComboboxInput,
+        MessageKey::ComboboxOpen,
+        MessageKey::ComboboxClear,
+        MessageKey::CalendarPreviousMonth,
+        MessageKey::CalendarNextMonth,
+        MessageKey::CalendarToday,
+        MessageKey::CarouselPrevious,
+        MessageKey::CarouselNext,
+        MessageKey::Close,
+        MessageKey::Loading,
+        MessageKey::AlertDismiss,
+        MessageKey::ToastClose,
+        MessageKey::SheetClose,
+        MessageKey::TableSort,
+        MessageKey::TableSelectionCount,
+        MessageKey::FormErrorSummary,
+        MessageKey::DragLift,
+        MessageKey::DragOver,
+        MessageKey::DragDrop,
+        MessageKey::DragCancel,
+        MessageKey::ChartLabel,
+        MessageKey::ChartLegend,
+        MessageKey::ChartCategory,
+        MessageKey::TableLoaded,
+        MessageKey::TableUpdated,
+        MessageKey::ResultsLoaded,
+        MessageKey::Loaded,
+        MessageKey::VideoPlayerLabel,
+        MessageKey::VideoPlayerRole,
+        MessageKey::VideoPlayerLoading,
+        MessageKey::VideoPlayerStart,
+        MessageKey::VideoPlayerPlay,
+        MessageKey::VideoPlayerPause,
+        MessageKey::VideoPlayerSeek,
+        MessageKey::VideoPlayerPosition,
+        MessageKey::VideoPlayerSpeed,
+        MessageKey::VideoPlayerMute,
+        MessageKey::VideoPlayerVolume,
+        MessageKey::VideoPlayerCaptions,
+        MessageKey::VideoPlayerPictureInPicture,
+        MessageKey::VideoPlayerFullscreen,
+        MessageKey::VideoPlayerExitFullscreen,
+        MessageKey::ToastRegion,
+        MessageKey::ToastViewport,
+        MessageKey::ToastAction,
+        MessageKey::CarouselLabel,
+        MessageKey::CarouselRole,
+        MessageKey::CarouselSlideRole,
+        MessageKey::CarouselSlideLabel,
+        MessageKey::CarouselSlidePicker,
+        MessageKey::CarouselGoToSlide,
+        MessageKey::CarouselStartRotation,
+        MessageKey::CarouselStopRotation,
+        MessageKey::FileUploadArea,
+        MessageKey::FileUploadDropZone,
+        MessageKey::FileUploadList,
+        MessageKey::JsonViewerSearch,
+        MessageKey::JsonViewerSearchPlaceholder,
+        MessageKey::JsonViewerCopyPath,
+        MessageKey::JsonViewerCopied,
+        MessageKey::JsonViewerLabel,
+        MessageKey::JsonViewerArrayItems,
+        MessageKey::JsonViewerObjectKeys,
+        MessageKey::CalendarLabel,
+        MessageKey::CalendarHeader,
+        MessageKey::CalendarNavigation,
+        MessageKey::CalendarTodayButton,
+        MessageKey::CalendarGrid,
+        MessageKey::CalendarWeekHeader,
+        MessageKey::TimePickerLabel,
+        MessageKey::TimePickerPlaceholder,
+        MessageKey::TimePickerInput,
+        MessageKey::TimePickerOptions,
+        MessageKey::TimePickerGrid,
+        MessageKey::DatePickerLabel,
+        MessageKey::DatePickerPlaceholder,
+        MessageKey::DatePickerOpen,
+        MessageKey::DatePickerCalendar,
+        MessageKey::KanbanLabel,
+        MessageKey::KanbanNewColumn,
+        MessageKey::KanbanAddColumn,
+        MessageKey::KanbanColumnZone,
+        MessageKey::KanbanColumnHandle,
+        MessageKey::KanbanCardMoved,
+        MessageKey::KanbanColumnMoved,
+        MessageKey::KanbanColumnAdded,
+        MessageKey::KanbanWipLimit,
+        MessageKey::DragInstructions,
+        MessageKey::DragDropLabel,
+        MessageKey::DragHandleLabel,
+        MessageKey::DragHandleRole,
+        MessageKey::DropZoneLabel,
+        MessageKey::DragPreviewLabel,
+        MessageKey::Zoom,
+        MessageKey::ZoomIn,
+        MessageKey::ZoomOut,
+        MessageKey::NoResults,
+        MessageKey::AudioPlayerLabel,
+        MessageKey::AudioPlayerRole,
+        MessageKey::PdfViewerLabel,
+        MessageKey::PdfViewerControls,
+        MessageKey::PdfViewerThumbnails,
+        MessageKey::PdfViewerPages,
+        MessageKey::PdfViewerPreviousPage,
+        MessageKey::PdfViewerNextPage,
+        MessageKey::PdfViewerPageInput,
+        MessageKey::PdfViewerPageCount,
+        MessageKey::PdfViewerPage,
+        MessageKey::PdfViewerPageRole,
+        MessageKey::PdfViewerPagePosition,
+        MessageKey::PdfViewerFitWidth,
+        MessageKey::PdfViewerSearch,
+        MessageKey::PdfViewerSearchPlaceholder,
+        MessageKey::PdfViewerPreviousMatch,
+        MessageKey::PdfViewerNextMatch,
+        MessageKey::PdfViewerMatch,
+        MessageKey::OrgChartLabel,
+        MessageKey::OrgChartResetView,
+        MessageKey::CascaderLabel,
+        MessageKey::CascaderPlaceholder,
+        MessageKey::CascaderLevel,
+        MessageKey::CascaderResults,
+        MessageKey::ImageCropperLabel,
+        MessageKey::ImageCropperRole,
+        MessageKey::ImageCropperInstructions,
+        MessageKey::ImageCropperRotateLeft,
+        MessageKey::ImageCropperRotateRight,
+        MessageKey::ImageCropperCrop,
+        MessageKey::ChatLabel,
+        MessageKey::ChatMessageFrom,
+        MessageKey::ChatToday,
+        MessageKey::ChatYesterday,
+        MessageKey::ChatTyping,
+        MessageKey::NotificationCenterLabel,
+        MessageKey::NotificationCenterTrigger,
+        MessageKey::NotificationCenterEmpty,
+        MessageKey::NotificationCenterUnread,
+        MessageKey::NotificationCenterMarkAllRead,
+        MessageKey::TreeSelectLabel,
+        MessageKey::TreeSelectPlaceholder,
+        MessageKey::TreeSelectSearchPlaceholder,
+        MessageKey::TreeSelectSearch,
+        MessageKey::TreeSelectTrigger,
+        MessageKey::MultiSelectSearchPlaceholder,
+        MessageKey::MultiSelectRemove,
+        MessageKey::SignaturePadLabel,
+        MessageKey::SignaturePadCanvas,
+        MessageKey::SignaturePadUndo,
+        MessageKey::SignaturePadClear,
+        MessageKey::SignaturePadRequired,
+        MessageKey::CalendarHeatmapLabel,
+        MessageKey::CalendarHeatmapDay,
+        MessageKey::CalendarHeatmapLess,
+        MessageKey::CalendarHeatmapMore,
+        MessageKey::ShortcutHelpTitle,
+        MessageKey::ShortcutHelpShow,
+        MessageKey::ShortcutHelpEmpty,
+        MessageKey::ShortcutHelpGeneral,
+        MessageKey::StatisticTrend,
+        MessageKey::TourNext,
+        MessageKey::TourPrevious,
+        MessageKey::TourSkip,
+        MessageKey::TourDone,
+        MessageKey::TourProgress,
+        MessageKey::BackToTopLabel,
+        MessageKey::AnchorLabel,
+        MessageKey::ScrollProgressLabel,
+        MessageKey::AvatarLabel,
+        MessageKey::AvatarImage,
+        MessageKey::AvatarFallback,
+        MessageKey::AvatarGroupLabel,
+        MessageKey::AvatarGroupOverflow,
+        MessageKey::AvatarGroupOverflowButton,
+        MessageKey::SeparatorGroup,
+        MessageKey::BarChartLabel,
+        MessageKey::BarChartBar,
+        MessageKey::BarChartGroup,
+        MessageKey::PieChartLabel,
+        MessageKey::PieChartSlice,
+        MessageKey::LineChartLabel,
+        MessageKey::LineChartArea,
+        MessageKey::LineChartPoint,
+        MessageKey::ChartDataPoint,
+        MessageKey::ScatterPlotLabel,
+        MessageKey::ScatterPlotPoint,
+        MessageKey::ScatterPlotTrendLine,
+        MessageKey::FormValidationLabel,
+        MessageKey::FormValidationErrors,
+        MessageKey::TimelineLabel,
+        MessageKey::TimelineEvent,
+        MessageKey::LabelDescription,
+        MessageKey::LabelGroup,
+        MessageKey::ContextMenuLabel,
+        MessageKey::AspectRatioContainer,
+        MessageKey::AspectRatioWrapper,
+        MessageKey::ComboboxPlaceholder,
+        MessageKey::ComboboxGroup,
+        MessageKey::SelectPlaceholder,
+        MessageKey::TreeViewSearchPlaceholder,
+        MessageKey::PasswordRequirementsTitle,
+        MessageKey::PasswordMinLength,
+        MessageKey::PasswordUppercase,
+        MessageKey::PasswordLowercase,
+        MessageKey::PasswordNumber,
+        MessageKey::PasswordSymbol,
+        MessageKey::PasswordTooShort,
+        MessageKey::PasswordMissingUppercase,
+        MessageKey::PasswordMissingLowercase,
+        MessageKey::PasswordMissingNumber,
+        MessageKey::PasswordMissingSymbol,
+        MessageKey::PasswordStrength,
+        MessageKey::OtpTimerReset,
+        MessageKey::OtpResend,
+        MessageKey::OtpResendCooldown,
+        MessageKey::ResizableExpand,
+        MessageKey::ResizableCollapse,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MessageKey::PaginationSummary => "pagination.summary",
+            MessageKey::TableSelectionSummary => "table.selection_summary",
+            MessageKey::FileUploadItemLabel => "file_upload.item_label",
+            MessageKey::ResultsAvailable => "listbox.results_available",
+            MessageKey::PaginationLabel => "pagination.label",
+            MessageKey::PaginationFirst => "pagination.first",
+            MessageKey::PaginationPrevious => "pagination.previous",
+            MessageKey::PaginationNext => "pagination.next",
+            MessageKey::PaginationLast => "pagination.last",
+            MessageKey::PaginationPage => "pagination.page",
+            MessageKey::ThemeSelection => "theme.selection",
+            MessageKey::SearchLabel => "search.label",
+            MessageKey::SearchInput => "search.input",
+            MessageKey::SearchSuggestions => "search.suggestions",
+            MessageKey::SearchClear => "search.clear",
+            MessageKey::SearchFilters => "search.filters",
+            MessageKey::ComboboxInput => "combobox.input",
+            MessageKey::ComboboxOpen => "combobox.open",
+            MessageKey::ComboboxClear => "combobox.clear",
+            MessageKey::CalendarPreviousMonth => "calendar.previous_month",
+            MessageKey::CalendarNextMonth => "calendar.next_month",
+            MessageKey::CalendarToday => "calendar.today",
+            MessageKey::CarouselPrevious => "carousel.previous",
+            MessageKey::CarouselNext => "carousel.next",
+            MessageKey::Close => "common.close",
+            MessageKey::Loading => "common.loading",
+            MessageKey::AlertDismiss => "alert.dismiss",
+            MessageKey::ToastClose => "toast.close",
+            MessageKey::SheetClose => "sheet.close",
+            MessageKey::TableSort => "table.sort",
+            MessageKey::TableSelectionCount => "table.selection_count",
+            MessageKey::FormErrorSummary => "form.error_summary",
+            MessageKey::DragLift => "drag_drop.lift",
+            MessageKey::DragOver => "drag_drop.over",
+            MessageKey::DragDrop => "drag_drop.drop",
+            MessageKey::DragCancel => "drag_drop.cancel",
+            MessageKey::ChartLabel => "chart.label",
+            MessageKey::ChartLegend => "chart.legend",
+            MessageKey::ChartCategory => "chart.category",
+            MessageKey::TableLoaded => "table.loaded",
+            MessageKey::TableUpdated => "table.updated",
+            MessageKey::ResultsLoaded => "listbox.results_loaded",
+            MessageKey::Loaded => "common.loaded",
+            MessageKey::VideoPlayerLabel => "video_player.label",
+            MessageKey::VideoPlayerRole => "video_player.role",
+            MessageKey::VideoPlayerLoading => "video_player.loading",
+            MessageKey::VideoPlayerStart => "video_player.start",
+            MessageKey::VideoPlayerPlay => "video_player.play",
+            MessageKey::VideoPlayerPause => "video_player.pause",
+            MessageKey::VideoPlayerSeek => "video_player.seek",
+            MessageKey::VideoPlayerPosition => "video_player.position",
+            MessageKey::VideoPlayerSpeed => "video_player.speed",
+            MessageKey::VideoPlayerMute => "video_player.mute",
+            MessageKey::VideoPlayerVolume => "video_player.volume",
+            MessageKey::VideoPlayerCaptions => "video_player.captions",
+            MessageKey::VideoPlayerPictureInPicture => "video_player.picture_in_picture",
+            MessageKey::VideoPlayerFullscreen => "video_player.fullscreen",
+            MessageKey::VideoPlayerExitFullscreen => "video_player.exit_fullscreen",
+            MessageKey::ToastRegion => "toast.region",
+            MessageKey::ToastViewport => "toast.viewport",
+            MessageKey::ToastAction => "toast.action",
+            MessageKey::CarouselLabel => "carousel.label",
+            MessageKey::CarouselRole => "carousel.role",
+            MessageKey::CarouselSlideRole => "carousel.slide_role",
+            MessageKey::CarouselSlideLabel => "carousel.slide_label",
+            MessageKey::CarouselSlidePicker => "carousel.slide_picker",
+            MessageKey::CarouselGoToSlide => "carousel.go_to_slide",
+            MessageKey::CarouselStartRotation => "carousel.start_rotation",
+            MessageKey::CarouselStopRotation => "carousel.stop_rotation",
+            MessageKey::FileUploadArea => "file_upload.area",
+            MessageKey::FileUploadDropZone => "file_upload.drop_zone",
+            MessageKey::FileUploadList => "file_upload.list",
+            MessageKey::JsonViewerSearch => "json_viewer.search",
+            MessageKey::JsonViewerSearchPlaceholder => "json_viewer.search_placeholder",
+            MessageKey::JsonViewerCopyPath => "json_viewer.copy_path",
+            MessageKey::JsonViewerCopied => "json_viewer.copied",
+            MessageKey::JsonViewerLabel => "json_viewer.label",
+            MessageKey::JsonViewerArrayItems => "json_viewer.array_items",
+            MessageKey::JsonViewerObjectKeys => "json_viewer.object_keys",
+            MessageKey::CalendarLabel => "calendar.label",
+            MessageKey::CalendarHeader => "calendar.header",
+            MessageKey::CalendarNavigation => "calendar.navigation",
+            MessageKey::CalendarTodayButton => "calendar.today_button",
+            MessageKey::CalendarGrid => "calendar.grid",
+            MessageKey::CalendarWeekHeader => "calendar.week_header",
+            MessageKey::TimePickerLabel => "time_picker.label",
+            MessageKey::TimePickerPlaceholder => "time_picker.placeholder",
+            MessageKey::TimePickerInput => "time_picker.input",
+            MessageKey::TimePickerOptions => "time_picker.options",
+            MessageKey::TimePickerGrid => "time_picker.grid",
+            MessageKey::DatePickerLabel => "date_picker.label",
+            MessageKey::DatePickerPlaceholder => "date_picker.placeholder",
+            MessageKey::DatePickerOpen => "date_picker.open",
+            MessageKey::DatePickerCalendar => "date_picker.calendar",
+            MessageKey::KanbanLabel => "kanban.label",
+            MessageKey::KanbanNewColumn => "kanban.new_column",
+            MessageKey::KanbanAddColumn => "kanban.add_column",
+            MessageKey::KanbanColumnZone => "kanban.column_zone",
+            MessageKey::KanbanColumnHandle => "kanban.column_handle",
+            MessageKey::KanbanCardMoved => "kanban.card_moved",
+            MessageKey::KanbanColumnMoved => "kanban.column_moved",
+            MessageKey::KanbanColumnAdded => "kanban.column_added",
+            MessageKey::KanbanWipLimit => "kanban.wip_limit",
+            MessageKey::DragInstructions => "drag_drop.instructions",
+            MessageKey::DragDropLabel => "drag_drop.label",
+            MessageKey::DragHandleLabel => "drag_drop.handle",
+            MessageKey::DragHandleRole => "drag_drop.handle_role",
+            MessageKey::DropZoneLabel => "drag_drop.drop_zone",
+            MessageKey::DragPreviewLabel => "drag_drop.preview",
+            MessageKey::Zoom => "common.zoom",
+            MessageKey::ZoomIn => "common.zoom_in",
+            MessageKey::ZoomOut => "common.zoom_out",
+            MessageKey::NoResults => "common.no_results",
+            MessageKey::AudioPlayerLabel => "audio_player.label",
+            MessageKey::AudioPlayerRole => "audio_player.role",
+            MessageKey::PdfViewerLabel => "pdf_viewer.label",
+            MessageKey::PdfViewerControls => "pdf_viewer.controls",
+            MessageKey::PdfViewerThumbnails => "pdf_viewer.thumbnails",
+            MessageKey::PdfViewerPages => "pdf_viewer.pages",
+            MessageKey::PdfViewerPreviousPage => "pdf_viewer.previous_page",
+            MessageKey::PdfViewerNextPage => "pdf_viewer.next_page",
+            MessageKey::PdfViewerPageInput => "pdf_viewer.page_input",
+            MessageKey::PdfViewerPageCount => "pdf_viewer.page_count",
+            MessageKey::PdfViewerPage => "pdf_viewer.page",
+            MessageKey::PdfViewerPageRole => "pdf_viewer.page_role",
+            MessageKey::PdfViewerPagePosition => "pdf_viewer.page_position",
+            MessageKey::PdfViewerFitWidth => "pdf_viewer.fit_width",
+            MessageKey::PdfViewerSearch => "pdf_viewer.search",
+            MessageKey::PdfViewerSearchPlaceholder => "pdf_viewer.search_placeholder",
+            MessageKey::PdfViewerPreviousMatch => "pdf_viewer.previous_match",
+            MessageKey::PdfViewerNextMatch => "pdf_viewer.next_match",
+            MessageKey::PdfViewerMatch => "pdf_viewer.match",
+            MessageKey::OrgChartLabel => "org_chart.label",
+            MessageKey::OrgChartResetView => "org_chart.reset_view",
+            MessageKey::CascaderLabel => "cascader.label",
+            MessageKey::CascaderPlaceholder => "cascader.placeholder",
+            MessageKey::CascaderLevel => "cascader.level",
+            MessageKey::CascaderResults => "cascader.results",
+            MessageKey::ImageCropperLabel => "image_cropper.label",
+            MessageKey::ImageCropperRole => "image_cropper.role",
+            MessageKey::ImageCropperInstructions => "image_cropper.instructions",
+            MessageKey::ImageCropperRotateLeft => "image_cropper.rotate_left",
+            MessageKey::ImageCropperRotateRight => "image_cropper.rotate_right",
+            MessageKey::ImageCropperCrop => "image_cropper.crop",
+            MessageKey::ChatLabel => "chat.label",
+            MessageKey::ChatMessageFrom => "chat.message_from",
+            MessageKey::ChatToday => "chat.today",
+            MessageKey::ChatYesterday => "chat.yesterday",
+            MessageKey::ChatTyping => "chat.typing",
+            MessageKey::NotificationCenterLabel => "notification_center.label",
+            MessageKey::NotificationCenterTrigger => "notification_center.trigger",
+            MessageKey::NotificationCenterEmpty => "notification_center.empty",
+            MessageKey::NotificationCenterUnread => "notification_center.unread",
+            MessageKey::NotificationCenterMarkAllRead => "notification_center.mark_all_read",
+            MessageKey::TreeSelectLabel => "tree_select.label",
+            MessageKey::TreeSelectPlaceholder => "tree_select.placeholder",
+            MessageKey::TreeSelectSearchPlaceholder => "tree_select.search_placeholder",
+            MessageKey::TreeSelectSearch => "tree_select.search",
+            MessageKey::TreeSelectTrigger => "tree_select.trigger",
+            MessageKey::MultiSelectSearchPlaceholder => "multi_select.search_placeholder",
+            MessageKey::MultiSelectRemove => "multi_select.remove",
+            MessageKey::SignaturePadLabel => "signature_pad.label",
+            MessageKey::SignaturePadCanvas => "signature_pad.canvas",
+            MessageKey::SignaturePadUndo => "signature_pad.undo",
+            MessageKey::SignaturePadClear => "signature_pad.clear",
+            MessageKey::SignaturePadRequired => "signature_pad.required",
+            MessageKey::CalendarHeatmapLabel => "calendar_heatmap.label",
+            MessageKey::CalendarHeatmapDay => "calendar_heatmap.day",
+            MessageKey::CalendarHeatmapLess => "calendar_heatmap.less",
+            MessageKey::CalendarHeatmapMore => "calendar_heatmap.more",
+            MessageKey::ShortcutHelpTitle => "shortcut_help.title",
+            MessageKey::ShortcutHelpShow => "shortcut_help.show",
+            MessageKey::ShortcutHelpEmpty => "shortcut_help.empty",
+            MessageKey::ShortcutHelpGeneral => "shortcut_help.general",
+            MessageKey::StatisticTrend => "statistic.trend",
+            MessageKey::TourNext => "tour.next",
+            MessageKey::TourPrevious => "tour.previous",
+            MessageKey::TourSkip => "tour.skip",
+            MessageKey::TourDone => "tour.done",
+            MessageKey::TourProgress => "tour.progress",
+            MessageKey::BackToTopLabel => "back_to_top.label",
+            MessageKey::AnchorLabel => "anchor.label",
+            MessageKey::ScrollProgressLabel => "scroll_progress.label",
+            MessageKey::AvatarLabel => "avatar.label",
+            MessageKey::AvatarImage => "avatar.image",
+            MessageKey::AvatarFallback => "avatar.fallback",
+            MessageKey::AvatarGroupLabel => "avatar.group",
+            MessageKey::AvatarGroupOverflow => "avatar.group_overflow",
+            MessageKey::AvatarGroupOverflowButton => "avatar.group_overflow_button",
+            MessageKey::SeparatorGroup => "separator.group",
+            MessageKey::BarChartLabel => "bar_chart.label",
+            MessageKey::BarChartBar => "bar_chart.bar",
+            MessageKey::BarChartGroup => "bar_chart.group",
+            MessageKey::PieChartLabel => "pie_chart.label",
+            MessageKey::PieChartSlice => "pie_chart.slice",
+            MessageKey::LineChartLabel => "line_chart.label",
+            MessageKey::LineChartArea => "line_chart.area",
+            MessageKey::LineChartPoint => "line_chart.point",
+            MessageKey::ChartDataPoint => "chart.data_point",
+            MessageKey::ScatterPlotLabel => "scatter_plot.label",
+            MessageKey::ScatterPlotPoint => "scatter_plot.point",
+            MessageKey::ScatterPlotTrendLine => "scatter_plot.trend_line",
+            MessageKey::FormValidationLabel => "form_validation.label",
+            MessageKey::FormValidationErrors => "form_validation.errors",
+            MessageKey::TimelineLabel => "timeline.label",
+            MessageKey::TimelineEvent => "timeline.event",
+            MessageKey::LabelDescription => "label.description",
+            MessageKey::LabelGroup => "label.group",
+            MessageKey::ContextMenuLabel => "context_menu.label",
+            MessageKey::AspectRatioContainer => "aspect_ratio.container",
+            MessageKey::AspectRatioWrapper => "aspect_ratio.wrapper",
+            MessageKey::ComboboxPlaceholder => "combobox.placeholder",
+            MessageKey::ComboboxGroup => "combobox.group",
+            MessageKey::SelectPlaceholder => "select.placeholder",
+            MessageKey::TreeViewSearchPlaceholder => "tree_view.search_placeholder",
+            MessageKey::PasswordRequirementsTitle => "password.requirements",
+            MessageKey::PasswordMinLength => "password.min_length",
+            MessageKey::PasswordUppercase => "password.uppercase",
+            MessageKey::PasswordLowercase => "password.lowercase",
+            MessageKey::PasswordNumber => "password.number",
+            MessageKey::PasswordSymbol => "password.symbol",
+            MessageKey::PasswordTooShort => "password.too_short",
+            MessageKey::PasswordMissingUppercase => "password.missing_uppercase",
+            MessageKey::PasswordMissingLowercase => "password.missing_lowercase",
+            MessageKey::PasswordMissingNumber => "password.missing_number",
+            MessageKey::PasswordMissingSymbol => "password.missing_symbol",
+            MessageKey::PasswordStrength => "password.strength",
+            MessageKey::OtpTimerReset => "otp.timer_reset",
+            MessageKey::OtpResend => "otp.resend",
+            MessageKey::OtpResendCooldown => "otp.resend_cooldown",
+            MessageKey::ResizableExpand => "resizable.expand",
+            MessageKey::ResizableCollapse => "resizable.collapse",
+        }
+    }
+
+    /// Key whose [`as_str`](Self::as_str) is `key`, for string tables
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|candidate| candidate.as_str() == key)
+    }
+
+    /// English message used when a catalog has no override
+    pub fn default_message(&self) -> &'static str {
+        match self {
+            MessageKey::PaginationSummary => {
+                "{start}–{end} of {total, plural, one {# item} other {# items}}"
+            }
+            MessageKey::TableSelectionSummary => {
+                "{selected, plural, =0 {No rows selected} one {# of {total} row selected} other {# of {total} rows selected}}"
+            }
+            MessageKey::FileUploadItemLabel => {
+                "{name}, {status, select, uploading {{percent}% uploaded} completed {upload complete} error {upload failed} cancelled {upload cancelled} other {waiting to upload}}"
+            }
+            MessageKey::ResultsAvailable => {
+                "{count, plural, =0 {No results} one {# result available} other {# results available}}"
+            }
+            MessageKey::PaginationLabel => "Pagination",
+            MessageKey::PaginationFirst => "Go to first page",
+            MessageKey::PaginationPrevious => "Go to previous page",
+            MessageKey::PaginationNext => "Go to next page",
+            MessageKey::PaginationLast => "Go to last page",
+            MessageKey::PaginationPage => "Page {page}",
+            MessageKey::ThemeSelection => "Theme selection",
+            MessageKey::SearchLabel => "Search",
+            MessageKey::SearchInput => "Search input",
+            MessageKey::SearchSuggestions => "Search suggestions",
+            MessageKey::SearchClear => "Clear search",
+            MessageKey::SearchFilters => "Search filters",
+            MessageKey::ComboboxInput => "Combobox input",
+            MessageKey::ComboboxOpen => "Open combobox",
+            MessageKey::ComboboxClear => "Clear selection",
+            MessageKey::CalendarPreviousMonth => "Previous month",
+            MessageKey::CalendarNextMonth => "Next month",
+            MessageKey::CalendarToday => "Go to today",
+            MessageKey::CarouselPrevious => "Previous slide",
+            MessageKey::CarouselNext => "Next slide",
+            MessageKey::Close => "Close",
+            MessageKey::Loading => "Loading",
+            MessageKey::AlertDismiss => "Dismiss alert",
+            MessageKey::ToastClose => "Close toast",
+            MessageKey::SheetClose => "Close sheet",
+            MessageKey::TableSort => {
+                "Sorted by {column} {direction, select, ascending {ascending} other {descending}}"
+            }
+            MessageKey::TableSelectionCount => {
+                "{count, plural, =0 {No rows selected} one {# row selected} other {# rows selected}}"
+            }
+            MessageKey::FormErrorSummary => {
+                "{count, plural, one {# error} other {# errors}}: {fields}"
+            }
+            MessageKey::DragLift => {
+                "Picked up {item}. Use the arrow keys to move, Space or Enter to drop, Escape to cancel."
+            }
+            MessageKey::DragOver => "{item} is over {zone}, {position} of {count}.",
+            MessageKey::DragDrop => "Dropped {item} on {zone}.",
+            MessageKey::DragCancel => "Cancelled. {item} returned to its starting position.",
+            MessageKey::ChartLabel => "Data visualization chart",
+            MessageKey::ChartLegend => "Chart legend",
+            MessageKey::ChartCategory => "Category",
+            MessageKey::TableLoaded => {
+                "{count, plural, =0 {No rows} one {Loaded # row} other {Loaded # rows}}"
+            }
+            MessageKey::TableUpdated => "Table updated",
+            MessageKey::ResultsLoaded => {
+                "{count, plural, =0 {No results} one {Loaded # result} other {Loaded # results}}"
+            }
+            MessageKey::Loaded => "Loaded",
+            MessageKey::VideoPlayerLabel => "Video player",
+            MessageKey::VideoPlayerRole => "video player",
+            MessageKey::VideoPlayerLoading => "Loading video",
+            MessageKey::VideoPlayerStart => "Play video",
+            MessageKey::VideoPlayerPlay => "Play",
+            MessageKey::VideoPlayerPause => "Pause",
+            MessageKey::VideoPlayerSeek => "Seek",
+            MessageKey::VideoPlayerPosition => "{current} of {duration}",
+            MessageKey::VideoPlayerSpeed => "Playback speed {rate}x",
+            MessageKey::VideoPlayerMute => "Mute",
+            MessageKey::VideoPlayerVolume => "Volume",
+            MessageKey::VideoPlayerCaptions => "Captions",
+            MessageKey::VideoPlayerPictureInPicture => "Picture in picture",
+            MessageKey::VideoPlayerFullscreen => "Full screen",
+            MessageKey::VideoPlayerExitFullscreen => "Exit full screen",
+            MessageKey::ToastRegion => "Toast notifications",
+            MessageKey::ToastViewport => "Toast viewport",
+            MessageKey::ToastAction => "Action",
+            MessageKey::CarouselLabel => "Carousel",
+            MessageKey::CarouselRole => "carousel",
+            MessageKey::CarouselSlideRole => "slide",
+            MessageKey::CarouselSlideLabel => "{position} of {count}",
+            MessageKey::CarouselSlidePicker => "Choose slide to display",
+            MessageKey::CarouselGoToSlide => "Slide {slide}",
+            MessageKey::CarouselStartRotation => "Start automatic slide show",
+            MessageKey::CarouselStopRotation => "Stop automatic slide show",
+            MessageKey::FileUploadArea => "File upload area",
+            MessageKey::FileUploadDropZone => "File drop zone",
+            MessageKey::FileUploadList => "Uploaded files",
+            MessageKey::JsonViewerSearch => "Search JSON",
+            MessageKey::JsonViewerSearchPlaceholder => "Search",
+            MessageKey::JsonViewerCopyPath => "Copy path",
+            MessageKey::JsonViewerCopied => "Copied {path}",
+            MessageKey::JsonViewerLabel => "JSON",
+            MessageKey::JsonViewerArrayItems => "{count, plural, one {# item} other {# items}}",
+            MessageKey::JsonViewerObjectKeys => "{count, plural, one {# key} other {# keys}}",
+            MessageKey::CalendarLabel => "Calendar",
+            MessageKey::CalendarHeader => "Calendar header",
+            MessageKey::CalendarNavigation => "Calendar navigation",
+            MessageKey::CalendarTodayButton => "Today",
+            MessageKey::CalendarGrid => "Calendar for {month} {year}",
+            MessageKey::CalendarWeekHeader => "Week header",
+            MessageKey::TimePickerLabel => "Time picker",
+            MessageKey::TimePickerPlaceholder => "Select time",
+            MessageKey::TimePickerInput => "Time input",
+            MessageKey::TimePickerOptions => "Time options",
+            MessageKey::TimePickerGrid => "Time selection grid",
+            MessageKey::DatePickerLabel => "Date picker",
+            MessageKey::DatePickerPlaceholder => "Select date",
+            MessageKey::DatePickerOpen => "Open date picker",
+            MessageKey::DatePickerCalendar => "Date picker calendar",
+            MessageKey::KanbanLabel => "Kanban board",
+            MessageKey::KanbanNewColumn => "New column",
+            MessageKey::KanbanAddColumn => "Add column",
+            MessageKey::KanbanColumnZone => "{column} column",
+            MessageKey::KanbanColumnHandle => "Column {column}",
+            MessageKey::KanbanCardMoved => {
+                "Moved {card} to {column}, position {position} of {count}"
+            }
+            MessageKey::KanbanColumnMoved => {
+                "Moved column {column} to position {position} of {count}"
+            }
+            MessageKey::KanbanColumnAdded => "Added column {column}",
+            MessageKey::KanbanWipLimit => {
+                "{column} is at its limit of {limit, plural, one {# card} other {# cards}}"
+            }
+            MessageKey::DragInstructions => {
+                "Press Space or Enter to pick up. While dragging, use the arrow keys to move, Space or Enter to drop, and Escape to cancel."
+            }
+            MessageKey::DragDropLabel => "Drag and drop container",
+            MessageKey::DragHandleLabel => "Drag handle",
+            MessageKey::DragHandleRole => "draggable",
+            MessageKey::DropZoneLabel => "Drop zone",
+            MessageKey::DragPreviewLabel => "Drag preview",
+            MessageKey::Zoom => "Zoom",
+            MessageKey::ZoomIn => "Zoom in",
+            MessageKey::ZoomOut => "Zoom out",
+            MessageKey::NoResults => "No results",
+            MessageKey::AudioPlayerLabel => "Audio player",
+            MessageKey::AudioPlayerRole => "audio player",
+            MessageKey::PdfViewerLabel => "PDF document",
+            MessageKey::PdfViewerControls => "Document controls",
+            MessageKey::PdfViewerThumbnails => "Thumbnails",
+            MessageKey::PdfViewerPages => "Pages",
+            MessageKey::PdfViewerPreviousPage => "Previous page",
+            MessageKey::PdfViewerNextPage => "Next page",
+            MessageKey::PdfViewerPageInput => "Page",
+            MessageKey::PdfViewerPageCount => "of {count}",
+            MessageKey::PdfViewerPage => "Page {page}",
+            MessageKey::PdfViewerPageRole => "page",
+            MessageKey::PdfViewerPagePosition => "Page {page} of {count}",
+            MessageKey::PdfViewerFitWidth => "Fit width",
+            MessageKey::PdfViewerSearch => "Search document",
+            MessageKey::PdfViewerSearchPlaceholder => "Search",
+            MessageKey::PdfViewerPreviousMatch => "Previous match",
+            MessageKey::PdfViewerNextMatch => "Next match",
+            MessageKey::PdfViewerMatch => {
+                "{position} of {count, plural, one {# page} other {# pages}}"
+            }
+            MessageKey::OrgChartLabel => "Organization chart",
+            MessageKey::OrgChartResetView => "Reset view",
+            MessageKey::CascaderLabel => "Select",
+            MessageKey::CascaderPlaceholder => "Select...",
+            MessageKey::CascaderLevel => "Level {level}",
+            MessageKey::CascaderResults => "Results",
+            MessageKey::ImageCropperLabel => "Crop image",
+            MessageKey::ImageCropperRole => "image cropper",
+            MessageKey::ImageCropperInstructions => {
+                "Drag or use the arrow keys to move the image, plus and minus to zoom, and the bracket keys to rotate."
+            }
+            MessageKey::ImageCropperRotateLeft => "Rotate left",
+            MessageKey::ImageCropperRotateRight => "Rotate right",
+            MessageKey::ImageCropperCrop => "Crop",
+            MessageKey::ChatLabel => "Conversation",
+            MessageKey::ChatMessageFrom => "Message from {author}",
+            MessageKey::ChatToday => "Today",
+            MessageKey::ChatYesterday => "Yesterday",
+            MessageKey::ChatTyping => {
+                "{others, plural, =0 {{first} is typing…} one {{first} and {second} are typing…} other {{first} and # others are typing…}}"
+            }
+            MessageKey::NotificationCenterLabel => "Notifications",
+            MessageKey::NotificationCenterTrigger => {
+                "{unread, plural, =0 {{label}} other {{label}, # unread}}"
+            }
+            MessageKey::NotificationCenterEmpty => "You're all caught up",
+            MessageKey::NotificationCenterUnread => "Unread:",
+            MessageKey::NotificationCenterMarkAllRead => "Mark all as read",
+            MessageKey::TreeSelectLabel => "Select",
+            MessageKey::TreeSelectPlaceholder => "Select...",
+            MessageKey::TreeSelectSearchPlaceholder => "Search...",
+            MessageKey::TreeSelectSearch => "Search {label}",
+            MessageKey::TreeSelectTrigger => {
+                "{count, plural, =0 {{label}} other {{label}, # selected}}"
+            }
+            MessageKey::MultiSelectSearchPlaceholder => "Search options...",
+            MessageKey::MultiSelectRemove => "Remove {option}",
+            MessageKey::SignaturePadLabel => "Signature",
+            MessageKey::SignaturePadCanvas => {
+                "{count, plural, =0 {{label}, empty} one {{label}, # stroke} other {{label}, # strokes}}"
+            }
+            MessageKey::SignaturePadUndo => "Undo",
+            MessageKey::SignaturePadClear => "Clear",
+            MessageKey::SignaturePadRequired => "Signature is required",
+            MessageKey::CalendarHeatmapLabel => "Activity",
+            MessageKey::CalendarHeatmapDay => {
+                "{count, plural, one {# contribution} other {# contributions}} on {date}"
+            }
+            MessageKey::CalendarHeatmapLess => "Less",
+            MessageKey::CalendarHeatmapMore => "More",
+            MessageKey::ShortcutHelpTitle => "Keyboard shortcuts",
+            MessageKey::ShortcutHelpShow => "Show keyboard shortcuts",
+            MessageKey::ShortcutHelpEmpty => "No keyboard shortcuts",
+            MessageKey::ShortcutHelpGeneral => "General",
+            MessageKey::StatisticTrend => {
+                "{trend, select, up {Increased by {delta}} down {Decreased by {delta}} other {No change}}"
+            }
+            MessageKey::TourNext => "Next",
+            MessageKey::TourPrevious => "Back",
+            MessageKey::TourSkip => "Skip",
+            MessageKey::TourDone => "Done",
+            MessageKey::TourProgress => "{step} / {count}",
+            MessageKey::BackToTopLabel => "Back to top",
+            MessageKey::AnchorLabel => "Table of contents",
+            MessageKey::ScrollProgressLabel => "Reading progress",
+            MessageKey::AvatarLabel => "Avatar",
+            MessageKey::AvatarImage => "Avatar image",
+            MessageKey::AvatarFallback => "Avatar fallback",
+            MessageKey::AvatarGroupLabel => "Avatar group",
+            MessageKey::AvatarGroupOverflow => "{count} more",
+            MessageKey::AvatarGroupOverflowButton => "{count} more: {names}",
+            MessageKey::SeparatorGroup => "Separator group",
+            MessageKey::BarChartLabel => "Bar chart visualization",
+            MessageKey::BarChartBar => "Bar: {category} - {value}",
+            MessageKey::BarChartGroup => "Bar group: {category}",
+            MessageKey::PieChartLabel => "Pie chart visualization",
+            MessageKey::PieChartSlice => "Slice: {label} - {percent}%",
+            MessageKey::LineChartLabel => "Line chart visualization",
+            MessageKey::LineChartArea => "Chart area fill",
+            MessageKey::LineChartPoint => "Data point: {label}",
+            MessageKey::ChartDataPoint => "Data point",
+            MessageKey::ScatterPlotLabel => "Scatter plot visualization",
+            MessageKey::ScatterPlotPoint => "Data point: ({x}, {y})",
+            MessageKey::ScatterPlotTrendLine => "Trend line for {series}",
+            MessageKey::FormValidationLabel => "Form with validation",
+            MessageKey::FormValidationErrors => "Form errors",
+            MessageKey::TimelineLabel => "Timeline",
+            MessageKey::TimelineEvent => "Timeline event: {title}",
+            MessageKey::LabelDescription => "Label description",
+            MessageKey::LabelGroup => "Label group",
+            MessageKey::ContextMenuLabel => "Context menu",
+            MessageKey::AspectRatioContainer => "Aspect ratio container",
+            MessageKey::AspectRatioWrapper => "Aspect ratio wrapper",
+            MessageKey::ComboboxPlaceholder => "Select option...",
+            MessageKey::ComboboxGroup => "Group",
+            MessageKey::SelectPlaceholder => "Select an option",
+            MessageKey::TreeViewSearchPlaceholder => "Search tree...",
+            MessageKey::PasswordRequirementsTitle => "Password Requirements",
+            MessageKey::PasswordMinLength => "At least {count} characters",
+            MessageKey::PasswordUppercase => "At least one uppercase letter",
+            MessageKey::PasswordLowercase => "At least one lowercase letter",
+            MessageKey::PasswordNumber => "At least one number",
+            MessageKey::PasswordSymbol => "At least one symbol",
+            MessageKey::PasswordTooShort => "Password must be at least {count} characters long",
+            MessageKey::PasswordMissingUppercase => {
+                "Password must contain at least one uppercase letter"
+            }
+            MessageKey::PasswordMissingLowercase => {
+                "Password must contain at least one lowercase letter"
+            }
+            MessageKey::PasswordMissingNumber => "Password must contain at least one number",
+            MessageKey::PasswordMissingSymbol => "Password must contain at least one symbol",
+            MessageKey::PasswordStrength => {
+                "Strength: {level, select, very_weak {Very weak} weak {Weak} fair {Fair} good {Good} other {Strong}}"
+            }
+            MessageKey::OtpTimerReset => "Reset",
+            MessageKey::OtpResend => "Resend OTP",
+            MessageKey::OtpResendCooldown => "Resend available in {seconds}s",
+            MessageKey::ResizableExpand => "Expand",
+            MessageKey::ResizableCollapse => "Collapse",
+        }
+    }
+
+    /// Arguments the message may reference
+    pub fn arguments(&self) -> &'static [&'static str] {
+        match self {
+            MessageKey::PaginationSummary => &["start", "end", "total", "current", "total_pages"],
+            MessageKey::TableSelectionSummary => &["selected", "total"],
+            MessageKey::FileUploadItemLabel => &["name", "status", "percent"],
+            MessageKey::ResultsAvailable => &["count"],
+            MessageKey::PaginationLabel => &[],
+            MessageKey::PaginationFirst => &[],
+            MessageKey::PaginationPrevious => &[],
+            MessageKey::PaginationNext => &[],
+            MessageKey::PaginationLast => &[],
+            MessageKey::PaginationPage => &["page"],
+            MessageKey::ThemeSelection => &[],
+            MessageKey::SearchLabel => &[],
+            MessageKey::SearchInput => &[],
+            MessageKey::SearchSuggestions => &[],
+            MessageKey::SearchClear => &[],
+            MessageKey::SearchFilters => &[],
+            MessageKey::ComboboxInput => &[],
+            MessageKey::ComboboxOpen => &[],
+            MessageKey::ComboboxClear => &[],
+            MessageKey::CalendarPreviousMonth => &[],
+            MessageKey::CalendarNextMonth => &[],
+            MessageKey::CalendarToday => &[],
+            MessageKey::CarouselPrevious => &[],
+            MessageKey::CarouselNext => &[],
+            MessageKey::Close => &[],
+            MessageKey::Loading => &[],
+            MessageKey::AlertDismiss => &[],
+            MessageKey::ToastClose => &[],
+            MessageKey::SheetClose => &[],
+            MessageKey::TableSort => &["column", "direction"],
+            MessageKey::TableSelectionCount => &["count"],
+            MessageKey::FormErrorSummary => &["count", "fields"],
+            MessageKey::DragLift => &["item"],
+            MessageKey::DragOver => &["item", "zone", "position", "count"],
+            MessageKey::DragDrop => &["item", "zone"],
+            MessageKey::DragCancel => &["item"],
+            MessageKey::ChartLabel => &[],
+            MessageKey::ChartLegend => &[],
+            MessageKey::ChartCategory => &[],
+            MessageKey::TableLoaded => &["count"],
+            MessageKey::TableUpdated => &[],
+            MessageKey::ResultsLoaded => &["count"],
+            MessageKey::Loaded => &[],
+            MessageKey::VideoPlayerLabel => &[],
+            MessageKey::VideoPlayerRole => &[],
+            MessageKey::VideoPlayerLoading => &[],
+            MessageKey::VideoPlayerStart => &[],
+            MessageKey::VideoPlayerPlay => &[],
+            MessageKey::VideoPlayerPause => &[],
+            MessageKey::VideoPlayerSeek => &[],
+            MessageKey::VideoPlayerPosition => &["current", "duration"],
+            MessageKey::VideoPlayerSpeed => &["rate"],
+            MessageKey::VideoPlayerMute => &[],
+            MessageKey::VideoPlayerVolume => &[],
+            MessageKey::VideoPlayerCaptions => &[],
+            MessageKey::VideoPlayerPictureInPicture => &[],
+            MessageKey::VideoPlayerFullscreen => &[],
+            MessageKey::VideoPlayerExitFullscreen => &[],
+            MessageKey::ToastRegion => &[],
+            MessageKey::ToastViewport => &[],
+            MessageKey::ToastAction => &[],
+            MessageKey::CarouselLabel => &[],
+            MessageKey::CarouselRole => &[],
+            MessageKey::CarouselSlideRole => &[],
+            MessageKey::CarouselSlideLabel => &["position", "count"],
+            MessageKey::CarouselSlidePicker => &[],
+            MessageKey::CarouselGoToSlide => &["slide"],
+            MessageKey::CarouselStartRotation => &[],
+            MessageKey::CarouselStopRotation => &[],
+            MessageKey::FileUploadArea => &[],
+            MessageKey::FileUploadDropZone => &[],
+            MessageKey::FileUploadList => &[],
+            MessageKey::JsonViewerSearch => &[],
+            MessageKey::JsonViewerSearchPlaceholder => &[],
+            MessageKey::JsonViewerCopyPath => &[],
+            MessageKey::JsonViewerCopied => &["path"],
+            MessageKey::JsonViewerLabel => &[],
+            MessageKey::JsonViewerArrayItems => &["count"],
+            MessageKey::JsonViewerObjectKeys => &["count"],
+            MessageKey::CalendarLabel => &[],
+            MessageKey::CalendarHeader => &[],
+            MessageKey::CalendarNavigation => &[],
+            MessageKey::CalendarTodayButton => &[],
+            MessageKey::CalendarGrid => &["month", "year"],
+            MessageKey::CalendarWeekHeader => &[],
+            MessageKey::TimePickerLabel => &[],
+            MessageKey::TimePickerPlaceholder => &[],
+            MessageKey::TimePickerInput => &[],
+            MessageKey::TimePickerOptions => &[],
+            MessageKey::TimePickerGrid => &[],
+            MessageKey::DatePickerLabel => &[],
+            MessageKey::DatePickerPlaceholder => &[],
+            MessageKey::DatePickerOpen => &[],
+            MessageKey::DatePickerCalendar => &[],
+            MessageKey::KanbanLabel => &[],
+            MessageKey::KanbanNewColumn => &[],
+            MessageKey::KanbanAddColumn => &[],
+            MessageKey::KanbanColumnZone => &["column"],
+            MessageKey::KanbanColumnHandle => &["column"],
+            MessageKey::KanbanCardMoved => &["card", "column", "position", "count"],
+            MessageKey::KanbanColumnMoved => &["column", "position", "count"],
+            MessageKey::KanbanColumnAdded => &["column"],
+            MessageKey::KanbanWipLimit => &["column", "limit"],
+            MessageKey::DragInstructions => &[],
+            MessageKey::DragDropLabel => &[],
+            MessageKey::DragHandleLabel => &[],
+            MessageKey::DragHandleRole => &[],
+            MessageKey::DropZoneLabel => &[],
+            MessageKey::DragPreviewLabel => &[],
+            MessageKey::Zoom => &[],
+            MessageKey::ZoomIn => &[],
+            MessageKey::ZoomOut => &[],
+            MessageKey::NoResults => &[],
+            MessageKey::AudioPlayerLabel => &[],
+            MessageKey::AudioPlayerRole => &[],
+            MessageKey::PdfViewerLabel => &[],
+            MessageKey::PdfViewerControls => &[],
+            MessageKey::PdfViewerThumbnails => &[],
+            MessageKey::PdfViewerPages => &[],
+            MessageKey::PdfViewerPreviousPage => &[],
+            MessageKey::PdfViewerNextPage => &[],
+            MessageKey::PdfViewerPageInput => &[],
+            MessageKey::PdfViewerPageCount => &["count"],
+            MessageKey::PdfViewerPage => &["page"],
+            MessageKey::PdfViewerPageRole => &[],
+            MessageKey::PdfViewerPagePosition => &["page", "count"],
+            MessageKey::PdfViewerFitWidth => &[],
+            MessageKey::PdfViewerSearch => &[],
+            MessageKey::PdfViewerSearchPlaceholder => &[],
+            MessageKey::PdfViewerPreviousMatch => &[],
+            MessageKey::PdfViewerNextMatch => &[],
+            MessageKey::PdfViewerMatch => &["position", "count"],
+            MessageKey::OrgChartLabel => &[],
+            MessageKey::OrgChartResetView => &[],
+            MessageKey::CascaderLabel => &[],
+            MessageKey::CascaderPlaceholder => &[],
+            MessageKey::CascaderLevel => &["level"],
+            MessageKey::CascaderResults => &[],
+            MessageKey::ImageCropperLabel => &[],
+            MessageKey::ImageCropperRole => &[],
+            MessageKey::ImageCropperInstructions => &[],
+            MessageKey::ImageCropperRotateLeft => &[],
+            MessageKey::ImageCropperRotateRight => &[],
+            MessageKey::ImageCropperCrop => &[],
+            MessageKey::ChatLabel => &[],
+            MessageKey::ChatMessageFrom => &["author"],
+            MessageKey::ChatToday => &[],
+            MessageKey::ChatYesterday => &[],
+            MessageKey::ChatTyping => &["first", "second", "others"],
+            MessageKey::NotificationCenterLabel => &[],
+            MessageKey::NotificationCenterTrigger => &["label", "unread"],
+            MessageKey::NotificationCenterEmpty => &[],
+            MessageKey::NotificationCenterUnread => &[],
+            MessageKey::NotificationCenterMarkAllRead => &[],
+            MessageKey::TreeSelectLabel => &[],
+            MessageKey::TreeSelectPlaceholder => &[],
+            MessageKey::TreeSelectSearchPlaceholder => &[],
+            MessageKey::TreeSelectSearch => &["label"],
+            MessageKey::TreeSelectTrigger => &["label", "count"],
+            MessageKey::MultiSelectSearchPlaceholder => &[],
+            MessageKey::MultiSelectRemove => &["option"],
+            MessageKey::SignaturePadLabel => &[],
+            MessageKey::SignaturePadCanvas => &["label", "count"],
+            MessageKey::SignaturePadUndo => &[],
+            MessageKey::SignaturePadClear => &[],
+            MessageKey::SignaturePadRequired => &[],
+            MessageKey::CalendarHeatmapLabel => &[],
+            MessageKey::CalendarHeatmapDay => &["count", "date"],
+            MessageKey::CalendarHeatmapLess => &[],
+            MessageKey::CalendarHeatmapMore => &[],
+            MessageKey::ShortcutHelpTitle => &[],
+            MessageKey::ShortcutHelpShow => &[],
+            MessageKey::ShortcutHelpEmpty => &[],
+            MessageKey::ShortcutHelpGeneral => &[],
+            MessageKey::StatisticTrend => &["trend", "delta"],
+            MessageKey::TourNext => &[],
+            MessageKey::TourPrevious => &[],
+            MessageKey::TourSkip => &[],
+            MessageKey::TourDone => &[],
+            MessageKey::TourProgress => &["step", "count"],
+            MessageKey::BackToTopLabel => &[],
+            MessageKey::AnchorLabel => &[],
+            MessageKey::ScrollProgressLabel => &[],
+            MessageKey::AvatarLabel => &[],
+            MessageKey::AvatarImage => &[],
+            MessageKey::AvatarFallback => &[],
+            MessageKey::AvatarGroupLabel => &[],
+            MessageKey::AvatarGroupOverflow => &["count"],
+            MessageKey::AvatarGroupOverflowButton => &["count", "names"],
+            MessageKey::SeparatorGroup => &[],
+            MessageKey::BarChartLabel => &[],
+            MessageKey::BarChartBar => &["category", "value"],
+            MessageKey::BarChartGroup => &["category"],
+            MessageKey::PieChartLabel => &[],
+            MessageKey::PieChartSlice => &["label", "percent"],
+            MessageKey::LineChartLabel => &[],
+            MessageKey::LineChartArea => &[],
+            MessageKey::LineChartPoint => &["label"],
+            MessageKey::ChartDataPoint => &[],
+            MessageKey::ScatterPlotLabel => &[],
+            MessageKey::ScatterPlotPoint => &["x", "y"],
+            MessageKey::ScatterPlotTrendLine => &["series"],
+            MessageKey::FormValidationLabel => &[],
+            MessageKey::FormValidationErrors => &[],
+            MessageKey::TimelineLabel => &[],
+            MessageKey::TimelineEvent => &["title"],
+            MessageKey::LabelDescription => &[],
+            MessageKey::LabelGroup => &[],
+            MessageKey::ContextMenuLabel => &[],
+            MessageKey::AspectRatioContainer => &[],
+            MessageKey::AspectRatioWrapper => &[],
+            MessageKey::ComboboxPlaceholder => &[],
+            MessageKey::ComboboxGroup => &[],
+            MessageKey::SelectPlaceholder => &[],
+            MessageKey::TreeViewSearchPlaceholder => &[],
+            MessageKey::PasswordRequirementsTitle => &[],
+            MessageKey::PasswordMinLength => &["count"],
+            MessageKey::PasswordUppercase => &[],
+            MessageKey::PasswordLowercase => &[],
+            MessageKey::PasswordNumber => &[],
+            MessageKey::PasswordSymbol => &[],
+            MessageKey::PasswordTooShort => &["count"],
+            MessageKey::PasswordMissingUppercase => &[],
+            MessageKey::PasswordMissingLowercase => &[],
+            MessageKey::PasswordMissingNumber => &[],
+            MessageKey::PasswordMissingSymbol => &[],
+            MessageKey::PasswordStrength => &["level"],
+            MessageKey::OtpTimerReset => &[],
+            MessageKey::OtpResend => &[],
+            MessageKey::OtpResendCooldown => &["seconds"],
+            MessageKey::ResizableExpand => &[],
+            MessageKey::ResizableCollapse => &[],
+        }
+    }
+}
+
+/// Localized messages for one locale
+///
+/// # Example
+///
+/// ```rust
+/// use radix_leptos_primitives::*;
+///
+/// let catalog = MessageCatalog::new("de").with_message(
+///     MessageKey::PaginationSummary,
+///     "{start}–{end} von {total, plural, one {# Eintrag} other {# Einträgen}}",
+/// );
+/// let args = MessageArgs::new().with("start", 1).with("end", 10).with("total", 42);
+/// assert_eq!(catalog.format(MessageKey::PaginationSummary, &args), "1–10 von 42 Einträgen");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct MessageCatalog {
+    pub locale: String,
+    messages: HashMap<MessageKey, String>,
+}
+
+impl Default for MessageCatalog {
+    fn default() -> Self {
+        Self::new("en")
+    }
+}
+
+impl MessageCatalog {
+    pub fn new(locale: impl Into<String>) -> Self {
+        Self {
+            locale: locale.into(),
+            messages: HashMap::new(),
+        }
+    }
+
+    pub fn with_message(mut self, key: MessageKey, message: impl Into<String>) -> Self {
+        self.messages.insert(key, message.into());
+        self
+    }
+
+    /// Add the translations of a string table keyed by
+    /// [`MessageKey::as_str`], e.g. one loaded from a JSON file
+    ///
+    /// Unknown keys are skipped with a development-mode warning.
+    pub fn with_strings<K, V>(mut self, strings: impl IntoIterator<Item = (K, V)>) -> Self
+    where
+        K: AsRef<str>,
+        V: Into<String>,
+    {
+        for (key, message) in strings {
+            match MessageKey::from_key(key.as_ref()) {
+                Some(key) => {
+                    self.messages.insert(key, message.into());
+                }
+                None => warn_invalid_prop(
+                    "MessageCatalog",
+                    &format!("unknown message key `{}`", key.as_ref()),
+                ),
+            }
+        }
+        self
+    }
+
+    /// Pattern for `key`, falling back to the English default
+    pub fn message(&self, key: MessageKey) -> &str {
+        self.messages
+            .get(&key)
+            .map(String::as_str)
+            .unwrap_or_else(|| key.default_message())
+    }
+
+    /// Format `key` with `args`
+    ///
+    /// A broken translation falls back to the default message (with a
+    /// development-mode warning) so a component never renders empty text.
+    pub fn format(&self, key: MessageKey, args: &MessageArgs) -> String {
+        format_message(self.message(key), &self.locale, args).unwrap_or_else(|error| {
+            warn_invalid_prop(
+                "MessageCatalog",
+                &format!(
+                    "message `{}` for `{}`: {}",
+                    key.as_str(),
+                    self.locale,
+                    error
+                ),
+            );
+            format_message(key.default_message(), &self.locale, args).unwrap_or_default()
+        })
+    }
+
+    /// Format a message without arguments, such as a button label
+    pub fn text(&self, key: MessageKey) -> String {
+        self.format(key, &MessageArgs::new())
+    }
+
+    /// Check every override parses and only uses the key's arguments
+    pub fn validate(&self) -> Result<(), String> {
+        for (key, pattern) in &self.messages {
+            let message =
+                Message::parse(pattern).map_err(|error| format!("{}: {}", key.as_str(), error))?;
+            if let Some(unknown) = message
+                .argument_names()
+                .into_iter()
+                .find(|name| !key.arguments().contains(&name.as_str()))
+            {
+                return Err(format!("{}: unknown argument `{}`", key.as_str(), unknown));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Provide a message catalog to the components below
+pub fn provide_i18n(catalog: MessageCatalog) {
+    provide_context(catalog);
+}
+
+/// The nearest provided message catalog, or the English defaults
+pub fn use_i18n() -> MessageCatalog {
+    use_context::<MessageCatalog>().unwrap_or_default()
+}
+
+/// I18nProvider component - Supplies localized messages to its children
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let catalog = MessageCatalog::new("fr").with_message(
+///         MessageKey::PaginationSummary,
+///         "{start}–{end} sur {total, plural, one {# élément} other {# éléments}}",
+///     );
+///     view! {
+///         <I18nProvider catalog=catalog>
+///             <Pagination total_pages=5>
+///                 <PaginationInfo>{()}</PaginationInfo>
+///             </Pagination>
+///         </I18nProvider>
+///     }
+/// }
+/// ```
+#[component]
+pub fn I18nProvider(catalog: MessageCatalog, children: Children) -> impl IntoView {
+    if let Err(error) = catalog.validate() {
+        warn_invalid_prop("I18nProvider", &error);
+    }
+    provide_i18n(catalog);
+    children()
+}
+
+/// LocaleProvider component - Translates the built-in strings of its
+/// children
+///
+/// `strings` overrides the English labels and announcements components
+/// render, keyed by [`MessageKey::as_str`]; keys it leaves out stay in
+/// English. `locale` selects the plural rules. Build a [`MessageCatalog`]
+/// and use [`I18nProvider`] instead to key translations by [`MessageKey`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::collections::HashMap;
+///
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     // Usually deserialized from a translation file
+///     let strings: HashMap<String, String> = [
+///         ("pagination.label", "Seitennavigation"),
+///         ("pagination.previous", "Vorherige Seite"),
+///         ("pagination.next", "Nächste Seite"),
+///         ("pagination.page", "Seite {page}"),
+///     ]
+///     .into_iter()
+///     .map(|(key, text)| (key.to_string(), text.to_string()))
+///     .collect();
+///     view! {
+///         <LocaleProvider locale="de" strings=strings>
+///             <Pagination total_pages=5>
+///                 <PaginationList>
+///                     <PaginationPrevious>{()}</PaginationPrevious>
+///                     <PaginationNext>{()}</PaginationNext>
+///                 </PaginationList>
+///             </Pagination>
+///         </LocaleProvider>
+///     }
+/// }
+/// ```
+#[component]
+pub fn LocaleProvider(
+    /// BCP 47 language tag of the translations
+    #[prop(into)]
+    locale: String,
+    /// Translations by message key
+    #[prop(optional)]
+    strings: HashMap<String, String>,
+    children: Children,
+) -> impl IntoView {
+    let catalog = MessageCatalog::new(locale).with_strings(strings);
+    if let Err(error) = catalog.validate() {
+        warn_invalid_prop("LocaleProvider", &error);
+    }
+    provide_i18n(catalog);
+    children()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_messages_are_valid() {
+        for key in MessageKey::ALL {
+            let message = Message::parse(key.default_message()).unwrap();
+            for name in message.argument_names() {
+                assert!(
+                    key.arguments().contains(&name.as_str()),
+                    "{} uses undeclared `{}`",
+                    key.as_str(),
+                    name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_default_pagination_summary() {
+        let catalog = MessageCatalog::default();
+        let args = |end: i32, total: i32| {
+            MessageArgs::new()
+                .with("start", 1)
+                .with("end", end)
+                .with("total", total)
+        };
+        assert_eq!(
+            catalog.format(MessageKey::PaginationSummary, &args(1, 1)),
+            "1–1 of 1 item"
+        );
+        assert_eq!(
+            catalog.format(MessageKey::PaginationSummary, &args(10, 95)),
+            "1–10 of 95 items"
+        );
+    }
+
+    #[test]
+    fn test_table_selection_summary() {
+        let catalog = MessageCatalog::default();
+        let args = |selected: i32| {
+            MessageArgs::new()
+                .with("selected", selected)
+                .with("total", 20)
+        };
+        assert_eq!(
+            catalog.format(MessageKey::TableSelectionSummary, &args(0)),
+            "No rows selected"
+        );
+        assert_eq!(
+            catalog.format(MessageKey::TableSelectionSummary, &args(1)),
+            "1 of 20 row selected"
+        );
+        assert_eq!(
+            catalog.format(MessageKey::TableSelectionSummary, &args(3)),
+            "3 of 20 rows selected"
+        );
+    }
+
+    #[test]
+    fn test_file_upload_label() {
+        let catalog = MessageCatalog::default();
+        let args = MessageArgs::new()
+            .with("name", "photo.png")
+            .with("status", "uploading")
+            .with("percent", 45);
+        assert_eq!(
+            catalog.format(MessageKey::FileUploadItemLabel, &args),
+            "photo.png, 45% uploaded"
+        );
+    }
+
+    #[test]
+    fn test_results_available() {
+        let catalog = MessageCatalog::default();
+        let args = |count: i32| MessageArgs::new().with("count", count);
+        assert_eq!(
+            catalog.format(MessageKey::ResultsAvailable, &args(0)),
+            "No results"
+        );
+        assert_eq!(
+            catalog.format(MessageKey::ResultsAvailable, &args(1)),
+            "1 result available"
+        );
+        assert_eq!(
+            catalog.format(MessageKey::ResultsAvailable, &args(5)),
+            "5 results available"
+        );
+    }
+
+    #[test]
+    fn test_override_and_fallback() {
+        let args = MessageArgs::new().with("selected", 2).with("total", 5);
+        let catalog = MessageCatalog::new("de").with_message(
+            MessageKey::TableSelectionSummary,
+            "{selected} von {total} ausgewählt",
+        );
+        assert_eq!(
+            catalog.format(MessageKey::TableSelectionSummary, &args),
+            "2 von 5 ausgewählt"
+        );
+
+        let broken = MessageCatalog::new("de")
+            .with_message(MessageKey::TableSelectionSummary, "{selected von {total}");
+        assert_eq!(
+            broken.format(MessageKey::TableSelectionSummary, &args),
+            "2 of 5 rows selected"
+        );
+    }
+
+    #[test]
+    fn test_string_table_overrides() {
+        assert_eq!(
+            MessageKey::from_key("pagination.next"),
+            Some(MessageKey::PaginationNext)
+        );
+        assert_eq!(MessageKey::from_key("pagination.upcoming"), None);
+        for key in MessageKey::ALL {
+            assert_eq!(MessageKey::from_key(key.as_str()), Some(key));
+        }
+
+        let catalog = MessageCatalog::new("fr").with_strings([
+            ("pagination.next", "Page suivante"),
+            ("pagination.page", "Page {page}"),
+            ("pagination.upcoming", "ignored"),
+        ]);
+        assert_eq!(catalog.text(MessageKey::PaginationNext), "Page suivante");
+        assert_eq!(catalog.text(MessageKey::PaginationPrevious), "Go to previous page");
+        assert_eq!(
+            catalog.format(MessageKey::PaginationPage, &MessageArgs::new().with("page", 3)),
+            "Page 3"
+        );
+        assert!(catalog.validate().is_ok());
+    }
+
+    #[test]
+    fn test_locale_provider_translates_labels() {
+        use crate::{Pagination, PaginationItem, PaginationList, PaginationNext, PaginationPage};
+
+        crate::test_support::init_test_executor();
+        let owner = Owner::new();
+        owner.with(|| {
+            let strings = [
+                ("pagination.label", "Seitennavigation"),
+                ("pagination.next", "Nächste Seite"),
+                ("pagination.page", "Seite {page}"),
+            ]
+            .into_iter()
+            .map(|(key, text)| (key.to_string(), text.to_string()))
+            .collect();
+            let html = view! {
+                <LocaleProvider locale="de" strings=strings>
+                    <Pagination total_pages=5>
+                        <PaginationList>
+                            <PaginationItem page=PaginationPage::new(2)>"2"</PaginationItem>
+                            <PaginationNext>"›"</PaginationNext>
+                        </PaginationList>
+                    </Pagination>
+                </LocaleProvider>
+            }
+            .to_html();
+            assert!(html.contains("aria-label=\"Seitennavigation\""));
+            assert!(html.contains("aria-label=\"Seite 2\""));
+            assert!(html.contains("aria-label=\"Nächste Seite\""));
+        });
+    }
+
+    #[test]
+    fn test_message_keys_are_unique() {
+        for key in MessageKey::ALL {
+            assert_eq!(MessageKey::from_key(key.as_str()), Some(key));
+        }
+    }
+
+    #[test]
+    fn test_locale_provider_translates_video_player() {
+        use crate::VideoPlayer;
+
+        crate::test_support::init_test_executor();
+        let owner = Owner::new();
+        owner.with(|| {
+            let strings = [
+                ("video_player.label", "Videoplayer"),
+                ("video_player.start", "Video abspielen"),
+                ("video_player.fullscreen", "Vollbild"),
+            ]
+            .into_iter()
+            .map(|(key, text)| (key.to_string(), text.to_string()))
+            .collect();
+            let html = view! {
+                <LocaleProvider locale="de" strings=strings>
+                    <VideoPlayer src="/v.mp4".to_string() />
+                </LocaleProvider>
+            }
+            .to_html();
+            assert!(html.contains("aria-label=\"Videoplayer\""));
+            assert!(html.contains("aria-label=\"Video abspielen\""));
+            assert!(html.contains("aria-label=\"Vollbild\""));
+            assert!(html.contains("aria-label=\"Mute\""));
+        });
+    }
+
+    /// Every message replaced by its key, so leftover English stands out
+    fn pseudo_catalog() -> MessageCatalog {
+        MessageKey::ALL
+            .into_iter()
+            .fold(MessageCatalog::new("qps"), |catalog, key| {
+                catalog.with_message(key, format!("‹{}›", key.as_str()))
+            })
+    }
+
+    #[test]
+    fn test_pseudo_locale_leaves_no_english() {
+        use crate::components::form_validation::{FormError, FormErrorSummary, FormValidationProvider};
+        use crate::*;
+        use chrono::NaiveDate;
+
+        crate::test_support::init_test_executor();
+        let catalog = pseudo_catalog();
+        assert!(catalog.validate().is_ok());
+        let day = NaiveDate::from_ymd_opt(2026, 3, 5).unwrap();
+        let leaf = |id: &str| TreeNode {
+            id: id.to_string(),
+            label: id.to_string(),
+            ..TreeNode::default()
+        };
+        let avatars = ["ada", "alan", "grace", "edsger"]
+            .into_iter()
+            .map(AvatarGroupItem::new)
+            .collect::<Vec<_>>();
+        let requirements = PasswordStrengthRequirements {
+            min_length: 8,
+            require_uppercase: true,
+            require_lowercase: true,
+            require_numbers: true,
+            require_symbols: true,
+            min_strength_score: 4,
+        };
+        let error = FormError {
+            message: "x".to_string(),
+            ..FormError::default()
+        };
+
+        let html = Owner::new().with(|| {
+            view! {
+                <I18nProvider catalog=catalog>
+                    <NotificationCenter
+                        notifications=RwSignal::new(vec![
+                            NotificationItem::new("1", "build", day.and_hms_opt(9, 0, 0).unwrap()),
+                        ])
+                        today=day
+                    />
+                    <TreeSelect
+                        data=vec![leaf("a"), leaf("b")]
+                        value=RwSignal::new(vec!["a".to_string()])
+                    />
+                    <SignaturePad />
+                    <CalendarHeatmap values=Vec::new() start=day end=day />
+                    <Statistic value=1.0 delta=0.5 />
+                    <Statistic value=1.0 delta=-0.5 />
+                    <Anchor links=vec![AnchorLink::new("#intro", "intro")] />
+                    <AvatarGroup avatars=avatars maxvisible=2 />
+                    <SeparatorGroup />
+                    <PasswordRequirements requirements=requirements.clone() />
+                    <PasswordStrengthIndicator password="a".to_string() requirements=requirements />
+                    <OtpTimer />
+                    <OtpResend />
+                    <OtpResend available=false cooldown=30 />
+                    <ResizablePanel title="panel".to_string() collapsible=true children=None />
+                    <ResizablePanel title="panel".to_string() collapsible=true collapsed=true children=None />
+                    <Combobox />
+                    <ComboboxGroup />
+                    <SelectValue />
+                    <TreeViewSearch />
+                    <MultiSelectSearch />
+                    <LabelDescription />
+                    <LabelGroup />
+                    <ContextMenu />
+                    <AspectRatio />
+                    <Timeline />
+                    <FormValidationProvider />
+                    <FormErrorSummary errors=vec![error] />
+                </I18nProvider>
+            }
+            .to_html()
+        });
+
+        for key in MessageKey::ALL {
+            if key.arguments().is_empty() {
+                assert!(
+                    !html.contains(key.default_message()),
+                    "`{}` is still in English",
+                    key.as_str()
+                );
+            }
+        }
+        for english in [
+            " unread",
+            "selected",
+            "Remove ",
+            ", empty",
+            "contribution",
+            "Increased by",
+            "Decreased by",
+            "more:",
+            "At least",
+            "Strength:",
+            "Resend available",
+            "error:",
+        ] {
+            assert!(!html.contains(english), "`{}` is still in English", english);
+        }
+        for key in [
+            MessageKey::NotificationCenterMarkAllRead,
+            MessageKey::NotificationCenterTrigger,
+            MessageKey::MultiSelectRemove,
+            MessageKey::SignaturePadCanvas,
+            MessageKey::CalendarHeatmapDay,
+            MessageKey::StatisticTrend,
+            MessageKey::AvatarGroupOverflowButton,
+            MessageKey::PasswordTooShort,
+            MessageKey::OtpResendCooldown,
+            MessageKey::ResizableExpand,
+        ] {
+            assert!(html.contains(&format!("‹{}›", key.as_str())), "{} not rendered", key.as_str());
+        }
+    }
+
+    #[cfg(feature = "experimental")]
+    #[test]
+    fn test_pseudo_locale_translates_charts() {
+        use crate::*;
+
+        crate::test_support::init_test_executor();
+        let html = Owner::new().with(|| {
+            view! {
+                <I18nProvider catalog=pseudo_catalog()>
+                    <BarChart />
+                    <BarChartBar />
+                    <BarChartGroup />
+                    <PieChart />
+                    <PieChartSlice slice=PieSlice { label: "Rent".to_string(), ..PieSlice::default() } />
+                    <LineChart />
+                    <LineChartArea />
+                    <LineChartPoint />
+                    <ScatterPlot />
+                    <ScatterPlotPoint />
+                    <ScatterPlotTrendLine />
+                </I18nProvider>
+            }
+            .to_html()
+        });
+        for english in [
+            "visualization",
+            "Bar",
+            "Slice",
+            "Chart area",
+            "Data point",
+            "Trend line",
+        ] {
+            assert!(!html.contains(english), "`{}` is still in English", english);
+        }
+        assert!(html.contains("‹scatter_plot.trend_line›"));
+    }
+
+    #[test]
+    fn test_validate_rejects_unknown_arguments() {
+        let catalog = MessageCatalog::new("en").with_message(
+            MessageKey::PaginationSummary,
+            "Page {page} of {total_pages}",
+        );
+        assert_eq!(
+            catalog.validate(),
+            Err("pagination.summary: unknown argument `page`".to_string())
+        );
+        assert!(MessageCatalog::new("en").validate().is_ok());
+    }
+}
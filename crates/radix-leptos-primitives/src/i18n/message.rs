@@ -0,0 +1,591 @@
+use std::collections::HashMap;
+
+use super::plural::{plural_category, PluralCategory};
+
+/// Errors raised while parsing or formatting a message
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum MessageError {
+    #[error("unexpected end of message, expected `{expected}`")]
+    UnexpectedEnd { expected: char },
+    #[error("unexpected `{found}` at position {position}")]
+    UnexpectedChar { found: char, position: usize },
+    #[error("unknown argument type `{0}`")]
+    UnknownType(String),
+    #[error("`{0}` needs an `other` case")]
+    MissingOther(String),
+    #[error("missing argument `{0}`")]
+    MissingArgument(String),
+    #[error("argument `{0}` must be a number")]
+    NotANumber(String),
+}
+
+/// Value of a message argument
+#[derive(Debug, Clone, PartialEq)]
+pub enum MessageValue {
+    Number(f64),
+    Text(String),
+}
+
+impl MessageValue {
+    fn to_text(&self) -> String {
+        match self {
+            MessageValue::Number(n) => format_number(*n),
+            MessageValue::Text(text) => text.clone(),
+        }
+    }
+}
+
+macro_rules! impl_number_value {
+    ($($ty:ty),*) => {
+        $(impl From<$ty> for MessageValue {
+            fn from(value: $ty) -> Self {
+                MessageValue::Number(value as f64)
+            }
+        })*
+    };
+}
+
+impl_number_value!(i32, i64, u32, u64, usize, f32, f64);
+
+impl From<&str> for MessageValue {
+    fn from(value: &str) -> Self {
+        MessageValue::Text(value.to_string())
+    }
+}
+
+impl From<String> for MessageValue {
+    fn from(value: String) -> Self {
+        MessageValue::Text(value)
+    }
+}
+
+/// Named arguments of a message
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MessageArgs {
+    values: HashMap<String, MessageValue>,
+}
+
+impl MessageArgs {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, name: &str, value: impl Into<MessageValue>) -> Self {
+        self.set(name, value);
+        self
+    }
+
+    pub fn set(&mut self, name: &str, value: impl Into<MessageValue>) {
+        self.values.insert(name.to_string(), value.into());
+    }
+
+    pub fn get(&self, name: &str) -> Option<&MessageValue> {
+        self.values.get(name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum PluralSelector {
+    Exact(f64),
+    Category(PluralCategory),
+}
+
+type PluralBranch = (PluralSelector, Message);
+type SelectBranch = (String, Message);
+type SelectBranches = Vec<SelectBranch>;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Part {
+    Text(String),
+    /// `#` inside a plural branch
+    Count,
+    Argument(String),
+    Plural {
+        name: String,
+        branches: Vec<PluralBranch>,
+    },
+    Select {
+        name: String,
+        branches: Vec<SelectBranch>,
+    },
+}
+
+/// A parsed ICU-style message
+///
+/// Supports plain text, `{name}` interpolation, `{name, number}`,
+/// `{count, plural, =0 {…} one {# item} other {# items}}` and
+/// `{name, select, a {…} other {…}}`. Text inside single quotes is literal
+/// (`'{'`), and `''` is an apostrophe.
+///
+/// # Example
+///
+/// ```rust
+/// use radix_leptos_primitives::*;
+///
+/// let message = Message::parse("{count, plural, =0 {No files} one {# file} other {# files}}").unwrap();
+/// let args = MessageArgs::new().with("count", 3);
+/// assert_eq!(message.format("en", &args).unwrap(), "3 files");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Message {
+    parts: Vec<Part>,
+}
+
+impl Message {
+    pub fn parse(pattern: &str) -> Result<Self, MessageError> {
+        let chars: Vec<char> = pattern.chars().collect();
+        let mut parser = Parser { chars, position: 0 };
+        let message = parser.message(false, false)?;
+        match parser.peek() {
+            None => Ok(message),
+            Some(found) => Err(MessageError::UnexpectedChar {
+                found,
+                position: parser.position,
+            }),
+        }
+    }
+
+    /// Names of all arguments referenced by the message
+    pub fn argument_names(&self) -> Vec<String> {
+        let mut names = Vec::new();
+        self.collect_names(&mut names);
+        names
+    }
+
+    fn collect_names(&self, names: &mut Vec<String>) {
+        for part in &self.parts {
+            let (name, branches) = match part {
+                Part::Text(_) | Part::Count => continue,
+                Part::Argument(name) => (name, Vec::new()),
+                Part::Plural { name, branches } => {
+                    (name, branches.iter().map(|(_, m)| m).collect::<Vec<_>>())
+                }
+                Part::Select { name, branches } => {
+                    (name, branches.iter().map(|(_, m)| m).collect::<Vec<_>>())
+                }
+            };
+            if !names.contains(name) {
+                names.push(name.clone());
+            }
+            for message in branches {
+                message.collect_names(names);
+            }
+        }
+    }
+
+    pub fn format(&self, locale: &str, args: &MessageArgs) -> Result<String, MessageError> {
+        let mut output = String::new();
+        self.write(locale, args, None, &mut output)?;
+        Ok(output)
+    }
+
+    fn write(
+        &self,
+        locale: &str,
+        args: &MessageArgs,
+        count: Option<f64>,
+        output: &mut String,
+    ) -> Result<(), MessageError> {
+        for part in &self.parts {
+            match part {
+                Part::Text(text) => output.push_str(text),
+                Part::Count => match count {
+                    Some(count) => output.push_str(&format_number(count)),
+                    None => output.push('#'),
+                },
+                Part::Argument(name) => {
+                    let value = args
+                        .get(name)
+                        .ok_or_else(|| MessageError::MissingArgument(name.clone()))?;
+                    output.push_str(&value.to_text());
+                }
+                Part::Plural { name, branches } => {
+                    let n = match args.get(name) {
+                        Some(MessageValue::Number(n)) => *n,
+                        Some(MessageValue::Text(_)) => {
+                            return Err(MessageError::NotANumber(name.clone()))
+                        }
+                        None => return Err(MessageError::MissingArgument(name.clone())),
+                    };
+                    let category = plural_category(locale, n);
+                    let branch = branches
+                        .iter()
+                        .find(|(selector, _)| *selector == PluralSelector::Exact(n))
+                        .or_else(|| {
+                            branches.iter().find(|(selector, _)| {
+                                *selector == PluralSelector::Category(category)
+                            })
+                        })
+                        .or_else(|| {
+                            branches.iter().find(|(selector, _)| {
+                                *selector == PluralSelector::Category(PluralCategory::Other)
+                            })
+                        })
+                        .map(|(_, message)| message)
+                        .ok_or_else(|| MessageError::MissingOther(name.clone()))?;
+                    branch.write(locale, args, Some(n), output)?;
+                }
+                Part::Select { name, branches } => {
+                    let value = args
+                        .get(name)
+                        .map(MessageValue::to_text)
+                        .ok_or_else(|| MessageError::MissingArgument(name.clone()))?;
+                    let branch = branches
+                        .iter()
+                        .find(|(key, _)| *key == value)
+                        .or_else(|| branches.iter().find(|(key, _)| key == "other"))
+                        .map(|(_, message)| message)
+                        .ok_or_else(|| MessageError::MissingOther(name.clone()))?;
+                    branch.write(locale, args, count, output)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parse and format a message in one step
+pub fn format_message(
+    pattern: &str,
+    locale: &str,
+    args: &MessageArgs,
+) -> Result<String, MessageError> {
+    Message::parse(pattern)?.format(locale, args)
+}
+
+/// Integers without a fractional part, everything else as-is
+fn format_number(n: f64) -> String {
+    if n.fract() == 0.0 && n.abs() < 1e15 {
+        format!("{}", n as i64)
+    } else {
+        n.to_string()
+    }
+}
+
+struct Parser {
+    chars: Vec<char>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.position += 1;
+        Some(c)
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), MessageError> {
+        match self.next() {
+            Some(c) if c == expected => Ok(()),
+            Some(found) => Err(MessageError::UnexpectedChar {
+                found,
+                position: self.position - 1,
+            }),
+            None => Err(MessageError::UnexpectedEnd { expected }),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.position += 1;
+        }
+    }
+
+    fn identifier(&mut self) -> String {
+        self.skip_whitespace();
+        let mut ident = String::new();
+        while let Some(c) = self.peek() {
+            if c.is_whitespace() || matches!(c, ',' | '{' | '}') {
+                break;
+            }
+            ident.push(c);
+            self.position += 1;
+        }
+        ident
+    }
+
+    /// Parse text and arguments up to (not including) a closing `}` or the end
+    fn message(&mut self, nested: bool, in_plural: bool) -> Result<Message, MessageError> {
+        let mut parts = Vec::new();
+        let mut text = String::new();
+
+        while let Some(c) = self.peek() {
+            match c {
+                '}' if nested => break,
+                '}' => {
+                    return Err(MessageError::UnexpectedChar {
+                        found: c,
+                        position: self.position,
+                    })
+                }
+                '{' => {
+                    if !text.is_empty() {
+                        parts.push(Part::Text(std::mem::take(&mut text)));
+                    }
+                    self.position += 1;
+                    parts.push(self.argument(in_plural)?);
+                }
+                '#' if in_plural => {
+                    if !text.is_empty() {
+                        parts.push(Part::Text(std::mem::take(&mut text)));
+                    }
+                    self.position += 1;
+                    parts.push(Part::Count);
+                }
+                '\'' => {
+                    self.position += 1;
+                    self.quoted(&mut text);
+                }
+                _ => {
+                    text.push(c);
+                    self.position += 1;
+                }
+            }
+        }
+
+        if !text.is_empty() {
+            parts.push(Part::Text(text));
+        }
+        Ok(Message { parts })
+    }
+
+    /// Apostrophe handling after the opening `'`
+    fn quoted(&mut self, text: &mut String) {
+        match self.peek() {
+            Some('\'') => {
+                self.position += 1;
+                text.push('\'');
+            }
+            Some('{' | '}' | '#') => {
+                while let Some(c) = self.next() {
+                    if c == '\'' {
+                        if self.peek() == Some('\'') {
+                            self.position += 1;
+                            text.push('\'');
+                            continue;
+                        }
+                        return;
+                    }
+                    text.push(c);
+                }
+            }
+            _ => text.push('\''),
+        }
+    }
+
+    /// Parse an argument after its opening `{`, consuming the closing `}`
+    fn argument(&mut self, in_plural: bool) -> Result<Part, MessageError> {
+        let name = self.identifier();
+        if name.is_empty() {
+            return Err(match self.peek() {
+                Some(found) => MessageError::UnexpectedChar {
+                    found,
+                    position: self.position,
+                },
+                None => MessageError::UnexpectedEnd { expected: '}' },
+            });
+        }
+        self.skip_whitespace();
+        if self.peek() == Some('}') {
+            self.position += 1;
+            return Ok(Part::Argument(name));
+        }
+
+        self.expect(',')?;
+        let kind = self.identifier();
+        self.skip_whitespace();
+        let part = match kind.as_str() {
+            "number" => {
+                // An optional style such as `integer` is accepted and ignored
+                if self.peek() == Some(',') {
+                    self.position += 1;
+                    self.identifier();
+                    self.skip_whitespace();
+                }
+                Part::Argument(name)
+            }
+            "plural" => {
+                self.expect(',')?;
+                let branches = self.branches(true)?;
+                let branches = branches
+                    .into_iter()
+                    .map(|(key, message)| {
+                        let selector = match key.strip_prefix('=') {
+                            Some(exact) => exact.parse().ok().map(PluralSelector::Exact),
+                            None => {
+                                PluralCategory::from_keyword(&key).map(PluralSelector::Category)
+                            }
+                        };
+                        selector
+                            .map(|selector| (selector, message))
+                            .ok_or(MessageError::UnknownType(key))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                if !branches.iter().any(|(selector, _)| {
+                    *selector == PluralSelector::Category(PluralCategory::Other)
+                }) {
+                    return Err(MessageError::MissingOther(name));
+                }
+                Part::Plural { name, branches }
+            }
+            "select" => {
+                self.expect(',')?;
+                let branches = self.branches(in_plural)?;
+                if !branches.iter().any(|(key, _)| key == "other") {
+                    return Err(MessageError::MissingOther(name));
+                }
+                Part::Select { name, branches }
+            }
+            _ => return Err(MessageError::UnknownType(kind)),
+        };
+        self.skip_whitespace();
+        self.expect('}')?;
+        Ok(part)
+    }
+
+    /// `key {message} key {message} ...` up to the closing `}` of the argument
+    fn branches(&mut self, in_plural: bool) -> Result<SelectBranches, MessageError> {
+        let mut branches = Vec::new();
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some('}') | None => break,
+                _ => {}
+            }
+            let key = self.identifier();
+            self.skip_whitespace();
+            self.expect('{')?;
+            let message = self.message(true, in_plural)?;
+            self.expect('}')?;
+            branches.push((key, message));
+        }
+        Ok(branches)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn format(pattern: &str, args: &MessageArgs) -> String {
+        format_message(pattern, "en", args).unwrap()
+    }
+
+    #[test]
+    fn test_plain_text_and_interpolation() {
+        let args = MessageArgs::new()
+            .with("name", "report.pdf")
+            .with("size", 12);
+        assert_eq!(format("Hello", &args), "Hello");
+        assert_eq!(format("{name} ({size} KB)", &args), "report.pdf (12 KB)");
+        assert_eq!(format("{ size , number }", &args), "12");
+        assert_eq!(format("{size, number, integer} KB", &args), "12 KB");
+    }
+
+    #[test]
+    fn test_plural() {
+        let pattern = "{count, plural, =0 {No items} one {# item} other {# items}}";
+        let message = Message::parse(pattern).unwrap();
+        let format = |n: f64| {
+            message
+                .format("en", &MessageArgs::new().with("count", n))
+                .unwrap()
+        };
+        assert_eq!(format(0.0), "No items");
+        assert_eq!(format(1.0), "1 item");
+        assert_eq!(format(42.0), "42 items");
+        assert_eq!(format(2.5), "2.5 items");
+    }
+
+    #[test]
+    fn test_plural_uses_locale_rules() {
+        let pattern = "{n, plural, one {# файл} few {# файла} many {# файлов} other {# файла}}";
+        let args = |n: i32| MessageArgs::new().with("n", n);
+        assert_eq!(format_message(pattern, "ru", &args(1)).unwrap(), "1 файл");
+        assert_eq!(format_message(pattern, "ru", &args(3)).unwrap(), "3 файла");
+        assert_eq!(format_message(pattern, "ru", &args(5)).unwrap(), "5 файлов");
+    }
+
+    #[test]
+    fn test_select_with_nested_plural() {
+        let pattern = "{status, select, uploading {{percent}% of {files, plural, one {# file} other {# files}}} other {Done}}";
+        let args = MessageArgs::new()
+            .with("status", "uploading")
+            .with("percent", 40)
+            .with("files", 3);
+        assert_eq!(format(pattern, &args), "40% of 3 files");
+        assert_eq!(
+            format(pattern, &MessageArgs::new().with("status", "completed")),
+            "Done"
+        );
+    }
+
+    #[test]
+    fn test_quoting() {
+        let args = MessageArgs::new().with("n", 2);
+        assert_eq!(format("It''s '{literal}' {n}", &args), "It's {literal} 2");
+        assert_eq!(format("{n, plural, other {'#' is #}}", &args), "# is 2");
+        assert_eq!(format("don't", &args), "don't");
+    }
+
+    #[test]
+    fn test_argument_names() {
+        let message = Message::parse(
+            "{start}–{end} of {total, plural, one {# item} other {# items from {source}}}",
+        )
+        .unwrap();
+        assert_eq!(
+            message.argument_names(),
+            vec!["start", "end", "total", "source"]
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            Message::parse("{count, plural, one {# item}}"),
+            Err(MessageError::MissingOther("count".to_string()))
+        );
+        assert_eq!(
+            Message::parse("{count, date}"),
+            Err(MessageError::UnknownType("date".to_string()))
+        );
+        assert_eq!(
+            Message::parse("{name"),
+            Err(MessageError::UnexpectedEnd { expected: ',' })
+        );
+        assert!(matches!(
+            Message::parse("oops }"),
+            Err(MessageError::UnexpectedChar { found: '}', .. })
+        ));
+        assert_eq!(
+            format_message("{name}", "en", &MessageArgs::new()),
+            Err(MessageError::MissingArgument("name".to_string()))
+        );
+        assert_eq!(
+            format_message(
+                "{n, plural, other {#}}",
+                "en",
+                &MessageArgs::new().with("n", "many")
+            ),
+            Err(MessageError::NotANumber("n".to_string()))
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_text_without_syntax_is_unchanged(text in "[^{}#']*") {
+            prop_assert_eq!(format(&text, &MessageArgs::new()), text);
+        }
+
+        #[test]
+        fn test_parse_never_panics(pattern in ".*") {
+            let _ = Message::parse(&pattern);
+        }
+    }
+}
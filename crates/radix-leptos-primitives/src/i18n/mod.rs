@@ -0,0 +1,16 @@
+//! Localized component messages
+//!
+//! ICU-style message formatting (interpolation, `plural` and `select`) with
+//! CLDR plural rules, and a catalog of the messages components render, keyed
+//! by [`MessageKey`]. The labels and announcements listed in [`MessageKey`]
+//! go through the catalog, so [`LocaleProvider`] can translate them from a
+//! string table; other components still render English defaults, which
+//! their `label` and `aria_label` props override.
+
+pub mod catalog;
+pub mod message;
+pub mod plural;
+
+pub use catalog::*;
+pub use message::*;
+pub use plural::*;
@@ -0,0 +1,175 @@
+/// CLDR plural category of a number
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl PluralCategory {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PluralCategory::Zero => "zero",
+            PluralCategory::One => "one",
+            PluralCategory::Two => "two",
+            PluralCategory::Few => "few",
+            PluralCategory::Many => "many",
+            PluralCategory::Other => "other",
+        }
+    }
+
+    pub fn from_keyword(keyword: &str) -> Option<Self> {
+        match keyword {
+            "zero" => Some(PluralCategory::Zero),
+            "one" => Some(PluralCategory::One),
+            "two" => Some(PluralCategory::Two),
+            "few" => Some(PluralCategory::Few),
+            "many" => Some(PluralCategory::Many),
+            "other" => Some(PluralCategory::Other),
+            _ => None,
+        }
+    }
+}
+
+/// Language subtag of a BCP 47 locale (`"pt-BR"` -> `"pt"`)
+pub fn language_of(locale: &str) -> String {
+    locale
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase()
+}
+
+/// Cardinal plural category of `n` in `locale`
+///
+/// Covers the CLDR rules of the most common languages; unknown languages use
+/// the English rule.
+pub fn plural_category(locale: &str, n: f64) -> PluralCategory {
+    let n = n.abs();
+    let is_integer = n.fract() == 0.0;
+    // Integer digits; plural rules only look at the last two
+    let i = (n.trunc() % 1000.0) as u64;
+    let (mod10, mod100) = (i % 10, i % 100);
+
+    match language_of(locale).as_str() {
+        "ja" | "zh" | "ko" | "vi" | "th" | "id" | "ms" | "tr" => PluralCategory::Other,
+        "fr" => {
+            if i <= 1 {
+                PluralCategory::One
+            } else {
+                PluralCategory::Other
+            }
+        }
+        "ru" | "uk" | "be" => {
+            if !is_integer {
+                PluralCategory::Other
+            } else if mod10 == 1 && mod100 != 11 {
+                PluralCategory::One
+            } else if (2..=4).contains(&mod10) && !(12..=14).contains(&mod100) {
+                PluralCategory::Few
+            } else {
+                PluralCategory::Many
+            }
+        }
+        "pl" => {
+            if !is_integer {
+                PluralCategory::Other
+            } else if i == 1 {
+                PluralCategory::One
+            } else if (2..=4).contains(&mod10) && !(12..=14).contains(&mod100) {
+                PluralCategory::Few
+            } else {
+                PluralCategory::Many
+            }
+        }
+        "cs" | "sk" => {
+            if !is_integer {
+                PluralCategory::Many
+            } else if i == 1 {
+                PluralCategory::One
+            } else if (2..=4).contains(&i) {
+                PluralCategory::Few
+            } else {
+                PluralCategory::Other
+            }
+        }
+        "ar" => {
+            if !is_integer {
+                PluralCategory::Other
+            } else if i == 0 {
+                PluralCategory::Zero
+            } else if i == 1 {
+                PluralCategory::One
+            } else if i == 2 {
+                PluralCategory::Two
+            } else if (3..=10).contains(&mod100) {
+                PluralCategory::Few
+            } else if (11..=99).contains(&mod100) {
+                PluralCategory::Many
+            } else {
+                PluralCategory::Other
+            }
+        }
+        _ => {
+            if is_integer && i == 1 && n == 1.0 {
+                PluralCategory::One
+            } else {
+                PluralCategory::Other
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_english_plurals() {
+        assert_eq!(plural_category("en", 0.0), PluralCategory::Other);
+        assert_eq!(plural_category("en-US", 1.0), PluralCategory::One);
+        assert_eq!(plural_category("en", 2.0), PluralCategory::Other);
+        assert_eq!(plural_category("en", 1.5), PluralCategory::Other);
+        assert_eq!(plural_category("en", 1001.0), PluralCategory::Other);
+    }
+
+    #[test]
+    fn test_french_plurals() {
+        assert_eq!(plural_category("fr", 0.0), PluralCategory::One);
+        assert_eq!(plural_category("fr-CA", 1.5), PluralCategory::One);
+        assert_eq!(plural_category("fr", 2.0), PluralCategory::Other);
+    }
+
+    #[test]
+    fn test_slavic_plurals() {
+        assert_eq!(plural_category("ru", 1.0), PluralCategory::One);
+        assert_eq!(plural_category("ru", 21.0), PluralCategory::One);
+        assert_eq!(plural_category("ru", 11.0), PluralCategory::Many);
+        assert_eq!(plural_category("ru", 3.0), PluralCategory::Few);
+        assert_eq!(plural_category("ru", 13.0), PluralCategory::Many);
+        assert_eq!(plural_category("pl", 22.0), PluralCategory::Few);
+        assert_eq!(plural_category("pl", 21.0), PluralCategory::Many);
+        assert_eq!(plural_category("cs", 3.0), PluralCategory::Few);
+        assert_eq!(plural_category("cs", 5.0), PluralCategory::Other);
+    }
+
+    #[test]
+    fn test_arabic_and_cjk_plurals() {
+        assert_eq!(plural_category("ar", 0.0), PluralCategory::Zero);
+        assert_eq!(plural_category("ar", 2.0), PluralCategory::Two);
+        assert_eq!(plural_category("ar", 105.0), PluralCategory::Few);
+        assert_eq!(plural_category("ar", 111.0), PluralCategory::Many);
+        assert_eq!(plural_category("ar", 100.0), PluralCategory::Other);
+        assert_eq!(plural_category("ja", 1.0), PluralCategory::Other);
+    }
+
+    #[test]
+    fn test_language_of() {
+        assert_eq!(language_of("pt-BR"), "pt");
+        assert_eq!(language_of("zh_Hant"), "zh");
+        assert_eq!(language_of("EN"), "en");
+    }
+}
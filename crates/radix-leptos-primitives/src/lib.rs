@@ -4,10 +4,15 @@
 //! These components provide the building blocks for accessible UI libraries.
 
 pub mod components;
+pub mod conformance;
+pub mod i18n;
 pub mod theming;
 pub mod utils;
 pub mod performance;
+#[cfg(test)]
+mod test_support;
 
 // Re-export all components at the crate root
 pub use components::*;
+pub use i18n::*;
 pub use theming::*;
@@ -0,0 +1,17 @@
+//! Helpers shared by the crate's unit tests
+
+/// Components whose hooks create effects need an executor to render;
+/// like on a server, the effects themselves never run
+struct ServerExecutor;
+
+impl any_spawner::CustomExecutor for ServerExecutor {
+    fn spawn(&self, _: any_spawner::PinnedFuture<()>) {}
+    fn spawn_local(&self, _: any_spawner::PinnedLocalFuture<()>) {}
+    fn poll_local(&self) {}
+}
+
+/// Set up the executor tests need to render components to HTML
+pub(crate) fn init_test_executor() {
+    // Already set by an earlier test
+    let _ = any_spawner::Executor::init_custom_executor(ServerExecutor);
+}
@@ -0,0 +1,298 @@
+//! Color palettes for data visualization
+//!
+//! Categorical colors tell series apart, sequential scales encode magnitude
+//! and diverging scales encode distance from a midpoint. The defaults are
+//! derived from the theme, so charts follow custom brand themes.
+
+use super::css_variables::CSSVariables;
+use super::palette::{generate_palette, Color, Oklch};
+use super::theme_provider::use_theme;
+use leptos::prelude::*;
+
+/// Okabe and Ito's palette, told apart under every common color vision
+/// deficiency
+pub const OKABE_ITO: [&str; 8] = [
+    "#e69f00", "#56b4e9", "#009e73", "#f0e442", "#0072b2", "#d55e00", "#cc79a7", "#000000",
+];
+
+/// Paul Tol's bright palette, also colorblind safe
+pub const TOL_BRIGHT: [&str; 7] = [
+    "#4477aa", "#66ccee", "#228833", "#ccbb44", "#ee6677", "#aa3377", "#bbbbbb",
+];
+
+/// Colors for the series, scales and ranges of a chart
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChartPalette {
+    /// Series colors, reused in order when a chart has more series
+    pub categorical: Vec<String>,
+    /// Stops from the lowest to the highest value
+    pub sequential: Vec<String>,
+    /// Stops from the most negative value through the midpoint to the most
+    /// positive one
+    pub diverging: Vec<String>,
+}
+
+impl Default for ChartPalette {
+    fn default() -> Self {
+        Self::from_theme(&CSSVariables::default())
+    }
+}
+
+impl ChartPalette {
+    /// The palette for `theme`
+    ///
+    /// Series use [`OKABE_ITO`], with its black replaced by the theme's text
+    /// color so it stays visible on dark backgrounds. The sequential scale
+    /// runs from the empty-cell neutral through the primary shades, and the
+    /// diverging scale pairs the primary color with its complement.
+    pub fn from_theme(theme: &CSSVariables) -> Self {
+        let mut categorical: Vec<String> =
+            OKABE_ITO.iter().map(|color| color.to_string()).collect();
+        if let Some(last) = categorical.last_mut() {
+            *last = theme.neutral.neutral_900.clone();
+        }
+
+        let sequential = vec![
+            theme.neutral.neutral_100.clone(),
+            theme.primary.primary_200.clone(),
+            theme.primary.primary_400.clone(),
+            theme.primary.primary_600.clone(),
+            theme.primary.primary_800.clone(),
+        ];
+
+        let complement = Color::from_hex(&theme.primary.primary_500).map(|primary| {
+            let primary = primary.to_oklch();
+            generate_palette(Color::from_oklch(Oklch {
+                h: (primary.h + 180.0) % 360.0,
+                ..primary
+            }))
+        });
+        let negative = |step: u16| {
+            complement
+                .as_ref()
+                .and_then(|scale| scale.shade(step))
+                .map_or_else(|| theme.semantic.error.clone(), |color| color.to_hex())
+        };
+        let diverging = vec![
+            negative(700),
+            negative(400),
+            theme.neutral.neutral_100.clone(),
+            theme.primary.primary_400.clone(),
+            theme.primary.primary_700.clone(),
+        ];
+
+        Self {
+            categorical,
+            sequential,
+            diverging,
+        }
+    }
+
+    /// Use other series colors, e.g. [`TOL_BRIGHT`]
+    pub fn with_categorical<S: Into<String>>(
+        mut self,
+        colors: impl IntoIterator<Item = S>,
+    ) -> Self {
+        self.categorical = colors.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Color of the series at `index`
+    pub fn series_color(&self, index: usize) -> &str {
+        match self.categorical.len() {
+            0 => "currentColor",
+            len => &self.categorical[index % len],
+        }
+    }
+
+    /// Colors of a chart's series, in order: a series' own color, or else
+    /// the categorical color at its index
+    pub fn series_colors<'a>(
+        &self,
+        explicit: impl IntoIterator<Item = Option<&'a str>>,
+    ) -> Vec<String> {
+        explicit
+            .into_iter()
+            .enumerate()
+            .map(|(index, color)| color.unwrap_or(self.series_color(index)).to_string())
+            .collect()
+    }
+
+    /// Color at `t` between 0 and 1 on the sequential scale
+    pub fn sequential_color(&self, t: f64) -> String {
+        interpolate(&self.sequential, t)
+    }
+
+    /// Color at `t` between -1 and 1 on the diverging scale, 0 being the
+    /// midpoint
+    pub fn diverging_color(&self, t: f64) -> String {
+        interpolate(&self.diverging, (t + 1.0) / 2.0)
+    }
+
+    /// The series colors as `--chart-1`, `--chart-2`, … declarations
+    pub fn to_css_declarations(&self) -> String {
+        self.categorical
+            .iter()
+            .enumerate()
+            .map(|(index, color)| format!("--chart-{}: {};", index + 1, color))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// Resolved series colors as `--chart-series-1`, `--chart-series-2`, …
+/// declarations, for a chart's root element
+pub fn series_color_declarations(colors: &[String]) -> String {
+    colors
+        .iter()
+        .enumerate()
+        .map(|(index, color)| format!("--chart-series-{}: {};", index + 1, color))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// The color at `t` along `stops`, blended in OKLCH so lightness changes
+/// evenly; stops that aren't hex colors are picked without blending
+fn interpolate(stops: &[String], t: f64) -> String {
+    let Some(last) = stops.len().checked_sub(1) else {
+        return String::new();
+    };
+    let position = t.clamp(0.0, 1.0) * last as f64;
+    let index = (position.floor() as usize).min(last.saturating_sub(1));
+    let amount = position - index as f64;
+    if amount < f64::EPSILON {
+        return stops[index].clone();
+    }
+    if amount > 1.0 - f64::EPSILON {
+        return stops[index + 1].clone();
+    }
+    let (Some(from), Some(to)) = (
+        Color::from_hex(&stops[index]),
+        stops.get(index + 1).and_then(|stop| Color::from_hex(stop)),
+    ) else {
+        return stops[position.round() as usize].clone();
+    };
+    let (from, to) = (from.to_oklch(), to.to_oklch());
+    // Grays have no meaningful hue, so take the other stop's
+    let from_h = if from.c < 0.01 { to.h } else { from.h };
+    let to_h = if to.c < 0.01 { from_h } else { to.h };
+    let mut hue_delta = to_h - from_h;
+    if hue_delta > 180.0 {
+        hue_delta -= 360.0;
+    } else if hue_delta < -180.0 {
+        hue_delta += 360.0;
+    }
+    Color::from_oklch(Oklch {
+        l: from.l + (to.l - from.l) * amount,
+        c: from.c + (to.c - from.c) * amount,
+        h: (from_h + hue_delta * amount).rem_euclid(360.0),
+    })
+    .to_hex()
+}
+
+/// Chart palette shared with everything below a ChartPaletteProvider
+#[derive(Clone, Copy)]
+pub struct ChartPaletteContext {
+    pub palette: Signal<ChartPalette>,
+}
+
+/// Chart palette provider component
+///
+/// Charts below it use `palette` instead of the one derived from the theme,
+/// and the series colors are set as `--chart-1`, `--chart-2`, … for
+/// stylesheets.
+#[component]
+pub fn ChartPaletteProvider(
+    /// Palette for the charts
+    #[prop(optional, into)]
+    palette: Signal<ChartPalette>,
+    /// Children content
+    children: Option<Children>,
+) -> impl IntoView {
+    provide_context(ChartPaletteContext { palette });
+
+    view! {
+        <div
+            class="chart-palette-provider"
+            style=move || format!("display: contents; {}", palette.with(ChartPalette::to_css_declarations))
+        >
+            {children.map(|c| c())}
+        </div>
+    }
+}
+
+/// Hook for the palette a chart draws with: the closest
+/// ChartPaletteProvider's, or else the one derived from the active theme
+pub fn use_chart_palette() -> Signal<ChartPalette> {
+    let context = use_context::<ChartPaletteContext>();
+    let theme = use_theme();
+    Signal::derive(move || match (context, theme.as_ref()) {
+        (Some(context), _) => context.palette.get(),
+        (None, Some(theme)) => theme.theme.with(ChartPalette::from_theme),
+        (None, None) => ChartPalette::default(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chart_palette_from_theme() {
+        let theme = CSSVariables::dark_theme();
+        let palette = ChartPalette::from_theme(&theme);
+
+        assert_eq!(palette.categorical.len(), OKABE_ITO.len());
+        assert_eq!(palette.categorical[0], OKABE_ITO[0]);
+        assert_eq!(palette.series_color(7), theme.neutral.neutral_900);
+        assert_eq!(palette.series_color(8), OKABE_ITO[0]);
+        assert_eq!(palette.sequential[4], theme.primary.primary_800);
+        assert_eq!(palette.diverging[2], theme.neutral.neutral_100);
+
+        // The negative side has the complementary hue
+        let negative = Color::from_hex(&palette.diverging[0]).unwrap().to_oklch();
+        let positive = Color::from_hex(&theme.primary.primary_500)
+            .unwrap()
+            .to_oklch();
+        let hue_distance = (negative.h - positive.h).abs();
+        assert!((hue_distance - 180.0).abs() < 20.0, "{hue_distance}");
+
+        let palette = palette.with_categorical(TOL_BRIGHT);
+        assert_eq!(palette.series_color(1), TOL_BRIGHT[1]);
+        assert!(palette
+            .to_css_declarations()
+            .starts_with("--chart-1: #4477aa;"));
+    }
+
+    #[test]
+    fn test_chart_palette_interpolation() {
+        let palette = ChartPalette::default();
+        assert_eq!(palette.sequential_color(0.0), palette.sequential[0]);
+        assert_eq!(palette.sequential_color(1.0), palette.sequential[4]);
+        assert_eq!(palette.sequential_color(0.5), palette.sequential[2]);
+        assert_eq!(palette.diverging_color(0.0), palette.diverging[2]);
+        assert_eq!(palette.diverging_color(-2.0), palette.diverging[0]);
+
+        // Lightness falls steadily along the scale
+        let lightness = |t: f64| {
+            Color::from_hex(&palette.sequential_color(t))
+                .unwrap()
+                .to_oklch()
+                .l
+        };
+        assert!(lightness(0.1) > lightness(0.3));
+        assert!(lightness(0.3) > lightness(0.6));
+        assert!(lightness(0.6) > lightness(0.9));
+    }
+
+    #[test]
+    fn test_series_colors() {
+        let palette = ChartPalette::default().with_categorical(["#111111", "#222222"]);
+        let colors = palette.series_colors([None, Some("#ff0000"), None]);
+        assert_eq!(colors, ["#111111", "#ff0000", "#111111"]);
+        assert_eq!(
+            series_color_declarations(&colors[..2]),
+            "--chart-series-1: #111111; --chart-series-2: #ff0000;"
+        );
+    }
+}
@@ -0,0 +1,335 @@
+use std::collections::BTreeMap;
+
+use leptos::children::Children;
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::button_variants::ButtonVariants;
+use super::input_variants::{InputVariants, SizeVariant, StateVariant, StyleVariant};
+
+/// Overrides for every component in a subtree, by component name
+type ComponentThemes = BTreeMap<String, ComponentThemeOverrides>;
+
+/// Every style variant, for telling style variables from shared ones
+const STYLE_VARIANTS: [StyleVariant; 11] = [
+    StyleVariant::Default,
+    StyleVariant::Primary,
+    StyleVariant::Secondary,
+    StyleVariant::Outline,
+    StyleVariant::Ghost,
+    StyleVariant::Destructive,
+    StyleVariant::Filled,
+    StyleVariant::Outlined,
+    StyleVariant::Success,
+    StyleVariant::Warning,
+    StyleVariant::Error,
+];
+
+/// Local re-theming of one component
+///
+/// Variables are set on the subtree as `--{component}-{name}`, so a button
+/// override named `primary-background` becomes `--button-primary-background`.
+/// The component applies a variable named `{style}-{property}` as its CSS
+/// `property` while rendered in that style, and one without a style prefix
+/// in every style. Variant lists replace the ones the component offers when
+/// set.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ComponentThemeOverrides {
+    pub variables: BTreeMap<String, String>,
+    pub sizes: Option<Vec<SizeVariant>>,
+    pub styles: Option<Vec<StyleVariant>>,
+    pub states: Option<Vec<StateVariant>>,
+}
+
+impl ComponentThemeOverrides {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_variable(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.variables.insert(name.into(), value.into());
+        self
+    }
+
+    /// Set a variable for one style variant, e.g. `primary-background`
+    pub fn with_style_variable(
+        self,
+        style: StyleVariant,
+        name: &str,
+        value: impl Into<String>,
+    ) -> Self {
+        self.with_variable(format!("{}-{}", style.as_str(), name), value)
+    }
+
+    pub fn with_sizes(mut self, sizes: Vec<SizeVariant>) -> Self {
+        self.sizes = Some(sizes);
+        self
+    }
+
+    pub fn with_styles(mut self, styles: Vec<StyleVariant>) -> Self {
+        self.styles = Some(styles);
+        self
+    }
+
+    pub fn with_states(mut self, states: Vec<StateVariant>) -> Self {
+        self.states = Some(states);
+        self
+    }
+
+    /// `other` layered on top of these overrides
+    pub fn merge(&self, other: &Self) -> Self {
+        let mut variables = self.variables.clone();
+        variables.extend(other.variables.clone());
+        Self {
+            variables,
+            sizes: other.sizes.clone().or_else(|| self.sizes.clone()),
+            styles: other.styles.clone().or_else(|| self.styles.clone()),
+            states: other.states.clone().or_else(|| self.states.clone()),
+        }
+    }
+
+    /// The variables as inline CSS declarations for `component`
+    pub fn to_css_declarations(&self, component: &str) -> String {
+        self.variables
+            .iter()
+            .map(|(name, value)| format!("--{}-{}: {};", component, name, value))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Inline declarations applying the variables to `component` rendered
+    /// in `style`, each reading the variable the ComponentTheme sets
+    pub fn to_style_declarations(&self, component: &str, style: Option<StyleVariant>) -> String {
+        let style_prefix = style.map(|style| format!("{}-", style.as_str()));
+        let shared = self.variables.keys().filter(|name| {
+            !STYLE_VARIANTS
+                .iter()
+                .any(|style| name.starts_with(&format!("{}-", style.as_str())))
+        });
+        let styled = self.variables.keys().filter(|name| {
+            style_prefix
+                .as_deref()
+                .is_some_and(|prefix| name.starts_with(prefix))
+        });
+        // Style variables come last so they win over shared ones
+        shared
+            .map(|name| (name.as_str(), name))
+            .chain(styled.map(|name| {
+                let property = style_prefix
+                    .as_deref()
+                    .and_then(|prefix| name.strip_prefix(prefix))
+                    .unwrap_or(name);
+                (property, name)
+            }))
+            .map(|(property, name)| format!("{}: var(--{}-{});", property, component, name))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// The `style` attribute of `component` rendered in `style_variant`:
+    /// the style declarations followed by the component's own `style`
+    pub fn inline_style(
+        &self,
+        component: &str,
+        style_variant: Option<StyleVariant>,
+        style: Option<&str>,
+    ) -> Option<String> {
+        let declarations = self.to_style_declarations(component, style_variant);
+        let style = [declarations.as_str(), style.unwrap_or("")]
+            .into_iter()
+            .filter(|style| !style.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ");
+        (!style.is_empty()).then_some(style)
+    }
+}
+
+/// `variant` if `offered` lists it, otherwise the first variant offered
+pub fn offered_variant<T: Copy + PartialEq>(variant: T, offered: &[T]) -> T {
+    match offered.first() {
+        Some(first) if !offered.contains(&variant) => *first,
+        _ => variant,
+    }
+}
+
+impl ButtonVariants {
+    /// These variants with the lists `overrides` replaces
+    pub fn with_overrides(&self, overrides: &ComponentThemeOverrides) -> Self {
+        Self {
+            sizes: overrides
+                .sizes
+                .clone()
+                .unwrap_or_else(|| self.sizes.clone()),
+            styles: overrides
+                .styles
+                .clone()
+                .unwrap_or_else(|| self.styles.clone()),
+            states: overrides
+                .states
+                .clone()
+                .unwrap_or_else(|| self.states.clone()),
+        }
+    }
+}
+
+impl InputVariants {
+    /// These variants with the lists `overrides` replaces
+    pub fn with_overrides(&self, overrides: &ComponentThemeOverrides) -> Self {
+        Self {
+            sizes: overrides
+                .sizes
+                .clone()
+                .unwrap_or_else(|| self.sizes.clone()),
+            styles: overrides
+                .styles
+                .clone()
+                .unwrap_or_else(|| self.styles.clone()),
+            states: overrides
+                .states
+                .clone()
+                .unwrap_or_else(|| self.states.clone()),
+            types: self.types.clone(),
+        }
+    }
+}
+
+/// Component theme context, holding the overrides of every enclosing
+/// ComponentTheme
+#[derive(Debug, Clone, Copy)]
+pub struct ComponentThemeContext {
+    themes: Signal<ComponentThemes>,
+}
+
+/// Hook for the overrides that apply to `component` here
+pub fn use_component_theme(component: &'static str) -> Signal<ComponentThemeOverrides> {
+    let context = use_context::<ComponentThemeContext>();
+    Signal::derive(move || {
+        context
+            .and_then(|context| context.themes.get().get(component).cloned())
+            .unwrap_or_default()
+    })
+}
+
+/// Re-themes one component for a subtree, leaving the global theme alone
+///
+/// Nested ComponentThemes for the same component merge, the inner one
+/// winning for the variables and variant lists it sets.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::*;
+/// use radix_leptos_primitives::theming::{ComponentTheme, ComponentThemeOverrides, StyleVariant};
+///
+/// #[component]
+/// fn DangerZone() -> impl IntoView {
+///     let overrides = ComponentThemeOverrides::new()
+///         .with_style_variable(StyleVariant::Destructive, "background", "#b91c1c");
+///
+///     view! {
+///         <ComponentTheme component="button" overrides=overrides>
+///             <Button variant=ButtonVariant::Destructive>"Delete account"</Button>
+///         </ComponentTheme>
+///     }
+/// }
+/// ```
+#[component]
+pub fn ComponentTheme(
+    /// Component to re-theme, e.g. "button" or "input"
+    #[prop(into)]
+    component: String,
+    /// Overrides for the component
+    #[prop(into)]
+    overrides: Signal<ComponentThemeOverrides>,
+    /// Child content
+    children: Children,
+) -> impl IntoView {
+    let parent = use_context::<ComponentThemeContext>();
+    let name = component.clone();
+    let themes = Memo::new(move |_| {
+        let mut themes = parent.map(|parent| parent.themes.get()).unwrap_or_default();
+        let merged = themes
+            .get(&name)
+            .map_or_else(|| overrides.get(), |outer| outer.merge(&overrides.get()));
+        themes.insert(name.clone(), merged);
+        themes
+    });
+    provide_context(ComponentThemeContext {
+        themes: themes.into(),
+    });
+
+    let style = {
+        let component = component.clone();
+        move || {
+            let declarations = overrides.get().to_css_declarations(&component);
+            format!("display: contents; {}", declarations)
+        }
+    };
+
+    view! {
+        <div class="component-theme" data-component=component style=style>
+            {children()}
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod component_theme_tests {
+    use super::*;
+
+    #[test]
+    fn test_component_theme_overrides_merge() {
+        let outer = ComponentThemeOverrides::new()
+            .with_variable("radius", "4px")
+            .with_style_variable(StyleVariant::Primary, "background", "#3b82f6")
+            .with_sizes(vec![SizeVariant::Small]);
+        let inner = ComponentThemeOverrides::new().with_style_variable(
+            StyleVariant::Primary,
+            "background",
+            "#dc2626",
+        );
+
+        let merged = outer.merge(&inner);
+        assert_eq!(merged.variables["primary-background"], "#dc2626");
+        assert_eq!(merged.variables["radius"], "4px");
+        assert_eq!(merged.sizes, Some(vec![SizeVariant::Small]));
+        assert_eq!(
+            merged.to_css_declarations("button"),
+            "--button-primary-background: #dc2626; --button-radius: 4px;"
+        );
+    }
+
+    #[test]
+    fn test_style_declarations() {
+        let overrides = ComponentThemeOverrides::new()
+            .with_variable("border-radius", "0")
+            .with_style_variable(StyleVariant::Primary, "background", "#dc2626")
+            .with_style_variable(StyleVariant::Ghost, "color", "#dc2626");
+        assert_eq!(
+            overrides.to_style_declarations("button", Some(StyleVariant::Primary)),
+            "border-radius: var(--button-border-radius); background: var(--button-primary-background);"
+        );
+        assert_eq!(
+            overrides.to_style_declarations("button", None),
+            "border-radius: var(--button-border-radius);"
+        );
+
+        let offered = [StyleVariant::Outline, StyleVariant::Ghost];
+        assert_eq!(offered_variant(StyleVariant::Ghost, &offered), StyleVariant::Ghost);
+        assert_eq!(offered_variant(StyleVariant::Primary, &offered), StyleVariant::Outline);
+    }
+
+    #[test]
+    fn test_variants_with_overrides() {
+        let overrides = ComponentThemeOverrides::new().with_styles(vec![StyleVariant::Primary]);
+
+        let buttons = ButtonVariants::default().with_overrides(&overrides);
+        assert_eq!(buttons.styles, vec![StyleVariant::Primary]);
+        assert_eq!(buttons.sizes, ButtonVariants::default().sizes);
+
+        let inputs = InputVariants::default().with_overrides(&overrides);
+        assert_eq!(inputs.styles, vec![StyleVariant::Primary]);
+        assert_eq!(inputs.types, InputVariants::default().types);
+    }
+}
@@ -9,6 +9,7 @@ mod input_variants;
 mod layout_variants;
 mod feedback_variants;
 mod data_variants;
+mod component_theme;
 
 // Re-export all types and functions from sub-modules
 pub use button_variants::*;
@@ -16,6 +17,7 @@ pub use input_variants::*;
 pub use layout_variants::*;
 pub use feedback_variants::*;
 pub use data_variants::*;
+pub use component_theme::*;
 
 /// Component variant system for consistent styling
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
@@ -134,7 +134,11 @@ pub struct SpacingVariables {
 }
 
 /// Border variables
+///
+/// Also holds the focus ring drawn around focused controls. Themes stored
+/// before the focus ring variables existed load with their defaults.
 #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 pub struct BorderVariables {
     pub border_width_0: String,
     pub border_width_1: String,
@@ -150,6 +154,9 @@ pub struct BorderVariables {
     pub border_radius_2xl: String,
     pub border_radius_3xl: String,
     pub border_radius_full: String,
+    pub focus_ring_width: String,
+    pub focus_ring_offset: String,
+    pub focus_ring_color: String,
 }
 
 /// Shadow variables
@@ -323,6 +330,9 @@ impl Default for BorderVariables {
             border_radius_2xl: "1rem".to_string(),
             border_radius_3xl: "1.5rem".to_string(),
             border_radius_full: "9999px".to_string(),
+            focus_ring_width: "2px".to_string(),
+            focus_ring_offset: "2px".to_string(),
+            focus_ring_color: "#3b82f6".to_string(),
         }
     }
 }
@@ -686,6 +696,18 @@ impl CSSVariables {
             "--border-radius-full: {};",
             self.border.border_radius_full
         ));
+        css.push_str(&format!(
+            "--focus-ring-width: {};",
+            self.border.focus_ring_width
+        ));
+        css.push_str(&format!(
+            "--focus-ring-offset: {};",
+            self.border.focus_ring_offset
+        ));
+        css.push_str(&format!(
+            "--focus-ring-color: {};",
+            self.border.focus_ring_color
+        ));
 
         // Shadow
         css.push_str(&format!("--shadow-sm: {};", self.shadow.shadow_sm));
@@ -716,6 +738,16 @@ impl CSSVariables {
 
         css
     }
+
+    /// Call `f` with each variable's name, without the leading `--`, and value
+    pub fn for_each_variable(&self, mut f: impl FnMut(&str, &str)) {
+        for declaration in self.to_css_string().split(';') {
+            let variable = declaration.trim().strip_prefix("--");
+            if let Some((name, value)) = variable.and_then(|variable| variable.split_once(':')) {
+                f(name.trim(), value.trim());
+            }
+        }
+    }
 }
 
 #[cfg(test)]
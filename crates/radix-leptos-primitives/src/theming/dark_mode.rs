@@ -3,6 +3,7 @@ use leptos::callback::Callback;
 use leptos::children::Children;
 use leptos::context::{provide_context, use_context};
 use leptos::prelude::*;
+use radix_leptos_core::{use_local_storage, use_media_query};
 
 /// Dark mode toggle component
 #[component]
@@ -78,7 +79,38 @@ pub fn DarkModeToggle(
     );
 }
 
+/// How DarkModeProvider decides between light and dark
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DarkModeStrategy {
+    /// Dark only when chosen, starting from `defaultdark`
+    Manual,
+    /// Follow the `prefers-color-scheme` media query until the user picks a
+    /// mode themselves
+    #[default]
+    System,
+}
+
+impl DarkModeStrategy {
+    /// Whether dark mode is on, where `preference` is a manual choice that
+    /// overrides everything else
+    pub fn resolve(
+        &self,
+        system_prefers_dark: bool,
+        preference: Option<bool>,
+        default: bool,
+    ) -> bool {
+        preference.unwrap_or(match self {
+            DarkModeStrategy::Manual => default,
+            DarkModeStrategy::System => system_prefers_dark,
+        })
+    }
+}
+
 /// Dark mode provider component
+///
+/// Applies the light or dark CSSVariables to its children. With
+/// [`DarkModeStrategy::System`] the mode follows the operating system until
+/// it is toggled; `clear_override` on the context goes back to following it.
 #[component]
 pub fn DarkModeProvider(
     /// Whether dark mode is enabled by default
@@ -87,6 +119,9 @@ pub fn DarkModeProvider(
     /// Whether to use system preference
     #[prop(optional)]
     use_system: Option<bool>,
+    /// How the mode is decided; overrides `use_system`
+    #[prop(optional)]
+    strategy: Option<DarkModeStrategy>,
     /// Whether to persist dark mode preference
     #[prop(optional)]
     persist: Option<bool>,
@@ -98,86 +133,58 @@ pub fn DarkModeProvider(
 ) -> impl IntoView {
     let defaultdark = defaultdark.unwrap_or(false);
     let use_system = use_system.unwrap_or(true);
+    let strategy = strategy.unwrap_or(if use_system {
+        DarkModeStrategy::System
+    } else {
+        DarkModeStrategy::Manual
+    });
     let persist = persist.unwrap_or(true);
     let storage_key = storage_key.unwrap_or_else(|| "dark-mode".to_string());
 
     let (isdark, set_isdark) = signal(defaultdark);
     let (system_preference, set_system_preference) = signal(false);
+    let prefersdark = use_media_query("(prefers-color-scheme: dark)");
 
-    // Load saved preference
-    let load_saved_preference = move || {
-        if persist {
-            // In a real implementation, this would load from localStorage
-            // For now, we'll simulate it
-            let saved = false; // This would be loaded from storage
-            if saved {
-                set_isdark.set(true);
-            }
-        }
-    };
-
-    // Save preference
-    let save_preference = move |dark: bool| {
-        if persist {
-            // In a real implementation, this would save to localStorage
-            // For now, we'll just store the state
-        }
+    // The user's own choice, kept across visits when persisting
+    let preference = if persist {
+        use_local_storage(storage_key, None::<bool>).value
+    } else {
+        RwSignal::new(None)
     };
 
-    // Detect system preference
-    let detect_system_preference = move || {
-        // In a real implementation, this would check the system preference
-        // For now, we'll simulate it
-        let prefersdark = false; // This would be detected from the system
-        set_system_preference.set(prefersdark);
-        if use_system {
-            set_isdark.set(prefersdark);
-        }
-    };
+    Effect::new(move |_| {
+        let system = prefersdark.get();
+        set_system_preference.set(system);
+        set_isdark.set(strategy.resolve(system, preference.get(), defaultdark));
+    });
 
-    // Apply dark mode
-    let applydark_mode_styles = move |dark: bool| {
-        let theme = if dark {
+    let css_variables = Memo::new(move |_| {
+        if isdark.get() {
             CSSVariables::dark_theme()
         } else {
             CSSVariables::light_theme()
-        };
-
-        let css_vars = theme.to_css_string();
-        // In a real implementation, this would apply the CSS to the document
-        // For now, we'll just store the state
-
-        save_preference(dark);
-    };
-
-    // Toggle dark mode
-    let toggledark_mode = move |_: ()| {
-        let newdark = !isdark.get();
-        set_isdark.set(newdark);
-        applydark_mode_styles(newdark);
-    };
-
-    // Set dark mode
-    let setdark_mode = move |dark: bool| {
-        set_isdark.set(dark);
-        applydark_mode_styles(dark);
-    };
-
-    // Initialize
-    Effect::new(move |_| {
-        load_saved_preference();
-        detect_system_preference();
+        }
     });
 
-    // Provide dark mode context
     provide_context(DarkModeContext {
         isdark,
         system_preference,
-        toggledark_mode: Callback::new(move |_| toggledark_mode(())),
-        setdark_mode: Callback::new(setdark_mode),
+        toggledark_mode: Callback::new(move |_| preference.set(Some(!isdark.get_untracked()))),
+        setdark_mode: Callback::new(move |dark| preference.set(Some(dark))),
+        clear_override: Callback::new(move |_| preference.set(None)),
+        strategy,
+        css_variables: css_variables.into(),
     });
 
-    let class = "dark-mode-provider".to_string();
+    view! {
+        <div
+            class="dark-mode-provider"
+            data-theme=move || if isdark.get() { "dark" } else { "light" }
+            style=move || css_variables.with(CSSVariables::to_css_string)
+        >
+            {children.map(|c| c())}
+        </div>
+    }
 }
 
 /// Dark mode context
@@ -187,6 +194,11 @@ pub struct DarkModeContext {
     pub system_preference: ReadSignal<bool>,
     pub toggledark_mode: Callback<()>,
     pub setdark_mode: Callback<bool>,
+    /// Forget the manual choice and follow the strategy again
+    pub clear_override: Callback<()>,
+    pub strategy: DarkModeStrategy,
+    /// Theme variables for the current mode
+    pub css_variables: Signal<CSSVariables>,
 }
 
 /// Hook for accessing dark mode context
@@ -451,4 +463,16 @@ mod tests {
         assert!(enabled);
         // Test completed
     }
+
+    #[test]
+    fn testdark_mode_strategy_resolve() {
+        use super::DarkModeStrategy;
+
+        assert!(DarkModeStrategy::System.resolve(true, None, false));
+        assert!(!DarkModeStrategy::Manual.resolve(true, None, false));
+        assert!(DarkModeStrategy::Manual.resolve(false, None, true));
+        // A manual choice wins over the system preference
+        assert!(!DarkModeStrategy::System.resolve(true, Some(false), false));
+        assert!(DarkModeStrategy::Manual.resolve(false, Some(true), false));
+    }
 }
@@ -0,0 +1,378 @@
+//! Import and export of themes in the W3C Design Tokens format
+//!
+//! Every CSS variable becomes a token in a group named after the
+//! [`CSSVariables`] field it lives in, e.g. `primary.500`,
+//! `typography.font-size-base` or `spacing.4`.
+
+use super::css_variables::CSSVariables;
+use leptos::serde_json::{self, Map, Value};
+
+/// A token group and the prefix its field names share
+struct TokenGroup {
+    name: &'static str,
+    prefix: &'static str,
+}
+
+const TOKEN_GROUPS: &[TokenGroup] = &[
+    TokenGroup {
+        name: "primary",
+        prefix: "primary_",
+    },
+    TokenGroup {
+        name: "secondary",
+        prefix: "secondary_",
+    },
+    TokenGroup {
+        name: "neutral",
+        prefix: "neutral_",
+    },
+    TokenGroup {
+        name: "semantic",
+        prefix: "",
+    },
+    TokenGroup {
+        name: "typography",
+        prefix: "",
+    },
+    TokenGroup {
+        name: "spacing",
+        prefix: "space_",
+    },
+    TokenGroup {
+        name: "border",
+        prefix: "border_",
+    },
+    TokenGroup {
+        name: "shadow",
+        prefix: "shadow_",
+    },
+    TokenGroup {
+        name: "animation",
+        prefix: "",
+    },
+];
+
+/// Alias chains longer than this are treated as cycles
+const MAX_ALIAS_DEPTH: usize = 8;
+
+/// Problems found while importing design tokens
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum DesignTokenError {
+    #[error("`{path}` is not a token or group")]
+    NotAnObject { path: String },
+    #[error("`{path}` has no `$value`")]
+    MissingValue { path: String },
+    #[error("`{path}` should have type `{expected}`, found `{found}`")]
+    TypeMismatch {
+        path: String,
+        expected: &'static str,
+        found: String,
+    },
+    #[error("`{path}` has a value that cannot be used as a CSS value")]
+    UnsupportedValue { path: String },
+    #[error("`{path}` is not a hex color: `{value}`")]
+    InvalidColor { path: String, value: String },
+    #[error("`{path}` refers to `{alias}`, which does not resolve to a token")]
+    UnresolvedAlias { path: String, alias: String },
+}
+
+/// Result of importing design tokens, with every problem found
+pub type DesignTokensResult<T> = Result<T, Vec<DesignTokenError>>;
+
+impl CSSVariables {
+    /// Export the theme as a W3C design tokens document
+    pub fn to_design_tokens(&self) -> Value {
+        let fields = serde_json::to_value(self).unwrap_or_default();
+        let mut document = Map::new();
+        for &TokenGroup {
+            name: group,
+            prefix,
+        } in TOKEN_GROUPS
+        {
+            let Some(values) = fields.get(group).and_then(Value::as_object) else {
+                continue;
+            };
+            let tokens = values
+                .iter()
+                .map(|(field, value)| {
+                    let token_type = token_type(group, field);
+                    let mut token = Map::new();
+                    token.insert("$value".to_string(), export_value(value, token_type));
+                    if let Some(token_type) = token_type {
+                        token.insert("$type".to_string(), token_type.into());
+                    }
+                    (token_name(field, prefix), Value::Object(token))
+                })
+                .collect();
+            document.insert(group.to_string(), Value::Object(tokens));
+        }
+        Value::Object(document)
+    }
+
+    /// Import a theme from a W3C design tokens document
+    ///
+    /// Tokens missing from the document keep their default value and tokens
+    /// the theme has no variable for are ignored, so partial documents and
+    /// exports from design tools with extra tokens both work. Aliases such
+    /// as `{primary.500}` are resolved. Every problem found is reported with
+    /// the path of the offending token.
+    pub fn from_design_tokens(document: &Value) -> DesignTokensResult<Self> {
+        if !document.is_object() {
+            return Err(vec![DesignTokenError::NotAnObject {
+                path: String::new(),
+            }]);
+        }
+        let mut fields = serde_json::to_value(Self::default()).unwrap_or_default();
+        let mut errors = Vec::new();
+
+        for &TokenGroup {
+            name: group,
+            prefix,
+        } in TOKEN_GROUPS
+        {
+            let Some(tokens) = document.get(group) else {
+                continue;
+            };
+            if !tokens.is_object() {
+                errors.push(DesignTokenError::NotAnObject {
+                    path: group.to_string(),
+                });
+                continue;
+            }
+            let Some(values) = fields.get_mut(group).and_then(Value::as_object_mut) else {
+                continue;
+            };
+            for (field, value) in values.iter_mut() {
+                let name = token_name(field, prefix);
+                let Some(token) = tokens.get(&name) else {
+                    continue;
+                };
+                let path = format!("{group}.{name}");
+                match import_token(document, &path, token, token_type(group, field)) {
+                    Ok(css) => *value = Value::String(css),
+                    Err(error) => errors.push(error),
+                }
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+        // Only strings were written over the serialized defaults
+        Ok(serde_json::from_value(fields).unwrap_or_default())
+    }
+}
+
+fn token_name(field: &str, prefix: &str) -> String {
+    field
+        .strip_prefix(prefix)
+        .unwrap_or(field)
+        .replace('_', "-")
+}
+
+/// The token type of a variable, `None` for CSS values such as shadows and
+/// easings that don't match the format of their token type
+fn token_type(group: &str, field: &str) -> Option<&'static str> {
+    match group {
+        "primary" | "secondary" | "neutral" | "semantic" => Some("color"),
+        "spacing" | "border" => Some("dimension"),
+        "typography" if field.starts_with("font_family") => Some("fontFamily"),
+        "typography" if field.starts_with("font_size") => Some("dimension"),
+        "typography" if field.starts_with("font_weight") => Some("fontWeight"),
+        "typography" if field.starts_with("line_height") => Some("number"),
+        "animation" if field.starts_with("duration") => Some("duration"),
+        _ => None,
+    }
+}
+
+/// Numeric token types are exported as numbers where the CSS value is one
+fn export_value(value: &Value, token_type: Option<&str>) -> Value {
+    match (value.as_str(), token_type) {
+        (Some(css), Some("fontWeight" | "number")) => {
+            if let Ok(integer) = css.parse::<i64>() {
+                integer.into()
+            } else {
+                css.parse::<f64>()
+                    .ok()
+                    .and_then(serde_json::Number::from_f64)
+                    .map_or_else(|| value.clone(), Value::Number)
+            }
+        }
+        _ => value.clone(),
+    }
+}
+
+fn import_token(
+    document: &Value,
+    path: &str,
+    token: &Value,
+    expected: Option<&'static str>,
+) -> Result<String, DesignTokenError> {
+    let Some(token) = token.as_object() else {
+        return Err(DesignTokenError::NotAnObject {
+            path: path.to_string(),
+        });
+    };
+    let value = token
+        .get("$value")
+        .ok_or_else(|| DesignTokenError::MissingValue {
+            path: path.to_string(),
+        })?;
+    if let (Some(expected), Some(found)) = (expected, token.get("$type").and_then(Value::as_str)) {
+        if expected != found {
+            return Err(DesignTokenError::TypeMismatch {
+                path: path.to_string(),
+                expected,
+                found: found.to_string(),
+            });
+        }
+    }
+
+    let value = resolve_alias(document, path, value)?;
+    let css = css_value(value).ok_or_else(|| DesignTokenError::UnsupportedValue {
+        path: path.to_string(),
+    })?;
+    if expected == Some("color") && !is_hex_color(&css) {
+        return Err(DesignTokenError::InvalidColor {
+            path: path.to_string(),
+            value: css,
+        });
+    }
+    Ok(css)
+}
+
+/// Follow `{group.token}` references to the value they point at
+fn resolve_alias<'a>(
+    document: &'a Value,
+    path: &str,
+    mut value: &'a Value,
+) -> Result<&'a Value, DesignTokenError> {
+    for _ in 0..MAX_ALIAS_DEPTH {
+        let Some(alias) = value
+            .as_str()
+            .and_then(|value| value.strip_prefix('{'))
+            .and_then(|value| value.strip_suffix('}'))
+        else {
+            return Ok(value);
+        };
+        value = alias
+            .split('.')
+            .try_fold(document, |node, segment| node.get(segment))
+            .and_then(|token| token.get("$value"))
+            .ok_or_else(|| DesignTokenError::UnresolvedAlias {
+                path: path.to_string(),
+                alias: alias.to_string(),
+            })?;
+    }
+    Err(DesignTokenError::UnresolvedAlias {
+        path: path.to_string(),
+        alias: value.as_str().unwrap_or_default().to_string(),
+    })
+}
+
+/// A token value as CSS, covering the forms design tools export
+fn css_value(value: &Value) -> Option<String> {
+    match value {
+        Value::String(css) => Some(css.clone()),
+        Value::Number(number) => Some(number.to_string()),
+        // Font family stacks
+        Value::Array(items) if items.iter().all(Value::is_string) => Some(
+            items
+                .iter()
+                .filter_map(Value::as_str)
+                .collect::<Vec<_>>()
+                .join(", "),
+        ),
+        // Cubic bézier control points
+        Value::Array(items) if items.len() == 4 && items.iter().all(Value::is_number) => {
+            let points: Vec<String> = items.iter().map(ToString::to_string).collect();
+            Some(format!("cubic-bezier({})", points.join(", ")))
+        }
+        // Dimensions and durations as `{ "value": 4, "unit": "px" }`
+        Value::Object(dimension) => {
+            let amount = dimension.get("value")?.as_f64()?;
+            let unit = dimension.get("unit")?.as_str()?;
+            Some(format!("{amount}{unit}"))
+        }
+        _ => None,
+    }
+}
+
+fn is_hex_color(value: &str) -> bool {
+    value.strip_prefix('#').is_some_and(|hex| {
+        matches!(hex.len(), 3 | 4 | 6 | 8) && hex.chars().all(|c| c.is_ascii_hexdigit())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptos::serde_json::json;
+
+    #[test]
+    fn test_design_tokens_round_trip() {
+        let theme = CSSVariables::dark_theme();
+        let tokens = theme.to_design_tokens();
+        assert_eq!(
+            tokens["primary"]["500"],
+            json!({ "$value": "#3b82f6", "$type": "color" })
+        );
+        assert_eq!(
+            tokens["typography"]["font-weight-normal"]["$value"],
+            json!(400)
+        );
+        assert_eq!(CSSVariables::from_design_tokens(&tokens), Ok(theme));
+    }
+
+    #[test]
+    fn test_design_tokens_import_formats() {
+        let theme = CSSVariables::from_design_tokens(&json!({
+            "primary": { "500": { "$value": "#ff0000", "$type": "color" } },
+            "semantic": { "info": { "$value": "{primary.500}" } },
+            "spacing": { "4": { "$value": { "value": 16, "unit": "px" } } },
+            "animation": { "ease-in": { "$value": [0.4, 0, 1, 1] } },
+            "unknown": { "token": { "$value": 1 } }
+        }))
+        .unwrap();
+        assert_eq!(theme.primary.primary_500, "#ff0000");
+        assert_eq!(theme.semantic.info, "#ff0000");
+        assert_eq!(theme.spacing.space_4, "16px");
+        assert_eq!(theme.animation.ease_in, "cubic-bezier(0.4, 0, 1, 1)");
+        assert_eq!(
+            theme.primary.primary_50,
+            CSSVariables::default().primary.primary_50
+        );
+    }
+
+    #[test]
+    fn test_design_tokens_validation_errors() {
+        let errors = CSSVariables::from_design_tokens(&json!({
+            "primary": { "500": { "$value": "red" } },
+            "neutral": { "50": { "$value": "#000", "$type": "dimension" } },
+            "semantic": { "error": { "$value": "{danger.base}" } },
+            "spacing": { "4": "1rem" }
+        }))
+        .unwrap_err();
+        assert_eq!(
+            errors,
+            vec![
+                DesignTokenError::InvalidColor {
+                    path: "primary.500".to_string(),
+                    value: "red".to_string(),
+                },
+                DesignTokenError::TypeMismatch {
+                    path: "neutral.50".to_string(),
+                    expected: "color",
+                    found: "dimension".to_string(),
+                },
+                DesignTokenError::UnresolvedAlias {
+                    path: "semantic.error".to_string(),
+                    alias: "danger.base".to_string(),
+                },
+                DesignTokenError::NotAnObject {
+                    path: "spacing.4".to_string(),
+                },
+            ]
+        );
+    }
+}
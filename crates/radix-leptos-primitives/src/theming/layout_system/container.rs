@@ -38,6 +38,14 @@ pub enum ContainerMaxWidth {
 }
 
 impl ContainerMaxWidth {
+    pub const ALL: [ContainerMaxWidth; 5] = [
+        ContainerMaxWidth::Small,
+        ContainerMaxWidth::Medium,
+        ContainerMaxWidth::Large,
+        ContainerMaxWidth::ExtraLarge,
+        ContainerMaxWidth::Fluid,
+    ];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             ContainerMaxWidth::Small => "sm",
@@ -101,6 +109,16 @@ pub enum GridAlignment {
 }
 
 impl GridAlignment {
+    pub const ALL: [GridAlignment; 7] = [
+        GridAlignment::Start,
+        GridAlignment::Center,
+        GridAlignment::End,
+        GridAlignment::Stretch,
+        GridAlignment::SpaceBetween,
+        GridAlignment::SpaceAround,
+        GridAlignment::SpaceEvenly,
+    ];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             GridAlignment::Start => "start",
@@ -169,6 +187,13 @@ pub enum FlexDirection {
 }
 
 impl FlexDirection {
+    pub const ALL: [FlexDirection; 4] = [
+        FlexDirection::Row,
+        FlexDirection::RowReverse,
+        FlexDirection::Column,
+        FlexDirection::ColumnReverse,
+    ];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             FlexDirection::Row => "row",
@@ -188,6 +213,12 @@ pub enum FlexWrap {
 }
 
 impl FlexWrap {
+    pub const ALL: [FlexWrap; 3] = [
+        FlexWrap::Nowrap,
+        FlexWrap::Wrap,
+        FlexWrap::WrapReverse,
+    ];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             FlexWrap::Nowrap => "nowrap",
@@ -209,6 +240,15 @@ pub enum JustifyContent {
 }
 
 impl JustifyContent {
+    pub const ALL: [JustifyContent; 6] = [
+        JustifyContent::Start,
+        JustifyContent::End,
+        JustifyContent::Center,
+        JustifyContent::SpaceBetween,
+        JustifyContent::SpaceAround,
+        JustifyContent::SpaceEvenly,
+    ];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             JustifyContent::Start => "start",
@@ -232,6 +272,14 @@ pub enum AlignItems {
 }
 
 impl AlignItems {
+    pub const ALL: [AlignItems; 5] = [
+        AlignItems::Start,
+        AlignItems::End,
+        AlignItems::Center,
+        AlignItems::Baseline,
+        AlignItems::Stretch,
+    ];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             AlignItems::Start => "start",
@@ -1,3 +1,4 @@
+use crate::theming::EditHistory;
 use crate::utils::merge_classes;
 use leptos::callback::Callback;
 use leptos::prelude::*;
@@ -24,11 +25,17 @@ pub struct LayoutSystem {
 }
 
 /// Layout builder component
+///
+/// Every control edits the typed fields of the layout, and edits can be
+/// undone and redone.
 #[component]
 pub fn LayoutBuilder(
     #[prop(optional)] class: Option<String>,
     #[prop(optional)] style: Option<String>,
     #[prop(optional)] layout_type: Option<String>,
+    /// Layout to start from, the default one without it
+    #[prop(optional)]
+    initial_layout: Option<LayoutSystem>,
     #[prop(optional)] on_layout_change: Option<Callback<LayoutSystem>>,
 ) -> impl IntoView {
     let layout_type = layout_type.unwrap_or_else(|| "grid".to_string());
@@ -43,11 +50,24 @@ pub fn LayoutBuilder(
         .to_vec(),
     );
 
-    let (layout, set_layout) = signal(LayoutSystem::default());
-
-    let handle_layout_change = move |new_layout: LayoutSystem| {
-        set_layout.set(new_layout.clone());
-        on_layout_change.run(new_layout);
+    let history = RwSignal::new(EditHistory::new(initial_layout.unwrap_or_default()));
+    let layout = Memo::new(move |_| history.with(|history| history.present().clone()));
+
+    let handle_layout_change = Callback::new(move |new_layout: LayoutSystem| {
+        let edited = history
+            .try_update(|history| history.edit(new_layout.clone()))
+            .unwrap_or(false);
+        if edited {
+            on_layout_change.run(new_layout);
+        }
+    });
+    let step = move |undo: bool| {
+        let moved = history
+            .try_update(|history| if undo { history.undo() } else { history.redo() })
+            .unwrap_or(false);
+        if moved {
+            on_layout_change.run(layout.get_untracked());
+        }
     };
 
     view! {
@@ -60,58 +80,72 @@ pub fn LayoutBuilder(
             <div class="layout-builder-header">
                 <h3>"Layout System"</h3>
                 <p>"Configure spacing, breakpoints, and layout utilities"</p>
+                <button
+                    class="undo-button"
+                    disabled=move || !history.with(EditHistory::can_undo)
+                    on:click=move |_| step(true)
+                >
+                    "Undo"
+                </button>
+                <button
+                    class="redo-button"
+                    disabled=move || !history.with(EditHistory::can_redo)
+                    on:click=move |_| step(false)
+                >
+                    "Redo"
+                </button>
             </div>
 
             <div class="layout-sections">
                 <SpacingLayoutSection
                     title="Spacing System".to_string()
                     layout_type="spacing".to_string()
-                    layout=layout.get().spacing
+                    layout=Memo::new(move |_| layout.get().spacing)
                     on_change=Callback::new(move |spacing| {
-                        let mut new_layout = layout.get();
+                        let mut new_layout = layout.get_untracked();
                         new_layout.spacing = spacing;
-                        handle_layout_change(new_layout);
+                        handle_layout_change.run(new_layout);
                     })
                 />
 
                 <BreakpointLayoutSection
                     title="Breakpoint System".to_string()
                     layout_type="breakpoints".to_string()
-                    layout=layout.get().breakpoints
+                    layout=Memo::new(move |_| layout.get().breakpoints)
                     on_change=Callback::new(move |breakpoints| {
-                        let mut new_layout = layout.get();
+                        let mut new_layout = layout.get_untracked();
                         new_layout.breakpoints = breakpoints;
-                        handle_layout_change(new_layout);
+                        handle_layout_change.run(new_layout);
                     })
                 />
 
                 <GridLayoutSection
                     title="Grid System".to_string()
-                    grid=layout.get().grid
+                    grid=Memo::new(move |_| layout.get().grid)
                     on_change=Callback::new(move |grid| {
-                        let mut new_layout = layout.get();
+                        let mut new_layout = layout.get_untracked();
                         new_layout.grid = grid;
-                        handle_layout_change(new_layout);
+                        handle_layout_change.run(new_layout);
                     })
                 />
 
                 <FlexboxLayoutSection
                     title="Flexbox System".to_string()
-                    flexbox=layout.get().flexbox
+                    flexbox=Memo::new(move |_| layout.get().flexbox)
                     on_change=Callback::new(move |flexbox| {
-                        let mut new_layout = layout.get();
+                        let mut new_layout = layout.get_untracked();
                         new_layout.flexbox = flexbox;
-                        handle_layout_change(new_layout);
+                        handle_layout_change.run(new_layout);
                     })
                 />
 
                 <ContainerLayoutSection
                     title="Container System".to_string()
-                    containers=layout.get().containers
+                    containers=Memo::new(move |_| layout.get().containers)
                     on_change=Callback::new(move |containers| {
-                        let mut new_layout = layout.get();
+                        let mut new_layout = layout.get_untracked();
                         new_layout.containers = containers;
-                        handle_layout_change(new_layout);
+                        handle_layout_change.run(new_layout);
                     })
                 />
             </div>
@@ -119,6 +153,27 @@ pub fn LayoutBuilder(
     }
 }
 
+/// Change a control makes to a layout system with its new value
+type LayoutEdit<T, V> = fn(&mut T, V);
+
+/// A callback that applies `edit` to the current value of `system` and
+/// passes the result to `on_change`
+fn edit_callback<T, V>(
+    system: Signal<T>,
+    on_change: Callback<T>,
+    edit: LayoutEdit<T, V>,
+) -> Callback<V>
+where
+    T: Clone + Send + Sync + 'static,
+    V: 'static,
+{
+    Callback::new(move |value| {
+        let mut new_system = system.get_untracked();
+        edit(&mut new_system, value);
+        on_change.run(new_system);
+    })
+}
+
 /// Spacing layout section component
 #[component]
 pub fn SpacingLayoutSection(
@@ -126,14 +181,12 @@ pub fn SpacingLayoutSection(
     #[prop(optional)] style: Option<String>,
     #[prop(optional)] title: Option<String>,
     #[prop(optional)] layout_type: Option<String>,
-    #[prop(optional)] layout: Option<SpacingSystem>,
+    #[prop(optional, into)] layout: Signal<SpacingSystem>,
     #[prop(optional)] on_change: Option<Callback<SpacingSystem>>,
 ) -> impl IntoView {
     let title = title.unwrap_or_default();
     let layout_type = layout_type.unwrap_or_default();
-    let layout = layout.unwrap_or_default();
     let on_change = on_change.unwrap_or_else(|| Callback::new(|_| {}));
-    let layout_clone = layout.clone();
 
     let class = merge_classes(
         [
@@ -155,21 +208,27 @@ pub fn SpacingLayoutSection(
             <div class="layout-options">
                 <LayoutOptionGroup
                     title="Base Unit".to_string()
-                    value=layout.base_unit
-                    on_change=Callback::new(move |base_unit| {
-                        let mut new_layout = layout.clone();
-                        new_layout.base_unit = base_unit;
-                        on_change.run(new_layout);
+                    value=Signal::derive(move || Some(layout.get().base_unit))
+                    on_change=edit_callback(layout, on_change, |spacing, base_unit| {
+                        spacing.base_unit = base_unit;
                     })
                 />
 
                 <LayoutOptionGroup
                     title="Scale".to_string()
-                    values=layout_clone.scale.clone()
-                    on_values_change=Callback::new(move |scale| {
-                        let mut new_layout = layout_clone.clone();
-                        new_layout.scale = scale;
-                        on_change.run(new_layout);
+                    values=Signal::derive(move || Some(layout.get().scale))
+                    on_values_change=edit_callback(layout, on_change, |spacing, scale| {
+                        spacing.scale = scale;
+                    })
+                />
+
+                <LayoutOptionChoices
+                    title="Directions".to_string()
+                    options=&SpacingDirection::ALL
+                    selected=Signal::derive(move || layout.get().directions)
+                    label=SpacingDirection::as_str
+                    on_change=edit_callback(layout, on_change, |spacing, directions| {
+                        spacing.directions = directions;
                     })
                 />
             </div>
@@ -184,14 +243,12 @@ pub fn BreakpointLayoutSection(
     #[prop(optional)] style: Option<String>,
     #[prop(optional)] title: Option<String>,
     #[prop(optional)] layout_type: Option<String>,
-    #[prop(optional)] layout: Option<BreakpointSystem>,
+    #[prop(optional, into)] layout: Signal<BreakpointSystem>,
     #[prop(optional)] on_change: Option<Callback<BreakpointSystem>>,
 ) -> impl IntoView {
     let title = title.unwrap_or_default();
     let layout_type = layout_type.unwrap_or_default();
-    let layout = layout.unwrap_or_default();
     let on_change = on_change.unwrap_or_else(|| Callback::new(|_| {}));
-    let layout_clone = layout.clone();
 
     let class = merge_classes(
         [
@@ -211,29 +268,44 @@ pub fn BreakpointLayoutSection(
             <h4 class="section-title">{title}</h4>
 
             <div class="layout-options">
-                <div class="breakpoint-info">
-                    <p>"Breakpoints: " {layout.breakpoints.len()}</p>
-                    <p>"Container Max Widths: " {layout.container_max_widths.len()}</p>
-                </div>
+                <LayoutOptionChoices
+                    title="Breakpoints".to_string()
+                    options=&Breakpoint::ALL
+                    selected=Signal::derive(move || layout.get().breakpoints)
+                    label=Breakpoint::as_str
+                    on_change=edit_callback(layout, on_change, |system, breakpoints| {
+                        system.breakpoints = breakpoints;
+                    })
+                />
+
+                <LayoutOptionChoices
+                    title="Container Max Widths".to_string()
+                    options=&ContainerMaxWidth::ALL
+                    selected=Signal::derive(move || layout.get().container_max_widths)
+                    label=ContainerMaxWidth::as_str
+                    on_change=edit_callback(layout, on_change, |system, max_widths| {
+                        system.container_max_widths = max_widths;
+                    })
+                />
             </div>
         </div>
     }
 }
 
 /// Layout option group component
+///
+/// Shows a number input for `value` and one per entry of `values`.
 #[component]
 pub fn LayoutOptionGroup(
     #[prop(optional)] class: Option<String>,
     #[prop(optional)] style: Option<String>,
     #[prop(optional)] title: Option<String>,
-    #[prop(optional)] value: Option<f64>,
-    #[prop(optional)] values: Option<Vec<f64>>,
+    #[prop(optional, into)] value: MaybeProp<f64>,
+    #[prop(optional, into)] values: MaybeProp<Vec<f64>>,
     #[prop(optional)] on_change: Option<Callback<f64>>,
     #[prop(optional)] on_values_change: Option<Callback<Vec<f64>>>,
 ) -> impl IntoView {
-    let title = title.unwrap_or_default();
-    let value = value.unwrap_or(0.0);
-    let _values = values.clone().unwrap_or_default();
+    let title = StoredValue::new(title.unwrap_or_default());
     let on_change = on_change.unwrap_or_else(|| Callback::new(|_| {}));
     let on_values_change = on_values_change.unwrap_or_else(|| Callback::new(|_| {}));
 
@@ -244,47 +316,114 @@ pub fn LayoutOptionGroup(
             class=class
             style=style
         >
-            <h5 class="option-group-title">{title}</h5>
+            <h5 class="option-group-title">{title.get_value()}</h5>
             <div class="option-list">
-                {if let Some(values) = values {
-                    if !values.is_empty() {
-                        view! {
-                            <div class="values-list">
-                                {values.into_iter().map(|val| {
-                                    view! {
-                                        <div class="layout-value" data-value=val.to_string()>
-                                            <span class="value-number">{val}</span>
-                                        </div>
+                {move || value.get().map(|current| view! {
+                    <input
+                        type="number"
+                        class="layout-value-input"
+                        aria-label=title.get_value()
+                        prop:value=current.to_string()
+                        on:change=move |ev| {
+                            if let Ok(value) = event_target_value(&ev).parse::<f64>() {
+                                on_change.run(value);
+                            }
+                        }
+                    />
+                })}
+                {move || values.get().map(|list| view! {
+                    <div class="values-list">
+                        {list.into_iter().enumerate().map(|(index, current)| view! {
+                            <input
+                                type="number"
+                                class="layout-value"
+                                data-value=current.to_string()
+                                aria-label=format!("{} {}", title.get_value(), index + 1)
+                                prop:value=current.to_string()
+                                on:change=move |ev| {
+                                    let Ok(value) = event_target_value(&ev).parse::<f64>() else {
+                                        return;
+                                    };
+                                    let mut list = values.get_untracked().unwrap_or_default();
+                                    if let Some(slot) = list.get_mut(index) {
+                                        *slot = value;
+                                        on_values_change.run(list);
                                     }
-                                }).collect::<Vec<_>>()}
-                            </div>
-                        }.into_any()
-                    } else {
-                        view! { <div></div> }.into_any()
-                    }
-                } else {
-                    view! { <div></div> }.into_any()
-                }}
+                                }
+                            />
+                        }).collect_view()}
+                    </div>
+                })}
             </div>
         </div>
     }
 }
 
+/// Name of a [`LayoutOptionChoices`] option
+pub type LayoutOptionLabel<T> = fn(&T) -> &'static str;
+
+/// Called with the options chosen in a [`LayoutOptionChoices`]
+pub type LayoutOptionsHandler<T> = Callback<Vec<T>>;
+
+/// Checkboxes choosing which of `options` a layout system offers
+#[component]
+pub fn LayoutOptionChoices<T>(
+    #[prop(optional)] class: Option<String>,
+    #[prop(optional)] title: Option<String>,
+    /// Every option, in the order they are listed and stored
+    options: &'static [T],
+    /// The options currently chosen
+    #[prop(into)]
+    selected: Signal<Vec<T>>,
+    /// Name of an option
+    label: LayoutOptionLabel<T>,
+    #[prop(optional)] on_change: Option<LayoutOptionsHandler<T>>,
+) -> impl IntoView
+where
+    T: Copy + PartialEq + Send + Sync + 'static,
+{
+    let on_change = on_change.unwrap_or_else(|| Callback::new(|_| {}));
+    let class = merge_classes(["layout-option-choices", class.as_deref().unwrap_or("")].to_vec());
+
+    view! {
+        <fieldset class=class>
+            <legend class="option-group-title">{title.unwrap_or_default()}</legend>
+            {options.iter().map(|&option| view! {
+                <label class="layout-choice" data-value=label(&option)>
+                    <input
+                        type="checkbox"
+                        prop:checked=move || selected.with(|selected| selected.contains(&option))
+                        on:change=move |_| {
+                            on_change.run(toggle_option(options, &selected.get_untracked(), option));
+                        }
+                    />
+                    {label(&option)}
+                </label>
+            }).collect_view()}
+        </fieldset>
+    }
+}
+
+/// `selected` with `option` added or removed, in the order of `options`
+fn toggle_option<T: Copy + PartialEq>(options: &[T], selected: &[T], option: T) -> Vec<T> {
+    options
+        .iter()
+        .copied()
+        .filter(|candidate| selected.contains(candidate) != (*candidate == option))
+        .collect()
+}
+
 /// Grid layout section component
 #[component]
 pub fn GridLayoutSection(
     #[prop(optional)] title: Option<String>,
-    #[prop(optional)] grid: Option<GridSystem>,
+    #[prop(optional, into)] grid: Signal<GridSystem>,
     #[prop(optional)] on_change: Option<Callback<GridSystem>>,
     #[prop(optional)] class: Option<String>,
     #[prop(optional)] style: Option<String>,
 ) -> impl IntoView {
     let title = title.unwrap_or_else(|| "Grid System".to_string());
-    let grid = grid.unwrap_or_default();
     let on_change = on_change.unwrap_or_else(|| Callback::new(|_| {}));
-    let grid_clone1 = grid.clone();
-    let grid_clone2 = grid.clone();
-    let grid_clone3 = grid.clone();
 
     let class = merge_classes(
         [
@@ -306,31 +445,35 @@ pub fn GridLayoutSection(
             <div class="layout-options">
                 <LayoutOptionGroup
                     title="Columns".to_string()
-                    values=[grid.columns as f64].to_vec()
-                    on_values_change=Callback::new(move |columns: Vec<f64>| {
-                        let mut new_grid = grid_clone1.clone();
-                        new_grid.columns = columns[0] as u32;
-                        on_change.run(new_grid);
+                    value=Signal::derive(move || Some(grid.get().columns as f64))
+                    on_change=edit_callback(grid, on_change, |grid, columns: f64| {
+                        grid.columns = columns.round().max(1.0) as u32;
                     })
                 />
 
                 <LayoutOptionGroup
                     title="Gutters".to_string()
-                    values=grid.gutters.clone()
-                    on_values_change=Callback::new(move |gutters| {
-                        let mut new_grid = grid_clone2.clone();
-                        new_grid.gutters = gutters;
-                        on_change.run(new_grid);
+                    values=Signal::derive(move || Some(grid.get().gutters))
+                    on_values_change=edit_callback(grid, on_change, |grid, gutters| {
+                        grid.gutters = gutters;
                     })
                 />
 
                 <LayoutOptionGroup
                     title="Gaps".to_string()
-                    values=grid.gaps.clone()
-                    on_values_change=Callback::new(move |gaps| {
-                        let mut new_grid = grid_clone3.clone();
-                        new_grid.gaps = gaps;
-                        on_change.run(new_grid);
+                    values=Signal::derive(move || Some(grid.get().gaps))
+                    on_values_change=edit_callback(grid, on_change, |grid, gaps| {
+                        grid.gaps = gaps;
+                    })
+                />
+
+                <LayoutOptionChoices
+                    title="Alignments".to_string()
+                    options=&GridAlignment::ALL
+                    selected=Signal::derive(move || grid.get().alignments)
+                    label=GridAlignment::as_str
+                    on_change=edit_callback(grid, on_change, |grid, alignments| {
+                        grid.alignments = alignments;
                     })
                 />
             </div>
@@ -342,17 +485,13 @@ pub fn GridLayoutSection(
 #[component]
 pub fn FlexboxLayoutSection(
     #[prop(optional)] title: Option<String>,
-    #[prop(optional)] flexbox: Option<FlexboxSystem>,
+    #[prop(optional, into)] flexbox: Signal<FlexboxSystem>,
     #[prop(optional)] on_change: Option<Callback<FlexboxSystem>>,
     #[prop(optional)] class: Option<String>,
     #[prop(optional)] style: Option<String>,
 ) -> impl IntoView {
     let title = title.unwrap_or_else(|| "Flexbox System".to_string());
-    let flexbox = flexbox.unwrap_or_default();
     let on_change = on_change.unwrap_or_else(|| Callback::new(|_| {}));
-    let flexbox_clone1 = flexbox.clone();
-    let flexbox_clone2 = flexbox.clone();
-    let flexbox_clone3 = flexbox.clone();
 
     let class = merge_classes(
         [
@@ -372,33 +511,59 @@ pub fn FlexboxLayoutSection(
             <h4 class="section-title">{title}</h4>
 
             <div class="layout-options">
-                <LayoutOptionGroup
+                <LayoutOptionChoices
                     title="Directions".to_string()
-                    values=flexbox.directions.iter().map(|d| d.as_str().len() as f64).collect()
-                    on_values_change=Callback::new(move |_directions| {
-                        // In a real implementation, this would update the directions
-                        let new_flexbox = flexbox_clone1.clone();
-                        on_change.run(new_flexbox);
+                    options=&FlexDirection::ALL
+                    selected=Signal::derive(move || flexbox.get().directions)
+                    label=FlexDirection::as_str
+                    on_change=edit_callback(flexbox, on_change, |flexbox, directions| {
+                        flexbox.directions = directions;
                     })
                 />
 
-                <LayoutOptionGroup
+                <LayoutOptionChoices
                     title="Wraps".to_string()
-                    values=flexbox.wraps.iter().map(|w| w.as_str().len() as f64).collect()
-                    on_values_change=Callback::new(move |_wraps| {
-                        // In a real implementation, this would update the wraps
-                        let new_flexbox = flexbox_clone2.clone();
-                        on_change.run(new_flexbox);
+                    options=&FlexWrap::ALL
+                    selected=Signal::derive(move || flexbox.get().wraps)
+                    label=FlexWrap::as_str
+                    on_change=edit_callback(flexbox, on_change, |flexbox, wraps| {
+                        flexbox.wraps = wraps;
                     })
                 />
 
-                <LayoutOptionGroup
+                <LayoutOptionChoices
                     title="Justifications".to_string()
-                    values=flexbox.justifications.iter().map(|j| j.as_str().len() as f64).collect()
-                    on_values_change=Callback::new(move |_justifications| {
-                        // In a real implementation, this would update the justifications
-                        let new_flexbox = flexbox_clone3.clone();
-                        on_change.run(new_flexbox);
+                    options=&JustifyContent::ALL
+                    selected=Signal::derive(move || flexbox.get().justifications)
+                    label=JustifyContent::as_str
+                    on_change=edit_callback(flexbox, on_change, |flexbox, justifications| {
+                        flexbox.justifications = justifications;
+                    })
+                />
+
+                <LayoutOptionChoices
+                    title="Alignments".to_string()
+                    options=&AlignItems::ALL
+                    selected=Signal::derive(move || flexbox.get().alignments)
+                    label=AlignItems::as_str
+                    on_change=edit_callback(flexbox, on_change, |flexbox, alignments| {
+                        flexbox.alignments = alignments;
+                    })
+                />
+
+                <LayoutOptionGroup
+                    title="Grows".to_string()
+                    values=Signal::derive(move || Some(flexbox.get().grows))
+                    on_values_change=edit_callback(flexbox, on_change, |flexbox, grows| {
+                        flexbox.grows = grows;
+                    })
+                />
+
+                <LayoutOptionGroup
+                    title="Shrinks".to_string()
+                    values=Signal::derive(move || Some(flexbox.get().shrinks))
+                    on_values_change=edit_callback(flexbox, on_change, |flexbox, shrinks| {
+                        flexbox.shrinks = shrinks;
                     })
                 />
             </div>
@@ -410,16 +575,13 @@ pub fn FlexboxLayoutSection(
 #[component]
 pub fn ContainerLayoutSection(
     #[prop(optional)] title: Option<String>,
-    #[prop(optional)] containers: Option<ContainerSystem>,
+    #[prop(optional, into)] containers: Signal<ContainerSystem>,
     #[prop(optional)] on_change: Option<Callback<ContainerSystem>>,
     #[prop(optional)] class: Option<String>,
     #[prop(optional)] style: Option<String>,
 ) -> impl IntoView {
     let title = title.unwrap_or_else(|| "Container System".to_string());
-    let containers = containers.unwrap_or_default();
     let on_change = on_change.unwrap_or_else(|| Callback::new(|_| {}));
-    let containers_clone1 = containers.clone();
-    let containers_clone2 = containers.clone();
 
     let class = merge_classes(
         [
@@ -439,23 +601,39 @@ pub fn ContainerLayoutSection(
             <h4 class="section-title">{title}</h4>
 
             <div class="layout-options">
-                <LayoutOptionGroup
+                <LayoutOptionChoices
                     title="Max Widths".to_string()
-                    values=containers.max_widths.iter().map(|w| w.as_str().len() as f64).collect()
-                    on_values_change=Callback::new(move |_max_widths| {
-                        // In a real implementation, this would update the max widths
-                        let new_containers = containers_clone1.clone();
-                        on_change.run(new_containers);
+                    options=&ContainerMaxWidth::ALL
+                    selected=Signal::derive(move || containers.get().max_widths)
+                    label=ContainerMaxWidth::as_str
+                    on_change=edit_callback(containers, on_change, |containers, max_widths| {
+                        containers.max_widths = max_widths;
                     })
                 />
 
                 <LayoutOptionGroup
                     title="Paddings".to_string()
-                    values=containers.paddings.clone()
-                    on_values_change=Callback::new(move |paddings| {
-                        let mut new_containers = containers_clone2.clone();
-                        new_containers.paddings = paddings;
-                        on_change.run(new_containers);
+                    values=Signal::derive(move || Some(containers.get().paddings))
+                    on_values_change=edit_callback(containers, on_change, |containers, paddings| {
+                        containers.paddings = paddings;
+                    })
+                />
+
+                <LayoutOptionGroup
+                    title="Margins".to_string()
+                    values=Signal::derive(move || Some(containers.get().margins))
+                    on_values_change=edit_callback(containers, on_change, |containers, margins| {
+                        containers.margins = margins;
+                    })
+                />
+
+                <LayoutOptionChoices
+                    title="Centering".to_string()
+                    options=&[true, false]
+                    selected=Signal::derive(move || containers.get().centers)
+                    label=|center: &bool| if *center { "centered" } else { "not centered" }
+                    on_change=edit_callback(containers, on_change, |containers, centers| {
+                        containers.centers = centers;
                     })
                 />
             </div>
@@ -535,6 +713,32 @@ mod layout_system_tests {
         assert!(!layout_type.is_empty()); // Test completed
     }
 
+    #[test]
+    fn test_layout_option_toggle() {
+        let directions = toggle_option(
+            &FlexDirection::ALL,
+            &[FlexDirection::Column, FlexDirection::Row],
+            FlexDirection::RowReverse,
+        );
+        assert_eq!(
+            directions,
+            [
+                FlexDirection::Row,
+                FlexDirection::RowReverse,
+                FlexDirection::Column
+            ]
+        );
+        let directions = toggle_option(&FlexDirection::ALL, &directions, FlexDirection::Row);
+        assert_eq!(
+            directions,
+            [FlexDirection::RowReverse, FlexDirection::Column]
+        );
+        assert_eq!(
+            LayoutSystem::default().flexbox.directions,
+            FlexDirection::ALL
+        );
+    }
+
     // Performance Tests
     #[test]
     fn test_layout_creation_performance() {
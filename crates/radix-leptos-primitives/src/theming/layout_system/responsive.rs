@@ -44,6 +44,15 @@ pub enum Breakpoint {
 }
 
 impl Breakpoint {
+    pub const ALL: [Breakpoint; 6] = [
+        Breakpoint::ExtraSmall,
+        Breakpoint::Small,
+        Breakpoint::Medium,
+        Breakpoint::Large,
+        Breakpoint::ExtraLarge,
+        Breakpoint::ExtraExtraLarge,
+    ];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             Breakpoint::ExtraSmall => "xs",
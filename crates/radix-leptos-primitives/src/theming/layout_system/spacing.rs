@@ -32,6 +32,9 @@ impl Default for SpacingSystem {
 }
 
 /// Spacing direction enum
+///
+/// `Start` and `End` follow the reading direction, so they mirror in
+/// right-to-left layouts; `Left` and `Right` never do.
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum SpacingDirection {
     All,
@@ -41,9 +44,23 @@ pub enum SpacingDirection {
     Right,
     Bottom,
     Left,
+    Start,
+    End,
 }
 
 impl SpacingDirection {
+    pub const ALL: [SpacingDirection; 9] = [
+        SpacingDirection::All,
+        SpacingDirection::Horizontal,
+        SpacingDirection::Vertical,
+        SpacingDirection::Top,
+        SpacingDirection::Right,
+        SpacingDirection::Bottom,
+        SpacingDirection::Left,
+        SpacingDirection::Start,
+        SpacingDirection::End,
+    ];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             SpacingDirection::All => "all",
@@ -53,8 +70,27 @@ impl SpacingDirection {
             SpacingDirection::Right => "right",
             SpacingDirection::Bottom => "bottom",
             SpacingDirection::Left => "left",
+            SpacingDirection::Start => "start",
+            SpacingDirection::End => "end",
         }
     }
+
+    /// The logical CSS property for spacing in this direction, e.g.
+    /// `margin-inline-start` for `property` `margin` and `Start`
+    pub fn css_property(&self, property: &str) -> String {
+        let side = match self {
+            SpacingDirection::All => return property.to_string(),
+            SpacingDirection::Horizontal => "inline",
+            SpacingDirection::Vertical => "block",
+            SpacingDirection::Top => "block-start",
+            SpacingDirection::Bottom => "block-end",
+            SpacingDirection::Start => "inline-start",
+            SpacingDirection::End => "inline-end",
+            SpacingDirection::Left => "left",
+            SpacingDirection::Right => "right",
+        };
+        format!("{}-{}", property, side)
+    }
 }
 
 /// Utility functions for spacing calculations
@@ -88,6 +124,21 @@ pub mod spacing_utils {
         calculate_spacing(spacing_system, scale_index)
     }
 
+    /// CSS declaration for spacing in a direction, using logical
+    /// properties so layouts mirror under `dir="rtl"`
+    pub fn spacing_declaration(
+        spacing_system: &SpacingSystem,
+        property: &str,
+        direction: SpacingDirection,
+        scale_index: usize,
+    ) -> String {
+        format!(
+            "{}: {}px;",
+            direction.css_property(property),
+            calculate_spacing(spacing_system, scale_index)
+        )
+    }
+
     /// Validate spacing configuration
     pub fn validate_spacing_system(spacing_system: &SpacingSystem) -> bool {
         spacing_system.base_unit > 0.0
@@ -172,6 +223,22 @@ mod spacing_tests {
         assert!(!spacing_utils::validate_spacing_system(&invalid_spacing));
     }
 
+    #[test]
+    fn test_spacing_logical_properties() {
+        let spacing = SpacingSystem::default();
+        assert_eq!(
+            spacing_utils::spacing_declaration(&spacing, "margin", SpacingDirection::Start, 4),
+            "margin-inline-start: 4px;"
+        );
+        assert_eq!(
+            spacing_utils::spacing_declaration(&spacing, "padding", SpacingDirection::Vertical, 8),
+            "padding-block: 10px;"
+        );
+        assert_eq!(SpacingDirection::Top.css_property("margin"), "margin-block-start");
+        assert_eq!(SpacingDirection::All.css_property("padding"), "padding");
+        assert_eq!(SpacingDirection::Left.css_property("margin"), "margin-left");
+    }
+
     // Property-based tests
     #[test]
     fn test_spacing_direction_property_based() {
@@ -185,6 +252,8 @@ mod spacing_tests {
             SpacingDirection::Right,
             SpacingDirection::Bottom,
             SpacingDirection::Left,
+            SpacingDirection::Start,
+            SpacingDirection::End,
         ]))| {
             let direction_str = direction.as_str();
             assert!(!direction_str.is_empty());
@@ -1,11 +1,22 @@
+pub mod chart_palette;
 pub mod component_variants;
 pub mod css_variables;
 pub mod dark_mode;
+pub mod design_tokens;
 pub mod layout_system;
+pub mod motion;
+pub mod palette;
 pub mod prebuilt_themes;
 pub mod size_variants;
+pub mod style_dictionary;
 pub mod theme_customization;
+pub mod theme_injection;
+pub mod theme_patch;
 pub mod theme_provider;
+pub mod theme_transition;
+pub mod theme_validator;
+pub mod tokens_studio;
+pub mod typography;
 
 // Test modules - temporarily commenting out problematic ones
 #[cfg(test)]
@@ -29,11 +40,22 @@ mod integration_tests;
 #[cfg(test)]
 mod simple_tests;
 
+pub use chart_palette::*;
 pub use component_variants::*;
 pub use css_variables::*;
 pub use dark_mode::*;
+pub use design_tokens::*;
 pub use layout_system::*;
+pub use motion::*;
+pub use palette::*;
 pub use prebuilt_themes::*;
 pub use size_variants::*;
+pub use style_dictionary::*;
 pub use theme_customization::*;
+pub use theme_injection::*;
+pub use theme_patch::*;
 pub use theme_provider::*;
+pub use theme_transition::*;
+pub use theme_validator::*;
+pub use tokens_studio::*;
+pub use typography::*;
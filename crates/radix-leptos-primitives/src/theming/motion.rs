@@ -0,0 +1,492 @@
+//! Motion tokens
+//!
+//! Named durations, easing curves and springs, written as `--motion-*`
+//! custom properties. Components animated through `use_presence` pick a
+//! [`PresenceAnimation`] with `data-motion`, and the [`MotionSystem`]
+//! stylesheet animates them from the tokens. Users who prefer reduced
+//! motion, and everything below a MotionProvider with `reduce_motion`, get
+//! every duration set to zero and no animations at all.
+
+use leptos::prelude::*;
+use radix_leptos_core::{provide_reduced_motion, use_reduced_motion};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Id of the `<style>` element a MotionProvider adds the animations with
+const STYLE_ID: &str = "radix-motion";
+
+/// Durations in milliseconds, from quick feedback to large surfaces
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MotionDurations {
+    pub fast: u32,
+    pub normal: u32,
+    pub slow: u32,
+    pub slower: u32,
+}
+
+impl Default for MotionDurations {
+    fn default() -> Self {
+        Self {
+            fast: 100,
+            normal: 200,
+            slow: 300,
+            slower: 500,
+        }
+    }
+}
+
+/// Easing curves as CSS `<easing-function>` values
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MotionEasings {
+    /// For elements moving within the page
+    pub standard: String,
+    /// For elements entering, decelerating to rest
+    pub enter: String,
+    /// For elements leaving, accelerating away
+    pub exit: String,
+    /// Overshoots slightly, for moments that deserve attention
+    pub emphasized: String,
+}
+
+impl Default for MotionEasings {
+    fn default() -> Self {
+        Self {
+            standard: "cubic-bezier(0.2, 0, 0, 1)".to_string(),
+            enter: "cubic-bezier(0, 0, 0, 1)".to_string(),
+            exit: "cubic-bezier(0.3, 0, 1, 1)".to_string(),
+            emphasized: "cubic-bezier(0.34, 1.56, 0.64, 1)".to_string(),
+        }
+    }
+}
+
+/// Points sampled along a spring for its CSS `linear()` easing
+const SPRING_SAMPLES: usize = 32;
+
+/// Distance from rest, in fractions of the travel, at which a spring counts
+/// as settled
+const SPRING_REST_THRESHOLD: f64 = 0.001;
+
+/// Springs simulated for longer than this are cut off
+const SPRING_MAX_DURATION: Duration = Duration::from_secs(5);
+
+/// A damped spring, moving from 0 to 1 from rest
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Spring {
+    pub stiffness: f64,
+    pub damping: f64,
+    pub mass: f64,
+}
+
+impl Spring {
+    pub fn new(stiffness: f64, damping: f64, mass: f64) -> Self {
+        Self {
+            stiffness,
+            damping,
+            mass,
+        }
+    }
+
+    /// Below 1 the spring overshoots and bounces, above it creeps to rest
+    pub fn damping_ratio(&self) -> f64 {
+        self.damping / (2.0 * (self.stiffness * self.mass).sqrt())
+    }
+
+    /// Time until the spring comes to rest
+    pub fn settling_time(&self) -> Duration {
+        Duration::from_millis(self.simulate().len().saturating_sub(1) as u64)
+    }
+
+    /// The spring's motion as a CSS `linear()` easing, to pair with
+    /// [`Self::settling_time`] as the duration
+    pub fn to_css_easing(&self) -> String {
+        let positions = self.simulate();
+        let last = positions.len() - 1;
+        let points = (0..=SPRING_SAMPLES)
+            .map(|sample| {
+                let position = positions[sample * last / SPRING_SAMPLES];
+                let position = if sample == SPRING_SAMPLES {
+                    1.0
+                } else {
+                    position
+                };
+                ((position * 10_000.0).round() / 10_000.0).to_string()
+            })
+            .collect::<Vec<_>>();
+        format!("linear({})", points.join(", "))
+    }
+
+    /// Position at every millisecond until the spring settles
+    fn simulate(&self) -> Vec<f64> {
+        let step = 0.001;
+        let max_steps = SPRING_MAX_DURATION.as_millis() as usize;
+        let mass = self.mass.max(f64::EPSILON);
+        let (mut position, mut velocity) = (0.0_f64, 0.0_f64);
+        let mut positions = vec![position];
+        while positions.len() <= max_steps {
+            let acceleration =
+                (-self.stiffness * (position - 1.0) - self.damping * velocity) / mass;
+            velocity += acceleration * step;
+            position += velocity * step;
+            positions.push(position);
+            if (position - 1.0).abs() < SPRING_REST_THRESHOLD
+                && (velocity * step).abs() < SPRING_REST_THRESHOLD
+            {
+                break;
+            }
+        }
+        positions
+    }
+}
+
+/// Named springs
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MotionSprings {
+    /// Soft, with a little overshoot
+    pub gentle: Spring,
+    /// Quick and barely overshooting, for things the user just opened
+    pub snappy: Spring,
+    /// Playful, with visible bounces
+    pub bouncy: Spring,
+}
+
+impl Default for MotionSprings {
+    fn default() -> Self {
+        Self {
+            gentle: Spring::new(120.0, 14.0, 1.0),
+            snappy: Spring::new(300.0, 30.0, 1.0),
+            bouncy: Spring::new(400.0, 15.0, 1.0),
+        }
+    }
+}
+
+/// How a component animates in and out with `use_presence`, set as its
+/// `data-motion`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PresenceAnimation {
+    Fade,
+    Scale,
+    SlideFromTop,
+    SlideFromBottom,
+    SlideFromLeft,
+    SlideFromRight,
+}
+
+impl PresenceAnimation {
+    pub const ALL: [PresenceAnimation; 6] = [
+        PresenceAnimation::Fade,
+        PresenceAnimation::Scale,
+        PresenceAnimation::SlideFromTop,
+        PresenceAnimation::SlideFromBottom,
+        PresenceAnimation::SlideFromLeft,
+        PresenceAnimation::SlideFromRight,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PresenceAnimation::Fade => "fade",
+            PresenceAnimation::Scale => "scale",
+            PresenceAnimation::SlideFromTop => "slide-from-top",
+            PresenceAnimation::SlideFromBottom => "slide-from-bottom",
+            PresenceAnimation::SlideFromLeft => "slide-from-left",
+            PresenceAnimation::SlideFromRight => "slide-from-right",
+        }
+    }
+
+    /// Styles the element starts entering from and ends leaving at
+    fn hidden_style(&self) -> &'static str {
+        match self {
+            PresenceAnimation::Fade => "opacity: 0;",
+            PresenceAnimation::Scale => "opacity: 0; transform: scale(0.95);",
+            PresenceAnimation::SlideFromTop => "transform: translateY(-100%);",
+            PresenceAnimation::SlideFromBottom => "transform: translateY(100%);",
+            PresenceAnimation::SlideFromLeft => "transform: translateX(-100%);",
+            PresenceAnimation::SlideFromRight => "transform: translateX(100%);",
+        }
+    }
+
+    /// Duration and easing of the entry, as `--motion-*` variables
+    fn enter_timing(&self) -> &'static str {
+        match self {
+            PresenceAnimation::Fade => "var(--motion-duration-normal) var(--motion-ease-enter)",
+            PresenceAnimation::Scale => {
+                "var(--motion-spring-snappy-duration) var(--motion-spring-snappy)"
+            }
+            _ => "var(--motion-duration-slow) var(--motion-ease-enter)",
+        }
+    }
+
+    /// Duration and easing of the exit, as `--motion-*` variables
+    fn exit_timing(&self) -> &'static str {
+        match self {
+            PresenceAnimation::Fade | PresenceAnimation::Scale => {
+                "var(--motion-duration-fast) var(--motion-ease-exit)"
+            }
+            _ => "var(--motion-duration-normal) var(--motion-ease-exit)",
+        }
+    }
+
+    /// Keyframes and `data-state` rules for this animation
+    pub fn to_css(&self) -> String {
+        let name = self.as_str();
+        let hidden = self.hidden_style();
+        format!(
+            "@keyframes motion-{name}-in {{ from {{ {hidden} }} }} \
+@keyframes motion-{name}-out {{ to {{ {hidden} }} }} \
+[data-motion=\"{name}\"][data-state=\"open\"] {{ animation: motion-{name}-in {enter}; }} \
+[data-motion=\"{name}\"][data-state=\"closed\"] {{ animation: motion-{name}-out {exit} forwards; }}",
+            enter = self.enter_timing(),
+            exit = self.exit_timing(),
+        )
+    }
+}
+
+/// Durations, easings and springs shared by every animated component
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct MotionSystem {
+    pub durations: MotionDurations,
+    pub easings: MotionEasings,
+    pub springs: MotionSprings,
+}
+
+impl MotionSystem {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_durations(mut self, durations: MotionDurations) -> Self {
+        self.durations = durations;
+        self
+    }
+
+    pub fn with_easings(mut self, easings: MotionEasings) -> Self {
+        self.easings = easings;
+        self
+    }
+
+    pub fn with_springs(mut self, springs: MotionSprings) -> Self {
+        self.springs = springs;
+        self
+    }
+
+    /// The tokens as `--motion-*` declarations
+    pub fn to_css_declarations(&self) -> String {
+        self.declarations(false)
+    }
+
+    /// The declarations with every duration zero, for users who prefer
+    /// reduced motion
+    pub fn reduced_css_declarations(&self) -> String {
+        self.declarations(true)
+    }
+
+    /// Stylesheet with the tokens on `:root` plus the
+    /// [`animation_stylesheet`](Self::animation_stylesheet), for apps that
+    /// ship their own CSS instead of using a MotionProvider
+    pub fn stylesheet(&self) -> String {
+        format!(
+            ":root {{ {} }} @media (prefers-reduced-motion: reduce) {{ :root {{ {} }} }} {}",
+            self.to_css_declarations(),
+            self.reduced_css_declarations(),
+            Self::animation_stylesheet()
+        )
+    }
+
+    /// The [`PresenceAnimation`]s and rules turning them off with
+    /// `prefers-reduced-motion` or under `data-reduced-motion="true"`. They
+    /// only refer to the tokens, so this is the same for every system.
+    pub fn animation_stylesheet() -> String {
+        let animations = PresenceAnimation::ALL
+            .iter()
+            .map(PresenceAnimation::to_css)
+            .collect::<Vec<_>>()
+            .join(" ");
+        format!(
+            "{} @media (prefers-reduced-motion: reduce) {{ \
+[data-motion] {{ animation: none !important; transition: none !important; }} }} \
+[data-reduced-motion=\"true\"] [data-motion] {{ animation: none !important; transition: none !important; }}",
+            animations
+        )
+    }
+
+    fn declarations(&self, reduced: bool) -> String {
+        let duration = |milliseconds: u128| if reduced { 0 } else { milliseconds };
+        let MotionDurations {
+            fast,
+            normal,
+            slow,
+            slower,
+        } = self.durations;
+        let mut declarations = Vec::new();
+        for (name, milliseconds) in [
+            ("fast", fast),
+            ("normal", normal),
+            ("slow", slow),
+            ("slower", slower),
+        ] {
+            declarations.push(format!(
+                "--motion-duration-{}: {}ms;",
+                name,
+                duration(u128::from(milliseconds))
+            ));
+        }
+        for (name, easing) in [
+            ("standard", &self.easings.standard),
+            ("enter", &self.easings.enter),
+            ("exit", &self.easings.exit),
+            ("emphasized", &self.easings.emphasized),
+        ] {
+            declarations.push(format!("--motion-ease-{}: {};", name, easing));
+        }
+        for (name, spring) in [
+            ("gentle", self.springs.gentle),
+            ("snappy", self.springs.snappy),
+            ("bouncy", self.springs.bouncy),
+        ] {
+            declarations.push(format!(
+                "--motion-spring-{}: {};",
+                name,
+                spring.to_css_easing()
+            ));
+            declarations.push(format!(
+                "--motion-spring-{}-duration: {}ms;",
+                name,
+                duration(spring.settling_time().as_millis())
+            ));
+        }
+        declarations.join(" ")
+    }
+}
+
+/// Motion tokens shared with everything below a MotionProvider
+#[derive(Clone, Copy)]
+pub struct MotionContext {
+    pub system: Signal<MotionSystem>,
+}
+
+/// Motion provider component
+///
+/// Sets the tokens on its subtree and, once per document, adds the
+/// [`MotionSystem::animation_stylesheet`] to `<head>`. Nothing is set on
+/// `:root`, so nested providers only affect their own subtree. While the user prefers reduced motion every duration is zero,
+/// so custom animations built on the tokens stop too. `reduce_motion` does
+/// the same regardless of the user's setting, e.g. for an in-app
+/// preference: animated components below check `use_reduced_motion` and
+/// stylesheets can key off the `data-reduced-motion` it sets.
+#[component]
+pub fn MotionProvider(
+    /// Motion tokens, the default ones without it
+    #[prop(optional, into)]
+    system: Signal<MotionSystem>,
+    /// Turn off non-essential animation below, whatever the user's setting
+    #[prop(optional, into)]
+    reduce_motion: Signal<bool>,
+    /// Children content
+    children: Option<Children>,
+) -> impl IntoView {
+    provide_context(MotionContext { system });
+    provide_reduced_motion(reduce_motion);
+    let reduced_motion = use_reduced_motion();
+
+    let declarations = move || {
+        system.with(|system| {
+            if reduced_motion.get() {
+                system.reduced_css_declarations()
+            } else {
+                system.to_css_declarations()
+            }
+        })
+    };
+
+    Effect::new(move |_| inject_animation_stylesheet());
+
+    view! {
+        <div
+            class="motion-provider"
+            style=move || format!("display: contents; {}", declarations())
+            data-reduced-motion=move || reduced_motion.get().to_string()
+        >
+            {children.map(|c| c())}
+        </div>
+    }
+}
+
+/// Adds the animation stylesheet to `<head>` unless it is already there
+fn inject_animation_stylesheet() {
+    let document = document();
+    if document.get_element_by_id(STYLE_ID).is_some() {
+        return;
+    }
+    let (Ok(style), Some(head)) = (document.create_element("style"), document.head()) else {
+        return;
+    };
+    style.set_id(STYLE_ID);
+    style.set_text_content(Some(&MotionSystem::animation_stylesheet()));
+    let _ = head.append_child(&style);
+}
+
+/// Hook for the motion tokens of the closest MotionProvider, or the
+/// default ones outside of any
+pub fn use_motion_system() -> Signal<MotionSystem> {
+    match use_context::<MotionContext>() {
+        Some(context) => context.system,
+        None => Signal::derive(MotionSystem::default),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_springs() {
+        let springs = MotionSprings::default();
+        assert!(springs.bouncy.damping_ratio() < springs.gentle.damping_ratio());
+        assert!(springs.snappy.settling_time() < springs.gentle.settling_time());
+        assert!(springs.gentle.settling_time() < SPRING_MAX_DURATION);
+
+        let easing = springs.bouncy.to_css_easing();
+        assert!(easing.starts_with("linear(0, "));
+        assert!(easing.ends_with(", 1)"));
+        assert_eq!(easing.matches(", ").count(), SPRING_SAMPLES);
+        // A bouncy spring overshoots its target
+        assert!(easing
+            .trim_start_matches("linear(")
+            .trim_end_matches(')')
+            .split(", ")
+            .any(|point| point.parse::<f64>().unwrap() > 1.0));
+    }
+
+    #[test]
+    fn test_motion_declarations() {
+        let system = MotionSystem::new().with_durations(MotionDurations {
+            fast: 80,
+            ..MotionDurations::default()
+        });
+        let css = system.to_css_declarations();
+        assert!(css.starts_with("--motion-duration-fast: 80ms;"));
+        assert!(css.contains("--motion-ease-enter: cubic-bezier(0, 0, 0, 1);"));
+        assert!(css.contains("--motion-spring-snappy: linear(0, "));
+
+        let reduced = system.reduced_css_declarations();
+        assert!(reduced.contains("--motion-duration-slower: 0ms;"));
+        assert!(reduced.contains("--motion-spring-bouncy-duration: 0ms;"));
+        assert!(!reduced.contains("--motion-duration-fast: 80ms;"));
+
+        let stylesheet = system.stylesheet();
+        assert!(stylesheet.contains(
+            "[data-motion=\"slide-from-left\"][data-state=\"closed\"] { animation: motion-slide-from-left-out"
+        ));
+        assert!(stylesheet.contains("@media (prefers-reduced-motion: reduce)"));
+        assert!(stylesheet.contains("[data-reduced-motion=\"true\"] [data-motion] { animation: none"));
+        assert!(stylesheet.starts_with(":root { --motion-duration-fast: 80ms;"));
+        assert!(!MotionSystem::animation_stylesheet().contains(":root"));
+    }
+
+    #[test]
+    fn test_motion_provider_sets_tokens_inline() {
+        crate::test_support::init_test_executor();
+        let html = Owner::new().with(|| view! { <MotionProvider children=None /> }.to_html());
+        assert!(!html.contains("<style"));
+        assert!(html.contains("style=\"display: contents; --motion-duration-fast:"));
+    }
+}
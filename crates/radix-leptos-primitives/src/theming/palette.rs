@@ -0,0 +1,336 @@
+//! Color palettes generated from a single color
+//!
+//! Shades are spaced evenly in OKLCH lightness, which unlike HSL is
+//! perceptually uniform, so a generated `500` looks as strong as a
+//! hand-picked one whatever the hue.
+
+use std::fmt;
+
+use super::css_variables::{NeutralColors, PrimaryColors, SecondaryColors};
+
+/// Lightness of each shade, from `50` to `950`
+const SHADE_LIGHTNESS: [f64; 11] = [
+    0.97, 0.93, 0.87, 0.79, 0.70, 0.62, 0.54, 0.46, 0.39, 0.32, 0.24,
+];
+
+/// Share of the base color's chroma kept by each shade; very light and very
+/// dark shades can't hold much color
+const SHADE_CHROMA: [f64; 11] = [0.08, 0.18, 0.35, 0.6, 0.85, 1.0, 1.0, 0.9, 0.75, 0.6, 0.45];
+
+/// An sRGB color
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+/// A color in OKLCH: lightness 0–1, chroma from 0 and hue in degrees
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Oklch {
+    pub l: f64,
+    pub c: f64,
+    pub h: f64,
+}
+
+impl Color {
+    pub fn new(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    /// Parse `#rgb` or `#rrggbb`
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        let hex = hex.trim().strip_prefix('#')?;
+        if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        let channel = |i: usize, len: usize| u8::from_str_radix(&hex[i * len..(i + 1) * len], 16);
+        match hex.len() {
+            3 => Some(Self::new(
+                channel(0, 1).ok()? * 17,
+                channel(1, 1).ok()? * 17,
+                channel(2, 1).ok()? * 17,
+            )),
+            6 => Some(Self::new(
+                channel(0, 2).ok()?,
+                channel(1, 2).ok()?,
+                channel(2, 2).ok()?,
+            )),
+            _ => None,
+        }
+    }
+
+    /// Lowercase `#rrggbb`
+    pub fn to_hex(&self) -> String {
+        self.to_string()
+    }
+
+    /// WCAG relative luminance, from 0 for black to 1 for white
+    pub fn relative_luminance(&self) -> f64 {
+        0.2126 * srgb_to_linear(self.r)
+            + 0.7152 * srgb_to_linear(self.g)
+            + 0.0722 * srgb_to_linear(self.b)
+    }
+
+    /// WCAG contrast ratio against `other`, from 1 to 21
+    pub fn contrast_ratio(&self, other: &Color) -> f64 {
+        let (a, b) = (self.relative_luminance(), other.relative_luminance());
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    pub fn to_oklch(&self) -> Oklch {
+        let [l, a, b] = linear_srgb_to_oklab([
+            srgb_to_linear(self.r),
+            srgb_to_linear(self.g),
+            srgb_to_linear(self.b),
+        ]);
+        Oklch {
+            l,
+            c: a.hypot(b),
+            h: b.atan2(a).to_degrees().rem_euclid(360.0),
+        }
+    }
+
+    /// The closest displayable color, reducing chroma until it fits sRGB
+    pub fn from_oklch(color: Oklch) -> Self {
+        let rgb = |chroma: f64| {
+            let hue = color.h.to_radians();
+            oklab_to_linear_srgb([color.l, chroma * hue.cos(), chroma * hue.sin()])
+        };
+        let fits = |rgb: [f64; 3]| rgb.iter().all(|c| (-1e-4..=1.0 + 1e-4).contains(c));
+
+        let mut linear = rgb(color.c);
+        if !fits(linear) {
+            let (mut low, mut high) = (0.0, color.c);
+            for _ in 0..20 {
+                let mid = (low + high) / 2.0;
+                if fits(rgb(mid)) {
+                    low = mid;
+                } else {
+                    high = mid;
+                }
+            }
+            linear = rgb(low);
+        }
+        let [r, g, b] = linear.map(linear_to_srgb);
+        Self { r, g, b }
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+/// Eleven shades of one color, `50` to `950`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColorScale {
+    shades: [Color; 11],
+}
+
+impl ColorScale {
+    /// The shade names, lightest first
+    pub const STEPS: [u16; 11] = [50, 100, 200, 300, 400, 500, 600, 700, 800, 900, 950];
+
+    /// The shade with the given name, e.g. `500`
+    pub fn shade(&self, step: u16) -> Option<Color> {
+        let index = Self::STEPS.iter().position(|&s| s == step)?;
+        Some(self.shades[index])
+    }
+
+    /// Shade names and colors, lightest first
+    pub fn iter(&self) -> impl Iterator<Item = (u16, Color)> + '_ {
+        Self::STEPS.into_iter().zip(self.shades)
+    }
+
+    /// The same shades, darkest first, for dark themes
+    pub fn reversed(&self) -> Self {
+        let mut shades = self.shades;
+        shades.reverse();
+        Self { shades }
+    }
+
+    fn hex(&self) -> [String; 11] {
+        self.shades.map(|shade| shade.to_hex())
+    }
+}
+
+/// Generate a full scale around `base`
+///
+/// `base` itself becomes the shade whose lightness it is closest to, so a
+/// brand color stays exact; the other shades share its hue.
+pub fn generate_palette(base: Color) -> ColorScale {
+    let base_oklch = base.to_oklch();
+    let base_index = SHADE_LIGHTNESS
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| {
+            (*a - base_oklch.l)
+                .abs()
+                .total_cmp(&(*b - base_oklch.l).abs())
+        })
+        .map_or(5, |(index, _)| index);
+
+    let mut index = 0;
+    let shades = SHADE_LIGHTNESS.map(|lightness| {
+        let chroma = SHADE_CHROMA[index] / SHADE_CHROMA[base_index];
+        let shade = if index == base_index {
+            base
+        } else {
+            Color::from_oklch(Oklch {
+                l: lightness,
+                c: base_oklch.c * chroma.min(1.0),
+                h: base_oklch.h,
+            })
+        };
+        index += 1;
+        shade
+    });
+    ColorScale { shades }
+}
+
+impl From<ColorScale> for PrimaryColors {
+    fn from(scale: ColorScale) -> Self {
+        let [primary_50, primary_100, primary_200, primary_300, primary_400, primary_500, primary_600, primary_700, primary_800, primary_900, primary_950] =
+            scale.hex();
+        Self {
+            primary_50,
+            primary_100,
+            primary_200,
+            primary_300,
+            primary_400,
+            primary_500,
+            primary_600,
+            primary_700,
+            primary_800,
+            primary_900,
+            primary_950,
+        }
+    }
+}
+
+impl From<ColorScale> for SecondaryColors {
+    fn from(scale: ColorScale) -> Self {
+        let [secondary_50, secondary_100, secondary_200, secondary_300, secondary_400, secondary_500, secondary_600, secondary_700, secondary_800, secondary_900, secondary_950] =
+            scale.hex();
+        Self {
+            secondary_50,
+            secondary_100,
+            secondary_200,
+            secondary_300,
+            secondary_400,
+            secondary_500,
+            secondary_600,
+            secondary_700,
+            secondary_800,
+            secondary_900,
+            secondary_950,
+        }
+    }
+}
+
+impl From<ColorScale> for NeutralColors {
+    fn from(scale: ColorScale) -> Self {
+        let [neutral_50, neutral_100, neutral_200, neutral_300, neutral_400, neutral_500, neutral_600, neutral_700, neutral_800, neutral_900, neutral_950] =
+            scale.hex();
+        Self {
+            neutral_50,
+            neutral_100,
+            neutral_200,
+            neutral_300,
+            neutral_400,
+            neutral_500,
+            neutral_600,
+            neutral_700,
+            neutral_800,
+            neutral_900,
+            neutral_950,
+        }
+    }
+}
+
+fn srgb_to_linear(channel: u8) -> f64 {
+    let c = f64::from(channel) / 255.0;
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(c: f64) -> u8 {
+    let c = c.clamp(0.0, 1.0);
+    let srgb = if c <= 0.003_130_8 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    };
+    (srgb * 255.0).round() as u8
+}
+
+fn linear_srgb_to_oklab([r, g, b]: [f64; 3]) -> [f64; 3] {
+    let l = (0.412_221_470_8 * r + 0.536_332_536_3 * g + 0.051_445_992_9 * b).cbrt();
+    let m = (0.211_903_498_2 * r + 0.680_699_545_1 * g + 0.107_396_956_6 * b).cbrt();
+    let s = (0.088_302_461_9 * r + 0.281_718_837_6 * g + 0.629_978_700_5 * b).cbrt();
+    [
+        0.210_454_255_3 * l + 0.793_617_785_0 * m - 0.004_072_046_8 * s,
+        1.977_998_495_1 * l - 2.428_592_205_0 * m + 0.450_593_709_9 * s,
+        0.025_904_037_1 * l + 0.782_771_766_2 * m - 0.808_675_766_0 * s,
+    ]
+}
+
+fn oklab_to_linear_srgb([l, a, b]: [f64; 3]) -> [f64; 3] {
+    let l_ = (l + 0.396_337_777_4 * a + 0.215_803_757_3 * b).powi(3);
+    let m_ = (l - 0.105_561_345_8 * a - 0.063_854_172_8 * b).powi(3);
+    let s_ = (l - 0.089_484_177_5 * a - 1.291_485_548_0 * b).powi(3);
+    [
+        4.076_741_662_1 * l_ - 3.307_711_591_3 * m_ + 0.230_969_929_2 * s_,
+        -1.268_438_004_6 * l_ + 2.609_757_401_1 * m_ - 0.341_319_396_5 * s_,
+        -0.004_196_086_3 * l_ - 0.703_418_614_7 * m_ + 1.707_614_701_0 * s_,
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color_hex_and_oklch_round_trip() {
+        assert_eq!(
+            Color::from_hex("#3B82F6"),
+            Some(Color::new(0x3b, 0x82, 0xf6))
+        );
+        assert_eq!(Color::from_hex("#fff"), Some(Color::new(255, 255, 255)));
+        assert_eq!(Color::from_hex("3b82f6"), None);
+        assert_eq!(Color::from_hex("#3b82f"), None);
+
+        for hex in ["#3b82f6", "#000000", "#ffffff", "#dc2626", "#10b981"] {
+            let color = Color::from_hex(hex).unwrap();
+            assert_eq!(Color::from_oklch(color.to_oklch()).to_hex(), hex);
+        }
+        let white = Color::new(255, 255, 255).to_oklch();
+        assert!((white.l - 1.0).abs() < 1e-3 && white.c < 1e-3);
+    }
+
+    #[test]
+    fn test_generate_palette() {
+        let brand = Color::from_hex("#3b82f6").unwrap();
+        let scale = generate_palette(brand);
+
+        // The brand color is kept exactly, at the shade matching its lightness
+        assert_eq!(scale.shade(500), Some(brand));
+        assert_eq!(scale.shade(450), None);
+
+        // Shades get darker step by step and keep the hue
+        let shades: Vec<Oklch> = scale.iter().map(|(_, shade)| shade.to_oklch()).collect();
+        assert!(shades.windows(2).all(|pair| pair[0].l > pair[1].l));
+        for shade in &shades[1..] {
+            assert!((shade.h - brand.to_oklch().h).abs() < 5.0);
+        }
+
+        // A very light brand color becomes one of the light shades
+        let pale = Color::from_hex("#dbeafe").unwrap();
+        assert_eq!(generate_palette(pale).shade(100), Some(pale));
+    }
+}
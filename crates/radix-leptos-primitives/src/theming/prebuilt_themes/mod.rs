@@ -1,5 +1,6 @@
+use crate::i18n::{use_i18n, MessageKey};
 use crate::theming::css_variables::*;
-use crate::utils::merge_classes;
+use crate::utils::{merge_classes, sanitize_css_color};
 use leptos::callback::Callback;
 use leptos::prelude::*;
 
@@ -164,7 +165,7 @@ pub fn ThemeSelector(
             class=class
             style=style
             role="radiogroup"
-            aria-label="Theme selection"
+            aria-label=use_i18n().text(MessageKey::ThemeSelection)
         >
             <div class="theme-selector-header">
                 <h3>"Choose a Theme"</h3>
@@ -311,7 +312,7 @@ pub fn ThemePreview(
     #[prop(optional)] style: Option<String>,
     #[prop(optional)] colors: Option<ThemeColors>,
 ) -> impl IntoView {
-    let colors = colors.unwrap_or_default();
+    let colors = sanitize_theme_colors(colors.unwrap_or_default());
 
     let class = merge_classes(["theme-preview", class.as_deref().unwrap_or("")].to_vec());
 
@@ -337,6 +338,27 @@ pub fn ThemePreview(
     }
 }
 
+/// Replace invalid color strings with the default palette so previews never
+/// render with broken inline styles
+fn sanitize_theme_colors(colors: ThemeColors) -> ThemeColors {
+    let defaults = ThemeColors::default();
+    let sanitize = |value: &str, fallback: &str| sanitize_css_color("ThemePreview", value, fallback);
+
+    ThemeColors {
+        primary: sanitize(&colors.primary, &defaults.primary),
+        secondary: sanitize(&colors.secondary, &defaults.secondary),
+        accent: sanitize(&colors.accent, &defaults.accent),
+        neutral: sanitize(&colors.neutral, &defaults.neutral),
+        text: sanitize(&colors.text, &defaults.text),
+        background: sanitize(&colors.background, &defaults.background),
+        surface: sanitize(&colors.surface, &defaults.surface),
+        error: sanitize(&colors.error, &defaults.error),
+        warning: sanitize(&colors.warning, &defaults.warning),
+        success: sanitize(&colors.success, &defaults.success),
+        info: sanitize(&colors.info, &defaults.info),
+    }
+}
+
 #[cfg(test)]
 mod prebuilt_themes_tests {
     use super::*;
@@ -400,6 +422,21 @@ mod prebuilt_themes_tests {
         // Test complete theme selector workflow
     }
 
+    #[test]
+    fn test_sanitize_theme_colors_replaces_invalid_values() {
+        let colors = ThemeColors {
+            primary: "not-a-color".to_string(),
+            accent: "red; background: url(x)".to_string(),
+            text: "rgb(17, 24, 39)".to_string(),
+            ..ThemeColors::default()
+        };
+        let sanitized = sanitize_theme_colors(colors);
+        let defaults = ThemeColors::default();
+        assert_eq!(sanitized.primary, defaults.primary);
+        assert_eq!(sanitized.accent, defaults.accent);
+        assert_eq!(sanitized.text, "rgb(17, 24, 39)");
+    }
+
     #[test]
     fn test_theme_preview_integration() {
         // Test theme preview functionality
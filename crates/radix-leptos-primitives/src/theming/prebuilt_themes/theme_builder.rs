@@ -1,4 +1,5 @@
 use crate::theming::css_variables::CSSVariables;
+use crate::theming::palette::{generate_palette, Color, Oklch};
 use super::color_schemes::{ThemeCategory, ThemeColors, ThemeInfo};
 
 // Theme creation functions
@@ -88,6 +89,76 @@ pub fn create_elegant_theme() -> CSSVariables {
     theme
 }
 
+/// Chroma kept by the secondary scale, relative to the brand color
+const SECONDARY_CHROMA: f64 = 0.25;
+/// Chroma of the neutral scale, just enough to tint the grays
+const NEUTRAL_CHROMA: f64 = 0.015;
+
+/// Builder for a complete theme from a single brand color
+///
+/// The primary scale is generated around the brand color, the secondary
+/// scale is a muted version of it and the neutrals are grays tinted with
+/// its hue. Everything else comes from the light or dark default theme.
+///
+/// ```rust,no_run
+/// use radix_leptos_primitives::theming::{Color, ThemeBuilder};
+///
+/// let theme = ThemeBuilder::new()
+///     .with_brand_color(Color::from_hex("#0ea5e9").unwrap())
+///     .build();
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ThemeBuilder {
+    brand_color: Option<Color>,
+    dark: bool,
+}
+
+impl ThemeBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_brand_color(mut self, color: Color) -> Self {
+        self.brand_color = Some(color);
+        self
+    }
+
+    /// Start from the dark theme, with the neutral scale reversed
+    pub fn with_dark_mode(mut self, dark: bool) -> Self {
+        self.dark = dark;
+        self
+    }
+
+    pub fn build(&self) -> CSSVariables {
+        let mut theme = if self.dark {
+            CSSVariables::dark_theme()
+        } else {
+            CSSVariables::default()
+        };
+        let Some(brand) = self.brand_color else {
+            return theme;
+        };
+
+        let brand_oklch = brand.to_oklch();
+        let tinted = |chroma: f64| {
+            Color::from_oklch(Oklch {
+                c: chroma,
+                ..brand_oklch
+            })
+        };
+        let neutral = generate_palette(tinted(brand_oklch.c.min(NEUTRAL_CHROMA)));
+
+        theme.primary = generate_palette(brand).into();
+        theme.secondary = generate_palette(tinted(brand_oklch.c * SECONDARY_CHROMA)).into();
+        theme.neutral = if self.dark {
+            neutral.reversed().into()
+        } else {
+            neutral.into()
+        };
+        theme
+    }
+}
+
 /// Get themes organized by categories
 pub fn get_themes_by_categories(
     categories: &[ThemeCategory],
@@ -398,4 +469,28 @@ mod theme_builder_tests {
             assert!(!display_name.is_empty());
         });
     }
+
+    #[test]
+    fn test_theme_builder_from_brand_color() {
+        assert_eq!(ThemeBuilder::new().build(), CSSVariables::default());
+
+        let brand = Color::from_hex("#0ea5e9").unwrap();
+        let theme = ThemeBuilder::new().with_brand_color(brand).build();
+        let scale = generate_palette(brand);
+        assert_eq!(theme.primary.primary_50, scale.shade(50).unwrap().to_hex());
+        assert_eq!(
+            theme.primary.primary_950,
+            scale.shade(950).unwrap().to_hex()
+        );
+        assert!(scale.iter().any(|(_, shade)| shade == brand));
+        assert_ne!(theme.secondary, CSSVariables::default().secondary);
+
+        // Dark themes put the darkest neutral first
+        let dark = ThemeBuilder::new()
+            .with_brand_color(brand)
+            .with_dark_mode(true)
+            .build();
+        assert_eq!(dark.neutral.neutral_50, theme.neutral.neutral_950);
+        assert_eq!(dark.primary, theme.primary);
+    }
 }
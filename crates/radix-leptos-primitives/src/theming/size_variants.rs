@@ -75,6 +75,18 @@ impl Size {
         }
     }
 
+    /// Get the icon width and height for the size
+    pub fn icon_size(&self) -> &'static str {
+        match self {
+            Size::Xs => "0.75rem", // 12px
+            Size::Sm => "1rem",    // 16px
+            Size::Md => "1.25rem", // 20px
+            Size::Lg => "1.5rem",  // 24px
+            Size::Xl => "2rem",    // 32px
+            Size::Xxl => "2.5rem", // 40px
+        }
+    }
+
     /// Get the padding for the size
     pub fn padding(&self) -> &'static str {
         match self {
@@ -88,6 +100,81 @@ impl Size {
     }
 }
 
+/// How tightly components are packed
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum Density {
+    Compact,
+    #[default]
+    Comfortable,
+    Spacious,
+}
+
+impl Density {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Density::Compact => "compact",
+            Density::Comfortable => "comfortable",
+            Density::Spacious => "spacious",
+        }
+    }
+
+    /// Get the CSS class for the density
+    pub fn class(&self) -> &'static str {
+        match self {
+            Density::Compact => "density-compact",
+            Density::Comfortable => "density-comfortable",
+            Density::Spacious => "density-spacious",
+        }
+    }
+
+    /// Factor paddings, heights and gaps are scaled by
+    pub fn scale(&self) -> f64 {
+        match self {
+            Density::Compact => 0.75,
+            Density::Comfortable => 1.0,
+            Density::Spacious => 1.25,
+        }
+    }
+
+    /// Scale each length in a space separated list such as `0.5rem 1rem`
+    pub fn apply(&self, lengths: &str) -> String {
+        lengths
+            .split_whitespace()
+            .map(|length| {
+                let unit_start = length
+                    .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+                    .unwrap_or(length.len());
+                let (number, unit) = length.split_at(unit_start);
+                match number.parse::<f64>() {
+                    Ok(number) => {
+                        let scaled = (number * self.scale() * 10_000.0).round() / 10_000.0;
+                        format!("{}{}", scaled, unit)
+                    }
+                    Err(_) => length.to_string(),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+impl Size {
+    /// Get the spacing value for the size at `density`
+    pub fn spacing_for(&self, density: Density) -> String {
+        density.apply(self.spacing())
+    }
+
+    /// Get the height for the size at `density`
+    pub fn height_for(&self, density: Density) -> String {
+        density.apply(self.height())
+    }
+
+    /// Get the padding for the size at `density`
+    pub fn padding_for(&self, density: Density) -> String {
+        density.apply(self.padding())
+    }
+}
+
 /// Variant system for components
 #[derive(Clone, Debug, PartialEq, Default)]
 pub enum Variant {
@@ -222,6 +309,51 @@ pub fn use_set_variant() -> Option<Callback<Variant>> {
     use_size_variant().map(|ctx| ctx.set_variant)
 }
 
+/// Density provider component
+///
+/// Sets the density for every component below it, as context for
+/// [`use_density`] and as the `--density-scale` custom property for
+/// stylesheets.
+#[component]
+pub fn DensityProvider(
+    /// Density for components
+    #[prop(optional, into)]
+    density: Signal<Density>,
+    /// Children content
+    children: Option<Children>,
+) -> impl IntoView {
+    provide_context(DensityContext { density });
+
+    view! {
+        <div
+            class=move || format!("density-provider {}", density.get().class())
+            style=move || format!("display: contents; --density-scale: {};", density.get().scale())
+            data-density=move || density.get().as_str()
+        >
+            {children.map(|c| c())}
+        </div>
+    }
+}
+
+/// Density context
+#[derive(Clone, Copy)]
+pub struct DensityContext {
+    pub density: Signal<Density>,
+}
+
+/// Hook for the density a component renders at
+///
+/// A component's own `density` wins over the closest DensityProvider's.
+pub fn use_density(density: MaybeProp<Density>) -> Signal<Density> {
+    let context = use_context::<DensityContext>();
+    Signal::derive(move || {
+        density
+            .get()
+            .or_else(|| context.map(|context| context.density.get()))
+            .unwrap_or_default()
+    })
+}
+
 /// Size selector component
 #[component]
 pub fn SizeSelector(
@@ -424,7 +556,20 @@ pub fn SizeVariantPreview(
 
 #[cfg(test)]
 mod tests {
-    use crate::theming::{Size, Variant};
+    use crate::theming::{Density, Size, Variant};
+
+    #[test]
+    fn test_density_scaling() {
+        assert_eq!(Density::default(), Density::Comfortable);
+        assert_eq!(
+            Size::Md.padding_for(Density::Comfortable),
+            Size::Md.padding()
+        );
+        assert_eq!(Size::Md.padding_for(Density::Compact), "0.5625rem 0.75rem");
+        assert_eq!(Size::Md.height_for(Density::Spacious), "3.125rem");
+        assert_eq!(Size::Sm.spacing_for(Density::Compact), "0.375rem");
+        assert_eq!(Density::Compact.apply("1px auto"), "0.75px auto");
+    }
 
     #[test]
     fn test_size_enum() {
@@ -446,6 +591,13 @@ mod tests {
         assert_eq!(Size::Xxl.spacing(), "3rem");
     }
 
+    #[test]
+    fn test_size_icon_size() {
+        assert_eq!(Size::Xs.icon_size(), "0.75rem");
+        assert_eq!(Size::Md.icon_size(), "1.25rem");
+        assert_eq!(Size::Xxl.icon_size(), "2.5rem");
+    }
+
     #[test]
     fn test_size_font_size() {
         assert_eq!(Size::Xs.font_size(), "0.75rem");
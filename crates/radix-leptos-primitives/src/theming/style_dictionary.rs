@@ -0,0 +1,265 @@
+//! Export of themes as Style Dictionary token sources
+//!
+//! Tokens follow Style Dictionary's category / type / item structure, e.g.
+//! `color.primary.500` or `size.spacing.4`, so its built-in transforms for
+//! Android, iOS and Compose apply to them without extra configuration.
+
+use super::css_variables::CSSVariables;
+use super::prebuilt_themes::PrebuiltThemes;
+use leptos::serde_json::{self, Map, Value};
+
+/// Where the variables of a theme group starting with `prefix` go
+///
+/// A variable matching several groups goes to the one with the longest
+/// prefix, and one matching the prefix exactly is the token at `path`.
+struct SourceGroup {
+    group: &'static str,
+    prefix: &'static str,
+    path: &'static [&'static str],
+    token_type: &'static str,
+}
+
+const SOURCE_GROUPS: &[SourceGroup] = &[
+    SourceGroup {
+        group: "primary",
+        prefix: "primary_",
+        path: &["color", "primary"],
+        token_type: "color",
+    },
+    SourceGroup {
+        group: "secondary",
+        prefix: "secondary_",
+        path: &["color", "secondary"],
+        token_type: "color",
+    },
+    SourceGroup {
+        group: "neutral",
+        prefix: "neutral_",
+        path: &["color", "neutral"],
+        token_type: "color",
+    },
+    SourceGroup {
+        group: "semantic",
+        prefix: "",
+        path: &["color"],
+        token_type: "color",
+    },
+    SourceGroup {
+        group: "typography",
+        prefix: "font_family_",
+        path: &["font", "family"],
+        token_type: "fontFamily",
+    },
+    SourceGroup {
+        group: "typography",
+        prefix: "font_size_",
+        path: &["size", "font"],
+        token_type: "dimension",
+    },
+    SourceGroup {
+        group: "typography",
+        prefix: "font_weight_",
+        path: &["font", "weight"],
+        token_type: "fontWeight",
+    },
+    SourceGroup {
+        group: "typography",
+        prefix: "line_height_",
+        path: &["font", "line-height"],
+        token_type: "number",
+    },
+    SourceGroup {
+        group: "spacing",
+        prefix: "space_",
+        path: &["size", "spacing"],
+        token_type: "dimension",
+    },
+    SourceGroup {
+        group: "border",
+        prefix: "border_width_",
+        path: &["size", "border-width"],
+        token_type: "dimension",
+    },
+    SourceGroup {
+        group: "border",
+        prefix: "border_radius_",
+        path: &["size", "border-radius"],
+        token_type: "dimension",
+    },
+    SourceGroup {
+        group: "border",
+        prefix: "focus_ring_",
+        path: &["size", "focus-ring"],
+        token_type: "dimension",
+    },
+    SourceGroup {
+        group: "border",
+        prefix: "focus_ring_color",
+        path: &["color", "focus-ring"],
+        token_type: "color",
+    },
+    SourceGroup {
+        group: "shadow",
+        prefix: "shadow_",
+        path: &["shadow"],
+        token_type: "shadow",
+    },
+    SourceGroup {
+        group: "animation",
+        prefix: "duration_",
+        path: &["time", "duration"],
+        token_type: "duration",
+    },
+    SourceGroup {
+        group: "animation",
+        prefix: "ease_",
+        path: &["time", "easing"],
+        token_type: "cubicBezier",
+    },
+];
+
+impl CSSVariables {
+    /// Export the theme as a Style Dictionary token source file
+    ///
+    /// Each token has the CSS `value` and a `type`. Its `comment` names the
+    /// CSS variable the web components read, since Style Dictionary's own
+    /// naming (`--color-primary-500`) includes the category.
+    pub fn to_style_dictionary(&self) -> Value {
+        let fields = serde_json::to_value(self).unwrap_or_default();
+        let mut source = Map::new();
+        for source_group in SOURCE_GROUPS {
+            let Some(values) = fields.get(source_group.group).and_then(Value::as_object) else {
+                continue;
+            };
+            for (field, value) in values {
+                let Some(item) = field.strip_prefix(source_group.prefix) else {
+                    continue;
+                };
+                let more_specific = SOURCE_GROUPS.iter().any(|other| {
+                    other.group == source_group.group
+                        && other.prefix.len() > source_group.prefix.len()
+                        && field.starts_with(other.prefix)
+                });
+                if more_specific {
+                    continue;
+                }
+                let (path, item) = match source_group.path.split_last() {
+                    Some((last, parent)) if item.is_empty() => (parent, *last),
+                    _ => (source_group.path, item),
+                };
+                let Some(group) = token_group(&mut source, path) else {
+                    continue;
+                };
+                let mut token = Map::new();
+                token.insert("value".to_string(), value.clone());
+                token.insert("type".to_string(), source_group.token_type.into());
+                token.insert(
+                    "comment".to_string(),
+                    format!("--{}", field.replace('_', "-")).into(),
+                );
+                group.insert(item.replace('_', "-"), Value::Object(token));
+            }
+        }
+        Value::Object(source)
+    }
+}
+
+/// A Style Dictionary token source file
+#[derive(Debug, Clone, PartialEq)]
+pub struct StyleDictionarySource {
+    /// Path of the file, relative to the Style Dictionary project
+    pub path: &'static str,
+    pub tokens: Value,
+}
+
+impl PrebuiltThemes {
+    /// Style Dictionary source files for the light and dark themes, for a
+    /// build that outputs one platform theme per file
+    pub fn to_style_dictionary(&self) -> Vec<StyleDictionarySource> {
+        vec![
+            StyleDictionarySource {
+                path: "tokens/light.json",
+                tokens: self.light.to_style_dictionary(),
+            },
+            StyleDictionarySource {
+                path: "tokens/dark.json",
+                tokens: self.dark.to_style_dictionary(),
+            },
+        ]
+    }
+}
+
+type TokenMap = Map<String, Value>;
+
+/// The group at `path`, created if missing
+fn token_group<'a>(source: &'a mut TokenMap, path: &[&str]) -> Option<&'a mut TokenMap> {
+    let Some((name, rest)) = path.split_first() else {
+        return Some(source);
+    };
+    let group = source
+        .entry(*name)
+        .or_insert_with(|| Value::Object(Map::new()))
+        .as_object_mut()?;
+    token_group(group, rest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_style_dictionary_export() {
+        let theme = CSSVariables::default();
+        let source = theme.to_style_dictionary();
+
+        assert_eq!(
+            source["color"]["primary"]["500"]["value"],
+            theme.primary.primary_500
+        );
+        assert_eq!(source["color"]["primary"]["500"]["type"], "color");
+        assert_eq!(source["color"]["success"]["value"], theme.semantic.success);
+        assert_eq!(
+            source["size"]["spacing"]["4"]["value"],
+            theme.spacing.space_4
+        );
+        assert_eq!(
+            source["size"]["border-radius"]["md"]["comment"],
+            "--border-radius-md"
+        );
+        assert_eq!(
+            source["font"]["line-height"]["normal"]["value"],
+            theme.typography.line_height_normal
+        );
+        assert_eq!(
+            source["time"]["easing"]["in-out"]["value"],
+            theme.animation.ease_in_out
+        );
+        assert_eq!(
+            source["size"]["focus-ring"]["width"]["value"],
+            theme.border.focus_ring_width
+        );
+        assert_eq!(source["color"]["focus-ring"]["type"], "color");
+        assert_eq!(
+            source["color"]["focus-ring"]["comment"],
+            "--focus-ring-color"
+        );
+
+        // Every variable is exported once
+        let mut count = 0;
+        theme.for_each_variable(|_, _| count += 1);
+        assert_eq!(
+            serde_json::to_string(&source)
+                .unwrap()
+                .matches("\"comment\"")
+                .count(),
+            count
+        );
+
+        let files = PrebuiltThemes::default().to_style_dictionary();
+        assert_eq!(files[1].path, "tokens/dark.json");
+        assert_eq!(
+            files[1].tokens,
+            PrebuiltThemes::default().dark.to_style_dictionary()
+        );
+    }
+}
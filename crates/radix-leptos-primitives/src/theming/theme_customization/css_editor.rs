@@ -6,12 +6,13 @@ use leptos::prelude::*;
 #[component]
 pub fn ColorCustomizer(
     /// Current theme
-    theme: ReadSignal<CSSVariables>,
+    #[prop(into)]
+    theme: Signal<CSSVariables>,
     /// Callback when theme changes
     on_change: Callback<CSSVariables>,
 ) -> impl IntoView {
     let handle_color_change = move |color_type: &str, value: String| {
-        let mut new_theme = theme.get();
+        let mut new_theme = theme.get_untracked();
         match color_type {
             "primary" => new_theme.primary.primary_500 = value,
             "primary-foreground" => new_theme.primary.primary_50 = value,
@@ -20,7 +21,6 @@ pub fn ColorCustomizer(
             "background" => new_theme.neutral.neutral_50 = value,
             "foreground" => new_theme.neutral.neutral_900 = value,
             "destructive" => new_theme.semantic.error = value,
-            "destructive-foreground" => new_theme.semantic.success = value,
             _ => {}
         }
         on_change.run(new_theme);
@@ -34,7 +34,7 @@ pub fn ColorCustomizer(
                     <label>"Primary"</label>
                     <input
                         type="color"
-                        value=move || theme.get().primary.primary_500
+                        prop:value=move || theme.get().primary.primary_500
                         on:change=move |ev| {
                             let value = event_target_value(&ev);
                             handle_color_change("primary", value);
@@ -45,7 +45,7 @@ pub fn ColorCustomizer(
                     <label>"Primary Foreground"</label>
                     <input
                         type="color"
-                        value=move || theme.get().primary.primary_50
+                        prop:value=move || theme.get().primary.primary_50
                         on:change=move |ev| {
                             let value = event_target_value(&ev);
                             handle_color_change("primary-foreground", value);
@@ -56,7 +56,7 @@ pub fn ColorCustomizer(
                     <label>"Secondary"</label>
                     <input
                         type="color"
-                        value=move || theme.get().secondary.secondary_500
+                        prop:value=move || theme.get().secondary.secondary_500
                         on:change=move |ev| {
                             let value = event_target_value(&ev);
                             handle_color_change("secondary", value);
@@ -67,7 +67,7 @@ pub fn ColorCustomizer(
                     <label>"Background"</label>
                     <input
                         type="color"
-                        value=move || theme.get().neutral.neutral_50
+                        prop:value=move || theme.get().neutral.neutral_50
                         on:change=move |ev| {
                             let value = event_target_value(&ev);
                             handle_color_change("background", value);
@@ -78,7 +78,7 @@ pub fn ColorCustomizer(
                     <label>"Foreground"</label>
                     <input
                         type="color"
-                        value=move || theme.get().neutral.neutral_900
+                        prop:value=move || theme.get().neutral.neutral_900
                         on:change=move |ev| {
                             let value = event_target_value(&ev);
                             handle_color_change("foreground", value);
@@ -89,7 +89,7 @@ pub fn ColorCustomizer(
                     <label>"Destructive"</label>
                     <input
                         type="color"
-                        value=move || theme.get().semantic.error
+                        prop:value=move || theme.get().semantic.error
                         on:change=move |ev| {
                             let value = event_target_value(&ev);
                             handle_color_change("destructive", value);
@@ -105,12 +105,13 @@ pub fn ColorCustomizer(
 #[component]
 pub fn TypographyCustomizer(
     /// Current theme
-    theme: ReadSignal<CSSVariables>,
+    #[prop(into)]
+    theme: Signal<CSSVariables>,
     /// Callback when theme changes
     on_change: Callback<CSSVariables>,
 ) -> impl IntoView {
     let handle_typography_change = move |typography_type: &str, value: String| {
-        let mut new_theme = theme.get();
+        let mut new_theme = theme.get_untracked();
         match typography_type {
             "font-size-base" => new_theme.typography.font_size_base = value,
             "font-size-lg" => new_theme.typography.font_size_lg = value,
@@ -132,7 +133,7 @@ pub fn TypographyCustomizer(
                     <label>"Base Font Size"</label>
                     <input
                         type="text"
-                        value=move || theme.get().typography.font_size_base
+                        prop:value=move || theme.get().typography.font_size_base
                         on:change=move |ev| {
                             let value = event_target_value(&ev);
                             handle_typography_change("font-size-base", value);
@@ -143,7 +144,7 @@ pub fn TypographyCustomizer(
                     <label>"Large Font Size"</label>
                     <input
                         type="text"
-                        value=move || theme.get().typography.font_size_lg
+                        prop:value=move || theme.get().typography.font_size_lg
                         on:change=move |ev| {
                             let value = event_target_value(&ev);
                             handle_typography_change("font-size-lg", value);
@@ -154,7 +155,7 @@ pub fn TypographyCustomizer(
                     <label>"Extra Large Font Size"</label>
                     <input
                         type="text"
-                        value=move || theme.get().typography.font_size_xl
+                        prop:value=move || theme.get().typography.font_size_xl
                         on:change=move |ev| {
                             let value = event_target_value(&ev);
                             handle_typography_change("font-size-xl", value);
@@ -213,7 +214,7 @@ pub fn TypographyCustomizer(
                     <label>"Line Height"</label>
                     <input
                         type="text"
-                        value=move || theme.get().typography.line_height_normal
+                        prop:value=move || theme.get().typography.line_height_normal
                         on:change=move |ev| {
                             let value = event_target_value(&ev);
                             handle_typography_change("line-height-normal", value);
@@ -229,12 +230,13 @@ pub fn TypographyCustomizer(
 #[component]
 pub fn SpacingCustomizer(
     /// Current theme
-    theme: ReadSignal<CSSVariables>,
+    #[prop(into)]
+    theme: Signal<CSSVariables>,
     /// Callback when theme changes
     on_change: Callback<CSSVariables>,
 ) -> impl IntoView {
     let handle_spacing_change = move |spacing_type: &str, value: String| {
-        let mut new_theme = theme.get();
+        let mut new_theme = theme.get_untracked();
         match spacing_type {
             "spacing-sm" => new_theme.spacing.space_2 = value,
             "spacing-md" => new_theme.spacing.space_4 = value,
@@ -253,7 +255,7 @@ pub fn SpacingCustomizer(
                     <label>"Small Spacing"</label>
                     <input
                         type="text"
-                        value=move || theme.get().spacing.space_2
+                        prop:value=move || theme.get().spacing.space_2
                         on:change=move |ev| {
                             let value = event_target_value(&ev);
                             handle_spacing_change("spacing-sm", value);
@@ -264,7 +266,7 @@ pub fn SpacingCustomizer(
                     <label>"Medium Spacing"</label>
                     <input
                         type="text"
-                        value=move || theme.get().spacing.space_4
+                        prop:value=move || theme.get().spacing.space_4
                         on:change=move |ev| {
                             let value = event_target_value(&ev);
                             handle_spacing_change("spacing-md", value);
@@ -275,7 +277,7 @@ pub fn SpacingCustomizer(
                     <label>"Large Spacing"</label>
                     <input
                         type="text"
-                        value=move || theme.get().spacing.space_8
+                        prop:value=move || theme.get().spacing.space_8
                         on:change=move |ev| {
                             let value = event_target_value(&ev);
                             handle_spacing_change("spacing-lg", value);
@@ -286,7 +288,7 @@ pub fn SpacingCustomizer(
                     <label>"Extra Large Spacing"</label>
                     <input
                         type="text"
-                        value=move || theme.get().spacing.space_16
+                        prop:value=move || theme.get().spacing.space_16
                         on:change=move |ev| {
                             let value = event_target_value(&ev);
                             handle_spacing_change("spacing-xl", value);
@@ -302,12 +304,13 @@ pub fn SpacingCustomizer(
 #[component]
 pub fn BorderRadiusCustomizer(
     /// Current theme
-    theme: ReadSignal<CSSVariables>,
+    #[prop(into)]
+    theme: Signal<CSSVariables>,
     /// Callback when theme changes
     on_change: Callback<CSSVariables>,
 ) -> impl IntoView {
     let handle_border_radius_change = move |radius_type: &str, value: String| {
-        let mut new_theme = theme.get();
+        let mut new_theme = theme.get_untracked();
         match radius_type {
             "radius-sm" => new_theme.border.border_radius_sm = value,
             "radius-md" => new_theme.border.border_radius_md = value,
@@ -326,7 +329,7 @@ pub fn BorderRadiusCustomizer(
                     <label>"Small Radius"</label>
                     <input
                         type="text"
-                        value=move || theme.get().border.border_radius_sm
+                        prop:value=move || theme.get().border.border_radius_sm
                         on:change=move |ev| {
                             let value = event_target_value(&ev);
                             handle_border_radius_change("radius-sm", value);
@@ -337,7 +340,7 @@ pub fn BorderRadiusCustomizer(
                     <label>"Medium Radius"</label>
                     <input
                         type="text"
-                        value=move || theme.get().border.border_radius_md
+                        prop:value=move || theme.get().border.border_radius_md
                         on:change=move |ev| {
                             let value = event_target_value(&ev);
                             handle_border_radius_change("radius-md", value);
@@ -348,7 +351,7 @@ pub fn BorderRadiusCustomizer(
                     <label>"Large Radius"</label>
                     <input
                         type="text"
-                        value=move || theme.get().border.border_radius_lg
+                        prop:value=move || theme.get().border.border_radius_lg
                         on:change=move |ev| {
                             let value = event_target_value(&ev);
                             handle_border_radius_change("radius-lg", value);
@@ -359,7 +362,7 @@ pub fn BorderRadiusCustomizer(
                     <label>"Extra Large Radius"</label>
                     <input
                         type="text"
-                        value=move || theme.get().border.border_radius_xl
+                        prop:value=move || theme.get().border.border_radius_xl
                         on:change=move |ev| {
                             let value = event_target_value(&ev);
                             handle_border_radius_change("radius-xl", value);
@@ -375,12 +378,13 @@ pub fn BorderRadiusCustomizer(
 #[component]
 pub fn ShadowCustomizer(
     /// Current theme
-    theme: ReadSignal<CSSVariables>,
+    #[prop(into)]
+    theme: Signal<CSSVariables>,
     /// Callback when theme changes
     on_change: Callback<CSSVariables>,
 ) -> impl IntoView {
     let handle_shadow_change = move |shadow_type: &str, value: String| {
-        let mut new_theme = theme.get();
+        let mut new_theme = theme.get_untracked();
         match shadow_type {
             "shadow-sm" => new_theme.shadow.shadow_sm = value,
             "shadow-md" => new_theme.shadow.shadow_md = value,
@@ -399,7 +403,7 @@ pub fn ShadowCustomizer(
                     <label>"Small Shadow"</label>
                     <input
                         type="text"
-                        value=move || theme.get().shadow.shadow_sm
+                        prop:value=move || theme.get().shadow.shadow_sm
                         on:change=move |ev| {
                             let value = event_target_value(&ev);
                             handle_shadow_change("shadow-sm", value);
@@ -410,7 +414,7 @@ pub fn ShadowCustomizer(
                     <label>"Medium Shadow"</label>
                     <input
                         type="text"
-                        value=move || theme.get().shadow.shadow_md
+                        prop:value=move || theme.get().shadow.shadow_md
                         on:change=move |ev| {
                             let value = event_target_value(&ev);
                             handle_shadow_change("shadow-md", value);
@@ -421,7 +425,7 @@ pub fn ShadowCustomizer(
                     <label>"Large Shadow"</label>
                     <input
                         type="text"
-                        value=move || theme.get().shadow.shadow_lg
+                        prop:value=move || theme.get().shadow.shadow_lg
                         on:change=move |ev| {
                             let value = event_target_value(&ev);
                             handle_shadow_change("shadow-lg", value);
@@ -432,7 +436,7 @@ pub fn ShadowCustomizer(
                     <label>"Extra Large Shadow"</label>
                     <input
                         type="text"
-                        value=move || theme.get().shadow.shadow_xl
+                        prop:value=move || theme.get().shadow.shadow_xl
                         on:change=move |ev| {
                             let value = event_target_value(&ev);
                             handle_shadow_change("shadow-xl", value);
@@ -448,12 +452,13 @@ pub fn ShadowCustomizer(
 #[component]
 pub fn AnimationCustomizer(
     /// Current theme
-    theme: ReadSignal<CSSVariables>,
+    #[prop(into)]
+    theme: Signal<CSSVariables>,
     /// Callback when theme changes
     on_change: Callback<CSSVariables>,
 ) -> impl IntoView {
     let handle_animation_change = move |animation_type: &str, value: String| {
-        let mut new_theme = theme.get();
+        let mut new_theme = theme.get_untracked();
         match animation_type {
             "duration-fast" => new_theme.animation.duration_150 = value,
             "duration-normal" => new_theme.animation.duration_300 = value,
@@ -474,7 +479,7 @@ pub fn AnimationCustomizer(
                     <label>"Fast Duration"</label>
                     <input
                         type="text"
-                        value=move || theme.get().animation.duration_150
+                        prop:value=move || theme.get().animation.duration_150
                         on:change=move |ev| {
                             let value = event_target_value(&ev);
                             handle_animation_change("duration-fast", value);
@@ -485,7 +490,7 @@ pub fn AnimationCustomizer(
                     <label>"Normal Duration"</label>
                     <input
                         type="text"
-                        value=move || theme.get().animation.duration_300
+                        prop:value=move || theme.get().animation.duration_300
                         on:change=move |ev| {
                             let value = event_target_value(&ev);
                             handle_animation_change("duration-normal", value);
@@ -496,7 +501,7 @@ pub fn AnimationCustomizer(
                     <label>"Slow Duration"</label>
                     <input
                         type="text"
-                        value=move || theme.get().animation.duration_500
+                        prop:value=move || theme.get().animation.duration_500
                         on:change=move |ev| {
                             let value = event_target_value(&ev);
                             handle_animation_change("duration-slow", value);
@@ -507,7 +512,7 @@ pub fn AnimationCustomizer(
                     <label>"Easing In"</label>
                     <input
                         type="text"
-                        value=move || theme.get().animation.ease_in
+                        prop:value=move || theme.get().animation.ease_in
                         on:change=move |ev| {
                             let value = event_target_value(&ev);
                             handle_animation_change("easing-in", value);
@@ -518,7 +523,7 @@ pub fn AnimationCustomizer(
                     <label>"Easing Out"</label>
                     <input
                         type="text"
-                        value=move || theme.get().animation.ease_out
+                        prop:value=move || theme.get().animation.ease_out
                         on:change=move |ev| {
                             let value = event_target_value(&ev);
                             handle_animation_change("easing-out", value);
@@ -529,7 +534,7 @@ pub fn AnimationCustomizer(
                     <label>"Easing In-Out"</label>
                     <input
                         type="text"
-                        value=move || theme.get().animation.ease_in_out
+                        prop:value=move || theme.get().animation.ease_in_out
                         on:change=move |ev| {
                             let value = event_target_value(&ev);
                             handle_animation_change("easing-in-out", value);
@@ -0,0 +1,80 @@
+/// Number of edits an [`EditHistory`] keeps to undo unless told otherwise
+pub const DEFAULT_HISTORY_LIMIT: usize = 100;
+
+/// Undo and redo for a value edited step by step, such as the theme in a
+/// ThemeCustomizer
+#[derive(Debug, Clone, PartialEq)]
+pub struct EditHistory<T> {
+    past: Vec<T>,
+    present: T,
+    future: Vec<T>,
+    limit: usize,
+}
+
+impl<T: Clone + PartialEq> EditHistory<T> {
+    pub fn new(present: T) -> Self {
+        Self {
+            past: Vec::new(),
+            present,
+            future: Vec::new(),
+            limit: DEFAULT_HISTORY_LIMIT,
+        }
+    }
+
+    /// Keep at most `limit` edits to undo, dropping the oldest
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self.trim();
+        self
+    }
+
+    pub fn present(&self) -> &T {
+        &self.present
+    }
+
+    /// Make `value` the present value, discarding the edits that were undone
+    ///
+    /// Returns whether the edit was recorded; edits that change nothing
+    /// aren't.
+    pub fn edit(&mut self, value: T) -> bool {
+        if value == self.present {
+            return false;
+        }
+        self.past.push(std::mem::replace(&mut self.present, value));
+        self.future.clear();
+        self.trim();
+        true
+    }
+
+    /// Go back to the value before the last edit, if there is one
+    pub fn undo(&mut self) -> bool {
+        let Some(previous) = self.past.pop() else {
+            return false;
+        };
+        self.future
+            .push(std::mem::replace(&mut self.present, previous));
+        true
+    }
+
+    /// Reapply the last undone edit, if there is one
+    pub fn redo(&mut self) -> bool {
+        let Some(next) = self.future.pop() else {
+            return false;
+        };
+        self.past.push(std::mem::replace(&mut self.present, next));
+        true
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.past.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.future.is_empty()
+    }
+
+    fn trim(&mut self) {
+        let excess = self.past.len().saturating_sub(self.limit);
+        self.past.drain(..excess);
+    }
+}
@@ -1,14 +1,19 @@
-use crate::theming::CSSVariables;
+use crate::theming::{CSSVariables, ThemeValidator, WcagLevel};
 use leptos::callback::Callback;
 use leptos::prelude::*;
-use leptos::serde_json;
 
 // Re-export all components and functions
 pub use css_editor::*;
+pub use edit_history::*;
 pub use theme_export::*;
+pub use theme_import::*;
+pub use theme_migration::*;
 
 mod css_editor;
+mod edit_history;
 mod theme_export;
+mod theme_import;
+mod theme_migration;
 
 /// Theme customization component
 #[component]
@@ -37,6 +42,15 @@ pub fn ThemeCustomizer(
     /// Callback when theme changes
     #[prop(optional)]
     on_theme_change: Option<Callback<CSSVariables>>,
+    /// Format the Export button produces
+    #[prop(optional)]
+    export_format: Option<ExportFormat>,
+    /// Receives the exported file contents
+    #[prop(optional)]
+    on_export: Option<Callback<String>>,
+    /// WCAG level the theme's color contrast is checked against
+    #[prop(optional)]
+    contrast_level: Option<WcagLevel>,
     /// Additional CSS classes
     #[prop(optional)]
     class: Option<String>,
@@ -51,15 +65,44 @@ pub fn ThemeCustomizer(
     let show_border_radius = show_border_radius.unwrap_or(true);
     let show_shadows = show_shadows.unwrap_or(true);
     let show_animations = show_animations.unwrap_or(true);
+    let export_format = export_format.unwrap_or_default();
+    let validator = ThemeValidator::new(contrast_level.unwrap_or_default());
 
-    let (current_theme, setcurrent_theme) = signal(initial_theme);
+    // Every change goes through the history, so it can be undone
+    let history = RwSignal::new(EditHistory::new(initial_theme));
+    let current_theme = Memo::new(move |_| history.with(|history| history.present().clone()));
 
     let handle_theme_change = Callback::new(move |new_theme: CSSVariables| {
-        setcurrent_theme.set(new_theme.clone());
-        if let Some(callback) = on_theme_change {
+        let edited = history
+            .try_update(|history| history.edit(new_theme.clone()))
+            .unwrap_or(false);
+        if let (true, Some(callback)) = (edited, on_theme_change) {
             callback.run(new_theme);
         }
     });
+    let step = move |undo: bool| {
+        let moved = history
+            .try_update(|history| if undo { history.undo() } else { history.redo() })
+            .unwrap_or(false);
+        if let (true, Some(callback)) = (moved, on_theme_change) {
+            callback.run(current_theme.get_untracked());
+        }
+    };
+
+    // Pasted theme JSON and what was wrong with it
+    let (import_open, set_import_open) = signal(false);
+    let (import_text, set_import_text) = signal(String::new());
+    let (import_errors, set_import_errors) = signal(Vec::<String>::new());
+    let apply_import = move |_| match CSSVariables::from_json_str(&import_text.get()) {
+        Ok(theme) => {
+            handle_theme_change.run(theme);
+            set_import_errors.set(Vec::new());
+            set_import_open.set(false);
+        }
+        Err(errors) => set_import_errors.set(errors.iter().map(ToString::to_string).collect()),
+    };
+
+    let contrast_issues = Memo::new(move |_| validator.validate(&current_theme.get()));
 
     let class = format!("theme-customizer {}", class.unwrap_or_default());
 
@@ -139,6 +182,20 @@ pub fn ThemeCustomizer(
             </div>
 
             <div class="theme-customizer-actions">
+                <button
+                    class="undo-button"
+                    disabled=move || !history.with(EditHistory::can_undo)
+                    on:click=move |_| step(true)
+                >
+                    "Undo"
+                </button>
+                <button
+                    class="redo-button"
+                    disabled=move || !history.with(EditHistory::can_redo)
+                    on:click=move |_| step(false)
+                >
+                    "Redo"
+                </button>
                 <button
                     class="reset-button"
                     on:click=move |_| {
@@ -150,15 +207,70 @@ pub fn ThemeCustomizer(
                 </button>
                 <button
                     class="export-button"
+                    data-format=export_format.as_str()
                     on:click=move |_| {
-                        let theme_json = serde_json::to_string(&current_theme.get()).unwrap_or_default();
-                        // In a real implementation, this would export the theme
-                        log::info!("Theme exported: {}", theme_json);
+                        let exported = export_theme(&current_theme.get_untracked(), export_format);
+                        match on_export {
+                            Some(on_export) => on_export.run(exported),
+                            None => log::info!("Theme exported: {}", exported),
+                        }
                     }
                 >
                     "Export Theme"
                 </button>
+                <button
+                    class="import-button"
+                    aria-expanded=move || import_open.get().to_string()
+                    on:click=move |_| set_import_open.update(|open| *open = !*open)
+                >
+                    "Import Theme"
+                </button>
             </div>
+
+            <Show when=move || import_open.get()>
+                <div class="theme-import">
+                    <textarea
+                        class="theme-import-input"
+                        aria-label="Theme JSON"
+                        prop:value=move || import_text.get()
+                        on:input=move |ev| set_import_text.set(event_target_value(&ev))
+                    ></textarea>
+                    <ul class="theme-import-errors" role="alert">
+                        {move || import_errors.get().into_iter().map(|error| view! { <li>{error}</li> }).collect_view()}
+                    </ul>
+                    <button class="apply-import-button" on:click=apply_import>
+                        "Apply"
+                    </button>
+                </div>
+            </Show>
+
+            <Show when=move || !contrast_issues.get().is_empty()>
+                <div class="theme-contrast" role="status" data-level=validator.level().as_str()>
+                    <h4>"Contrast issues"</h4>
+                    <ul class="theme-contrast-issues">
+                        {move || contrast_issues.get().into_iter().map(|issue| {
+                            let label = issue.to_string();
+                            let suggestion = issue.suggestion.map(|color| {
+                                let hex = color.to_hex();
+                                view! {
+                                    <button
+                                        class="contrast-suggestion"
+                                        style=format!("--suggested-color: {hex}")
+                                        on:click=move |_| {
+                                            if let Some(theme) = issue.apply_suggestion(&current_theme.get_untracked()) {
+                                                handle_theme_change.run(theme);
+                                            }
+                                        }
+                                    >
+                                        {format!("Use {hex}")}
+                                    </button>
+                                }
+                            });
+                            view! { <li>{label} {suggestion}</li> }
+                        }).collect_view()}
+                    </ul>
+                </div>
+            </Show>
         </div>
     }
 }
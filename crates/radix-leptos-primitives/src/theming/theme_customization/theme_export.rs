@@ -1,6 +1,7 @@
-use crate::theming::CSSVariables;
+use super::{SCHEMA_VERSION_KEY, THEME_SCHEMA_VERSION};
+use crate::theming::{CSSVariables, LayoutSystem};
 use leptos::prelude::*;
-use leptos::serde_json;
+use leptos::serde_json::{self, json, Map, Value};
 
 /// Theme export preview component
 #[component]
@@ -52,9 +53,19 @@ pub fn ThemeExportPreview(
     }
 }
 
-/// Export theme as JSON string
+/// Export theme as JSON string, tagged with the
+/// [`THEME_SCHEMA_VERSION`](super::THEME_SCHEMA_VERSION) it was written in
 pub fn export_theme_json(theme: &CSSVariables) -> String {
-    serde_json::to_string(theme).unwrap_or_default()
+    theme_document(theme).to_string()
+}
+
+/// Theme as the versioned document [`export_theme_json`] writes
+pub(crate) fn theme_document(theme: &CSSVariables) -> Value {
+    let mut document = serde_json::to_value(theme).unwrap_or_default();
+    if let Some(document) = document.as_object_mut() {
+        document.insert(SCHEMA_VERSION_KEY.to_string(), THEME_SCHEMA_VERSION.into());
+    }
+    document
 }
 
 /// Export theme as CSS variables
@@ -66,3 +77,153 @@ pub fn export_theme_css(theme: &CSSVariables) -> String {
 pub fn export_theme_js(theme: &CSSVariables) -> String {
     format!("const theme = {};", serde_json::to_string(theme).unwrap_or_default())
 }
+
+/// File format for [`export_theme`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExportFormat {
+    /// CSS custom properties on `:root`
+    #[default]
+    Css,
+    /// SCSS `$variables`
+    Scss,
+    /// LESS `@variables`
+    Less,
+    Json,
+    JavaScript,
+    /// A `tailwind.config.js` module
+    Tailwind,
+    /// A Style Dictionary token source
+    StyleDictionary,
+}
+
+impl ExportFormat {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExportFormat::Css => "css",
+            ExportFormat::Scss => "scss",
+            ExportFormat::Less => "less",
+            ExportFormat::Json => "json",
+            ExportFormat::JavaScript => "js",
+            ExportFormat::Tailwind => "tailwind",
+            ExportFormat::StyleDictionary => "style-dictionary",
+        }
+    }
+
+    /// Suggested name for the exported file
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            ExportFormat::Css => "theme.css",
+            ExportFormat::Scss => "_theme.scss",
+            ExportFormat::Less => "theme.less",
+            ExportFormat::Json => "theme.json",
+            ExportFormat::JavaScript => "theme.js",
+            ExportFormat::Tailwind => "tailwind.config.js",
+            ExportFormat::StyleDictionary => "tokens.json",
+        }
+    }
+}
+
+/// Export theme as a file in the given format
+///
+/// The stylesheet formats use the same variable names, so `--primary-500`
+/// becomes `$primary-500` in SCSS and `@primary-500` in LESS.
+pub fn export_theme(theme: &CSSVariables, format: ExportFormat) -> String {
+    match format {
+        ExportFormat::Css => format!(
+            ":root {{\n{}}}\n",
+            format_declarations(theme, |name, value| format!("  --{name}: {value};\n"))
+        ),
+        ExportFormat::Scss => {
+            format_declarations(theme, |name, value| format!("${name}: {value};\n"))
+        }
+        ExportFormat::Less => {
+            format_declarations(theme, |name, value| format!("@{name}: {value};\n"))
+        }
+        ExportFormat::Json => export_theme_json(theme),
+        ExportFormat::JavaScript => export_theme_js(theme),
+        ExportFormat::Tailwind => export_tailwind_config_js(theme, None),
+        ExportFormat::StyleDictionary => {
+            serde_json::to_string_pretty(&theme.to_style_dictionary()).unwrap_or_default()
+        }
+    }
+}
+
+/// Format each CSS variable, by name without the leading `--` and value
+fn format_declarations(theme: &CSSVariables, line: impl Fn(&str, &str) -> String) -> String {
+    let mut declarations = String::new();
+    theme.for_each_variable(|name, value| declarations.push_str(&line(name, value)));
+    declarations
+}
+
+/// Export theme as a Tailwind CSS config object
+///
+/// Colors, spacing, radii, shadows, typography and transitions go under
+/// `theme.extend`, so Tailwind's own utilities stay available. Breakpoints
+/// from `layout` become `screens`.
+pub fn export_tailwind_config(theme: &CSSVariables, layout: Option<&LayoutSystem>) -> Value {
+    let fields = serde_json::to_value(theme).unwrap_or_default();
+    let scale = |group: &str, prefix: &str| Value::Object(tailwind_scale(&fields, group, prefix));
+
+    let mut colors = Map::new();
+    colors.insert("primary".to_string(), scale("primary", "primary_"));
+    colors.insert("secondary".to_string(), scale("secondary", "secondary_"));
+    colors.insert("neutral".to_string(), scale("neutral", "neutral_"));
+    colors.extend(tailwind_scale(&fields, "semantic", ""));
+
+    let mut extend = json!({
+        "colors": colors,
+        "spacing": scale("spacing", "space_"),
+        "borderRadius": scale("border", "border_radius_"),
+        "borderWidth": scale("border", "border_width_"),
+        "boxShadow": scale("shadow", "shadow_"),
+        "fontFamily": scale("typography", "font_family_"),
+        "fontSize": scale("typography", "font_size_"),
+        "fontWeight": scale("typography", "font_weight_"),
+        "lineHeight": scale("typography", "line_height_"),
+        "transitionDuration": scale("animation", "duration_"),
+        "transitionTimingFunction": scale("animation", "ease_"),
+    });
+    if let Some(layout) = layout {
+        // Tailwind screens are min-widths; the smallest breakpoint is the default
+        let screens: Map<String, Value> = layout
+            .breakpoints
+            .breakpoints
+            .iter()
+            .filter(|breakpoint| breakpoint.min_width() > 0.0)
+            .map(|breakpoint| {
+                (
+                    breakpoint.as_str().to_string(),
+                    format!("{}px", breakpoint.min_width()).into(),
+                )
+            })
+            .collect();
+        extend["screens"] = Value::Object(screens);
+    }
+
+    json!({ "theme": { "extend": extend } })
+}
+
+/// Export theme as a `tailwind.config.js` module
+pub fn export_tailwind_config_js(theme: &CSSVariables, layout: Option<&LayoutSystem>) -> String {
+    let config =
+        serde_json::to_string_pretty(&export_tailwind_config(theme, layout)).unwrap_or_default();
+    format!("/** @type {{import('tailwindcss').Config}} */\nmodule.exports = {config};\n")
+}
+
+/// Fields of a theme group starting with `prefix`, keyed the way Tailwind
+/// names its scales
+fn tailwind_scale(fields: &Value, group: &str, prefix: &str) -> Map<String, Value> {
+    let Some(values) = fields.get(group).and_then(Value::as_object) else {
+        return Map::new();
+    };
+    values
+        .iter()
+        .filter_map(|(field, value)| {
+            let key = match field.strip_prefix(prefix)? {
+                "base" => "DEFAULT".to_string(),
+                key => key.replace('_', "-"),
+            };
+            Some((key, value.clone()))
+        })
+        .collect()
+}
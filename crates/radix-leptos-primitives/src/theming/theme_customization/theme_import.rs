@@ -0,0 +1,155 @@
+use super::ThemeMigrator;
+use crate::theming::CSSVariables;
+use leptos::serde_json::{self, Value};
+
+/// Groups whose values must be colors
+const COLOR_GROUPS: &[&str] = &["primary", "secondary", "neutral", "semantic"];
+
+/// CSS color functions accepted in imported themes
+const COLOR_FUNCTIONS: &[&str] = &[
+    "rgb(", "rgba(", "hsl(", "hsla(", "hwb(", "lab(", "lch(", "oklab(", "oklch(", "color(",
+];
+
+/// Problems found while importing a theme from JSON
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ThemeImportError {
+    #[error("invalid JSON at line {line}, column {column}: {message}")]
+    Syntax {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    #[error("unknown key `{path}`")]
+    UnknownKey { path: String },
+    #[error("missing scale `{path}`")]
+    MissingScale { path: String },
+    #[error("missing value `{path}`")]
+    MissingValue { path: String },
+    #[error("`{path}` should be {expected}")]
+    InvalidType {
+        path: String,
+        expected: &'static str,
+    },
+    #[error("`{path}` is not a valid color: `{value}`")]
+    InvalidColor { path: String, value: String },
+    #[error("theme schema version {found} is newer than {supported}, the newest supported")]
+    UnsupportedVersion { found: u64, supported: u32 },
+    #[error("no migration from theme schema version {version}")]
+    MissingMigration { version: u32 },
+}
+
+/// Result of importing a theme, with every problem found
+pub type ThemeImportResult<T> = Result<T, Vec<ThemeImportError>>;
+
+impl CSSVariables {
+    /// Import a theme exported as JSON, e.g. by
+    /// [`export_theme_json`](super::export_theme_json)
+    ///
+    /// Unlike plain deserialization every problem is reported, with the path
+    /// of the key it concerns: unknown keys, missing scales and values,
+    /// values that aren't strings and colors that aren't CSS colors. Themes
+    /// exported with an older schema version are upgraded first, see
+    /// [`ThemeMigrator`](super::ThemeMigrator).
+    pub fn from_json_str(json: &str) -> ThemeImportResult<Self> {
+        ThemeMigrator::default().import(json)
+    }
+}
+
+impl ThemeMigrator {
+    /// Import a theme exported as JSON, upgrading it with this migrator
+    pub fn import(&self, json: &str) -> ThemeImportResult<CSSVariables> {
+        let document: Value = serde_json::from_str(json).map_err(|error| {
+            vec![ThemeImportError::Syntax {
+                line: error.line(),
+                column: error.column(),
+                message: error.to_string(),
+            }]
+        })?;
+        self.import_document(document)
+    }
+
+    /// Import a theme document already parsed from JSON
+    pub(crate) fn import_document(&self, document: Value) -> ThemeImportResult<CSSVariables> {
+        let document = self.migrate(document).map_err(|error| vec![error])?;
+        let schema = serde_json::to_value(CSSVariables::default()).unwrap_or_default();
+        let errors = validate_theme(&schema, &document);
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+        // The document has exactly the theme's shape, with strings throughout
+        Ok(serde_json::from_value(document).unwrap_or_default())
+    }
+}
+
+fn validate_theme(schema: &Value, document: &Value) -> Vec<ThemeImportError> {
+    let (Some(schema), Some(document)) = (schema.as_object(), document.as_object()) else {
+        return vec![ThemeImportError::InvalidType {
+            path: String::new(),
+            expected: "an object",
+        }];
+    };
+    let mut errors: Vec<ThemeImportError> = document
+        .keys()
+        .filter(|group| !schema.contains_key(*group))
+        .map(|group| ThemeImportError::UnknownKey {
+            path: group.clone(),
+        })
+        .collect();
+
+    for (group, fields) in schema {
+        let Some(values) = document.get(group) else {
+            errors.push(ThemeImportError::MissingScale {
+                path: group.clone(),
+            });
+            continue;
+        };
+        let (Some(fields), Some(values)) = (fields.as_object(), values.as_object()) else {
+            errors.push(ThemeImportError::InvalidType {
+                path: group.clone(),
+                expected: "an object",
+            });
+            continue;
+        };
+        errors.extend(
+            values
+                .keys()
+                .filter(|field| !fields.contains_key(*field))
+                .map(|field| ThemeImportError::UnknownKey {
+                    path: format!("{group}.{field}"),
+                }),
+        );
+        for field in fields.keys() {
+            let path = format!("{group}.{field}");
+            match values.get(field).map(Value::as_str) {
+                None => errors.push(ThemeImportError::MissingValue { path }),
+                Some(None) => errors.push(ThemeImportError::InvalidType {
+                    path,
+                    expected: "a string",
+                }),
+                Some(Some(value))
+                    if COLOR_GROUPS.contains(&group.as_str()) && !is_css_color(value) =>
+                {
+                    errors.push(ThemeImportError::InvalidColor {
+                        path,
+                        value: value.to_string(),
+                    })
+                }
+                Some(Some(_)) => {}
+            }
+        }
+    }
+    errors
+}
+
+/// Hex colors and CSS color functions
+fn is_css_color(value: &str) -> bool {
+    let value = value.trim();
+    if let Some(hex) = value.strip_prefix('#') {
+        return matches!(hex.len(), 3 | 4 | 6 | 8) && hex.chars().all(|c| c.is_ascii_hexdigit());
+    }
+    let lowercase = value.to_ascii_lowercase();
+    COLOR_FUNCTIONS
+        .iter()
+        .any(|function| lowercase.starts_with(function))
+        && lowercase.ends_with(')')
+}
@@ -0,0 +1,185 @@
+use super::ThemeImportError;
+use crate::theming::CSSVariables;
+use leptos::serde_json::{self, Map, Value};
+use std::collections::BTreeMap;
+
+/// Schema version of themes written by [`export_theme_json`](super::export_theme_json)
+///
+/// Version 1 is the unversioned format of earlier releases.
+pub const THEME_SCHEMA_VERSION: u32 = 2;
+
+/// Key of the schema version in exported themes
+pub const SCHEMA_VERSION_KEY: &str = "schema_version";
+
+/// A key to move, as `group` or `group.field`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldRename {
+    pub from: String,
+    pub to: String,
+}
+
+/// Upgrade of exported themes from schema version `from` to the next one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThemeMigration {
+    pub from: u32,
+    pub renames: Vec<FieldRename>,
+    /// Give scales and values missing from the document their default
+    pub fill_missing: bool,
+}
+
+impl ThemeMigration {
+    pub fn new(from: u32) -> Self {
+        Self {
+            from,
+            renames: Vec::new(),
+            fill_missing: false,
+        }
+    }
+
+    /// Move the scale or value at `from` to `to`, unless `to` is already set
+    pub fn rename(mut self, from: impl Into<String>, to: impl Into<String>) -> Self {
+        self.renames.push(FieldRename {
+            from: from.into(),
+            to: to.into(),
+        });
+        self
+    }
+
+    /// Fill in the scales and values added in the next version
+    pub fn fill_missing(mut self) -> Self {
+        self.fill_missing = true;
+        self
+    }
+
+    /// Apply the migration to a theme document
+    pub fn apply(&self, document: &mut Map<String, Value>) {
+        for FieldRename { from, to } in &self.renames {
+            if get_path(document, to).is_some() {
+                continue;
+            }
+            if let Some(value) = take_path(document, from) {
+                set_path(document, to, value);
+            }
+        }
+        if self.fill_missing {
+            let defaults = serde_json::to_value(CSSVariables::default()).unwrap_or_default();
+            for (group, fields) in defaults.as_object().into_iter().flatten() {
+                let values = document
+                    .entry(group.clone())
+                    .or_insert_with(|| Value::Object(Map::new()));
+                let (Some(values), Some(fields)) = (values.as_object_mut(), fields.as_object())
+                else {
+                    continue;
+                };
+                for (field, default) in fields {
+                    values
+                        .entry(field.clone())
+                        .or_insert_with(|| default.clone());
+                }
+            }
+        }
+    }
+}
+
+/// Upgrades themes exported by earlier releases to the current schema
+///
+/// Documents carry their version under [`SCHEMA_VERSION_KEY`], and
+/// migrations are applied one version at a time up to
+/// [`THEME_SCHEMA_VERSION`], so a theme saved years ago still loads
+/// instead of failing on renamed fields or scales added since.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThemeMigrator {
+    migrations: BTreeMap<u32, ThemeMigration>,
+}
+
+impl Default for ThemeMigrator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ThemeMigrator {
+    /// A migrator with the migrations between the built-in schema versions
+    pub fn new() -> Self {
+        // Version 1 exports may predate scales added to the theme since
+        Self::empty().with_migration(ThemeMigration::new(1).fill_missing())
+    }
+
+    /// A migrator without any migrations
+    pub fn empty() -> Self {
+        Self {
+            migrations: BTreeMap::new(),
+        }
+    }
+
+    /// Add a migration, replacing any other from the same version
+    pub fn with_migration(mut self, migration: ThemeMigration) -> Self {
+        self.migrations.insert(migration.from, migration);
+        self
+    }
+
+    /// Schema version of a theme document, 1 for unversioned ones
+    pub fn version(document: &Value) -> Result<u64, ThemeImportError> {
+        match document.get(SCHEMA_VERSION_KEY) {
+            None => Ok(1),
+            Some(version) => version
+                .as_u64()
+                .filter(|version| *version > 0)
+                .ok_or_else(|| ThemeImportError::InvalidType {
+                    path: SCHEMA_VERSION_KEY.to_string(),
+                    expected: "a positive integer",
+                }),
+        }
+    }
+
+    /// Upgrade a theme document to [`THEME_SCHEMA_VERSION`]
+    ///
+    /// The result has no version key, so it deserializes as a theme.
+    pub fn migrate(&self, mut document: Value) -> Result<Value, ThemeImportError> {
+        let found = Self::version(&document)?;
+        let supported = THEME_SCHEMA_VERSION;
+        if found > u64::from(supported) {
+            return Err(ThemeImportError::UnsupportedVersion { found, supported });
+        }
+        let Some(theme) = document.as_object_mut() else {
+            return Ok(document);
+        };
+        theme.remove(SCHEMA_VERSION_KEY);
+        // `found` is at most `supported`, a `u32`
+        for version in found as u32..supported {
+            let migration = self
+                .migrations
+                .get(&version)
+                .ok_or(ThemeImportError::MissingMigration { version })?;
+            migration.apply(theme);
+        }
+        Ok(document)
+    }
+}
+
+fn get_path<'a>(document: &'a Map<String, Value>, path: &str) -> Option<&'a Value> {
+    match path.split_once('.') {
+        Some((group, field)) => document.get(group)?.get(field),
+        None => document.get(path),
+    }
+}
+
+fn take_path(document: &mut Map<String, Value>, path: &str) -> Option<Value> {
+    match path.split_once('.') {
+        Some((group, field)) => document.get_mut(group)?.as_object_mut()?.remove(field),
+        None => document.remove(path),
+    }
+}
+
+fn set_path(document: &mut Map<String, Value>, path: &str, value: Value) {
+    let Some((group, field)) = path.split_once('.') else {
+        document.insert(path.to_string(), value);
+        return;
+    };
+    let group = document
+        .entry(group.to_string())
+        .or_insert_with(|| Value::Object(Map::new()));
+    if let Some(group) = group.as_object_mut() {
+        group.insert(field.to_string(), value);
+    }
+}
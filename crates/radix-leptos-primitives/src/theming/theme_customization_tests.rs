@@ -199,4 +199,204 @@ mod tests {
         let size3 = Size::Lg;
         assert_ne!(size1, size3);
     }
+
+    #[test]
+    fn test_theme_customization_tailwind_export() {
+        use crate::theming::{export_tailwind_config, export_tailwind_config_js, LayoutSystem};
+
+        let theme = CSSVariables::default();
+        let config = export_tailwind_config(&theme, Some(&LayoutSystem::default()));
+        let extend = &config["theme"]["extend"];
+
+        assert_eq!(extend["colors"]["primary"]["500"], "#3b82f6");
+        assert_eq!(extend["colors"]["success"], "#10b981");
+        assert_eq!(extend["spacing"]["4"], "1rem");
+        assert_eq!(extend["borderRadius"]["DEFAULT"], "0.25rem");
+        assert_eq!(extend["boxShadow"]["DEFAULT"], theme.shadow.shadow_base);
+        assert_eq!(extend["fontSize"]["2xl"], "1.5rem");
+        assert_eq!(
+            extend["transitionTimingFunction"]["in-out"],
+            theme.animation.ease_in_out
+        );
+        assert_eq!(extend["screens"]["md"], "768px");
+        assert!(extend["screens"].get("xs").is_none());
+        assert!(export_tailwind_config(&theme, None)["theme"]["extend"]
+            .get("screens")
+            .is_none());
+
+        let js = export_tailwind_config_js(&theme, None);
+        assert!(js.contains("module.exports = {"));
+        assert!(js.contains("\"primary\""));
+    }
+
+    #[test]
+    fn test_theme_customization_export_formats() {
+        use crate::theming::{export_theme, ExportFormat};
+
+        let theme = CSSVariables::default();
+        let css = export_theme(&theme, ExportFormat::Css);
+        assert!(css.starts_with(":root {\n"));
+        assert!(css.contains("  --primary-500: #3b82f6;\n"));
+        assert!(css.ends_with("}\n"));
+
+        let scss = export_theme(&theme, ExportFormat::Scss);
+        assert!(scss.contains("$primary-500: #3b82f6;\n"));
+        assert!(scss.contains("$font-family-sans: ui-sans-serif, system-ui, sans-serif;\n"));
+
+        let less = export_theme(&theme, ExportFormat::Less);
+        assert!(less.contains("@space-4: 1rem;\n"));
+        // Every variable is exported once in each format
+        assert_eq!(
+            scss.lines().count(),
+            theme.to_css_string().matches("--").count()
+        );
+        assert_eq!(less.lines().count(), scss.lines().count());
+
+        assert_eq!(ExportFormat::Scss.file_name(), "_theme.scss");
+
+        let tokens = export_theme(&theme, ExportFormat::StyleDictionary);
+        assert!(tokens.contains("\"comment\": \"--primary-500\""));
+        assert_eq!(ExportFormat::StyleDictionary.file_name(), "tokens.json");
+    }
+
+    #[test]
+    fn test_theme_customization_json_import() {
+        use crate::theming::{
+            export_theme_json, ThemeImportError, SCHEMA_VERSION_KEY, THEME_SCHEMA_VERSION,
+        };
+
+        let theme = CSSVariables::dark_theme();
+        assert_eq!(
+            CSSVariables::from_json_str(&export_theme_json(&theme)),
+            Ok(theme.clone())
+        );
+
+        let mut document = serde_json::to_value(&theme).unwrap();
+        document[SCHEMA_VERSION_KEY] = THEME_SCHEMA_VERSION.into();
+        document["primary"]["primary_500"] = "blue-ish".into();
+        document["neutral"]["neutral_50"] = "rgb(10 10 10)".into();
+        document["semantic"]["danger"] = "#ff0000".into();
+        document["spacing"]["space_4"] = 16.into();
+        document["shadow"]
+            .as_object_mut()
+            .unwrap()
+            .remove("shadow_sm");
+        document.as_object_mut().unwrap().remove("animation");
+        let errors = CSSVariables::from_json_str(&document.to_string()).unwrap_err();
+        assert_eq!(errors.len(), 5);
+        assert!(errors.contains(&ThemeImportError::InvalidColor {
+            path: "primary.primary_500".to_string(),
+            value: "blue-ish".to_string(),
+        }));
+        assert!(errors.contains(&ThemeImportError::UnknownKey {
+            path: "semantic.danger".to_string(),
+        }));
+        assert!(errors.contains(&ThemeImportError::InvalidType {
+            path: "spacing.space_4".to_string(),
+            expected: "a string",
+        }));
+        assert!(errors.contains(&ThemeImportError::MissingValue {
+            path: "shadow.shadow_sm".to_string(),
+        }));
+        assert!(errors.contains(&ThemeImportError::MissingScale {
+            path: "animation".to_string(),
+        }));
+
+        assert!(matches!(
+            CSSVariables::from_json_str("{\"primary\": ").unwrap_err()[..],
+            [ThemeImportError::Syntax { line: 1, .. }]
+        ));
+    }
+
+    #[test]
+    fn test_theme_customization_edit_history() {
+        use crate::theming::EditHistory;
+
+        let mut history = EditHistory::new(CSSVariables::default());
+        assert!(!history.can_undo());
+
+        let mut edited = CSSVariables::default();
+        edited.primary.primary_500 = "#db2777".to_string();
+        assert!(history.edit(edited.clone()));
+        // Edits that change nothing aren't recorded
+        assert!(!history.edit(edited.clone()));
+        let mut dark = edited.clone();
+        dark.neutral.neutral_50 = "#0a0a0a".to_string();
+        assert!(history.edit(dark.clone()));
+
+        assert!(history.undo());
+        assert_eq!(history.present(), &edited);
+        assert!(history.undo());
+        assert_eq!(history.present(), &CSSVariables::default());
+        assert!(!history.undo());
+        assert!(history.redo());
+        assert_eq!(history.present(), &edited);
+
+        // A new edit discards what was undone
+        assert!(history.edit(CSSVariables::dark_theme()));
+        assert!(!history.can_redo());
+
+        let mut limited = EditHistory::new(0).with_limit(2);
+        for value in 1..=4 {
+            limited.edit(value);
+        }
+        assert!(limited.undo() && limited.undo());
+        assert!(!limited.undo());
+        assert_eq!(*limited.present(), 2);
+    }
+
+    #[test]
+    fn test_theme_customization_migration() {
+        use crate::theming::{
+            ThemeImportError, ThemeMigration, ThemeMigrator, SCHEMA_VERSION_KEY,
+            THEME_SCHEMA_VERSION,
+        };
+
+        // Unversioned exports are upgraded instead of rejected
+        let theme = CSSVariables::dark_theme();
+        let mut document = serde_json::to_value(&theme).unwrap();
+        document.as_object_mut().unwrap().remove("animation");
+        document["shadow"]
+            .as_object_mut()
+            .unwrap()
+            .remove("shadow_sm");
+        let imported = CSSVariables::from_json_str(&document.to_string()).unwrap();
+        assert_eq!(imported.primary, theme.primary);
+        assert_eq!(imported.animation, CSSVariables::default().animation);
+        assert_eq!(
+            imported.shadow.shadow_sm,
+            CSSVariables::default().shadow.shadow_sm
+        );
+
+        let migrator = ThemeMigrator::empty().with_migration(
+            ThemeMigration::new(1)
+                .rename("border.radius_md", "border.border_radius_md")
+                .rename("motion", "animation")
+                .fill_missing(),
+        );
+        let mut document = serde_json::to_value(&theme).unwrap();
+        let animation = document.as_object_mut().unwrap().remove("animation").unwrap();
+        document["motion"] = animation;
+        let radius = document["border"]
+            .as_object_mut()
+            .unwrap()
+            .remove("border_radius_md")
+            .unwrap();
+        document["border"]["radius_md"] = radius;
+        assert_eq!(migrator.import(&document.to_string()), Ok(theme));
+
+        document[SCHEMA_VERSION_KEY] = (THEME_SCHEMA_VERSION + 1).into();
+        assert_eq!(
+            migrator.import(&document.to_string()),
+            Err(vec![ThemeImportError::UnsupportedVersion {
+                found: u64::from(THEME_SCHEMA_VERSION + 1),
+                supported: THEME_SCHEMA_VERSION,
+            }])
+        );
+        document[SCHEMA_VERSION_KEY] = 1.into();
+        assert_eq!(
+            ThemeMigrator::empty().import(&document.to_string()),
+            Err(vec![ThemeImportError::MissingMigration { version: 1 }])
+        );
+    }
 }
@@ -0,0 +1,126 @@
+//! Runtime theming without a stylesheet
+//!
+//! Themes are written as inline custom properties, so components pick them
+//! up without any CSS file generated from the theme at build time.
+
+use super::css_variables::CSSVariables;
+use radix_leptos_core::Direction;
+use wasm_bindgen::JsCast;
+use web_sys::{CssStyleDeclaration, Element, HtmlElement};
+
+/// Write the theme's custom properties onto `scope`, or onto `:root`
+/// without one
+///
+/// Inline properties win over ones a stylesheet sets on the same element
+/// and are inherited by the whole subtree.
+pub fn inject_theme(theme: &CSSVariables, scope: Option<Element>) {
+    let Some(style) = scope_style(scope) else {
+        return;
+    };
+    theme.for_each_variable(|name, value| {
+        let _ = style.set_property(&format!("--{}", name), value);
+    });
+}
+
+/// Remove the custom properties [`inject_theme`] wrote
+pub fn remove_theme(theme: &CSSVariables, scope: Option<Element>) {
+    let Some(style) = scope_style(scope) else {
+        return;
+    };
+    theme.for_each_variable(|name, _| {
+        let _ = style.remove_property(&format!("--{}", name));
+    });
+}
+
+/// Custom properties for the values CSS has no logical form for, such as
+/// the sign of a horizontal `translate` or a `background-position` side
+pub const DIRECTION_VARIABLES: [&str; 3] =
+    ["direction-sign", "inline-start-side", "inline-end-side"];
+
+/// The value of one of the [`DIRECTION_VARIABLES`] in `direction`
+pub fn direction_value(variable: &str, direction: Direction) -> Option<&'static str> {
+    let value = match (variable, direction) {
+        ("direction-sign", Direction::Ltr) => "1",
+        ("direction-sign", Direction::Rtl) => "-1",
+        ("inline-start-side", Direction::Ltr) | ("inline-end-side", Direction::Rtl) => "left",
+        ("inline-start-side", Direction::Rtl) | ("inline-end-side", Direction::Ltr) => "right",
+        _ => return None,
+    };
+    Some(value)
+}
+
+/// Write the [`DIRECTION_VARIABLES`] for `direction` onto `scope`, or onto
+/// `:root` without one
+///
+/// Stylesheets use them where logical properties don't reach, e.g.
+/// `transform: translateX(calc(var(--direction-sign) * 100%))`.
+pub fn inject_direction(direction: Direction, scope: Option<Element>) {
+    let Some(style) = scope_style(scope) else {
+        return;
+    };
+    for variable in DIRECTION_VARIABLES {
+        if let Some(value) = direction_value(variable, direction) {
+            let _ = style.set_property(&format!("--{}", variable), value);
+        }
+    }
+}
+
+fn scope_style(scope: Option<Element>) -> Option<CssStyleDeclaration> {
+    let element = match scope {
+        Some(element) => element,
+        None => web_sys::window()?.document()?.document_element()?,
+    };
+    Some(element.dyn_into::<HtmlElement>().ok()?.style())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[test]
+    fn test_direction_values() {
+        for variable in DIRECTION_VARIABLES {
+            assert!(direction_value(variable, Direction::Ltr).is_some());
+            assert_ne!(
+                direction_value(variable, Direction::Ltr),
+                direction_value(variable, Direction::Rtl)
+            );
+        }
+        assert_eq!(
+            direction_value("inline-start-side", Direction::Rtl),
+            Some("right")
+        );
+        assert_eq!(
+            direction_value("direction-sign", Direction::Rtl),
+            Some("-1")
+        );
+        assert_eq!(direction_value("unknown", Direction::Ltr), None);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_inject_theme() {
+        let document = web_sys::window().unwrap().document().unwrap();
+        let root = document.document_element().unwrap();
+        let root_style = root.clone().dyn_into::<HtmlElement>().unwrap().style();
+        let scope = document.create_element("div").unwrap();
+        let scope_style = scope.clone().dyn_into::<HtmlElement>().unwrap().style();
+        let theme = CSSVariables::dark_theme();
+
+        inject_theme(&theme, None);
+        assert_eq!(
+            root_style.get_property_value("--primary-500").unwrap(),
+            theme.primary.primary_500
+        );
+        inject_theme(&theme, Some(scope.clone()));
+        assert_eq!(
+            scope_style.get_property_value("--neutral-50").unwrap(),
+            theme.neutral.neutral_50
+        );
+
+        remove_theme(&theme, None);
+        assert_eq!(root_style.get_property_value("--primary-500").unwrap(), "");
+    }
+}
@@ -0,0 +1,104 @@
+//! Sparse theme changes
+//!
+//! A design system can ship brand overrides as a small patch instead of a
+//! full theme, and compare theme versions by diffing them.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use super::css_variables::CSSVariables;
+use super::theme_provider::ThemeOverrides;
+
+/// The variables that differ between two themes, by name without the
+/// leading `--`
+///
+/// Serializes as a flat JSON object such as `{ "primary-500": "#db2777" }`.
+pub type ThemePatch = ThemeOverrides;
+
+impl CSSVariables {
+    /// The variables whose value in `other` differs from this theme, with
+    /// their value in `other`
+    pub fn diff(&self, other: &Self) -> ThemePatch {
+        let mut current = BTreeMap::new();
+        self.for_each_variable(|name, value| {
+            current.insert(name.to_string(), value.to_string());
+        });
+        let mut patch = ThemePatch::new();
+        other.for_each_variable(|name, value| {
+            if current.get(name).map(String::as_str) != Some(value) {
+                patch.variables.insert(name.to_string(), value.to_string());
+            }
+        });
+        patch
+    }
+
+    /// This theme with `patch` applied
+    pub fn merge(&self, patch: &ThemePatch) -> Self {
+        self.with_overrides(patch)
+    }
+}
+
+impl ThemeOverrides {
+    pub fn len(&self) -> usize {
+        self.variables.len()
+    }
+
+    /// Names in the patch that aren't variables of any theme, which
+    /// [`CSSVariables::merge`] ignores
+    pub fn unknown_variables(&self) -> Vec<&str> {
+        let mut known = Vec::new();
+        CSSVariables::default().for_each_variable(|name, _| known.push(name.to_string()));
+        self.variables
+            .keys()
+            .map(String::as_str)
+            .filter(|name| !known.iter().any(|known| known == name))
+            .collect()
+    }
+}
+
+/// One `--name: value;` declaration per line
+impl fmt::Display for ThemeOverrides {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (name, value) in &self.variables {
+            writeln!(f, "--{}: {};", name, value)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptos::serde_json;
+
+    #[test]
+    fn test_theme_diff_and_merge() {
+        let light = CSSVariables::light_theme();
+        let dark = CSSVariables::dark_theme();
+
+        assert!(light.diff(&light).is_empty());
+        let patch = light.diff(&dark);
+        assert_eq!(
+            patch.variables.get("neutral-50").map(String::as_str),
+            Some("#0a0a0a")
+        );
+        assert!(!patch.variables.contains_key("primary-500"));
+        assert_eq!(light.merge(&patch), dark);
+        assert!(patch.to_string().contains("--neutral-50: #0a0a0a;\n"));
+    }
+
+    #[test]
+    fn test_theme_patch_file() {
+        let patch: ThemePatch =
+            serde_json::from_str(r##"{ "primary-500": "#db2777", "brand-glow": "1px" }"##).unwrap();
+        assert_eq!(patch.len(), 2);
+        assert_eq!(patch.unknown_variables(), vec!["brand-glow"]);
+
+        let theme = CSSVariables::default().merge(&patch);
+        assert_eq!(theme.primary.primary_500, "#db2777");
+        assert_eq!(
+            serde_json::to_value(CSSVariables::default().diff(&theme)).unwrap(),
+            serde_json::json!({ "primary-500": "#db2777" })
+        );
+    }
+}
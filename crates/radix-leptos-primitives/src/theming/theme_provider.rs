@@ -1,21 +1,80 @@
-use crate::theming::CSSVariables;
+use crate::theming::{
+    inject_direction, inject_theme, remove_theme, theme_document, transition_theme, CSSVariables,
+    DarkModeStrategy, ThemeMigrator, ThemeTransition,
+};
 use leptos::callback::Callback;
 use leptos::children::Children;
 use leptos::context::{provide_context, use_context};
+use leptos::html;
 use leptos::prelude::*;
+use leptos::serde_json::{self, Value};
+use radix_leptos_core::{use_direction, use_local_storage, use_media_query, use_reduced_motion};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use web_sys::Element;
 
 /// Theme provider component for managing global theme state
+///
+/// The theme's CSS variables are injected at runtime with
+/// [`inject_theme`], so no stylesheet is needed: the outermost provider
+/// sets them on `:root`, which also themes content portalled out of it,
+/// and nested providers on their own element, so they apply to its subtree
+/// only. The direction-aware [`DIRECTION_VARIABLES`] follow the closest
+/// DirectionProvider. A provider inside another one starts from the
+/// outer provider's theme and dark mode, follows their changes and applies
+/// its `overrides` on top, so it only needs to name what differs. With a
+/// `transition`, later theme and dark mode switches are animated as
+/// [`transition_theme`] describes instead of repainting at once, unless
+/// motion is reduced.
+///
+/// With `system_theme` dark mode follows the operating system's color
+/// scheme, as [`DarkModeStrategy::System`] describes, until it is set
+/// through the context. With a `storage_key` the theme is saved in the
+/// versioned format of [`export_theme_json`](crate::theming::export_theme_json)
+/// and upgraded by [`ThemeMigrator`] when read back.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use leptos::prelude::*;
+/// use radix_leptos_primitives::theming::{ThemeOverrides, ThemeProvider};
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let promo = ThemeOverrides::new().with_variable("primary-500", "#db2777");
+///
+///     view! {
+///         <ThemeProvider>
+///             <main>"Regular content"</main>
+///             <ThemeProvider overrides=promo>
+///                 <aside>"Promotion"</aside>
+///             </ThemeProvider>
+///         </ThemeProvider>
+///     }
+/// }
+/// ```
 #[component]
 pub fn ThemeProvider(
-    /// Theme configuration
+    /// Theme configuration; nested providers inherit the outer theme
+    /// without one
     #[prop(optional)]
     theme: Option<CSSVariables>,
-    /// Whether to use dark mode
+    /// Variables to change on top of the theme
+    #[prop(optional)]
+    overrides: Option<ThemeOverrides>,
+    /// Whether to use dark mode; nested providers inherit it without one
     #[prop(optional)]
     dark_mode: Option<bool>,
-    /// Whether to enable system theme detection
+    /// Whether dark mode follows the system color scheme until it is set
     #[prop(optional)]
     system_theme: Option<bool>,
+    /// localStorage key to persist the selected theme and dark mode under;
+    /// nothing is persisted without one
+    #[prop(optional)]
+    storage_key: Option<String>,
+    /// How to animate theme switches; they are instant without one
+    #[prop(optional)]
+    transition: Option<ThemeTransition>,
     /// Additional CSS classes
     #[prop(optional)]
     class: Option<String>,
@@ -23,15 +82,86 @@ pub fn ThemeProvider(
     #[prop(optional)]
     style: Option<String>,
     /// Children content
+    #[prop(optional)]
     children: Option<Children>,
 ) -> impl IntoView {
-    let theme = theme.unwrap_or_default();
-    let dark_mode = dark_mode.unwrap_or(false);
-    let system_theme = system_theme.unwrap_or(true);
+    let parent = use_context::<ThemeContext>();
+    let nested = parent.is_some();
+    let overrides = overrides.unwrap_or_default();
+    let inherits_theme = theme.is_none();
+    let theme = match (theme, &parent) {
+        (Some(theme), _) => theme,
+        (None, Some(parent)) => parent.theme.get_untracked(),
+        (None, None) => CSSVariables::default(),
+    }
+    .with_overrides(&overrides);
+    let inherits_dark = dark_mode.is_none();
+    let dark_mode = dark_mode
+        .or_else(|| parent.as_ref().map(|parent| parent.isdark.get_untracked()))
+        .unwrap_or(false);
+    let strategy = if system_theme.unwrap_or(false) {
+        DarkModeStrategy::System
+    } else {
+        DarkModeStrategy::Manual
+    };
 
     let (current_theme, setcurrent_theme) = signal(theme.clone());
     let (isdark, set_isdark) = signal(dark_mode);
+    // Dark mode as set through the context or restored from storage
+    let chosen_dark = RwSignal::new(None::<bool>);
+
+    // Follow the outer provider for whatever this one doesn't set itself
+    let overrides = StoredValue::new(overrides);
+    if let Some(parent) = parent {
+        Effect::new(move |_| {
+            if inherits_theme {
+                let theme = parent.theme.get();
+                setcurrent_theme
+                    .set(overrides.with_value(|overrides| theme.with_overrides(overrides)));
+            }
+            if inherits_dark {
+                set_isdark.set(parent.isdark.get());
+            }
+        });
+    }
     let (system_preference, set_system_preference) = signal(false);
+    let prefersdark = use_media_query("(prefers-color-scheme: dark)");
+    Effect::new(move |_| {
+        let system = prefersdark.get();
+        set_system_preference.set(system);
+        if strategy == DarkModeStrategy::System {
+            set_isdark.set(strategy.resolve(system, chosen_dark.get(), dark_mode));
+        }
+    });
+
+    // Restore the saved choice once mounted and follow changes from other tabs
+    let stored = storage_key.map(|key| {
+        let stored = use_local_storage(
+            key,
+            StoredTheme {
+                theme: theme.clone(),
+                dark: None,
+            },
+        )
+        .value;
+        Effect::new(move |_| {
+            let StoredTheme { theme, dark } = stored.get();
+            setcurrent_theme.set(theme);
+            if let Some(dark) = dark {
+                chosen_dark.set(Some(dark));
+                set_isdark.set(dark);
+            }
+        });
+        stored
+    });
+    let persist = move |theme: CSSVariables| {
+        if let Some(stored) = stored {
+            stored.set(StoredTheme {
+                theme,
+                dark: chosen_dark.get_untracked(),
+            });
+        }
+    };
 
     // Apply theme changes
     let apply_theme = move |new_theme: CSSVariables, dark: bool| {
@@ -40,14 +170,12 @@ pub fn ThemeProvider(
         } else {
             CSSVariables::light_theme()
         };
+        let css_vars = overrides.with_value(|overrides| css_vars.with_overrides(overrides));
 
         setcurrent_theme.set(css_vars.clone());
+        chosen_dark.set(Some(dark));
         set_isdark.set(dark);
-
-        // Apply CSS variables to document root
-        let css_string = css_vars.to_css_string();
-        // In a real implementation, this would apply the CSS to the document
-        // For now, we'll just store the theme state
+        persist(css_vars);
     };
 
     // Toggle dark mode
@@ -58,7 +186,8 @@ pub fn ThemeProvider(
 
     // Set theme
     let set_theme = move |new_theme: CSSVariables| {
-        apply_theme(new_theme, isdark.get());
+        setcurrent_theme.set(new_theme.clone());
+        persist(new_theme);
     };
 
     // Set dark mode
@@ -76,19 +205,131 @@ pub fn ThemeProvider(
         setdark_mode: Callback::new(setdark_mode),
     });
 
-    let class = format!(
-        "theme-provider {} {}",
-        current_theme.get().to_css_string(),
-        style.as_ref().unwrap_or(&String::new())
-    );
+    let provider_ref = NodeRef::<html::Div>::new();
+    let direction = use_direction();
+    let injected = StoredValue::new(false);
+    let reduced_motion = use_reduced_motion();
+    Effect::new(move |_| {
+        let theme = current_theme.get();
+        let scope = provider_ref.get().map(Element::from);
+        if nested && scope.is_none() {
+            return;
+        }
+        let scope = scope.filter(|_| nested);
+        let direction = direction.get();
+        let apply = {
+            let scope = scope.clone();
+            move || {
+                inject_theme(&theme, scope.clone());
+                inject_direction(direction, scope);
+            }
+        };
+        // The first theme is there from the start, only switches animate
+        let animate = injected.get_value() && !reduced_motion.get_untracked();
+        match transition.as_ref().filter(|_| animate) {
+            Some(transition) => transition_theme(transition, scope, apply),
+            None => apply(),
+        }
+        injected.set_value(true);
+    });
+    on_cleanup(move || {
+        if !nested && injected.try_get_value() == Some(true) {
+            current_theme.try_with_untracked(|theme| remove_theme(theme, None));
+        }
+    });
+
+    let class = format!("theme-provider {}", class.unwrap_or_default());
 
     view! {
-        <div class=class style=style.unwrap_or_default()>
+        <div node_ref=provider_ref class=class style=style.unwrap_or_default() data-theme=move || if isdark.get() { "dark" } else { "light" }>
             {children.map(|c| c())}
         </div>
     }
 }
 
+/// Part of a theme, applied on top of a full one
+///
+/// Variables are named as in the generated CSS without the leading `--`,
+/// e.g. `primary-500` or `font-size-base`; names the theme has no variable
+/// for are ignored.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ThemeOverrides {
+    pub variables: BTreeMap<String, String>,
+}
+
+impl ThemeOverrides {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_variable(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.variables.insert(name.into(), value.into());
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.variables.is_empty()
+    }
+}
+
+impl CSSVariables {
+    /// This theme with `overrides` applied
+    pub fn with_overrides(&self, overrides: &ThemeOverrides) -> Self {
+        if overrides.is_empty() {
+            return self.clone();
+        }
+        let Ok(mut fields) = serde_json::to_value(self) else {
+            return self.clone();
+        };
+        let groups = fields
+            .as_object_mut()
+            .into_iter()
+            .flat_map(|groups| groups.values_mut());
+        for values in groups.filter_map(Value::as_object_mut) {
+            for (field, value) in values.iter_mut() {
+                if let Some(css) = overrides.variables.get(&field.replace('_', "-")) {
+                    *value = Value::String(css.clone());
+                }
+            }
+        }
+        serde_json::from_value(fields).unwrap_or_else(|_| self.clone())
+    }
+}
+
+/// What ThemeProvider keeps in storage
+///
+/// The theme is stored as a versioned theme document, so entries written
+/// by earlier releases are upgraded on read instead of being dropped.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct StoredTheme {
+    #[serde(with = "stored_theme")]
+    theme: CSSVariables,
+    /// Dark mode as last set, `None` until it is
+    dark: Option<bool>,
+}
+
+mod stored_theme {
+    use super::{theme_document, CSSVariables, ThemeMigrator, Value};
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        theme: &CSSVariables,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        theme_document(theme).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<CSSVariables, D::Error> {
+        let document = Value::deserialize(deserializer)?;
+        ThemeMigrator::default()
+            .import_document(document)
+            .map_err(|errors| de::Error::custom(errors[0].to_string()))
+    }
+}
+
 /// Theme context for accessing theme state
 #[derive(Clone)]
 pub struct ThemeContext {
@@ -257,7 +498,9 @@ pub fn ThemeProviderSelector(
 
 #[cfg(test)]
 mod tests {
+    use super::StoredTheme;
     use crate::theming::CSSVariables;
+    use leptos::serde_json;
 
     #[test]
     fn test_theme_provider_creation() {
@@ -371,4 +614,28 @@ mod tests {
         assert!(!theme_name.is_empty());
         // Test completed
     }
+
+    #[test]
+    fn test_stored_theme_round_trip() {
+        let stored = StoredTheme {
+            theme: CSSVariables::dark_theme(),
+            dark: Some(true),
+        };
+        let json = serde_json::to_string(&stored).unwrap();
+        assert!(json.contains("\"schema_version\":2"));
+        assert_eq!(serde_json::from_str::<StoredTheme>(&json).unwrap(), stored);
+    }
+
+    #[test]
+    fn test_stored_theme_upgrades_unversioned_entries() {
+        // Written before themes were versioned, and missing a scale since added
+        let mut theme = serde_json::to_value(CSSVariables::dark_theme()).unwrap();
+        theme.as_object_mut().unwrap().remove("shadow");
+        let json = serde_json::json!({ "theme": theme, "dark": true }).to_string();
+
+        let stored = serde_json::from_str::<StoredTheme>(&json).unwrap();
+        assert_eq!(stored.dark, Some(true));
+        assert_eq!(stored.theme.neutral, CSSVariables::dark_theme().neutral);
+        assert_eq!(stored.theme.shadow, CSSVariables::default().shadow);
+    }
 }
@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use crate::theming::CSSVariables;
+    use crate::theming::{CSSVariables, ThemeOverrides};
     use leptos::serde_json;
 
     #[test]
@@ -133,4 +133,22 @@ mod tests {
         // Test that spacing values contain valid units
         assert!(theme.spacing.space_0.contains("px") || theme.spacing.space_0.contains("rem"));
     }
+
+    #[test]
+    fn test_theme_provider_overrides() {
+        let overrides = ThemeOverrides::new()
+            .with_variable("primary-500", "#db2777")
+            .with_variable("font-size-base", "1.125rem")
+            .with_variable("not-a-variable", "1px");
+        let dark = CSSVariables::dark_theme();
+        let theme = dark.with_overrides(&overrides);
+
+        assert_eq!(theme.primary.primary_500, "#db2777");
+        assert_eq!(theme.typography.font_size_base, "1.125rem");
+        // Everything else is inherited
+        assert_eq!(theme.neutral, dark.neutral);
+        assert_eq!(theme.primary.primary_600, dark.primary.primary_600);
+        assert!(theme.to_css_string().contains("--primary-500: #db2777;"));
+        assert_eq!(dark.with_overrides(&ThemeOverrides::new()), dark);
+    }
 }
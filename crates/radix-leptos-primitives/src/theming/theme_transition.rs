@@ -0,0 +1,175 @@
+//! Animated theme switches
+//!
+//! A [`ThemeTransition`] cross-fades the page with the View Transition API
+//! where the browser has it, and otherwise briefly transitions colors on
+//! every element while the new custom properties are written. Users who
+//! prefer reduced motion always get the instant switch.
+
+use radix_leptos_core::prefers_reduced_motion;
+use std::time::Duration;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::{Document, Element};
+
+/// Attribute set on the transitioning scope while the fallback runs
+pub const THEME_TRANSITION_ATTRIBUTE: &str = "data-theme-transition";
+
+/// Id of the `<style>` element holding the transition rules
+const STYLE_ID: &str = "radix-theme-transition";
+
+/// Properties the CSS fallback transitions
+const TRANSITIONED_PROPERTIES: [&str; 7] = [
+    "background-color",
+    "color",
+    "border-color",
+    "outline-color",
+    "fill",
+    "stroke",
+    "box-shadow",
+];
+
+/// How a theme switch is animated
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThemeTransition {
+    pub duration: Duration,
+    /// CSS `<easing-function>`
+    pub easing: String,
+    /// Whether to use the View Transition API when the browser has it;
+    /// only switches on `:root` can, scoped ones always use the fallback
+    pub view_transition: bool,
+}
+
+impl Default for ThemeTransition {
+    fn default() -> Self {
+        Self {
+            duration: Duration::from_millis(200),
+            easing: "ease".to_string(),
+            view_transition: true,
+        }
+    }
+}
+
+impl ThemeTransition {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    pub fn with_easing(mut self, easing: impl Into<String>) -> Self {
+        self.easing = easing.into();
+        self
+    }
+
+    /// Always use the CSS transition fallback
+    pub fn without_view_transition(mut self) -> Self {
+        self.view_transition = false;
+        self
+    }
+
+    /// Rules timing the view transition and the fallback
+    pub fn stylesheet(&self) -> String {
+        let timing = format!("{}ms {}", self.duration.as_millis(), self.easing);
+        let transition = TRANSITIONED_PROPERTIES
+            .iter()
+            .map(|property| format!("{} {}", property, timing))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!(
+            "::view-transition-old(root), ::view-transition-new(root) {{ \
+             animation-duration: {}ms; animation-timing-function: {}; }}\n\
+             [{attr}], [{attr}] *, [{attr}] *::before, [{attr}] *::after {{ \
+             transition: {} !important; }}",
+            self.duration.as_millis(),
+            self.easing,
+            transition,
+            attr = THEME_TRANSITION_ATTRIBUTE,
+        )
+    }
+}
+
+/// Run `apply`, which writes a new theme onto `scope` or onto `:root`
+/// without one, animated as `transition` says
+pub fn transition_theme(
+    transition: &ThemeTransition,
+    scope: Option<Element>,
+    apply: impl FnOnce() + 'static,
+) {
+    let Some(document) = web_sys::window().and_then(|window| window.document()) else {
+        apply();
+        return;
+    };
+    if prefers_reduced_motion() {
+        apply();
+        return;
+    }
+    write_stylesheet(&document, &transition.stylesheet());
+
+    if transition.view_transition && scope.is_none() {
+        if let Some(start) = view_transition_start(&document) {
+            let _ = start.call1(&document, &Closure::once_into_js(apply));
+            return;
+        }
+    }
+
+    let Some(element) = scope.or_else(|| document.document_element()) else {
+        apply();
+        return;
+    };
+    let _ = element.set_attribute(THEME_TRANSITION_ATTRIBUTE, "");
+    apply();
+    leptos::prelude::set_timeout(
+        move || {
+            let _ = element.remove_attribute(THEME_TRANSITION_ATTRIBUTE);
+        },
+        transition.duration,
+    );
+}
+
+/// `document.startViewTransition` if the browser has it
+fn view_transition_start(document: &Document) -> Option<js_sys::Function> {
+    js_sys::Reflect::get(document, &"startViewTransition".into())
+        .ok()?
+        .dyn_into::<js_sys::Function>()
+        .ok()
+}
+
+fn write_stylesheet(document: &Document, css: &str) {
+    let style = match document.get_element_by_id(STYLE_ID) {
+        Some(style) => style,
+        None => {
+            let Ok(style) = document.create_element("style") else {
+                return;
+            };
+            style.set_id(STYLE_ID);
+            let Some(head) = document.head() else {
+                return;
+            };
+            let _ = head.append_child(&style);
+            style
+        }
+    };
+    if style.text_content().as_deref() != Some(css) {
+        style.set_text_content(Some(css));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transition_stylesheet() {
+        let css = ThemeTransition::new()
+            .with_duration(Duration::from_millis(350))
+            .with_easing("linear")
+            .stylesheet();
+        assert!(css.contains("::view-transition-new(root)"));
+        assert!(css.contains("animation-duration: 350ms; animation-timing-function: linear;"));
+        assert!(css.contains("[data-theme-transition] *::after"));
+        assert!(css.contains("background-color 350ms linear, color 350ms linear"));
+    }
+}
@@ -0,0 +1,439 @@
+//! WCAG contrast checks for themes
+//!
+//! Every foreground color a component draws on a background is checked
+//! against the WCAG 2.1 contrast ratio it needs, and failures come with the
+//! closest color that passes.
+
+use std::fmt;
+
+use super::css_variables::CSSVariables;
+use super::palette::{Color, Oklch};
+use leptos::serde_json::{self, Value};
+
+/// Lightness step when searching for a passing color
+const SUGGESTION_STEP: f64 = 0.005;
+
+/// Contrast every pairing reaches in themes from [`derive_high_contrast`]
+pub const HIGH_CONTRAST_RATIO: f64 = 7.0;
+
+/// Rounds of fixes, since moving a background can break a pairing fixed
+/// before it
+const HIGH_CONTRAST_PASSES: usize = 4;
+
+/// WCAG conformance level
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WcagLevel {
+    #[default]
+    AA,
+    AAA,
+}
+
+impl WcagLevel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WcagLevel::AA => "AA",
+            WcagLevel::AAA => "AAA",
+        }
+    }
+}
+
+/// What the foreground color is used for, which sets the ratio it needs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContrastUsage {
+    /// Body text
+    Text,
+    /// Text of at least 18pt, or 14pt bold
+    LargeText,
+    /// Icons, borders and other non-text indicators
+    Graphics,
+}
+
+impl ContrastUsage {
+    /// The minimum contrast ratio for `level`
+    pub fn required_ratio(&self, level: WcagLevel) -> f64 {
+        match (self, level) {
+            (ContrastUsage::Text, WcagLevel::AA) => 4.5,
+            (ContrastUsage::Text, WcagLevel::AAA) => 7.0,
+            (ContrastUsage::LargeText, WcagLevel::AA) => 3.0,
+            (ContrastUsage::LargeText, WcagLevel::AAA) => 4.5,
+            // WCAG has no AAA criterion for non-text contrast
+            (ContrastUsage::Graphics, _) => 3.0,
+        }
+    }
+}
+
+/// A foreground and background the components draw together, as
+/// `group.field` paths into [`CSSVariables`]
+struct ContrastPair {
+    name: &'static str,
+    foreground: &'static str,
+    background: &'static str,
+    usage: ContrastUsage,
+}
+
+const CONTRAST_PAIRS: &[ContrastPair] = &[
+    ContrastPair {
+        name: "Body text",
+        foreground: "neutral.neutral_900",
+        background: "neutral.neutral_50",
+        usage: ContrastUsage::Text,
+    },
+    ContrastPair {
+        name: "Muted text",
+        foreground: "neutral.neutral_600",
+        background: "neutral.neutral_50",
+        usage: ContrastUsage::Text,
+    },
+    ContrastPair {
+        name: "Links",
+        foreground: "primary.primary_600",
+        background: "neutral.neutral_50",
+        usage: ContrastUsage::Text,
+    },
+    ContrastPair {
+        name: "Primary button label",
+        foreground: "primary.primary_50",
+        background: "primary.primary_600",
+        usage: ContrastUsage::Text,
+    },
+    ContrastPair {
+        name: "Secondary button label",
+        foreground: "secondary.secondary_50",
+        background: "secondary.secondary_600",
+        usage: ContrastUsage::Text,
+    },
+    ContrastPair {
+        name: "Success indicator",
+        foreground: "semantic.success",
+        background: "neutral.neutral_50",
+        usage: ContrastUsage::Graphics,
+    },
+    ContrastPair {
+        name: "Warning indicator",
+        foreground: "semantic.warning",
+        background: "neutral.neutral_50",
+        usage: ContrastUsage::Graphics,
+    },
+    ContrastPair {
+        name: "Error indicator",
+        foreground: "semantic.error",
+        background: "neutral.neutral_50",
+        usage: ContrastUsage::Graphics,
+    },
+    ContrastPair {
+        name: "Info indicator",
+        foreground: "semantic.info",
+        background: "neutral.neutral_50",
+        usage: ContrastUsage::Graphics,
+    },
+];
+
+/// A pairing whose contrast is too low
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContrastIssue {
+    /// What the pairing is used for, e.g. "Body text"
+    pub name: &'static str,
+    /// Path of the foreground variable, e.g. `neutral.neutral_900`
+    pub foreground: &'static str,
+    /// Path of the background variable
+    pub background: &'static str,
+    pub usage: ContrastUsage,
+    pub ratio: f64,
+    pub required: f64,
+    /// The passing foreground closest in lightness to the current one
+    pub suggestion: Option<Color>,
+}
+
+impl ContrastIssue {
+    /// `theme` with the foreground replaced by the suggested color
+    pub fn apply_suggestion(&self, theme: &CSSVariables) -> Option<CSSVariables> {
+        let suggestion = self.suggestion?;
+        let (group, field) = self.foreground.split_once('.')?;
+        let mut fields = serde_json::to_value(theme).ok()?;
+        *fields.get_mut(group)?.get_mut(field)? = Value::String(suggestion.to_hex());
+        serde_json::from_value(fields).ok()
+    }
+}
+
+impl fmt::Display for ContrastIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: `{}` on `{}` has a contrast of {:.2}:1, {}:1 is required",
+            self.name, self.foreground, self.background, self.ratio, self.required
+        )
+    }
+}
+
+/// Checks themes against the WCAG 2.1 contrast requirements
+///
+/// Only hex colors are checked; variables holding other CSS colors are
+/// skipped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ThemeValidator {
+    level: WcagLevel,
+}
+
+impl ThemeValidator {
+    pub fn new(level: WcagLevel) -> Self {
+        Self { level }
+    }
+
+    pub fn level(&self) -> WcagLevel {
+        self.level
+    }
+
+    /// Every pairing in `theme` below the required contrast
+    pub fn validate(&self, theme: &CSSVariables) -> Vec<ContrastIssue> {
+        let fields = serde_json::to_value(theme).unwrap_or_default();
+        let color = |path: &str| color_at(&fields, path);
+
+        CONTRAST_PAIRS
+            .iter()
+            .filter_map(|pair| {
+                let foreground = color(pair.foreground)?;
+                let background = color(pair.background)?;
+                let ratio = foreground.contrast_ratio(&background);
+                let required = pair.usage.required_ratio(self.level);
+                (ratio < required).then(|| ContrastIssue {
+                    name: pair.name,
+                    foreground: pair.foreground,
+                    background: pair.background,
+                    usage: pair.usage,
+                    ratio,
+                    required,
+                    suggestion: nearest_passing_color(foreground, background, required),
+                })
+            })
+            .collect()
+    }
+
+    pub fn is_valid(&self, theme: &CSSVariables) -> bool {
+        self.validate(theme).is_empty()
+    }
+}
+
+/// A high-contrast variant of `theme`
+///
+/// Every pairing [`ThemeValidator`] checks is pushed to at least
+/// [`HIGH_CONTRAST_RATIO`] while keeping its hue: the foreground moves to the
+/// closest lightness that passes, and where no shade of its hue does, it
+/// becomes black or white and the background moves away from it. Border
+/// widths are doubled, and the focus ring is half as thick again and drawn
+/// in the primary color that links use, which is kept at 7:1 on the
+/// background.
+pub fn derive_high_contrast(theme: &CSSVariables) -> CSSVariables {
+    let mut fields = serde_json::to_value(theme).unwrap_or_default();
+    for _ in 0..HIGH_CONTRAST_PASSES {
+        let mut changed = false;
+        for pair in CONTRAST_PAIRS {
+            let (Some(foreground), Some(background)) = (
+                color_at(&fields, pair.foreground),
+                color_at(&fields, pair.background),
+            ) else {
+                continue;
+            };
+            if foreground.contrast_ratio(&background) >= HIGH_CONTRAST_RATIO {
+                continue;
+            }
+            changed = true;
+            if let Some(color) = nearest_passing_color(foreground, background, HIGH_CONTRAST_RATIO)
+            {
+                set_color(&mut fields, pair.foreground, color);
+                continue;
+            }
+            let black = Color::new(0, 0, 0);
+            let white = Color::new(255, 255, 255);
+            let extreme = if black.contrast_ratio(&background) > white.contrast_ratio(&background) {
+                black
+            } else {
+                white
+            };
+            set_color(&mut fields, pair.foreground, extreme);
+            if let Some(color) = nearest_passing_color(background, extreme, HIGH_CONTRAST_RATIO) {
+                set_color(&mut fields, pair.background, color);
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    let mut high_contrast: CSSVariables =
+        serde_json::from_value(fields).unwrap_or_else(|_| theme.clone());
+    let border = &mut high_contrast.border;
+    for width in [
+        &mut border.border_width_1,
+        &mut border.border_width_2,
+        &mut border.border_width_4,
+        &mut border.border_width_8,
+    ] {
+        *width = scale_length(width, 2.0);
+    }
+    border.focus_ring_width = scale_length(&border.focus_ring_width, 1.5);
+    border.focus_ring_color = high_contrast.primary.primary_600.clone();
+    high_contrast
+}
+
+fn color_at(fields: &Value, path: &str) -> Option<Color> {
+    let (group, field) = path.split_once('.')?;
+    Color::from_hex(fields.get(group)?.get(field)?.as_str()?)
+}
+
+fn set_color(fields: &mut Value, path: &str, color: Color) {
+    let value = path
+        .split_once('.')
+        .and_then(|(group, field)| fields.get_mut(group)?.get_mut(field));
+    if let Some(value) = value {
+        *value = Value::String(color.to_hex());
+    }
+}
+
+/// A CSS length such as `1px` multiplied by `factor`, unchanged if it has no
+/// leading number
+fn scale_length(length: &str, factor: f64) -> String {
+    let split = length
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(length.len());
+    let (amount, unit) = length.split_at(split);
+    match amount.parse::<f64>() {
+        Ok(amount) => format!("{}{}", amount * factor, unit),
+        Err(_) => length.to_string(),
+    }
+}
+
+/// The color with the hue of `foreground` and the closest lightness that
+/// reaches `required` against `background`
+fn nearest_passing_color(foreground: Color, background: Color, required: f64) -> Option<Color> {
+    let start = foreground.to_oklch();
+    let search = |direction: f64| {
+        let mut lightness = start.l;
+        while (0.0..=1.0).contains(&lightness) {
+            let candidate = Color::from_oklch(Oklch {
+                l: lightness,
+                ..start
+            });
+            if candidate.contrast_ratio(&background) >= required {
+                return Some((candidate, (lightness - start.l).abs()));
+            }
+            lightness += direction * SUGGESTION_STEP;
+        }
+        None
+    };
+    match (search(-1.0), search(1.0)) {
+        (Some(darker), Some(lighter)) if lighter.1 < darker.1 => Some(lighter.0),
+        (Some(darker), _) => Some(darker.0),
+        (None, lighter) => lighter.map(|(color, _)| color),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contrast_ratio() {
+        let black = Color::new(0, 0, 0);
+        let white = Color::new(255, 255, 255);
+        assert!((black.contrast_ratio(&white) - 21.0).abs() < 1e-9);
+        assert!((white.contrast_ratio(&white) - 1.0).abs() < 1e-9);
+        let gray = Color::from_hex("#767676").unwrap();
+        assert!((gray.contrast_ratio(&white) - 4.54).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_theme_validator() {
+        let mut theme = CSSVariables::default();
+        theme.neutral.neutral_600 = "#a3a3a3".to_string();
+        theme.semantic.info = "rgb(59 130 246)".to_string();
+
+        let issues = ThemeValidator::default().validate(&theme);
+        let muted = issues
+            .iter()
+            .find(|issue| issue.foreground == "neutral.neutral_600")
+            .unwrap();
+        assert_eq!(muted.required, 4.5);
+        assert!(muted.ratio < 4.5);
+        assert!(!issues
+            .iter()
+            .any(|issue| issue.foreground == "neutral.neutral_900"));
+        // Colors that aren't hex are skipped
+        assert!(!issues
+            .iter()
+            .any(|issue| issue.foreground == "semantic.info"));
+
+        // The suggestion passes, and is darker since the background is light
+        let suggestion = muted.suggestion.unwrap();
+        let background = Color::from_hex(&theme.neutral.neutral_50).unwrap();
+        assert!(suggestion.contrast_ratio(&background) >= 4.5);
+        assert!(
+            suggestion.relative_luminance() < Color::new(0xa3, 0xa3, 0xa3).relative_luminance()
+        );
+
+        let fixed = muted.apply_suggestion(&theme).unwrap();
+        assert_eq!(fixed.neutral.neutral_600, suggestion.to_hex());
+        let validator = ThemeValidator::new(WcagLevel::AA);
+        assert!(!validator
+            .validate(&fixed)
+            .iter()
+            .any(|issue| issue.foreground == "neutral.neutral_600"));
+
+        // AAA asks more of the same pairing
+        let strict = ThemeValidator::new(WcagLevel::AAA).validate(&fixed);
+        let muted = strict
+            .iter()
+            .find(|issue| issue.foreground == "neutral.neutral_600")
+            .unwrap();
+        assert_eq!(muted.required, 7.0);
+    }
+
+    #[test]
+    fn test_derive_high_contrast() {
+        let brand = crate::theming::ThemeBuilder::new()
+            .with_brand_color(Color::from_hex("#e11d48").unwrap())
+            .build();
+        for theme in [CSSVariables::default(), CSSVariables::dark_theme(), brand] {
+            let high_contrast = derive_high_contrast(&theme);
+            for pair in CONTRAST_PAIRS {
+                let fields = serde_json::to_value(&high_contrast).unwrap();
+                let (Some(foreground), Some(background)) = (
+                    color_at(&fields, pair.foreground),
+                    color_at(&fields, pair.background),
+                ) else {
+                    continue;
+                };
+                assert!(
+                    foreground.contrast_ratio(&background) >= HIGH_CONTRAST_RATIO,
+                    "{}",
+                    pair.name
+                );
+            }
+            assert_eq!(high_contrast.border.border_width_0, "0px");
+            assert_eq!(high_contrast.border.border_width_1, "2px");
+            assert_eq!(high_contrast.typography, theme.typography);
+        }
+        // Body text already passes, so it keeps its color
+        let theme = CSSVariables::default();
+        assert_eq!(
+            derive_high_contrast(&theme).neutral.neutral_900,
+            theme.neutral.neutral_900
+        );
+
+        // The derived theme carries a thicker focus ring in the link color
+        let high_contrast = derive_high_contrast(&theme);
+        assert_eq!(theme.border.focus_ring_width, "2px");
+        assert_eq!(high_contrast.border.focus_ring_width, "3px");
+        assert_eq!(
+            high_contrast.border.focus_ring_color,
+            high_contrast.primary.primary_600
+        );
+        assert!(high_contrast
+            .to_css_string()
+            .contains("--focus-ring-width: 3px;"));
+        let patch = theme.diff(&high_contrast);
+        assert_eq!(patch.variables["focus-ring-width"], "3px");
+        assert!(patch.unknown_variables().is_empty());
+        assert_eq!(scale_length("0.125rem", 2.0), "0.25rem");
+        assert_eq!(scale_length("thin", 2.0), "thin");
+    }
+}
@@ -0,0 +1,315 @@
+//! Import of Tokens Studio (Figma Tokens) exports
+//!
+//! Token sets are combined into a light and a dark theme, then read like a
+//! W3C design tokens document, so tokens are matched by the same paths as
+//! [`CSSVariables::to_design_tokens`] produces, e.g. `primary.500`.
+
+use super::css_variables::CSSVariables;
+use super::design_tokens::DesignTokenError;
+use super::prebuilt_themes::PrebuiltThemes;
+use leptos::serde_json::{Map, Value};
+
+/// Problems found while importing a Tokens Studio export
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum TokensStudioError {
+    #[error("the export is not a JSON object")]
+    NotAnObject,
+    #[error("theme `{theme}` uses token set `{set}`, which is not in the export")]
+    UnknownTokenSet { theme: String, set: String },
+    #[error("{theme} theme: {error}")]
+    Token {
+        theme: &'static str,
+        error: DesignTokenError,
+    },
+}
+
+/// Result of importing a Tokens Studio export, with every problem found
+pub type TokensStudioResult<T> = Result<T, Vec<TokensStudioError>>;
+
+/// The light and dark themes of a Tokens Studio export
+#[derive(Debug, Clone, PartialEq)]
+pub struct TokensStudioThemes {
+    pub light: CSSVariables,
+    pub dark: CSSVariables,
+}
+
+impl TokensStudioThemes {
+    /// Convert a Tokens Studio export
+    ///
+    /// Themes from `$themes` whose name contains "dark" make up the dark
+    /// theme and the others the light one, each from the sets they enable
+    /// or use as source. Exports without `$themes` use the `light` and
+    /// `dark` sets, on top of every other set. Sets are applied in
+    /// `$metadata.tokenSetOrder`, later sets winning, and references such
+    /// as `{palette.blue.500}` or `$palette.blue.500` are resolved across
+    /// all of a theme's sets. Tokens missing from a theme keep the value of
+    /// the built-in light or dark theme.
+    pub fn from_json(export: &Value) -> TokensStudioResult<Self> {
+        let Some(sets) = export.as_object() else {
+            return Err(vec![TokensStudioError::NotAnObject]);
+        };
+        let mut errors = Vec::new();
+        let order = set_order(sets);
+        let (light_sets, dark_sets) = match export.get("$themes").and_then(Value::as_array) {
+            Some(themes) => {
+                let mut light = Vec::new();
+                let mut dark = Vec::new();
+                for theme in themes {
+                    let name = theme
+                        .get("name")
+                        .and_then(Value::as_str)
+                        .unwrap_or_default();
+                    let selected = theme
+                        .get("selectedTokenSets")
+                        .and_then(Value::as_object)
+                        .into_iter()
+                        .flatten()
+                        .filter(|(_, status)| matches!(status.as_str(), Some("enabled" | "source")))
+                        .map(|(set, _)| set.clone());
+                    for set in selected {
+                        if !sets.contains_key(&set) {
+                            errors.push(TokensStudioError::UnknownTokenSet {
+                                theme: name.to_string(),
+                                set,
+                            });
+                        } else if name.to_lowercase().contains("dark") {
+                            dark.push(set);
+                        } else {
+                            light.push(set);
+                        }
+                    }
+                }
+                (light, dark)
+            }
+            None => {
+                let shared = order
+                    .iter()
+                    .filter(|set| !matches!(set.as_str(), "light" | "dark"));
+                let with = |theme: &str| {
+                    let mut selected: Vec<String> = shared.clone().cloned().collect();
+                    if sets.contains_key(theme) {
+                        selected.push(theme.to_string());
+                    }
+                    selected
+                };
+                (with("light"), with("dark"))
+            }
+        };
+
+        let light = import_theme(
+            sets,
+            &order,
+            &light_sets,
+            "light",
+            CSSVariables::light_theme(),
+        );
+        let dark = import_theme(sets, &order, &dark_sets, "dark", CSSVariables::dark_theme());
+        match (light, dark) {
+            (Ok(light), Ok(dark)) if errors.is_empty() => Ok(Self { light, dark }),
+            (light, dark) => {
+                errors.extend(light.err().into_iter().flatten());
+                errors.extend(dark.err().into_iter().flatten());
+                Err(errors)
+            }
+        }
+    }
+}
+
+impl PrebuiltThemes {
+    /// The prebuilt themes with light and dark replaced by the ones in a
+    /// Tokens Studio export
+    pub fn from_tokens_studio(export: &Value) -> TokensStudioResult<Self> {
+        let TokensStudioThemes { light, dark } = TokensStudioThemes::from_json(export)?;
+        Ok(Self {
+            light,
+            dark,
+            ..Self::default()
+        })
+    }
+}
+
+/// Token set names, in the order the export applies them
+fn set_order(sets: &Map<String, Value>) -> Vec<String> {
+    let mut order: Vec<String> = sets
+        .get("$metadata")
+        .and_then(|metadata| metadata.get("tokenSetOrder"))
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .filter(|set| sets.contains_key(*set))
+        .map(str::to_string)
+        .collect();
+    for set in sets.keys() {
+        if !set.starts_with('$') && !order.contains(set) {
+            order.push(set.clone());
+        }
+    }
+    order
+}
+
+fn import_theme(
+    sets: &Map<String, Value>,
+    order: &[String],
+    selected: &[String],
+    theme: &'static str,
+    base: CSSVariables,
+) -> TokensStudioResult<CSSVariables> {
+    let mut document = base.to_design_tokens();
+    for set in order.iter().filter(|set| selected.contains(set)) {
+        if let Some(tokens) = sets.get(set) {
+            merge_tokens(&mut document, &design_tokens(tokens));
+        }
+    }
+    CSSVariables::from_design_tokens(&document).map_err(|errors| {
+        errors
+            .into_iter()
+            .map(|error| TokensStudioError::Token { theme, error })
+            .collect()
+    })
+}
+
+/// A token set in the W3C format: `value` and `type` become `$value` and
+/// `$type`, and `$alias` references become `{alias}`
+fn design_tokens(tokens: &Value) -> Value {
+    let Some(group) = tokens.as_object() else {
+        return tokens.clone();
+    };
+    let value = group.get("value").or_else(|| group.get("$value"));
+    let Some(value) = value else {
+        return Value::Object(
+            group
+                .iter()
+                .filter(|(name, _)| !name.starts_with('$'))
+                .map(|(name, child)| (name.clone(), design_tokens(child)))
+                .collect(),
+        );
+    };
+
+    let mut token = Map::new();
+    let value = match value.as_str().and_then(|value| value.strip_prefix('$')) {
+        Some(alias) if !alias.contains(char::is_whitespace) => {
+            Value::String(format!("{{{}}}", alias))
+        }
+        _ => value.clone(),
+    };
+    token.insert("$value".to_string(), value);
+    let token_type = group
+        .get("type")
+        .or_else(|| group.get("$type"))
+        .and_then(Value::as_str);
+    if let Some(token_type) = token_type.and_then(design_token_type) {
+        token.insert("$type".to_string(), token_type.into());
+    }
+    Value::Object(token)
+}
+
+/// The W3C type of a Tokens Studio type, for the types that have one
+fn design_token_type(token_type: &str) -> Option<&'static str> {
+    match token_type {
+        "color" => Some("color"),
+        "dimension" | "spacing" | "sizing" | "borderRadius" | "borderWidth" | "fontSizes" => {
+            Some("dimension")
+        }
+        "fontFamilies" => Some("fontFamily"),
+        "fontWeights" => Some("fontWeight"),
+        "lineHeights" => Some("number"),
+        _ => None,
+    }
+}
+
+/// Copy the groups and tokens of `tokens` into `document`
+fn merge_tokens(document: &mut Value, tokens: &Value) {
+    let (Some(document), Some(tokens)) = (document.as_object_mut(), tokens.as_object()) else {
+        return;
+    };
+    for (name, child) in tokens {
+        match document.get_mut(name) {
+            Some(existing) if child.get("$value").is_none() && existing.is_object() => {
+                merge_tokens(existing, child)
+            }
+            _ => {
+                document.insert(name.clone(), child.clone());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptos::serde_json::json;
+
+    #[test]
+    fn test_tokens_studio_themes() {
+        let export = json!({
+            "core": {
+                "palette": {
+                    "pink": { "value": "#db2777", "type": "color" },
+                    "ink": { "value": "#111827", "type": "color" }
+                },
+                "spacing": { "4": { "value": "1.125rem", "type": "spacing" } }
+            },
+            "light": {
+                "primary": { "500": { "value": "{palette.pink}", "type": "color" } }
+            },
+            "dark": {
+                "primary": { "500": { "value": "$palette.pink", "type": "color" } },
+                "neutral": { "50": { "value": "{palette.ink}", "type": "color" } }
+            },
+            "$themes": [
+                { "name": "Light", "selectedTokenSets": { "core": "source", "light": "enabled", "dark": "disabled" } },
+                { "name": "Dark", "selectedTokenSets": { "core": "source", "dark": "enabled" } }
+            ],
+            "$metadata": { "tokenSetOrder": ["core", "light", "dark"] }
+        });
+        let themes = TokensStudioThemes::from_json(&export).unwrap();
+
+        assert_eq!(themes.light.primary.primary_500, "#db2777");
+        assert_eq!(themes.light.spacing.space_4, "1.125rem");
+        assert_eq!(themes.light.neutral, CSSVariables::light_theme().neutral);
+        assert_eq!(themes.dark.primary.primary_500, "#db2777");
+        assert_eq!(themes.dark.neutral.neutral_50, "#111827");
+        assert_eq!(
+            themes.dark.neutral.neutral_950,
+            CSSVariables::dark_theme().neutral.neutral_950
+        );
+
+        let prebuilt = PrebuiltThemes::from_tokens_studio(&export).unwrap();
+        assert_eq!(prebuilt.dark, themes.dark);
+        assert_eq!(prebuilt.finance, PrebuiltThemes::default().finance);
+    }
+
+    #[test]
+    fn test_tokens_studio_without_themes_and_errors() {
+        let export = json!({
+            "base": { "primary": { "600": { "value": "#1d4ed8", "type": "color" } } },
+            "dark": { "primary": { "600": { "value": "#60a5fa", "type": "color" } } }
+        });
+        let themes = TokensStudioThemes::from_json(&export).unwrap();
+        assert_eq!(themes.light.primary.primary_600, "#1d4ed8");
+        assert_eq!(themes.dark.primary.primary_600, "#60a5fa");
+
+        let errors = TokensStudioThemes::from_json(&json!({
+            "light": { "primary": { "500": { "value": "{palette.missing}" } } },
+            "$themes": [{ "name": "Light", "selectedTokenSets": { "light": "enabled", "brand": "source" } }]
+        }))
+        .unwrap_err();
+        assert_eq!(
+            errors,
+            vec![
+                TokensStudioError::UnknownTokenSet {
+                    theme: "Light".to_string(),
+                    set: "brand".to_string(),
+                },
+                TokensStudioError::Token {
+                    theme: "light",
+                    error: DesignTokenError::UnresolvedAlias {
+                        path: "primary.500".to_string(),
+                        alias: "palette.missing".to_string(),
+                    },
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,295 @@
+//! Fluid typography
+//!
+//! Font sizes follow a modular scale, each step being the one below times a
+//! ratio. The base size and ratio can differ between a small and a large
+//! viewport, and sizes grow linearly between the two with CSS `clamp()`, so
+//! headings shrink on phones without media queries.
+
+use super::css_variables::{CSSVariables, TypographyVariables};
+use super::layout_system::Breakpoint;
+use serde::{Deserialize, Serialize};
+
+/// Pixels per `rem`, assuming the browser's default root font size
+pub const ROOT_FONT_SIZE: f64 = 16.0;
+
+/// A size of the type scale, named like the theme's `--font-size-*`
+/// variables
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum TextSize {
+    Xs,
+    Sm,
+    #[default]
+    Base,
+    Lg,
+    Xl,
+    Xl2,
+    Xl3,
+    Xl4,
+    Xl5,
+    Xl6,
+}
+
+impl TextSize {
+    pub const ALL: [TextSize; 10] = [
+        TextSize::Xs,
+        TextSize::Sm,
+        TextSize::Base,
+        TextSize::Lg,
+        TextSize::Xl,
+        TextSize::Xl2,
+        TextSize::Xl3,
+        TextSize::Xl4,
+        TextSize::Xl5,
+        TextSize::Xl6,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TextSize::Xs => "xs",
+            TextSize::Sm => "sm",
+            TextSize::Base => "base",
+            TextSize::Lg => "lg",
+            TextSize::Xl => "xl",
+            TextSize::Xl2 => "2xl",
+            TextSize::Xl3 => "3xl",
+            TextSize::Xl4 => "4xl",
+            TextSize::Xl5 => "5xl",
+            TextSize::Xl6 => "6xl",
+        }
+    }
+
+    /// Steps above (or below) the base size on the modular scale
+    pub fn step(&self) -> i32 {
+        match self {
+            TextSize::Xs => -2,
+            TextSize::Sm => -1,
+            TextSize::Base => 0,
+            TextSize::Lg => 1,
+            TextSize::Xl => 2,
+            TextSize::Xl2 => 3,
+            TextSize::Xl3 => 4,
+            TextSize::Xl4 => 5,
+            TextSize::Xl5 => 6,
+            TextSize::Xl6 => 7,
+        }
+    }
+
+    /// The `font-size` value for this size: the theme's variable, falling
+    /// back to the default fluid size outside a themed tree
+    pub fn css_value(&self) -> String {
+        format!(
+            "var(--font-size-{}, {})",
+            self.as_str(),
+            TypographySystem::default().fluid_size(*self)
+        )
+    }
+}
+
+/// `base` moved `step` steps along a modular scale of `ratio`
+pub fn modular_scale(base: f64, ratio: f64, step: i32) -> f64 {
+    base * ratio.powi(step)
+}
+
+/// Type scale that interpolates between a small and a large viewport
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TypographySystem {
+    /// Base size in pixels at `min_viewport` and below
+    pub min_base_size: f64,
+    /// Base size in pixels at `max_viewport` and above
+    pub max_base_size: f64,
+    /// Scale ratio at `min_viewport` and below
+    pub min_ratio: f64,
+    /// Scale ratio at `max_viewport` and above
+    pub max_ratio: f64,
+    pub min_viewport: Breakpoint,
+    pub max_viewport: Breakpoint,
+}
+
+impl Default for TypographySystem {
+    fn default() -> Self {
+        Self {
+            min_base_size: 16.0,
+            max_base_size: 18.0,
+            min_ratio: Self::MINOR_THIRD,
+            max_ratio: Self::MAJOR_THIRD,
+            min_viewport: Breakpoint::Small,
+            max_viewport: Breakpoint::ExtraLarge,
+        }
+    }
+}
+
+impl TypographySystem {
+    pub const MAJOR_SECOND: f64 = 1.125;
+    pub const MINOR_THIRD: f64 = 1.2;
+    pub const MAJOR_THIRD: f64 = 1.25;
+    pub const PERFECT_FOURTH: f64 = 1.333;
+    pub const GOLDEN_RATIO: f64 = 1.618;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Base sizes in pixels at the small and the large viewport
+    pub fn with_base_size(mut self, min: f64, max: f64) -> Self {
+        self.min_base_size = min;
+        self.max_base_size = max;
+        self
+    }
+
+    /// Scale ratios at the small and the large viewport
+    pub fn with_ratio(mut self, min: f64, max: f64) -> Self {
+        self.min_ratio = min;
+        self.max_ratio = max;
+        self
+    }
+
+    /// Breakpoints between which sizes are fluid
+    pub fn with_viewports(mut self, min: Breakpoint, max: Breakpoint) -> Self {
+        self.min_viewport = min;
+        self.max_viewport = max;
+        self
+    }
+
+    /// Size in pixels at the small viewport
+    pub fn min_size(&self, size: TextSize) -> f64 {
+        modular_scale(self.min_base_size, self.min_ratio, size.step())
+    }
+
+    /// Size in pixels at the large viewport
+    pub fn max_size(&self, size: TextSize) -> f64 {
+        modular_scale(self.max_base_size, self.max_ratio, size.step())
+    }
+
+    /// Size in pixels at a viewport `width` pixels wide, as the browser
+    /// resolves [`Self::fluid_size`]
+    pub fn size_at(&self, size: TextSize, width: f64) -> f64 {
+        let (min_width, max_width) = self.viewport_range();
+        let (min, max) = (self.min_size(size), self.max_size(size));
+        if max_width <= min_width {
+            return min;
+        }
+        let progress = ((width - min_width) / (max_width - min_width)).clamp(0.0, 1.0);
+        min + (max - min) * progress
+    }
+
+    /// CSS value of a size, a `clamp()` growing with the viewport width
+    /// between the two breakpoints
+    pub fn fluid_size(&self, size: TextSize) -> String {
+        let (min_width, max_width) = self.viewport_range();
+        let (min, max) = (self.min_size(size), self.max_size(size));
+        if max_width <= min_width || (max - min).abs() < 1e-6 {
+            return format!("{}rem", to_rem(min));
+        }
+        let slope = (max - min) / (max_width - min_width);
+        let intercept = min - slope * min_width;
+        format!(
+            "clamp({}rem, {}rem + {}vw, {}rem)",
+            to_rem(min.min(max)),
+            to_rem(intercept),
+            round(slope * 100.0),
+            to_rem(min.max(max))
+        )
+    }
+
+    /// The scale as `--font-size-xs`, `--font-size-sm`, … declarations
+    pub fn to_css_declarations(&self) -> String {
+        TextSize::ALL
+            .iter()
+            .map(|size| format!("--font-size-{}: {};", size.as_str(), self.fluid_size(*size)))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Replace the font sizes of `typography` with the fluid ones
+    pub fn apply(&self, typography: &mut TypographyVariables) {
+        for size in TextSize::ALL {
+            *font_size_mut(typography, size) = self.fluid_size(size);
+        }
+    }
+
+    fn viewport_range(&self) -> (f64, f64) {
+        (self.min_viewport.min_width(), self.max_viewport.min_width())
+    }
+}
+
+impl CSSVariables {
+    /// The theme with the font sizes of `system`, so `--font-size-*` are
+    /// fluid wherever the theme is applied
+    pub fn with_typography_system(mut self, system: &TypographySystem) -> Self {
+        system.apply(&mut self.typography);
+        self
+    }
+}
+
+fn font_size_mut(typography: &mut TypographyVariables, size: TextSize) -> &mut String {
+    match size {
+        TextSize::Xs => &mut typography.font_size_xs,
+        TextSize::Sm => &mut typography.font_size_sm,
+        TextSize::Base => &mut typography.font_size_base,
+        TextSize::Lg => &mut typography.font_size_lg,
+        TextSize::Xl => &mut typography.font_size_xl,
+        TextSize::Xl2 => &mut typography.font_size_2xl,
+        TextSize::Xl3 => &mut typography.font_size_3xl,
+        TextSize::Xl4 => &mut typography.font_size_4xl,
+        TextSize::Xl5 => &mut typography.font_size_5xl,
+        TextSize::Xl6 => &mut typography.font_size_6xl,
+    }
+}
+
+fn to_rem(pixels: f64) -> f64 {
+    round(pixels / ROOT_FONT_SIZE)
+}
+
+/// Four decimals are plenty for CSS lengths and keep the values readable
+fn round(value: f64) -> f64 {
+    (value * 10_000.0).round() / 10_000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_modular_scale() {
+        assert_eq!(modular_scale(16.0, 1.25, 0), 16.0);
+        assert_eq!(modular_scale(16.0, 1.25, 2), 25.0);
+        assert_eq!(modular_scale(16.0, 1.25, -1), 12.8);
+
+        let system = TypographySystem::new();
+        assert_eq!(system.min_size(TextSize::Base), 16.0);
+        assert_eq!(system.max_size(TextSize::Lg), 22.5);
+        assert!(TextSize::ALL
+            .windows(2)
+            .all(|pair| system.max_size(pair[0]) < system.max_size(pair[1])));
+    }
+
+    #[test]
+    fn test_fluid_sizes() {
+        let system = TypographySystem::new();
+        assert_eq!(
+            system.fluid_size(TextSize::Base),
+            "clamp(1rem, 0.875rem + 0.3125vw, 1.125rem)"
+        );
+        assert_eq!(system.size_at(TextSize::Base, 320.0), 16.0);
+        assert_eq!(system.size_at(TextSize::Base, 960.0), 17.0);
+        assert_eq!(system.size_at(TextSize::Base, 1920.0), 18.0);
+
+        // A fixed scale needs no clamp
+        let fixed = system.with_base_size(16.0, 16.0).with_ratio(1.25, 1.25);
+        assert_eq!(fixed.fluid_size(TextSize::Xl), "1.5625rem");
+
+        let css = system.to_css_declarations();
+        assert!(css.starts_with("--font-size-xs: clamp("));
+        assert!(css.contains("--font-size-base: clamp(1rem, 0.875rem + 0.3125vw, 1.125rem);"));
+
+        let theme = CSSVariables::default().with_typography_system(&system);
+        assert_eq!(
+            theme.typography.font_size_6xl,
+            system.fluid_size(TextSize::Xl6)
+        );
+        assert!(theme.to_css_string().contains(&format!(
+            "--font-size-lg: {};",
+            system.fluid_size(TextSize::Lg)
+        )));
+    }
+}
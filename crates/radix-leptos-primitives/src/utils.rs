@@ -60,3 +60,302 @@ pub fn generate_id(prefix: &str) -> String {
     let id = COUNTER.fetch_add(1, Ordering::Relaxed);
     format!("{}-{}", prefix, id)
 }
+
+/// Inline `style` with `declarations` appended while forced colors are
+/// active
+///
+/// Components that are told apart by background colors alone use this to
+/// switch to system colors and borders, which forced colors mode keeps.
+pub fn forced_colors_style(style: Option<&str>, forced: bool, declarations: &str) -> String {
+    let style = style.unwrap_or("").trim();
+    if !forced {
+        return style.to_string();
+    }
+    match style {
+        "" => declarations.to_string(),
+        style if style.ends_with(';') => format!("{} {}", style, declarations),
+        style => format!("{}; {}", style, declarations),
+    }
+}
+
+/// Log a development-mode warning about an inconsistent component prop
+///
+/// Compiled out of release builds so production bundles stay quiet.
+pub fn warn_invalid_prop(component: &str, message: &str) {
+    #[cfg(debug_assertions)]
+    leptos::logging::warn!("[radix-leptos] {}: {}", component, message);
+    #[cfg(not(debug_assertions))]
+    let _ = (component, message);
+}
+
+/// Normalise a `min`/`max` pair, swapping inverted bounds and replacing
+/// non-finite values with the given defaults
+pub fn sanitize_range(component: &str, min: f64, max: f64, defaults: (f64, f64)) -> (f64, f64) {
+    let min = if min.is_finite() {
+        min
+    } else {
+        warn_invalid_prop(component, &format!("min {} is not finite, using {}", min, defaults.0));
+        defaults.0
+    };
+    let max = if max.is_finite() {
+        max
+    } else {
+        warn_invalid_prop(component, &format!("max {} is not finite, using {}", max, defaults.1));
+        defaults.1
+    };
+
+    if min > max {
+        warn_invalid_prop(component, &format!("min {} is greater than max {}, swapping", min, max));
+        (max, min)
+    } else {
+        (min, max)
+    }
+}
+
+/// Clamp a value into `[min, max]`, falling back to `min` for NaN
+pub fn clamp_to_range(component: &str, value: f64, min: f64, max: f64) -> f64 {
+    if value.is_nan() {
+        warn_invalid_prop(component, &format!("value is NaN, using {}", min));
+        return min;
+    }
+    if value < min || value > max {
+        warn_invalid_prop(
+            component,
+            &format!("value {} is outside [{}, {}], clamping", value, min, max),
+        );
+    }
+    value.clamp(min, max)
+}
+
+/// Normalise a 1-based `(current_page, total_pages)` pair so that
+/// `1 <= current_page <= total_pages`
+pub fn sanitize_page(component: &str, current_page: usize, total_pages: usize) -> (usize, usize) {
+    let total_pages = if total_pages == 0 {
+        warn_invalid_prop(component, "total_pages is 0, using 1");
+        1
+    } else {
+        total_pages
+    };
+
+    let current_page = if current_page == 0 || current_page > total_pages {
+        let clamped = current_page.clamp(1, total_pages);
+        warn_invalid_prop(
+            component,
+            &format!(
+                "current_page {} is outside 1..={}, using {}",
+                current_page, total_pages, clamped
+            ),
+        );
+        clamped
+    } else {
+        current_page
+    };
+
+    (current_page, total_pages)
+}
+
+/// CSS named colors accepted by [`is_valid_css_color`]
+const CSS_NAMED_COLORS: &[&str] = &[
+    "aliceblue", "antiquewhite", "aqua", "aquamarine", "azure", "beige", "bisque", "black",
+    "blanchedalmond", "blue", "blueviolet", "brown", "burlywood", "cadetblue", "chartreuse",
+    "chocolate", "coral", "cornflowerblue", "cornsilk", "crimson", "cyan", "darkblue",
+    "darkcyan", "darkgoldenrod", "darkgray", "darkgreen", "darkgrey", "darkkhaki",
+    "darkmagenta", "darkolivegreen", "darkorange", "darkorchid", "darkred", "darksalmon",
+    "darkseagreen", "darkslateblue", "darkslategray", "darkslategrey", "darkturquoise",
+    "darkviolet", "deeppink", "deepskyblue", "dimgray", "dimgrey", "dodgerblue", "firebrick",
+    "floralwhite", "forestgreen", "fuchsia", "gainsboro", "ghostwhite", "gold", "goldenrod",
+    "gray", "green", "greenyellow", "grey", "honeydew", "hotpink", "indianred", "indigo",
+    "ivory", "khaki", "lavender", "lavenderblush", "lawngreen", "lemonchiffon", "lightblue",
+    "lightcoral", "lightcyan", "lightgoldenrodyellow", "lightgray", "lightgreen", "lightgrey",
+    "lightpink", "lightsalmon", "lightseagreen", "lightskyblue", "lightslategray",
+    "lightslategrey", "lightsteelblue", "lightyellow", "lime", "limegreen", "linen", "magenta",
+    "maroon", "mediumaquamarine", "mediumblue", "mediumorchid", "mediumpurple",
+    "mediumseagreen", "mediumslateblue", "mediumspringgreen", "mediumturquoise",
+    "mediumvioletred", "midnightblue", "mintcream", "mistyrose", "moccasin", "navajowhite",
+    "navy", "oldlace", "olive", "olivedrab", "orange", "orangered", "orchid", "palegoldenrod",
+    "palegreen", "paleturquoise", "palevioletred", "papayawhip", "peachpuff", "peru", "pink",
+    "plum", "powderblue", "purple", "rebeccapurple", "red", "rosybrown", "royalblue",
+    "saddlebrown", "salmon", "sandybrown", "seagreen", "seashell", "sienna", "silver", "skyblue",
+    "slateblue", "slategray", "slategrey", "snow", "springgreen", "steelblue", "tan", "teal",
+    "thistle", "tomato", "turquoise", "violet", "wheat", "white", "whitesmoke", "yellow",
+    "yellowgreen", "transparent", "currentcolor", "inherit", "initial", "unset",
+];
+
+/// CSS color functions accepted by [`is_valid_css_color`]
+const CSS_COLOR_FUNCTIONS: &[&str] = &[
+    "rgb", "rgba", "hsl", "hsla", "hwb", "lab", "lch", "oklab", "oklch", "color", "color-mix",
+    "var",
+];
+
+/// Whether a string is a syntactically valid CSS color value
+pub fn is_valid_css_color(value: &str) -> bool {
+    let value = value.trim();
+    if let Some(hex) = value.strip_prefix('#') {
+        return matches!(hex.len(), 3 | 4 | 6 | 8) && hex.chars().all(|c| c.is_ascii_hexdigit());
+    }
+
+    let lower = value.to_ascii_lowercase();
+    if let Some((name, rest)) = lower.split_once('(') {
+        let Some(arguments) = rest.strip_suffix(')') else {
+            return false;
+        };
+        let balanced = arguments
+            .chars()
+            .try_fold(0usize, |depth, c| match c {
+                '(' => Some(depth + 1),
+                ')' => depth.checked_sub(1),
+                ';' | '{' | '}' => None,
+                _ => Some(depth),
+            })
+            == Some(0);
+        return CSS_COLOR_FUNCTIONS.contains(&name.trim()) && !arguments.trim().is_empty() && balanced;
+    }
+
+    CSS_NAMED_COLORS.contains(&lower.as_str())
+}
+
+/// Return `value` when it is a valid CSS color, otherwise warn and return `fallback`
+pub fn sanitize_css_color(component: &str, value: &str, fallback: &str) -> String {
+    if is_valid_css_color(value) {
+        value.trim().to_string()
+    } else {
+        warn_invalid_prop(
+            component,
+            &format!("invalid color {:?}, using {}", value, fallback),
+        );
+        fallback.to_string()
+    }
+}
+
+/// Escape text for direct inclusion in HTML markup or attribute values
+pub fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_forced_colors_style() {
+        let border = "border: 1px solid CanvasText;";
+        assert_eq!(forced_colors_style(Some("width: 4px"), false, border), "width: 4px");
+        assert_eq!(forced_colors_style(None, true, border), border);
+        assert_eq!(
+            forced_colors_style(Some("width: 4px"), true, border),
+            "width: 4px; border: 1px solid CanvasText;"
+        );
+        assert_eq!(
+            forced_colors_style(Some("width: 4px;"), true, border),
+            "width: 4px; border: 1px solid CanvasText;"
+        );
+    }
+
+    #[test]
+    fn test_sanitize_range_swaps_inverted_bounds() {
+        assert_eq!(sanitize_range("Test", 100.0, 0.0, (0.0, 100.0)), (0.0, 100.0));
+        assert_eq!(sanitize_range("Test", 5.0, 10.0, (0.0, 100.0)), (5.0, 10.0));
+        assert_eq!(sanitize_range("Test", f64::NAN, 10.0, (0.0, 100.0)), (0.0, 10.0));
+        assert_eq!(sanitize_range("Test", 0.0, f64::INFINITY, (0.0, 100.0)), (0.0, 100.0));
+    }
+
+    #[test]
+    fn test_clamp_to_range() {
+        assert_eq!(clamp_to_range("Test", 150.0, 0.0, 100.0), 100.0);
+        assert_eq!(clamp_to_range("Test", -5.0, 0.0, 100.0), 0.0);
+        assert_eq!(clamp_to_range("Test", f64::NAN, 10.0, 100.0), 10.0);
+        assert_eq!(clamp_to_range("Test", 42.0, 0.0, 100.0), 42.0);
+    }
+
+    #[test]
+    fn test_sanitize_page() {
+        assert_eq!(sanitize_page("Test", 12, 10), (10, 10));
+        assert_eq!(sanitize_page("Test", 0, 10), (1, 10));
+        assert_eq!(sanitize_page("Test", 3, 0), (1, 1));
+        assert_eq!(sanitize_page("Test", 4, 10), (4, 10));
+    }
+
+    #[test]
+    fn test_is_valid_css_color() {
+        for valid in [
+            "#fff", "#FFFA", "#3b82f6", "#3b82f680", "red", "RebeccaPurple", "transparent",
+            "rgb(0, 0, 0)", "rgba(0 0 0 / 50%)", "hsl(210 40% 50%)", "oklch(0.7 0.1 200)",
+            "var(--radix-primary)", "color-mix(in srgb, red 50%, blue)",
+        ] {
+            assert!(is_valid_css_color(valid), "{} should be valid", valid);
+        }
+        for invalid in [
+            "", "#ff", "#ggg", "notacolor", "rgb(", "rgb()", "rgb(0,0,0", "url(evil)",
+            "red; background: url(x)", "rgb(0,0,0)) ; x",
+        ] {
+            assert!(!is_valid_css_color(invalid), "{} should be invalid", invalid);
+        }
+    }
+
+    #[test]
+    fn test_sanitize_css_color() {
+        assert_eq!(sanitize_css_color("Test", " #123456 ", "#000"), "#123456");
+        assert_eq!(sanitize_css_color("Test", "blurple", "#000"), "#000");
+    }
+
+    #[test]
+    fn test_escape_html() {
+        assert_eq!(escape_html("plain"), "plain");
+        assert_eq!(
+            escape_html("<b class=\"x\">Tom & Jerry's</b>"),
+            "&lt;b class=&quot;x&quot;&gt;Tom &amp; Jerry&#39;s&lt;/b&gt;"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_escaped_html_has_no_markup(value in ".*") {
+            let escaped = escape_html(&value);
+            prop_assert!(!escaped.contains(['<', '>', '"', '\'']));
+        }
+
+        #[test]
+        fn test_sanitize_range_is_ordered(min in proptest::num::f64::ANY, max in proptest::num::f64::ANY) {
+            let (min, max) = sanitize_range("Test", min, max, (0.0, 100.0));
+            prop_assert!(min.is_finite() && max.is_finite());
+            prop_assert!(min <= max);
+        }
+
+        #[test]
+        fn test_clamp_to_range_stays_in_bounds(value in proptest::num::f64::ANY, a in -1e6..1e6f64, b in -1e6..1e6f64) {
+            let (min, max) = sanitize_range("Test", a, b, (0.0, 100.0));
+            let clamped = clamp_to_range("Test", value, min, max);
+            prop_assert!(clamped >= min && clamped <= max);
+        }
+
+        #[test]
+        fn test_sanitize_page_is_in_bounds(current in 0..10_000usize, total in 0..10_000usize) {
+            let (current, total) = sanitize_page("Test", current, total);
+            prop_assert!(total >= 1);
+            prop_assert!((1..=total).contains(&current));
+        }
+
+        #[test]
+        fn test_hex_colors_are_valid(hex in "#([0-9a-fA-F]{3}|[0-9a-fA-F]{4}|[0-9a-fA-F]{6}|[0-9a-fA-F]{8})") {
+            prop_assert!(is_valid_css_color(&hex));
+        }
+
+        #[test]
+        fn test_sanitized_colors_are_valid(value in ".*") {
+            let color = sanitize_css_color("Test", &value, "#000000");
+            prop_assert!(is_valid_css_color(&color));
+        }
+    }
+}
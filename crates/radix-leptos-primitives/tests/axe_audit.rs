@@ -0,0 +1,81 @@
+//! axe-core audits of mounted components
+//!
+//! Where the hand-written WCAG tests check that a component compiles with
+//! the right props, these run axe-core's rule set over the rendered DOM.
+//! Color contrast depends on the application's stylesheet, which the test
+//! page doesn't load, so it is left to the docs site audit.
+
+use leptos::prelude::*;
+use radix_leptos_primitives::conformance::{run_axe, AxeImpact, AxeOptions};
+use radix_leptos_primitives::*;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn options() -> AxeOptions {
+    AxeOptions::new().without_rule("color-contrast")
+}
+
+#[wasm_bindgen_test]
+async fn test_button_axe() {
+    assert_no_axe_violations!(view! { <Button>"Save changes"</Button> }, options());
+}
+
+#[wasm_bindgen_test]
+async fn test_switch_axe() {
+    assert_no_axe_violations!(
+        view! { <Switch checked=true>"Airplane mode"</Switch> },
+        options()
+    );
+}
+
+#[wasm_bindgen_test]
+async fn test_separator_axe() {
+    assert_no_axe_violations!(view! { <Separator /> }, options());
+}
+
+#[wasm_bindgen_test]
+async fn test_kbd_axe() {
+    assert_no_axe_violations!(
+        view! { <p>"Press " <Kbd keys=vec!["Ctrl".to_string(), "K".to_string()] /></p> },
+        options()
+    );
+}
+
+#[wasm_bindgen_test]
+async fn test_data_table_axe() {
+    assert_no_axe_violations!(
+        view! {
+            <DataTable caption="Team".to_string()>
+                <DataTableHeader>
+                    <DataTableRow>
+                        <DataTableHeaderCell>"Name"</DataTableHeaderCell>
+                        <DataTableHeaderCell>"Role"</DataTableHeaderCell>
+                    </DataTableRow>
+                </DataTableHeader>
+                <DataTableBody>
+                    <DataTableRow>
+                        <DataTableCell>"Ada"</DataTableCell>
+                        <DataTableCell>"Engineer"</DataTableCell>
+                    </DataTableRow>
+                </DataTableBody>
+            </DataTable>
+        },
+        options()
+    );
+}
+
+// The bridge reports what it finds, so a known violation must show up
+#[wasm_bindgen_test]
+async fn test_axe_reports_unlabelled_button() {
+    let violations = run_axe(|| view! { <button type="button"></button> })
+        .await
+        .expect("axe-core runs");
+
+    let button_name = violations
+        .iter()
+        .find(|violation| violation.id == "button-name")
+        .expect("an unlabelled button is reported");
+    assert_eq!(button_name.impact, Some(AxeImpact::Critical));
+    assert_eq!(button_name.nodes.len(), 1);
+}
@@ -26,6 +26,7 @@ pub fn Sidebar() -> impl IntoView {
             <div class="nav-section">
                 <div class="nav-title">"Core Concepts"</div>
                 <A href="/accessibility" class="nav-link">"Accessibility"</A>
+                <A href="/accessibility/conformance" class="nav-link">"ARIA Conformance"</A>
                 <A href="/styling" class="nav-link">"Styling"</A>
                 <A href="/composition" class="nav-link">"Composition"</A>
             </div>
@@ -29,6 +29,7 @@ fn App() -> impl IntoView {
                         <Route path="/components" view=ComponentsPage/>
                         <Route path="/components/:component" view=ComponentPage/>
                         <Route path="/playground" view=PlaygroundPage/>
+                        <Route path="/accessibility/conformance" view=ConformancePage/>
                     </Routes>
                 </main>
             </div>
@@ -0,0 +1,60 @@
+use leptos::*;
+use radix_leptos::conformance::{conformance_specs, CheckStatus, ConformanceMatrix};
+
+#[component]
+pub fn ConformancePage() -> impl IntoView {
+    let matrix = ConformanceMatrix::run(&conformance_specs());
+
+    view! {
+        <div>
+            <header style="margin-bottom: 2rem;">
+                <h1>"ARIA Conformance"</h1>
+                <p style="color: var(--color-text-muted); max-width: 640px;">
+                    "Each component is rendered and its markup checked against the roles and states of its WAI-ARIA Authoring Practices pattern. Keyboard interactions need a browser and are not part of this matrix."
+                </p>
+            </header>
+
+            <table class="conformance-table">
+                <thead>
+                    <tr>
+                        <th>"Component"</th>
+                        <th>"Pattern"</th>
+                        <th>"Passed"</th>
+                        <th>"Failed"</th>
+                    </tr>
+                </thead>
+                <tbody>
+                    {matrix.components.iter().map(|component| view! {
+                        <tr data-conforms=component.conforms()>
+                            <td>{component.component}</td>
+                            <td><a href=component.guide_url>{component.pattern}</a></td>
+                            <td>{component.passed()}</td>
+                            <td>{component.failed()}</td>
+                        </tr>
+                    }).collect_view()}
+                </tbody>
+            </table>
+
+            {matrix.components.into_iter().map(|component| view! {
+                <section class="conformance-details">
+                    <h2 id=component.component.to_lowercase()>{component.component}</h2>
+                    <ul>
+                        {component.results.into_iter().map(|result| {
+                            let (status, detail) = match result.status {
+                                CheckStatus::Pass => ("pass", None),
+                                CheckStatus::Fail(detail) => ("fail", Some(detail)),
+                            };
+                            view! {
+                                <li data-status=status>
+                                    <span class="conformance-status">{status}</span>
+                                    {result.description}
+                                    {detail.map(|detail| view! { <div class="conformance-detail">{detail}</div> })}
+                                </li>
+                            }
+                        }).collect_view()}
+                    </ul>
+                </section>
+            }).collect_view()}
+        </div>
+    }
+}
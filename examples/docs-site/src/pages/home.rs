@@ -1,10 +1,28 @@
 use leptos::*;
 use crate::components::*;
+use radix_leptos::{Anchor, AnchorLink};
+
+fn home_toc() -> Vec<AnchorLink> {
+    vec![
+        AnchorLink::new("why-radix-leptos", "Why Radix-Leptos?"),
+        AnchorLink::new("quick-example", "Quick Example"),
+        AnchorLink::new("getting-started", "Getting Started")
+            .child(AnchorLink::new("installation", "Installation")),
+    ]
+}
 
 #[component]
 pub fn HomePage() -> impl IntoView {
     view! {
         <div>
+            <aside class="docs-toc">
+                <Anchor
+                    links=home_toc()
+                    container=".docs-main".to_string()
+                    offset=24.0
+                    label="On this page".to_string()
+                />
+            </aside>
             <header style="margin-bottom: 3rem;">
                 <h1 style="font-size: 3rem; margin-bottom: 1rem; background: linear-gradient(135deg, #6366f1 0%, #8b5cf6 100%); -webkit-background-clip: text; -webkit-text-fill-color: transparent; background-clip: text;">
                     "Radix-Leptos"
@@ -23,7 +41,7 @@ pub fn HomePage() -> impl IntoView {
             </header>
 
             <section style="margin-bottom: 4rem;">
-                <h2 style="margin-bottom: 2rem;">"Why Radix-Leptos?"</h2>
+                <h2 id="why-radix-leptos" style="margin-bottom: 2rem;">"Why Radix-Leptos?"</h2>
                 <div class="example-grid">
                     <div class="example-card">
                         <div class="example-preview">
@@ -92,7 +110,7 @@ pub fn HomePage() -> impl IntoView {
             </section>
 
             <section style="margin-bottom: 4rem;">
-                <h2 style="margin-bottom: 2rem;">"Quick Example"</h2>
+                <h2 id="quick-example" style="margin-bottom: 2rem;">"Quick Example"</h2>
                 
                 <ComponentPlayground
                     title="Dialog Component"
@@ -142,13 +160,13 @@ fn DialogExample() -> impl IntoView {
             </section>
 
             <section>
-                <h2 style="margin-bottom: 2rem;">"Getting Started"</h2>
+                <h2 id="getting-started" style="margin-bottom: 2rem;">"Getting Started"</h2>
                 <p style="margin-bottom: 2rem; font-size: 1.1rem;">
                     "Ready to build accessible applications with Radix-Leptos? Follow our getting started guide to set up your first project."
                 </p>
                 
                 <div style="background-color: var(--color-surface); padding: 2rem; border-radius: var(--border-radius); border-left: 4px solid var(--color-primary);">
-                    <h3 style="margin-top: 0;">"Installation"</h3>
+                    <h3 id="installation" style="margin-top: 0;">"Installation"</h3>
                     <CodeBlock 
                         code=r#"[dependencies]
 radix-leptos = "0.1"
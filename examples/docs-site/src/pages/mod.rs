@@ -3,9 +3,11 @@ mod getting_started;
 mod components;
 mod component;
 mod playground;
+mod conformance;
 
 pub use home::*;
 pub use getting_started::*;
 pub use components::*;
 pub use component::*;
-pub use playground::*;
\ No newline at end of file
+pub use playground::*;
+pub use conformance::*;
\ No newline at end of file
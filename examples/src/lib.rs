@@ -12,9 +12,11 @@ pub mod timeline_examples;
 pub mod toast_examples;
 pub mod real_demo;
 pub mod simple_test;
-// Note: test_components, avatar, image, video, audio, carousel, context_menu, menubar, scroll_area are not in core feature
+pub mod video_examples;
+// Note: test_components, avatar, image, audio, carousel, context_menu, menubar, scroll_area are not in core feature
 
 use pagination_examples::PaginationExamples;
+use video_examples::VideoExamples;
 
 // Test function to see if wasm_bindgen is working
 #[wasm_bindgen]
@@ -37,3 +39,13 @@ pub fn start_pagination_examples() {
 
     web_sys::console::log_1(&"Pagination Examples mounted successfully!".into());
 }
+
+// Export the video examples mount function
+#[wasm_bindgen]
+pub fn start_video_examples() {
+    mount_to_body(|| {
+        view! {
+            <VideoExamples/>
+        }
+    });
+}
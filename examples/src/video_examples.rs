@@ -0,0 +1,53 @@
+use leptos::logging::log;
+use leptos::prelude::*;
+use radix_leptos_primitives::*;
+
+#[component]
+pub fn VideoExamples() -> impl IntoView {
+    let handle_ended = Callback::new(move |_| {
+        log!("Video ended");
+    });
+
+    view! {
+        <div class="video-examples">
+            <h1>"Video Player Examples"</h1>
+
+            <div class="example-section">
+                <h2>"Video with captions"</h2>
+                <p>"Custom controls overlay with captions, picture-in-picture and fullscreen:"</p>
+                <VideoPlayer
+                    src="https://interactive-examples.mdn.mozilla.net/media/cc0-videos/friday.mp4".to_string()
+                    poster="https://interactive-examples.mdn.mozilla.net/media/examples/friday.png".to_string()
+                    label="Friday".to_string()
+                    captions=vec![VideoCaptionTrack::new(
+                        "https://interactive-examples.mdn.mozilla.net/media/examples/friday.vtt",
+                        "en",
+                        "English",
+                    )]
+                    on_ended=handle_ended
+                />
+            </div>
+
+            <div class="example-section">
+                <h2>"Without captions"</h2>
+                <VideoPlayer
+                    src="https://interactive-examples.mdn.mozilla.net/media/cc0-videos/flower.mp4".to_string()
+                    label="Flower".to_string()
+                    seek_step=10.0
+                />
+            </div>
+
+            <div class="api-docs">
+                <h2>"Keyboard Shortcuts"</h2>
+                <ul>
+                    <li><strong>"Space / K"</strong>" - Play or pause"</li>
+                    <li><strong>"Left / Right, J / L"</strong>" - Seek backwards or forwards"</li>
+                    <li><strong>"Up / Down"</strong>" - Change the volume"</li>
+                    <li><strong>"M"</strong>" - Mute"</li>
+                    <li><strong>"C"</strong>" - Toggle captions"</li>
+                    <li><strong>"F"</strong>" - Toggle fullscreen"</li>
+                </ul>
+            </div>
+        </div>
+    }
+}